@@ -29,6 +29,11 @@
       url = "github:libtom/libtommath/v1.2.0";
       flake = false;
     };
+    # Of the Unicode version of rts/motoko-rts/src/unicode/tables.rs
+    unicode-normalization-test = {
+      url = "file+https://www.unicode.org/Public/17.0.0/ucd/NormalizationTest.txt";
+      flake = false;
+    };
     motoko-base-src = {
       url = "github:caffeinelabs/motoko-base/next-moc";
       flake = false;
@@ -61,6 +66,7 @@
     , candid-src
     , ic-wasm-src
     , libtommath-src
+    , unicode-normalization-test
     , motoko-base-src
     , motoko-core-src
     , motoko-matchers-src
@@ -75,6 +81,7 @@
             candid-src
             ic-wasm-src
             libtommath-src
+            unicode-normalization-test
             motoko-base-src
             motoko-core-src
             motoko-matchers-src
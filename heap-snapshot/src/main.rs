@@ -0,0 +1,216 @@
+//! Converts a heap snapshot of the Motoko incremental GC into the V8 heap snapshot format.
+//!
+//! Usage: `heap-snapshot <input> [<output>]`
+//!
+//! The input is either the raw snapshot blob or its Candid text representation
+//! `(blob "...")`, as printed by `dfx canister call`. The output defaults to the
+//! input path with the extension `.heapsnapshot`.
+
+mod snapshot;
+mod v8;
+
+use std::{
+    fs::{self, File},
+    io::BufWriter,
+    path::PathBuf,
+    process::exit,
+};
+
+use snapshot::{MAGIC, Snapshot};
+
+fn main() {
+    let arguments: Vec<String> = std::env::args().skip(1).collect();
+    if arguments.is_empty() || arguments.len() > 2 {
+        eprintln!("Usage: heap-snapshot <input> [<output>]");
+        exit(2);
+    }
+    let input = PathBuf::from(&arguments[0]);
+    let output = match arguments.get(1) {
+        Some(path) => PathBuf::from(path),
+        None => input.with_extension("heapsnapshot"),
+    };
+    if let Err(message) = convert(&input, &output) {
+        eprintln!("heap-snapshot: {message}");
+        exit(1);
+    }
+}
+
+fn convert(input: &PathBuf, output: &PathBuf) -> Result<(), String> {
+    let content =
+        fs::read(input).map_err(|error| format!("cannot read {}: {error}", input.display()))?;
+    let bytes = if content.starts_with(MAGIC) {
+        content
+    } else {
+        parse_candid_blob(&content)?
+    };
+    let snapshot = Snapshot::decode(&bytes).map_err(|error| error.to_string())?;
+    let file = File::create(output)
+        .map_err(|error| format!("cannot create {}: {error}", output.display()))?;
+    let mut writer = BufWriter::new(file);
+    let statistics = v8::write_snapshot(&snapshot, &mut writer)
+        .and_then(|statistics| {
+            writer
+                .into_inner()
+                .map(|_| statistics)
+                .map_err(|error| error.into_error())
+        })
+        .map_err(|error| format!("cannot write {}: {error}", output.display()))?;
+    eprintln!(
+        "{}: {} nodes, {} edges ({} dropped edges to unknown objects)",
+        output.display(),
+        statistics.nodes,
+        statistics.edges,
+        statistics.dropped_edges
+    );
+    Ok(())
+}
+
+/// Parses the Candid text format of a blob, e.g. `(blob "MOHS\01\08...")`.
+fn parse_candid_blob(content: &[u8]) -> Result<Vec<u8>, String> {
+    let text = std::str::from_utf8(content).map_err(|_| "invalid input format".to_string())?;
+    let start = text
+        .find("blob \"")
+        .ok_or_else(|| "input is neither a snapshot nor a Candid blob".to_string())?
+        + "blob \"".len();
+    let mut bytes = vec![];
+    let mut characters = text[start..].chars();
+    loop {
+        match characters.next() {
+            None => return Err("unterminated Candid blob".to_string()),
+            Some('"') => return Ok(bytes),
+            Some('\\') => {
+                let first = characters.next();
+                let escaped = match first {
+                    Some('n') => b'\n',
+                    Some('r') => b'\r',
+                    Some('t') => b'\t',
+                    Some('\\') => b'\\',
+                    Some('"') => b'"',
+                    Some('\'') => b'\'',
+                    Some(high) => {
+                        let low = characters.next().unwrap_or(' ');
+                        let digits: String = [high, low].iter().collect();
+                        u8::from_str_radix(&digits, 16)
+                            .map_err(|_| format!("invalid escape \\{digits} in Candid blob"))?
+                    }
+                    None => return Err("unterminated Candid blob".to_string()),
+                };
+                bytes.push(escaped);
+            }
+            Some(character) => {
+                let mut buffer = [0; 4];
+                bytes.extend_from_slice(character.encode_utf8(&mut buffer).as_bytes());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::snapshot::DecodeError;
+
+    fn write_number(bytes: &mut Vec<u8>, mut value: u64) {
+        loop {
+            let byte = (value & 0x7f) as u8;
+            value >>= 7;
+            if value == 0 {
+                bytes.push(byte);
+                return;
+            }
+            bytes.push(byte | 0x80);
+        }
+    }
+
+    /// Root -> object @0x1000 -> text @0x1040, weak reference @0x1080 -> object,
+    /// and a pointer from the text to an unknown address.
+    fn example_snapshot() -> Vec<u8> {
+        let mut bytes = MAGIC.to_vec();
+        bytes.extend([1, 8]);
+        for number in [1, 0x1000] {
+            write_number(&mut bytes, number);
+        }
+        write_number(&mut bytes, 3);
+        for number in [0x1000, 1, 5, 1, 0x1040] {
+            write_number(&mut bytes, number);
+        }
+        for number in [0x40, 27, 3, 1, 0x9999] {
+            write_number(&mut bytes, number);
+        }
+        for number in [0x40, 45, 3, 1, 0x1000] {
+            write_number(&mut bytes, number);
+        }
+        bytes
+    }
+
+    #[test]
+    fn decode_snapshot() {
+        let snapshot = Snapshot::decode(&example_snapshot()).unwrap();
+        assert_eq!(snapshot.word_size, 8);
+        assert_eq!(snapshot.roots, [0x1000]);
+        let addresses: Vec<_> = snapshot
+            .objects
+            .iter()
+            .map(|object| object.address)
+            .collect();
+        assert_eq!(addresses, [0x1000, 0x1040, 0x1080]);
+        assert_eq!(snapshot.objects[0].size, 5);
+        assert_eq!(snapshot.objects[2].pointers, [0x1000]);
+        assert_eq!(snapshot.find(0x1040), Some(1));
+        assert_eq!(snapshot.find(0x1048), None);
+    }
+
+    #[test]
+    fn reject_invalid_snapshots() {
+        let mut bytes = example_snapshot();
+        assert_eq!(
+            Snapshot::decode(&bytes[..bytes.len() - 1]).err(),
+            Some(DecodeError::Truncated)
+        );
+        bytes.push(0);
+        assert!(matches!(
+            Snapshot::decode(&bytes),
+            Err(DecodeError::TrailingBytes { .. })
+        ));
+        bytes[4] = 2;
+        assert_eq!(
+            Snapshot::decode(&bytes).err(),
+            Some(DecodeError::UnsupportedVersion(2))
+        );
+        assert_eq!(
+            Snapshot::decode(b"MOHT\x01\x08").err(),
+            Some(DecodeError::InvalidMagic)
+        );
+    }
+
+    #[test]
+    fn write_v8_snapshot() {
+        let snapshot = Snapshot::decode(&example_snapshot()).unwrap();
+        let mut output = vec![];
+        let statistics = v8::write_snapshot(&snapshot, &mut output).unwrap();
+        assert_eq!(statistics.nodes, 4);
+        assert_eq!(statistics.edges, 3);
+        assert_eq!(statistics.dropped_edges, 1);
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("\"node_count\":4,\"edge_count\":3"));
+        // Root, object, text, and weak reference nodes.
+        assert!(output.contains(
+            "\"nodes\":[9,1,0,0,1,0,0,\n3,2,4096,40,1,0,0,\n2,3,4160,24,0,0,0,\n3,4,4224,24,1,0,0]"
+        ));
+        // Root edge to the object, object edge to the text, weak edge to the object.
+        assert!(output.contains("\"edges\":[1,0,7,\n1,0,14,\n6,0,7]"));
+        assert!(
+            output.contains(
+                "\"strings\":[\"\",\n\"(GC roots)\",\n\"Object\",\n\"Text\",\n\"WeakRef\"]"
+            )
+        );
+    }
+
+    #[test]
+    fn parse_candid_text() {
+        let bytes = parse_candid_blob(b"(blob \"MOHS\\01\\08\\00\\00\\\\\")\n").unwrap();
+        assert_eq!(bytes, b"MOHS\x01\x08\x00\x00\\");
+        assert!(parse_candid_blob(b"(blob \"MOHS").is_err());
+        assert!(parse_candid_blob(b"(42 : nat)").is_err());
+    }
+}
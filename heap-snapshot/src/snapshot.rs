@@ -0,0 +1,196 @@
+//! Decoder of the binary heap snapshot recorded by the incremental GC,
+//! cf. `rts/motoko-rts/src/gc/incremental/heap_snapshot.rs`.
+
+use std::fmt;
+
+pub const MAGIC: &[u8; 4] = b"MOHS";
+pub const FORMAT_VERSION: u8 = 1;
+
+#[derive(Debug, PartialEq)]
+pub enum DecodeError {
+    InvalidMagic,
+    UnsupportedVersion(u8),
+    UnsupportedWordSize(u8),
+    Truncated,
+    NumberOverflow { offset: usize },
+    TrailingBytes { offset: usize },
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::InvalidMagic => write!(f, "not a Motoko heap snapshot"),
+            DecodeError::UnsupportedVersion(version) => {
+                write!(f, "unsupported snapshot format version {version}")
+            }
+            DecodeError::UnsupportedWordSize(size) => write!(f, "unsupported word size {size}"),
+            DecodeError::Truncated => write!(f, "truncated snapshot"),
+            DecodeError::NumberOverflow { offset } => {
+                write!(f, "number overflow at offset {offset}")
+            }
+            DecodeError::TrailingBytes { offset } => {
+                write!(f, "unexpected trailing bytes at offset {offset}")
+            }
+        }
+    }
+}
+
+pub struct Object {
+    pub address: u64,
+    pub tag: u64,
+    /// Size in words, including the header.
+    pub size: u64,
+    pub pointers: Vec<u64>,
+}
+
+pub struct Snapshot {
+    /// 8 for enhanced orthogonal persistence, 4 for classical persistence.
+    pub word_size: u8,
+    pub roots: Vec<u64>,
+    /// In increasing address order.
+    pub objects: Vec<Object>,
+}
+
+impl Snapshot {
+    pub fn decode(bytes: &[u8]) -> Result<Snapshot, DecodeError> {
+        if bytes.len() < 6 || &bytes[0..4] != MAGIC {
+            return Err(DecodeError::InvalidMagic);
+        }
+        if bytes[4] != FORMAT_VERSION {
+            return Err(DecodeError::UnsupportedVersion(bytes[4]));
+        }
+        let word_size = bytes[5];
+        if word_size != 4 && word_size != 8 {
+            return Err(DecodeError::UnsupportedWordSize(word_size));
+        }
+        let mut reader = Reader { bytes, offset: 6 };
+        let root_count = reader.read_length()?;
+        let mut roots = Vec::with_capacity(root_count);
+        for _ in 0..root_count {
+            roots.push(reader.read_number()?);
+        }
+        let object_count = reader.read_length()?;
+        let mut objects = Vec::with_capacity(object_count);
+        let mut address = 0u64;
+        for _ in 0..object_count {
+            let offset = reader.offset;
+            address = address
+                .checked_add(reader.read_number()?)
+                .ok_or(DecodeError::NumberOverflow { offset })?;
+            let tag = reader.read_number()?;
+            let size = reader.read_number()?;
+            let pointer_count = reader.read_length()?;
+            let mut pointers = Vec::with_capacity(pointer_count);
+            for _ in 0..pointer_count {
+                pointers.push(reader.read_number()?);
+            }
+            objects.push(Object {
+                address,
+                tag,
+                size,
+                pointers,
+            });
+        }
+        if reader.offset != bytes.len() {
+            return Err(DecodeError::TrailingBytes {
+                offset: reader.offset,
+            });
+        }
+        Ok(Snapshot {
+            word_size,
+            roots,
+            objects,
+        })
+    }
+
+    /// Index of the object at `address`, if contained in the snapshot.
+    pub fn find(&self, address: u64) -> Option<usize> {
+        self.objects
+            .binary_search_by_key(&address, |object| object.address)
+            .ok()
+    }
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl Reader<'_> {
+    fn read_number(&mut self) -> Result<u64, DecodeError> {
+        let start = self.offset;
+        let mut result = 0u64;
+        let mut shift = 0;
+        loop {
+            let byte = *self.bytes.get(self.offset).ok_or(DecodeError::Truncated)?;
+            self.offset += 1;
+            if shift > 63 || (shift == 63 && byte & 0x7e != 0) {
+                return Err(DecodeError::NumberOverflow { offset: start });
+            }
+            result |= ((byte & 0x7f) as u64) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(result);
+            }
+            shift += 7;
+        }
+    }
+
+    /// Counts are bounded by the remaining bytes, as every element occupies at least one byte.
+    fn read_length(&mut self) -> Result<usize, DecodeError> {
+        let length = self.read_number()?;
+        if length > (self.bytes.len() - self.offset) as u64 {
+            return Err(DecodeError::Truncated);
+        }
+        Ok(length as usize)
+    }
+}
+
+/// Names of the object tags, cf. `rts/motoko-rts/src/types.rs`.
+pub fn tag_name(tag: u64, word_size: u8) -> &'static str {
+    match (tag, word_size) {
+        (1, _) => "Object",
+        (3, _) => "Array",
+        (5, _) => "MutableArray",
+        (7, _) => "Tuple",
+        (9, _) => "SharedFunction",
+        (11, _) => "Nat64",
+        (13, _) => "Int64",
+        (15, _) => "Float",
+        (17, _) => "MutBox",
+        (19, _) => "Closure",
+        (21, _) => "Some",
+        (23, _) => "Variant",
+        (25, _) => "Blob",
+        (27, _) => "Text",
+        (29, _) => "Principal",
+        (31, _) => "Actor",
+        (33, _) => "FwdPtr",
+        (35, _) => "BigInt",
+        (37, _) => "Concat",
+        (39, _) => "Region",
+        (41, 8) => "OneWordFiller",
+        (43, 8) => "FreeSpace",
+        (45, 8) => "WeakRef",
+        (46.., 8) => "ArraySlice",
+        (41, _) => "Nat32",
+        (43, _) => "Int32",
+        (45, _) => "Float32",
+        (47, _) => "Null",
+        (49, _) => "OneWordFiller",
+        (51, _) => "FreeSpace",
+        (52.., _) => "ArraySlice",
+        _ => "Unknown",
+    }
+}
+
+pub fn is_weak_ref(tag: u64, word_size: u8) -> bool {
+    tag == 45 && word_size == 8
+}
+
+pub fn is_array(tag: u64) -> bool {
+    matches!(tag, 3 | 5 | 7 | 9)
+}
+
+pub fn is_text(tag: u64) -> bool {
+    matches!(tag, 27 | 37)
+}
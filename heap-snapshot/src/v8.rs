@@ -0,0 +1,200 @@
+//! Writer of the V8 heap snapshot format (`.heapsnapshot`), as loaded by the
+//! memory tab of the Chrome developer tools.
+//!
+//! Node 0 is a synthetic `(GC roots)` node referring to the GC roots.
+//! Each heap object becomes a node whose id is the object address and whose
+//! name is the object kind. Pointers to objects that are not contained in the
+//! snapshot are dropped.
+
+use std::{
+    collections::HashMap,
+    io::{self, Write},
+};
+
+use crate::snapshot::{Snapshot, is_array, is_text, is_weak_ref, tag_name};
+
+const NODE_FIELD_COUNT: usize = 7;
+
+// Indices in the `node_types` of the snapshot meta data.
+const NODE_HIDDEN: usize = 0;
+const NODE_ARRAY: usize = 1;
+const NODE_STRING: usize = 2;
+const NODE_OBJECT: usize = 3;
+const NODE_CLOSURE: usize = 5;
+const NODE_NUMBER: usize = 7;
+const NODE_SYNTHETIC: usize = 9;
+const NODE_CONCATENATED_STRING: usize = 10;
+const NODE_BIGINT: usize = 13;
+
+// Indices in the `edge_types` of the snapshot meta data.
+const EDGE_ELEMENT: usize = 1;
+const EDGE_WEAK: usize = 6;
+
+const META: &str = r#"{"node_fields":["type","name","id","self_size","edge_count","trace_node_id","detachedness"],"node_types":[["hidden","array","string","object","code","closure","regexp","number","native","synthetic","concatenated string","sliced string","symbol","bigint","object shape"],"string","number","number","number","number","number"],"edge_fields":["type","name_or_index","to_node"],"edge_types":[["context","element","property","internal","hidden","shortcut","weak"],"string_or_number","node"],"trace_function_info_fields":["function_id","name","script_name","script_id","line","column"],"trace_node_fields":["id","function_info_index","count","size","children"],"sample_fields":["timestamp_us","last_assigned_id"],"location_fields":["object_index","script_id","line","column"]}"#;
+
+pub struct Statistics {
+    pub nodes: usize,
+    pub edges: usize,
+    pub dropped_edges: usize,
+}
+
+struct Node {
+    node_type: usize,
+    name: usize,
+    id: u64,
+    self_size: u64,
+    edge_count: usize,
+}
+
+struct Edge {
+    edge_type: usize,
+    index: usize,
+    to_node: usize,
+}
+
+#[derive(Default)]
+struct Strings {
+    table: Vec<&'static str>,
+    indices: HashMap<&'static str, usize>,
+}
+
+impl Strings {
+    fn intern(&mut self, string: &'static str) -> usize {
+        *self.indices.entry(string).or_insert_with(|| {
+            self.table.push(string);
+            self.table.len() - 1
+        })
+    }
+}
+
+fn node_type(tag: u64, word_size: u8) -> usize {
+    match (tag, tag_name(tag, word_size)) {
+        (1, _) => NODE_OBJECT,
+        (tag, _) if is_array(tag) => NODE_ARRAY,
+        (27, _) => NODE_STRING,
+        (tag, _) if is_text(tag) => NODE_CONCATENATED_STRING,
+        (19, _) => NODE_CLOSURE,
+        (35, _) => NODE_BIGINT,
+        (_, "Nat64" | "Int64" | "Float" | "Nat32" | "Int32" | "Float32") => NODE_NUMBER,
+        (_, "FwdPtr" | "OneWordFiller" | "FreeSpace" | "ArraySlice" | "Unknown") => NODE_HIDDEN,
+        _ => NODE_OBJECT,
+    }
+}
+
+pub fn write_snapshot<W: Write>(snapshot: &Snapshot, output: &mut W) -> io::Result<Statistics> {
+    let mut strings = Strings::default();
+    strings.intern("");
+    let root_name = strings.intern("(GC roots)");
+    let word_size = snapshot.word_size as u64;
+
+    let mut dropped_edges = 0;
+    let mut to_node = |address: u64| match snapshot.find(address) {
+        Some(index) => Some((index + 1) * NODE_FIELD_COUNT),
+        None => {
+            dropped_edges += 1;
+            None
+        }
+    };
+
+    let mut nodes = Vec::with_capacity(snapshot.objects.len() + 1);
+    let mut edges = vec![];
+    for (index, root) in snapshot.roots.iter().enumerate() {
+        if let Some(to_node) = to_node(*root) {
+            edges.push(Edge {
+                edge_type: EDGE_ELEMENT,
+                index,
+                to_node,
+            });
+        }
+    }
+    nodes.push(Node {
+        node_type: NODE_SYNTHETIC,
+        name: root_name,
+        id: 0,
+        self_size: 0,
+        edge_count: edges.len(),
+    });
+
+    for object in &snapshot.objects {
+        let edge_type = if is_weak_ref(object.tag, snapshot.word_size) {
+            EDGE_WEAK
+        } else {
+            EDGE_ELEMENT
+        };
+        let first_edge = edges.len();
+        for (index, pointer) in object.pointers.iter().enumerate() {
+            if let Some(to_node) = to_node(*pointer) {
+                edges.push(Edge {
+                    edge_type,
+                    index,
+                    to_node,
+                });
+            }
+        }
+        nodes.push(Node {
+            node_type: node_type(object.tag, snapshot.word_size),
+            name: strings.intern(tag_name(object.tag, snapshot.word_size)),
+            id: object.address,
+            self_size: object.size.saturating_mul(word_size),
+            edge_count: edges.len() - first_edge,
+        });
+    }
+
+    write!(output, "{{\"snapshot\":{{\"meta\":{META},")?;
+    write!(
+        output,
+        "\"node_count\":{},\"edge_count\":{},\"trace_function_count\":0}},",
+        nodes.len(),
+        edges.len()
+    )?;
+
+    output.write_all(b"\"nodes\":[")?;
+    for (index, node) in nodes.iter().enumerate() {
+        if index > 0 {
+            output.write_all(b",\n")?;
+        }
+        write!(
+            output,
+            "{},{},{},{},{},0,0",
+            node.node_type, node.name, node.id, node.self_size, node.edge_count
+        )?;
+    }
+    output.write_all(b"],\n\"edges\":[")?;
+    for (index, edge) in edges.iter().enumerate() {
+        if index > 0 {
+            output.write_all(b",\n")?;
+        }
+        write!(output, "{},{},{}", edge.edge_type, edge.index, edge.to_node)?;
+    }
+    output.write_all(
+        b"],\n\"trace_function_infos\":[],\"trace_tree\":[],\"samples\":[],\"locations\":[],\n\"strings\":[",
+    )?;
+    for (index, string) in strings.table.iter().enumerate() {
+        if index > 0 {
+            output.write_all(b",\n")?;
+        }
+        write_json_string(output, string)?;
+    }
+    output.write_all(b"]}\n")?;
+
+    Ok(Statistics {
+        nodes: nodes.len(),
+        edges: edges.len(),
+        dropped_edges,
+    })
+}
+
+fn write_json_string<W: Write>(output: &mut W, string: &str) -> io::Result<()> {
+    output.write_all(b"\"")?;
+    for character in string.chars() {
+        match character {
+            '"' => output.write_all(b"\\\"")?,
+            '\\' => output.write_all(b"\\\\")?,
+            character if (character as u32) < 0x20 => {
+                write!(output, "\\u{:04x}", character as u32)?
+            }
+            character => write!(output, "{character}")?,
+        }
+    }
+    output.write_all(b"\"")
+}
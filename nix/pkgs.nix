@@ -44,7 +44,9 @@
     rust-overlay.overlays.default
     (self: super: {
       # When you change the rust-nightly version,
-      # make sure to change the rustStdDepsHash in ./rts.nix accordingly.
+      # make sure to change the rustStdDepsHash in ./rts.nix accordingly,
+      # and regenerate rts/motoko-rts/src/unicode/tables.rs if its Unicode version changed,
+      # together with the unicode-normalization-test input in ../flake.nix.
       rust-nightly = self.rust-bin.nightly."2026-07-25".default.override {
         extensions = [ "rust-src" ];
         targets = [ "wasm32-wasip1" ];
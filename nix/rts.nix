@@ -81,6 +81,7 @@ let
 
       ${llvmEnv}
       export TOMMATHSRC=${pkgs.sources.libtommath-src}
+      export NORMALIZATION_TEST=${pkgs.sources.unicode-normalization-test}
     '';
 
     installPhase = ''
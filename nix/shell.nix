@@ -85,6 +85,7 @@ pkgs.mkShell {
   '';
   ESM = esm;
   TOMMATHSRC = pkgs.sources.libtommath-src;
+  NORMALIZATION_TEST = pkgs.sources.unicode-normalization-test;
   LOCALE_ARCHIVE = pkgs.lib.optionalString pkgs.stdenv.isLinux "${pkgs.glibcLocales}/lib/locale/locale-archive";
   MOTOKO_BASE = base-src;
   MOTOKO_CORE = core-src;
@@ -1,6 +1,8 @@
 use crate::memory::{initialize_test_memory, reset_test_memory};
 
-use motoko_rts::principal_id::{base32_of_checksummed_blob, base32_to_blob, blob_of_ptr_size};
+use motoko_rts::principal_id::{
+    base32_of_checksummed_blob, base32_to_blob, blob_of_ptr_size, compute_crc32, update_crc32,
+};
 use motoko_rts::text::{blob_compare, text_compare, text_of_ptr_size};
 use motoko_rts::types::Bytes;
 
@@ -79,5 +81,15 @@ pub unsafe fn test() {
         0
     );
 
+    //
+    // Incremental computation
+    //
+
+    assert_eq!(update_crc32(0, b""), 0);
+    assert_eq!(update_crc32(0, b"123456789"), 0xcbf43926);
+    assert_eq!(update_crc32(update_crc32(0, b"1234"), b"56789"), 0xcbf43926);
+    let blob = blob_of_ptr_size(&mut heap, b"123456789".as_ptr(), Bytes(9));
+    assert_eq!(compute_crc32(blob), 0xcbf43926);
+
     reset_test_memory();
 }
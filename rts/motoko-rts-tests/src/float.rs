@@ -0,0 +1,173 @@
+//! Float formatting and parsing tests
+
+use crate::memory::initialize_test_memory;
+
+use motoko_rts::float::{HexFloat, ShortestFloat, float_fmt, float_of_text, parse_float};
+use motoko_rts::memory::Memory;
+use motoko_rts::text::{text_compare, text_concat, text_of_str};
+
+use oorandom::Rand64;
+
+// (value, precision, hexadecimal format)
+static HEX_FORMAT_TESTS: [(f64, usize, &str); 16] = [
+    (1.0, 0, "0x1p+0"),
+    (1.0, 2, "0x1.00p+0"),
+    (-3.0, 1, "-0x1.8p+1"),
+    // Ties to even
+    (1.5, 0, "0x2p+0"),
+    (2.5, 0, "0x1p+1"),
+    (0.1, 3, "0x1.99ap-4"),
+    (0.1, 13, "0x1.999999999999ap-4"),
+    (0.1, 16, "0x1.999999999999a000p-4"),
+    (f64::MAX, 2, "0x2.00p+1023"),
+    (0.0, 0, "0x0p+0"),
+    (-0.0, 2, "-0x0.00p+0"),
+    (5e-324, 13, "0x0.0000000000001p-1022"),
+    (2.2250738585072014e-308, 1, "0x1.0p-1022"),
+    (f64::INFINITY, 3, "infinity"),
+    (f64::NEG_INFINITY, 3, "-infinity"),
+    (f64::NAN, 3, "nan"),
+];
+
+// (value, shortest round-trip format)
+static SHORTEST_FORMAT_TESTS: [(f64, &str); 18] = [
+    (0.1, "0.1"),
+    (-1.5, "-1.5"),
+    (1.0 / 3.0, "0.3333333333333333"),
+    (100.0, "100"),
+    (123456.789, "123456.789"),
+    (1e16, "10000000000000000"),
+    (1e17, "1e+17"),
+    (1.25e20, "1.25e+20"),
+    (1e100, "1e+100"),
+    (0.0001, "0.0001"),
+    (0.00012, "0.00012"),
+    (1e-5, "1e-05"),
+    (5e-324, "5e-324"),
+    (f64::MAX, "1.7976931348623157e+308"),
+    (0.0, "0"),
+    (-0.0, "-0"),
+    (f64::INFINITY, "inf"),
+    (f64::NEG_INFINITY, "-inf"),
+];
+
+// (text, parsed value)
+static PARSE_TESTS: [(&str, Option<f64>); 41] = [
+    ("0", Some(0.0)),
+    ("-0", Some(-0.0)),
+    ("+1.5", Some(1.5)),
+    (".5", Some(0.5)),
+    ("2.", Some(2.0)),
+    ("1e-7", Some(1e-7)),
+    ("1.25E+20", Some(1.25e20)),
+    ("0.1", Some(0.1)),
+    ("2.4703282292062328e-324", Some(5e-324)),
+    ("2.4703282292062327e-324", Some(0.0)),
+    ("1.7976931348623159e308", Some(f64::INFINITY)),
+    ("inf", Some(f64::INFINITY)),
+    ("-Infinity", Some(f64::NEG_INFINITY)),
+    // Hexadecimal
+    ("0x1p0", Some(1.0)),
+    ("0X1.8P+1", Some(3.0)),
+    ("-0xAp-2", Some(-2.5)),
+    ("0x.8p1", Some(1.0)),
+    ("0x10.8p-4", Some(1.03125)),
+    ("0xff", Some(255.0)),
+    ("-0x0p0", Some(-0.0)),
+    ("0x1.999999999999ap-4", Some(0.1)),
+    ("0x1p-1074", Some(5e-324)),
+    ("0x1p-1075", Some(0.0)),
+    ("0x1.0000000000001p-1075", Some(5e-324)),
+    ("0x3p-1076", Some(5e-324)),
+    ("0x1.fffffffffffffp-1023", Some(2.2250738585072014e-308)),
+    ("0x1.00000000000008p0", Some(1.0)),
+    ("0x1.00000000000018p0", Some(1.0000000000000004)),
+    ("0x1.000000000000080001p0", Some(1.0000000000000002)),
+    ("0x123456789abcdef0123p-100", Some(4.238552517361111e-09)),
+    ("0x1.fffffffffffff7ffp+1023", Some(f64::MAX)),
+    ("0x1.fffffffffffff8p+1023", Some(f64::INFINITY)),
+    ("0x1p99999999999", Some(f64::INFINITY)),
+    ("0x1p-99999999999", Some(0.0)),
+    // Invalid
+    ("", None),
+    (".", None),
+    ("1e", None),
+    ("0x", None),
+    ("0x1p", None),
+    (" 1", None),
+    ("1_000", None),
+];
+
+pub unsafe fn test() {
+    println!("Testing float formatting and parsing ...");
+
+    println!("  Testing hexadecimal format");
+    for (value, prec, expected) in HEX_FORMAT_TESTS.iter() {
+        assert_eq!(HexFloat(*value, *prec).to_string(), *expected);
+    }
+
+    println!("  Testing shortest round-trip format");
+    for (value, expected) in SHORTEST_FORMAT_TESTS.iter() {
+        assert_eq!(ShortestFloat(*value).to_string(), *expected);
+    }
+    assert_eq!(ShortestFloat(f64::NAN).to_string(), "nan");
+
+    println!("  Testing parsing");
+    for (text, expected) in PARSE_TESTS.iter() {
+        assert_eq!(
+            parse_float(text).map(f64::to_bits),
+            expected.map(f64::to_bits)
+        );
+    }
+    assert!(parse_float("nan").unwrap().is_nan());
+    assert!(parse_float("-NaN").unwrap().is_nan());
+
+    println!("  Testing round trips");
+    let mut random = Rand64::new(42);
+    for _ in 0..100_000 {
+        let value = f64::from_bits(random.rand_u64());
+        if value.is_nan() {
+            continue;
+        }
+        let shortest = ShortestFloat(value).to_string();
+        assert_eq!(parse_float(&shortest).unwrap().to_bits(), value.to_bits());
+        let hex = HexFloat(value, 13).to_string();
+        assert_eq!(parse_float(&hex).unwrap().to_bits(), value.to_bits());
+    }
+
+    println!("  Testing float_fmt and float_of_text");
+    let mut mem = initialize_test_memory();
+    text_test(&mut mem);
+}
+
+unsafe fn text_test<M: Memory>(mem: &mut M) {
+    // `prec` and `mode` are `Nat8` values
+    const SHIFT: u32 = usize::BITS - 8;
+    let hex = float_fmt(mem, 0.1, 3 << SHIFT, 3 << SHIFT);
+    let expected = text_of_str(mem, "0x1.99ap-4");
+    assert_eq!(text_compare(hex, expected), 0);
+    let shortest = float_fmt(mem, 5e-324, 0, 5 << SHIFT);
+    let expected = text_of_str(mem, "5e-324");
+    assert_eq!(text_compare(shortest, expected), 0);
+    // Mode 4 keeps the fixed notation of Rust
+    let exact = float_fmt(mem, 1e21, 0, 4 << SHIFT);
+    let expected = text_of_str(mem, "1000000000000000000000");
+    assert_eq!(text_compare(exact, expected), 0);
+    let exact = float_fmt(mem, f64::NAN, 0, 4 << SHIFT);
+    let expected = text_of_str(mem, "NaN");
+    assert_eq!(text_compare(exact, expected), 0);
+
+    // A text too long to be copied on concatenation
+    let mut result = 0.0;
+    let prefix = text_of_str(
+        mem,
+        "0.000000000000000000000000000000000000000000000000000000",
+    );
+    let suffix = text_of_str(mem, "1e54");
+    let text = text_concat(mem, prefix, suffix);
+    assert_eq!(float_of_text(mem, text, &mut result), 1);
+    assert_eq!(result, 0.1);
+    let text = text_concat(mem, text, suffix);
+    assert_eq!(float_of_text(mem, text, &mut result), 0);
+    assert_eq!(result, 0.1);
+}
@@ -1,20 +1,30 @@
 pub mod array_slicing;
+pub mod census;
+pub mod full_gc;
+pub mod heap_snapshot;
 pub mod mark_bitmap;
 pub mod mark_stack;
 pub mod partitioned_heap;
+pub mod policy;
 pub mod roots;
 pub mod sort;
 pub mod time;
+pub mod young_generation;
 
 pub fn test() {
     println!("Testing incremental GC ...");
     unsafe {
         array_slicing::test();
+        heap_snapshot::test();
+        census::test();
+        full_gc::test();
         mark_bitmap::test();
         mark_stack::test();
         partitioned_heap::test();
+        policy::test();
         sort::test();
         roots::test();
         time::test();
+        young_generation::test();
     }
 }
@@ -0,0 +1,135 @@
+use std::collections::HashMap;
+
+use motoko_rts::{
+    gc::incremental::{
+        census::{
+            PARTITION_ALLOCATION, PARTITION_FREE, discard_census, get_census_object_count,
+            get_census_object_size, get_partition_count, get_partition_flags,
+            get_partition_garbage_size, get_partition_marked_size, get_partition_occupied_size,
+        },
+        get_partitioned_heap,
+        heap_snapshot::{request_heap_snapshot, take_snapshot},
+        is_gc_running,
+        policy::{DEFAULT_POLICY, reset_gc_scheduling_policy, set_gc_scheduling_policy},
+    },
+    types::{TAG_ARRAY_M, TAG_ARRAY_SLICE_MIN, TAG_OBJECT, Tag},
+};
+
+use crate::gc::{
+    initialize_gc, reset_gc,
+    utils::{GC, WORD_SIZE},
+};
+
+use super::{full_gc::increment, heap_snapshot::decode};
+
+pub unsafe fn test() {
+    println!("  Testing census...");
+
+    let object_map = [
+        (0, vec![1, 2]),
+        (1, vec![2]),
+        (2, vec![]),
+        (3, vec![2]), // Garbage
+        (4, vec![]),  // Garbage
+    ];
+    let root_ids = [0];
+    let continuation_ids = [];
+
+    let mut heap = crate::gc::heap::MotokoHeap::new(
+        &object_map,
+        &root_ids,
+        &continuation_ids,
+        GC::Incremental,
+        0,
+    );
+    initialize_gc(&mut heap);
+
+    request_heap_snapshot();
+    GC::Incremental.run(&mut heap, 0);
+    let snapshot = decode(take_snapshot().unwrap());
+    // The snapshot blob becomes garbage after it has been taken.
+    GC::Incremental.run(&mut heap, 1);
+
+    let mut expected: HashMap<usize, (usize, usize)> = HashMap::new();
+    for object in &snapshot.objects {
+        let entry = expected.entry(object.tag).or_default();
+        entry.0 += 1;
+        entry.1 += object.size * WORD_SIZE;
+    }
+    assert!(expected[&(TAG_ARRAY_M as usize)].0 >= 3);
+    for tag in (TAG_OBJECT..TAG_ARRAY_SLICE_MIN).step_by(2) {
+        let (count, size) = expected.get(&(tag as usize)).copied().unwrap_or_default();
+        assert_eq!(get_census_object_count(tag), count);
+        assert_eq!(get_census_object_size(tag).as_usize(), size);
+    }
+    check_invalid_tag(0);
+    check_invalid_tag(TAG_ARRAY_SLICE_MIN);
+
+    check_partitions();
+
+    let array_count = get_census_object_count(TAG_ARRAY_M);
+    check_upgrade_during_mark(&mut heap, array_count);
+
+    reset_gc();
+}
+
+unsafe fn check_invalid_tag(tag: Tag) {
+    assert_eq!(get_census_object_count(tag), 0);
+    assert_eq!(get_census_object_size(tag).as_usize(), 0);
+}
+
+unsafe fn check_partitions() {
+    let heap = get_partitioned_heap();
+    let count = get_partition_count();
+    assert!(count > 0);
+    let mut occupied_size = 0;
+    let mut allocation_partitions = 0;
+    for index in 0..count {
+        let flags = get_partition_flags(index);
+        if flags & PARTITION_ALLOCATION != 0 {
+            allocation_partitions += 1;
+        }
+        let size = get_partition_occupied_size(index).as_usize();
+        if flags & PARTITION_FREE != 0 {
+            assert_eq!(size, 0);
+        }
+        occupied_size += size;
+        // No mark information outside of a GC run.
+        assert_eq!(get_partition_marked_size(index).as_usize(), 0);
+        assert_eq!(get_partition_garbage_size(index).as_usize(), 0);
+    }
+    assert_eq!(allocation_partitions, 1);
+    assert_eq!(occupied_size, heap.occupied_size().as_usize());
+}
+
+unsafe fn check_upgrade_during_mark(heap: &mut crate::gc::heap::MotokoHeap, array_count: usize) {
+    // Small increments to interrupt the mark phase.
+    set_gc_scheduling_policy(
+        DEFAULT_POLICY.critical_heap_limit,
+        DEFAULT_POLICY.medium_heap_limit,
+        DEFAULT_POLICY.critical_growth_threshold,
+        DEFAULT_POLICY.medium_growth_threshold,
+        DEFAULT_POLICY.low_growth_threshold,
+        1,
+        0,
+    );
+    increment(heap);
+    // Still marking, as the mark information is only available after mark completion.
+    assert!(is_gc_running());
+    assert_eq!(get_partition_marked_size(0).as_usize(), 0);
+    // The census state is lost on an upgrade, while the GC continues with its persistent state.
+    discard_census();
+    while is_gc_running() {
+        increment(heap);
+    }
+    // The partial census of the interrupted mark phase is not published.
+    assert_eq!(get_census_object_count(TAG_ARRAY_M), 0);
+    assert_eq!(get_census_object_size(TAG_ARRAY_M).as_usize(), 0);
+
+    increment(heap);
+    while is_gc_running() {
+        increment(heap);
+    }
+    assert_eq!(get_census_object_count(TAG_ARRAY_M), array_count);
+    reset_gc_scheduling_policy();
+}
@@ -0,0 +1,110 @@
+use motoko_rts::{
+    gc::incremental::{
+        IncrementalGC, get_incremental_gc_state, is_gc_running,
+        policy::{DEFAULT_POLICY, reset_gc_scheduling_policy, set_gc_scheduling_policy},
+        run_full_gc,
+    },
+    types::Value,
+};
+
+use crate::gc::{heap::MotokoHeap, initialize_gc, reset_gc, utils::GC};
+
+pub unsafe fn test() {
+    println!("  Testing forced full GC...");
+
+    let object_map = [
+        (0, vec![1, 2]),
+        (1, vec![2]),
+        (2, vec![]),
+        (3, vec![2]), // Garbage
+        (4, vec![]),  // Garbage
+    ];
+    let root_ids = [0];
+    let continuation_ids = [];
+
+    let mut heap = MotokoHeap::new(
+        &object_map,
+        &root_ids,
+        &continuation_ids,
+        GC::Incremental,
+        0,
+    );
+    initialize_gc(&mut heap);
+    // Small increments to require multiple increments per GC run.
+    set_gc_scheduling_policy(
+        DEFAULT_POLICY.critical_heap_limit,
+        DEFAULT_POLICY.medium_heap_limit,
+        DEFAULT_POLICY.critical_growth_threshold,
+        DEFAULT_POLICY.medium_growth_threshold,
+        DEFAULT_POLICY.low_growth_threshold,
+        4,
+        0,
+    );
+
+    let paused_increments = test_full_gc(&mut heap);
+    assert!(paused_increments > 1);
+
+    // Start a GC run before forcing the full GC.
+    increment(&mut heap);
+    assert!(is_gc_running());
+    let running_increments = test_full_gc(&mut heap);
+    assert!(running_increments > paused_increments);
+
+    test_limit(&mut heap);
+
+    reset_gc_scheduling_policy();
+    reset_gc();
+}
+
+unsafe fn test_full_gc(heap: &mut MotokoHeap) -> usize {
+    let mut increments = 0;
+    let completed = run_full_gc(
+        || {
+            increment(heap);
+            increments += 1;
+        },
+        || false,
+    );
+    assert!(completed);
+    assert!(!is_gc_running());
+    increments
+}
+
+unsafe fn test_limit(heap: &mut MotokoHeap) {
+    const INCREMENT_LIMIT: usize = 2;
+    let mut increments = 0;
+    let mut checks = 0;
+    let completed = run_full_gc(
+        || {
+            increment(heap);
+            increments += 1;
+        },
+        || {
+            checks += 1;
+            checks > INCREMENT_LIMIT
+        },
+    );
+    assert!(!completed);
+    assert!(is_gc_running());
+    assert_eq!(increments, INCREMENT_LIMIT);
+
+    // The GC continues with the next forced full GC.
+    assert!(run_full_gc(|| increment(heap), || false));
+    assert!(!is_gc_running());
+}
+
+pub unsafe fn increment(heap: &mut MotokoHeap) {
+    let static_root = heap.static_root_array_variable_address() as *mut Value;
+    let continuation_table_location = heap.continuation_table_variable_address() as *mut Value;
+    let region0_pointer_location = heap.region0_pointer_variable_address() as *mut Value;
+    let unused_root = &mut Value::from_scalar(0) as *mut Value;
+    let roots = [
+        static_root,
+        continuation_table_location,
+        region0_pointer_location,
+        unused_root,
+        unused_root,
+        unused_root,
+    ];
+    IncrementalGC::instance(heap, get_incremental_gc_state()).empty_call_stack_increment(roots);
+}
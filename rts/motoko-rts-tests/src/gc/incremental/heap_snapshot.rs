@@ -0,0 +1,166 @@
+use motoko_rts::{
+    gc::incremental::heap_snapshot::{
+        MAXIMUM_SNAPSHOT_HEAP_SIZE, SNAPSHOT_FORMAT_VERSION, SNAPSHOT_MAGIC, request_heap_snapshot,
+        snapshot_fits, take_snapshot,
+    },
+    types::{Array, Bytes, TAG_ARRAY_M, Value, Words, size_of},
+};
+
+use crate::gc::{
+    initialize_gc, reset_gc,
+    utils::{GC, ObjectIdx, WORD_SIZE},
+};
+
+pub struct SnapshotObject {
+    pub address: usize,
+    pub tag: usize,
+    pub size: usize,
+    pub pointers: Vec<usize>,
+}
+
+pub struct Snapshot {
+    pub roots: Vec<usize>,
+    pub objects: Vec<SnapshotObject>,
+}
+
+pub unsafe fn test() {
+    println!("  Testing heap snapshot...");
+
+    let object_map = [
+        (0, vec![1, 2]),
+        (1, vec![2]),
+        (2, vec![]),
+        (3, vec![2]), // Garbage
+    ];
+    let root_ids = [0];
+    let continuation_ids = [];
+
+    let mut heap = crate::gc::heap::MotokoHeap::new(
+        &object_map,
+        &root_ids,
+        &continuation_ids,
+        GC::Incremental,
+        0,
+    );
+    initialize_gc(&mut heap);
+    let addresses = object_addresses(heap.heap_base_address(), &object_map);
+
+    request_heap_snapshot();
+    GC::Incremental.run(&mut heap, 0);
+    let blob = take_snapshot().unwrap();
+    assert!(take_snapshot().is_none());
+    let snapshot = decode(blob);
+    check_snapshot(&snapshot, &addresses, &object_map);
+
+    GC::Incremental.run(&mut heap, 1);
+    assert!(take_snapshot().is_none());
+
+    reset_gc();
+
+    assert!(snapshot_fits(Bytes(
+        heap.heap_ptr_address() - heap.heap_base_address()
+    )));
+    assert!(snapshot_fits(MAXIMUM_SNAPSHOT_HEAP_SIZE));
+    assert!(!snapshot_fits(MAXIMUM_SNAPSHOT_HEAP_SIZE + Bytes(1)));
+}
+
+fn object_addresses(heap_base: usize, object_map: &[(ObjectIdx, Vec<ObjectIdx>)]) -> Vec<usize> {
+    let mut address = heap_base;
+    let mut addresses = vec![];
+    for (_, fields) in object_map {
+        addresses.push(address);
+        address += object_size(fields).to_bytes().as_usize();
+    }
+    addresses
+}
+
+fn object_size(fields: &[ObjectIdx]) -> Words<usize> {
+    // Index field and pointer fields.
+    size_of::<Array>() + Words(1 + fields.len())
+}
+
+fn check_snapshot(
+    snapshot: &Snapshot,
+    addresses: &[usize],
+    object_map: &[(ObjectIdx, Vec<ObjectIdx>)],
+) {
+    let find = |address: usize| {
+        snapshot
+            .objects
+            .iter()
+            .find(|object| object.address == address)
+    };
+    for (id, fields) in object_map {
+        let object = find(addresses[*id]);
+        if *id == 3 {
+            assert!(object.is_none());
+            continue;
+        }
+        let object = object.unwrap();
+        assert_eq!(object.tag, TAG_ARRAY_M as usize);
+        assert_eq!(object.size, object_size(fields).as_usize());
+        let expected: Vec<usize> = fields.iter().map(|field| addresses[*field]).collect();
+        assert_eq!(object.pointers, expected);
+    }
+
+    assert!(!snapshot.roots.is_empty());
+    for root in &snapshot.roots {
+        assert!(find(*root).is_some());
+    }
+    for object in &snapshot.objects {
+        for pointer in &object.pointers {
+            assert!(find(*pointer).is_some());
+        }
+    }
+}
+
+pub unsafe fn decode(blob: Value) -> Snapshot {
+    let blob = blob.as_blob();
+    let bytes = std::slice::from_raw_parts(blob.payload_const(), blob.len().as_usize());
+    assert_eq!(&bytes[0..4], SNAPSHOT_MAGIC);
+    assert_eq!(bytes[4], SNAPSHOT_FORMAT_VERSION);
+    assert_eq!(bytes[5] as usize, WORD_SIZE);
+
+    let mut reader = Reader { bytes, position: 6 };
+    let root_count = reader.read_number();
+    let roots = (0..root_count).map(|_| reader.read_number()).collect();
+    let object_count = reader.read_number();
+    let mut objects = vec![];
+    let mut address = 0;
+    for _ in 0..object_count {
+        address += reader.read_number();
+        let tag = reader.read_number();
+        let size = reader.read_number();
+        let pointer_count = reader.read_number();
+        let pointers = (0..pointer_count).map(|_| reader.read_number()).collect();
+        objects.push(SnapshotObject {
+            address,
+            tag,
+            size,
+            pointers,
+        });
+    }
+    assert_eq!(reader.position, bytes.len());
+    Snapshot { roots, objects }
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn read_number(&mut self) -> usize {
+        let mut result = 0;
+        let mut shift = 0;
+        loop {
+            let byte = self.bytes[self.position];
+            self.position += 1;
+            result |= ((byte & 0x7f) as usize) << shift;
+            if byte & 0x80 == 0 {
+                return result;
+            }
+            shift += 7;
+        }
+    }
+}
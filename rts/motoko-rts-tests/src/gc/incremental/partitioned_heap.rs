@@ -7,20 +7,25 @@ use std::{
 use motoko_rts::{
     gc::incremental::{
         IncrementalGC,
+        mark_bitmap::BITMAP_SIZE,
         partitioned_heap::{
-            PARTITION_SIZE, Partition, PartitionedHeap, PartitionedHeapIterator,
-            SURVIVAL_RATE_THRESHOLD,
+            PARTITION_SIZE, PARTITIONS_PER_TABLE, Partition, PartitionedHeap,
+            PartitionedHeapIterator, SURVIVAL_RATE_THRESHOLD, is_defragmentation_requested,
+            set_critical_memory,
         },
         set_incremental_gc_state,
         time::BoundedTime,
     },
     memory::{Memory, alloc_array, alloc_blob},
     types::{
-        Array, Blob, Bytes, Obj, TAG_ARRAY_I, TAG_ARRAY_M, TAG_ARRAY_S, TAG_ARRAY_T, TAG_BLOB_A,
-        TAG_BLOB_B, TAG_BLOB_P, TAG_BLOB_T, Tag, Value, Words,
+        Array, Blob, Bytes, FreeSpace, Obj, TAG_ARRAY_I, TAG_ARRAY_M, TAG_ARRAY_S, TAG_ARRAY_T,
+        TAG_BLOB_A, TAG_BLOB_B, TAG_BLOB_P, TAG_BLOB_T, TAG_FREE_SPACE, TAG_ONE_WORD_FILLER, Tag,
+        Value, Words,
     },
 };
 
+use motoko_rts_macros::uses_enhanced_orthogonal_persistence;
+
 use crate::{gc::utils::WORD_SIZE, memory::TestMemory};
 
 const OBJECT_SIZE: usize = size_of::<Array>() + WORD_SIZE;
@@ -31,6 +36,13 @@ pub unsafe fn test() {
     println!("  Testing partitioned heap...");
     test_normal_size_scenario();
     test_large_size_scenario();
+    test_defragmentation_scenario();
+    test_critical_defragmentation_scenario();
+    test_large_object_tail_scenario();
+    // The 32-bit memory cannot hold extension tables.
+    if uses_enhanced_orthogonal_persistence!() {
+        test_extension_table_scenario();
+    }
 }
 
 unsafe fn test_normal_size_scenario() {
@@ -38,6 +50,7 @@ unsafe fn test_normal_size_scenario() {
     let occupied_partitions = 2 + heap.heap_pointer() / PARTITION_SIZE;
     test_allocation_partitions(&heap.inner, occupied_partitions);
     test_iteration(&heap.inner, 1024);
+    test_mark_bitmap_reserve(&heap.inner, occupied_partitions);
     test_evacuation_plan(&mut heap, occupied_partitions);
     test_freeing_partitions(&mut heap, occupied_partitions);
     test_reallocations(&mut heap);
@@ -61,6 +74,13 @@ fn test_allocation_partitions(heap: &PartitionedHeap, number_of_partitions: usiz
     assert!(exists);
 }
 
+fn test_mark_bitmap_reserve(heap: &PartitionedHeap, occupied_partitions: usize) {
+    println!("    Test mark bitmap reserve...");
+    let reserve = heap.maximum_mark_bitmap_size();
+    assert_eq!(reserve % PARTITION_SIZE, 0);
+    assert!(reserve >= occupied_partitions * BITMAP_SIZE);
+}
+
 unsafe fn test_iteration(heap: &PartitionedHeap, break_step_size: usize) {
     println!("    Test heap iteration...");
     let mut iterator = PartitionedHeapIterator::new(heap);
@@ -304,6 +324,239 @@ unsafe fn test_allocation_sizes(sizes: &[usize], number_of_partitions: usize) {
     set_incremental_gc_state(None);
 }
 
+unsafe fn test_defragmentation_scenario() {
+    println!("    Test defragmentation...");
+    const LARGE: usize = PARTITION_SIZE + WORD_SIZE;
+    // Survival rate above the evacuation threshold if the blob is live.
+    const BLOB_SIZE: usize = PARTITION_SIZE / 8 * 7;
+    const NUMBER_OF_BLOBS: usize = 6;
+    let mut heap = PartitionedTestHeap::new(16 * PARTITION_SIZE);
+    let heap_base = heap.heap_base();
+    let state = IncrementalGC::<PartitionedTestHeap>::initial_gc_state(heap_base);
+    set_incremental_gc_state(Some(state));
+    let mut time = BoundedTime::new(0);
+    heap.inner.start_collection(&mut heap.memory, &mut time);
+
+    // Every blob occupies its own partition, only every second blob is live.
+    let mut live_blobs = vec![];
+    for index in 0..NUMBER_OF_BLOBS {
+        let blob = heap.allocate_blob(BLOB_SIZE - size_of::<Blob>());
+        if index % 2 == 0 {
+            live_blobs.push(blob.get_ptr() as *mut Obj);
+        }
+    }
+    let mark_live_blobs = |heap: &mut PartitionedTestHeap| {
+        for blob in live_blobs.iter() {
+            assert!(heap.inner.mark_object(*blob));
+        }
+    };
+    mark_live_blobs(&mut heap);
+    heap.inner.plan_evacuations(&mut heap.memory);
+    heap.inner.complete_collection();
+    heap.inner.start_collection(&mut heap.memory, &mut time);
+    assert!(!is_defragmentation_requested());
+
+    // The free partitions between the live blobs do not suffice for a contiguous huge object.
+    let huge_blob = heap.allocate_blob(LARGE - size_of::<Blob>());
+    assert!(is_defragmentation_requested());
+    let huge_partition = huge_blob.get_ptr() / PARTITION_SIZE;
+    assert!(huge_partition > *live_blobs.last().unwrap() as usize / PARTITION_SIZE);
+
+    mark_live_blobs(&mut heap);
+    heap.inner.plan_evacuations(&mut heap.memory);
+    assert!(!is_defragmentation_requested());
+    let reserved = heap.inner.reserved_for_defragmentation();
+    assert_eq!(reserved.len(), 2);
+    assert!(reserved.end <= huge_partition);
+    heap.inner.collect_large_objects();
+    heap.inner.complete_collection();
+    heap.inner.start_collection(&mut heap.memory, &mut time);
+    for index in reserved.clone() {
+        assert!(heap.inner.get_partition(index).is_completely_free());
+    }
+
+    // Normal allocations avoid the reserved partitions.
+    let normal_blob = heap.allocate_blob(BLOB_SIZE - size_of::<Blob>());
+    assert!(!reserved.contains(&(normal_blob.get_ptr() / PARTITION_SIZE)));
+    assert_eq!(heap.inner.reserved_for_defragmentation(), reserved);
+
+    let huge_blob = heap.allocate_blob(LARGE - size_of::<Blob>());
+    assert_eq!(huge_blob.get_ptr() / PARTITION_SIZE, reserved.start);
+    assert!(heap.inner.reserved_for_defragmentation().is_empty());
+    set_incremental_gc_state(None);
+}
+
+unsafe fn test_critical_defragmentation_scenario() {
+    println!("    Test critical defragmentation...");
+    const LARGE: usize = PARTITION_SIZE + WORD_SIZE;
+    const BLOB_SIZE: usize = PARTITION_SIZE / 8 * 7;
+    const NUMBER_OF_BLOBS: usize = 5;
+    // The memory cannot be extended beyond the partitions of the blobs and the mark bitmaps.
+    let mut heap = PartitionedTestHeap::new((NUMBER_OF_BLOBS + 2) * PARTITION_SIZE);
+    let heap_base = heap.heap_base();
+    let state = IncrementalGC::<PartitionedTestHeap>::initial_gc_state(heap_base);
+    set_incremental_gc_state(Some(state));
+    let mut time = BoundedTime::new(0);
+    heap.inner.start_collection(&mut heap.memory, &mut time);
+
+    let mut live_blobs = vec![];
+    for index in 0..NUMBER_OF_BLOBS {
+        let blob = heap.allocate_blob(BLOB_SIZE - size_of::<Blob>());
+        if index % 2 == 0 {
+            live_blobs.push(blob.get_ptr() as *mut Obj);
+        }
+    }
+    let mark_live_blobs = |heap: &mut PartitionedTestHeap| {
+        for blob in live_blobs.iter() {
+            assert!(heap.inner.mark_object(*blob));
+        }
+    };
+    mark_live_blobs(&mut heap);
+    heap.inner.plan_evacuations(&mut heap.memory);
+    heap.inner.complete_collection();
+    heap.inner.start_collection(&mut heap.memory, &mut time);
+    let last_partition = *live_blobs.last().unwrap() as usize / PARTITION_SIZE;
+    // Allocating the huge object beyond the used heap space would exceed the memory.
+    assert!((last_partition + 1) * PARTITION_SIZE + LARGE > heap.memory.heap_end());
+
+    // Without request, the GC defragments the scattered free partitions.
+    set_critical_memory(true);
+    mark_live_blobs(&mut heap);
+    heap.inner.plan_evacuations(&mut heap.memory);
+    let reserved = heap.inner.reserved_for_defragmentation();
+    assert_eq!(reserved.len(), 2);
+    assert!(reserved.end <= last_partition);
+    heap.inner.collect_large_objects();
+    heap.inner.complete_collection();
+    heap.inner.start_collection(&mut heap.memory, &mut time);
+    set_critical_memory(false);
+
+    let huge_blob = heap.allocate_blob(LARGE - size_of::<Blob>());
+    assert_eq!(huge_blob.get_ptr() / PARTITION_SIZE, reserved.start);
+    assert!(!is_defragmentation_requested());
+    set_incremental_gc_state(None);
+}
+
+unsafe fn test_large_object_tail_scenario() {
+    println!("    Test large object tail...");
+    const TAIL_SIZE: usize = PARTITION_SIZE / 2;
+    const HUGE_SIZE: usize = PARTITION_SIZE + TAIL_SIZE;
+    const SMALL_SIZE: usize = PARTITION_SIZE / 4;
+    let mut heap = PartitionedTestHeap::new(8 * PARTITION_SIZE);
+    let heap_base = heap.heap_base();
+    let state = IncrementalGC::<PartitionedTestHeap>::initial_gc_state(heap_base);
+    set_incremental_gc_state(Some(state));
+    let mut time = BoundedTime::new(0);
+    heap.inner.start_collection(&mut heap.memory, &mut time);
+
+    // The tail of a dead huge object is immediately freed if the last partition is unused.
+    let huge_blob = heap.allocate_blob(HUGE_SIZE - size_of::<Blob>());
+    let tail_index = huge_blob.get_ptr() / PARTITION_SIZE + 1;
+    let tail_partition = heap.inner.get_partition(tail_index);
+    assert!(tail_partition.is_free());
+    assert!(!tail_partition.has_large_content());
+    assert_eq!(
+        tail_partition.dynamic_space_start(),
+        huge_blob.get_ptr() + HUGE_SIZE
+    );
+    let reclaimed_before = heap.inner.reclaimed_size();
+    collect_garbage(&mut heap, &[]);
+    assert_eq!(
+        heap.inner.reclaimed_size() - reclaimed_before,
+        Bytes(HUGE_SIZE as u64)
+    );
+    assert!(heap.inner.get_partition(tail_index).is_completely_free());
+
+    // Normal allocations use the remainder of the last partition of a huge object.
+    let huge_blob = heap.allocate_blob(HUGE_SIZE - size_of::<Blob>());
+    assert_eq!(huge_blob.get_ptr() / PARTITION_SIZE + 1, tail_index);
+    let mut small_blob = heap.allocate_blob(SMALL_SIZE - size_of::<Blob>());
+    while small_blob.get_ptr() / PARTITION_SIZE != tail_index {
+        small_blob = heap.allocate_blob(SMALL_SIZE - size_of::<Blob>());
+    }
+    assert_eq!(small_blob.get_ptr(), huge_blob.get_ptr() + HUGE_SIZE);
+    let tail_partition = heap.inner.get_partition(tail_index);
+    assert!(!tail_partition.is_free());
+    assert!(heap.inner.is_allocation_partition(tail_index));
+
+    // The live huge object retains its tail.
+    let huge_object = huge_blob.get_ptr() as *mut Obj;
+    let small_object = small_blob.get_ptr() as *mut Obj;
+    collect_garbage(&mut heap, &[huge_object, small_object]);
+    assert!(heap.inner.get_partition(tail_index - 1).has_large_content());
+    assert_eq!(
+        heap.inner.get_partition(tail_index).dynamic_space_start(),
+        small_blob.get_ptr()
+    );
+
+    // The tail of a dead huge object becomes garbage in the dynamic space.
+    let dynamic_end = heap.inner.get_partition(tail_index).dynamic_space_end();
+    collect_garbage(&mut heap, &[small_object]);
+    assert!(
+        heap.inner
+            .get_partition(tail_index - 1)
+            .is_completely_free()
+    );
+    let tail_partition = heap.inner.get_partition(tail_index);
+    assert_eq!(
+        tail_partition.dynamic_space_start(),
+        tail_partition.start_address()
+    );
+    assert_eq!(tail_partition.dynamic_space_end(), dynamic_end);
+    assert_eq!(
+        occupied_space(tail_partition),
+        tail_partition.dynamic_size()
+    );
+    set_incremental_gc_state(None);
+}
+
+unsafe fn test_extension_table_scenario() {
+    println!("    Test extension tables...");
+    const EXTENSION_TABLES: usize = 3;
+    let last_partition = EXTENSION_TABLES * PARTITIONS_PER_TABLE + 1;
+    let mut heap = PartitionedTestHeap::new((last_partition + 1) * PARTITION_SIZE);
+    let heap_base = heap.heap_base();
+    let state = IncrementalGC::<PartitionedTestHeap>::initial_gc_state(heap_base);
+    set_incremental_gc_state(Some(state));
+
+    // Each blob fills an entire partition, such that the heap grows by extension tables.
+    let mut blob = heap.allocate_blob(PARTITION_SIZE - size_of::<Blob>());
+    while heap.inner.number_of_partitions() <= EXTENSION_TABLES * PARTITIONS_PER_TABLE {
+        blob = heap.allocate_blob(PARTITION_SIZE - size_of::<Blob>());
+    }
+    assert_eq!(
+        heap.inner.number_of_partitions(),
+        (EXTENSION_TABLES + 1) * PARTITIONS_PER_TABLE
+    );
+    assert_eq!(blob.get_ptr() / PARTITION_SIZE, last_partition);
+
+    // Extension tables are located at the start address of their first partition.
+    for table in 1..EXTENSION_TABLES + 1 {
+        let first_index = table * PARTITIONS_PER_TABLE;
+        let table_address = first_index * PARTITION_SIZE;
+        for index in first_index..first_index + PARTITIONS_PER_TABLE {
+            let partition = heap.inner.get_partition(index);
+            assert_eq!(partition.get_index(), index);
+            let entry_address = table_address + (index - first_index) * size_of::<Partition>();
+            assert_eq!(partition as *const Partition as usize, entry_address);
+        }
+        let first_partition = heap.inner.get_partition(first_index);
+        assert!(first_partition.dynamic_space_start() > table_address);
+    }
+    set_incremental_gc_state(None);
+}
+
+unsafe fn collect_garbage(heap: &mut PartitionedTestHeap, live_objects: &[*mut Obj]) {
+    for object in live_objects {
+        assert!(heap.inner.mark_object(*object));
+    }
+    heap.inner.plan_evacuations(&mut heap.memory);
+    heap.inner.collect_large_objects();
+    heap.inner.complete_collection();
+    let mut time = BoundedTime::new(0);
+    heap.inner.start_collection(&mut heap.memory, &mut time);
+}
+
 unsafe fn unmark_all_objects(heap: &mut PartitionedTestHeap) {
     heap.inner.complete_collection();
     let mut time = BoundedTime::new(0);
@@ -444,6 +697,8 @@ unsafe fn block_size(block: *const Tag) -> usize {
         TAG_BLOB_B | TAG_BLOB_T | TAG_BLOB_P | TAG_BLOB_A => {
             size_of::<Blob>() + (block as *const Blob).len().as_usize()
         }
+        TAG_FREE_SPACE => (block as *mut FreeSpace).size().to_bytes().as_usize(),
+        TAG_ONE_WORD_FILLER => WORD_SIZE,
         _ => unimplemented!(),
     }
 }
@@ -0,0 +1,109 @@
+use motoko_rts::gc::incremental::policy::{
+    BASIS_POINTS, DEFAULT_POLICY, MAXIMUM_GROWTH_THRESHOLD, SchedulingPolicy,
+    get_gc_scheduling_policy_field, get_scheduling_policy, reset_gc_scheduling_policy,
+    set_gc_scheduling_policy,
+};
+
+pub unsafe fn test() {
+    println!("  Testing scheduling policy...");
+
+    test_validation();
+    test_heap_limit();
+    test_adjustment();
+}
+
+fn test_validation() {
+    assert!(DEFAULT_POLICY.is_valid());
+
+    let zero = SchedulingPolicy {
+        critical_heap_limit: 0,
+        medium_heap_limit: 0,
+        critical_growth_threshold: 0,
+        medium_growth_threshold: 0,
+        low_growth_threshold: 0,
+        increment_base_limit: 0,
+        increment_allocation_factor: 0,
+    };
+    assert!(!zero.is_valid());
+
+    let extreme = SchedulingPolicy {
+        critical_heap_limit: BASIS_POINTS,
+        medium_heap_limit: 1,
+        critical_growth_threshold: 0,
+        medium_growth_threshold: MAXIMUM_GROWTH_THRESHOLD,
+        low_growth_threshold: MAXIMUM_GROWTH_THRESHOLD,
+        increment_base_limit: 1,
+        increment_allocation_factor: 0,
+    };
+    assert!(extreme.is_valid());
+
+    let invalid = [
+        SchedulingPolicy {
+            critical_heap_limit: BASIS_POINTS + 1,
+            ..DEFAULT_POLICY
+        },
+        SchedulingPolicy {
+            medium_heap_limit: 0,
+            ..DEFAULT_POLICY
+        },
+        SchedulingPolicy {
+            medium_heap_limit: DEFAULT_POLICY.critical_heap_limit + 1,
+            ..DEFAULT_POLICY
+        },
+        SchedulingPolicy {
+            critical_growth_threshold: DEFAULT_POLICY.medium_growth_threshold + 1,
+            ..DEFAULT_POLICY
+        },
+        SchedulingPolicy {
+            medium_growth_threshold: DEFAULT_POLICY.low_growth_threshold + 1,
+            ..DEFAULT_POLICY
+        },
+        SchedulingPolicy {
+            low_growth_threshold: MAXIMUM_GROWTH_THRESHOLD + 1,
+            ..DEFAULT_POLICY
+        },
+        SchedulingPolicy {
+            increment_base_limit: 0,
+            ..DEFAULT_POLICY
+        },
+    ];
+    for policy in invalid {
+        assert!(!policy.is_valid());
+    }
+}
+
+fn test_heap_limit() {
+    const GB: u64 = 1024 * 1024 * 1024;
+    assert_eq!(SchedulingPolicy::heap_limit(4 * GB, 5_625), 2 * GB + GB / 4);
+    assert_eq!(SchedulingPolicy::heap_limit(4 * GB, 2_500), GB);
+    assert_eq!(SchedulingPolicy::heap_limit(10_001, 5_000), 5_000);
+    assert_eq!(
+        SchedulingPolicy::heap_limit(u64::MAX, BASIS_POINTS),
+        u64::MAX
+    );
+    assert_eq!(SchedulingPolicy::heap_limit(u64::MAX, 0), 0);
+    assert_eq!(SchedulingPolicy::growth_ratio(3_500), 0.35);
+}
+
+unsafe fn test_adjustment() {
+    assert_eq!(*get_scheduling_policy(), DEFAULT_POLICY);
+    set_gc_scheduling_policy(9_000, 6_000, 50, 5_000, 20_000, 10_000_000, 100);
+    assert_eq!(
+        *get_scheduling_policy(),
+        SchedulingPolicy {
+            critical_heap_limit: 9_000,
+            medium_heap_limit: 6_000,
+            critical_growth_threshold: 50,
+            medium_growth_threshold: 5_000,
+            low_growth_threshold: 20_000,
+            increment_base_limit: 10_000_000,
+            increment_allocation_factor: 100,
+        }
+    );
+    let fields: Vec<usize> = (0..7)
+        .map(|index| get_gc_scheduling_policy_field(index))
+        .collect();
+    assert_eq!(fields, [9_000, 6_000, 50, 5_000, 20_000, 10_000_000, 100]);
+    reset_gc_scheduling_policy();
+    assert_eq!(*get_scheduling_policy(), DEFAULT_POLICY);
+}
@@ -0,0 +1,293 @@
+use motoko_rts::{
+    constants::WORD_SIZE,
+    gc::incremental::{
+        IncrementalGC,
+        barriers::{running_gc, write_with_barrier},
+        get_incremental_gc_state, get_partitioned_heap,
+        partitioned_heap::PARTITION_SIZE,
+        set_incremental_gc_state,
+        young_generation::{
+            collect_young_generation, get_gc_young_generation, is_nursery_active, is_young_address,
+            nursery_occupation, remembered_locations, schedule_young_collection,
+            set_gc_young_generation,
+        },
+    },
+    memory::{Memory, alloc_array, alloc_blob},
+    text::{text_concat, text_of_str},
+    text_iter::{text_iter, text_iter_done, text_iter_next},
+    types::{Bytes, TAG_ARRAY_M, TAG_BLOB_B, Value, Words},
+};
+
+use crate::memory::TestMemory;
+
+const HEAP_SIZE: usize = 8 * PARTITION_SIZE;
+const NURSERY_SIZE: usize = PARTITION_SIZE / 2;
+const BLOB_SIZE: usize = 64;
+const GARBAGE_SIZE: usize = 64 * 1024;
+
+pub unsafe fn test() {
+    println!("  Testing young generation...");
+
+    let mut memory = YoungTestMemory::new(HEAP_SIZE);
+    let state = IncrementalGC::<YoungTestMemory>::initial_gc_state(memory.heap_base());
+    set_incremental_gc_state(Some(state));
+
+    let old_array = allocate_array(&mut memory, 2);
+    let mut old_root = old_array;
+    let mut young_root = Value::from_scalar(0);
+    let mut unused_root = Value::from_scalar(0);
+    let roots = [
+        &mut old_root as *mut Value,
+        &mut young_root as *mut Value,
+        &mut unused_root as *mut Value,
+        &mut unused_root as *mut Value,
+        &mut unused_root as *mut Value,
+        &mut unused_root as *mut Value,
+    ];
+
+    assert_eq!(get_gc_young_generation(), 0);
+    schedule(&mut memory, roots);
+    assert!(!is_nursery_active());
+
+    set_gc_young_generation(NURSERY_SIZE);
+    schedule(&mut memory, roots);
+    assert!(is_nursery_active());
+    assert!(!is_young_address(old_array.get_ptr()));
+
+    test_promotion(&mut memory, old_array, roots);
+    test_compiled_store(&mut memory, old_array, roots);
+    test_root_update(&mut memory, &mut young_root, roots);
+    test_scheduling(&mut memory, roots);
+    test_huge_object_tail(&mut memory, roots);
+    test_text_iterator(&mut memory, &mut young_root, roots);
+
+    set_gc_young_generation(0);
+    schedule(&mut memory, roots);
+    assert!(!is_nursery_active());
+    assert!(!running_gc());
+    set_incremental_gc_state(None);
+}
+
+unsafe fn test_promotion(memory: &mut YoungTestMemory, old_array: Value, roots: [*mut Value; 6]) {
+    println!("    Test promotion...");
+    let young_blob = allocate_blob(memory, BLOB_SIZE, 1);
+    let young_array = allocate_array(memory, 2);
+    young_array.as_array().set(0, young_blob, memory);
+    allocate_blob(memory, GARBAGE_SIZE, 0);
+    assert!(is_young_address(young_array.get_ptr()));
+    assert_eq!(remembered_locations(), 0);
+
+    // Only old-to-young pointers are remembered.
+    old_array.as_array().set(0, young_array, memory);
+    assert_eq!(remembered_locations(), 1);
+    young_array.as_array().set(1, old_array, memory);
+    assert_eq!(remembered_locations(), 1);
+
+    let heap_size = get_partitioned_heap().occupied_size();
+    let reclaimed = get_partitioned_heap().reclaimed_size();
+    collect(memory, roots);
+
+    let promoted_array = old_array.as_array().get(0);
+    assert_ne!(promoted_array, young_array);
+    assert!(!is_young_address(promoted_array.get_ptr()));
+    assert_eq!(promoted_array.as_array().get(1), old_array);
+    let promoted_blob = promoted_array.as_array().get(0);
+    assert_ne!(promoted_blob, young_blob);
+    assert!(!is_young_address(promoted_blob.get_ptr()));
+    check_blob(promoted_blob, BLOB_SIZE, 1);
+
+    assert!(is_nursery_active());
+    assert_eq!(remembered_locations(), 0);
+    assert_eq!(nursery_occupation(get_partitioned_heap()), 0);
+    assert!(get_partitioned_heap().occupied_size() < heap_size);
+    assert!(get_partitioned_heap().reclaimed_size() - reclaimed >= Bytes(GARBAGE_SIZE as u64));
+}
+
+unsafe fn test_compiled_store(
+    memory: &mut YoungTestMemory,
+    old_array: Value,
+    roots: [*mut Value; 6],
+) {
+    println!("    Test compiled store...");
+    // The GC pauses during a nursery epoch, but the compiled stores must call the barrier.
+    assert!(is_nursery_active());
+    assert!(running_gc());
+    let young_blob = allocate_blob(memory, BLOB_SIZE, 3);
+    let location = old_array.as_array().payload_addr().add(1);
+    compiled_store(memory, location, young_blob);
+    assert_eq!(remembered_locations(), 1);
+
+    collect(memory, roots);
+    let promoted_blob = old_array.as_array().get(1);
+    assert_ne!(promoted_blob, young_blob);
+    assert!(!is_young_address(promoted_blob.get_ptr()));
+    check_blob(promoted_blob, BLOB_SIZE, 3);
+}
+
+/// Store as compiled by `Tagged.write_with_barrier`, with the fast path skipping the barrier.
+unsafe fn compiled_store(memory: &mut YoungTestMemory, location: *mut Value, value: Value) {
+    if running_gc() {
+        write_with_barrier(memory, location, value);
+    } else {
+        *location = value;
+    }
+}
+
+unsafe fn test_root_update(
+    memory: &mut YoungTestMemory,
+    young_root: &mut Value,
+    roots: [*mut Value; 6],
+) {
+    println!("    Test root update...");
+    let young_blob = allocate_blob(memory, BLOB_SIZE, 2);
+    *young_root = young_blob;
+    collect(memory, roots);
+    assert_ne!(*young_root, young_blob);
+    assert!(!is_young_address(young_root.get_ptr()));
+    check_blob(*young_root, BLOB_SIZE, 2);
+    *young_root = Value::from_scalar(0);
+}
+
+unsafe fn test_scheduling(memory: &mut YoungTestMemory, roots: [*mut Value; 6]) {
+    println!("    Test scheduling...");
+    let reclaimed = get_partitioned_heap().reclaimed_size();
+    schedule(memory, roots);
+    while nursery_occupation(get_partitioned_heap()) < NURSERY_SIZE {
+        allocate_blob(memory, GARBAGE_SIZE, 0);
+    }
+    schedule(memory, roots);
+    assert!(is_nursery_active());
+    assert_eq!(nursery_occupation(get_partitioned_heap()), 0);
+    assert!(get_partitioned_heap().reclaimed_size() - reclaimed >= Bytes(NURSERY_SIZE as u64));
+}
+
+unsafe fn test_huge_object_tail(memory: &mut YoungTestMemory, roots: [*mut Value; 6]) {
+    println!("    Test huge object tail...");
+    let length = (PARTITION_SIZE + PARTITION_SIZE / 2) / WORD_SIZE;
+    let huge_array = allocate_array(memory, length);
+    collect(memory, roots);
+    let tail_location = huge_array.as_array().payload_addr().add(length - 1) as usize;
+    let tail_index = tail_location / PARTITION_SIZE;
+    assert!(!is_young_address(tail_location));
+
+    // Fill the nursery until it continues in the remainder of the tail partition.
+    while get_partitioned_heap().allocation_partition_index() != tail_index {
+        allocate_blob(memory, GARBAGE_SIZE, 0);
+    }
+    let young_blob = allocate_blob(memory, BLOB_SIZE, 4);
+    assert_eq!(young_blob.get_ptr() / PARTITION_SIZE, tail_index);
+    assert!(is_young_address(young_blob.get_ptr()));
+    assert!(!is_young_address(tail_location));
+
+    // The old huge object holds the only pointer to the young blob.
+    huge_array.as_array().set(length - 1, young_blob, memory);
+    assert_eq!(remembered_locations(), 1);
+    collect(memory, roots);
+    let promoted_blob = huge_array.as_array().get(length - 1);
+    assert_ne!(promoted_blob, young_blob);
+    assert!(!is_young_address(promoted_blob.get_ptr()));
+    check_blob(promoted_blob, BLOB_SIZE, 4);
+}
+
+unsafe fn test_text_iterator(
+    memory: &mut YoungTestMemory,
+    young_root: &mut Value,
+    roots: [*mut Value; 6],
+) {
+    println!("    Test text iterator...");
+    // Long enough to not be merged into a single blob.
+    let pieces = [
+        "a".repeat(40),
+        "b".repeat(40),
+        "c".repeat(40),
+        "d".repeat(40),
+    ];
+    let first = text_of_str(memory, &pieces[0]);
+    let second = text_of_str(memory, &pieces[1]);
+    let third = text_of_str(memory, &pieces[2]);
+    let fourth = text_of_str(memory, &pieces[3]);
+    let inner = text_concat(memory, second, third);
+    let right = text_concat(memory, inner, fourth);
+    let text = text_concat(memory, first, right);
+    *young_root = text_iter(memory, text);
+    collect(memory, roots);
+    let iterator = *young_root;
+    assert!(!is_young_address(iterator.get_ptr()));
+
+    // Descending into the nested concatenation links a new young TODO entry in the old iterator.
+    let mut result = String::new();
+    for _ in 0..=pieces[0].len() {
+        result.push(char::from_u32(text_iter_next(memory, iterator)).unwrap());
+    }
+    assert_eq!(remembered_locations(), 1);
+    collect(memory, roots);
+    while text_iter_done(iterator) == 0 {
+        result.push(char::from_u32(text_iter_next(memory, iterator)).unwrap());
+    }
+    assert_eq!(result, pieces.concat());
+    *young_root = Value::from_scalar(0);
+}
+
+unsafe fn schedule(memory: &mut YoungTestMemory, roots: [*mut Value; 6]) {
+    schedule_young_collection(memory, get_incremental_gc_state(), roots);
+}
+
+unsafe fn collect(memory: &mut YoungTestMemory, roots: [*mut Value; 6]) {
+    collect_young_generation(memory, get_incremental_gc_state(), roots);
+}
+
+unsafe fn allocate_array(memory: &mut YoungTestMemory, length: usize) -> Value {
+    let array = alloc_array(memory, TAG_ARRAY_M, length);
+    for index in 0..length {
+        array
+            .as_array()
+            .initialize(index, Value::from_scalar(index), memory);
+    }
+    array
+}
+
+unsafe fn allocate_blob(memory: &mut YoungTestMemory, size: usize, content: u8) -> Value {
+    let blob = alloc_blob(memory, TAG_BLOB_B, Bytes(size));
+    let payload = blob.as_blob_mut().payload_addr();
+    for index in 0..size {
+        *payload.add(index) = content;
+    }
+    blob
+}
+
+unsafe fn check_blob(blob: Value, size: usize, content: u8) {
+    let blob = blob.as_blob();
+    assert_eq!(blob.len(), Bytes(size));
+    for index in 0..size {
+        assert_eq!(blob.get(index), content);
+    }
+}
+
+/// Allocates in the partitioned heap of the incremental GC state.
+struct YoungTestMemory {
+    memory: TestMemory,
+}
+
+impl YoungTestMemory {
+    fn new(size: usize) -> YoungTestMemory {
+        let memory = TestMemory::new(Bytes(size).to_words());
+        YoungTestMemory { memory }
+    }
+
+    fn heap_base(&self) -> usize {
+        self.memory.heap_base()
+    }
+}
+
+impl Memory for YoungTestMemory {
+    unsafe fn alloc_words(&mut self, size: Words<usize>) -> Value {
+        let result = get_partitioned_heap().allocate(&mut self.memory, size);
+        self.memory
+            .set_heap_pointer(result.get_ptr() + size.to_bytes().as_usize());
+        result
+    }
+
+    unsafe fn grow_memory(&mut self, ptr: usize) {
+        assert!(ptr <= self.memory.heap_end());
+    }
+}
@@ -0,0 +1,673 @@
+//! Candid decoder tests.
+//!
+//! Runs a regression corpus of DIDL messages and randomly generated messages through the
+//! header parser, the value skipper, the type table printer, and the sub-type and memory
+//! compatibility checks. See `motoko-rts/fuzz` for the corresponding fuzzing harness, which
+//! also covers malformed input.
+
+use crate::memory::{TestMemory, initialize_test_memory, reset_test_memory};
+
+use motoko_rts::bitrel::BitRel;
+use motoko_rts::buf::Buf;
+use motoko_rts::idl::{
+    TypeTable, idl_decoded_values, idl_peek_leb128, idl_peek_type, idl_set_value_limit,
+    idl_skipped_bytes, idl_start_message, idl_sub_buf_init, idl_sub_buf_words,
+    idl_subtype_cache_size, idl_vec_begin, idl_vec_next, idl_vec_skip, parse_idl_header, skip_any,
+    sub,
+};
+use motoko_rts::leb128::{leb128_decode, sleb128_decode};
+use motoko_rts_macros::{classical_persistence, enhanced_orthogonal_persistence};
+use oorandom::Rand32;
+use std::cell::Cell;
+
+thread_local! {
+    static VALUE_QUOTA: Cell<u64> = Cell::new(u64::MAX);
+}
+
+/// Reset the number of values that can be decoded before `idl_limit_check` traps.
+pub fn set_value_quota(quota: u64) {
+    VALUE_QUOTA.with(|current| current.set(quota));
+}
+
+pub fn value_quota() -> u64 {
+    VALUE_QUOTA.with(|quota| quota.get())
+}
+
+/// Decoding limit check, otherwise provided by the compiler-generated code.
+#[unsafe(no_mangle)]
+pub extern "C-unwind" fn idl_limit_check(decrement: bool, value_count: u64) {
+    VALUE_QUOTA.with(|quota| {
+        if quota.get() < value_count {
+            crate::trap("IDL error: exceeded value limit");
+        }
+        if decrement {
+            quota.set(quota.get() - value_count);
+        }
+    });
+}
+
+/// Known messages with the expected number of decoded values and, where given, the
+/// rendered type table.
+const CORPUS: &[(&str, u64, Option<&str>)] = &[
+    // no arguments
+    ("4449444c0000", 0, Some("")),
+    // null
+    ("4449444c00017f", 1, Some("")),
+    // nat, int, text
+    ("4449444c00037d7c712a7f03616263", 3, Some("")),
+    // nat8, nat16, nat32, float32
+    ("4449444c00047b7a7973ffffff010000000000803f", 4, Some("")),
+    // principal
+    ("4449444c000168010a00000000000000000101", 1, Some("")),
+    // record { 0 : int; 1 : opt nat }
+    (
+        "4449444c026c02007c01016e7d01000501ac02",
+        4,
+        Some("type T0 = record { 0 : int; 1 : T1 };\ntype T1 = opt nat;\n"),
+    ),
+    // variant { 0 : null; 1 : text }
+    (
+        "4449444c016b02007f0171010001026869",
+        2,
+        Some("type T0 = variant { 0 : null; 1 : text };\n"),
+    ),
+    // blob
+    (
+        "4449444c016d7b010003010203",
+        4,
+        Some("type T0 = vec nat8;\n"),
+    ),
+    // recursive list
+    (
+        "4449444c026e016c02007c010001000101010200",
+        7,
+        Some("type T0 = opt T1;\ntype T1 = record { 0 : int; 1 : T0 };\n"),
+    ),
+    // vec null with 1000 elements
+    (
+        "4449444c016d7f0100e807",
+        1001,
+        Some("type T0 = vec null;\n"),
+    ),
+    // oneway function reference
+    (
+        "4449444c016a00000102010001010a00000000000000000101066f6e65776179",
+        1,
+        Some("type T0 = func () -> () oneway;\n"),
+    ),
+    // service reference with a query method
+    (
+        "4449444c02690103666f6f016a000001010100010a00000000000000000101",
+        1,
+        Some("type T0 = service { \"foo\" : T1 };\ntype T1 = func () -> () query;\n"),
+    ),
+    // function with arguments and results
+    (
+        "4449444c016a027d71017e0103010001010a000000000000000001010166",
+        1,
+        Some("type T0 = func (nat, text) -> (bool) composite_query;\n"),
+    ),
+];
+
+const RANDOM_SEED: u64 = 4711;
+const RANDOM_MESSAGES: usize = 500;
+
+pub unsafe fn test() {
+    println!("Testing IDL ...");
+
+    let mut mem = initialize_test_memory();
+    test_corpus(&mut mem);
+    test_random_messages(&mut mem);
+    test_value_limit(&mut mem);
+    test_subtype_cache_size(&mut mem);
+    test_vec_reader(&mut mem);
+    #[cfg(not(target_family = "wasm"))]
+    test_vec_reader_traps(&mut mem);
+    reset_test_memory();
+    set_value_quota(u64::MAX);
+}
+
+unsafe fn test_corpus(mem: &mut TestMemory) {
+    println!("  Testing corpus ...");
+    for (hex, values, rendering) in CORPUS {
+        let bytes = decode_hex(hex);
+        set_value_quota(u64::MAX);
+        idl_start_message();
+        let message = check_message(mem, &bytes);
+        assert_eq!(u64::MAX - value_quota(), *values, "values of {hex}");
+        assert_eq!(idl_decoded_values(), *values, "decoded values of {hex}");
+        assert_eq!(
+            idl_skipped_bytes(),
+            message.value_size as u64,
+            "skipped bytes of {hex}"
+        );
+        if let Some(rendering) = rendering {
+            assert_eq!(message.render(), *rendering, "type table of {hex}");
+        }
+    }
+}
+
+unsafe fn test_random_messages(mem: &mut TestMemory) {
+    println!("  Testing random messages ...");
+    let mut random = Rand32::new(RANDOM_SEED);
+    for _ in 0..RANDOM_MESSAGES {
+        let bytes = random_message(&mut random);
+        let message = check_message(mem, &bytes);
+        assert_eq!(message.render().lines().count(), message.typtbl_size);
+    }
+}
+
+unsafe fn test_value_limit(mem: &mut TestMemory) {
+    println!("  Testing per-call value limit ...");
+    for (hex, values, _) in CORPUS {
+        let bytes = decode_hex(hex);
+        // The limit only applies to the next deserialization, where it can be met exactly,
+        // also after earlier deserializations of the same message.
+        idl_start_message();
+        check_message(mem, &bytes);
+        idl_set_value_limit(*values);
+        check_message(mem, &bytes);
+        assert_eq!(idl_decoded_values(), 2 * *values);
+        check_message(mem, &bytes);
+    }
+    idl_set_value_limit(0);
+    check_message(mem, &decode_hex("4449444c0000"));
+
+    // A pending limit does not carry over to the next message.
+    let (hex, values, _) = CORPUS[7];
+    idl_set_value_limit(0);
+    idl_start_message();
+    check_message(mem, &decode_hex(hex));
+    assert_eq!(idl_decoded_values(), values);
+
+    // The statistics accumulate over the deserializations of a message.
+    let skipped = idl_skipped_bytes();
+    check_message(mem, &decode_hex(hex));
+    assert_eq!(idl_decoded_values(), 2 * values);
+    assert_eq!(idl_skipped_bytes(), 2 * skipped);
+    idl_start_message();
+    assert_eq!(idl_decoded_values(), 0);
+    assert_eq!(idl_skipped_bytes(), 0);
+}
+
+unsafe fn test_subtype_cache_size(mem: &mut TestMemory) {
+    println!("  Testing sub-type cache size ...");
+    let bytes = decode_hex(CORPUS[8].0);
+    idl_start_message();
+    let message = check_message(mem, &bytes);
+    assert_eq!(idl_subtype_cache_size(), 0);
+    let size = message.typtbl_size;
+    let mut cache = vec![0usize; idl_sub_buf_words(size, 10)];
+    idl_sub_buf_init(cache.as_mut_ptr(), size, 10);
+    let expected = cache.len() * std::mem::size_of::<usize>();
+    assert_eq!(idl_subtype_cache_size(), expected as u64);
+    check_message(mem, &bytes);
+    idl_sub_buf_init(cache.as_mut_ptr(), size, 10);
+    assert_eq!(idl_subtype_cache_size(), 2 * expected as u64);
+}
+
+unsafe fn test_vec_reader(mem: &mut TestMemory) {
+    println!("  Testing vector reader ...");
+    const IDL_PRIM_nat: i32 = -3;
+    const IDL_CON_vec: i32 = -19;
+    // vec nat, [1, 2, 3]
+    let vec_nat = decode_hex("4449444c016d7d010003010203");
+
+    println!("    Testing begin and next ...");
+    set_value_quota(u64::MAX);
+    idl_start_message();
+    let (message, mut buf) = parse_message(mem, &vec_nat);
+    let (typtbl, t) = (message.typtbl, message.main_types[0]);
+    assert_eq!(idl_peek_type(&mut buf, typtbl, t), IDL_CON_vec);
+    assert_eq!(idl_peek_type(&mut buf, typtbl, IDL_PRIM_nat), IDL_PRIM_nat);
+    assert_eq!(idl_peek_leb128(&mut buf), 3);
+    let mut elem_type = 0;
+    let mut remaining = idl_vec_begin(&mut buf, typtbl, t, &mut elem_type);
+    assert_eq!((remaining, elem_type), (3, IDL_PRIM_nat));
+    // Only checks the quota for the elements.
+    assert_eq!(value_quota(), u64::MAX);
+    for expected in 1..=3 {
+        let element = idl_vec_next(&mut buf, typtbl, elem_type, &mut remaining);
+        let mut element_buf = Buf {
+            ptr: element,
+            end: buf.end,
+        };
+        assert_eq!(leb128_decode(&mut element_buf), expected);
+        assert_eq!(remaining, 3 - expected as u32);
+    }
+    assert!(idl_vec_next(&mut buf, typtbl, elem_type, &mut remaining).is_null());
+    let position = buf.ptr;
+    assert_eq!(position, message.end);
+    // The caller decodes the elements, which consumes the quota.
+    assert_eq!(value_quota(), u64::MAX);
+    assert_eq!(idl_decoded_values(), 0);
+    assert_eq!(idl_skipped_bytes(), 0);
+
+    println!("    Testing skip ...");
+    idl_start_message();
+    let (message, mut buf) = parse_message(mem, &vec_nat);
+    let (typtbl, t) = (message.typtbl, message.main_types[0]);
+    let mut remaining = idl_vec_begin(&mut buf, typtbl, t, &mut elem_type);
+    let quota = value_quota();
+    idl_vec_skip(&mut buf, typtbl, elem_type, &mut remaining, 2);
+    assert_eq!(remaining, 1);
+    assert_eq!(quota - value_quota(), 2);
+    assert_eq!(idl_decoded_values(), 2);
+    assert_eq!(idl_skipped_bytes(), 2);
+    assert_eq!(idl_peek_leb128(&mut buf), 3);
+    idl_vec_skip(&mut buf, typtbl, elem_type, &mut remaining, 0);
+    assert_eq!(remaining, 1);
+
+    println!("    Testing quota ...");
+    let (message, mut buf) = parse_message(mem, &vec_nat);
+    let (typtbl, t) = (message.typtbl, message.main_types[0]);
+    set_value_quota(3);
+    assert_eq!(idl_vec_begin(&mut buf, typtbl, t, &mut elem_type), 3);
+    assert_eq!(value_quota(), 3);
+    // Stepping over an element checks the quota for its values.
+    let (message, mut buf) = parse_message(mem, &decode_hex(VEC_RECORD));
+    let (typtbl, t) = (message.typtbl, message.main_types[0]);
+    set_value_quota(4);
+    let mut remaining = idl_vec_begin(&mut buf, typtbl, t, &mut elem_type);
+    assert!(!idl_vec_next(&mut buf, typtbl, elem_type, &mut remaining).is_null());
+    assert_eq!(value_quota(), 4);
+    set_value_quota(u64::MAX);
+}
+
+/// vec record { 0 : nat; 1 : nat; 2 : nat }, [{1; 2; 3}]
+const VEC_RECORD: &str = "4449444c026d016c03007d017d027d010001010203";
+
+#[cfg(not(target_family = "wasm"))]
+unsafe fn test_vec_reader_traps(mem: &mut TestMemory) {
+    use crate::traps;
+
+    println!("  Testing vector reader traps ...");
+    const IDL_PRIM_nat: i32 = -3;
+    let vec_nat = decode_hex("4449444c016d7d010003010203");
+    let mut elem_type = 0;
+
+    // The element count exceeds the quota.
+    let (message, mut buf) = parse_message(mem, &vec_nat);
+    let (typtbl, t) = (message.typtbl, message.main_types[0]);
+    set_value_quota(2);
+    assert!(traps(|| idl_vec_begin(&mut buf, typtbl, t, &mut elem_type)));
+    set_value_quota(u64::MAX);
+
+    // The element count exceeds the per-call limit.
+    idl_set_value_limit(2);
+    let (message, mut buf) = parse_message(mem, &vec_nat);
+    let (typtbl, t) = (message.typtbl, message.main_types[0]);
+    assert!(traps(|| idl_vec_begin(&mut buf, typtbl, t, &mut elem_type)));
+
+    // Skipping past the end of the vector.
+    let (message, mut buf) = parse_message(mem, &vec_nat);
+    let (typtbl, t) = (message.typtbl, message.main_types[0]);
+    let mut remaining = idl_vec_begin(&mut buf, typtbl, t, &mut elem_type);
+    assert!(traps(|| idl_vec_skip(
+        &mut buf,
+        typtbl,
+        elem_type,
+        &mut remaining,
+        4
+    )));
+    assert_eq!(remaining, 3);
+    idl_vec_skip(&mut buf, typtbl, elem_type, &mut remaining, 3);
+    assert!(traps(|| idl_vec_skip(
+        &mut buf,
+        typtbl,
+        elem_type,
+        &mut remaining,
+        1
+    )));
+
+    // The values of the next element exceed the quota.
+    let (message, mut buf) = parse_message(mem, &decode_hex(VEC_RECORD));
+    let (typtbl, t) = (message.typtbl, message.main_types[0]);
+    set_value_quota(3);
+    let mut remaining = idl_vec_begin(&mut buf, typtbl, t, &mut elem_type);
+    assert!(traps(|| idl_vec_next(
+        &mut buf,
+        typtbl,
+        elem_type,
+        &mut remaining
+    )));
+    assert_eq!(value_quota(), 3);
+    set_value_quota(u64::MAX);
+    // Restore the state after the trap, otherwise rolled back on the IC.
+    let (message, mut buf) = parse_message(mem, &decode_hex(VEC_RECORD));
+    let (typtbl, t) = (message.typtbl, message.main_types[0]);
+    let mut remaining = idl_vec_begin(&mut buf, typtbl, t, &mut elem_type);
+    idl_vec_next(&mut buf, typtbl, elem_type, &mut remaining);
+
+    // Not a vector type, neither primitive nor in the type table.
+    let (message, mut buf) = parse_message(mem, &decode_hex("4449444c00017d2a"));
+    let typtbl = message.typtbl;
+    assert!(traps(|| idl_vec_begin(
+        &mut buf,
+        typtbl,
+        IDL_PRIM_nat,
+        &mut elem_type
+    )));
+    // record { 0 : int; 1 : opt nat }
+    let (message, mut buf) = parse_message(mem, &decode_hex(CORPUS[5].0));
+    let (typtbl, t) = (message.typtbl, message.main_types[0]);
+    assert!(traps(|| idl_vec_begin(&mut buf, typtbl, t, &mut elem_type)));
+}
+
+/// A message after parsing the header.
+struct Message {
+    typtbl: *mut *mut u8,
+    typtbl_size: usize,
+    main_types: Vec<i32>,
+    value_size: usize,
+    end: *mut u8,
+}
+
+impl Message {
+    fn render(&self) -> String {
+        TypeTable::new(self.typtbl, self.typtbl_size, self.end).to_string()
+    }
+}
+
+/// Parse the message header, returning the buffer at the argument values.
+unsafe fn parse_message(mem: &mut TestMemory, bytes: &[u8]) -> (Message, Buf) {
+    let start = bytes.as_ptr() as *mut u8;
+    let end = start.add(bytes.len());
+    let mut buf = Buf { ptr: start, end };
+    let mut typtbl = core::ptr::null_mut();
+    let mut typtbl_size = 0;
+    let mut main_types = core::ptr::null_mut();
+    parse_idl_header(
+        mem,
+        false,
+        &mut buf,
+        bytes.len(),
+        &mut typtbl,
+        &mut typtbl_size,
+        &mut main_types,
+    );
+    let value_size = end.offset_from(buf.ptr) as usize;
+
+    let mut main_types_buf = Buf {
+        ptr: main_types,
+        end,
+    };
+    let count = leb128_decode(&mut main_types_buf);
+    let main_types = (0..count)
+        .map(|_| sleb128_decode(&mut main_types_buf) as i32)
+        .collect::<Vec<_>>();
+
+    let message = Message {
+        typtbl,
+        typtbl_size,
+        main_types,
+        value_size,
+        end,
+    };
+    (message, buf)
+}
+
+/// Parse the message, skip all argument values and check the sub-type and memory
+/// compatibility relations for reflexivity.
+unsafe fn check_message(mem: &mut TestMemory, bytes: &[u8]) -> Message {
+    let (message, mut buf) = parse_message(mem, bytes);
+    for &t in &message.main_types {
+        skip_any(&mut buf, message.typtbl, t, 0);
+    }
+    let position = buf.ptr;
+    assert_eq!(position, message.end, "undecoded data");
+
+    check_reflexivity(&message);
+    message
+}
+
+unsafe fn check_reflexivity(message: &Message) {
+    let size = message.typtbl_size;
+    let types = (0..size as i32).chain(message.main_types.iter().copied());
+    let mut cache = vec![0usize; BitRel::words(size, size)];
+    let rel = new_cache(&mut cache, size);
+    for t in types.clone() {
+        let (typtbl, end) = (message.typtbl, message.end);
+        assert!(sub(&rel, true, typtbl, typtbl, end, end, t, t));
+    }
+    for t in types {
+        check_memory_compatibility(message, t);
+    }
+}
+
+#[enhanced_orthogonal_persistence]
+unsafe fn check_memory_compatibility(message: &Message, t: i32) {
+    use motoko_rts::idl::{IncompatibilityTrace, TypeVariance, memory_compatible};
+
+    let size = message.typtbl_size;
+    let mut cache = vec![0usize; BitRel::words(size, size)];
+    let rel = new_cache(&mut cache, size);
+    let mut trace = IncompatibilityTrace::new();
+    let (typtbl, end) = (message.typtbl, message.end);
+    let variance = TypeVariance::Covariance;
+    assert!(memory_compatible(
+        &rel, variance, typtbl, typtbl, end, end, t, t, false, &mut trace
+    ));
+}
+
+#[classical_persistence]
+unsafe fn check_memory_compatibility(_message: &Message, _t: i32) {}
+
+unsafe fn new_cache(cache: &mut [usize], size: usize) -> BitRel {
+    let range = cache.as_mut_ptr_range();
+    let rel = BitRel {
+        ptr: range.start,
+        end: range.end,
+        size1: size,
+        size2: size,
+    };
+    rel.init();
+    rel
+}
+
+fn decode_hex(hex: &str) -> Vec<u8> {
+    (0..hex.len() / 2)
+        .map(|index| u8::from_str_radix(&hex[2 * index..2 * index + 2], 16).unwrap())
+        .collect()
+}
+
+//
+// Random messages
+//
+// The type tables are well-formed and inhabited: record and variant fields only refer to
+// later type table entries, while recursion through `opt` and `vec` ends at a maximum depth.
+//
+
+const PRIMITIVE_TYPES: &[i32] = &[
+    -1, -2, -3, -4, -5, -6, -7, -8, -9, -10, -11, -12, -13, -14, -15, -16, -24,
+];
+const MAX_TYPES: u32 = 8;
+const MAX_FIELDS: u32 = 4;
+const MAX_DEPTH: u32 = 5;
+
+enum RandomType {
+    Opt(i32),
+    Vec(i32),
+    Record(Vec<(u32, i32)>),
+    Variant(Vec<(u32, i32)>),
+}
+
+fn random_message(random: &mut Rand32) -> Vec<u8> {
+    let count = 1 + random.rand_range(0..MAX_TYPES);
+    let table = (0..count)
+        .map(|index| random_type(random, index, count))
+        .collect::<Vec<_>>();
+
+    let mut bytes = b"DIDL".to_vec();
+    write_leb128(&mut bytes, count as u64);
+    for entry in &table {
+        write_type(&mut bytes, entry);
+    }
+    let main_types = (0..1 + random.rand_range(0..4))
+        .map(|_| random_reference(random, 0, count))
+        .collect::<Vec<_>>();
+    write_leb128(&mut bytes, main_types.len() as u64);
+    for &t in &main_types {
+        write_sleb128(&mut bytes, t as i64);
+    }
+    for &t in &main_types {
+        write_value(random, &table, t, 0, &mut bytes);
+    }
+    bytes
+}
+
+fn random_type(random: &mut Rand32, index: u32, count: u32) -> RandomType {
+    match random.rand_range(0..4) {
+        0 => RandomType::Opt(random_reference(random, 0, count)),
+        1 => RandomType::Vec(random_reference(random, 0, count)),
+        2 => RandomType::Record(random_fields(random, 0, index + 1, count)),
+        _ => RandomType::Variant(random_fields(random, 1, index + 1, count)),
+    }
+}
+
+/// Fields with ascending tags, referring to primitive types or type table entries from `first`.
+fn random_fields(random: &mut Rand32, minimum: u32, first: u32, count: u32) -> Vec<(u32, i32)> {
+    let mut tag = 0;
+    (0..minimum + random.rand_range(0..MAX_FIELDS))
+        .map(|_| {
+            tag += random.rand_range(0..1000);
+            let field = (tag, random_reference(random, first, count));
+            tag += 1;
+            field
+        })
+        .collect()
+}
+
+fn random_reference(random: &mut Rand32, first: u32, count: u32) -> i32 {
+    if first < count && random.rand_range(0..2) == 0 {
+        random.rand_range(first..count) as i32
+    } else {
+        PRIMITIVE_TYPES[random.rand_range(0..PRIMITIVE_TYPES.len() as u32) as usize]
+    }
+}
+
+fn write_type(bytes: &mut Vec<u8>, entry: &RandomType) {
+    match entry {
+        RandomType::Opt(t) => {
+            write_sleb128(bytes, -18);
+            write_sleb128(bytes, *t as i64);
+        }
+        RandomType::Vec(t) => {
+            write_sleb128(bytes, -19);
+            write_sleb128(bytes, *t as i64);
+        }
+        RandomType::Record(fields) | RandomType::Variant(fields) => {
+            let opcode = if let RandomType::Record(_) = entry {
+                -20
+            } else {
+                -21
+            };
+            write_sleb128(bytes, opcode);
+            write_leb128(bytes, fields.len() as u64);
+            for &(tag, t) in fields {
+                write_leb128(bytes, tag as u64);
+                write_sleb128(bytes, t as i64);
+            }
+        }
+    }
+}
+
+fn write_value(random: &mut Rand32, table: &[RandomType], t: i32, depth: u32, bytes: &mut Vec<u8>) {
+    let size = match t {
+        -1 | -16 => 0,
+        -2 => {
+            bytes.push(random.rand_range(0..2) as u8);
+            return;
+        }
+        -3 => {
+            write_leb128(bytes, random.rand_u32() as u64);
+            return;
+        }
+        -4 => {
+            write_sleb128(bytes, random.rand_i32() as i64);
+            return;
+        }
+        -5 | -9 => 1,
+        -6 | -10 => 2,
+        -7 | -11 | -13 => 4,
+        -8 | -12 | -14 => 8,
+        -15 => {
+            let length = random.rand_range(0..8);
+            write_leb128(bytes, length as u64);
+            (0..length).for_each(|_| bytes.push(b'a' + random.rand_range(0..26) as u8));
+            return;
+        }
+        -24 => {
+            bytes.push(1);
+            let length = random.rand_range(0..30);
+            write_leb128(bytes, length as u64);
+            length
+        }
+        _ => {
+            write_constructed_value(random, table, t, depth, bytes);
+            return;
+        }
+    };
+    (0..size).for_each(|_| bytes.push(random.rand_u32() as u8));
+}
+
+fn write_constructed_value(
+    random: &mut Rand32,
+    table: &[RandomType],
+    t: i32,
+    depth: u32,
+    bytes: &mut Vec<u8>,
+) {
+    let at_limit = depth >= MAX_DEPTH;
+    match &table[t as usize] {
+        RandomType::Opt(t) => {
+            if at_limit || random.rand_range(0..2) == 0 {
+                bytes.push(0);
+            } else {
+                bytes.push(1);
+                write_value(random, table, *t, depth + 1, bytes);
+            }
+        }
+        RandomType::Vec(t) => {
+            let length = if at_limit { 0 } else { random.rand_range(0..4) };
+            write_leb128(bytes, length as u64);
+            for _ in 0..length {
+                write_value(random, table, *t, depth + 1, bytes);
+            }
+        }
+        RandomType::Record(fields) => {
+            for &(_, t) in fields {
+                write_value(random, table, t, depth + 1, bytes);
+            }
+        }
+        RandomType::Variant(fields) => {
+            let index = random.rand_range(0..fields.len() as u32);
+            write_leb128(bytes, index as u64);
+            write_value(random, table, fields[index as usize].1, depth + 1, bytes);
+        }
+    }
+}
+
+fn write_leb128(bytes: &mut Vec<u8>, mut value: u64) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            bytes.push(byte);
+            return;
+        }
+        bytes.push(byte | 0x80);
+    }
+}
+
+fn write_sleb128(bytes: &mut Vec<u8>, mut value: i64) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if (value == 0 && byte & 0x40 == 0) || (value == -1 && byte & 0x40 != 0) {
+            bytes.push(byte);
+            return;
+        }
+        bytes.push(byte | 0x80);
+    }
+}
@@ -13,15 +13,20 @@ mod bigint;
 mod bitrel;
 mod continuation_table;
 mod crc32;
+mod float;
 mod gc;
+mod idl;
 mod leb128;
 mod memory;
 mod principal_id;
+mod region;
 
 #[enhanced_orthogonal_persistence]
 mod stabilization;
+mod stable_mem;
 mod stable_option;
 mod text;
+mod unicode;
 mod utf8;
 
 fn main() {
@@ -32,12 +37,17 @@ fn main() {
         bitrel::test();
         continuation_table::test();
         crc32::test();
+        float::test();
         gc::test();
+        idl::test();
         leb128::test();
         principal_id::test();
         persistence_test();
+        stable_mem::test();
+        region::test();
         stable_option::test();
         text::test();
+        unicode::test();
         utf8::test();
     }
 }
@@ -74,6 +84,13 @@ pub extern "C" fn test_crc32() {
     }
 }
 #[unsafe(no_mangle)]
+pub extern "C" fn test_float() {
+    check_architecture();
+    unsafe {
+        float::test();
+    }
+}
+#[unsafe(no_mangle)]
 pub extern "C" fn test_gc() {
     check_architecture();
     gc::test();
@@ -139,6 +156,13 @@ pub extern "C" fn test_gc_chunk_9() {
     gc::test_random_range(9 * gc::SEEDS_PER_CHUNK, 10 * gc::SEEDS_PER_CHUNK);
 }
 #[unsafe(no_mangle)]
+pub extern "C" fn test_idl() {
+    check_architecture();
+    unsafe {
+        idl::test();
+    }
+}
+#[unsafe(no_mangle)]
 pub extern "C" fn test_leb128() {
     check_architecture();
     unsafe {
@@ -168,6 +192,18 @@ pub extern "C" fn test_persistence_20k() {
     persistence_20k_test();
 }
 #[unsafe(no_mangle)]
+pub extern "C" fn test_stable_mem() {
+    check_architecture();
+    stable_mem::test();
+}
+#[unsafe(no_mangle)]
+pub extern "C" fn test_region() {
+    check_architecture();
+    unsafe {
+        region::test();
+    }
+}
+#[unsafe(no_mangle)]
 pub extern "C" fn test_stable_option() {
     check_architecture();
     stable_option::test();
@@ -180,6 +216,13 @@ pub extern "C" fn test_text() {
     }
 }
 #[unsafe(no_mangle)]
+pub extern "C" fn test_unicode() {
+    check_architecture();
+    unsafe {
+        unicode::test();
+    }
+}
+#[unsafe(no_mangle)]
 pub extern "C" fn test_utf8() {
     check_architecture();
     unsafe {
@@ -248,15 +291,36 @@ fn test_read_write_64_bit() {
 
 // Called by the RTS to panic
 #[unsafe(no_mangle)]
-extern "C" fn rts_trap(ptr: *const u8, len: u32) -> ! {
+extern "C-unwind" fn rts_trap(ptr: *const u8, len: u32) -> ! {
     let msg = unsafe { std::slice::from_raw_parts(ptr, len as usize) };
     match core::str::from_utf8(msg) {
         Err(err) => panic!(
             "rts_trap_with called with non-UTF8 string (error={:?}, string={:?})",
             err, msg
         ),
-        Ok(str) => panic!("rts_trap_with: {:?}", str),
+        Ok(str) => trap(str),
+    }
+}
+
+thread_local! {
+    static RECOVER_TRAPS: std::cell::Cell<bool> = const { std::cell::Cell::new(false) };
+}
+
+/// Whether `f` traps. Only native builds can recover from traps, by unwinding.
+#[cfg(not(target_family = "wasm"))]
+pub fn traps<R>(f: impl FnOnce() -> R) -> bool {
+    RECOVER_TRAPS.with(|recover| recover.set(true));
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(f));
+    RECOVER_TRAPS.with(|recover| recover.set(false));
+    result.is_err()
+}
+
+pub fn trap(msg: &str) -> ! {
+    #[cfg(not(target_family = "wasm"))]
+    if RECOVER_TRAPS.with(|recover| recover.get()) {
+        std::panic::resume_unwind(Box::new(msg.to_string()));
     }
+    panic!("rts_trap_with: {:?}", msg)
 }
 
 // Called by RTS BigInt functions to panic. Normally generated by the compiler
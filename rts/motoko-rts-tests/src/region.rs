@@ -0,0 +1,575 @@
+//! Region manager tests, running on the simulated stable memory.
+
+use crate::memory::{initialize_test_memory, reset_test_memory};
+use crate::stable_mem::clear_stable_memory;
+
+use motoko_rts::memory::Memory;
+use motoko_rts::principal_id::update_crc32;
+use motoko_rts::region::meta_data::{
+    block_region_table, free_blocks, header_checksum, offset, size::BLOCK_IN_BYTES,
+    size::PAGE_IN_BYTES, size::PAGES_IN_BLOCK, total_allocated_blocks, total_allocated_regions,
+    version,
+};
+use motoko_rts::region::{
+    BlockId, RegionId, region_checksum, region_copy, region_fill, region_free, region_grow,
+    region_id, region_init, region_load_word8, region_load_word64, region_meta_data_checksum,
+    region_new, region_recover, region_shrink, region_size, region_store_word8,
+    region_store_word64, region_vec_pages, region0_get,
+};
+use motoko_rts::stable_mem::{self, read, read_u16, read_u32, read_u64, write_u32, write_u64};
+use motoko_rts::types::Value;
+use motoko_rts_macros::{classical_persistence, enhanced_orthogonal_persistence};
+
+/// Region ids below are reserved for region 0 and future use.
+const FIRST_USER_REGION: u64 = 16;
+
+pub unsafe fn test() {
+    println!("Testing regions ...");
+
+    test_migration_from_no_stable_memory();
+    test_migration_on_first_region();
+    test_migration_from_some_stable_memory();
+    test_region_free();
+    test_region_shrink();
+    test_multi_block_reuse();
+    test_region_copy_and_fill();
+    test_checksums();
+
+    clear_stable_memory();
+}
+
+unsafe fn test_migration_from_no_stable_memory() {
+    println!("  Testing migration from no stable memory ...");
+    clear_stable_memory();
+    stable_mem::set_version(no_stable_memory_version());
+    let mut mem = initialize_test_memory();
+
+    region_init(&mut mem, 1);
+    check_header(offset::BASE_LOW);
+    assert_eq!(stable_mem::get_version(), regions_version());
+    assert_eq!(total_allocated_regions::get(), FIRST_USER_REGION);
+    assert_eq!(total_allocated_blocks::get(), 0);
+    let region0 = region0_get(&mut mem);
+    assert_eq!(region_id(&mut mem, region0), 0);
+    assert_eq!(region_size(&mut mem, region0), 0);
+
+    let r1 = region_new(&mut mem);
+    let r2 = region_new(&mut mem);
+    assert_eq!(region_id(&mut mem, r1), FIRST_USER_REGION);
+    assert_eq!(region_id(&mut mem, r2), FIRST_USER_REGION + 1);
+    assert_eq!(total_allocated_regions::get(), FIRST_USER_REGION + 2);
+
+    println!("    Testing region grow ...");
+    let pages = PAGES_IN_BLOCK as u64;
+    assert_eq!(region_grow(&mut mem, r1, pages + 2), 0);
+    assert_eq!(region_grow(&mut mem, r2, 1), 0);
+    assert_eq!(region_grow(&mut mem, r1, 0), pages + 2);
+    assert_eq!(region_grow(&mut mem, r1, pages - 2 + 3), pages + 2);
+    assert_eq!(region_size(&mut mem, r1), 2 * pages + 3);
+    assert_eq!(region_size(&mut mem, r2), 1);
+    assert_eq!(region_grow(&mut mem, region0, 1), 0);
+
+    println!("    Testing block region table ...");
+    let id1 = RegionId(FIRST_USER_REGION);
+    let id2 = RegionId(FIRST_USER_REGION + 1);
+    let full = PAGES_IN_BLOCK as u8;
+    assert_eq!(total_allocated_blocks::get(), 5);
+    check_block(0, Some((id1.clone(), 0, full)));
+    check_block(1, Some((id1.clone(), 1, full)));
+    check_block(2, Some((id2.clone(), 0, 1)));
+    check_block(3, Some((id1.clone(), 2, 3)));
+    check_block(4, Some((RegionId(0), 0, 1)));
+    check_block(5, None);
+    check_access_vector(&mut mem, r1, &[0, 1, 3]);
+    check_access_vector(&mut mem, r2, &[2]);
+    assert!(stable_mem::size() * PAGE_IN_BYTES >= offset::BASE_LOW + 5 * BLOCK_IN_BYTES);
+
+    println!("    Testing access across blocks ...");
+    // The value straddles the non-adjacent blocks 1 and 3.
+    let straddling = 2 * BLOCK_IN_BYTES - 4;
+    region_store_word64(&mut mem, r1, straddling, 0x0123_4567_89ab_cdef);
+    assert_eq!(
+        region_load_word64(&mut mem, r1, straddling),
+        0x0123_4567_89ab_cdef
+    );
+    assert_eq!(
+        read_u32(offset::BASE_LOW + 2 * BLOCK_IN_BYTES - 4),
+        0x89ab_cdef
+    );
+    assert_eq!(read_u32(offset::BASE_LOW + 3 * BLOCK_IN_BYTES), 0x0123_4567);
+    region_store_word8(&mut mem, r2, PAGE_IN_BYTES - 1, 0xab);
+    assert_eq!(region_load_word8(&mut mem, r2, PAGE_IN_BYTES - 1), 0xab);
+    assert_eq!(region_load_word8(&mut mem, r1, 0), 0);
+    region_store_word8(&mut mem, region0, 7, 0xcd);
+
+    println!("    Testing region recovery ...");
+    let recovered = region_recover(&mut mem, &id1);
+    assert_eq!(region_id(&mut mem, recovered), FIRST_USER_REGION);
+    assert_eq!(region_size(&mut mem, recovered), 2 * pages + 3);
+    check_access_vector(&mut mem, recovered, &[0, 1, 3]);
+    assert_eq!(
+        region_load_word64(&mut mem, recovered, straddling),
+        0x0123_4567_89ab_cdef
+    );
+
+    println!("    Testing upgrade with existing regions ...");
+    reset_test_memory();
+    let mut mem = initialize_test_memory();
+    region_init(&mut mem, 1);
+    check_header(offset::BASE_LOW);
+    assert_eq!(stable_mem::get_version(), regions_version());
+    assert_eq!(total_allocated_regions::get(), FIRST_USER_REGION + 2);
+    let region0 = region0_get(&mut mem);
+    assert_eq!(region_id(&mut mem, region0), 0);
+    assert_eq!(region_size(&mut mem, region0), 1);
+    check_access_vector(&mut mem, region0, &[4]);
+    assert_eq!(region_load_word8(&mut mem, region0, 7), 0xcd);
+    let r3 = region_new(&mut mem);
+    assert_eq!(region_id(&mut mem, r3), FIRST_USER_REGION + 2);
+    assert_eq!(region_grow(&mut mem, r3, 1), 0);
+    check_block(5, Some((RegionId(FIRST_USER_REGION + 2), 0, 1)));
+
+    reset_test_memory();
+}
+
+unsafe fn test_migration_on_first_region() {
+    println!("  Testing migration on first region allocation ...");
+    clear_stable_memory();
+    stable_mem::set_version(no_stable_memory_version());
+    let mut mem = initialize_test_memory();
+
+    // Regions are not used by the program yet.
+    region_init(&mut mem, 0);
+    assert_eq!(stable_mem::size(), 0);
+    assert_eq!(stable_mem::get_version(), no_stable_memory_version());
+
+    let r = region_new(&mut mem);
+    check_header(offset::BASE_LOW);
+    assert_eq!(stable_mem::get_version(), regions_version());
+    assert_eq!(region_id(&mut mem, r), FIRST_USER_REGION);
+    let region0 = region0_get(&mut mem);
+    assert_eq!(region_id(&mut mem, region0), 0);
+
+    reset_test_memory();
+}
+
+unsafe fn test_migration_from_some_stable_memory() {
+    println!("  Testing migration from some stable memory ...");
+    clear_stable_memory();
+    stable_mem::set_version(some_stable_memory_version());
+    let mut mem = initialize_test_memory();
+
+    // Legacy stable memory of one full and one partial block.
+    let pages = PAGES_IN_BLOCK as u64 + 2;
+    assert_eq!(stable_mem::grow(pages), 0);
+    let samples = [
+        0,
+        PAGE_IN_BYTES - 8,
+        BLOCK_IN_BYTES - 4,
+        BLOCK_IN_BYTES + 8,
+        pages * PAGE_IN_BYTES - 8,
+    ];
+    for (index, address) in samples.iter().enumerate() {
+        write_u64(*address, sample_value(index));
+    }
+
+    region_init(&mut mem, 1);
+    check_header(offset::BASE_HIGH);
+    assert_eq!(stable_mem::get_version(), regions_version());
+    assert_eq!(total_allocated_regions::get(), FIRST_USER_REGION);
+    assert_eq!(total_allocated_blocks::get(), 2);
+
+    // The head block is relocated to the end, behind the remaining blocks.
+    check_block(0, Some((RegionId(0), 1, 2)));
+    check_block(1, Some((RegionId(0), 0, PAGES_IN_BLOCK as u8)));
+    let region0 = region0_get(&mut mem);
+    assert_eq!(region_id(&mut mem, region0), 0);
+    assert_eq!(region_size(&mut mem, region0), pages);
+    check_access_vector(&mut mem, region0, &[1, 0]);
+    for (index, address) in samples.iter().enumerate() {
+        assert_eq!(
+            region_load_word64(&mut mem, region0, *address),
+            sample_value(index)
+        );
+    }
+    assert_eq!(
+        read_u64(offset::BASE_HIGH + BLOCK_IN_BYTES),
+        sample_value(0)
+    );
+
+    let r = region_new(&mut mem);
+    assert_eq!(region_id(&mut mem, r), FIRST_USER_REGION);
+    assert_eq!(region_grow(&mut mem, r, 1), 0);
+    check_block(2, Some((RegionId(FIRST_USER_REGION), 0, 1)));
+
+    reset_test_memory();
+}
+
+unsafe fn test_region_free() {
+    println!("  Testing region free ...");
+    clear_stable_memory();
+    stable_mem::set_version(no_stable_memory_version());
+    let mut mem = initialize_test_memory();
+    region_init(&mut mem, 1);
+
+    // Simulate a layout written before the free block list and the header checksum existed.
+    // The older layout is kept, such that older compilers can still upgrade from it.
+    write_u32(offset::VERSION, 2);
+    write_u32(offset::HEADER_CHECKSUM, 0);
+    region_init(&mut mem, 1);
+    assert_eq!(read_u32(offset::VERSION), 2);
+    assert_eq!(header_checksum::get(), 0);
+
+    let pages = PAGES_IN_BLOCK as u64;
+    let r1 = region_new(&mut mem);
+    let r2 = region_new(&mut mem);
+    assert_eq!(region_grow(&mut mem, r1, pages + 1), 0);
+    assert_eq!(region_grow(&mut mem, r2, 1), 0);
+    region_store_word64(&mut mem, r1, 0, u64::MAX);
+    region_store_word64(&mut mem, r1, BLOCK_IN_BYTES, u64::MAX);
+    assert_eq!(total_allocated_blocks::get(), 3);
+    assert_eq!(free_blocks::count(), 0);
+    assert_eq!(region_shrink(&mut mem, r1, pages + 1), pages + 1);
+    reset_test_memory();
+    let mut mem = initialize_test_memory();
+    region_init(&mut mem, 1);
+    assert_eq!(read_u32(offset::VERSION), 2);
+    assert_eq!(header_checksum::get(), 0);
+    let r1 = region_recover(&mut mem, &RegionId(FIRST_USER_REGION));
+    let r2 = region_recover(&mut mem, &RegionId(FIRST_USER_REGION + 1));
+
+    // The first release of blocks upgrades the layout and starts checksumming.
+    assert_eq!(region_free(&mut mem, r1), pages + 1);
+    assert_eq!(read_u32(offset::VERSION), version::VERSION);
+    assert_eq!(header_checksum::get(), header_checksum::compute());
+    assert_eq!(region_size(&mut mem, r1), 0);
+    check_access_vector(&mut mem, r1, &[]);
+    assert_eq!(total_allocated_blocks::get(), 3);
+    assert_eq!(free_blocks::count(), 2);
+    assert_eq!(free_blocks::first(), Some(BlockId(1)));
+    check_block(0, None);
+    check_block(1, None);
+    check_block(2, Some((RegionId(FIRST_USER_REGION + 1), 0, 1)));
+    assert_eq!(region_free(&mut mem, r1), 0);
+    assert_eq!(free_blocks::count(), 2);
+
+    println!("    Testing block reuse ...");
+    let r3 = region_new(&mut mem);
+    let id3 = RegionId(FIRST_USER_REGION + 2);
+    assert_eq!(region_grow(&mut mem, r3, 1), 0);
+    check_block(1, Some((id3.clone(), 0, 1)));
+    assert_eq!(region_load_word64(&mut mem, r3, 0), 0);
+    assert_eq!(free_blocks::count(), 1);
+
+    // Reuses the remaining free block, then appends a new block.
+    assert_eq!(region_grow(&mut mem, r3, 2 * pages), 1);
+    check_block(1, Some((id3.clone(), 0, PAGES_IN_BLOCK as u8)));
+    check_block(0, Some((id3.clone(), 1, PAGES_IN_BLOCK as u8)));
+    check_block(3, Some((id3.clone(), 2, 1)));
+    check_access_vector(&mut mem, r3, &[1, 0, 3]);
+    assert_eq!(region_load_word64(&mut mem, r3, 2 * BLOCK_IN_BYTES), 0);
+    assert_eq!(total_allocated_blocks::get(), 4);
+    assert_eq!(free_blocks::count(), 0);
+    assert_eq!(free_blocks::first(), None);
+
+    // A freed region can be grown again.
+    assert_eq!(region_grow(&mut mem, r1, 1), 0);
+    check_block(4, Some((RegionId(FIRST_USER_REGION), 0, 1)));
+
+    println!("    Testing upgrade with free blocks ...");
+    assert_eq!(region_free(&mut mem, r2), 1);
+    assert_eq!(region_free(&mut mem, r1), 1);
+    reset_test_memory();
+    let mut mem = initialize_test_memory();
+    region_init(&mut mem, 1);
+    assert_eq!(free_blocks::count(), 2);
+    let recovered = region_recover(&mut mem, &RegionId(FIRST_USER_REGION));
+    assert_eq!(region_size(&mut mem, recovered), 0);
+    let recovered = region_recover(&mut mem, &id3);
+    assert_eq!(region_size(&mut mem, recovered), 2 * pages + 1);
+    check_access_vector(&mut mem, recovered, &[1, 0, 3]);
+    let r4 = region_new(&mut mem);
+    assert_eq!(region_grow(&mut mem, r4, 2 * pages), 0);
+    check_access_vector(&mut mem, r4, &[4, 2]);
+    assert_eq!(total_allocated_blocks::get(), 5);
+
+    reset_test_memory();
+}
+
+unsafe fn test_region_shrink() {
+    println!("  Testing region shrink ...");
+    clear_stable_memory();
+    stable_mem::set_version(no_stable_memory_version());
+    let mut mem = initialize_test_memory();
+    region_init(&mut mem, 1);
+
+    let pages = PAGES_IN_BLOCK as u64;
+    let r = region_new(&mut mem);
+    let id = RegionId(FIRST_USER_REGION);
+    assert_eq!(region_grow(&mut mem, r, 2 * pages + 3), 0);
+    let kept = pages * PAGE_IN_BYTES;
+    let truncated = (pages + 1) * PAGE_IN_BYTES;
+    region_store_word64(&mut mem, r, kept, u64::MAX);
+    region_store_word64(&mut mem, r, truncated, u64::MAX);
+
+    assert_eq!(region_shrink(&mut mem, r, 2 * pages + 4), u64::MAX);
+    assert_eq!(region_shrink(&mut mem, r, 2 * pages + 3), 2 * pages + 3);
+    assert_eq!(free_blocks::count(), 0);
+
+    // Truncate within the last block.
+    assert_eq!(region_shrink(&mut mem, r, 2 * pages + 1), 2 * pages + 3);
+    check_block(2, Some((id.clone(), 2, 1)));
+    check_access_vector(&mut mem, r, &[0, 1, 2]);
+    assert_eq!(free_blocks::count(), 0);
+
+    // Release a trailing block and zero the truncated pages of the kept block.
+    assert_eq!(region_shrink(&mut mem, r, pages + 1), 2 * pages + 1);
+    assert_eq!(region_size(&mut mem, r), pages + 1);
+    check_access_vector(&mut mem, r, &[0, 1]);
+    check_block(1, Some((id.clone(), 1, 1)));
+    check_block(2, None);
+    assert_eq!(free_blocks::count(), 1);
+    assert_eq!(read_u32(offset::VERSION), version::VERSION);
+    assert_eq!(region_load_word64(&mut mem, r, kept), u64::MAX);
+    assert_eq!(region_grow(&mut mem, r, 1), pages + 1);
+    assert_eq!(region_load_word64(&mut mem, r, truncated), 0);
+
+    let recovered = region_recover(&mut mem, &id);
+    assert_eq!(region_size(&mut mem, recovered), pages + 2);
+    check_access_vector(&mut mem, recovered, &[0, 1]);
+
+    assert_eq!(region_shrink(&mut mem, r, 0), pages + 2);
+    check_access_vector(&mut mem, r, &[]);
+    assert_eq!(free_blocks::count(), 3);
+    assert_eq!(total_allocated_blocks::get(), 3);
+
+    reset_test_memory();
+}
+
+unsafe fn test_multi_block_reuse() {
+    println!("  Testing multi-block reuse ...");
+    clear_stable_memory();
+    stable_mem::set_version(no_stable_memory_version());
+    let mut mem = initialize_test_memory();
+    region_init(&mut mem, 1);
+
+    let pages = PAGES_IN_BLOCK as u64;
+    let r1 = region_new(&mut mem);
+    assert_eq!(region_grow(&mut mem, r1, 3 * pages), 0);
+    for block in 0..3 {
+        for page in [0, pages / 2, pages - 1] {
+            let offset = block * BLOCK_IN_BYTES + page * PAGE_IN_BYTES;
+            region_store_word64(&mut mem, r1, offset, u64::MAX);
+        }
+    }
+    check_access_vector(&mut mem, r1, &[0, 1, 2]);
+
+    // Freeing zeroes the released blocks in stable memory.
+    assert_eq!(region_free(&mut mem, r1), 3 * pages);
+    assert_eq!(free_blocks::count(), 3);
+    let block_base = offset::BASE_LOW;
+    for block in 0..3 {
+        for page in [0, pages / 2, pages - 1] {
+            let offset = block_base + block * BLOCK_IN_BYTES + page * PAGE_IN_BYTES;
+            assert_eq!(read_u64(offset), 0);
+        }
+    }
+
+    // A single grow reuses all free blocks and appends one more.
+    let r2 = region_new(&mut mem);
+    assert_eq!(region_grow(&mut mem, r2, 3 * pages + 1), 0);
+    check_access_vector(&mut mem, r2, &[2, 1, 0, 3]);
+    assert_eq!(free_blocks::count(), 0);
+    assert_eq!(total_allocated_blocks::get(), 4);
+    for block in 0..3 {
+        for page in [0, pages / 2, pages - 1] {
+            let offset = block * BLOCK_IN_BYTES + page * PAGE_IN_BYTES;
+            assert_eq!(region_load_word64(&mut mem, r2, offset), 0);
+        }
+    }
+
+    reset_test_memory();
+}
+
+unsafe fn test_region_copy_and_fill() {
+    println!("  Testing region copy and fill ...");
+    clear_stable_memory();
+    stable_mem::set_version(no_stable_memory_version());
+    let mut mem = initialize_test_memory();
+    region_init(&mut mem, 1);
+
+    // Interleaved growth gives both regions non-adjacent blocks.
+    let pages = PAGES_IN_BLOCK as u64;
+    let r1 = region_new(&mut mem);
+    let r2 = region_new(&mut mem);
+    region_grow(&mut mem, r1, pages);
+    region_grow(&mut mem, r2, pages);
+    region_grow(&mut mem, r1, pages);
+    region_grow(&mut mem, r2, pages);
+    check_access_vector(&mut mem, r1, &[0, 2]);
+    check_access_vector(&mut mem, r2, &[1, 3]);
+
+    println!("    Testing fill ...");
+    region_fill(&mut mem, r1, BLOCK_IN_BYTES - 10, 20, 0x5a);
+    assert_eq!(region_load_word8(&mut mem, r1, BLOCK_IN_BYTES - 11), 0);
+    for offset in BLOCK_IN_BYTES - 10..BLOCK_IN_BYTES + 10 {
+        assert_eq!(region_load_word8(&mut mem, r1, offset), 0x5a);
+    }
+    assert_eq!(region_load_word8(&mut mem, r1, BLOCK_IN_BYTES + 10), 0);
+    region_fill(&mut mem, r2, 0, 2 * BLOCK_IN_BYTES, 0x11);
+    for offset in [
+        0,
+        BLOCK_IN_BYTES - 1,
+        BLOCK_IN_BYTES,
+        2 * BLOCK_IN_BYTES - 1,
+    ] {
+        assert_eq!(region_load_word8(&mut mem, r2, offset), 0x11);
+    }
+    region_fill(&mut mem, r2, 0, 0, 0);
+    assert_eq!(region_load_word8(&mut mem, r2, 0), 0x11);
+
+    println!("    Testing copy ...");
+    let start = BLOCK_IN_BYTES - 32;
+    for index in 0..64 {
+        region_store_word8(&mut mem, r1, start + index, index as u32 + 1);
+    }
+    region_copy(&mut mem, r1, start, r2, start + 12, 64);
+    assert_eq!(region_load_word8(&mut mem, r2, start + 11), 0x11);
+    check_sequence(&mut mem, r2, start + 12, 64);
+    assert_eq!(region_load_word8(&mut mem, r2, start + 76), 0x11);
+
+    region_copy(&mut mem, r1, 0, r2, 0, 2 * BLOCK_IN_BYTES);
+    check_sequence(&mut mem, r2, start, 64);
+    assert_eq!(region_load_word8(&mut mem, r2, 0), 0);
+    assert_eq!(region_load_word8(&mut mem, r2, 2 * BLOCK_IN_BYTES - 1), 0);
+
+    println!("    Testing overlapping copy ...");
+    region_copy(&mut mem, r1, start, r1, start + 4, 64);
+    check_sequence(&mut mem, r1, start + 4, 64);
+    region_copy(&mut mem, r1, start + 4, r1, start, 64);
+    check_sequence(&mut mem, r1, start, 64);
+    region_copy(&mut mem, r1, start, r1, start, 64);
+    check_sequence(&mut mem, r1, start, 64);
+
+    reset_test_memory();
+}
+
+unsafe fn test_checksums() {
+    println!("  Testing checksums ...");
+    clear_stable_memory();
+    stable_mem::set_version(no_stable_memory_version());
+    let mut mem = initialize_test_memory();
+    region_init(&mut mem, 1);
+    assert_eq!(header_checksum::get(), header_checksum::compute());
+
+    println!("    Testing header checksum ...");
+    let pages = PAGES_IN_BLOCK as u64;
+    let r1 = region_new(&mut mem);
+    let r2 = region_new(&mut mem);
+    region_grow(&mut mem, r1, pages);
+    region_grow(&mut mem, r2, 1);
+    region_grow(&mut mem, r1, 1);
+    assert_eq!(header_checksum::get(), header_checksum::compute());
+    region_shrink(&mut mem, r1, 1);
+    assert_eq!(header_checksum::get(), header_checksum::compute());
+    region_grow(&mut mem, r1, pages);
+    assert_eq!(header_checksum::get(), header_checksum::compute());
+
+    // Silent corruption of a header field is detected.
+    let checksum = header_checksum::get();
+    let total_regions = total_allocated_regions::get();
+    write_u64(offset::TOTAL_ALLOCATED_REGIONS, total_regions + 1);
+    assert_ne!(header_checksum::compute(), checksum);
+    write_u64(offset::TOTAL_ALLOCATED_REGIONS, total_regions);
+    assert_eq!(header_checksum::compute(), checksum);
+
+    println!("    Testing meta data checksum ...");
+    let mut meta_data = vec![0u8; offset::FREE as usize];
+    read(0, &mut meta_data);
+    let meta_data_checksum = region_meta_data_checksum(&mut mem);
+    assert_eq!(meta_data_checksum, update_crc32(0, &meta_data));
+    region_grow(&mut mem, r2, pages);
+    assert_ne!(region_meta_data_checksum(&mut mem), meta_data_checksum);
+
+    println!("    Testing region checksum ...");
+    // The range straddles the non-adjacent blocks of the region.
+    let start = BLOCK_IN_BYTES - 4;
+    for (index, byte) in b"123456789".iter().enumerate() {
+        region_store_word8(&mut mem, r1, start + index as u64, *byte as u32);
+    }
+    assert_eq!(region_checksum(&mut mem, r1, start, 9), 0xcbf43926);
+    assert_eq!(region_checksum(&mut mem, r1, start, 0), 0);
+
+    let length = BLOCK_IN_BYTES + 1;
+    region_fill(&mut mem, r2, 0, length, 0xee);
+    let expected = update_crc32(0, &vec![0xee; length as usize]);
+    assert_eq!(region_checksum(&mut mem, r2, 0, length), expected);
+    region_copy(&mut mem, r2, 0, r1, 1, length);
+    assert_eq!(region_checksum(&mut mem, r1, 1, length), expected);
+
+    reset_test_memory();
+}
+
+unsafe fn check_sequence<M: Memory>(mem: &mut M, region: Value, start: u64, length: u64) {
+    for index in 0..length {
+        assert_eq!(
+            region_load_word8(mem, region, start + index),
+            index as u32 + 1
+        );
+    }
+}
+
+fn check_header(block_base: u64) {
+    let mut magic = [0u8; 8];
+    read(offset::MAGIC, &mut magic);
+    assert_eq!(&magic, version::MAGIC);
+    assert_eq!(read_u32(offset::VERSION), version::VERSION);
+    assert_eq!(read_u16(offset::BLOCK_PAGES), PAGES_IN_BLOCK as u16);
+    assert_eq!(read_u64(offset::BLOCK_BASE), block_base);
+}
+
+fn check_block(block: u16, expected: Option<(RegionId, u16, u8)>) {
+    assert_eq!(block_region_table::get(BlockId(block)), expected);
+}
+
+unsafe fn check_access_vector<M: Memory>(mem: &mut M, region: Value, expected: &[u16]) {
+    let blob = region_vec_pages(mem, region).as_blob();
+    assert_eq!(blob.len().as_usize(), expected.len() * 2);
+    for (index, block) in expected.iter().enumerate() {
+        assert_eq!(blob.get_u16(index), *block);
+    }
+}
+
+fn sample_value(index: usize) -> u64 {
+    0x1111_1111_1111_1111 * (index as u64 + 1)
+}
+
+#[classical_persistence]
+fn no_stable_memory_version() -> usize {
+    motoko_rts::region::LEGACY_VERSION_NO_STABLE_MEMORY
+}
+
+#[enhanced_orthogonal_persistence]
+fn no_stable_memory_version() -> usize {
+    motoko_rts::region::VERSION_STABLE_HEAP_NO_REGIONS
+}
+
+#[classical_persistence]
+fn some_stable_memory_version() -> usize {
+    motoko_rts::region::LEGACY_VERSION_SOME_STABLE_MEMORY
+}
+
+#[enhanced_orthogonal_persistence]
+fn some_stable_memory_version() -> usize {
+    motoko_rts::region::VERSION_STABLE_HEAP_NO_REGIONS
+}
+
+#[classical_persistence]
+fn regions_version() -> usize {
+    motoko_rts::region::LEGACY_VERSION_REGIONS
+}
+
+#[enhanced_orthogonal_persistence]
+fn regions_version() -> usize {
+    motoko_rts::region::VERSION_STABLE_HEAP_REGIONS
+}
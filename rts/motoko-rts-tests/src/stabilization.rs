@@ -1,7 +1,7 @@
+mod inspection;
 mod layout;
 mod reader_writer;
 mod stable_bigints;
-mod stable_memory;
 
 use crate::{
     gc::{
@@ -9,7 +9,7 @@ use crate::{
         utils::WORD_SIZE,
     },
     memory::TestMemory,
-    stabilization::stable_memory::clear_stable_memory,
+    stable_mem::clear_stable_memory,
 };
 use motoko_rts::{
     memory::{Memory, alloc_array},
@@ -26,6 +26,7 @@ use oorandom::Rand32;
 pub unsafe fn test() {
     println!("Testing stabilization ...");
     layout::test();
+    inspection::test();
     stable_bigints::test();
     reader_writer::test();
     test_stabilization();
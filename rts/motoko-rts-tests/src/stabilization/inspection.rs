@@ -0,0 +1,302 @@
+use motoko_rts::stabilization::{
+    inspection::{InspectionError, Snapshot, write_report},
+    layout::{StableBody, StableObjectKind, StableSummary, StableValue},
+};
+
+use crate::stable_mem::{
+    clear_stable_memory, ic0_stable64_grow, ic0_stable64_size, ic0_stable64_write,
+};
+
+const PAGE_SIZE: u64 = 64 * 1024;
+
+const OBJECT_ADDRESS: u64 = 0;
+const TEXT_ADDRESS: u64 = 40;
+const HASH_BLOB_ADDRESS: u64 = 64;
+const VARIANT_ADDRESS: u64 = 96;
+const SERIALIZED_LENGTH: u64 = 120;
+
+const FIRST_FIELD_HASH: u64 = 1_234;
+const SECOND_FIELD_HASH: u64 = 5_678;
+const VARIANT_TAG: u64 = 99;
+
+pub unsafe fn test() {
+    println!("  Testing snapshot inspection ...");
+    test_object_walk(0);
+    test_object_walk(PAGE_SIZE);
+    test_type_table();
+    test_report();
+    test_legacy_version();
+    test_invalid_snapshots();
+    clear_stable_memory();
+}
+
+fn pointer(address: u64) -> u64 {
+    StableValue::from_stable_address(address).get_raw()
+}
+
+fn write_words(offset: u64, words: &[u64]) {
+    let bytes: Vec<u8> = words.iter().flat_map(|word| word.to_le_bytes()).collect();
+    write_bytes(offset, &bytes);
+}
+
+fn write_bytes(offset: u64, bytes: &[u8]) {
+    let required_pages = (offset + bytes.len() as u64).div_ceil(PAGE_SIZE);
+    let current_pages = ic0_stable64_size();
+    if required_pages > current_pages {
+        ic0_stable64_grow(required_pages - current_pages);
+    }
+    ic0_stable64_write(offset, bytes.as_ptr() as u64, bytes.len() as u64);
+}
+
+/// Serialized object graph:
+/// `{ #1234 = "hello"; #5678 = #99(42) }` with the field hashes stored in a separate blob.
+fn write_object_graph(start: u64) {
+    write_words(
+        start + OBJECT_ADDRESS,
+        &[
+            StableObjectKind::Object as u64,
+            2,
+            pointer(HASH_BLOB_ADDRESS),
+            pointer(TEXT_ADDRESS),
+            pointer(VARIANT_ADDRESS),
+        ],
+    );
+    write_words(
+        start + TEXT_ADDRESS,
+        &[StableObjectKind::BlobText as u64, 5],
+    );
+    write_bytes(start + TEXT_ADDRESS + 16, b"hello\0\0\0");
+    write_words(
+        start + HASH_BLOB_ADDRESS,
+        &[
+            StableObjectKind::BlobBytes as u64,
+            16,
+            FIRST_FIELD_HASH,
+            SECOND_FIELD_HASH,
+        ],
+    );
+    write_words(
+        start + VARIANT_ADDRESS,
+        &[StableObjectKind::Variant as u64, VARIANT_TAG, 42 << 1],
+    );
+}
+
+/// Type table: `t0 = record {#1234: text; #5678: t1}` and `t1 = variant {#99: nat}`.
+fn write_type_descriptor(offset: u64) {
+    let candid_data = [
+        0x6c, 0x02, 0xd2, 0x09, 0x71, 0xae, 0x2c, 0x01, // record
+        0x6b, 0x01, 0x63, 0x7d, // variant
+    ];
+    write_words(offset, &[candid_data.len() as u64]);
+    write_bytes(offset + 8, &candid_data);
+    let offsets_address = offset + 8 + candid_data.len() as u64;
+    write_words(offsets_address, &[16, 0, 8]);
+}
+
+fn write_last_page_record(
+    version: u32,
+    serialized_data_address: u64,
+    type_descriptor_address: u64,
+    first_word_backup: u32,
+) {
+    let record_end = ic0_stable64_size() * PAGE_SIZE;
+    let record_start = record_end - 56;
+    write_words(
+        record_start,
+        &[
+            pointer(0), // Dedup table address.
+            0,          // Migrations list address.
+            1_000,      // Stabilization instructions.
+            serialized_data_address,
+            SERIALIZED_LENGTH,
+            type_descriptor_address,
+        ],
+    );
+    let tail = (first_word_backup as u64) | ((version as u64) << 32);
+    write_words(record_start + 48, &[tail]);
+}
+
+fn write_snapshot(version: u32, start: u64) {
+    clear_stable_memory();
+    write_object_graph(start);
+    let type_descriptor_address = start + PAGE_SIZE;
+    write_type_descriptor(type_descriptor_address);
+    let mut first_word_backup = 0;
+    if start == 0 {
+        // Mimic the stabilization that zeroes the first word in stable memory.
+        first_word_backup = StableObjectKind::Object as u32;
+        write_bytes(0, &[0; 4]);
+    }
+    write_bytes(type_descriptor_address + PAGE_SIZE, &[0]);
+    write_last_page_record(version, start, type_descriptor_address, first_word_backup);
+}
+
+fn test_object_walk(start: u64) {
+    println!("    Testing object walk at {start:#x} ...");
+    write_snapshot(7, start);
+    let snapshot = Snapshot::open().unwrap_or_else(|_| panic!("invalid snapshot"));
+    assert_eq!(snapshot.record().version(), 7);
+    assert_eq!(snapshot.record().serialized_data_address(), start);
+    assert_eq!(
+        snapshot.record().serialized_data_length(),
+        SERIALIZED_LENGTH
+    );
+    assert_eq!(
+        snapshot.record().statistics.stabilization_instructions,
+        1_000
+    );
+    assert!(!snapshot.uses_regions());
+
+    let objects: Vec<_> = snapshot
+        .objects()
+        .map(|object| object.ok().unwrap())
+        .collect();
+    let kinds: Vec<_> = objects.iter().map(|object| object.kind).collect();
+    assert_eq!(
+        kinds,
+        [
+            StableObjectKind::Object,
+            StableObjectKind::BlobText,
+            StableObjectKind::BlobBytes,
+            StableObjectKind::Variant
+        ]
+    );
+    let addresses: Vec<_> = objects.iter().map(|object| object.address).collect();
+    assert_eq!(
+        addresses,
+        [
+            OBJECT_ADDRESS,
+            TEXT_ADDRESS,
+            HASH_BLOB_ADDRESS,
+            VARIANT_ADDRESS
+        ]
+    );
+    let total_size: u64 = objects.iter().map(|object| object.size().unwrap()).sum();
+    assert_eq!(total_size, SERIALIZED_LENGTH);
+
+    let object = &objects[0];
+    assert_eq!(object.body, StableBody::Values(2));
+    assert!(object.static_values() == [StableValue::from_stable_address(HASH_BLOB_ADDRESS)]);
+    assert!(snapshot.body_value(object, 1).is_ptr());
+    assert_eq!(
+        snapshot.body_value(object, 1).to_stable_address(),
+        VARIANT_ADDRESS
+    );
+
+    let text = &objects[1];
+    assert_eq!(text.body, StableBody::Bytes(5));
+    let mut buffer = [0u8; 16];
+    assert_eq!(snapshot.body_bytes(text, 0, &mut buffer), 5);
+    assert_eq!(&buffer[..5], b"hello");
+
+    let variant = &objects[3];
+    assert_eq!(variant.summary, StableSummary::Variant { tag: VARIANT_TAG });
+    assert!(!variant.static_values()[0].is_ptr());
+}
+
+fn test_type_table() {
+    println!("    Testing type table ...");
+    write_snapshot(8, PAGE_SIZE);
+    let snapshot = Snapshot::open().unwrap_or_else(|_| panic!("invalid snapshot"));
+    assert!(snapshot.uses_regions());
+    let table = snapshot
+        .type_table()
+        .unwrap_or_else(|_| panic!("invalid type table"));
+    assert_eq!(table.type_count(), 2);
+    assert_eq!(table.candid_length(), 12);
+    assert_eq!(table.type_offset(1), 8);
+    let mut output = String::new();
+    assert!(table.write_type(&mut output, 0).is_ok());
+    assert_eq!(output, "record {#1234: text; #5678: t1}");
+    output.clear();
+    assert!(table.write_type(&mut output, 1).is_ok());
+    assert_eq!(output, "variant {#99: nat}");
+}
+
+fn test_report() {
+    println!("    Testing report ...");
+    write_snapshot(7, 0);
+    let snapshot = Snapshot::open().unwrap_or_else(|_| panic!("invalid snapshot"));
+    let mut report = String::new();
+    write_report(&mut report, &snapshot).unwrap();
+    for line in [
+        "  version: 7 (V1, no regions)",
+        "  stabilization instructions: 1000",
+        "  serialized data: address 0x0, length 120",
+        "  dedup table: @0x0, migrations list: 0x0",
+        "Type table: 2 types, 12 bytes",
+        "  t0 = record {#1234: text; #5678: t1}",
+        "  t1 = variant {#99: nat}",
+        "  @0x0 Object @0x40 [2] @0x28 @0x60",
+        "  @0x28 BlobText [5 bytes] \"hello\"",
+        "  @0x40 BlobBytes [16 bytes] d2040000000000002e16000000000000",
+        "  @0x60 Variant #99 0x54",
+        "  4 objects",
+    ] {
+        assert!(
+            report.lines().any(|reported| reported == line),
+            "missing line {line:?} in report:\n{report}"
+        );
+    }
+}
+
+fn test_legacy_version() {
+    println!("    Testing legacy version ...");
+    write_snapshot(3, PAGE_SIZE);
+    let snapshot = Snapshot::open().unwrap_or_else(|_| panic!("invalid snapshot"));
+    // V0 records do not have the extension with the dedup table and migrations list.
+    assert_eq!(snapshot.record().dedup_table_address.get_raw(), 0);
+    assert_eq!(snapshot.objects().count(), 4);
+}
+
+fn test_invalid_snapshots() {
+    println!("    Testing invalid snapshots ...");
+    clear_stable_memory();
+    assert!(matches!(
+        Snapshot::open(),
+        Err(InspectionError::EmptyStableMemory)
+    ));
+
+    write_snapshot(5, PAGE_SIZE);
+    assert!(matches!(
+        Snapshot::open(),
+        Err(InspectionError::UnsupportedVersion(5))
+    ));
+
+    write_snapshot(7, PAGE_SIZE);
+    write_words(PAGE_SIZE + VARIANT_ADDRESS, &[1_000]);
+    let snapshot = Snapshot::open().unwrap_or_else(|_| panic!("invalid snapshot"));
+    let objects: Vec<_> = snapshot.objects().collect();
+    assert_eq!(objects.len(), 4);
+    assert!(matches!(
+        objects[3],
+        Err(InspectionError::InvalidTag {
+            address: VARIANT_ADDRESS,
+            tag: 1_000
+        })
+    ));
+
+    // A corrupted text length whose padded size overflows.
+    write_snapshot(7, PAGE_SIZE);
+    write_words(PAGE_SIZE + TEXT_ADDRESS + 8, &[u64::MAX - 2]);
+    let snapshot = Snapshot::open().unwrap_or_else(|_| panic!("invalid snapshot"));
+    let objects: Vec<_> = snapshot.objects().collect();
+    assert_eq!(objects.len(), 2);
+    assert!(matches!(
+        objects[1],
+        Err(InspectionError::InvalidObjectSize {
+            address: TEXT_ADDRESS
+        })
+    ));
+
+    // A corrupted object field count whose size overflows.
+    write_snapshot(7, PAGE_SIZE);
+    write_words(PAGE_SIZE + OBJECT_ADDRESS + 8, &[u64::MAX / 4]);
+    let snapshot = Snapshot::open().unwrap_or_else(|_| panic!("invalid snapshot"));
+    assert!(matches!(
+        snapshot.objects().next(),
+        Some(Err(InspectionError::InvalidObjectSize {
+            address: OBJECT_ADDRESS
+        }))
+    ));
+}
@@ -5,7 +5,7 @@ use motoko_rts::stabilization::serialization::stable_memory_stream::{
 };
 use oorandom::Rand32;
 
-use crate::stabilization::stable_memory::ic0_stable64_read;
+use crate::stable_mem::ic0_stable64_read;
 
 pub unsafe fn test() {
     println!("  Testing stable memory stream ...");
@@ -9,7 +9,8 @@ use oorandom::Rand32;
 use crate::{
     bigint::set_bigint_heap,
     memory::{initialize_test_memory, reset_test_memory},
-    stabilization::{deserialize, serialize, stable_memory::clear_stable_memory},
+    stabilization::{deserialize, serialize},
+    stable_mem::clear_stable_memory,
 };
 
 pub unsafe fn test() {
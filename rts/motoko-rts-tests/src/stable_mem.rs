@@ -0,0 +1,141 @@
+//! Simulated stable memory for the native RTS build.
+//!
+//! Implements the `ic0_stable64_*` system API and the `moc_stable_mem_*` functions that are
+//! otherwise provided by the compiler-generated code, backed by an in-process byte vector.
+//! The physical size is the length of the vector. The virtual size and the stable memory
+//! version are kept separately, as in the compiler.
+
+use motoko_rts::{
+    mem_utils::memcpy_bytes,
+    stable_mem::{
+        PAGE_SIZE, get_version, grow, read, read_u64, set_version, size, write, write_u64,
+    },
+    types::Bytes,
+};
+use std::cell::{Cell, RefCell};
+
+thread_local! {
+    static STABLE_MEMORY: RefCell<Vec<u8>> = RefCell::new(vec![]);
+    static VIRTUAL_PAGES: Cell<u64> = Cell::new(0);
+    static VERSION: Cell<usize> = Cell::new(0);
+}
+
+/// Discard the stable memory contents and reset the virtual size and the version.
+pub fn clear_stable_memory() {
+    STABLE_MEMORY.with(|memory| {
+        memory.borrow_mut().clear();
+    });
+    VIRTUAL_PAGES.with(|pages| pages.set(0));
+    VERSION.with(|version| version.set(0));
+}
+
+#[unsafe(no_mangle)]
+pub fn ic0_stable64_write(offset: u64, source: u64, size: u64) {
+    STABLE_MEMORY.with(|memory| {
+        assert!(offset + size <= memory.borrow().len() as u64);
+        let destination = memory.borrow_mut().as_mut_ptr() as u64 + offset;
+        unsafe {
+            memcpy_bytes(destination as usize, source as usize, Bytes(size as usize));
+        }
+    });
+}
+
+#[unsafe(no_mangle)]
+pub fn ic0_stable64_read(destination: u64, offset: u64, size: u64) {
+    STABLE_MEMORY.with(|memory| {
+        assert!(offset + size <= memory.borrow().len() as u64);
+        let source = memory.borrow_mut().as_mut_ptr() as u64 + offset;
+        unsafe {
+            memcpy_bytes(destination as usize, source as usize, Bytes(size as usize));
+        }
+    });
+}
+
+#[unsafe(no_mangle)]
+pub fn ic0_stable64_size() -> u64 {
+    STABLE_MEMORY.with(|memory| memory.borrow().len()) as u64 / PAGE_SIZE
+}
+
+#[unsafe(no_mangle)]
+pub fn ic0_stable64_grow(additional_pages: u64) -> u64 {
+    STABLE_MEMORY.with(|memory| {
+        let mut memory = memory.borrow_mut();
+        let old_pages = memory.len() as u64 / PAGE_SIZE;
+        let new_length = (old_pages + additional_pages) * PAGE_SIZE;
+        memory.resize(new_length as usize, 0);
+        old_pages
+    })
+}
+
+#[unsafe(no_mangle)]
+pub fn moc_stable_mem_get_version() -> usize {
+    VERSION.with(|version| version.get())
+}
+
+#[unsafe(no_mangle)]
+pub fn moc_stable_mem_set_version(version: usize) {
+    VERSION.with(|current| current.set(version));
+}
+
+#[unsafe(no_mangle)]
+pub fn moc_stable_mem_get_size() -> u64 {
+    VIRTUAL_PAGES.with(|pages| pages.get())
+}
+
+#[unsafe(no_mangle)]
+pub fn moc_stable_mem_set_size(pages: u64) {
+    VIRTUAL_PAGES.with(|current| current.set(pages));
+}
+
+#[unsafe(no_mangle)]
+pub fn moc_stable_mem_grow(additional_pages: u64) -> u64 {
+    let old_pages = moc_stable_mem_get_size();
+    let new_pages = old_pages + additional_pages;
+    let physical_pages = ic0_stable64_size();
+    if new_pages > physical_pages {
+        ic0_stable64_grow(new_pages - physical_pages);
+    }
+    moc_stable_mem_set_size(new_pages);
+    old_pages
+}
+
+pub fn test() {
+    println!("Testing simulated stable memory ...");
+
+    clear_stable_memory();
+    assert_eq!(size(), 0);
+    assert_eq!(ic0_stable64_size(), 0);
+    assert_eq!(get_version(), 0);
+
+    set_version(5);
+    assert_eq!(get_version(), 5);
+
+    assert_eq!(grow(2), 0);
+    assert_eq!(grow(1), 2);
+    assert_eq!(size(), 3);
+    assert_eq!(ic0_stable64_size(), 3);
+
+    // The physical memory can exceed the virtual size.
+    assert_eq!(ic0_stable64_grow(2), 3);
+    assert_eq!(size(), 3);
+    assert_eq!(grow(1), 3);
+    assert_eq!(ic0_stable64_size(), 5);
+
+    let last = 4 * PAGE_SIZE - 8;
+    write_u64(last, 0x0123_4567_89ab_cdef);
+    assert_eq!(read_u64(last), 0x0123_4567_89ab_cdef);
+    let mut bytes = [0u8; 8];
+    read(last, &mut bytes);
+    assert_eq!(bytes, 0x0123_4567_89ab_cdefu64.to_le_bytes());
+
+    let data: Vec<u8> = (0..=255).collect();
+    write(PAGE_SIZE - 100, &data);
+    let mut buffer = vec![0u8; data.len()];
+    read(PAGE_SIZE - 100, &mut buffer);
+    assert_eq!(buffer, data);
+
+    clear_stable_memory();
+    assert_eq!(size(), 0);
+    assert_eq!(ic0_stable64_size(), 0);
+    assert_eq!(get_version(), 0);
+}
@@ -1,15 +1,22 @@
 //! Text and text iterator tests
 
-use crate::memory::{initialize_test_memory, reset_test_memory};
+use crate::memory::{TestMemory, initialize_test_memory, reset_test_memory};
 
 use motoko_rts::memory::Memory;
 use motoko_rts::text::{
-    blob_of_text, decode_code_point, text_compare, text_concat, text_len, text_of_str,
-    text_singleton, text_size,
+    blob_of_text, decode_code_point, text_char_at, text_compare, text_concat, text_find,
+    text_grapheme_count, text_len, text_of_str, text_singleton, text_size, text_slice,
+};
+use motoko_rts::text_iter::{
+    text_grapheme_iter, text_grapheme_iter_done, text_grapheme_iter_next, text_iter,
+    text_iter_done, text_iter_next,
+};
+use motoko_rts::types::{
+    Bytes, Concat, TAG_BLOB_T, TAG_CONCAT, TAG_COUNTED_CONCAT, Value, is_concat_tag, size_of,
 };
-use motoko_rts::text_iter::{text_iter, text_iter_done, text_iter_next};
-use motoko_rts::types::{Bytes, TAG_BLOB_T, Value};
 
+use oorandom::Rand32;
+use std::cmp::min;
 use std::convert::TryFrom;
 
 static STR: &str = "abcdefgh";
@@ -99,11 +106,298 @@ pub unsafe fn test() {
         ],
     );
 
+    println!("  Testing character indexing, slicing and search");
+    let text = text_of_str(&mut mem, "äbc€d-0123456789");
+    position_test(&mut mem, text);
+    let mut text = text_of_str(&mut mem, "");
+    for i in 0..5_000 {
+        let piece = text_of_str(&mut mem, PIECES[i % PIECES.len()]);
+        text = text_concat(&mut mem, text, piece);
+    }
+    position_test(&mut mem, text);
+    let mut text = text_of_str(&mut mem, "");
+    for i in 0..5_000 {
+        let piece = text_of_str(&mut mem, PIECES[i * 7 % PIECES.len()]);
+        text = text_concat(&mut mem, piece, text);
+    }
+    position_test(&mut mem, text);
+
+    println!("  Testing character counts");
+    char_count_test(&mut mem);
+
+    println!("  Testing grapheme clusters");
+    grapheme_test(&mut mem);
+
+    drop(mem);
+
+    // Ropes with many pieces need more memory than the other tests
+    let mut mem = TestMemory::new(Bytes(ROPE_TEST_MEMORY_SIZE).to_words());
+
+    println!("  Testing ropes built by appending");
+    rope_test(&mut mem, 1_000_000, |mem, text, piece| {
+        text_concat(mem, text, piece)
+    });
+
+    println!("  Testing ropes built by prepending");
+    rope_test(&mut mem, 100_000, |mem, text, piece| {
+        text_concat(mem, piece, text)
+    });
+
+    println!("  Testing ropes grown at both ends");
+    zigzag_test(&mut mem, 10_000);
+
     drop(mem);
 
     reset_test_memory();
 }
 
+const ROPE_TEST_MEMORY_SIZE: usize = 256 * 1024 * 1024;
+
+/// Pieces of different sizes and UTF-8 encoding lengths.
+static PIECES: [&str; 5] = ["a", "ö", "bc", "€uro", "0123456789"];
+
+/// Texts split into their grapheme clusters.
+static GRAPHEME_TESTS: [&[&str]; 22] = [
+    &["e\u{301}"],
+    &["\r\n"],
+    &["\n", "\r"],
+    &["\u{1f1e9}\u{1f1ea}", "\u{1f1e8}\u{1f1ed}"],
+    &["\u{1f1e9}\u{1f1ea}", "\u{1f1e8}"],
+    &["\u{1f1e9}\u{301}", "\u{1f1ea}"],
+    &["\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}"],
+    &["\u{1f468}\u{1f3fd}\u{200d}\u{1f469}"],
+    &["\u{1f3f3}\u{fe0f}\u{200d}\u{1f308}"],
+    &["\u{1f44d}\u{1f3fd}"],
+    &["a\u{200d}", "b"],
+    &["a\u{200d}", "\u{1f467}"],
+    &["\u{1100}\u{1161}\u{11a8}"],
+    &["\u{d55c}", "\u{ad6d}", "\u{c5b4}"],
+    &["\u{600}1"],
+    &["\u{915}\u{93f}"],
+    &["\u{915}\u{94d}\u{937}"],
+    &["\u{915}\u{93c}\u{94d}\u{200d}\u{924}\u{94d}\u{930}"],
+    &["\u{915}\u{94d}\u{200c}", "\u{924}"],
+    &["a\u{94d}", "\u{924}"],
+    &["a", "\u{0}", "\u{301}"],
+    &["Z\u{351}\u{35b}\u{36a}\u{350}"],
+];
+
+unsafe fn grapheme_test<M: Memory>(mem: &mut M) {
+    let text = text_of_str(mem, "");
+    check_graphemes(mem, text, &[]);
+
+    // Padding that is too long to be merged into a single blob on concatenation
+    let padding = "-".repeat(70);
+    let padding_clusters = vec!["-"; 70];
+
+    for clusters in GRAPHEME_TESTS.iter() {
+        let str = clusters.concat();
+        let text = text_of_str(mem, &str);
+        check_graphemes(mem, text, clusters);
+
+        // Split between blobs at each character boundary
+        let expected = [&padding_clusters, *clusters, &padding_clusters].concat();
+        for (split, _) in str.char_indices().skip(1) {
+            let left = text_of_str(mem, &(padding.clone() + &str[..split]));
+            let right = text_of_str(mem, &(String::from(&str[split..]) + &padding));
+            let text = text_concat(mem, left, right);
+            assert_eq!(text.tag(), TAG_COUNTED_CONCAT);
+            check_graphemes(mem, text, &expected);
+        }
+    }
+}
+
+unsafe fn check_graphemes<M: Memory>(mem: &mut M, text: Value, expected: &[&str]) {
+    assert_eq!(text_grapheme_count(text), expected.len());
+
+    let iter = text_grapheme_iter(mem, text);
+    for cluster in expected {
+        assert_eq!(text_grapheme_iter_done(iter), 0);
+        let next = text_grapheme_iter_next(mem, iter);
+        let cluster = text_of_str(mem, cluster);
+        assert_eq!(text_compare(next, cluster), 0);
+    }
+    assert_eq!(text_grapheme_iter_done(iter), 1);
+}
+
+/// Builds a text from `n` pieces with `add` and checks it against the same text built as a string.
+unsafe fn rope_test<M: Memory>(mem: &mut M, n: usize, add: impl Fn(&mut M, Value, Value) -> Value) {
+    let mut text = text_of_str(mem, "");
+    let mut pieces = vec![];
+    for i in 0..n {
+        let piece = PIECES[i % PIECES.len()];
+        let piece_text = text_of_str(mem, piece);
+        text = add(mem, text, piece_text);
+        pieces.push(piece);
+    }
+
+    // The pieces were either appended or prepended
+    let appended = pieces.concat();
+    pieces.reverse();
+    let prepended = pieces.concat();
+    let expected = if text_compare(text, text_of_str(mem, &appended)) == 0 {
+        appended
+    } else {
+        prepended
+    };
+
+    // Counted when concatenated
+    let length = expected.chars().count();
+    assert_eq!(text.as_concat().n_chars(), Some(length));
+
+    check_text(mem, text, &expected);
+
+    // Balanced, i.e. logarithmic in the size of the text
+    let size = expected.len();
+    assert!(rope_depth(text) <= 4 * size.ilog2() as usize);
+}
+
+/// Builds a text by repeatedly wrapping it in parentheses, which is not rebalanced.
+unsafe fn zigzag_test<M: Memory>(mem: &mut M, n: usize) {
+    let mut text = text_of_str(mem, "0123456789");
+    for _ in 0..n {
+        let open = text_of_str(mem, "(");
+        let close = text_of_str(mem, ")");
+        text = text_concat(mem, open, text);
+        text = text_concat(mem, text, close);
+    }
+
+    let expected = "(".repeat(n) + "0123456789" + &")".repeat(n);
+    check_text(mem, text, &expected);
+}
+
+const RANDOM_SEED: u64 = 4711;
+
+/// Checks `text_char_at`, `text_slice` and `text_find` against the same operations on strings.
+unsafe fn position_test<M: Memory>(mem: &mut M, text: Value) {
+    let expected = TextIter::from_text(mem, text).collect::<String>();
+    let chars = expected.chars().collect::<Vec<_>>();
+    let mut random = Rand32::new(RANDOM_SEED);
+
+    // Characters at both ends and in between
+    let step = chars.len() / 100 + 1;
+    for index in (0..chars.len()).step_by(step).chain([chars.len() - 1]) {
+        assert_eq!(text_char_at(text, index), chars[index] as u32);
+    }
+
+    // Slices, including the empty and the entire text
+    let mut ranges = vec![(0, 0), (0, chars.len()), (chars.len(), 0)];
+    for _ in 0..100 {
+        let start = random.rand_range(0..chars.len() as u32 + 1) as usize;
+        let len = random.rand_range(0..(chars.len() - start) as u32 + 1) as usize;
+        ranges.push((start, len));
+    }
+    for (start, len) in ranges {
+        let slice = text_slice(mem, text, start, len);
+        let expected_slice = chars[start..start + len].iter().collect::<String>();
+        assert_eq!(text_size(slice), Bytes(expected_slice.len()));
+        assert_eq!(text_len(slice), len);
+        let expected_text = text_of_str(mem, &expected_slice);
+        assert_eq!(text_compare(slice, expected_text), 0);
+        assert!(TextIter::from_text(mem, slice).eq(expected_slice.chars()));
+    }
+
+    // Search for parts of the text and for absent text
+    for _ in 0..100 {
+        let start = random.rand_range(0..chars.len() as u32) as usize;
+        let len = 1 + random.rand_range(0..min(20, chars.len() - start) as u32) as usize;
+        let needle = chars[start..start + len].iter().collect::<String>();
+        let byte_index = expected.find(&needle).unwrap();
+        let expected_index = expected[..byte_index].chars().count();
+        let needle_text = text_of_str(mem, &needle);
+        assert_eq!(text_find(text, needle_text), expected_index as isize);
+
+        let needle_slice = text_slice(mem, text, start, len);
+        assert_eq!(text_find(text, needle_slice), expected_index as isize);
+    }
+    let empty = text_of_str(mem, "");
+    assert_eq!(text_find(text, empty), 0);
+    let absent = text_of_str(mem, "#");
+    assert_eq!(text_find(text, absent), -1);
+    assert_eq!(text_find(empty, absent), -1);
+    let longer = text_concat(mem, text, absent);
+    assert_eq!(text_find(text, longer), -1);
+    assert_eq!(text_find(longer, absent), chars.len() as isize);
+}
+
+unsafe fn char_count_test<M: Memory>(mem: &mut M) {
+    // Long blobs are not counted on concatenation, but when the length is first needed
+    let long = "€".repeat(1_000);
+    let long_text = text_of_str(mem, &long);
+    let piece = text_of_str(mem, PIECES[4]);
+    let text = text_concat(mem, long_text, piece);
+    assert_eq!(text.tag(), TAG_COUNTED_CONCAT);
+    assert_eq!(text.as_concat().n_chars(), None);
+    let text = text_concat(mem, text, long_text);
+    assert_eq!(text.as_concat().n_chars(), None);
+    position_test(mem, text);
+    assert_eq!(text.as_concat().n_chars(), Some(2_010));
+
+    // Concat nodes without a count, e.g. persisted before the count was introduced
+    let mut text = text_of_str(mem, "");
+    for i in 0..1_000 {
+        let piece = text_of_str(mem, PIECES[i % PIECES.len()]);
+        text = uncounted_concat(mem, text, piece);
+    }
+    assert_eq!(text.tag(), TAG_CONCAT);
+    assert_eq!(text.as_concat().n_chars(), None);
+    position_test(mem, text);
+    assert_eq!(text.as_concat().n_chars(), None);
+
+    // Counted concat nodes with uncounted parts
+    let piece = text_of_str(mem, PIECES[3]);
+    let text = text_concat(mem, text, piece);
+    assert_eq!(text.tag(), TAG_COUNTED_CONCAT);
+    position_test(mem, text);
+}
+
+/// Concat node with `TAG_CONCAT`, without rebalancing and merging blobs.
+unsafe fn uncounted_concat<M: Memory>(mem: &mut M, s1: Value, s2: Value) -> Value {
+    let r = mem.alloc_words(size_of::<Concat>());
+    let concat = r.get_ptr() as *mut Concat;
+    (*concat).header.tag = TAG_CONCAT;
+    (*concat).header.init_forward(r);
+    (*concat).n_bytes = text_size(s1) + text_size(s2);
+    (*concat).text1 = s1;
+    (*concat).text2 = s2;
+    r
+}
+
+/// Checks length, size, comparison and iteration of a text.
+unsafe fn check_text<M: Memory>(mem: &mut M, text: Value, expected: &str) {
+    assert_eq!(text_len(text), expected.chars().count());
+    assert_eq!(text_size(text), Bytes(expected.len()));
+
+    let expected_text = text_of_str(mem, expected);
+    assert_eq!(text_compare(text, expected_text), 0);
+    assert_eq!(text_compare(expected_text, text), 0);
+
+    let smaller = text_of_str(mem, &expected[..expected.len() - 1]);
+    assert_eq!(text_compare(text, smaller), 1);
+    assert_eq!(text_compare(smaller, text), -1);
+
+    let blob = blob_of_text(mem, text);
+    assert_eq!(text_compare(blob, expected_text), 0);
+
+    assert!(TextIter::from_text(mem, text).eq(expected.chars()));
+}
+
+/// Depth of the concat nodes of a text.
+unsafe fn rope_depth(text: Value) -> usize {
+    let mut max_depth = 0;
+    let mut todo = vec![(text, 0)];
+    while let Some((text, depth)) = todo.pop() {
+        if is_concat_tag(text.tag()) {
+            let concat = text.as_concat();
+            todo.push((concat.text1(), depth + 1));
+            todo.push((concat.text2(), depth + 1));
+        } else {
+            max_depth = max_depth.max(depth);
+        }
+    }
+    max_depth
+}
+
 unsafe fn concat1<M: Memory>(mem: &mut M) {
     // A simple test extracted from a QuickCheck generated test case
     let strs = ["a", "öabcdef", "y"];
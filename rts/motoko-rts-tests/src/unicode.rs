@@ -0,0 +1,192 @@
+//! Unicode normalization and case folding tests
+
+use crate::memory::initialize_test_memory;
+
+use std::collections::HashSet;
+
+use motoko_rts::memory::Memory;
+use motoko_rts::text::{
+    text_case_fold, text_compare, text_concat, text_nfc, text_nfd, text_nfkc, text_nfkd,
+    text_of_str,
+};
+use motoko_rts::types::{TAG_COUNTED_CONCAT, Value};
+use motoko_rts::unicode::{
+    NormalizationForm, UNICODE_VERSION, case_fold, normalize, simple_case_fold, simple_lowercase,
+    simple_uppercase,
+};
+
+use NormalizationForm::*;
+
+/// The normalization conformance test of the Unicode version of the tables, from
+/// https://www.unicode.org/Public/<version>/ucd/NormalizationTest.txt. Its path is provided by
+/// nix, see `unicode-normalization-test` in `flake.nix`.
+static NORMALIZATION_TEST: &str = include_str!(env!(
+    "NORMALIZATION_TEST",
+    "NORMALIZATION_TEST not set. Are you running this in a nix-shell?"
+));
+
+// (source, full case folding)
+static CASE_FOLDING_TESTS: [(&str, &str); 7] = [
+    ("Motoko", "motoko"),
+    ("Stra\u{df}e", "strasse"),
+    ("STRASSE", "strasse"),
+    ("\u{1e9e}", "ss"),
+    ("\u{3a3}\u{391}\u{3a3}", "\u{3c3}\u{3b1}\u{3c3}"),
+    ("\u{fb01}", "fi"),
+    ("\u{130}", "i\u{307}"),
+];
+
+pub unsafe fn test() {
+    println!("Testing Unicode normalization and case folding ...");
+
+    println!("  Testing normalization conformance");
+    normalization_conformance_test();
+
+    println!("  Testing normalization stability");
+    // All code points in chunks, as each character composes with its predecessor at most
+    let chars: Vec<char> = (0..=0x10FFFF).filter_map(char::from_u32).collect();
+    for chunk in chars.chunks(64) {
+        let source: String = chunk.iter().collect();
+        for form in [Nfc, Nfd, Nfkc, Nfkd] {
+            let normalized = normalize(&source, form);
+            assert_eq!(normalize(&normalized, form), normalized);
+        }
+        assert_eq!(
+            normalize(&normalize(&source, Nfd), Nfc),
+            normalize(&source, Nfc)
+        );
+        assert_eq!(
+            normalize(&normalize(&source, Nfkd), Nfkc),
+            normalize(&source, Nfkc)
+        );
+    }
+
+    println!("  Testing case folding");
+    for (source, folded) in CASE_FOLDING_TESTS.iter() {
+        assert_eq!(case_fold(source), *folded);
+    }
+
+    println!("  Testing simple case mappings");
+    assert_eq!(simple_uppercase('\u{df}'), '\u{df}');
+    assert_eq!(simple_uppercase('\u{1f3}'), '\u{1f1}');
+    assert_eq!(simple_uppercase('\u{1fb3}'), '\u{1fbc}');
+    assert_eq!(simple_uppercase('\u{149}'), '\u{149}');
+    assert_eq!(simple_lowercase('\u{130}'), 'i');
+    assert_eq!(simple_lowercase('\u{3a3}'), '\u{3c3}');
+    assert_eq!(simple_case_fold('\u{1e9e}'), '\u{df}');
+    assert_eq!(simple_case_fold('\u{1f88}'), '\u{1f80}');
+    assert_eq!(simple_case_fold('\u{212a}'), 'k');
+    assert_eq!(simple_case_fold('\u{17f}'), 's');
+    assert_eq!(simple_case_fold('\u{130}'), '\u{130}');
+    for c in (0..=0x10FFFF).filter_map(char::from_u32) {
+        let folded = case_fold(&c.to_string());
+        if folded.chars().count() == 1 {
+            assert_eq!(simple_case_fold(c).to_string(), folded);
+        }
+    }
+
+    println!("  Testing text normalization and case folding");
+    let mut mem = initialize_test_memory();
+    text_test(&mut mem);
+}
+
+fn normalization_conformance_test() {
+    let (major, minor, patch) = UNICODE_VERSION;
+    let header = format!("# NormalizationTest-{major}.{minor}.{patch}.txt");
+    assert!(NORMALIZATION_TEST.starts_with(&header));
+
+    let mut part = "";
+    // Characters of the character by character test, part 1
+    let mut listed = HashSet::new();
+    for line in NORMALIZATION_TEST.lines() {
+        let line = line.split('#').next().unwrap().trim();
+        if line.is_empty() {
+            continue;
+        }
+        if line.starts_with('@') {
+            part = line;
+            continue;
+        }
+
+        let columns: Vec<String> = line
+            .split(';')
+            .take(5)
+            .map(|column| {
+                column
+                    .split_whitespace()
+                    .map(|c| char::from_u32(u32::from_str_radix(c, 16).unwrap()).unwrap())
+                    .collect()
+            })
+            .collect();
+        let [source, nfc, nfd, nfkc, nfkd] = columns.as_slice() else {
+            panic!("Invalid normalization test: {line}");
+        };
+        if part == "@Part1" {
+            let mut chars = source.chars();
+            listed.insert(chars.next().unwrap());
+            assert_eq!(chars.next(), None);
+        }
+
+        for column in [source, nfc, nfd] {
+            assert_eq!(normalize(column, Nfc), *nfc);
+            assert_eq!(normalize(column, Nfd), *nfd);
+        }
+        for column in [nfkc, nfkd] {
+            assert_eq!(normalize(column, Nfc), *nfkc);
+            assert_eq!(normalize(column, Nfd), *nfkd);
+        }
+        for column in [source, nfc, nfd, nfkc, nfkd] {
+            assert_eq!(normalize(column, Nfkc), *nfkc);
+            assert_eq!(normalize(column, Nfkd), *nfkd);
+        }
+    }
+
+    // All other characters are invariant
+    for c in (0..=0x10FFFF).filter_map(char::from_u32) {
+        if !listed.contains(&c) {
+            let source = c.to_string();
+            for form in [Nfc, Nfd, Nfkc, Nfkd] {
+                assert_eq!(normalize(&source, form), source);
+            }
+        }
+    }
+}
+
+unsafe fn text_test<M: Memory>(mem: &mut M) {
+    // ASCII text is returned as is
+    let ascii = text_of_str(mem, "Motoko");
+    assert_eq!(text_nfc(mem, ascii), ascii);
+    assert_eq!(text_nfkd(mem, ascii), ascii);
+
+    // A combining mark in a separate rope part
+    let prefix = text_of_str(
+        mem,
+        "Cafe and r\u{e9}sum\u{e9}, in a text too long to be copied on concatenation, cafe",
+    );
+    let accent = text_of_str(mem, "\u{301}");
+    let text = text_concat(mem, prefix, accent);
+    assert_eq!(text.tag(), TAG_COUNTED_CONCAT);
+    let normalized = text_nfc(mem, text);
+    check_text(
+        mem,
+        normalized,
+        "Cafe and r\u{e9}sum\u{e9}, in a text too long to be copied on concatenation, caf\u{e9}",
+    );
+    let normalized = text_nfd(mem, text);
+    check_text(
+        mem,
+        normalized,
+        "Cafe and re\u{301}sume\u{301}, in a text too long to be copied on concatenation, cafe\u{301}",
+    );
+
+    let text = text_of_str(mem, "\u{fb01}\u{b2} STRA\u{df}E");
+    let normalized = text_nfkc(mem, text);
+    check_text(mem, normalized, "fi2 STRA\u{df}E");
+    let folded = text_case_fold(mem, text);
+    check_text(mem, folded, "fi\u{b2} strasse");
+}
+
+unsafe fn check_text<M: Memory>(mem: &mut M, text: Value, expected: &str) {
+    let expected = text_of_str(mem, expected);
+    assert_eq!(text_compare(text, expected), 0);
+}
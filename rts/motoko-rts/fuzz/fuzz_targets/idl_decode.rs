@@ -0,0 +1,88 @@
+//! Decode arbitrary Candid messages: parse the header and skip all argument values.
+//!
+//! Malformed messages must trap. Well-formed type tables must render, and, unless they contain
+//! future types, every type must be a sub-type of itself and memory-compatible with itself.
+
+#![no_main]
+// Edition-2024 defer: rewriting every unsafe-op-in-unsafe-fn call site is a separate task.
+#![allow(unsafe_op_in_unsafe_fn)]
+
+use libfuzzer_sys::fuzz_target;
+use motoko_rts::bitrel::BitRel;
+use motoko_rts::buf::Buf;
+use motoko_rts::idl::{
+    IncompatibilityTrace, TypeTable, TypeVariance, memory_compatible, skip_any, sub,
+};
+use motoko_rts::leb128::sleb128_decode;
+use motoko_rts_fuzz::{Message, parse_message, run};
+
+/// Lowest opcode of the type table entries known to Candid, i.e. `principal`.
+const IDL_LOWEST_KNOWN: isize = -24;
+
+fuzz_target!(|bytes: &[u8]| {
+    let Some(message) = run(|| unsafe { decode(bytes) }) else {
+        return;
+    };
+    let _ = TypeTable::new(message.typtbl, message.typtbl_size, message.end).to_string();
+    unsafe {
+        if !has_future_types(&message) {
+            check_reflexivity(&message);
+        }
+    }
+});
+
+unsafe fn decode(bytes: &[u8]) -> Message {
+    let mut message = parse_message(bytes);
+    for &t in &message.main_types {
+        skip_any(&mut message.values, message.typtbl, t, 0);
+    }
+    message
+}
+
+/// Future types are not related to any type, not even to themselves.
+unsafe fn has_future_types(message: &Message) -> bool {
+    (0..message.typtbl_size).any(|index| {
+        let mut buf = Buf {
+            ptr: *message.typtbl.add(index),
+            end: message.end,
+        };
+        sleb128_decode(&mut buf) < IDL_LOWEST_KNOWN
+    })
+}
+
+unsafe fn check_reflexivity(message: &Message) {
+    let (typtbl, end, size) = (message.typtbl, message.end, message.typtbl_size);
+    let mut cache = vec![0usize; BitRel::words(size, size)];
+    let mut compatibility_cache = vec![0usize; BitRel::words(size, size)];
+    let rel = new_cache(&mut cache, size, size);
+    let compatibility_rel = new_cache(&mut compatibility_cache, size, size);
+    for t in (0..size as i32).chain(message.main_types.iter().copied()) {
+        assert!(sub(&rel, true, typtbl, typtbl, end, end, t, t));
+        let mut trace = IncompatibilityTrace::new();
+        let variance = TypeVariance::Covariance;
+        assert!(memory_compatible(
+            &compatibility_rel,
+            variance,
+            typtbl,
+            typtbl,
+            end,
+            end,
+            t,
+            t,
+            false,
+            &mut trace
+        ));
+    }
+}
+
+unsafe fn new_cache(cache: &mut [usize], size1: usize, size2: usize) -> BitRel {
+    let range = cache.as_mut_ptr_range();
+    let rel = BitRel {
+        ptr: range.start,
+        end: range.end,
+        size1,
+        size2,
+    };
+    rel.init();
+    rel
+}
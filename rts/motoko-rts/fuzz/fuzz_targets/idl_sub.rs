@@ -0,0 +1,71 @@
+//! Check the sub-type and memory compatibility relations on pairs of Candid type tables.
+//!
+//! The input is split into two messages, the first one prefixed by its length as a 16-bit
+//! little-endian number. Only the headers are decoded. The relations must not trap on
+//! well-formed type tables, whatever their outcome.
+
+#![no_main]
+// Edition-2024 defer: rewriting every unsafe-op-in-unsafe-fn call site is a separate task.
+#![allow(unsafe_op_in_unsafe_fn)]
+
+use libfuzzer_sys::fuzz_target;
+use motoko_rts::bitrel::BitRel;
+use motoko_rts::idl::{IncompatibilityTrace, TypeVariance, memory_compatible, sub};
+use motoko_rts_fuzz::{parse_message, run};
+
+fuzz_target!(|bytes: &[u8]| {
+    let Some((length, rest)) = bytes.split_first_chunk::<2>() else {
+        return;
+    };
+    let length = u16::from_le_bytes(*length) as usize;
+    if length > rest.len() {
+        return;
+    }
+    let (first, second) = rest.split_at(length);
+    let Some(message1) = run(|| unsafe { parse_message(first) }) else {
+        return;
+    };
+    let Some(message2) = run(|| unsafe { parse_message(second) }) else {
+        return;
+    };
+
+    let (size1, size2) = (message1.typtbl_size, message2.typtbl_size);
+    let (typtbl1, typtbl2) = (message1.typtbl, message2.typtbl);
+    let (end1, end2) = (message1.end, message2.end);
+    let types = message1.main_types.iter().zip(message2.main_types.iter());
+    unsafe {
+        let mut cache = vec![0usize; BitRel::words(size1, size2)];
+        let mut compatibility_cache = vec![0usize; BitRel::words(size1, size2)];
+        let rel = new_cache(&mut cache, size1, size2);
+        let compatibility_rel = new_cache(&mut compatibility_cache, size1, size2);
+        for (&t1, &t2) in types {
+            sub(&rel, true, typtbl1, typtbl2, end1, end2, t1, t2);
+            let mut trace = IncompatibilityTrace::new();
+            let variance = TypeVariance::Covariance;
+            memory_compatible(
+                &compatibility_rel,
+                variance,
+                typtbl1,
+                typtbl2,
+                end1,
+                end2,
+                t1,
+                t2,
+                false,
+                &mut trace,
+            );
+        }
+    }
+});
+
+unsafe fn new_cache(cache: &mut [usize], size1: usize, size2: usize) -> BitRel {
+    let range = cache.as_mut_ptr_range();
+    let rel = BitRel {
+        ptr: range.start,
+        end: range.end,
+        size1,
+        size2,
+    };
+    rel.init();
+    rel
+}
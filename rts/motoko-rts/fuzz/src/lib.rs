@@ -0,0 +1,155 @@
+//! Shared support for the RTS fuzz targets.
+//!
+//! The RTS is linked natively, as in the RTS unit tests, with the host functions otherwise
+//! provided by the compiler-generated code or the IC. Traps unwind, so that `run` can
+//! recover from them.
+
+// Edition-2024 defer: rewriting every unsafe-op-in-unsafe-fn call site is a separate task.
+#![allow(unsafe_op_in_unsafe_fn)]
+
+use std::cell::Cell;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Once;
+
+use motoko_rts::buf::Buf;
+use motoko_rts::idl::parse_idl_header;
+use motoko_rts::leb128::{leb128_decode, sleb128_decode};
+use motoko_rts::memory::Memory;
+use motoko_rts::types::{Value, Words};
+
+/// Called by the RTS to trap.
+#[unsafe(no_mangle)]
+extern "C-unwind" fn rts_trap(ptr: *const u8, len: u32) -> ! {
+    let message = unsafe { std::slice::from_raw_parts(ptr, len as usize) };
+    panic!("{}", String::from_utf8_lossy(message))
+}
+
+/// Called by the RTS BigInt functions, which are not fuzzed.
+#[unsafe(no_mangle)]
+extern "C" fn bigint_trap() -> ! {
+    std::process::abort()
+}
+
+/// Called by the RTS for debug prints.
+#[unsafe(no_mangle)]
+extern "C" fn print_ptr(_ptr: usize, _len: usize) {}
+
+thread_local! {
+    static VALUE_QUOTA: Cell<u64> = const { Cell::new(0) };
+    static RUNNING: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Decoding limit check, with the same quota as the compiler-generated code: one value per
+/// message byte plus a fixed bias.
+#[unsafe(no_mangle)]
+extern "C-unwind" fn idl_limit_check(decrement: bool, value_count: u64) {
+    VALUE_QUOTA.with(|quota| {
+        if quota.get() < value_count {
+            panic!("IDL error: exceeded value limit");
+        }
+        if decrement {
+            quota.set(quota.get() - value_count);
+        }
+    });
+}
+
+const VALUE_BIAS: u64 = 1024;
+
+/// Heap of a single message, holding the type table allocated by `parse_idl_header`.
+pub struct FuzzMemory {
+    heap: Vec<usize>,
+    hp: usize,
+}
+
+impl FuzzMemory {
+    /// Large enough for the type table of a message of `size` bytes.
+    fn new(size: usize) -> Self {
+        FuzzMemory {
+            heap: vec![0; size + 16],
+            hp: 0,
+        }
+    }
+}
+
+impl Memory for FuzzMemory {
+    unsafe fn alloc_words(&mut self, n: Words<usize>) -> Value {
+        let words = &mut self.heap[self.hp..self.hp + n.as_usize()];
+        self.hp += n.as_usize();
+        Value::from_ptr(words.as_mut_ptr() as usize)
+    }
+
+    unsafe fn grow_memory(&mut self, _ptr: usize) {}
+}
+
+/// Run `f` on input, returning `None` if the RTS traps or panics, which both end in a trap
+/// on the IC. Panics outside of `run` are crashes.
+pub fn run<R>(f: impl FnOnce() -> R) -> Option<R> {
+    static HOOK: Once = Once::new();
+    HOOK.call_once(|| {
+        let default_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !RUNNING.with(|running| running.get()) {
+                default_hook(info);
+            }
+        }));
+    });
+    RUNNING.with(|running| running.set(true));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    RUNNING.with(|running| running.set(false));
+    result.ok()
+}
+
+/// A Candid message after parsing the header.
+pub struct Message {
+    pub typtbl: *mut *mut u8,
+    pub typtbl_size: usize,
+    pub main_types: Vec<i32>,
+    pub values: Buf,
+    pub end: *mut u8,
+    _heap: FuzzMemory,
+}
+
+/// Parse the header of `bytes` and reset the value quota for the message. Traps on
+/// malformed input.
+///
+/// # Safety
+///
+/// The message points into `bytes`, which must outlive it.
+pub unsafe fn parse_message(bytes: &[u8]) -> Message {
+    VALUE_QUOTA.with(|quota| quota.set(bytes.len() as u64 + VALUE_BIAS));
+
+    let start = bytes.as_ptr() as *mut u8;
+    let end = start.wrapping_add(bytes.len());
+    let mut values = Buf { ptr: start, end };
+    let mut typtbl = std::ptr::null_mut();
+    let mut typtbl_size = 0;
+    let mut main_types = std::ptr::null_mut();
+    let mut heap = FuzzMemory::new(bytes.len());
+    parse_idl_header(
+        &mut heap,
+        false,
+        &mut values,
+        bytes.len(),
+        &mut typtbl,
+        &mut typtbl_size,
+        &mut main_types,
+    );
+
+    let mut main_types_buf = Buf {
+        ptr: main_types,
+        end,
+    };
+    let count = leb128_decode(&mut main_types_buf);
+    let main_types = (0..count)
+        .map(|_| sleb128_decode(&mut main_types_buf) as i32)
+        .collect();
+
+    Message {
+        typtbl,
+        typtbl_size,
+        main_types,
+        values,
+        end,
+        _heap: heap,
+    }
+}
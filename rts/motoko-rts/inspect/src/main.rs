@@ -0,0 +1,85 @@
+//! Prints a report of a graph-copy stabilization snapshot in a stable memory image.
+//!
+//! Usage: `inspect-stable-memory <stable-memory-file>`
+//!
+//! The file is the raw stable memory of a canister, e.g. downloaded from a canister
+//! snapshot, and is only read. The RTS is linked natively, as in the RTS unit tests,
+//! with the stable memory functions of the IC served from the file.
+
+use std::process::exit;
+use std::sync::OnceLock;
+
+use motoko_rts::stabilization::inspection::{Snapshot, write_report};
+
+const PAGE_SIZE: usize = 64 * 1024;
+
+static STABLE_MEMORY: OnceLock<Vec<u8>> = OnceLock::new();
+
+fn stable_memory() -> &'static [u8] {
+    STABLE_MEMORY.get().expect("stable memory not loaded")
+}
+
+#[unsafe(no_mangle)]
+extern "C" fn ic0_stable64_size() -> u64 {
+    (stable_memory().len() / PAGE_SIZE) as u64
+}
+
+#[unsafe(no_mangle)]
+extern "C" fn ic0_stable64_read(destination: u64, offset: u64, size: u64) {
+    let source = offset
+        .checked_add(size)
+        .and_then(|end| stable_memory().get(offset as usize..end as usize))
+        .unwrap_or_else(|| fail("read beyond the end of stable memory"));
+    let destination =
+        unsafe { std::slice::from_raw_parts_mut(destination as *mut u8, size as usize) };
+    destination.copy_from_slice(source);
+}
+
+/// Called by the RTS to trap.
+#[unsafe(no_mangle)]
+extern "C" fn rts_trap(ptr: *const u8, len: u32) -> ! {
+    let message = unsafe { std::slice::from_raw_parts(ptr, len as usize) };
+    fail(&String::from_utf8_lossy(message))
+}
+
+/// Called by the RTS BigInt functions, which are not used by the inspection.
+#[unsafe(no_mangle)]
+extern "C" fn bigint_trap() -> ! {
+    fail("unexpected BigInt operation")
+}
+
+/// Called by the RTS for debug prints.
+#[unsafe(no_mangle)]
+extern "C" fn print_ptr(ptr: usize, len: usize) {
+    let message = unsafe { std::slice::from_raw_parts(ptr as *const u8, len) };
+    eprintln!("{}", String::from_utf8_lossy(message));
+}
+
+fn fail(message: &str) -> ! {
+    eprintln!("inspect-stable-memory: {message}");
+    exit(1)
+}
+
+fn main() {
+    let arguments: Vec<String> = std::env::args().skip(1).collect();
+    let [path] = arguments.as_slice() else {
+        eprintln!("Usage: inspect-stable-memory <stable-memory-file>");
+        exit(2);
+    };
+    let mut content =
+        std::fs::read(path).unwrap_or_else(|error| fail(&format!("cannot read {path}: {error}")));
+    if content.len() % PAGE_SIZE != 0 {
+        // Stable memory consists of whole pages, with the last page record at the very end.
+        fail(&format!(
+            "{path}: size {} is not a multiple of the page size {PAGE_SIZE}",
+            content.len()
+        ));
+    }
+    content.shrink_to_fit();
+    STABLE_MEMORY.set(content).unwrap();
+
+    let snapshot = Snapshot::open().unwrap_or_else(|error| fail(&format!("{path}: {error}")));
+    let mut report = String::new();
+    write_report(&mut report, &snapshot).unwrap_or_else(|_| fail("cannot format the report"));
+    print!("{report}");
+}
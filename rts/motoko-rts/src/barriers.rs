@@ -2,6 +2,8 @@ use motoko_rts_macros::{incremental_gc, non_incremental_gc};
 
 use crate::{memory::Memory, types::Value};
 
+/// Initializing a new object does not need to be recorded for the young generation of the
+/// incremental GC, as the object is either young or a huge object scanned by young collections.
 #[incremental_gc]
 pub unsafe fn init_with_barrier<M: Memory>(_mem: &mut M, location: *mut Value, value: Value) {
     *location = value.forward_if_possible();
@@ -11,13 +11,11 @@ pub struct Buf {
 }
 
 impl Buf {
-    #[cfg(feature = "ic")]
     pub(crate) unsafe fn advance(self: *mut Self, n: usize) {
         advance(self, n)
     }
 
     /// Restrict the read area
-    #[cfg(feature = "ic")]
     pub(crate) unsafe fn limit_size(self: *mut Self, lim: usize) -> *mut u8 {
         let prev = (*self).end;
         if lim < self.size() {
@@ -26,7 +24,6 @@ impl Buf {
         prev
     }
 
-    #[cfg(feature = "ic")]
     pub(crate) unsafe fn size(self: *const Self) -> usize {
         (*self).end.offset_from_unsigned((*self).ptr)
     }
@@ -44,7 +41,6 @@ pub(crate) unsafe fn read_byte(buf: *mut Buf) -> u8 {
     byte
 }
 
-#[cfg(feature = "ic")]
 /// Read a little-endian word
 pub(crate) unsafe fn read_word(buf: *mut Buf) -> u32 {
     // IDL buffer is still 32-bit-based.
@@ -64,7 +60,6 @@ pub(crate) unsafe fn read_word(buf: *mut Buf) -> u32 {
     word
 }
 
-#[cfg(feature = "ic")]
 unsafe fn advance(buf: *mut Buf, n: usize) {
     if n > buf.size() {
         idl_trap_with("advance out of buffer");
@@ -74,9 +69,8 @@ unsafe fn advance(buf: *mut Buf, n: usize) {
 }
 
 /// Can also be used for sleb
-#[cfg(feature = "ic")]
 #[unsafe(no_mangle)]
-pub(crate) unsafe extern "C" fn skip_leb128(buf: *mut Buf) {
+pub(crate) unsafe extern "C-unwind" fn skip_leb128(buf: *mut Buf) {
     loop {
         let byte = read_byte(buf);
         if byte & 0b1000_0000 == 0 {
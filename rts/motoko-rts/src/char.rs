@@ -1,23 +1,22 @@
+#[cfg(feature = "ic")]
+use crate::unicode::{simple_case_fold, simple_lowercase, simple_uppercase};
+
 #[cfg(feature = "ic")]
 #[unsafe(no_mangle)]
 unsafe extern "C" fn char_to_upper(c: u32) -> u32 {
-    let mut upper_chars = core::char::from_u32_unchecked(c).to_uppercase();
-    if upper_chars.len() == 1 {
-        upper_chars.next().unwrap() as u32
-    } else {
-        c
-    }
+    simple_uppercase(core::char::from_u32_unchecked(c)) as u32
 }
 
 #[cfg(feature = "ic")]
 #[unsafe(no_mangle)]
 unsafe extern "C" fn char_to_lower(c: u32) -> u32 {
-    let mut lower_chars = core::char::from_u32_unchecked(c).to_lowercase();
-    if lower_chars.len() == 1 {
-        lower_chars.next().unwrap() as u32
-    } else {
-        c
-    }
+    simple_lowercase(core::char::from_u32_unchecked(c)) as u32
+}
+
+#[cfg(feature = "ic")]
+#[unsafe(no_mangle)]
+unsafe extern "C" fn char_case_fold(c: u32) -> u32 {
+    simple_case_fold(core::char::from_u32_unchecked(c)) as u32
 }
 
 #[cfg(feature = "ic")]
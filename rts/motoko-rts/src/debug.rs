@@ -225,7 +225,7 @@ pub(crate) unsafe fn print_boxed_object(buf: &mut WriteBuf, p: usize) {
             // Add more details here as needed
             let _ = write!(buf, "<BigInt>");
         }
-        TAG_CONCAT => {
+        TAG_CONCAT | TAG_COUNTED_CONCAT => {
             let concat = obj.as_concat();
             let _ = write!(
                 buf,
@@ -1,7 +1,11 @@
 use crate::memory::Memory;
-use crate::text::text_of_ptr_size;
+use crate::print::WriteBuf;
+use crate::text::{blob_of_text, text_of_ptr_size};
 use crate::types::{Bytes, Value};
 
+use core::fmt::{self, Write};
+use core::{slice, str};
+
 use motoko_rts_macros::ic_mem_fn;
 
 // The meaning of the `mode` parameter is documented in motoko-base, function Float.format()
@@ -19,13 +23,12 @@ pub unsafe fn float_fmt<M: Memory>(mem: &mut M, a: f64, prec: usize, mode: usize
         0 => format!(BUFFER_LENGTH, "{:.*}", prec, a),
         1 => format!(BUFFER_LENGTH, "{:.*e}", prec, a),
         2 => format!(BUFFER_LENGTH, "{:.*}", prec, a),
-        3 => panic!("float_fmt: unsupported mode"), // Deprecated in the base library.
+        3 => format!(BUFFER_LENGTH, "{}", HexFloat(a, prec)),
         4 => format!(BUFFER_LENGTH, "{}", a),
+        5 => format!(BUFFER_LENGTH, "{}", ShortestFloat(a)),
         _ => panic!("float_fmt: unrecognized mode"),
     };
 
-    // TODO: Certain modes are not supported such as hexadecimal output (mode 3).
-
     let length = written_length(&buffer);
     text_of_ptr_size(mem, buffer.as_ptr(), Bytes(length))
 }
@@ -39,55 +42,301 @@ fn written_length(buffer: &[u8]) -> usize {
     buffer.len()
 }
 
-#[unsafe(no_mangle)]
-pub fn pow(a: f64, b: f64) -> f64 {
-    libm::pow(a, b)
-}
-#[unsafe(no_mangle)]
-pub fn powf(a: f32, b: f32) -> f32 {
-    libm::powf(a, b)
-}
-#[unsafe(no_mangle)]
-pub fn tan(a: f64) -> f64 {
-    libm::tan(a)
-}
-#[unsafe(no_mangle)]
-pub fn asin(a: f64) -> f64 {
-    libm::asin(a)
-}
-#[unsafe(no_mangle)]
-pub fn acos(a: f64) -> f64 {
-    libm::acos(a)
-}
-#[unsafe(no_mangle)]
-pub fn atan(a: f64) -> f64 {
-    libm::atan(a)
-}
-#[unsafe(no_mangle)]
-pub fn atan2(a: f64, b: f64) -> f64 {
-    libm::atan2(a, b)
+/// Hexadecimal format with `prec` fraction digits, like `%.*h` of OCaml's `Printf`,
+/// e.g. `0x1.8p+1` for 3.0 with precision 1.
+pub struct HexFloat(pub f64, pub usize);
+
+impl fmt::Display for HexFloat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let HexFloat(value, prec) = *self;
+        let bits = value.to_bits();
+        let negative = bits >> 63 != 0;
+        let mut exponent = ((bits >> 52) & 0x7FF) as i32;
+        let mut mantissa = bits & ((1 << 52) - 1);
+        if exponent == 0x7FF {
+            // Like OCaml, without a sign for NaN
+            return f.write_str(match (mantissa, negative) {
+                (0, false) => "infinity",
+                (0, true) => "-infinity",
+                _ => "nan",
+            });
+        }
+        if exponent == 0 {
+            // Zero or subnormal
+            if mantissa != 0 {
+                exponent = -1022;
+            }
+        } else {
+            exponent -= 1023;
+            mantissa |= 1 << 52;
+        }
+        if prec < 13 {
+            // Round to nearest, ties to even, the leading digit may become 2
+            let unit = 1u64 << (52 - 4 * prec);
+            let fraction = mantissa & (unit - 1);
+            mantissa -= fraction;
+            let half = unit >> 1;
+            if fraction > half || (fraction == half && mantissa & unit != 0) {
+                mantissa += unit;
+            }
+        }
+        if negative {
+            f.write_char('-')?;
+        }
+        write!(f, "0x{:x}", mantissa >> 52)?;
+        if prec > 0 {
+            f.write_char('.')?;
+            for index in 0..prec {
+                let digit = if index < 13 {
+                    (mantissa >> (48 - 4 * index)) & 0xF
+                } else {
+                    0
+                };
+                write!(f, "{:x}", digit)?;
+            }
+        }
+        write!(f, "p{:+}", exponent)
+    }
 }
-#[unsafe(no_mangle)]
-pub fn sin(a: f64) -> f64 {
-    libm::sin(a)
+
+/// Shortest decimal representation that parses back to the same value. The notation is fixed,
+/// unless the decimal exponent is below -4 or above 16, e.g. `0.1`, `100`, `1e+100` or `5e-324`.
+pub struct ShortestFloat(pub f64);
+
+/// Exponents of the fixed notation of `ShortestFloat`, up to the significant digits of a float.
+const FIXED_EXPONENTS: core::ops::Range<i32> = -4..17;
+
+impl fmt::Display for ShortestFloat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = self.0;
+        if value.is_nan() {
+            return f.write_str("nan");
+        }
+        if value.is_sign_negative() {
+            f.write_char('-')?;
+        }
+        if value.is_infinite() {
+            return f.write_str("inf");
+        }
+
+        // Rust's exponent notation has the shortest round-trip digits, e.g. `1.25e-7`
+        let mut buffer = [0u8; 32];
+        let mut scientific = WriteBuf::new(&mut buffer);
+        write!(&mut scientific, "{:e}", value.abs())?;
+        let (mantissa, exponent) = scientific.as_str().split_once('e').unwrap();
+        let exponent: i32 = exponent.parse().unwrap();
+        let (leading, fraction) = mantissa.split_at(1);
+        let fraction = fraction.strip_prefix('.').unwrap_or("");
+
+        if !FIXED_EXPONENTS.contains(&exponent) {
+            write!(
+                f,
+                "{}e{}{:02}",
+                mantissa,
+                if exponent < 0 { '-' } else { '+' },
+                exponent.abs()
+            )
+        } else if exponent < 0 {
+            f.write_str("0.")?;
+            for _ in 0..-exponent - 1 {
+                f.write_char('0')?;
+            }
+            write!(f, "{}{}", leading, fraction)
+        } else if fraction.len() > exponent as usize {
+            let (integral, fraction) = fraction.split_at(exponent as usize);
+            write!(f, "{}{}.{}", leading, integral, fraction)
+        } else {
+            write!(f, "{}{}", leading, fraction)?;
+            for _ in fraction.len()..exponent as usize {
+                f.write_char('0')?;
+            }
+            Ok(())
+        }
+    }
 }
-#[unsafe(no_mangle)]
-pub fn cos(a: f64) -> f64 {
-    libm::cos(a)
+
+/// Parses the text as a float and stores it in `result`. Returns 1 on success and 0 if the text
+/// is not a valid float, see `parse_float`.
+#[ic_mem_fn]
+pub unsafe fn float_of_text<M: Memory>(mem: &mut M, text: Value, result: *mut f64) -> usize {
+    let blob = blob_of_text(mem, text).as_blob();
+    let text = str::from_utf8_unchecked(slice::from_raw_parts(
+        blob.payload_const(),
+        blob.len().as_usize(),
+    ));
+    match parse_float(text) {
+        Some(value) => {
+            result.write_unaligned(value);
+            1
+        }
+        None => 0,
+    }
 }
-#[unsafe(no_mangle)]
-pub fn exp(a: f64) -> f64 {
-    libm::exp(a)
+
+/// Parses a decimal float with optional exponent (`-1.5`, `.5`, `2.`, `1e-7`), a hexadecimal
+/// float with optional binary exponent (`0x1.8p+1`, `-0XAp-2`), or `inf`, `infinity` and `nan`
+/// (case-insensitive), each with an optional sign. The result is correctly rounded to the
+/// nearest float, ties to even. Surrounding whitespace and underscores are not allowed.
+pub fn parse_float(text: &str) -> Option<f64> {
+    let (negative, unsigned) = match text.as_bytes().first() {
+        Some(b'-') => (true, &text[1..]),
+        Some(b'+') => (false, &text[1..]),
+        _ => (false, text),
+    };
+    match unsigned.get(..2) {
+        Some("0x" | "0X") => {
+            let value = parse_hex_float(&unsigned[2..])?;
+            Some(if negative { -value } else { value })
+        }
+        _ => text.parse().ok(),
+    }
 }
-#[unsafe(no_mangle)]
-pub fn fmod(a: f64, b: f64) -> f64 {
-    libm::fmod(a, b)
+
+/// Parses the digits and the optional binary exponent of a hexadecimal float.
+fn parse_hex_float(text: &str) -> Option<f64> {
+    let (digits, binary_exponent) = match text.find(['p', 'P']) {
+        Some(index) => (&text[..index], parse_exponent(&text[index + 1..])?),
+        None => (text, 0),
+    };
+
+    // The value is `(mantissa + sticky) * 2^exponent`, where `sticky` is a non-zero fraction below
+    // one if non-zero digits were dropped.
+    let mut mantissa = 0u64;
+    let mut sticky = false;
+    let mut exponent = binary_exponent;
+    let mut digit_count = 0;
+    let mut in_fraction = false;
+    for c in digits.chars() {
+        if c == '.' && !in_fraction {
+            in_fraction = true;
+            continue;
+        }
+        let digit = c.to_digit(16)? as u64;
+        digit_count += 1;
+        if mantissa < 1 << 60 {
+            mantissa = mantissa << 4 | digit;
+            if in_fraction {
+                exponent -= 4;
+            }
+        } else {
+            sticky |= digit != 0;
+            if !in_fraction {
+                exponent += 4;
+            }
+        }
+    }
+    if digit_count == 0 {
+        return None;
+    }
+    if mantissa == 0 {
+        return Some(0.0);
+    }
+
+    // Normalize to `1.xxx * 2^exponent` with the leading bit at bit 63
+    let shift = mantissa.leading_zeros();
+    let mantissa = mantissa << shift;
+    let exponent = exponent + 63 - shift as i64;
+    if exponent > 1023 {
+        return Some(f64::INFINITY);
+    }
+
+    // Drop the bits below the 53 bits of a normal float, or more for a subnormal float
+    let dropped = (-1011 - exponent).clamp(11, 100) as u32;
+    let mantissa = mantissa as u128;
+    let mut kept = (mantissa >> dropped) as u64;
+    let remainder = mantissa & ((1 << dropped) - 1);
+    let half = 1 << (dropped - 1);
+    if remainder > half || (remainder == half && (sticky || kept & 1 != 0)) {
+        kept += 1;
+    }
+
+    if exponent < -1022 {
+        // Subnormal, or the smallest normal float if rounded up to 2^52
+        return Some(f64::from_bits(kept));
+    }
+    let (kept, exponent) = if kept == 1 << 53 {
+        (kept >> 1, exponent + 1)
+    } else {
+        (kept, exponent)
+    };
+    if exponent > 1023 {
+        return Some(f64::INFINITY);
+    }
+    Some(f64::from_bits(
+        ((exponent + 1023) as u64) << 52 | (kept & ((1 << 52) - 1)),
+    ))
 }
-#[unsafe(no_mangle)]
-pub fn fmodf(a: f32, b: f32) -> f32 {
-    libm::fmodf(a, b)
+
+/// Parses a signed decimal exponent, saturated to a magnitude beyond any finite float.
+fn parse_exponent(text: &str) -> Option<i64> {
+    let (negative, digits) = match text.as_bytes().first() {
+        Some(b'-') => (true, &text[1..]),
+        Some(b'+') => (false, &text[1..]),
+        _ => (false, text),
+    };
+    if digits.is_empty() {
+        return None;
+    }
+    let mut exponent = 0i64;
+    for c in digits.chars() {
+        exponent = (exponent * 10 + c.to_digit(10)? as i64).min(100_000);
+    }
+    Some(if negative { -exponent } else { exponent })
 }
-#[unsafe(no_mangle)]
-pub fn log(a: f64) -> f64 {
-    libm::log(a)
+
+// The C math functions of the compiled code. Not defined natively, where they are part of libc.
+#[cfg(feature = "ic")]
+mod libm_exports {
+    #[unsafe(no_mangle)]
+    pub fn pow(a: f64, b: f64) -> f64 {
+        libm::pow(a, b)
+    }
+    #[unsafe(no_mangle)]
+    pub fn powf(a: f32, b: f32) -> f32 {
+        libm::powf(a, b)
+    }
+    #[unsafe(no_mangle)]
+    pub fn tan(a: f64) -> f64 {
+        libm::tan(a)
+    }
+    #[unsafe(no_mangle)]
+    pub fn asin(a: f64) -> f64 {
+        libm::asin(a)
+    }
+    #[unsafe(no_mangle)]
+    pub fn acos(a: f64) -> f64 {
+        libm::acos(a)
+    }
+    #[unsafe(no_mangle)]
+    pub fn atan(a: f64) -> f64 {
+        libm::atan(a)
+    }
+    #[unsafe(no_mangle)]
+    pub fn atan2(a: f64, b: f64) -> f64 {
+        libm::atan2(a, b)
+    }
+    #[unsafe(no_mangle)]
+    pub fn sin(a: f64) -> f64 {
+        libm::sin(a)
+    }
+    #[unsafe(no_mangle)]
+    pub fn cos(a: f64) -> f64 {
+        libm::cos(a)
+    }
+    #[unsafe(no_mangle)]
+    pub fn exp(a: f64) -> f64 {
+        libm::exp(a)
+    }
+    #[unsafe(no_mangle)]
+    pub fn fmod(a: f64, b: f64) -> f64 {
+        libm::fmod(a, b)
+    }
+    #[unsafe(no_mangle)]
+    pub fn fmodf(a: f32, b: f32) -> f32 {
+        libm::fmodf(a, b)
+    }
+    #[unsafe(no_mangle)]
+    pub fn log(a: f64) -> f64 {
+        libm::log(a)
+    }
 }
@@ -30,10 +30,13 @@ use self::{
 
 pub mod array_slicing;
 pub mod barriers;
+pub mod census;
+pub mod heap_snapshot;
 pub mod mark_bitmap;
 pub mod mark_stack;
 pub mod partitioned_heap;
 mod phases;
+pub mod policy;
 pub mod roots;
 #[cfg(feature = "memory_check")]
 pub mod sanity_checks;
@@ -41,6 +44,7 @@ pub mod sanity_checks;
 mod scheduling;
 pub mod sort;
 pub mod time;
+pub mod young_generation;
 
 #[ic_mem_fn(ic_only)]
 unsafe fn initialize_incremental_gc<M: Memory>(mem: &mut M) {
@@ -71,6 +75,8 @@ unsafe fn schedule_incremental_gc<M: Memory>(mem: &mut M) {
     let running = state.phase != Phase::Pause;
     if running || scheduling::should_start_gc() {
         incremental_gc(mem);
+    } else {
+        young_generation::schedule_young_collection(mem, state, roots::root_set());
     }
 }
 
@@ -88,6 +94,61 @@ unsafe fn incremental_gc<M: Memory>(mem: &mut M) {
     }
 }
 
+#[cfg(feature = "ic")]
+unsafe extern "C" {
+    fn ic0_performance_counter(number: u32) -> u64;
+}
+
+/// Forced full GC, e.g. for deterministic memory measurements:
+/// Completes the running GC run, if any, and performs one additional entire GC run, such that
+/// all objects that are unreachable at the time of the call are reclaimed.
+/// The GC increments are bounded as usual. `instruction_limit` optionally caps the total number
+/// of instructions (zero for no limit). When the cap is reached, the GC continues in the
+/// subsequently scheduled increments.
+/// Returns the amount of memory reclaimed by the performed increments.
+/// Must be called on an empty call stack.
+#[ic_mem_fn(ic_only)]
+unsafe fn force_incremental_gc<M: Memory>(mem: &mut M, instruction_limit: u64) -> Bytes<u64> {
+    // Only query the instruction counter if needed, as it is unavailable outside the IC.
+    let start_instructions = if instruction_limit == 0 {
+        0
+    } else {
+        ic0_performance_counter(0)
+    };
+    let initially_reclaimed = get_partitioned_heap().reclaimed_size();
+    run_full_gc(
+        || incremental_gc(mem),
+        || {
+            instruction_limit != 0
+                && ic0_performance_counter(0) - start_instructions >= instruction_limit
+        },
+    );
+    get_partitioned_heap().reclaimed_size() - initially_reclaimed
+}
+
+/// Drive the GC increments of a forced full GC, see `force_incremental_gc`.
+/// `limit_exceeded` is checked before each increment.
+/// Returns whether the full GC has been completed within the limit.
+pub unsafe fn run_full_gc<I: FnMut(), L: FnMut() -> bool>(
+    mut increment: I,
+    mut limit_exceeded: L,
+) -> bool {
+    let phase = &get_incremental_gc_state().phase;
+    assert!(*phase != Phase::Stop);
+    let mut pending_runs = if *phase == Phase::Pause { 1 } else { 2 };
+    while pending_runs > 0 {
+        if limit_exceeded() {
+            return false;
+        }
+        increment();
+        // An increment never starts a new GC run after completing a run.
+        if get_incremental_gc_state().phase == Phase::Pause {
+            pending_runs -= 1;
+        }
+    }
+    true
+}
+
 #[cfg(feature = "ic")]
 unsafe fn record_gc_start<M: Memory>() {
     use crate::memory::ic::partitioned_memory;
@@ -130,12 +191,6 @@ struct Statistics {
 /// Finally, all the evacuated and temporary partitions are freed.
 /// The temporary partitions store mark bitmaps.
 
-/// The limit on the GC increment has a fixed base with a linear increase depending on the number of
-/// allocations that were performed during a running GC. The allocation-proportional term adapts
-/// to the allocation rate and helps the GC to reduce reclamation latency.
-const INCREMENT_BASE_LIMIT: usize = 5_000_000; // Increment limit without concurrent allocations.
-const INCREMENT_ALLOCATION_FACTOR: usize = 50; // Additional time factor per concurrent allocation.
-
 // Performance note: Storing the phase-specific state in the enum would be nicer but it is much slower.
 #[derive(PartialEq)]
 #[repr(C)]
@@ -208,9 +263,13 @@ impl<'a, M: Memory + 'a> IncrementalGC<'a, M> {
     /// This is because the memory implementation is not stored as global variable.
     pub unsafe fn instance(mem: &'a mut M, state: &'a mut State) -> IncrementalGC<'a, M> {
         debug_assert!(state.partitioned_heap.is_initialized());
+        // The limit on the GC increment has a fixed base with a linear increase depending on the number of
+        // allocations that were performed during a running GC. The allocation-proportional term adapts
+        // to the allocation rate and helps the GC to reduce reclamation latency.
+        let policy = policy::get_scheduling_policy();
         let limit = usize::saturating_add(
-            INCREMENT_BASE_LIMIT,
-            usize::saturating_mul(state.allocation_count, INCREMENT_ALLOCATION_FACTOR),
+            policy.increment_base_limit,
+            usize::saturating_mul(state.allocation_count, policy.increment_allocation_factor),
         );
         state.allocation_count = 0;
         let time = BoundedTime::new(limit);
@@ -257,9 +316,14 @@ impl<'a, M: Memory + 'a> IncrementalGC<'a, M> {
         debug_assert!(self.pausing());
 
         self.state.phase = Phase::Mark;
+        young_generation::dissolve_nursery();
+        census::start_census();
         MarkIncrement::start_phase(self.mem, self.state, &mut self.time);
         let mut increment = MarkIncrement::instance(self.mem, self.state, &mut self.time);
         increment.mark_roots(roots);
+        if let Some(snapshot) = heap_snapshot::pending_snapshot() {
+            increment.mark_object(snapshot);
+        }
     }
 
     unsafe fn mark_completed(&mut self) -> bool {
@@ -282,7 +346,9 @@ impl<'a, M: Memory + 'a> IncrementalGC<'a, M> {
         self.check_mark_completion(roots);
         debug_assert!(self.mark_completed());
         MarkIncrement::<M>::complete_phase(self.mem, self.state);
+        census::publish_census();
         self.state.phase = Phase::Evacuate;
+        heap_snapshot::record_if_requested(self.mem, self.state, roots);
         EvacuationIncrement::<M>::start_phase(self.mem, self.state);
     }
 
@@ -307,6 +373,7 @@ impl<'a, M: Memory + 'a> IncrementalGC<'a, M> {
     /// Only to be called when the call stack is empty as pointers on stack are not updated.
     unsafe fn complete_run(&mut self, roots: Roots) {
         debug_assert!(self.updating_completed());
+        heap_snapshot::forward_snapshot();
         UpdateIncrement::complete_phase(self.state);
         self.state.phase = Phase::Pause;
         self.check_update_completion(roots);
@@ -465,6 +532,7 @@ pub unsafe fn get_max_live_size() -> Bytes<usize> {
 pub unsafe fn stop_gc() {
     let state = get_incremental_gc_state();
     state.phase = Phase::Stop;
+    young_generation::dissolve_nursery();
 }
 
 /// Resume the stopped GC. Called after completed destabilization.
@@ -481,6 +549,12 @@ pub unsafe fn is_gc_stopped() -> bool {
     get_incremental_gc_state().phase == Phase::Stop
 }
 
+/// Determines whether a GC run has been started and not yet completed.
+pub unsafe fn is_gc_running() -> bool {
+    let phase = &get_incremental_gc_state().phase;
+    *phase != Phase::Pause && *phase != Phase::Stop
+}
+
 /// Safety guard before Candid-stabilization with classical persistence.
 /// For graph copying, a different GC stop function is used, see
 /// `stabilization::ic::stop_gc_before_stabilization()`.
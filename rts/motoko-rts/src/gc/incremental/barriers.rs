@@ -1,5 +1,5 @@
 //! Barriers for the incremental GC used by the compiler:
-//! * Write barrier
+//! * Write barrier, also serving the optional young generation
 //! * Allocation barrier
 
 use motoko_rts_macros::ic_mem_fn;
@@ -11,12 +11,15 @@ use crate::{
 
 use super::{
     Phase, count_allocation, get_incremental_gc_state, post_allocation_barrier, pre_read_barrier,
-    pre_write_barrier,
+    pre_write_barrier, young_generation,
 };
 
+/// Fast-path check of the compiled code whether the barriers need to be called:
+/// While the GC is running, and, for the post-update write barrier of the young
+/// generation, while a nursery epoch is active during the GC pause.
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn running_gc() -> bool {
-    get_incremental_gc_state().phase != Phase::Pause
+    get_incremental_gc_state().phase != Phase::Pause || young_generation::is_nursery_active()
 }
 
 /// Write a potential pointer value with a pre-update barrier and resolving pointer forwarding.
@@ -27,6 +30,8 @@ pub unsafe extern "C" fn running_gc() -> bool {
 /// Additional write effects:
 /// * Pre-update barrier: Used during the GC mark phase to guarantee incremental snapshot-at-the-beginning marking.
 /// * Resolve forwarding: Used during the GC update phase to adjust old pointers to their new forwarded addresses.
+/// * Post-update barrier: Used during the GC pause to record old locations referring to young objects,
+///   if the young generation is enabled.
 #[ic_mem_fn]
 pub unsafe fn write_with_barrier<M: Memory>(mem: &mut M, location: *mut Value, value: Value) {
     debug_assert!(!is_skewed(location as usize));
@@ -35,6 +40,8 @@ pub unsafe fn write_with_barrier<M: Memory>(mem: &mut M, location: *mut Value, v
     let state = get_incremental_gc_state();
     pre_write_barrier(mem, state, *location);
     *location = value.forward_if_possible();
+    let heap_base = state.partitioned_heap.base_address();
+    young_generation::post_write_barrier(mem, heap_base, location);
 }
 
 /// Read a potential pointer value through a load barrier for the incremental GC.
@@ -0,0 +1,190 @@
+//! Heap composition statistics for monitoring:
+//! * Census of the live objects per tag, as determined by the last completed marking.
+//! * Occupancy and GC state of the individual heap partitions.
+//!
+//! The census is counted during marking, when an object transitions from unmarked to marked,
+//! and published on mark completion. It is not part of the persistent metadata, i.e. it is
+//! empty until the first mark completion after an installation or upgrade. A mark phase
+//! that has been interrupted by an upgrade only has a partial census, which is therefore
+//! not published.
+//! Objects that are conservatively marked during the mark phase, i.e. new allocations,
+//! count as live.
+
+use crate::{
+    rts_trap_with,
+    types::{Bytes, Obj, TAG_ARRAY_SLICE_MIN, Tag, Words, base_array_tag},
+};
+
+use super::{Phase, get_incremental_gc_state, partitioned_heap::Partition};
+
+/// Tags are odd numbers below `TAG_ARRAY_SLICE_MIN`.
+const NUMBER_OF_TAGS: usize = TAG_ARRAY_SLICE_MIN as usize / 2;
+
+#[derive(Clone, Copy)]
+struct TagCensus {
+    count: usize,
+    size: Bytes<usize>,
+}
+
+#[derive(Clone, Copy)]
+struct Census {
+    tags: [TagCensus; NUMBER_OF_TAGS],
+}
+
+const EMPTY_CENSUS: Census = Census {
+    tags: [TagCensus {
+        count: 0,
+        size: Bytes(0),
+    }; NUMBER_OF_TAGS],
+};
+
+/// Census of the running mark phase.
+static mut MARK_CENSUS: Census = EMPTY_CENSUS;
+
+/// Census of the last completed mark phase.
+static mut LAST_CENSUS: Census = EMPTY_CENSUS;
+
+/// Whether the running mark phase has been started since the last installation or upgrade,
+/// such that `MARK_CENSUS` covers all marked objects.
+static mut CENSUS_COMPLETE: bool = false;
+
+fn tag_index(tag: Tag) -> Option<usize> {
+    if tag % 2 == 1 && tag < TAG_ARRAY_SLICE_MIN {
+        Some(tag as usize / 2)
+    } else {
+        None
+    }
+}
+
+/// Called at the start of the mark phase.
+pub(super) unsafe fn start_census() {
+    MARK_CENSUS = EMPTY_CENSUS;
+    CENSUS_COMPLETE = true;
+}
+
+/// Called when an object has been newly marked.
+pub(super) unsafe fn record_object(object: *mut Obj, size: Words<usize>) {
+    let mut tag = object.tag();
+    if tag >= TAG_ARRAY_SLICE_MIN {
+        tag = base_array_tag(tag);
+    }
+    debug_assert!(tag_index(tag).is_some());
+    let entry = &mut MARK_CENSUS.tags[tag / 2];
+    entry.count += 1;
+    entry.size += size.to_bytes();
+}
+
+/// Called on mark completion.
+pub(super) unsafe fn publish_census() {
+    if CENSUS_COMPLETE {
+        LAST_CENSUS = MARK_CENSUS;
+    }
+    CENSUS_COMPLETE = false;
+}
+
+/// For RTS unit testing only: Discards the census state that is not retained on an upgrade.
+#[cfg(not(feature = "ic"))]
+pub unsafe fn discard_census() {
+    MARK_CENSUS = EMPTY_CENSUS;
+    LAST_CENSUS = EMPTY_CENSUS;
+    CENSUS_COMPLETE = false;
+}
+
+/// Number of live objects with the given tag at the last mark completion.
+/// Zero for invalid tags.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn get_census_object_count(tag: Tag) -> usize {
+    match tag_index(tag) {
+        Some(index) => LAST_CENSUS.tags[index].count,
+        None => 0,
+    }
+}
+
+/// Total size of the live objects with the given tag at the last mark completion.
+/// Zero for invalid tags.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn get_census_object_size(tag: Tag) -> Bytes<usize> {
+    match tag_index(tag) {
+        Some(index) => LAST_CENSUS.tags[index].size,
+        None => Bytes(0),
+    }
+}
+
+pub const PARTITION_FREE: usize = 1;
+pub const PARTITION_LARGE_CONTENT: usize = 2;
+pub const PARTITION_TEMPORARY: usize = 4;
+pub const PARTITION_EVACUATE: usize = 8;
+pub const PARTITION_ALLOCATION: usize = 16;
+
+/// Number of partitions, including free partitions.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn get_partition_count() -> usize {
+    get_incremental_gc_state()
+        .partitioned_heap
+        .number_of_partitions()
+}
+
+unsafe fn partition(index: usize) -> &'static Partition {
+    let heap = &get_incremental_gc_state().partitioned_heap;
+    if index >= heap.number_of_partitions() {
+        rts_trap_with("Invalid partition index");
+    }
+    heap.get_partition(index)
+}
+
+/// Combination of the `PARTITION_*` flags describing the state of the partition.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn get_partition_flags(index: usize) -> usize {
+    let partition = partition(index);
+    let heap = &get_incremental_gc_state().partitioned_heap;
+    let mut flags = 0;
+    for (condition, flag) in [
+        (partition.is_free(), PARTITION_FREE),
+        (partition.has_large_content(), PARTITION_LARGE_CONTENT),
+        (partition.is_temporary(), PARTITION_TEMPORARY),
+        (partition.to_be_evacuated(), PARTITION_EVACUATE),
+        (heap.is_allocation_partition(index), PARTITION_ALLOCATION),
+    ] {
+        if condition {
+            flags |= flag;
+        }
+    }
+    flags
+}
+
+/// Size of the static and dynamic space in the partition.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn get_partition_occupied_size(index: usize) -> Bytes<usize> {
+    Bytes(partition(index).occuped_size())
+}
+
+/// The mark information is only available from the mark completion until the end
+/// of the GC run.
+unsafe fn marking_available() -> bool {
+    let phase = &get_incremental_gc_state().phase;
+    *phase == Phase::Evacuate || *phase == Phase::Update
+}
+
+/// Size of the marked objects in the partition during the running GC.
+/// Zero outside of the evacuation and update phase.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn get_partition_marked_size(index: usize) -> Bytes<usize> {
+    let partition = partition(index);
+    if marking_available() {
+        Bytes(partition.marked_size())
+    } else {
+        Bytes(0)
+    }
+}
+
+/// Size of the unmarked objects in the dynamic space of the partition during the running GC.
+/// Zero outside of the evacuation and update phase.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn get_partition_garbage_size(index: usize) -> Bytes<usize> {
+    let partition = partition(index);
+    if marking_available() && !partition.is_free() && !partition.is_temporary() {
+        Bytes(partition.garbage_amount())
+    } else {
+        Bytes(0)
+    }
+}
@@ -0,0 +1,257 @@
+//! Heap snapshots for offline memory analysis, e.g. to find memory leaks in long-lived canisters.
+//!
+//! A snapshot is requested by `request_heap_snapshot` and recorded by the next GC run at
+//! mark completion. At that point, the mark bitmaps denote the reachable objects, no object
+//! has yet been moved, and the call stack is empty. The snapshot is stored as a blob that
+//! can be retrieved by `take_heap_snapshot`, e.g. to be returned by a query.
+//!
+//! Binary format (all numbers are unsigned LEB128 encoded):
+//! ```text
+//! "MOHS" (magic) | format version (1 byte) | word size in bytes (1 byte)
+//! root count | root target address ...
+//! object count | object ...
+//! ```
+//! with each object encoded as:
+//! ```text
+//! address delta to the previous object | tag | size in words | pointer count | target address ...
+//! ```
+//! Addresses are unskewed. The first object delta is relative to address 0.
+//! The objects are listed in increasing address order.
+//!
+//! The host-side tool `heap-snapshot` converts the binary snapshot into the V8 heap snapshot
+//! format that can be loaded in the Chrome developer tools.
+//!
+//! Notes:
+//! * The snapshot is recorded in a single GC increment, with work proportional to the heap
+//!   size. To stay within the message instruction limit, no snapshot is recorded if the
+//!   occupied heap exceeds `MAXIMUM_SNAPSHOT_HEAP_SIZE`. The request is then dropped, such
+//!   that the GC increment does not repeatedly fail.
+//! * The GC retains the snapshot until it is taken or replaced by a newer snapshot.
+//!   The snapshot is not retained across upgrades.
+
+use motoko_rts_macros::ic_mem_fn;
+
+use crate::{
+    memory::{Memory, alloc_blob},
+    types::{Bytes, Obj, TAG_BLOB_B, Value, block_size},
+    visitor::visit_pointer_fields,
+};
+
+use super::{
+    State, mark_new_allocation,
+    partitioned_heap::{PartitionedHeap, PartitionedHeapIterator},
+    roots::{Roots, visit_roots},
+};
+
+pub const SNAPSHOT_MAGIC: &[u8; 4] = b"MOHS";
+pub const SNAPSHOT_FORMAT_VERSION: u8 = 1;
+
+/// Largest occupied heap size for which a requested snapshot is recorded.
+/// Recording takes a few hundred instructions per heap word in the worst case, e.g. for
+/// arrays of pointers, which remains below the instruction limit of an update message.
+pub const MAXIMUM_SNAPSHOT_HEAP_SIZE: Bytes<usize> = Bytes(128 * 1024 * 1024);
+
+/// Temporary state, not part of the persistent metadata.
+static mut SNAPSHOT_REQUESTED: bool = false;
+
+/// Recorded snapshot blob that has not yet been taken. Not part of the persistent metadata.
+static mut HEAP_SNAPSHOT: Option<Value> = None;
+
+/// Request a heap snapshot to be recorded by the next GC run.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn request_heap_snapshot() {
+    SNAPSHOT_REQUESTED = true;
+}
+
+/// Return the recorded heap snapshot, or an empty blob if no snapshot is available,
+/// i.e. if no snapshot has been recorded since the last call or the heap was too large.
+#[ic_mem_fn(ic_only)]
+unsafe fn take_heap_snapshot<M: Memory>(mem: &mut M) -> Value {
+    use crate::barriers::allocation_barrier;
+
+    match take_snapshot() {
+        Some(snapshot) => snapshot,
+        None => allocation_barrier(alloc_blob(mem, TAG_BLOB_B, Bytes(0))),
+    }
+}
+
+/// Remove and return the recorded heap snapshot, if available.
+pub unsafe fn take_snapshot() -> Option<Value> {
+    let snapshot = HEAP_SNAPSHOT.take();
+    // The snapshot blob may have been evacuated in the current GC run.
+    snapshot.map(|blob| blob.forward_if_possible())
+}
+
+/// The snapshot that has not yet been taken is retained by the GC like a root.
+pub(super) unsafe fn pending_snapshot() -> Option<Value> {
+    HEAP_SNAPSHOT
+}
+
+/// Called before the evacuated partitions are freed at the end of a GC run.
+pub(super) unsafe fn forward_snapshot() {
+    if let Some(blob) = HEAP_SNAPSHOT {
+        HEAP_SNAPSHOT = Some(blob.forward_if_possible());
+    }
+}
+
+/// Record a heap snapshot if requested. Called on mark completion at the beginning of the
+/// evacuation phase, before any evacuation has been planned.
+pub(super) unsafe fn record_if_requested<M: Memory>(mem: &mut M, state: &mut State, roots: Roots) {
+    if SNAPSHOT_REQUESTED {
+        SNAPSHOT_REQUESTED = false;
+        if snapshot_fits(state.partitioned_heap.occupied_size()) {
+            HEAP_SNAPSHOT = Some(record_snapshot(mem, state, roots));
+        }
+    }
+}
+
+/// Whether a snapshot of a heap of `heap_size` can be recorded in a single GC increment.
+pub fn snapshot_fits(heap_size: Bytes<usize>) -> bool {
+    heap_size <= MAXIMUM_SNAPSHOT_HEAP_SIZE
+}
+
+unsafe fn record_snapshot<M: Memory>(mem: &mut M, state: &mut State, roots: Roots) -> Value {
+    let mut counter = ByteCounter { length: 0 };
+    let object_count = write_snapshot(&mut counter, &state.partitioned_heap, roots, None);
+    let length = counter.length;
+
+    // The snapshot blob is marked like any allocation during the evacuation phase.
+    let blob = alloc_blob(mem, TAG_BLOB_B, Bytes(length));
+    mark_new_allocation(state, blob);
+
+    let payload = blob.as_blob_mut().payload_addr();
+    let mut writer = BlobWriter {
+        cursor: payload,
+        end: payload.add(length),
+    };
+    let excluded = blob.get_ptr() as *mut Obj;
+    let written_count = write_snapshot(&mut writer, &state.partitioned_heap, roots, Some(excluded));
+    debug_assert_eq!(written_count, object_count);
+    debug_assert_eq!(writer.cursor, writer.end);
+    blob
+}
+
+trait SnapshotSink {
+    fn write_byte(&mut self, byte: u8);
+
+    fn write_number(&mut self, mut value: u64) {
+        loop {
+            let byte = (value & 0x7f) as u8;
+            value >>= 7;
+            if value == 0 {
+                self.write_byte(byte);
+                return;
+            }
+            self.write_byte(byte | 0x80);
+        }
+    }
+}
+
+/// Determines the snapshot length before the blob is allocated.
+struct ByteCounter {
+    length: usize,
+}
+
+impl SnapshotSink for ByteCounter {
+    fn write_byte(&mut self, _byte: u8) {
+        self.length += 1;
+    }
+}
+
+struct BlobWriter {
+    cursor: *mut u8,
+    end: *mut u8,
+}
+
+impl SnapshotSink for BlobWriter {
+    fn write_byte(&mut self, byte: u8) {
+        debug_assert!(self.cursor < self.end);
+        unsafe {
+            *self.cursor = byte;
+            self.cursor = self.cursor.add(1);
+        }
+    }
+}
+
+/// Returns the number of written objects.
+/// `excluded` denotes the snapshot blob itself that is skipped in the second pass.
+unsafe fn write_snapshot<S: SnapshotSink>(
+    sink: &mut S,
+    heap: &PartitionedHeap,
+    roots: Roots,
+    excluded: Option<*mut Obj>,
+) -> usize {
+    for byte in SNAPSHOT_MAGIC {
+        sink.write_byte(*byte);
+    }
+    sink.write_byte(SNAPSHOT_FORMAT_VERSION);
+    sink.write_byte(size_of::<usize>() as u8);
+
+    let heap_base = heap.base_address();
+    let mut root_count = 0usize;
+    visit_roots(roots, heap_base, &mut root_count, |count, _| *count += 1);
+    sink.write_number(root_count as u64);
+    visit_roots(roots, heap_base, sink, |sink, location| {
+        sink.write_number((*location).get_ptr() as u64);
+    });
+
+    let object_count = count_objects(heap, excluded);
+    sink.write_number(object_count as u64);
+    let mut previous_address = 0;
+    let mut iterator = PartitionedHeapIterator::new(heap);
+    while iterator.has_partition() {
+        while iterator.has_object() {
+            let object = iterator.current_object();
+            if Some(object) != excluded {
+                let address = object as usize;
+                debug_assert!(address >= previous_address);
+                sink.write_number((address - previous_address) as u64);
+                previous_address = address;
+                write_object(sink, object, heap_base);
+            }
+            iterator.next_object();
+        }
+        iterator.next_partition(heap);
+    }
+    object_count
+}
+
+unsafe fn count_objects(heap: &PartitionedHeap, excluded: Option<*mut Obj>) -> usize {
+    let mut count = 0;
+    let mut iterator = PartitionedHeapIterator::new(heap);
+    while iterator.has_partition() {
+        while iterator.has_object() {
+            if Some(iterator.current_object()) != excluded {
+                count += 1;
+            }
+            iterator.next_object();
+        }
+        iterator.next_partition(heap);
+    }
+    count
+}
+
+unsafe fn write_object<S: SnapshotSink>(sink: &mut S, object: *mut Obj, heap_base: usize) {
+    let tag = object.tag();
+    sink.write_number(tag as u64);
+    sink.write_number(block_size(object as usize).as_usize() as u64);
+
+    let mut pointer_count = 0usize;
+    visit_pointer_fields(
+        &mut pointer_count,
+        object,
+        tag,
+        heap_base,
+        |count, _| *count += 1,
+        |_, _, array| array.len(),
+    );
+    sink.write_number(pointer_count as u64);
+    visit_pointer_fields(
+        sink,
+        object,
+        tag,
+        heap_base,
+        |sink, field| sink.write_number((*field).get_ptr() as u64),
+        |_, _, array| array.len(),
+    );
+}
@@ -21,7 +21,9 @@
 //!
 //! A linked list of partition tables allows dynamic growth of the heap memory even in 64-bit address
 //! space. The first partition table is placed in the record of the partitioned heap. Subsequent
-//! partition tables reside in the static space of a partition.
+//! partition tables reside in the static space of the first partition that they describe.
+//! Therefore, the table of a partition is directly located by its index, without traversing
+//! the list, and the heap size is only bounded by the Wasm memory capacity that can be obtained.
 //!
 //! ┌─────────────────────┐ extension ┌─────────────────────┐ extension
 //! │   Partition table   │---------->│   Partition table   │----------> ...
@@ -42,11 +44,29 @@
 //! to be searched. Huge objects stay in their partitions for their entire lifetime, i.e. they
 //! are never evacuated. When becoming garbage, the underlying partitions of a huge object are
 //! immediately freed. Large object allocation may be prone to external fragmentation problems,
-//! i.e. that no sufficient contiguous free partitions are available on allocation. Currently,
-//! this external fragmentation problem is not handled by moving other partitions which would
-//! require a special blocking full GC collection. Moreover, for simplicity, the remainder
-//! of the last partition of a huge object is not used for further small object allocations,
-//! which implies limited internal fragmentation.
+//! i.e. that no sufficient contiguous free partitions are available on allocation.
+//!
+//! Tail of huge objects:
+//! If a huge object does not entirely fill its last partition, the object tail in that partition
+//! is declared as static space of a normal free partition. The remainder of the partition thus
+//! serves as regular dynamic space for subsequent allocations. When the huge object becomes
+//! garbage, the static tail is released: If the partition is still free, it becomes completely
+//! free. Otherwise, the tail turns into garbage at the beginning of the dynamic space, covered
+//! by a free space block, and is reclaimed once the partition is evacuated.
+//!
+//! Defragmentation for huge objects:
+//! If a huge object cannot be placed in the used heap space, although there are sufficient
+//! but scattered free partitions, the allocation extends the heap and requests a defragmentation.
+//! The next GC run then selects the contiguous partition range with the least live data in the
+//! used heap space and evacuates all of its partitions, independent of their survival rate.
+//! The range is thereafter reserved for a huge object allocation: Normal and temporary partitions
+//! are only allocated in the reserved range if no other free partition is available, which cancels
+//! the reservation. As the defragmentation request and the reservation only serve as a hint for the
+//! GC, they are not retained across upgrades.
+//! A huge object allocation that cannot extend the heap traps, which also rolls back its request.
+//! Therefore, when the memory is critically full, the GC itself scans the used heap space for
+//! scattered free partitions and, without request, defragments the longest range that fits into
+//! the evacuation space. The critical state is determined by the GC scheduling.
 
 use core::{
     array::from_fn,
@@ -58,6 +78,7 @@ use core::{
 use motoko_rts_macros::{classical_persistence, enhanced_orthogonal_persistence};
 
 use crate::{
+    constants::WORD_SIZE,
     gc::incremental::mark_bitmap::BITMAP_ITERATION_END,
     memory::{Memory, alloc_blob},
     rts_trap_with,
@@ -66,9 +87,11 @@ use crate::{
 };
 
 use super::{
+    census,
     mark_bitmap::{BITMAP_SIZE, BitmapIterator, MarkBitmap},
     sort::sort,
     time::BoundedTime,
+    young_generation,
 };
 
 /// Size of each partition.
@@ -86,7 +109,7 @@ pub const PARTITION_SIZE: usize = 32 * 1024 * 1024;
 /// Number of entries per partition table.
 /// Tables are linearly linked, allowing the usage of the entire address space.
 /// Maximum contiguous space is `(PARTITION_PER_TABLE - 1) * PARTITION_SIZE`.
-const PARTITIONS_PER_TABLE: usize = 128; // 8 GB of space for 64 MB partitions and 4 GB for 32 MB partitions.
+pub const PARTITIONS_PER_TABLE: usize = 128; // 8 GB of space for 64 MB partitions and 4 GB for 32 MB partitions.
 
 /// Maximum number of partitions in the memory.
 /// For simplicity, the last partition is left unused, to avoid a numeric overflow when
@@ -101,6 +124,35 @@ const MAX_PARTITIONS: usize = usize::MAX / PARTITION_SIZE;
 /// 2. Lowest WASM memory size (i.e. for minimum heap size).
 pub const SURVIVAL_RATE_THRESHOLD: f64 = 0.85;
 
+/// Pending defragmentation request and reservation, see the module description.
+struct Defragmentation {
+    /// Number of contiguous partitions to be freed by the next GC run, or zero.
+    requested: usize,
+    /// Contiguous partitions that are being freed or are free for a huge object allocation.
+    reserved: Range<usize>,
+    /// Whether the GC defragments scattered free partitions without request.
+    critical_memory: bool,
+}
+
+static mut DEFRAGMENTATION: Defragmentation = Defragmentation {
+    requested: 0,
+    reserved: 0..0,
+    critical_memory: false,
+};
+
+/// Whether a huge object allocation has requested a defragmentation by the next GC run.
+pub unsafe fn is_defragmentation_requested() -> bool {
+    DEFRAGMENTATION.requested > 0
+}
+
+/// Set by the GC scheduling whether the memory is critically full, such that the GC
+/// defragments the used heap space without a request by a huge object allocation.
+pub fn set_critical_memory(critical: bool) {
+    unsafe {
+        DEFRAGMENTATION.critical_memory = critical;
+    }
+}
+
 /// Heap partition of size `PARTITION_SIZE`.
 /// Use a long-term representation by relying on C layout.
 #[repr(C)]
@@ -209,9 +261,25 @@ impl Partition {
         &mut self.bitmap
     }
 
+    /// Releases the static tail of a dead huge object in the last partition of the object.
+    /// Returns the size of the immediately reclaimed space.
+    unsafe fn release_large_object_tail(&mut self) -> usize {
+        debug_assert!(!self.large_content);
+        debug_assert!(self.static_size > 0 && self.static_size < PARTITION_SIZE);
+        let tail_size = self.static_size;
+        self.static_size = 0;
+        if self.free {
+            debug_assert_eq!(self.dynamic_size, 0);
+            tail_size
+        } else {
+            write_free_space(self.start_address(), tail_size);
+            self.dynamic_size += tail_size;
+            0
+        }
+    }
+
     #[cfg(feature = "memory_check")]
     unsafe fn clear_free_remainder(&self) {
-        use crate::constants::WORD_SIZE;
         debug_assert!(self.dynamic_space_end() <= self.end_address());
         let remaining_space = self.end_address() - self.dynamic_space_end();
         // This code assumes that the free space has been allocated (with Wasm memory grow).
@@ -225,26 +293,24 @@ impl Partition {
         if remaining_space == 0 {
             return;
         }
-        let block = self.dynamic_space_end() as *mut Tag;
-        if remaining_space == WORD_SIZE {
-            *block = TAG_ONE_WORD_FILLER;
-        } else {
-            *block = TAG_FREE_SPACE;
-            let header_size = size_of::<FreeSpace>().to_bytes().as_usize();
-            debug_assert!(remaining_space >= header_size);
-            let free_space = block as *mut FreeSpace;
-            (*free_space).words = Bytes(remaining_space - header_size).to_words();
+        write_free_space(self.dynamic_space_end(), remaining_space);
+        let header_size = size_of::<FreeSpace>().to_bytes().as_usize();
+        if remaining_space > header_size {
             // Clear the remainder of the free space.
-            let clear_start = free_space as usize + header_size;
+            let clear_start = self.dynamic_space_end() + header_size;
             let clear_length = Bytes(remaining_space - header_size);
             crate::mem_utils::memzero(clear_start, clear_length.to_words());
-            debug_assert_eq!(free_space.size().to_bytes().as_usize(), remaining_space);
         }
     }
 
     pub unsafe fn free(&mut self) {
-        debug_assert!(!self.free);
         debug_assert!(self.evacuate || self.large_content || self.temporary);
+        self.release();
+    }
+
+    /// Frees the partition, without checking the reason.
+    unsafe fn release(&mut self) {
+        debug_assert!(!self.free);
         debug_assert_eq!(self.marked_size, 0);
         debug_assert!(!self.update);
         self.free = true;
@@ -323,7 +389,7 @@ impl PartitionedHeapIterator {
         let partition = heap.get_partition(self.partition_index);
         let number_of_partitions = if partition.has_large_content() {
             let large_object = partition.dynamic_space_start() as *mut Obj;
-            PartitionedHeap::partitions_length(large_object)
+            heap.large_content_length(large_object)
         } else {
             1
         };
@@ -526,6 +592,13 @@ impl PartitionedHeap {
             partition_table.partitions[index].free = false;
         }
         let free_partitions = number_of_partitions - allocation_index - 1;
+        unsafe {
+            DEFRAGMENTATION = Defragmentation {
+                requested: 0,
+                reserved: 0..0,
+                critical_memory: false,
+            };
+        }
         PartitionedHeap {
             partition_table,
             number_of_partitions,
@@ -541,10 +614,11 @@ impl PartitionedHeap {
         }
     }
 
+    /// Mark bitmaps are allocated in entire temporary partitions.
     pub fn maximum_mark_bitmap_size(&self) -> usize {
         debug_assert!(self.free_partitions <= self.number_of_partitions);
         let used_partitions = self.number_of_partitions - self.free_partitions;
-        used_partitions * BITMAP_SIZE
+        (used_partitions * BITMAP_SIZE).next_multiple_of(PARTITION_SIZE)
     }
 
     fn partitions(&mut self) -> PartitionIterator {
@@ -559,15 +633,18 @@ impl PartitionedHeap {
         self.heap_base
     }
 
+    pub fn number_of_partitions(&self) -> usize {
+        self.number_of_partitions
+    }
+
+    /// Extension tables are placed at the start address of their first partition,
+    /// see `add_partition_table`.
     unsafe fn get_extension_table(&self, partition_index: usize) -> *mut PartitionTable {
         debug_assert!(partition_index >= PARTITIONS_PER_TABLE);
-        let mut index = partition_index - PARTITIONS_PER_TABLE;
-        let mut table = self.partition_table.extension;
-        while index >= PARTITIONS_PER_TABLE {
-            index -= PARTITIONS_PER_TABLE;
-            debug_assert_ne!((*table).extension, null_mut());
-            table = (*table).extension;
-        }
+        debug_assert!(partition_index < self.number_of_partitions);
+        let first_index = partition_index - partition_index % PARTITIONS_PER_TABLE;
+        let table = (first_index * PARTITION_SIZE) as *mut PartitionTable;
+        debug_assert_eq!((*table).partitions[0].index, first_index);
         table
     }
 
@@ -636,13 +713,33 @@ impl PartitionedHeap {
         result.unwrap()
     }
 
-    fn scan_for_temporary_partition(&mut self) -> Option<usize> {
+    /// Returns the first partition satisfying `condition`, preferably outside the
+    /// defragmentation reservation. Cancels the reservation if only reserved partitions
+    /// satisfy the condition.
+    fn scan_for_unreserved_partition<F: Fn(&Partition) -> bool>(
+        &mut self,
+        condition: F,
+    ) -> Option<usize> {
+        let reserved = unsafe { DEFRAGMENTATION.reserved.clone() };
+        let mut fallback = None;
         for partition in self.partitions() {
-            if partition.is_completely_free() {
-                return Some(partition.index);
+            if condition(partition) {
+                if !reserved.contains(&partition.index) {
+                    return Some(partition.index);
+                }
+                fallback = fallback.or(Some(partition.index));
             }
         }
-        None
+        if fallback.is_some() {
+            unsafe {
+                DEFRAGMENTATION.reserved = 0..0;
+            }
+        }
+        fallback
+    }
+
+    fn scan_for_temporary_partition(&mut self) -> Option<usize> {
+        self.scan_for_unreserved_partition(|partition| partition.is_completely_free())
     }
 
     unsafe fn allocate_temporary_partition<M: Memory>(&mut self, mem: &mut M) -> &mut Partition {
@@ -686,7 +783,9 @@ impl PartitionedHeap {
             return false;
         }
         bitmap.mark(offset);
-        partition.marked_size += block_size(address).to_bytes().as_usize();
+        let size = block_size(address);
+        partition.marked_size += size.to_bytes().as_usize();
+        census::record_object(object, size);
         true
     }
 
@@ -732,10 +831,14 @@ impl PartitionedHeap {
         let reserved_partitions =
             (self.free_partitions + EVACUATION_FRACTION - 1) / EVACUATION_FRACTION;
         let mut evacuation_space = reserved_partitions * PARTITION_SIZE;
+        self.plan_defragmentation(&mut evacuation_space);
         let ranked_partitions = self.rank_partitions_by_garbage(mem);
         for rank in 0..self.number_of_partitions {
             let index = *ranked_partitions.add(rank);
-            if index != self.allocation_index && self.get_partition(index).is_evacuation_candidate()
+            let partition = self.get_partition(index);
+            if index != self.allocation_index
+                && partition.is_evacuation_candidate()
+                && !partition.to_be_evacuated()
             {
                 let partition = self.mutable_partition(index);
                 if evacuation_space < partition.marked_size() {
@@ -750,6 +853,139 @@ impl PartitionedHeap {
         }
     }
 
+    /// Evacuate the contiguous range of the requested number of partitions with the least
+    /// live data in the used heap space, if the evacuation space suffices, and reserve the range
+    /// for the huge object allocation that has requested the defragmentation.
+    /// Without request, but with critical memory, select the longest range that joins scattered
+    /// free partitions within the evacuation space.
+    unsafe fn plan_defragmentation(&mut self, evacuation_space: &mut usize) {
+        let requested = DEFRAGMENTATION.requested;
+        DEFRAGMENTATION.requested = 0;
+        let lengths = if requested > 0 {
+            requested..requested + 1
+        } else if DEFRAGMENTATION.critical_memory {
+            self.scattered_free_partitions()
+        } else {
+            return;
+        };
+        let Some((range, live_size)) = lengths
+            .rev()
+            .filter_map(|length| self.cheapest_defragmentation_range(length))
+            .find(|(_, live_size)| *live_size <= *evacuation_space)
+        else {
+            return;
+        };
+        *evacuation_space -= live_size;
+        for index in range.clone() {
+            let partition = self.mutable_partition(index);
+            if !partition.is_free() {
+                partition.evacuate = true;
+                self.evacuating = true;
+            }
+        }
+        DEFRAGMENTATION.reserved = range;
+    }
+
+    /// Live data to be evacuated for freeing the partition, or `None` if the partition
+    /// cannot be freed by evacuation.
+    fn defragmentation_cost(&self, index: usize) -> Option<usize> {
+        let partition = self.get_partition(index);
+        if partition.is_completely_free() {
+            Some(0)
+        } else if !partition.is_free()
+            && !partition.has_large_content()
+            && !partition.is_temporary()
+            && partition.static_size == 0
+            && index != self.allocation_index
+        {
+            Some(partition.marked_size())
+        } else {
+            None
+        }
+    }
+
+    fn cheapest_defragmentation_range(&self, length: usize) -> Option<(Range<usize>, usize)> {
+        let mut cheapest: Option<(Range<usize>, usize)> = None;
+        let mut start = 0;
+        let mut live_size = 0;
+        for index in 0..self.used_partitions_end() {
+            // Invariant: The partitions `start..index` can be freed by evacuating `live_size`.
+            match self.defragmentation_cost(index) {
+                None => {
+                    start = index + 1;
+                    live_size = 0;
+                }
+                Some(cost) => {
+                    live_size += cost;
+                    if index + 1 - start > length {
+                        live_size -= self.defragmentation_cost(start).unwrap();
+                        start += 1;
+                    }
+                    if index + 1 - start == length
+                        && cheapest
+                            .as_ref()
+                            .is_none_or(|(_, minimum)| live_size < *minimum)
+                    {
+                        cheapest = Some((start..index + 1, live_size));
+                    }
+                }
+            }
+        }
+        cheapest
+    }
+
+    /// End of the partitions that are or have been used, i.e. the index after the last
+    /// partition that is not free.
+    fn used_partitions_end(&self) -> usize {
+        (0..self.number_of_partitions)
+            .rev()
+            .find(|index| !self.get_partition(*index).is_free())
+            .map_or(0, |index| index + 1)
+    }
+
+    /// Range lengths that would join more free partitions in the used heap space than the
+    /// longest contiguous free range, up to the number of these free partitions.
+    fn scattered_free_partitions(&self) -> Range<usize> {
+        let mut contiguous_free = 0;
+        let mut longest_free = 0;
+        let mut total_free = 0;
+        for index in 0..self.used_partitions_end() {
+            if self.get_partition(index).is_completely_free() {
+                contiguous_free += 1;
+                total_free += 1;
+                longest_free = usize::max(longest_free, contiguous_free);
+            } else {
+                contiguous_free = 0;
+            }
+        }
+        longest_free + 1..total_free + 1
+    }
+
+    /// Request a defragmentation if there is no contiguous free space for the huge object
+    /// in the used heap space, although the free partitions there would suffice.
+    unsafe fn check_fragmentation(&self, number_of_partitions: usize) {
+        let mut contiguous_free = 0;
+        let mut total_free = 0;
+        for index in 0..self.used_partitions_end() {
+            if self.get_partition(index).is_completely_free() {
+                contiguous_free += 1;
+                total_free += 1;
+                if contiguous_free >= number_of_partitions {
+                    return;
+                }
+            } else {
+                contiguous_free = 0;
+            }
+        }
+        if total_free >= number_of_partitions {
+            DEFRAGMENTATION.requested = usize::max(DEFRAGMENTATION.requested, number_of_partitions);
+        }
+    }
+
+    pub fn reserved_for_defragmentation(&self) -> Range<usize> {
+        unsafe { DEFRAGMENTATION.reserved.clone() }
+    }
+
     unsafe fn temporary_array<M: Memory>(mem: &mut M, length: usize) -> *mut usize {
         // No post allocation barrier as this RTS-internal blob can be collected by the GC.
         let blob = alloc_blob(mem, TAG_BLOB_B, Words(length).to_bytes());
@@ -824,12 +1060,9 @@ impl PartitionedHeap {
     }
 
     fn scan_for_free_partition(&mut self, requested_space: usize) -> Option<usize> {
-        for partition in self.partitions() {
-            if partition.free && partition.free_size() >= requested_space {
-                return Some(partition.index);
-            }
-        }
-        None
+        self.scan_for_unreserved_partition(|partition| {
+            partition.free && partition.free_size() >= requested_space
+        })
     }
 
     unsafe fn allocate_free_partition<M: Memory>(
@@ -930,9 +1163,85 @@ impl PartitionedHeap {
         let heap_pointer = new_partition.dynamic_space_end();
         new_partition.dynamic_size += size;
         self.allocation_index = new_partition.index;
+        // The young space excludes a possible static tail of an old huge object.
+        young_generation::record_new_partition(heap_pointer);
         Value::from_ptr(heap_pointer)
     }
 
+    pub fn allocation_partition_index(&self) -> usize {
+        self.allocation_index
+    }
+
+    /// Continues the allocations in a new partition, e.g. for a nursery epoch.
+    pub unsafe fn open_allocation_partition<M: Memory>(&mut self, mem: &mut M) {
+        self.allocate_in_new_partition(mem, 0);
+    }
+
+    /// Bump allocation in a partition other than the allocation partition, used for
+    /// promotions to the old generation during the GC pause.
+    /// Returns `None` if the partition has insufficient free space.
+    pub unsafe fn allocate_in_partition<M: Memory>(
+        &mut self,
+        mem: &mut M,
+        index: usize,
+        words: Words<usize>,
+    ) -> Option<Value> {
+        debug_assert!(!self.gc_running);
+        debug_assert!(index != self.allocation_index);
+        let size = words.to_bytes().as_usize();
+        let partition = self.mutable_partition(index);
+        debug_assert!(!partition.free && !partition.large_content && !partition.temporary);
+        let heap_pointer = partition.dynamic_space_end();
+        if size > partition.end_address() - heap_pointer {
+            return None;
+        }
+        intra_partition_memory_grow(mem, heap_pointer + size);
+        partition.dynamic_size += size;
+        self.precomputed_heap_size += size;
+        Some(Value::from_ptr(heap_pointer))
+    }
+
+    /// Occupies a free partition with sufficient space for `words` without using it as
+    /// allocation partition. Returns the partition index.
+    pub unsafe fn allocate_side_partition<M: Memory>(
+        &mut self,
+        mem: &mut M,
+        words: Words<usize>,
+    ) -> usize {
+        let size = words.to_bytes().as_usize();
+        debug_assert!(size <= PARTITION_SIZE);
+        self.allocate_free_partition(mem, size).index
+    }
+
+    /// Frees the partitions of a collected nursery, whose surviving objects of the total
+    /// `promoted_size` have been copied to other partitions. The allocations continue in a
+    /// new partition. Returns the reclaimed size.
+    pub unsafe fn free_young_partitions<M: Memory>(
+        &mut self,
+        mem: &mut M,
+        indices: &[usize],
+        promoted_size: usize,
+    ) -> usize {
+        debug_assert!(!self.gc_running);
+        debug_assert!(indices.contains(&self.allocation_index));
+        self.precomputed_heap_size += self.allocation_partition().dynamic_size;
+        let mut freed_size = 0;
+        for index in indices {
+            let partition = self.mutable_partition(*index);
+            debug_assert!(!partition.large_content && !partition.temporary);
+            freed_size += partition.dynamic_size;
+            partition.release();
+        }
+        self.free_partitions += indices.len();
+        self.precomputed_heap_size -= freed_size;
+        debug_assert!(freed_size >= promoted_size);
+        let reclaimed = freed_size - promoted_size;
+        self.reclaimed += reclaimed as u64;
+        self.open_allocation_partition(mem);
+        self.check_occupied_size();
+        reclaimed
+    }
+
     // Significant performance gain by not inlining.
     #[inline(never)]
     unsafe fn allocate_large_object<M: Memory>(&mut self, mem: &mut M, size: usize) -> Value {
@@ -942,13 +1251,21 @@ impl PartitionedHeap {
         let number_of_partitions = (size + PARTITION_SIZE - 1) / PARTITION_SIZE;
         debug_assert!(number_of_partitions > 0);
 
+        self.check_fragmentation(number_of_partitions);
         let first_index = self.allocate_partition(mem, &|context| {
             context.scan_for_large_space(number_of_partitions)
         });
         let last_index = first_index + number_of_partitions - 1;
+        let reserved = &DEFRAGMENTATION.reserved;
+        if first_index < reserved.end && reserved.start <= last_index {
+            DEFRAGMENTATION.reserved = 0..0;
+        }
 
         debug_assert!(self.free_partitions >= number_of_partitions);
-        self.free_partitions -= number_of_partitions;
+        let tail_size = size - (number_of_partitions - 1) * PARTITION_SIZE;
+        let has_static_tail = tail_size < PARTITION_SIZE;
+        // A partition with a static tail remains free for normal allocations.
+        self.free_partitions -= number_of_partitions - has_static_tail as usize;
 
         let end_address = self.get_partition(last_index).end_address();
         mem.grow_memory(end_address);
@@ -956,23 +1273,27 @@ impl PartitionedHeap {
             let partition = self.mutable_partition(index);
             debug_assert!(partition.free);
             debug_assert!(!partition.large_content);
-            partition.free = false;
-            partition.large_content = true;
             debug_assert_eq!(partition.static_size, 0);
             debug_assert_eq!(partition.dynamic_size, 0);
             debug_assert_eq!(partition.marked_size, 0);
-            if index == last_index {
-                partition.dynamic_size = size - (number_of_partitions - 1) * PARTITION_SIZE;
+            if index == last_index && has_static_tail {
+                // The tail becomes static space, such that the remainder of the partition
+                // can be used for normal allocations.
+                partition.static_size = tail_size;
 
                 #[cfg(feature = "memory_check")]
                 partition.clear_free_remainder();
             } else {
+                partition.free = false;
+                partition.large_content = true;
                 partition.dynamic_size = PARTITION_SIZE;
             }
-            self.precomputed_heap_size += partition.dynamic_size;
+            self.precomputed_heap_size += partition.occuped_size();
         }
         let first_partition = self.mutable_partition(first_index);
-        Value::from_ptr(first_partition.dynamic_space_start())
+        let address = first_partition.dynamic_space_start();
+        young_generation::record_large_object(address);
+        Value::from_ptr(address)
     }
 
     unsafe fn scan_for_large_space(&self, number_of_partitions: usize) -> Option<usize> {
@@ -1009,6 +1330,17 @@ impl PartitionedHeap {
         (size + PARTITION_SIZE - 1) / PARTITION_SIZE
     }
 
+    /// Number of partitions that are exclusively occupied by the large object,
+    /// i.e. excluding a last partition that only holds the object tail as static space.
+    unsafe fn large_content_length(&self, large_object: *mut Obj) -> usize {
+        let range = Self::occupied_partition_range(large_object);
+        if self.get_partition(range.end - 1).has_large_content() {
+            range.len()
+        } else {
+            range.len() - 1
+        }
+    }
+
     pub unsafe fn collect_large_objects(&mut self) {
         let mut index = 0;
         while index < self.number_of_partitions {
@@ -1016,7 +1348,7 @@ impl PartitionedHeap {
             if partition.has_large_content() {
                 debug_assert!(!partition.free);
                 let object = partition.dynamic_space_start() as *mut Obj;
-                let number_of_partitions = Self::partitions_length(object);
+                let number_of_partitions = self.large_content_length(object);
                 if partition.marked_size == 0 {
                     self.free_large_object(object);
                 }
@@ -1029,15 +1361,26 @@ impl PartitionedHeap {
 
     unsafe fn free_large_object(&mut self, object: *mut Obj) {
         let occupied_range = Self::occupied_partition_range(object);
-        self.free_partitions += occupied_range.len();
         for index in occupied_range {
             let partition = self.mutable_partition(index);
-            debug_assert!(partition.large_content);
-            let size = partition.dynamic_size;
-            partition.update = false;
-            partition.free();
-            self.reclaimed += size as u64;
-            self.precomputed_heap_size -= size;
+            if partition.large_content {
+                let size = partition.dynamic_size;
+                partition.update = false;
+                partition.free();
+                self.free_partitions += 1;
+                self.reclaimed += size as u64;
+                self.precomputed_heap_size -= size;
+            } else {
+                let tail_size = partition.static_size;
+                let reclaimed = partition.release_large_object_tail();
+                self.reclaimed += reclaimed as u64;
+                self.precomputed_heap_size -= reclaimed;
+                if reclaimed == 0 && index == self.allocation_index {
+                    // The tail now belongs to the dynamic space of the allocation partition,
+                    // which is not included in the precomputed heap size.
+                    self.precomputed_heap_size -= tail_size;
+                }
+            }
         }
     }
 
@@ -1052,12 +1395,34 @@ impl PartitionedHeap {
         for index in range.start..range.end - 1 {
             self.mutable_partition(index).marked_size = PARTITION_SIZE;
         }
-        let object_size = block_size(object as usize).to_bytes().as_usize();
-        self.mutable_partition(range.end - 1).marked_size = object_size % PARTITION_SIZE;
+        let object_size = block_size(object as usize);
+        let last_partition = self.mutable_partition(range.end - 1);
+        // A tail in static space does not count as marked dynamic space.
+        if last_partition.large_content {
+            last_partition.marked_size = object_size.to_bytes().as_usize() % PARTITION_SIZE;
+        }
+        census::record_object(object, object_size);
         true
     }
 }
 
+/// Covers the space by a free space block or a one-word filler.
+unsafe fn write_free_space(address: usize, size: usize) {
+    debug_assert_eq!(size % WORD_SIZE, 0);
+    debug_assert_ne!(size, 0);
+    let block = address as *mut Tag;
+    if size == WORD_SIZE {
+        *block = TAG_ONE_WORD_FILLER;
+    } else {
+        *block = TAG_FREE_SPACE;
+        let header_size = size_of::<FreeSpace>().to_bytes().as_usize();
+        debug_assert!(size >= header_size);
+        let free_space = block as *mut FreeSpace;
+        (*free_space).words = Bytes(size - header_size).to_words();
+        debug_assert_eq!(free_space.size().to_bytes().as_usize(), size);
+    }
+}
+
 #[cfg(feature = "ic")]
 pub(crate) unsafe fn allocate_initial_memory(heap_base: Bytes<usize>) {
     use crate::memory::ic::allocate_wasm_memory;
@@ -0,0 +1,152 @@
+//! Runtime-adjustable scheduling policy of the incremental GC, cf. `scheduling.rs`.
+//!
+//! The policy determines when a new GC run is started and how much work is performed
+//! per GC increment. Canisters with bursty workloads can thereby trade GC latency for
+//! mutator throughput without recompiling the RTS.
+//!
+//! Like the GC state, the policy is retained across upgrades with enhanced orthogonal
+//! persistence, as it is stored in the persistent metadata, cf. `persistence::PersistentMetadata`.
+//!
+//! Ratios are expressed in basis points (1/100 of a percent).
+
+use motoko_rts_macros::{classical_persistence, enhanced_orthogonal_persistence};
+
+use crate::rts_trap_with;
+
+/// Scale of the ratios in the policy: 10_000 basis points correspond to 100%.
+pub const BASIS_POINTS: usize = 10_000;
+
+/// Upper bound of the growth thresholds: A new GC run is scheduled at the latest
+/// when the heap has grown by 1000% since the last GC start.
+pub const MAXIMUM_GROWTH_THRESHOLD: usize = 10 * BASIS_POINTS;
+
+/// Use a long-term representation by relying on C layout.
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct SchedulingPolicy {
+    /// Critical heap size in basis points of the memory capacity.
+    pub critical_heap_limit: usize,
+    /// Medium heap size in basis points of the memory capacity.
+    pub medium_heap_limit: usize,
+    /// Heap growth in basis points that triggers a GC start above the critical heap limit.
+    pub critical_growth_threshold: usize,
+    /// Heap growth in basis points that triggers a GC start above the medium heap limit.
+    pub medium_growth_threshold: usize,
+    /// Heap growth in basis points that triggers a GC start below the medium heap limit.
+    pub low_growth_threshold: usize,
+    /// Limit of a GC increment without concurrent allocations.
+    pub increment_base_limit: usize,
+    /// Additional increment limit per concurrent allocation during a running GC.
+    pub increment_allocation_factor: usize,
+}
+
+/// The default heap limits are 80% and 50% of the memory capacity.
+#[enhanced_orthogonal_persistence]
+const DEFAULT_HEAP_LIMITS: (usize, usize) = (8_000, 5_000);
+
+/// The default heap limits are 2.25GB and 1GB of the 4GB memory.
+#[classical_persistence]
+const DEFAULT_HEAP_LIMITS: (usize, usize) = (5_625, 2_500);
+
+pub const DEFAULT_POLICY: SchedulingPolicy = SchedulingPolicy {
+    critical_heap_limit: DEFAULT_HEAP_LIMITS.0,
+    medium_heap_limit: DEFAULT_HEAP_LIMITS.1,
+    critical_growth_threshold: 100,  // 1%
+    medium_growth_threshold: 3_500,  // 35%
+    low_growth_threshold: 6_500,     // 65%
+    increment_base_limit: 5_000_000, // Increment limit without concurrent allocations.
+    increment_allocation_factor: 50, // Additional time factor per concurrent allocation.
+};
+
+impl SchedulingPolicy {
+    /// Valid policies schedule the GC more frequently the fuller the heap is and
+    /// allow each GC increment to make progress.
+    /// The zero-initialized policy is invalid.
+    pub fn is_valid(&self) -> bool {
+        0 < self.medium_heap_limit
+            && self.medium_heap_limit <= self.critical_heap_limit
+            && self.critical_heap_limit <= BASIS_POINTS
+            && self.critical_growth_threshold <= self.medium_growth_threshold
+            && self.medium_growth_threshold <= self.low_growth_threshold
+            && self.low_growth_threshold <= MAXIMUM_GROWTH_THRESHOLD
+            && self.increment_base_limit > 0
+    }
+
+    /// Growth threshold as a fraction of the heap size.
+    pub fn growth_ratio(threshold: usize) -> f64 {
+        threshold as f64 / BASIS_POINTS as f64
+    }
+
+    /// Portion of `capacity` corresponding to `limit` in basis points, without overflow.
+    pub fn heap_limit(capacity: u64, limit: usize) -> u64 {
+        let limit = limit as u64;
+        let scale = BASIS_POINTS as u64;
+        capacity / scale * limit + capacity % scale * limit / scale
+    }
+}
+
+#[cfg(feature = "ic")]
+#[enhanced_orthogonal_persistence]
+pub unsafe fn get_scheduling_policy() -> &'static mut SchedulingPolicy {
+    crate::persistence::get_gc_scheduling_policy()
+}
+
+/// Without enhanced orthogonal persistence, the GC state and the policy are not retained
+/// across upgrades.
+#[cfg(any(not(feature = "ic"), not(feature = "enhanced_orthogonal_persistence")))]
+static mut POLICY: SchedulingPolicy = DEFAULT_POLICY;
+
+#[cfg(any(not(feature = "ic"), not(feature = "enhanced_orthogonal_persistence")))]
+pub unsafe fn get_scheduling_policy() -> &'static mut SchedulingPolicy {
+    &mut POLICY
+}
+
+/// Adjust the GC scheduling policy. Takes effect from the next GC increment on.
+/// Traps if the policy is invalid, see `SchedulingPolicy::is_valid`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn set_gc_scheduling_policy(
+    critical_heap_limit: usize,
+    medium_heap_limit: usize,
+    critical_growth_threshold: usize,
+    medium_growth_threshold: usize,
+    low_growth_threshold: usize,
+    increment_base_limit: usize,
+    increment_allocation_factor: usize,
+) {
+    let policy = SchedulingPolicy {
+        critical_heap_limit,
+        medium_heap_limit,
+        critical_growth_threshold,
+        medium_growth_threshold,
+        low_growth_threshold,
+        increment_base_limit,
+        increment_allocation_factor,
+    };
+    if !policy.is_valid() {
+        rts_trap_with("Invalid GC scheduling policy");
+    }
+    *get_scheduling_policy() = policy;
+}
+
+/// Restore the default GC scheduling policy.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn reset_gc_scheduling_policy() {
+    *get_scheduling_policy() = DEFAULT_POLICY;
+}
+
+/// Field of the current GC scheduling policy, indexed in the parameter order of
+/// `set_gc_scheduling_policy`. Traps on an invalid index.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn get_gc_scheduling_policy_field(index: usize) -> usize {
+    let policy = get_scheduling_policy();
+    match index {
+        0 => policy.critical_heap_limit,
+        1 => policy.medium_heap_limit,
+        2 => policy.critical_growth_threshold,
+        3 => policy.medium_growth_threshold,
+        4 => policy.low_growth_threshold,
+        5 => policy.increment_base_limit,
+        6 => policy.increment_allocation_factor,
+        _ => rts_trap_with("Invalid GC scheduling policy field"),
+    }
+}
@@ -129,9 +129,16 @@ impl<'a, M: Memory> MemoryChecker<'a, M> {
         let size = block_size(address).to_bytes().as_usize();
         if size > PARTITION_SIZE {
             let number_of_partitions = (size + PARTITION_SIZE - 1) / PARTITION_SIZE;
-            for index in partition_index..partition_index + number_of_partitions {
+            let last_index = partition_index + number_of_partitions - 1;
+            for index in partition_index..last_index {
                 assert!(self.heap.get_partition(index).has_large_content());
             }
+            // The tail may reside in the static space of the last partition.
+            let last_partition = self.heap.get_partition(last_index);
+            assert!(
+                last_partition.has_large_content()
+                    || address + size <= last_partition.dynamic_space_start()
+            );
         } else {
             assert!(
                 address + block_size(address).to_bytes().as_usize()
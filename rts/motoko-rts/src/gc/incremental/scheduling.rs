@@ -1,9 +1,11 @@
 //! Heuristics for GC scheduling.
 //! Tuned for 64-bit main memory with unknown IC memory capacity.
 //!
-//! Distinction of three scheduling modes:
+//! Distinction of three scheduling modes, with the default limits and thresholds of the
+//! adjustable scheduling policy, cf. `policy.rs`:
 //! * Critical: The heap exceeds 80% of the memory capacity (using capacity probing):
 //!   - Schedule a GC start with a frequency of 1% of the heap growth.
+//!   - Defragment scattered free partitions for huge object allocations.
 //! * Medium: The heap exceeds 50% of the memory capacity:
 //!   - Schedule a GC start with a frequency of 35% of the heap growth.
 //! * Low: The heap is below 50% of the memory capacity.
@@ -13,27 +15,45 @@
 //! This is necessary because the IC does not provide runtime information about the implemented
 //! Wasm memory capacity in 64-bit. This capacity may also increase over time with newer IC versions.
 
-use crate::gc::incremental::{get_incremental_gc_state, partitioned_heap::PARTITION_SIZE};
+use crate::gc::incremental::{
+    get_incremental_gc_state,
+    partitioned_heap::{PARTITION_SIZE, is_defragmentation_requested, set_critical_memory},
+    policy::{SchedulingPolicy, get_scheduling_policy},
+};
 use crate::memory::ic::partitioned_memory::{get_heap_size, get_total_allocations};
 use crate::types::Bytes;
 use motoko_rts_macros::{classical_persistence, enhanced_orthogonal_persistence};
 
+/// Heap sizes, represented in 64-bit as the 32-bit memory capacity of 4GB exceeds `usize`
+/// with classical persistence.
 struct HeapThresholds {
-    critical_heap_limit: Bytes<usize>,
-    medium_heap_limit: Bytes<usize>,
+    critical_heap_limit: Bytes<u64>,
+    medium_heap_limit: Bytes<u64>,
+}
+
+impl HeapThresholds {
+    unsafe fn of_capacity(capacity: u64) -> HeapThresholds {
+        let policy = get_scheduling_policy();
+        HeapThresholds {
+            critical_heap_limit: Bytes(SchedulingPolicy::heap_limit(
+                capacity,
+                policy.critical_heap_limit,
+            )),
+            medium_heap_limit: Bytes(SchedulingPolicy::heap_limit(
+                capacity,
+                policy.medium_heap_limit,
+            )),
+        }
+    }
 }
 
 #[classical_persistence]
 impl HeapThresholds {
     unsafe fn get() -> HeapThresholds {
-        use crate::constants::{GB, MB};
+        use crate::constants::GB;
 
-        const CRITICAL_HEAP_LIMIT: Bytes<usize> = Bytes(2 * GB + 256 * MB);
-        const MEDIUM_HEAP_LIMIT: Bytes<usize> = Bytes(1 * GB);
-        HeapThresholds {
-            critical_heap_limit: CRITICAL_HEAP_LIMIT,
-            medium_heap_limit: MEDIUM_HEAP_LIMIT,
-        }
+        const MEMORY_CAPACITY: u64 = 4 * GB as u64;
+        HeapThresholds::of_capacity(MEMORY_CAPACITY)
     }
 }
 
@@ -49,7 +69,7 @@ impl HeapThresholds {
         use crate::memory::ic::enhanced_memory::{minimum_memory_capacity, probe_wasm_memory};
 
         let thresholds = HeapThresholds::get_without_probing();
-        let heap_size = get_heap_size();
+        let heap_size = Bytes(get_heap_size().as_usize() as u64);
         // Only if the heap size seems to be critically low, try to expand the Wasm memory beyond the
         // assumed current available memory.
         if heap_size > thresholds.critical_heap_limit
@@ -63,37 +83,34 @@ impl HeapThresholds {
     }
 
     /// Obtain the heap thresholds without memory probing:
-    /// The critical limit is 80% of the currently known memory size.
-    /// The medium limit is 50% of the currently known memory size.
+    /// The limits are relative to the currently known memory size.
     unsafe fn get_without_probing() -> HeapThresholds {
-        use crate::memory::ic::enhanced_memory::minimum_memory_capacity;
+        use crate::memory::maximum_memory_size;
 
-        let available_memory = minimum_memory_capacity().as_usize();
-        let critical_heap_limit = Bytes(available_memory / 10 * 8); // 80%
-        let medium_heap_limit: Bytes<usize> = Bytes(available_memory / 2); // 50%
-        HeapThresholds {
-            critical_heap_limit,
-            medium_heap_limit,
-        }
+        HeapThresholds::of_capacity(maximum_memory_size().0)
     }
 }
 
 /// Determine whether a new GC run should be started based on the heap growth since
-/// the last GC completion.
+/// the last GC completion. A pending defragmentation request for a huge object
+/// allocation also starts a new GC run.
 pub unsafe fn should_start_gc() -> bool {
+    if is_defragmentation_requested() {
+        return true;
+    }
     let heap_size = get_heap_size();
+    let comparable_heap_size = Bytes(heap_size.as_usize() as u64);
 
-    const CRITICAL_GROWTH_THRESHOLD: f64 = 0.01;
-    const MEDIUM_GROWTH_THRESHOLD: f64 = 0.35;
-    const LOW_GROWTH_THRESHOLD: f64 = 0.65;
-
+    let policy = get_scheduling_policy();
     let heap_thresholds = HeapThresholds::get();
-    let growth_threshold = if heap_size > heap_thresholds.critical_heap_limit {
-        CRITICAL_GROWTH_THRESHOLD
-    } else if heap_size > heap_thresholds.medium_heap_limit {
-        MEDIUM_GROWTH_THRESHOLD
+    // A huge object allocation may no longer be able to extend the heap.
+    set_critical_memory(comparable_heap_size > heap_thresholds.critical_heap_limit);
+    let growth_threshold = if comparable_heap_size > heap_thresholds.critical_heap_limit {
+        policy.critical_growth_threshold
+    } else if comparable_heap_size > heap_thresholds.medium_heap_limit {
+        policy.medium_growth_threshold
     } else {
-        LOW_GROWTH_THRESHOLD
+        policy.low_growth_threshold
     };
 
     let current_allocations = get_total_allocations();
@@ -101,5 +118,6 @@ pub unsafe fn should_start_gc() -> bool {
     debug_assert!(current_allocations >= state.statistics.last_allocations);
     let absolute_growth = current_allocations - state.statistics.last_allocations;
     let relative_growth = absolute_growth.0 as f64 / heap_size.as_usize() as f64;
-    relative_growth > growth_threshold && heap_size.as_usize() >= PARTITION_SIZE
+    relative_growth > SchedulingPolicy::growth_ratio(growth_threshold)
+        && heap_size.as_usize() >= PARTITION_SIZE
 }
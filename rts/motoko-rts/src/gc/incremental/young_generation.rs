@@ -0,0 +1,372 @@
+//! Optional young generation of the incremental GC.
+//!
+//! Short-lived objects are reclaimed by young collections without a full-heap
+//! snapshot-at-the-beginning marking. The young generation is disabled by default and
+//! can be enabled by `set_gc_young_generation` with a nursery size.
+//!
+//! During the GC pause, new objects are allocated in nursery partitions that only contain
+//! young objects. All other partitions are considered old. Pointer writes from old locations
+//! to young objects are recorded in a remembered set by the write barrier. Huge objects that
+//! are allocated in the nursery epoch are not moved but are scanned like roots, since their
+//! fields are initialized without barrier.
+//!
+//! When the nursery partitions exceed the configured size, a young collection is performed
+//! at a GC schedule point, i.e. on an empty call stack:
+//! * The young objects reachable from the roots, the remembered set, and the young huge
+//!   objects are promoted by copying them to a tenure partition that is old. Weak references
+//!   are conservatively treated as strong references.
+//! * Pointer forwarding serves for updating the references to the promoted objects.
+//! * All nursery partitions are freed and the allocation continues in a new nursery partition.
+//!
+//! A young collection is not bounded in time, such that the nursery size should be moderate.
+//!
+//! The nursery is dissolved at the start of a full GC run and when the GC is stopped, i.e. the
+//! young objects simply become old. A new nursery epoch is started at the next GC schedule
+//! point in the GC pause. The nursery is also dissolved if more partitions or huge objects are
+//! allocated in the nursery epoch than can be tracked. As the nursery state is not needed for
+//! the heap consistency, it is not retained across upgrades. Only the configured nursery size
+//! is stored in the persistent metadata with enhanced orthogonal persistence.
+
+#[cfg(feature = "ic")]
+use motoko_rts_macros::enhanced_orthogonal_persistence;
+
+use crate::{
+    gc::remembered_set::RememberedSet, mem_utils::memcpy_words, memory::Memory, rts_trap_with,
+    types::*, visitor::visit_pointer_fields,
+};
+
+use super::{
+    Phase, RUNNING_GC_INCREMENT, State,
+    mark_stack::{MarkStack, STACK_EMPTY},
+    partitioned_heap::{PARTITION_SIZE, PartitionedHeap},
+    roots::{Roots, visit_roots},
+};
+
+/// Maximum number of partitions of a nursery epoch.
+const MAX_YOUNG_PARTITIONS: usize = 32;
+
+/// Maximum number of huge objects allocated in a nursery epoch.
+const MAX_YOUNG_LARGE_OBJECTS: usize = 32;
+
+/// Upper bound of the configurable nursery size, leaving room for the allocations
+/// during the last message before a young collection.
+pub const MAXIMUM_NURSERY_SIZE: usize = MAX_YOUNG_PARTITIONS / 2 * PARTITION_SIZE;
+
+/// Temporary state, not part of the persistent metadata.
+struct Nursery {
+    active: bool,
+    /// More partitions or huge objects than trackable have been allocated in this epoch.
+    overflowed: bool,
+    /// Start addresses of the young space in the nursery partitions. The static space
+    /// before may contain the tail of an old huge object.
+    young_spaces: [usize; MAX_YOUNG_PARTITIONS],
+    number_of_partitions: usize,
+    large_objects: [usize; MAX_YOUNG_LARGE_OBJECTS],
+    number_of_large_objects: usize,
+    /// Old partition that receives the promoted objects.
+    tenure_index: usize,
+}
+
+static mut NURSERY: Nursery = Nursery {
+    active: false,
+    overflowed: false,
+    young_spaces: [0; MAX_YOUNG_PARTITIONS],
+    number_of_partitions: 0,
+    large_objects: [0; MAX_YOUNG_LARGE_OBJECTS],
+    number_of_large_objects: 0,
+    tenure_index: 0,
+};
+
+/// Old locations referring to young objects, lazily created in a nursery epoch.
+/// Kept separate from `NURSERY`, as recording a location may allocate a nursery partition.
+static mut REMEMBERED_SET: Option<RememberedSet> = None;
+
+#[cfg(feature = "ic")]
+#[enhanced_orthogonal_persistence]
+unsafe fn nursery_size() -> &'static mut usize {
+    crate::persistence::get_young_generation_size()
+}
+
+/// Without enhanced orthogonal persistence, the nursery size is not retained across upgrades.
+#[cfg(any(not(feature = "ic"), not(feature = "enhanced_orthogonal_persistence")))]
+static mut NURSERY_SIZE: usize = 0;
+
+#[cfg(any(not(feature = "ic"), not(feature = "enhanced_orthogonal_persistence")))]
+unsafe fn nursery_size() -> &'static mut usize {
+    &mut NURSERY_SIZE
+}
+
+/// Enable the young generation with the given nursery size in bytes, or disable it with zero.
+/// Takes effect at the next GC schedule point.
+/// Traps if the size exceeds `MAXIMUM_NURSERY_SIZE`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn set_gc_young_generation(size: usize) {
+    if size > MAXIMUM_NURSERY_SIZE {
+        rts_trap_with("Invalid young generation size");
+    }
+    *nursery_size() = size;
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn get_gc_young_generation() -> usize {
+    *nursery_size()
+}
+
+/// Determines whether a nursery epoch is active.
+pub unsafe fn is_nursery_active() -> bool {
+    NURSERY.active
+}
+
+/// Determines whether `address` lies in the young space of a nursery partition.
+pub unsafe fn is_young_address(address: usize) -> bool {
+    let index = address / PARTITION_SIZE;
+    NURSERY.young_spaces[..NURSERY.number_of_partitions]
+        .iter()
+        .any(|start| *start / PARTITION_SIZE == index && address >= *start)
+}
+
+unsafe fn nursery_partition_indices() -> impl Iterator<Item = usize> {
+    NURSERY.young_spaces[..NURSERY.number_of_partitions]
+        .iter()
+        .map(|start| *start / PARTITION_SIZE)
+}
+
+unsafe fn is_young_value(value: Value, heap_base: usize) -> bool {
+    value.points_to_or_beyond(heap_base) && is_young_address(value.get_ptr())
+}
+
+/// Called by the partitioned heap when switching to a new allocation partition,
+/// with the start of the dynamic space of that partition.
+pub(super) unsafe fn record_new_partition(dynamic_space_start: usize) {
+    if NURSERY.active {
+        if NURSERY.number_of_partitions == MAX_YOUNG_PARTITIONS {
+            NURSERY.overflowed = true;
+        } else {
+            NURSERY.young_spaces[NURSERY.number_of_partitions] = dynamic_space_start;
+            NURSERY.number_of_partitions += 1;
+        }
+    }
+}
+
+/// Called by the partitioned heap on a huge object allocation.
+pub(super) unsafe fn record_large_object(address: usize) {
+    if NURSERY.active {
+        if NURSERY.number_of_large_objects == MAX_YOUNG_LARGE_OBJECTS {
+            NURSERY.overflowed = true;
+        } else {
+            NURSERY.large_objects[NURSERY.number_of_large_objects] = address;
+            NURSERY.number_of_large_objects += 1;
+        }
+    }
+}
+
+/// Write barrier to be called AFTER a pointer write to `location`.
+/// Records old locations that refer to young objects.
+/// Only effective in an active nursery epoch, i.e. during the GC pause.
+pub unsafe fn post_write_barrier<M: Memory>(mem: &mut M, heap_base: usize, location: *mut Value) {
+    if NURSERY.active
+        && is_young_value(*location, heap_base)
+        && !is_young_address(location as usize)
+    {
+        remember_location(mem, location);
+    }
+}
+
+#[inline(never)]
+unsafe fn remember_location<M: Memory>(mem: &mut M, location: *mut Value) {
+    let value = Value::from_raw(location as usize);
+    if REMEMBERED_SET.is_none() {
+        let remembered_set = RememberedSet::new(mem);
+        REMEMBERED_SET = Some(remembered_set);
+    }
+    REMEMBERED_SET.as_mut().unwrap().insert(mem, value);
+}
+
+/// Number of old locations that have been recorded in the current nursery epoch.
+pub unsafe fn remembered_locations() -> usize {
+    REMEMBERED_SET.as_ref().map_or(0, |set| set.count())
+}
+
+/// Occupied size of the nursery partitions.
+pub unsafe fn nursery_occupation(heap: &PartitionedHeap) -> usize {
+    nursery_partition_indices()
+        .map(|index| heap.get_partition(index).dynamic_size())
+        .sum()
+}
+
+/// Start a new nursery epoch. The existing objects are thereby considered old.
+pub unsafe fn start_nursery<M: Memory>(mem: &mut M, state: &mut State) {
+    debug_assert!(state.phase == Phase::Pause);
+    debug_assert!(!NURSERY.active);
+    debug_assert!(REMEMBERED_SET.is_none());
+    let heap = &mut state.partitioned_heap;
+    NURSERY.active = true;
+    NURSERY.tenure_index = heap.allocation_partition_index();
+    heap.open_allocation_partition(mem);
+}
+
+/// End the nursery epoch without collection. The young objects thereby become old.
+pub unsafe fn dissolve_nursery() {
+    NURSERY.active = false;
+    NURSERY.overflowed = false;
+    NURSERY.number_of_partitions = 0;
+    NURSERY.number_of_large_objects = 0;
+    REMEMBERED_SET = None;
+}
+
+/// Young generation handling at a GC schedule point in the GC pause.
+/// Starts a new nursery epoch if needed or performs a young collection if the nursery
+/// exceeds the configured size.
+/// Only to be called when the call stack is empty as pointers on stack are not updated.
+pub unsafe fn schedule_young_collection<M: Memory>(mem: &mut M, state: &mut State, roots: Roots) {
+    debug_assert!(state.phase == Phase::Pause);
+    let size = *nursery_size();
+    if size == 0 {
+        dissolve_nursery();
+    } else if !NURSERY.active {
+        start_nursery(mem, state);
+    } else if NURSERY.overflowed {
+        dissolve_nursery();
+        start_nursery(mem, state);
+    } else if nursery_occupation(&state.partitioned_heap) >= size {
+        collect_young_generation(mem, state, roots);
+    }
+}
+
+/// Promote the reachable young objects, free the nursery, and start a new nursery epoch.
+/// Only to be called when the call stack is empty as pointers on stack are not updated.
+pub unsafe fn collect_young_generation<M: Memory>(mem: &mut M, state: &mut State, roots: Roots) {
+    debug_assert!(state.phase == Phase::Pause);
+    debug_assert!(NURSERY.active);
+    if NURSERY.overflowed {
+        // Untracked young objects may be referred to without a remembered location.
+        dissolve_nursery();
+        start_nursery(mem, state);
+        return;
+    }
+    debug_assert!(!RUNNING_GC_INCREMENT);
+    RUNNING_GC_INCREMENT = true;
+    let heap = &mut state.partitioned_heap;
+    let mut collection = YoungCollection::new(mem, heap);
+    collection.promote_survivors(roots);
+    let promoted_size = collection.promoted_size;
+
+    // Partitions allocated during the collection only hold temporary data.
+    let mut nursery_partitions = [0; MAX_YOUNG_PARTITIONS];
+    let number_of_partitions = NURSERY.number_of_partitions;
+    for (index, partition) in nursery_partition_indices().enumerate() {
+        nursery_partitions[index] = partition;
+    }
+    debug_assert!(!NURSERY.overflowed);
+    dissolve_nursery();
+    NURSERY.active = true;
+    let reclaimed = heap.free_young_partitions(
+        mem,
+        &nursery_partitions[..number_of_partitions],
+        promoted_size,
+    );
+    // Reclaimed young objects do not count as heap growth for scheduling a full GC run.
+    #[cfg(feature = "ic")]
+    {
+        state.statistics.last_allocations += Bytes(reclaimed as u64);
+    }
+    #[cfg(not(feature = "ic"))]
+    let _ = reclaimed;
+    RUNNING_GC_INCREMENT = false;
+}
+
+struct YoungCollection<'a, M: Memory> {
+    mem: &'a mut M,
+    heap: &'a mut PartitionedHeap,
+    worklist: MarkStack,
+    promoted_size: usize,
+}
+
+impl<'a, M: Memory + 'a> YoungCollection<'a, M> {
+    unsafe fn new(mem: &'a mut M, heap: &'a mut PartitionedHeap) -> YoungCollection<'a, M> {
+        let worklist = MarkStack::new(mem);
+        YoungCollection {
+            mem,
+            heap,
+            worklist,
+            promoted_size: 0,
+        }
+    }
+
+    unsafe fn promote_survivors(&mut self, roots: Roots) {
+        let heap_base = self.heap.base_address();
+        visit_roots(roots, heap_base, self, |collection, field| {
+            collection.promote_field(field);
+        });
+        if let Some(remembered_set) = REMEMBERED_SET.as_ref() {
+            let mut iterator = remembered_set.iterate();
+            while iterator.has_next() {
+                let location = iterator.current().get_raw() as *mut Value;
+                self.promote_field(location);
+                iterator.next();
+            }
+        }
+        for index in 0..NURSERY.number_of_large_objects {
+            let object = NURSERY.large_objects[index] as *mut Obj;
+            self.promote_fields(object);
+        }
+        loop {
+            let value = self.worklist.pop();
+            if value == STACK_EMPTY {
+                break;
+            }
+            self.promote_fields(value.get_ptr() as *mut Obj);
+        }
+    }
+
+    unsafe fn promote_fields(&mut self, object: *mut Obj) {
+        let heap_base = self.heap.base_address();
+        visit_pointer_fields(
+            self,
+            object,
+            object.tag(),
+            heap_base,
+            |collection, field| collection.promote_field(field),
+            |_, _, array| array.len(),
+        );
+    }
+
+    unsafe fn promote_field(&mut self, field: *mut Value) {
+        let value = *field;
+        if is_young_value(value, self.heap.base_address()) {
+            let original = value.get_ptr() as *mut Obj;
+            if !original.is_forwarded() {
+                self.promote_object(original);
+            }
+            *field = (*original).forward;
+        }
+    }
+
+    unsafe fn promote_object(&mut self, original: *mut Obj) {
+        debug_assert!(is_object_tag(original.tag()));
+        let size = block_size(original as usize);
+        let new_address = self.allocate_tenured(size);
+        let copy = new_address.get_ptr() as *mut Obj;
+        memcpy_words(copy as usize, original as usize, size);
+        (*copy).forward = new_address;
+        (*original).forward = new_address;
+        debug_assert!(!copy.is_forwarded());
+        debug_assert!(original.is_forwarded());
+        self.promoted_size += size.to_bytes().as_usize();
+        self.worklist.push(self.mem, new_address);
+    }
+
+    unsafe fn allocate_tenured(&mut self, size: Words<usize>) -> Value {
+        let tenure_index = NURSERY.tenure_index;
+        if let Some(address) = self
+            .heap
+            .allocate_in_partition(self.mem, tenure_index, size)
+        {
+            return address;
+        }
+        NURSERY.tenure_index = self.heap.allocate_side_partition(self.mem, size);
+        self.heap
+            .allocate_in_partition(self.mem, NURSERY.tenure_index, size)
+            .unwrap()
+    }
+}
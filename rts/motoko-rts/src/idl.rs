@@ -5,19 +5,22 @@ use crate::buf::{Buf, read_byte, read_word, skip_leb128};
 use crate::idl_trap_with;
 
 use crate::memory::{Memory, alloc_blob};
-use crate::types::{TAG_BLOB_B, Words};
+use crate::print::WriteBuf;
+use crate::types::{Bytes, TAG_BLOB_B, Words};
 use crate::utf8::utf8_validate;
 
 use core::cmp::min;
+use core::fmt::{Display, Formatter, Result as FmtResult, Write};
 
 use motoko_rts_macros::{enhanced_orthogonal_persistence, ic_mem_fn};
 
 use crate::libc_declarations::{c_void, memcmp};
 
+#[cfg(feature = "ic")]
 #[enhanced_orthogonal_persistence]
 use crate::types::Value;
 
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     // check instruction decoding limit, exported by moc
     pub fn idl_limit_check(decrement: bool, value_count: u64);
 }
@@ -61,6 +64,11 @@ const IDL_CON_alias: i32 = 1;
 
 const IDL_PRIM_lowest: i32 = -17;
 
+// Function annotations
+const IDL_FUNC_query: u8 = 1;
+const IDL_FUNC_oneway: u8 = 2;
+const IDL_FUNC_composite_query: u8 = 3;
+
 // Only used for memory compatiblity checks for orthogonal persistence.
 #[enhanced_orthogonal_persistence]
 const IDL_EXT_blob: i32 = -129;
@@ -126,23 +134,79 @@ unsafe fn utf8_cmp(len1: usize, p1: *mut u8, len2: usize, p2: *mut u8) -> i32 {
     }
 }
 
-unsafe fn check_typearg(mode: CompatibilityMode, ty: i32, n_types: u32) {
+/// Position in a type description, to give context in error messages.
+#[derive(Clone, Copy)]
+enum TypePosition {
+    /// Type table entry.
+    Entry(u32),
+    /// Record or variant field of a type table entry, identified by its field hash.
+    Field(u32, u32),
+    /// List of main (argument) types.
+    MainTypes,
+}
+
+impl Display for TypePosition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match *self {
+            TypePosition::Entry(index) => write!(f, "type T{index}"),
+            TypePosition::Field(index, hash) => write!(f, "field #{hash} of type T{index}"),
+            TypePosition::MainTypes => write!(f, "argument types"),
+        }
+    }
+}
+
+/// Traps with an IDL error that names the position in the type description, e.g.
+/// `invalid type argument 42 (field #4107 of type T3)`.
+fn idl_trap_at(msg: &str, position: TypePosition) -> ! {
+    // The trap message is limited to 512 bytes, see `trap_with_prefix`.
+    let mut buffer = [0u8; 512];
+    let mut message = WriteBuf::new(&mut buffer);
+    let _ = write!(&mut message, "{msg} ({position})");
+    idl_trap_with(message.as_str())
+}
+
+/// Reads the annotations of a function type and returns its mode, i.e. the annotation,
+/// or 0 for a plain update function. As `query`, `oneway` and `composite_query` are
+/// mutually exclusive, a function type carries at most one annotation.
+/// NB: The sub type checks below compare function types by their mode.
+unsafe fn read_func_mode(buf: *mut Buf, position: TypePosition) -> u8 {
+    let mut mode = 0;
+    for _ in 0..leb128_decode(buf) {
+        let a = read_byte(buf);
+        if !(IDL_FUNC_query..=IDL_FUNC_composite_query).contains(&a) {
+            idl_trap_at("func annotation not within 1..3", position);
+        }
+        if mode != 0 {
+            idl_trap_at("func annotations are mutually exclusive", position);
+        }
+        mode = a;
+    }
+    mode
+}
+
+unsafe fn check_typearg(mode: CompatibilityMode, ty: i32, n_types: u32, position: TypePosition) {
     // Arguments to type constructors can be primitive types or type indices
     if !(is_primitive_type(mode, ty) || (ty >= 0 && (ty as u32) < n_types)) {
-        idl_trap_with("invalid type argument");
+        let mut buffer = [0u8; 64];
+        let mut message = WriteBuf::new(&mut buffer);
+        let _ = write!(&mut message, "invalid type argument {ty}");
+        idl_trap_at(message.as_str(), position);
     }
 }
 
-unsafe fn parse_fields(mode: CompatibilityMode, buf: *mut Buf, n_types: u32) {
+unsafe fn parse_fields(mode: CompatibilityMode, buf: *mut Buf, n_types: u32, index: u32) {
     let mut next_valid = 0;
     for n in (1..=leb128_decode(buf)).rev() {
         let tag = leb128_decode(buf);
         if (tag < next_valid) || (tag == 0xFFFFFFFF && n > 1) {
-            idl_trap_with("variant or record tag out of order");
+            idl_trap_at(
+                "variant or record tag out of order",
+                TypePosition::Field(index, tag),
+            );
         }
         next_valid = tag + 1;
         let t = sleb128_decode(buf);
-        check_typearg(mode, t, n_types);
+        check_typearg(mode, t, n_types, TypePosition::Field(index, tag));
     }
 }
 
@@ -172,7 +236,7 @@ unsafe fn alloc<M: Memory>(mem: &mut M, size: Words<usize>) -> *mut u8 {
 ///
 /// `extended` denotes Candidish stabilization format, otherwise it assumes the pure Candid format.
 #[ic_mem_fn]
-unsafe fn parse_idl_header<M: Memory>(
+pub unsafe fn parse_idl_header<M: Memory>(
     mem: &mut M,
     extended: bool,
     buf: *mut Buf,
@@ -186,6 +250,7 @@ unsafe fn parse_idl_header<M: Memory>(
     } else {
         CompatibilityMode::PureCandid
     };
+    start_accounting(extended);
 
     if (*buf).ptr == (*buf).end {
         idl_trap_with(
@@ -222,6 +287,7 @@ unsafe fn parse_idl_header<M: Memory>(
     // Go through the table
     for i in 0..n_types {
         *typtbl.add(i as usize) = (*buf).ptr;
+        let position = TypePosition::Entry(i);
 
         let ty = sleb128_decode(buf);
 
@@ -229,44 +295,37 @@ unsafe fn parse_idl_header<M: Memory>(
             // internal
             // See Note [mutable stable values] in codegen/compile.ml
             let t = sleb128_decode(buf);
-            check_typearg(mode, t, n_types);
+            check_typearg(mode, t, n_types, position);
         } else if ty >= 0 {
-            idl_trap_with("illegal type table"); // illegal
+            idl_trap_at("illegal type table", position); // illegal
         } else if is_primitive_type(mode, ty) {
             // illegal
-            idl_trap_with("primitive type in type table");
+            idl_trap_at("primitive type in type table", position);
         } else if ty == IDL_CON_opt {
             let t = sleb128_decode(buf);
-            check_typearg(mode, t, n_types);
+            check_typearg(mode, t, n_types, position);
         } else if ty == IDL_CON_vec {
             let t = sleb128_decode(buf);
-            check_typearg(mode, t, n_types);
+            check_typearg(mode, t, n_types, position);
         } else if ty == IDL_CON_record {
-            parse_fields(mode, buf, n_types);
+            parse_fields(mode, buf, n_types, i);
         } else if ty == IDL_CON_variant {
-            parse_fields(mode, buf, n_types);
+            parse_fields(mode, buf, n_types, i);
         } else if ty == IDL_CON_func {
             // Arg types
             for _ in 0..leb128_decode(buf) {
                 let t = sleb128_decode(buf);
-                check_typearg(mode, t, n_types);
+                check_typearg(mode, t, n_types, position);
             }
             // Ret types
-            for _ in 0..leb128_decode(buf) {
+            let n_rets = leb128_decode(buf);
+            for _ in 0..n_rets {
                 let t = sleb128_decode(buf);
-                check_typearg(mode, t, n_types);
+                check_typearg(mode, t, n_types, position);
             }
             // Annotations
-            for _ in 0..leb128_decode(buf) {
-                let a = read_byte(buf);
-                if !(1 <= a && a <= 3) {
-                    idl_trap_with("func annotation not within 1..3");
-                }
-                // TODO: shouldn't we also check
-                // * 1 (query) or 2 (oneway), but not both
-                // * 2 -> |Ret types| == 0
-                // c.f. https://github.com/dfinity/candid/issues/318
-                // NB: if this code changes, change sub type check below accordingly
+            if read_func_mode(buf, position) == IDL_FUNC_oneway && n_rets != 0 {
+                idl_trap_at("oneway func has results", position);
             }
         } else if ty == IDL_CON_service {
             let mut last_len: u32 = 0 as u32;
@@ -285,7 +344,7 @@ unsafe fn parse_idl_header<M: Memory>(
                         min(last_len, len) as usize,
                     );
                     if cmp > 0 || (cmp == 0 && last_len >= len) {
-                        idl_trap_with("service method names out of order");
+                        idl_trap_at("service method names out of order", position);
                     }
                 }
                 last_len = len;
@@ -293,7 +352,7 @@ unsafe fn parse_idl_header<M: Memory>(
 
                 // Type
                 let t = sleb128_decode(buf);
-                check_typearg(mode, t, n_types);
+                check_typearg(mode, t, n_types, position);
             }
         } else {
             // Future type
@@ -325,7 +384,10 @@ unsafe fn parse_idl_header<M: Memory>(
                 // Type
                 let t = sleb128_decode(&mut tmp_buf);
                 if !(t >= 0 && (t as u32) < n_types) {
-                    idl_trap_with("service method arg not a constructor type");
+                    idl_trap_at(
+                        "service method arg not a constructor type",
+                        TypePosition::Entry(i),
+                    );
                 }
                 let mut tmp_buf2 = Buf {
                     end: (*buf).end,
@@ -333,7 +395,10 @@ unsafe fn parse_idl_header<M: Memory>(
                 };
                 let mty = sleb128_decode(&mut tmp_buf2);
                 if mty != IDL_CON_func {
-                    idl_trap_with("service method arg not a function type");
+                    idl_trap_at(
+                        "service method arg not a function type",
+                        TypePosition::Entry(i),
+                    );
                 }
             }
         }
@@ -343,7 +408,7 @@ unsafe fn parse_idl_header<M: Memory>(
     *main_types_out = (*buf).ptr;
     for _ in 0..leb128_decode(buf) {
         let t = sleb128_decode(buf);
-        check_typearg(mode, t, n_types);
+        check_typearg(mode, t, n_types, TypePosition::MainTypes);
     }
 
     *typtbl_out = typtbl;
@@ -373,35 +438,40 @@ unsafe fn skip_any_vec(buf: *mut Buf, typtbl: *mut *mut u8, t: i32, count: u32)
     if count == 0 {
         return;
     }
-    idl_limit_check(false, count as u64);
+    skip_limit_check(false, count as u64);
     let ptr_before = (*buf).ptr;
-    skip_any(buf, typtbl, t, 0);
+    skip_value(buf, typtbl, t, 0);
     let ptr_after = (*buf).ptr;
     if ptr_after == ptr_before {
         // this looks like a vec null bomb, or equivalent, where skip_any
         // makes no progress. No point in calling it over and over again.
         // (This is easier to detect this way than by analyzing the type table,
         // where we’d have to chase single-field-records.)
-        idl_limit_check(true, (count - 1) as u64);
+        skip_limit_check(true, (count - 1) as u64);
         return;
     }
     for _ in 1..count {
-        skip_any(buf, typtbl, t, 0);
+        skip_value(buf, typtbl, t, 0);
     }
 }
 
 // Assumes buf is the encoding of type t, and fast-forwards past that
 // Assumes all type references in the typtbl are already checked
-//
+#[unsafe(no_mangle)]
+pub unsafe extern "C-unwind" fn skip_any(buf: *mut Buf, typtbl: *mut *mut u8, t: i32, depth: i32) {
+    let start = (*buf).ptr;
+    skip_value(buf, typtbl, t, depth);
+    account_skipped_bytes(start, (*buf).ptr);
+}
+
 // This is currently implemented recursively, but we could
 // do this in a loop (by maintaining a stack of the t arguments)
-#[unsafe(no_mangle)]
-unsafe extern "C" fn skip_any(buf: *mut Buf, typtbl: *mut *mut u8, t: i32, depth: i32) {
+unsafe fn skip_value(buf: *mut Buf, typtbl: *mut *mut u8, t: i32, depth: i32) {
     if depth > 100 {
         idl_trap_with("skip_any: too deeply nested record");
     }
 
-    idl_limit_check(true, 1); // decrement and check quota
+    skip_limit_check(true, 1); // decrement and check quota
 
     if t < 0 {
         // Primitive type
@@ -453,7 +523,7 @@ unsafe extern "C" fn skip_any(buf: *mut Buf, typtbl: *mut *mut u8, t: i32, depth
             IDL_CON_opt => {
                 let it = sleb128_decode(&mut tb);
                 if read_byte_tag(buf) != 0 {
-                    skip_any(buf, typtbl, it, 0);
+                    skip_value(buf, typtbl, it, 0);
                 }
             }
             IDL_CON_vec => {
@@ -468,16 +538,16 @@ unsafe extern "C" fn skip_any(buf: *mut Buf, typtbl: *mut *mut u8, t: i32, depth
                     // This is just a quick check; we should be keeping
                     // track of all enclosing records to detect larger loops
                     if it == t {
-                        idl_trap_with("skip_any: recursive record");
+                        idl_trap_with_type("skip_any: recursive record", typtbl, (*buf).end, t);
                     }
-                    skip_any(buf, typtbl, it, depth + 1);
+                    skip_value(buf, typtbl, it, depth + 1);
                 }
             }
             IDL_CON_variant => {
                 let n = leb128_decode(&mut tb);
                 let i = leb128_decode(buf);
                 if i >= n {
-                    idl_trap_with("skip_any: variant tag too large");
+                    idl_trap_with_type("skip_any: variant tag too large", typtbl, (*buf).end, t);
                 }
                 for _ in 0..i {
                     skip_leb128(&mut tb);
@@ -485,14 +555,14 @@ unsafe extern "C" fn skip_any(buf: *mut Buf, typtbl: *mut *mut u8, t: i32, depth
                 }
                 skip_leb128(&mut tb);
                 let it = sleb128_decode(&mut tb);
-                skip_any(buf, typtbl, it, 0);
+                skip_value(buf, typtbl, it, 0);
             }
             IDL_CON_func => {
                 if read_byte_tag(buf) == 0 {
-                    idl_trap_with("skip_any: skipping references");
+                    idl_trap_with_type("skip_any: skipping references", typtbl, (*buf).end, t);
                 } else {
                     if read_byte_tag(buf) == 0 {
-                        idl_trap_with("skip_any: skipping references");
+                        idl_trap_with_type("skip_any: skipping references", typtbl, (*buf).end, t);
                     } else {
                         skip_blob(buf)
                     }
@@ -501,7 +571,7 @@ unsafe extern "C" fn skip_any(buf: *mut Buf, typtbl: *mut *mut u8, t: i32, depth
             }
             IDL_CON_service => {
                 if read_byte_tag(buf) == 0 {
-                    idl_trap_with("skip_any: skipping references");
+                    idl_trap_with_type("skip_any: skipping references", typtbl, (*buf).end, t);
                 } else {
                     skip_blob(buf)
                 }
@@ -513,7 +583,7 @@ unsafe extern "C" fn skip_any(buf: *mut Buf, typtbl: *mut *mut u8, t: i32, depth
                 if tag == 0 {
                     buf.advance(8);
                     // this is the contents (not a reference)
-                    skip_any(buf, typtbl, it, 0);
+                    skip_value(buf, typtbl, it, 0);
                 } else {
                     buf.advance(4);
                 }
@@ -524,7 +594,7 @@ unsafe extern "C" fn skip_any(buf: *mut Buf, typtbl: *mut *mut u8, t: i32, depth
                 let n_ref = leb128_decode(buf);
                 buf.advance(n_data as usize);
                 if n_ref > 0 {
-                    idl_trap_with("skip_any: skipping references");
+                    idl_trap_with_type("skip_any: skipping references", typtbl, (*buf).end, t);
                 }
             }
         }
@@ -596,6 +666,248 @@ unsafe extern "C" fn skip_fields(tb: *mut Buf, buf: *mut Buf, typtbl: *mut *mut
     }
 }
 
+//
+// Decoding statistics
+//
+// Accounting of the Candid deserializations of the current message, readable by the canister
+// after decoding, and an optional per-call value limit that is stricter than the quota of the
+// compiler-generated code (`idl_limit_check`). Stabilization formats are not accounted.
+//
+// The statistics accumulate over all deserializations of a message, i.e. of its arguments and
+// of any `from_candid`. As the RTS state persists between messages, they are reset at the start
+// of every message (`idl_start_message`). The per-call limit only applies to the values of the
+// next deserialization: `parse_idl_header` of a pure Candid message consumes the pending limit,
+// which is also cleared at the start of every message, such that it never applies to another
+// message.
+//
+
+struct DecodingStatistics {
+    /// Values charged to the value quota, whether decoded or skipped.
+    values: u64,
+    /// Bytes passed over by `skip_any` and `idl_vec_skip`.
+    skipped_bytes: u64,
+    /// Size of the memo table of the sub-type checks.
+    subtype_cache_size: Bytes<usize>,
+}
+
+static mut DECODING_STATISTICS: DecodingStatistics = DecodingStatistics {
+    values: 0,
+    skipped_bytes: 0,
+    subtype_cache_size: Bytes(0),
+};
+
+/// Whether the current deserialization is pure Candid, and therefore accounted.
+static mut ACCOUNTING: bool = false;
+
+/// Bound of the accounted values during the current deserialization, as given by its limit.
+static mut VALUE_LIMIT: u64 = u64::MAX;
+
+/// Value limit for the next deserialization, as set by `idl_set_value_limit`.
+static mut NEXT_VALUE_LIMIT: u64 = u64::MAX;
+
+/// Called by `parse_idl_header` at the start of a deserialization.
+unsafe fn start_accounting(extended: bool) {
+    ACCOUNTING = !extended;
+    if extended {
+        return;
+    }
+    VALUE_LIMIT = DECODING_STATISTICS.values.saturating_add(NEXT_VALUE_LIMIT);
+    NEXT_VALUE_LIMIT = u64::MAX;
+}
+
+/// Checks, and if `decrement` is set consumes, the quota for `value_count` values. Besides the
+/// quota of the compiler-generated code, this enforces the per-call limit.
+#[unsafe(no_mangle)]
+pub unsafe extern "C-unwind" fn idl_value_limit_check(decrement: bool, value_count: u64) {
+    idl_limit_check(decrement, value_count);
+    if !ACCOUNTING {
+        return;
+    }
+    let values = DECODING_STATISTICS.values.saturating_add(value_count);
+    if values > VALUE_LIMIT {
+        idl_trap_with("exceeded per-call value limit");
+    }
+    if decrement {
+        DECODING_STATISTICS.values = values;
+    }
+}
+
+unsafe fn account_skipped_bytes(start: *mut u8, end: *mut u8) {
+    if ACCOUNTING {
+        DECODING_STATISTICS.skipped_bytes += end.offset_from(start) as u64;
+    }
+}
+
+/// Limits the number of values of the next Candid deserialization of the current message,
+/// e.g. of `from_candid`, below the quota of the compiler-generated code.
+/// The limit is cleared when that deserialization or the next message starts.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn idl_set_value_limit(limit: u64) {
+    NEXT_VALUE_LIMIT = limit;
+}
+
+/// Called by the compiler-generated code at the start of every message, including callbacks.
+/// Resets the statistics and clears a pending value limit.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn idl_start_message() {
+    DECODING_STATISTICS = DecodingStatistics {
+        values: 0,
+        skipped_bytes: 0,
+        subtype_cache_size: Bytes(0),
+    };
+    NEXT_VALUE_LIMIT = u64::MAX;
+}
+
+/// Number of values of the Candid deserializations of the current message, whether decoded
+/// or skipped.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn idl_decoded_values() -> u64 {
+    DECODING_STATISTICS.values
+}
+
+/// Number of bytes skipped by the Candid deserializations of the current message, e.g. of
+/// extra arguments, of unexpected record fields, or of values failing the sub-type check.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn idl_skipped_bytes() -> u64 {
+    DECODING_STATISTICS.skipped_bytes
+}
+
+/// Total size in bytes of the sub-type check caches of the Candid deserializations of the
+/// current message.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn idl_subtype_cache_size() -> u64 {
+    DECODING_STATISTICS.subtype_cache_size.as_usize() as u64
+}
+
+//
+// Incremental value reader
+//
+// These functions walk a Candid value step by step instead of decoding it into heap values
+// at once, e.g. to decode the elements of a large vector on demand. They are RTS entry points
+// only: the compiler-generated deserialization does not use them and still decodes a vector
+// into an array at once, as Motoko has no lazy vector values to decode into.
+// They assume that the type table has been validated by `parse_idl_header` and are subject
+// to the same value quota (`idl_limit_check`) and accounting as the full decoding.
+//
+
+/// Values visited by the `idl_vec_next` in progress, or `None` otherwise.
+static mut WALKED_VALUES: Option<u64> = None;
+
+/// Checks, and if `decrement` is set consumes, the quota for `value_count` skipped values.
+/// While `idl_vec_next` steps over an element, the values are checked against the quota
+/// without consuming it, as the caller decodes that element afterwards.
+unsafe fn skip_limit_check(decrement: bool, value_count: u64) {
+    match WALKED_VALUES {
+        None => idl_value_limit_check(decrement, value_count),
+        Some(walked) => {
+            let walked = walked.saturating_add(value_count);
+            idl_value_limit_check(false, walked);
+            if decrement {
+                WALKED_VALUES = Some(walked);
+            }
+        }
+    }
+}
+
+/// Returns the type constructor of `t` without advancing `buf`: `t` itself for primitive
+/// types, otherwise the constructor of the type table entry.
+#[unsafe(no_mangle)]
+pub unsafe extern "C-unwind" fn idl_peek_type(buf: *mut Buf, typtbl: *mut *mut u8, t: i32) -> i32 {
+    if t < 0 {
+        return t;
+    }
+    let mut tb = Buf {
+        ptr: *typtbl.add(t as usize),
+        end: (*buf).end,
+    };
+    sleb128_decode(&mut tb)
+}
+
+/// Decodes the LEB128 number at the current position without advancing `buf`,
+/// e.g. the length of a vector or the index of a variant.
+#[unsafe(no_mangle)]
+pub unsafe extern "C-unwind" fn idl_peek_leb128(buf: *mut Buf) -> u32 {
+    let mut tmp_buf = Buf {
+        ptr: (*buf).ptr,
+        end: (*buf).end,
+    };
+    leb128_decode(&mut tmp_buf)
+}
+
+/// Starts reading a vector value of type `t`:
+///
+/// * traps if `t` is not a vector type
+///
+/// * checks (but does not consume) the value quota for the element count
+///
+/// * returns the element type (via pointer argument) and the element count
+///
+/// * advances `buf` to the first element
+#[unsafe(no_mangle)]
+pub unsafe extern "C-unwind" fn idl_vec_begin(
+    buf: *mut Buf,
+    typtbl: *mut *mut u8,
+    t: i32,
+    elem_type_out: *mut i32,
+) -> u32 {
+    if t < 0 {
+        idl_trap_with_type("idl_vec_begin: not a vector type", typtbl, (*buf).end, t);
+    }
+    let mut tb = Buf {
+        ptr: *typtbl.add(t as usize),
+        end: (*buf).end,
+    };
+    if sleb128_decode(&mut tb) != IDL_CON_vec {
+        idl_trap_with_type("idl_vec_begin: not a vector type", typtbl, (*buf).end, t);
+    }
+    *elem_type_out = sleb128_decode(&mut tb);
+    let count = leb128_decode(buf);
+    idl_value_limit_check(false, count as u64);
+    count
+}
+
+/// Steps over the next element of a vector started with `idl_vec_begin`.
+/// Returns a pointer to the encoding of that element, so that the caller can decode it
+/// (now or later) with a buffer starting there, or null if no elements are left.
+/// `remaining` is the number of elements left and is decremented.
+/// The element is neither charged to the value quota nor accounted as skipped.
+#[unsafe(no_mangle)]
+pub unsafe extern "C-unwind" fn idl_vec_next(
+    buf: *mut Buf,
+    typtbl: *mut *mut u8,
+    elem_type: i32,
+    remaining: *mut u32,
+) -> *mut u8 {
+    if *remaining == 0 {
+        return core::ptr::null_mut();
+    }
+    let element = (*buf).ptr;
+    WALKED_VALUES = Some(0);
+    skip_value(buf, typtbl, elem_type, 0);
+    WALKED_VALUES = None;
+    *remaining -= 1;
+    element
+}
+
+/// Skips `count` elements of a vector started with `idl_vec_begin`.
+/// `remaining` is the number of elements left and is decremented.
+#[unsafe(no_mangle)]
+pub unsafe extern "C-unwind" fn idl_vec_skip(
+    buf: *mut Buf,
+    typtbl: *mut *mut u8,
+    elem_type: i32,
+    remaining: *mut u32,
+    count: u32,
+) {
+    if count > *remaining {
+        idl_trap_with("idl_vec_skip: skipping past the end of the vector");
+    }
+    let start = (*buf).ptr;
+    skip_any_vec(buf, typtbl, elem_type, count);
+    account_skipped_bytes(start, (*buf).ptr);
+    *remaining -= count;
+}
+
 unsafe fn is_null_opt_reserved(typtbl: *mut *mut u8, end: *mut u8, t: i32) -> bool {
     if is_primitive_type(CompatibilityMode::PureCandid, t) {
         return t == IDL_PRIM_null || t == IDL_PRIM_reserved;
@@ -616,7 +928,7 @@ unsafe fn is_null_opt_reserved(typtbl: *mut *mut u8, end: *mut u8, t: i32) -> bo
 
 #[enhanced_orthogonal_persistence]
 #[derive(PartialEq, Clone, Copy, Debug)]
-pub(crate) enum TypeVariance {
+pub enum TypeVariance {
     Covariance,
     Contravariance,
     Invariance,
@@ -659,6 +971,370 @@ unsafe fn remember_memory_check(cache: &BitRel, variance: TypeVariance, t1: usiz
     }
 }
 
+/// Maximum number of path steps recorded for a failed memory compatibility check.
+/// Steps beyond this depth are dropped from the outer end of the path.
+#[enhanced_orthogonal_persistence]
+const MAX_TRACE_DEPTH: usize = 16;
+
+/// A step on the type path from the main actor to an incompatible type pair.
+#[enhanced_orthogonal_persistence]
+#[derive(Clone, Copy)]
+pub enum TypePathStep {
+    /// Record or actor field, identified by its field hash.
+    Field(u32),
+    /// Variant tag, identified by its field hash.
+    Tag(u32),
+    /// Tuple component by position.
+    Item(u32),
+    /// Function parameter by position. Swaps the roles of the old and the new type.
+    Argument(u32),
+    /// Function result by position.
+    Result(u32),
+    /// Service method, referring to the UTF-8 name in the Candid type data.
+    Method(*const u8, u32),
+    Option,
+    Vector,
+    Mutable,
+    Weak,
+}
+
+/// Reason of a failed memory compatibility check at the end of the recorded path.
+/// "First" and "second" refer to the two type tables passed to `memory_compatible()`.
+#[enhanced_orthogonal_persistence]
+#[derive(Clone, Copy)]
+pub enum IncompatibilityReason {
+    /// The type constructors cannot be related.
+    Constructors,
+    /// Different number of function parameters, function results, or tuple components.
+    Arity,
+    /// Different function annotations.
+    Annotations,
+    /// A field, tag, or method of the first type is missing in the second type.
+    Removed(TypePathStep),
+    /// The second type contains a field, tag, or method that is not present in the first type.
+    Added(TypePathStep),
+}
+
+/// Diagnosis of a failed memory compatibility check.
+/// The innermost failure records the reason and the two offending type constructors. The enclosing
+/// checks then append their path step while the failure unwinds. As the check stops at the first
+/// incompatibility, there is only a single path to record. The trace does not allocate.
+#[enhanced_orthogonal_persistence]
+pub struct IncompatibilityTrace {
+    /// Recorded path steps, innermost step first.
+    path: [TypePathStep; MAX_TRACE_DEPTH],
+    length: usize,
+    truncated: bool,
+    /// Reason with the constructors of the first and second type.
+    failure: Option<(IncompatibilityReason, i32, i32)>,
+}
+
+#[enhanced_orthogonal_persistence]
+impl IncompatibilityTrace {
+    pub const fn new() -> Self {
+        IncompatibilityTrace {
+            path: [TypePathStep::Option; MAX_TRACE_DEPTH],
+            length: 0,
+            truncated: false,
+            failure: None,
+        }
+    }
+
+    /// Record the innermost incompatibility. Always returns `false`.
+    fn fail(&mut self, reason: IncompatibilityReason, first: i32, second: i32) -> bool {
+        if self.failure.is_none() {
+            self.failure = Some((reason, first, second));
+        }
+        false
+    }
+
+    /// Record a path step while unwinding a failure. Always returns `false`.
+    fn enclose(&mut self, step: TypePathStep) -> bool {
+        debug_assert!(self.failure.is_some());
+        if self.length < MAX_TRACE_DEPTH {
+            self.path[self.length] = step;
+            self.length += 1;
+        } else {
+            self.truncated = true;
+        }
+        false
+    }
+}
+
+#[enhanced_orthogonal_persistence]
+impl Default for IncompatibilityTrace {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[enhanced_orthogonal_persistence]
+impl core::fmt::Display for TypePathStep {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            TypePathStep::Field(hash) => write!(f, "field #{hash}"),
+            TypePathStep::Tag(hash) => write!(f, "tag #{hash}"),
+            TypePathStep::Item(index) => write!(f, "tuple item {index}"),
+            TypePathStep::Argument(index) => write!(f, "argument {index}"),
+            TypePathStep::Result(index) => write!(f, "result {index}"),
+            TypePathStep::Method(name, length) => {
+                let name = unsafe { core::slice::from_raw_parts(name, length as usize) };
+                match core::str::from_utf8(name) {
+                    Ok(name) => write!(f, "method {name}"),
+                    Err(_) => write!(f, "method <invalid name>"),
+                }
+            }
+            TypePathStep::Option => write!(f, "opt"),
+            TypePathStep::Vector => write!(f, "vec"),
+            TypePathStep::Mutable => write!(f, "var"),
+            TypePathStep::Weak => write!(f, "weak"),
+        }
+    }
+}
+
+/// Renders the path from the main actor to the incompatibility, followed by the reason, e.g.
+/// `actor field #4107 > field #5097222 > opt: incompatible types nat (old) and text (new)`.
+#[enhanced_orthogonal_persistence]
+impl core::fmt::Display for IncompatibilityTrace {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let Some((reason, first, second)) = self.failure else {
+            return write!(f, "no diagnosis available");
+        };
+        // Function parameters are checked contravariantly, which swaps the old and the new type.
+        let mut swapped = false;
+        if self.truncated {
+            write!(f, "... > ")?;
+        }
+        for index in (0..self.length).rev() {
+            let step = self.path[index];
+            let outermost = index + 1 == self.length && !self.truncated;
+            if outermost && matches!(step, TypePathStep::Field(_)) {
+                write!(f, "actor ")?;
+            }
+            write!(f, "{step}")?;
+            write!(f, "{}", if index > 0 { " > " } else { ": " })?;
+            if let TypePathStep::Argument(_) = step {
+                swapped = !swapped;
+            }
+        }
+        let (old, new) = if swapped {
+            (second, first)
+        } else {
+            (first, second)
+        };
+        match reason {
+            IncompatibilityReason::Constructors => write!(
+                f,
+                "incompatible types {} (old) and {} (new)",
+                type_constructor_name(old),
+                type_constructor_name(new)
+            ),
+            IncompatibilityReason::Arity => {
+                write!(f, "different arity of {}", type_constructor_name(new))
+            }
+            IncompatibilityReason::Annotations => write!(f, "different function annotations"),
+            IncompatibilityReason::Removed(label) if !swapped => {
+                write!(f, "{label} removed from {}", type_constructor_name(old))
+            }
+            IncompatibilityReason::Added(label) if swapped => {
+                write!(f, "{label} removed from {}", type_constructor_name(old))
+            }
+            IncompatibilityReason::Removed(label) | IncompatibilityReason::Added(label) => {
+                write!(f, "{label} added to {}", type_constructor_name(new))
+            }
+        }
+    }
+}
+
+/// Name of a (primitive or compound) Candid type constructor, for diagnostics.
+fn type_constructor_name(constructor: i32) -> &'static str {
+    match constructor {
+        IDL_PRIM_null => "null",
+        IDL_PRIM_bool => "bool",
+        IDL_PRIM_nat => "nat",
+        IDL_PRIM_int => "int",
+        IDL_PRIM_nat8 => "nat8",
+        IDL_PRIM_nat16 => "nat16",
+        IDL_PRIM_nat32 => "nat32",
+        IDL_PRIM_nat64 => "nat64",
+        IDL_PRIM_int8 => "int8",
+        IDL_PRIM_int16 => "int16",
+        IDL_PRIM_int32 => "int32",
+        IDL_PRIM_int64 => "int64",
+        IDL_PRIM_float32 => "float32",
+        IDL_PRIM_float64 => "float64",
+        IDL_PRIM_text => "text",
+        IDL_PRIM_reserved => "reserved",
+        IDL_PRIM_empty => "empty",
+        IDL_CON_opt => "opt",
+        IDL_CON_vec => "vec",
+        IDL_CON_record => "record",
+        IDL_CON_variant => "variant",
+        IDL_CON_func => "func",
+        IDL_CON_service => "service",
+        IDL_REF_principal => "principal",
+        IDL_EXT_region => "region",
+        IDL_CON_alias => "var",
+        #[cfg(feature = "enhanced_orthogonal_persistence")]
+        IDL_EXT_blob => "blob",
+        #[cfg(feature = "enhanced_orthogonal_persistence")]
+        IDL_EXT_tuple => "tuple",
+        #[cfg(feature = "enhanced_orthogonal_persistence")]
+        IDL_EXT_weak => "weak",
+        _ => "unknown",
+    }
+}
+
+//
+// Type table printing
+//
+// Renders type tables in `.did`-style syntax for diagnostics. Type table entries are named
+// by their index, e.g. `T3`, and fields by their hash. The rendering assumes that the type
+// table has been validated by `parse_idl_header`.
+//
+
+/// Reference to a primitive type or to a type table entry.
+struct TypeReference(i32);
+
+impl Display for TypeReference {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if self.0 >= 0 {
+            write!(f, "T{}", self.0)
+        } else {
+            write!(f, "{}", type_constructor_name(self.0))
+        }
+    }
+}
+
+/// Definition of a type table entry, e.g. `T2 = record { 0 : nat; 1 : opt T3 }`.
+struct TypeDefinition {
+    typtbl: *mut *mut u8,
+    end: *mut u8,
+    index: u32,
+}
+
+impl TypeDefinition {
+    unsafe fn write_types(f: &mut Formatter<'_>, tb: *mut Buf) -> FmtResult {
+        write!(f, "(")?;
+        for i in 0..leb128_decode(tb) {
+            let separator = if i == 0 { "" } else { ", " };
+            write!(f, "{separator}{}", TypeReference(sleb128_decode(tb)))?;
+        }
+        write!(f, ")")
+    }
+}
+
+impl Display for TypeDefinition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        unsafe {
+            let mut tb = Buf {
+                ptr: *self.typtbl.add(self.index as usize),
+                end: self.end,
+            };
+            write!(f, "T{} = ", self.index)?;
+            let ty = sleb128_decode(&mut tb);
+            match ty {
+                IDL_CON_opt | IDL_CON_vec | IDL_CON_alias => {
+                    let it = TypeReference(sleb128_decode(&mut tb));
+                    write!(f, "{} {it}", type_constructor_name(ty))
+                }
+                IDL_CON_record | IDL_CON_variant => {
+                    write!(f, "{} {{", type_constructor_name(ty))?;
+                    let n = leb128_decode(&mut tb);
+                    for i in 0..n {
+                        let tag = leb128_decode(&mut tb);
+                        let it = TypeReference(sleb128_decode(&mut tb));
+                        let separator = if i == 0 { " " } else { "; " };
+                        write!(f, "{separator}{tag} : {it}")?;
+                    }
+                    write!(f, "{}}}", if n == 0 { "" } else { " " })
+                }
+                IDL_CON_func => {
+                    write!(f, "func ")?;
+                    Self::write_types(f, &mut tb)?;
+                    write!(f, " -> ")?;
+                    Self::write_types(f, &mut tb)?;
+                    match read_func_mode(&mut tb, TypePosition::Entry(self.index)) {
+                        IDL_FUNC_query => write!(f, " query"),
+                        IDL_FUNC_oneway => write!(f, " oneway"),
+                        IDL_FUNC_composite_query => write!(f, " composite_query"),
+                        _ => Ok(()),
+                    }
+                }
+                IDL_CON_service => {
+                    write!(f, "service {{")?;
+                    let n = leb128_decode(&mut tb);
+                    for i in 0..n {
+                        let (len, p) = leb128_decode_ptr(&mut tb);
+                        Buf::advance(&mut tb, len as usize);
+                        let name = core::slice::from_raw_parts(p, len as usize);
+                        let name = core::str::from_utf8(name).unwrap_or("<invalid name>");
+                        let it = TypeReference(sleb128_decode(&mut tb));
+                        let separator = if i == 0 { " " } else { "; " };
+                        write!(f, "{separator}\"{name}\" : {it}")?;
+                    }
+                    write!(f, "{}}}", if n == 0 { "" } else { " " })
+                }
+                _ => write!(f, "future type {ty}"),
+            }
+        }
+    }
+}
+
+/// All definitions of a type table, one `type` declaration per line.
+pub struct TypeTable {
+    typtbl: *mut *mut u8,
+    size: usize,
+    end: *mut u8,
+}
+
+impl TypeTable {
+    /// Type table as obtained from `parse_idl_header`, ending at `end`.
+    pub fn new(typtbl: *mut *mut u8, size: usize, end: *mut u8) -> Self {
+        TypeTable { typtbl, size, end }
+    }
+}
+
+impl Display for TypeTable {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        for index in 0..self.size {
+            let definition = TypeDefinition {
+                typtbl: self.typtbl,
+                end: self.end,
+                index: index as u32,
+            };
+            writeln!(f, "type {definition};")?;
+        }
+        Ok(())
+    }
+}
+
+/// Traps with an IDL error that shows the type `t` the failure relates to, e.g.
+/// `skip_any: variant tag too large (T2 = variant { 0 : nat; 1 : text })`.
+unsafe fn idl_trap_with_type(msg: &str, typtbl: *mut *mut u8, end: *mut u8, t: i32) -> ! {
+    // The trap message is limited to 512 bytes, see `trap_with_prefix`.
+    let mut buffer = [0u8; 512];
+    let mut message = WriteBuf::new(&mut buffer);
+    if t >= 0 {
+        let definition = TypeDefinition {
+            typtbl,
+            end,
+            index: t as u32,
+        };
+        let _ = write!(&mut message, "{msg} ({definition})");
+    } else {
+        let _ = write!(&mut message, "{msg} ({})", TypeReference(t));
+    }
+    idl_trap_with(message.as_str())
+}
+
+/// Prints a type table as obtained from `parse_idl_header`, for debugging.
+#[unsafe(no_mangle)]
+unsafe extern "C" fn idl_print_typtbl(typtbl: *mut *mut u8, typtbl_size: usize, end: *mut u8) {
+    let table = TypeTable::new(typtbl, typtbl_size, end);
+    println!(4096, "{table}");
+}
+
 /// Memory compatibility check for orthogonal persistence (with or without graph copying).
 /// Checks whether the new type (`typetbl2`) is compatible to the old type (`typetbl1`).
 /// The implementation is similar to the Candid sub-type test `sub()` below, however,
@@ -669,8 +1345,10 @@ unsafe fn remember_memory_check(cache: &BitRel, variance: TypeVariance, t1: usiz
 /// * Records cannot introduce additional optional fields.
 /// * Same arity for tuple types.
 /// * Records and tuples are distinct.
+/// On incompatibility, the failing type path and the offending type constructors are
+/// recorded in `trace`.
 #[enhanced_orthogonal_persistence]
-pub(crate) unsafe fn memory_compatible(
+pub unsafe fn memory_compatible(
     rel: &BitRel,
     variance: TypeVariance,
     typtbl1: *mut *mut u8,
@@ -680,6 +1358,7 @@ pub(crate) unsafe fn memory_compatible(
     t1: i32,
     t2: i32,
     main_actor: bool,
+    trace: &mut IncompatibilityTrace,
 ) -> bool {
     // Do not use the cache for the main actor sub-type relation, as it does not follow the ordinary sub-type rules,
     // i.e. new actor fields can be inserted in new program versions.
@@ -733,6 +1412,8 @@ pub(crate) unsafe fn memory_compatible(
         t2
     };
 
+    use IncompatibilityReason::*;
+
     match (u1, u2) {
         (IDL_CON_alias, IDL_CON_alias) => {
             let t11 = sleb128_decode(&mut tb1);
@@ -748,37 +1429,46 @@ pub(crate) unsafe fn memory_compatible(
                 t11,
                 t21,
                 false,
-            )
+                trace,
+            ) || trace.enclose(TypePathStep::Mutable)
         }
         (IDL_PRIM_reserved, IDL_PRIM_reserved) | (IDL_PRIM_empty, IDL_PRIM_empty) => true,
-        (_, IDL_PRIM_reserved) => false, // information lost
-        (IDL_PRIM_empty, _) | (IDL_PRIM_nat, IDL_PRIM_int) => variance != TypeVariance::Invariance,
-        (_, IDL_CON_alias) | (IDL_CON_alias, _) => false,
+        (_, IDL_PRIM_reserved) => trace.fail(Constructors, u1, u2), // information lost
+        (IDL_PRIM_empty, _) | (IDL_PRIM_nat, IDL_PRIM_int) => {
+            variance != TypeVariance::Invariance || trace.fail(Constructors, u1, u2)
+        }
+        (_, IDL_CON_alias) | (IDL_CON_alias, _) => trace.fail(Constructors, u1, u2),
         (IDL_EXT_weak, IDL_EXT_weak) => {
             let t11 = sleb128_decode(&mut tb1);
             let t21 = sleb128_decode(&mut tb2);
-            memory_compatible(rel, variance, typtbl1, typtbl2, end1, end2, t11, t21, false)
+            memory_compatible(
+                rel, variance, typtbl1, typtbl2, end1, end2, t11, t21, false, trace,
+            ) || trace.enclose(TypePathStep::Weak)
         }
         (IDL_CON_opt, IDL_CON_opt) => {
             let t11 = sleb128_decode(&mut tb1);
             let t21 = sleb128_decode(&mut tb2);
-            memory_compatible(rel, variance, typtbl1, typtbl2, end1, end2, t11, t21, false)
+            memory_compatible(
+                rel, variance, typtbl1, typtbl2, end1, end2, t11, t21, false, trace,
+            ) || trace.enclose(TypePathStep::Option)
         }
         (IDL_PRIM_null, IDL_CON_opt) => true,
-        (_, IDL_CON_opt) => false,
+        (_, IDL_CON_opt) => trace.fail(Constructors, u1, u2),
         (IDL_CON_vec, IDL_CON_vec) => {
             let t11 = sleb128_decode(&mut tb1);
             let t21 = sleb128_decode(&mut tb2);
-            memory_compatible(rel, variance, typtbl1, typtbl2, end1, end2, t11, t21, false)
+            memory_compatible(
+                rel, variance, typtbl1, typtbl2, end1, end2, t11, t21, false, trace,
+            ) || trace.enclose(TypePathStep::Vector)
         }
         (IDL_CON_func, IDL_CON_func) => {
             // contra in domain
             let in1 = leb128_decode(&mut tb1);
             let in2 = leb128_decode(&mut tb2);
             if in1 != in2 {
-                return false;
+                return trace.fail(Arity, u1, u2);
             }
-            for _ in 0..in1 {
+            for index in 0..in1 {
                 let t11 = sleb128_decode(&mut tb1);
                 let t21 = sleb128_decode(&mut tb2);
                 // NB: invert p and args!
@@ -792,57 +1482,36 @@ pub(crate) unsafe fn memory_compatible(
                     t21,
                     t11,
                     false,
+                    trace,
                 ) {
-                    return false;
+                    return trace.enclose(TypePathStep::Argument(index));
                 }
             }
             // co in range
             let out1 = leb128_decode(&mut tb1);
             let out2 = leb128_decode(&mut tb2);
             if out1 != out2 {
-                return false;
+                return trace.fail(Arity, u1, u2);
             }
-            for _ in 0..out2 {
+            for index in 0..out2 {
                 let t21 = sleb128_decode(&mut tb2);
                 let t11 = sleb128_decode(&mut tb1);
-                if !memory_compatible(rel, variance, typtbl1, typtbl2, end1, end2, t11, t21, false)
-                {
-                    return false;
-                }
-            }
-            // check annotations (that we care about)
-            // TODO: more generally, we would check equality of 256-bit bit-vectors,
-            // but validity ensures each entry is 1, 2 or 3 (for now)
-            // c.f. https://github.com/dfinity/candid/issues/318
-            let mut a11 = false;
-            let mut a12 = false;
-            let mut a13 = false;
-            for _ in 0..leb128_decode(&mut tb1) {
-                match read_byte(&mut tb1) {
-                    1 => a11 = true,
-                    2 => a12 = true,
-                    3 => a13 = true,
-                    _ => {}
-                }
-            }
-            let mut a21 = false;
-            let mut a22 = false;
-            let mut a23 = false;
-            for _ in 0..leb128_decode(&mut tb2) {
-                match read_byte(&mut tb2) {
-                    1 => a21 = true,
-                    2 => a22 = true,
-                    3 => a23 = true,
-                    _ => {}
+                if !memory_compatible(
+                    rel, variance, typtbl1, typtbl2, end1, end2, t11, t21, false, trace,
+                ) {
+                    return trace.enclose(TypePathStep::Result(index));
                 }
             }
-            a11 == a21 && a12 == a22 && a13 == a23
+            // same mode (query, oneway, composite query or none)
+            read_func_mode(&mut tb1, TypePosition::Entry(t1 as u32))
+                == read_func_mode(&mut tb2, TypePosition::Entry(t2 as u32))
+                || trace.fail(Annotations, u1, u2)
         }
         (IDL_EXT_tuple, IDL_EXT_tuple) => {
             let n1 = leb128_decode(&mut tb1);
             let n2 = leb128_decode(&mut tb2);
             if n1 != n2 {
-                return false;
+                return trace.fail(Arity, u1, u2);
             }
             for _ in 0..n1 {
                 let tag1 = leb128_decode(&mut tb1);
@@ -850,11 +1519,12 @@ pub(crate) unsafe fn memory_compatible(
                 let tag2 = leb128_decode(&mut tb2);
                 let t21 = sleb128_decode(&mut tb2);
                 if tag1 != tag2 {
-                    return false;
+                    return trace.fail(Arity, u1, u2);
                 }
-                if !memory_compatible(rel, variance, typtbl1, typtbl2, end1, end2, t11, t21, false)
-                {
-                    return false;
+                if !memory_compatible(
+                    rel, variance, typtbl1, typtbl2, end1, end2, t11, t21, false, trace,
+                ) {
+                    return trace.enclose(TypePathStep::Item(tag1));
                 }
             }
             true
@@ -868,23 +1538,30 @@ pub(crate) unsafe fn memory_compatible(
                 let tag2 = leb128_decode(&mut tb2);
                 let t21 = sleb128_decode(&mut tb2);
                 if n1 == 0 {
-                    return false;
+                    return trace.fail(Added(TypePathStep::Field(tag2)), u1, u2);
                 };
                 let tag1 = leb128_decode(&mut tb1);
                 let t11 = sleb128_decode(&mut tb1);
                 n1 -= 1;
                 if tag1 < tag2 {
-                    return false; // discarded field
+                    // discarded field
+                    return trace.fail(Removed(TypePathStep::Field(tag1)), u1, u2);
                 }
                 if tag1 > tag2 {
-                    return false; // new field
+                    // new field
+                    return trace.fail(Added(TypePathStep::Field(tag2)), u1, u2);
                 };
-                if !memory_compatible(rel, variance, typtbl1, typtbl2, end1, end2, t11, t21, false)
-                {
-                    return false;
+                if !memory_compatible(
+                    rel, variance, typtbl1, typtbl2, end1, end2, t11, t21, false, trace,
+                ) {
+                    return trace.enclose(TypePathStep::Field(tag1));
                 }
             }
-            return n1 == 0;
+            if n1 > 0 {
+                let tag1 = leb128_decode(&mut tb1);
+                return trace.fail(Removed(TypePathStep::Field(tag1)), u1, u2);
+            }
+            true
         }
         (IDL_CON_record, IDL_CON_record) if main_actor => {
             // memory compatibility
@@ -904,7 +1581,8 @@ pub(crate) unsafe fn memory_compatible(
                 n2 -= 1;
                 while tag1 != tag2 {
                     if tag1 < tag2 {
-                        return false; // discarded field
+                        // discarded field
+                        return trace.fail(Removed(TypePathStep::Field(tag1)), u1, u2);
                     };
                     if tag1 > tag2 {
                         if n2 > 0 {
@@ -916,22 +1594,28 @@ pub(crate) unsafe fn memory_compatible(
                         return true;
                     };
                 }
-                if !memory_compatible(rel, variance, typtbl1, typtbl2, end1, end2, t11, t21, false)
-                {
-                    return false;
+                if !memory_compatible(
+                    rel, variance, typtbl1, typtbl2, end1, end2, t11, t21, false, trace,
+                ) {
+                    return trace.enclose(TypePathStep::Field(tag1));
                 }
             }
-            return n1 == 0; // false if any remaining fields discarded
+            // false if any remaining fields discarded
+            if n1 > 0 {
+                let tag1 = leb128_decode(&mut tb1);
+                return trace.fail(Removed(TypePathStep::Field(tag1)), u1, u2);
+            }
+            true
         }
         (IDL_CON_variant, IDL_CON_variant) => {
             let n1 = leb128_decode(&mut tb1);
             let mut n2 = leb128_decode(&mut tb2);
             for _ in 0..n1 {
-                if n2 == 0 {
-                    return false;
-                };
                 let tag1 = leb128_decode(&mut tb1);
                 let t11 = sleb128_decode(&mut tb1);
+                if n2 == 0 {
+                    return trace.fail(Removed(TypePathStep::Tag(tag1)), u1, u2);
+                };
                 let mut tag2: u32;
                 let mut t21: i32;
                 loop {
@@ -943,25 +1627,35 @@ pub(crate) unsafe fn memory_compatible(
                     }
                 }
                 if tag1 != tag2 {
-                    return false;
+                    let reason = if tag2 < tag1 && variance == TypeVariance::Invariance {
+                        Added(TypePathStep::Tag(tag2))
+                    } else {
+                        Removed(TypePathStep::Tag(tag1))
+                    };
+                    return trace.fail(reason, u1, u2);
                 }
-                if !memory_compatible(rel, variance, typtbl1, typtbl2, end1, end2, t11, t21, false)
-                {
-                    return false;
+                if !memory_compatible(
+                    rel, variance, typtbl1, typtbl2, end1, end2, t11, t21, false, trace,
+                ) {
+                    return trace.enclose(TypePathStep::Tag(tag1));
                 }
             }
-            variance != TypeVariance::Invariance || n2 == 0
+            if variance == TypeVariance::Invariance && n2 > 0 {
+                let tag2 = leb128_decode(&mut tb2);
+                return trace.fail(Added(TypePathStep::Tag(tag2)), u1, u2);
+            }
+            true
         }
         (IDL_CON_service, IDL_CON_service) => {
             let mut n1 = leb128_decode(&mut tb1);
             let n2 = leb128_decode(&mut tb2);
             for _ in 0..n2 {
-                if n1 == 0 {
-                    return false;
-                };
                 let (len2, p2) = leb128_decode_ptr(&mut tb2);
                 Buf::advance(&mut tb2, len2 as usize);
                 let t21 = sleb128_decode(&mut tb2);
+                if n1 == 0 {
+                    return trace.fail(Added(TypePathStep::Method(p2, len2)), u1, u2);
+                };
                 let mut len1: u32;
                 let mut p1: *mut u8;
                 let mut t11: i32;
@@ -978,22 +1672,32 @@ pub(crate) unsafe fn memory_compatible(
                     break;
                 }
                 if cmp != 0 {
-                    return false;
+                    let reason = if cmp < 0 && variance == TypeVariance::Invariance {
+                        Removed(TypePathStep::Method(p1, len1))
+                    } else {
+                        Added(TypePathStep::Method(p2, len2))
+                    };
+                    return trace.fail(reason, u1, u2);
                 };
-                if !memory_compatible(rel, variance, typtbl1, typtbl2, end1, end2, t11, t21, false)
-                {
-                    return false;
+                if !memory_compatible(
+                    rel, variance, typtbl1, typtbl2, end1, end2, t11, t21, false, trace,
+                ) {
+                    return trace.enclose(TypePathStep::Method(p2, len2));
                 }
             }
-            variance != TypeVariance::Invariance || n1 == 0
+            if variance == TypeVariance::Invariance && n1 > 0 {
+                let (len1, p1) = leb128_decode_ptr(&mut tb1);
+                return trace.fail(Removed(TypePathStep::Method(p1, len1)), u1, u2);
+            }
+            true
         }
         // default
-        (_, _) => false,
+        (_, _) => trace.fail(Constructors, u1, u2),
     }
 }
 
 // TODO: consider storing fixed args typtbl1...end2 in `rel` to use less stack.
-pub(crate) unsafe fn sub(
+pub unsafe fn sub(
     rel: &BitRel,
     p: bool,
     typtbl1: *mut *mut u8,
@@ -1118,33 +1822,11 @@ pub(crate) unsafe fn sub(
                     let _ = sleb128_decode(&mut tb1);
                     out1 -= 1;
                 }
-                // check annotations (that we care about)
-                // TODO: more generally, we would check equality of 256-bit bit-vectors,
-                // but validity ensures each entry is 1, 2 or 3 (for now)
+                // same mode (query, oneway, composite query or none),
                 // c.f. https://github.com/dfinity/candid/issues/318
-                let mut a11 = false;
-                let mut a12 = false;
-                let mut a13 = false;
-                for _ in 0..leb128_decode(&mut tb1) {
-                    match read_byte(&mut tb1) {
-                        1 => a11 = true,
-                        2 => a12 = true,
-                        3 => a13 = true,
-                        _ => {}
-                    }
-                }
-                let mut a21 = false;
-                let mut a22 = false;
-                let mut a23 = false;
-                for _ in 0..leb128_decode(&mut tb2) {
-                    match read_byte(&mut tb2) {
-                        1 => a21 = true,
-                        2 => a22 = true,
-                        3 => a23 = true,
-                        _ => {}
-                    }
-                }
-                if (a11 == a21) && (a12 == a22) && (a13 == a23) {
+                if read_func_mode(&mut tb1, TypePosition::Entry(t1 as u32))
+                    == read_func_mode(&mut tb2, TypePosition::Entry(t2 as u32))
+                {
                     return true;
                 } else {
                     break 'return_false;
@@ -1270,27 +1952,31 @@ pub(crate) unsafe fn sub(
 }
 
 #[unsafe(no_mangle)]
-unsafe extern "C" fn idl_sub_buf_words(typtbl_size1: usize, typtbl_size2: usize) -> usize {
+pub unsafe extern "C" fn idl_sub_buf_words(typtbl_size1: usize, typtbl_size2: usize) -> usize {
     BitRel::words(typtbl_size1, typtbl_size2)
 }
 
 #[unsafe(no_mangle)]
-unsafe extern "C" fn idl_sub_buf_init(
+pub unsafe extern "C" fn idl_sub_buf_init(
     rel_buf: *mut usize,
     typtbl_size1: usize,
     typtbl_size2: usize,
 ) {
+    let words = idl_sub_buf_words(typtbl_size1, typtbl_size2);
     let rel = BitRel {
         ptr: rel_buf,
-        end: rel_buf.add(idl_sub_buf_words(typtbl_size1, typtbl_size2) as usize),
+        end: rel_buf.add(words),
         size1: typtbl_size1,
         size2: typtbl_size2,
     };
     rel.init();
+    if ACCOUNTING {
+        DECODING_STATISTICS.subtype_cache_size += Words(words).to_bytes();
+    }
 }
 
 #[enhanced_orthogonal_persistence]
-#[ic_mem_fn]
+#[ic_mem_fn(ic_only)]
 unsafe fn idl_alloc_typtbl<M: Memory>(
     mem: &mut M,
     candid_data: Value,
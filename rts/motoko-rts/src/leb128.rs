@@ -34,7 +34,7 @@ pub unsafe extern "C" fn sleb128_encode(mut val: isize, mut buf: *mut u8) {
 }
 
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn leb128_decode(buf: *mut Buf) -> usize {
+pub unsafe extern "C-unwind" fn leb128_decode(buf: *mut Buf) -> usize {
     leb128_decode_checked(buf).expect("leb128_decode: overflow")
 }
 
@@ -79,7 +79,7 @@ pub unsafe fn leb128_decode_checked(buf: *mut Buf) -> Option<usize> {
 }
 
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn sleb128_decode(buf: *mut Buf) -> isize {
+pub unsafe extern "C-unwind" fn sleb128_decode(buf: *mut Buf) -> isize {
     sleb128_decode_checked(buf).expect("sleb128_decode: overflow")
 }
 
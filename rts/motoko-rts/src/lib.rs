@@ -37,11 +37,9 @@ pub mod buf;
 mod char;
 pub mod constants;
 pub mod continuation_table;
-#[cfg(feature = "ic")]
-mod float;
+pub mod float;
 pub mod gc;
-#[cfg(feature = "ic")]
-mod idl;
+pub mod idl;
 pub mod leb128;
 mod libc_declarations;
 pub mod mem_utils;
@@ -50,7 +48,6 @@ pub mod memory;
 #[enhanced_orthogonal_persistence]
 pub mod persistence;
 pub mod principal_id;
-#[cfg(feature = "ic")]
 pub mod region;
 #[enhanced_orthogonal_persistence]
 pub mod stabilization;
@@ -62,6 +59,7 @@ pub mod text;
 pub mod text_iter;
 mod tommath_bindings;
 pub mod types;
+pub mod unicode;
 pub mod utf8;
 mod visitor;
 
@@ -84,7 +82,9 @@ unsafe fn alloc_words<M: memory::Memory>(mem: &mut M, n: types::Words<usize>) ->
     crate::gc::incremental::get_partitioned_heap().allocate(mem, n)
 }
 
-unsafe extern "C" {
+// `C-unwind` lets native harnesses (see `fuzz/`) recover from traps by unwinding.
+// This makes no difference on Wasm, where the RTS is built with `panic = "abort"`.
+unsafe extern "C-unwind" {
     fn rts_trap(msg: *const u8, len: u32) -> !;
 }
 
@@ -14,7 +14,6 @@ pub unsafe fn memzero(to: usize, n: Words<usize>) {
     memset(to as *mut _, 0, n.to_bytes().as_usize());
 }
 
-#[cfg(feature = "ic")]
 pub unsafe fn memzero_bytes(to: usize, n: Bytes<usize>) {
     memset(to as *mut _, 0, n.as_usize());
 }
@@ -2,7 +2,6 @@
 pub mod ic;
 use crate::{constants::MAX_ARRAY_LENGTH_FOR_ITERATOR, types::*};
 
-use motoko_rts_macros::classical_persistence;
 use motoko_rts_macros::enhanced_orthogonal_persistence;
 use motoko_rts_macros::ic_mem_fn;
 
@@ -12,16 +11,14 @@ use crate::barriers::init_with_barrier;
 #[cfg(feature = "ic")]
 use crate::constants::MB;
 
+/// Currently known upper bound of the memory size, i.e. the probed Wasm memory capacity,
+/// which can increase over time. The heap itself imposes no fixed limit.
 #[enhanced_orthogonal_persistence]
-use crate::constants::GB;
-
-// TODO: Redesign for 64-bit support by using a dynamic partition list.
-/// Currently limited to 64 GB.
-#[enhanced_orthogonal_persistence]
-pub const MAXIMUM_MEMORY_SIZE: Bytes<u64> = Bytes(64u64 * GB as u64);
-
-#[classical_persistence]
-pub const MAXIMUM_MEMORY_SIZE: Bytes<u64> = Bytes(usize::MAX as u64);
+#[cfg(feature = "ic")]
+pub fn maximum_memory_size() -> Bytes<u64> {
+    let capacity = ic::enhanced_memory::minimum_memory_capacity();
+    Bytes(capacity.as_usize() as u64)
+}
 
 // Memory reserve in bytes ensured during update and initialization calls.
 // For use by queries and upgrade calls.
@@ -23,24 +23,25 @@ const IC_MEMORY_CAPACITY_GRANULARITY: usize = 2 * GB;
 /// Page allocation. Ensures that the memory up to, but excluding, the given pointer is allocated.
 /// Ensure a memory reserve of at least one Wasm page depending on the canister state.
 /// `memory_reserve`: A memory reserve in bytes ensured during update and initialization calls.
-/// The reserve can be used by queries and upgrade calls.
+/// The reserve can be used by queries and upgrade calls. It grows with the heap size, as it
+/// includes the mark bitmaps, and may therefore exceed the guaranteed memory capacity.
 pub(crate) unsafe fn grow_memory(ptr: usize, memory_reserve: usize) {
-    debug_assert!(memory_reserve <= GUARANTEED_MEMORY_CAPACITY);
-    let memory_demand =
-        if keep_memory_reserve() && ptr > GUARANTEED_MEMORY_CAPACITY - memory_reserve {
-            // Detect overflow of `ptr + memory_reserve`.
-            if ptr > usize::MAX - memory_reserve {
-                rts_trap_with("Cannot grow memory");
-            }
-            // The reserve will be pre-allocated as a way to check the main memory capacity.
-            // As the reserve can be substantial, this is only done when memory demand has
-            // grown beyond `GUARANTEED_MEMORY_CAPACITY`.
-            ptr + memory_reserve
-        } else {
-            // Either no reserve is needed or there is enough guaranteed memory capacity for the reserve,
-            // we can skip the pre-allocation of a reserve.
-            ptr
-        };
+    let memory_demand = if keep_memory_reserve()
+        && memory_reserve > GUARANTEED_MEMORY_CAPACITY.saturating_sub(ptr)
+    {
+        // Detect overflow of `ptr + memory_reserve`.
+        if ptr > usize::MAX - memory_reserve {
+            rts_trap_with("Cannot grow memory");
+        }
+        // The reserve will be pre-allocated as a way to check the main memory capacity.
+        // As the reserve can be substantial, this is only done when memory demand has
+        // grown beyond `GUARANTEED_MEMORY_CAPACITY`.
+        ptr + memory_reserve
+    } else {
+        // Either no reserve is needed or there is enough guaranteed memory capacity for the reserve,
+        // we can skip the pre-allocation of a reserve.
+        ptr
+    };
     allocate_wasm_memory(Bytes(memory_demand));
 }
 
@@ -10,9 +10,15 @@ use crate::gc::incremental::mark_stack::MarkStack;
 use crate::{
     barriers::write_with_barrier,
     constants::{KB, MB},
-    gc::incremental::{State, partitioned_heap::allocate_initial_memory},
+    gc::incremental::{
+        State,
+        partitioned_heap::allocate_initial_memory,
+        policy::{DEFAULT_POLICY, SchedulingPolicy},
+    },
+    idl::IncompatibilityTrace,
     memory::{Memory, alloc_blob},
     persistence::compatibility::memory_compatible,
+    print::WriteBuf,
     region::{
         LEGACY_VERSION_NO_STABLE_MEMORY, LEGACY_VERSION_REGIONS, LEGACY_VERSION_SOME_STABLE_MEMORY,
         VERSION_GRAPH_COPY_NO_REGIONS, VERSION_GRAPH_COPY_REGIONS,
@@ -68,6 +74,12 @@ struct PersistentMetadata {
     /// This is for the purpose of multi-migration tracking such that a single migration function
     /// cannot be executed multiple times, removing the risk of data loss.
     migration_functions: Value,
+    /// The adjustable scheduling policy of the incremental GC.
+    /// Retained across upgrades, like the GC state.
+    gc_scheduling_policy: SchedulingPolicy,
+    /// The nursery size of the optional young generation of the incremental GC, or zero if disabled.
+    /// The zero-initialized value of previous versions disables the young generation.
+    young_generation_size: usize,
 }
 
 /// Location of the persistent metadata. Prereserved and fixed forever.
@@ -120,6 +132,8 @@ impl PersistentMetadata {
         (*self).dedup_table = NULL_POINTER;
         // Initialize the migration functions list as the null pointer.
         (*self).migration_functions = NULL_POINTER;
+        (*self).gc_scheduling_policy = DEFAULT_POLICY;
+        (*self).young_generation_size = 0;
     }
 }
 
@@ -149,6 +163,12 @@ pub unsafe fn initialize_memory<M: Memory>() {
             // We need to initialize the migration functions array to NULL_POINTER.
             (*metadata).migration_functions = NULL_POINTER;
         }
+        // Explicit migration from a version of the RTS without GC scheduling policy support.
+        if !(*metadata).gc_scheduling_policy.is_valid() {
+            // This is the first upgrade from a version of the RTS without GC scheduling policy
+            // support. The zero-initialized policy is replaced by the default policy.
+            (*metadata).gc_scheduling_policy = DEFAULT_POLICY;
+        }
     } else {
         metadata.initialize::<M>();
     }
@@ -244,11 +264,17 @@ unsafe fn update_stable_type<M: Memory>(
     let mut new_type = TypeDescriptor::new(new_candid_data, new_type_offsets);
     let metadata = PersistentMetadata::get();
     let old_type = &mut (*metadata).stable_type;
+    let mut trace = IncompatibilityTrace::new();
     if check_compatibility
         && !old_type.is_default()
-        && !memory_compatible(mem, old_type, &mut new_type)
+        && !memory_compatible(mem, old_type, &mut new_type, &mut trace)
     {
-        rts_trap_with("Memory-incompatible program upgrade");
+        use core::fmt::Write;
+        // The trap message is limited to 512 bytes, see `trap_with_prefix`.
+        let mut buffer = [0u8; 512];
+        let mut message = WriteBuf::new(&mut buffer);
+        let _ = write!(&mut message, "Memory-incompatible program upgrade: {trace}");
+        rts_trap_with(message.as_str());
     }
     (*metadata).stable_type.assign(mem, &new_type);
 }
@@ -296,6 +322,16 @@ pub(crate) unsafe fn get_incremental_gc_state() -> &'static mut State {
     &mut (*metadata).incremental_gc_state
 }
 
+pub(crate) unsafe fn get_gc_scheduling_policy() -> &'static mut SchedulingPolicy {
+    let metadata = PersistentMetadata::get();
+    &mut (*metadata).gc_scheduling_policy
+}
+
+pub(crate) unsafe fn get_young_generation_size() -> &'static mut usize {
+    let metadata = PersistentMetadata::get();
+    &mut (*metadata).young_generation_size
+}
+
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn get_upgrade_instructions() -> u64 {
     let metadata = PersistentMetadata::get();
@@ -6,7 +6,7 @@ use crate::{
     barriers::write_with_barrier,
     bitrel::BitRel,
     constants::WORD_SIZE,
-    idl::TypeVariance,
+    idl::{IncompatibilityTrace, TypeVariance},
     memory::{Memory, alloc_blob},
     types::{TAG_BLOB_B, Value, Words},
 };
@@ -145,10 +145,12 @@ const MAIN_ACTOR_TYPE_INDEX: i32 = 0;
 
 /// Test whether the new stable type complies with the existing old stable type.
 /// This uses the existing IDL subtype test.
-pub unsafe fn memory_compatible<M: Memory>(
+/// On incompatibility, `trace` explains the failing type path.
+pub(crate) unsafe fn memory_compatible<M: Memory>(
     mem: &mut M,
     old_type: &mut TypeDescriptor,
     new_type: &mut TypeDescriptor,
+    trace: &mut IncompatibilityTrace,
 ) -> bool {
     let cache = create_type_check_cache(mem, old_type, new_type);
 
@@ -168,5 +170,6 @@ pub unsafe fn memory_compatible<M: Memory>(
         MAIN_ACTOR_TYPE_INDEX,
         MAIN_ACTOR_TYPE_INDEX,
         true,
+        trace,
     )
 }
@@ -18,12 +18,15 @@ pub unsafe extern "C" fn compute_crc32(blob: Value) -> u32 {
     }
 
     let blob = blob.as_blob();
-    let len = blob.len();
+    let bytes = core::slice::from_raw_parts(blob.payload_const(), blob.len().as_usize());
+    update_crc32(0, bytes)
+}
 
-    let mut crc: u32 = !0;
+/// Extend the CRC32 `crc` of preceding data by `bytes`, starting with `0` for empty data.
+pub fn update_crc32(crc: u32, bytes: &[u8]) -> u32 {
+    let mut crc = !crc;
 
-    for i in 0..len.as_usize() {
-        let octet = blob.get(i);
+    for octet in bytes {
         crc = (crc >> 8) ^ CRC_TABLE[usize::from((crc & 0xFF) as u8 ^ octet)];
     }
 
@@ -75,6 +75,15 @@ impl<'a> WriteBuf<'a> {
         self.offset = 0;
     }
 
+    /// The written text, cut at the last complete UTF-8 character if the buffer overflowed.
+    pub(crate) fn as_str(&self) -> &str {
+        let bytes = &self.buf[..self.offset];
+        match core::str::from_utf8(bytes) {
+            Ok(text) => text,
+            Err(error) => unsafe { core::str::from_utf8_unchecked(&bytes[..error.valid_up_to()]) },
+        }
+    }
+
     pub(crate) unsafe fn print(&self) {
         print_ptr(self.buf.as_ptr() as usize, self.offset)
     }
@@ -12,14 +12,14 @@ pub const LEGACY_VERSION_SOME_STABLE_MEMORY: usize = 1;
 pub const LEGACY_VERSION_REGIONS: usize = 2;
 
 // New versions, used with enhanced orthogonal persistence
-pub(crate) const VERSION_GRAPH_COPY_NO_REGIONS: usize = 3;
-pub(crate) const VERSION_GRAPH_COPY_REGIONS: usize = 4;
-pub(crate) const VERSION_STABLE_HEAP_NO_REGIONS: usize = 5;
-pub(crate) const VERSION_STABLE_HEAP_REGIONS: usize = 6;
+pub const VERSION_GRAPH_COPY_NO_REGIONS: usize = 3;
+pub const VERSION_GRAPH_COPY_REGIONS: usize = 4;
+pub const VERSION_STABLE_HEAP_NO_REGIONS: usize = 5;
+pub const VERSION_STABLE_HEAP_REGIONS: usize = 6;
 // V1 graph-copy: adds a 16-byte extension block in front of the legacy 40-byte
 // last-page record carrying extra GC roots (dedup table, migrations list).
-pub(crate) const VERSION_GRAPH_COPY_V1_NO_REGIONS: usize = 7;
-pub(crate) const VERSION_GRAPH_COPY_V1_REGIONS: usize = 8;
+pub const VERSION_GRAPH_COPY_V1_NO_REGIONS: usize = 7;
+pub const VERSION_GRAPH_COPY_V1_REGIONS: usize = 8;
 
 const _: () = assert!(meta_data::size::PAGE_IN_BYTES == crate::stable_mem::PAGE_SIZE);
 const _: () = assert!(meta_data::size::PAGES_IN_BLOCK <= u8::MAX as u32);
@@ -232,15 +232,35 @@ impl RegionObject {
 }
 
 // Mutable meta data stored in stable memory header (See motoko/design/StableRegions.md)
-mod meta_data {
+pub mod meta_data {
 
     pub const fn bytes_of<T>() -> u64 {
         core::mem::size_of::<T>() as u64
     }
 
     pub mod version {
+        use super::offset;
+        use crate::stable_mem::{read_u32, write_u32};
+
         pub const MAGIC: &[u8; 8] = b"MOREGION";
-        pub const VERSION: u32 = 2;
+        /// Version 3 adds the free block list. Older layouts have a zeroed (empty) list.
+        /// Version 4 adds the header checksum.
+        pub const VERSION: u32 = 4;
+        pub const FIRST_CHECKSUM_VERSION: u32 = 4;
+
+        pub fn get() -> u32 {
+            read_u32(offset::VERSION)
+        }
+
+        /// Upgrade an older layout on the first release of blocks, such that older
+        /// compilers can still read the unchanged meta data until the free block list is used.
+        pub fn upgrade() {
+            if get() < VERSION {
+                // Older layouts have no free blocks and no header checksum, i.e. zeroed fields.
+                write_u32(offset::VERSION, VERSION);
+                super::header_checksum::update();
+            }
+        }
     }
 
     /// Maximum number of entities.
@@ -294,7 +314,13 @@ mod meta_data {
 
         pub const BLOCK_REGION_TABLE: u64 = TOTAL_ALLOCATED_REGIONS + bytes_of::<u64>();
 
-        pub const FREE: u64 = BLOCK_REGION_TABLE + super::size::BLOCK_REGION_TABLE;
+        pub const FREE_BLOCK_LIST: u64 = BLOCK_REGION_TABLE + super::size::BLOCK_REGION_TABLE;
+
+        pub const TOTAL_FREE_BLOCKS: u64 = FREE_BLOCK_LIST + bytes_of::<u16>();
+
+        pub const HEADER_CHECKSUM: u64 = TOTAL_FREE_BLOCKS + bytes_of::<u32>();
+
+        pub const FREE: u64 = HEADER_CHECKSUM + bytes_of::<u32>();
 
         pub const BASE_LOW: u64 = 16 * super::size::PAGE_IN_BYTES;
 
@@ -319,7 +345,8 @@ mod meta_data {
             unsafe {
                 REGION_TOTAL_ALLOCATED_BLOCKS = n;
             };
-            write_u32(offset::TOTAL_ALLOCATED_BLOCKS, n)
+            write_u32(offset::TOTAL_ALLOCATED_BLOCKS, n);
+            super::header_checksum::update();
         }
     }
 
@@ -331,7 +358,35 @@ mod meta_data {
             read_u64(offset::TOTAL_ALLOCATED_REGIONS)
         }
         pub fn set(n: u64) {
-            write_u64(offset::TOTAL_ALLOCATED_REGIONS, n)
+            write_u64(offset::TOTAL_ALLOCATED_REGIONS, n);
+            super::header_checksum::update();
+        }
+    }
+
+    /// CRC32 over the header fields, i.e. the meta data without the block region table.
+    /// Guards against silent corruption of the header in stable memory.
+    pub mod header_checksum {
+        use super::offset;
+        use crate::principal_id::update_crc32;
+        use crate::stable_mem::{read, read_u32, write_u32};
+
+        pub fn compute() -> u32 {
+            let mut fields = [0u8; offset::BLOCK_REGION_TABLE as usize];
+            read(offset::MAGIC, &mut fields);
+            let mut free_list = [0u8; (offset::HEADER_CHECKSUM - offset::FREE_BLOCK_LIST) as usize];
+            read(offset::FREE_BLOCK_LIST, &mut free_list);
+            update_crc32(update_crc32(0, &fields), &free_list)
+        }
+
+        pub fn get() -> u32 {
+            read_u32(offset::HEADER_CHECKSUM)
+        }
+
+        /// Older layouts are only checksummed after their `version::upgrade`.
+        pub fn update() {
+            if super::version::get() >= super::version::FIRST_CHECKSUM_VERSION {
+                write_u32(offset::HEADER_CHECKSUM, compute())
+            }
         }
     }
 
@@ -372,6 +427,68 @@ mod meta_data {
             write_u16(rank_offset, rank);
             write_u8(page_count_offset, allocated_pages);
         }
+
+        /// Link of a free block in the free block list, stored in the rank field.
+        pub fn get_next_free(b: BlockId) -> Option<BlockId> {
+            debug_assert!(get(b.clone()).is_none());
+            let rank_offset = index(&b) + bytes_of::<u64>();
+            super::free_blocks::decode(read_u16(rank_offset))
+        }
+
+        /// Release the block and link it to the next free block.
+        pub fn set_next_free(b: BlockId, next: Option<BlockId>) {
+            set(b.clone(), None);
+            let rank_offset = index(&b) + bytes_of::<u64>();
+            write_u16(rank_offset, super::free_blocks::encode(next));
+        }
+    }
+
+    /// Blocks released by `region_free`, linked through their `block_region_table` entries.
+    pub mod free_blocks {
+        use super::{block_region_table, offset};
+        use crate::region::BlockId;
+        use crate::stable_mem::{read_u16, read_u32, write_u16, write_u32};
+
+        // Block ids are encoded as `id + 1`, zero denotes the end of the list.
+        pub(super) fn decode(link: u16) -> Option<BlockId> {
+            if link == 0 {
+                None
+            } else {
+                Some(BlockId(link - 1))
+            }
+        }
+
+        pub(super) fn encode(block: Option<BlockId>) -> u16 {
+            match block {
+                None => 0,
+                Some(b) => b.0 + 1,
+            }
+        }
+
+        pub fn count() -> u32 {
+            read_u32(offset::TOTAL_FREE_BLOCKS)
+        }
+
+        pub fn first() -> Option<BlockId> {
+            decode(read_u16(offset::FREE_BLOCK_LIST))
+        }
+
+        pub fn push(b: BlockId) {
+            super::version::upgrade();
+            block_region_table::set_next_free(b.clone(), first());
+            write_u16(offset::FREE_BLOCK_LIST, encode(Some(b)));
+            write_u32(offset::TOTAL_FREE_BLOCKS, count() + 1);
+            super::header_checksum::update();
+        }
+
+        pub fn pop() -> Option<BlockId> {
+            let b = first()?;
+            let next = block_region_table::get_next_free(b.clone());
+            write_u16(offset::FREE_BLOCK_LIST, encode(next));
+            write_u32(offset::TOTAL_FREE_BLOCKS, count() - 1);
+            super::header_checksum::update();
+            Some(b)
+        }
     }
 }
 
@@ -386,6 +503,7 @@ unsafe fn write_magic() {
         meta_data::size::PAGES_IN_BLOCK as u16,
     );
     write_u64(meta_data::offset::BLOCK_BASE, BLOCK_BASE);
+    meta_data::header_checksum::update();
 }
 
 #[ic_mem_fn]
@@ -507,14 +625,18 @@ unsafe fn migrate_on_new_region<M: Memory>(mem: &mut M) {
 #[enhanced_orthogonal_persistence]
 unsafe fn migrate_on_new_region<M: Memory>(mem: &mut M) {
     match crate::stable_mem::get_version() {
-        VERSION_STABLE_HEAP_NO_REGIONS | VERSION_GRAPH_COPY_NO_REGIONS => {
+        VERSION_STABLE_HEAP_NO_REGIONS
+        | VERSION_GRAPH_COPY_NO_REGIONS
+        | VERSION_GRAPH_COPY_V1_NO_REGIONS => {
             if crate::stable_mem::size() == 0 {
                 region_migration_from_no_stable_memory(mem);
             } else {
                 region_migration_from_some_stable_memory(mem);
             }
         }
-        VERSION_STABLE_HEAP_REGIONS | VERSION_GRAPH_COPY_REGIONS => {}
+        VERSION_STABLE_HEAP_REGIONS
+        | VERSION_GRAPH_COPY_REGIONS
+        | VERSION_GRAPH_COPY_V1_REGIONS => {}
         _ => {
             assert!(false);
         }
@@ -600,6 +722,7 @@ fn upgrade_version_to_regions() {
         match crate::stable_mem::get_version() {
             VERSION_STABLE_HEAP_NO_REGIONS => VERSION_STABLE_HEAP_REGIONS,
             VERSION_GRAPH_COPY_NO_REGIONS => VERSION_GRAPH_COPY_REGIONS,
+            VERSION_GRAPH_COPY_V1_NO_REGIONS => VERSION_GRAPH_COPY_V1_REGIONS,
             _ => unreachable!(),
         }
     } else {
@@ -616,6 +739,7 @@ pub(crate) unsafe fn region_migration_from_no_stable_memory<M: Memory>(mem: &mut
         assert!(
             get_version() == VERSION_STABLE_HEAP_NO_REGIONS
                 || get_version() == VERSION_GRAPH_COPY_NO_REGIONS
+                || get_version() == VERSION_GRAPH_COPY_V1_NO_REGIONS
         );
     } else {
         assert!(get_version() == LEGACY_VERSION_NO_STABLE_MEMORY);
@@ -792,9 +916,8 @@ pub(crate) unsafe fn region_migration_from_some_stable_memory<M: Memory>(mem: &m
 // region manager migration/initialization, with pre-existing stable data.
 // Case: Version 2 into version 2 ("Trivial migration" case).
 //
-#[cfg(feature = "ic")]
 pub(crate) unsafe fn region_migration_from_regions_plus<M: Memory>(mem: &mut M) {
-    use crate::stable_mem::{read, read_u16, read_u32, read_u64, size};
+    use crate::stable_mem::{read, read_u16, read_u64, size};
 
     // Check if the magic in the memory corresponds to this object.
     assert!(size() > 1);
@@ -803,10 +926,16 @@ pub(crate) unsafe fn region_migration_from_regions_plus<M: Memory>(mem: &mut M)
     if &magic_bytes != meta_data::version::MAGIC {
         region_trap_with("migration failure (bad magic bytes)")
     };
-    let version = read_u32(meta_data::offset::VERSION);
+    // Older versions are kept until `meta_data::version::upgrade`.
+    let version = meta_data::version::get();
     if version > meta_data::version::VERSION {
         region_trap_with("migration failure (unexpected higher version)")
     };
+    if version >= meta_data::version::FIRST_CHECKSUM_VERSION
+        && meta_data::header_checksum::get() != meta_data::header_checksum::compute()
+    {
+        region_trap_with("migration failure (meta data checksum mismatch)")
+    };
 
     let block_pages = read_u16(meta_data::offset::BLOCK_PAGES);
     if block_pages as u32 != meta_data::size::PAGES_IN_BLOCK {
@@ -831,8 +960,8 @@ pub(crate) unsafe fn region_migration_from_regions_plus<M: Memory>(mem: &mut M)
 // region manager migration/initialization, with pre-existing stable data.
 //
 #[classical_persistence]
-#[ic_mem_fn(ic_only)]
-pub(crate) unsafe fn region_init<M: Memory>(mem: &mut M, use_stable_regions: usize) {
+#[ic_mem_fn]
+pub unsafe fn region_init<M: Memory>(mem: &mut M, use_stable_regions: usize) {
     match crate::stable_mem::get_version() {
         LEGACY_VERSION_NO_STABLE_MEMORY => {
             assert!(crate::stable_mem::size() == 0);
@@ -862,10 +991,12 @@ pub(crate) unsafe fn region_init<M: Memory>(mem: &mut M, use_stable_regions: usi
 }
 
 #[enhanced_orthogonal_persistence]
-#[ic_mem_fn(ic_only)]
-pub(crate) unsafe fn region_init<M: Memory>(mem: &mut M, use_stable_regions: usize) {
+#[ic_mem_fn]
+pub unsafe fn region_init<M: Memory>(mem: &mut M, use_stable_regions: usize) {
     match crate::stable_mem::get_version() {
-        VERSION_STABLE_HEAP_NO_REGIONS | VERSION_GRAPH_COPY_NO_REGIONS => {
+        VERSION_STABLE_HEAP_NO_REGIONS
+        | VERSION_GRAPH_COPY_NO_REGIONS
+        | VERSION_GRAPH_COPY_V1_NO_REGIONS => {
             if use_stable_regions != 0 {
                 if crate::stable_mem::size() == 0 {
                     region_migration_from_no_stable_memory(mem);
@@ -878,7 +1009,9 @@ pub(crate) unsafe fn region_init<M: Memory>(mem: &mut M, use_stable_regions: usi
                 }
             }
         }
-        VERSION_STABLE_HEAP_REGIONS | VERSION_GRAPH_COPY_REGIONS => {
+        VERSION_STABLE_HEAP_REGIONS
+        | VERSION_GRAPH_COPY_REGIONS
+        | VERSION_GRAPH_COPY_V1_REGIONS => {
             region_migration_from_regions_plus(mem); //check format & recover region0
             debug_assert!(meta_data::offset::FREE < BLOCK_BASE);
             debug_assert!(
@@ -919,8 +1052,13 @@ pub unsafe fn region_grow<M: Memory>(mem: &mut M, r: Value, new_pages: u64) -> u
     let inc_block_count = new_block_count - old_block_count;
 
     // Determine the required total number of allocated blocks,
+    // reusing blocks released by `region_free` before appending new blocks.
     let old_total_blocks = meta_data::total_allocated_blocks::get();
-    let new_total_blocks = old_total_blocks as u64 + inc_block_count as u64;
+    let reused_block_count = core::cmp::min(inc_block_count, meta_data::free_blocks::count());
+    let new_total_blocks = old_total_blocks as u64 + (inc_block_count - reused_block_count) as u64;
+    if new_total_blocks > meta_data::max::BLOCKS as u64 {
+        return u64::MAX;
+    }
 
     // Actually grow stable memory with more pages as required,
     // while respecting the global maximum limit on pages.
@@ -971,16 +1109,20 @@ pub unsafe fn region_grow<M: Memory>(mem: &mut M, r: Value, new_pages: u64) -> u
     );
 
     let new_pages = AccessVector::from_value(&new_vec_pages);
+    let mut next_appended_block = old_total_blocks;
 
     // Record new associations, between the region and each new block:
     // - in block_region_table (stable memory, for persistence).
     // - in region representation (heap memory, for fast access operations).
     for i in old_block_count..new_block_count {
-        // rel_i starts at zero.
-        let rel_i: u16 = (i - old_block_count) as u16;
-
-        // (to do -- handle case where allocating this way has run out.)
-        let block_id: u16 = (old_total_blocks + rel_i as u32) as u16;
+        // Free blocks are zero, see `region_shrink`.
+        let block_id: u16 = match meta_data::free_blocks::pop() {
+            Some(BlockId(block_id)) => block_id,
+            None => {
+                next_appended_block += 1;
+                (next_appended_block - 1) as u16
+            }
+        };
 
         // Update stable memory with new association.
         let block_page_count = block_page_count(i as u16, new_block_count, (*r).page_count as u32);
@@ -995,6 +1137,71 @@ pub unsafe fn region_grow<M: Memory>(mem: &mut M, r: Value, new_pages: u64) -> u
     old_page_count as u64
 }
 
+/// Release all blocks of a region for reuse by other regions and return its previous size.
+/// The region remains valid with zero pages and can be grown again.
+/// Region ids are not recycled.
+#[ic_mem_fn]
+pub unsafe fn region_free<M: Memory>(mem: &mut M, r: Value) -> u64 {
+    if r.as_region().read_id64() == 0 {
+        region_trap_with("cannot free region 0")
+    }
+    region_shrink(mem, r, 0)
+}
+
+/// Truncate a region to `new_pages` and return its previous size, or `u64::MAX` if the region
+/// is smaller than `new_pages`. Whole trailing blocks are released for reuse by other regions.
+/// Truncated pages are zeroed, such that they read as zero after regrowing or reusing their block.
+/// This costs in proportion to the truncated pages, while `region_grow` never zeroes.
+#[ic_mem_fn]
+pub unsafe fn region_shrink<M: Memory>(mem: &mut M, r: Value, new_pages: u64) -> u64 {
+    use crate::stable_mem::write;
+    use meta_data::size::{PAGE_IN_BYTES, PAGES_IN_BLOCK};
+
+    let region = RegionObject::from_value(&r);
+    let r = r.as_region();
+    let old_page_count = (*r).page_count as u64;
+    if new_pages > old_page_count {
+        return u64::MAX;
+    }
+
+    let old_block_count = (old_page_count as u32).div_ceil(PAGES_IN_BLOCK);
+    let new_block_count = (new_pages as u32).div_ceil(PAGES_IN_BLOCK);
+
+    // Unused pages of allocated blocks and all pages of free blocks are zero.
+    let zero_page: [u8; PAGE_IN_BYTES as usize] = [0; PAGE_IN_BYTES as usize];
+    for page in new_pages..old_page_count {
+        let (offset, _, _) = region.relative_into_absolute_info(page * PAGE_IN_BYTES);
+        write(offset, &zero_page);
+    }
+
+    let av = AccessVector::from_value(&(*r).vec_pages);
+    if new_block_count > 0 {
+        let last_block_rank = (new_block_count - 1) as u16;
+        let last_block_id = av.get_ith_block_id(last_block_rank as u32);
+        let last_page_count = block_page_count(last_block_rank, new_block_count, new_pages as u32);
+        let assoc = Some((region.id(), last_block_rank, last_page_count));
+        meta_data::block_region_table::set(last_block_id, assoc);
+    }
+    (*r).page_count = new_pages as usize;
+
+    if new_block_count < old_block_count {
+        for rank in new_block_count..old_block_count {
+            meta_data::free_blocks::push(av.get_ith_block_id(rank));
+        }
+
+        let new_vec_byte_count = new_block_count as usize * meta_data::bytes_of::<u16>() as usize;
+        let new_vec_pages = alloc_blob(mem, TAG_BLOB_B, Bytes(new_vec_byte_count));
+        crate::mem_utils::memcpy_bytes(
+            new_vec_pages.as_blob_mut().payload_addr() as usize,
+            (*r).vec_pages.as_blob().payload_const() as usize,
+            Bytes(new_vec_byte_count),
+        );
+        allocation_barrier(new_vec_pages);
+        write_with_barrier(mem, &mut (*r).vec_pages, new_vec_pages);
+    }
+    old_page_count
+}
+
 pub(crate) unsafe fn region_load<M: Memory>(_mem: &mut M, r: Value, offset: u64, dst: &mut [u8]) {
     use crate::stable_mem::read;
     use meta_data::size::BLOCK_IN_BYTES;
@@ -1102,6 +1309,128 @@ pub(crate) unsafe fn region_store<M: Memory>(_mem: &mut M, r: Value, offset: u64
     }
 }
 
+// -- Region bulk operations.
+
+/// Copy `length` bytes between (or within) regions, without passing through the heap.
+/// Overlapping ranges within the same region are copied as if through a temporary buffer.
+#[ic_mem_fn]
+pub unsafe fn region_copy<M: Memory>(
+    _mem: &mut M,
+    src: Value,
+    src_offset: u64,
+    dst: Value,
+    dst_offset: u64,
+    length: u64,
+) {
+    use crate::stable_mem::{read, write};
+    use meta_data::size::{BLOCK_IN_BYTES, PAGE_IN_BYTES};
+
+    let src = RegionObject::from_value(&src);
+    let dst = RegionObject::from_value(&dst);
+    src.check_relative_range(src_offset, length);
+    dst.check_relative_range(dst_offset, length);
+
+    // Each chunk is contained in a single block of both regions.
+    let mut buffer: [u8; PAGE_IN_BYTES as usize] = [0; PAGE_IN_BYTES as usize];
+    let backwards = src.id() == dst.id() && src_offset < dst_offset;
+    let mut remaining = length;
+    while remaining > 0 {
+        let (position, chunk) = if backwards {
+            // Copy from the end, such that overlapping source bytes are read before being overwritten.
+            let src_available = (src_offset + remaining - 1) % BLOCK_IN_BYTES + 1;
+            let dst_available = (dst_offset + remaining - 1) % BLOCK_IN_BYTES + 1;
+            let chunk = remaining.min(src_available).min(dst_available);
+            (
+                remaining - chunk.min(PAGE_IN_BYTES),
+                chunk.min(PAGE_IN_BYTES),
+            )
+        } else {
+            let position = length - remaining;
+            let src_available = BLOCK_IN_BYTES - (src_offset + position) % BLOCK_IN_BYTES;
+            let dst_available = BLOCK_IN_BYTES - (dst_offset + position) % BLOCK_IN_BYTES;
+            let chunk = remaining.min(src_available).min(dst_available);
+            (position, chunk.min(PAGE_IN_BYTES))
+        };
+        let (src_address, _, _) = src.relative_into_absolute_info(src_offset + position);
+        let (dst_address, _, _) = dst.relative_into_absolute_info(dst_offset + position);
+        let chunk = &mut buffer[..chunk as usize];
+        read(src_address, chunk);
+        write(dst_address, chunk);
+        remaining -= chunk.len() as u64;
+    }
+}
+
+/// Set `length` bytes of a region to `byte`.
+#[ic_mem_fn]
+pub unsafe fn region_fill<M: Memory>(_mem: &mut M, r: Value, offset: u64, length: u64, byte: u32) {
+    use crate::stable_mem::write;
+    use meta_data::size::PAGE_IN_BYTES;
+
+    let r = RegionObject::from_value(&r);
+    r.check_relative_range(offset, length);
+
+    let pattern: [u8; PAGE_IN_BYTES as usize] = [byte as u8; PAGE_IN_BYTES as usize];
+    let mut position = 0;
+    while position < length {
+        let (address, _, available) = r.relative_into_absolute_info(offset + position);
+        let chunk = (length - position).min(available).min(PAGE_IN_BYTES);
+        write(address, &pattern[..chunk as usize]);
+        position += chunk;
+    }
+}
+
+// -- Region checksums.
+
+/// CRC32 of `length` bytes of a region, e.g. to verify region contents across upgrades.
+#[ic_mem_fn]
+pub unsafe fn region_checksum<M: Memory>(_mem: &mut M, r: Value, offset: u64, length: u64) -> u32 {
+    use crate::principal_id::update_crc32;
+    use crate::stable_mem::read;
+    use meta_data::size::PAGE_IN_BYTES;
+
+    let r = RegionObject::from_value(&r);
+    r.check_relative_range(offset, length);
+
+    let mut buffer: [u8; PAGE_IN_BYTES as usize] = [0; PAGE_IN_BYTES as usize];
+    let mut crc = 0;
+    let mut position = 0;
+    while position < length {
+        let (address, _, available) = r.relative_into_absolute_info(offset + position);
+        let chunk = &mut buffer[..(length - position).min(available).min(PAGE_IN_BYTES) as usize];
+        read(address, chunk);
+        crc = update_crc32(crc, chunk);
+        position += chunk.len() as u64;
+    }
+    crc
+}
+
+/// CRC32 of the entire region manager meta data, including the block region table.
+#[ic_mem_fn]
+pub unsafe fn region_meta_data_checksum<M: Memory>(_mem: &mut M) -> u32 {
+    use crate::principal_id::update_crc32;
+    use crate::stable_mem::{read, size};
+    use meta_data::size::PAGE_IN_BYTES;
+
+    let mut magic_bytes: [u8; 8] = [0; 8];
+    if size() > 0 {
+        read(meta_data::offset::MAGIC, &mut magic_bytes);
+    }
+    if &magic_bytes != meta_data::version::MAGIC {
+        region_trap_with("no region meta data")
+    }
+
+    let mut buffer: [u8; PAGE_IN_BYTES as usize] = [0; PAGE_IN_BYTES as usize];
+    let mut crc = 0;
+    let mut position = 0;
+    while position < meta_data::offset::FREE {
+        let chunk = &mut buffer[..(meta_data::offset::FREE - position).min(PAGE_IN_BYTES) as usize];
+        read(position, chunk);
+        crc = update_crc32(crc, chunk);
+        position += chunk.len() as u64;
+    }
+    crc
+}
+
 // -- Region load operations.
 
 #[ic_mem_fn]
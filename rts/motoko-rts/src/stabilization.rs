@@ -17,6 +17,8 @@
 
 pub mod deserialization;
 pub mod graph_copy;
+pub mod inspection;
+pub mod last_page_record;
 pub mod layout;
 pub mod serialization;
 
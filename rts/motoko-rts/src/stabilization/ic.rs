@@ -18,9 +18,10 @@ use crate::{
     types::Value,
 };
 
-use self::{metadata::UpgradeStatistics, performance::InstructionMeter};
+use self::performance::InstructionMeter;
 
 use super::graph_copy::GraphCopy;
+use super::last_page_record::UpgradeStatistics;
 use super::{deserialization::Deserialization, serialization::Serialization};
 
 struct StabilizationState {
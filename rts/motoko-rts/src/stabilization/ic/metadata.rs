@@ -42,7 +42,10 @@ use crate::{
         VERSION_GRAPH_COPY_V1_NO_REGIONS, VERSION_GRAPH_COPY_V1_REGIONS,
         VERSION_STABLE_HEAP_NO_REGIONS, VERSION_STABLE_HEAP_REGIONS,
     },
-    stabilization::{StableValue, clear_stable_memory, grant_stable_space},
+    stabilization::{
+        StableValue, clear_stable_memory, grant_stable_space,
+        last_page_record::{LastPageRecord, UpgradeStatistics},
+    },
     stable_mem::{
         PAGE_SIZE, get_version, ic0_stable64_read, ic0_stable64_size, ic0_stable64_write, read_u32,
         read_u64, set_version, write_u32, write_u64,
@@ -52,44 +55,6 @@ use crate::{
 
 use super::performance::InstructionMeter;
 
-#[repr(C)]
-#[derive(Default)]
-pub struct UpgradeStatistics {
-    pub stabilization_instructions: u64,
-}
-
-/// `#[repr(C)]` laid out to mirror the full V1 last-page record in stable
-/// memory: the 16-byte extension (extra GC roots) sits in the first two
-/// fields, followed by the legacy 40-byte core ending with `first_word_backup`
-/// and `version` at the compiler-hard-coded offsets. This ordering lets a
-/// single `ic0_stable64_read`/`write` move the whole record in one go.
-///
-/// The extension must be at the *start* of the struct because the record is
-/// anchored at the *end* of the last stable-memory page (so that `version`
-/// lands at `PAGE_SIZE-4`, as the OCaml compiler hard-codes). The extension
-/// therefore precedes the legacy core in stable memory too, and only a
-/// struct that opens with the extension yields a 1:1 byte mapping over a
-/// single `ic0_stable64_read`/`write`.
-#[repr(C)]
-#[derive(Default)]
-pub struct LastPageRecord {
-    /// Extra arguments to handle extra GC roots:
-    ///     * dedup_table_address
-    ///     * migrations_list_address
-    /// The two fields are tracking the addresses of
-    /// the saved extra GC-roots used specifically for
-    /// handling the auxiliary runtime data, i.e., the blob dedup table and the migrations list.
-    /// The value is 0 if not present, or a u64 integer with the address.
-    pub dedup_table_address: StableValue,
-    pub migrations_list_address: StableValue,
-    pub statistics: UpgradeStatistics,
-    serialized_data_address: u64,
-    serialized_data_length: u64,
-    type_descriptor_address: u64,
-    first_word_backup: u32,
-    version: u32,
-}
-
 pub struct StabilizationMetadata {
     pub serialized_data_start: u64,
     pub serialized_data_length: u64,
@@ -0,0 +1,708 @@
+//! Offline inspection of graph-copy stabilization snapshots.
+//!
+//! Reads a stable memory image without modifying it, e.g. a snapshot downloaded from
+//! a canister before attempting a risky upgrade. The image is accessed through
+//! `ic0_stable64_size` and `ic0_stable64_read`, such that a host-side program can serve
+//! these functions from a raw stable memory file. The inspection does not allocate
+//! and does not depend on the main memory heap.
+//!
+//! See `ic/metadata.rs` for the stable memory format and `layout.rs` for the object encoding.
+
+use core::fmt::{self, Display, Write};
+use core::mem::size_of;
+
+use crate::region::{
+    VERSION_GRAPH_COPY_NO_REGIONS, VERSION_GRAPH_COPY_REGIONS, VERSION_GRAPH_COPY_V1_NO_REGIONS,
+    VERSION_GRAPH_COPY_V1_REGIONS,
+};
+use crate::stable_mem::{PAGE_SIZE, ic0_stable64_read, ic0_stable64_size};
+
+use super::{
+    deserialization::stable_memory_access::StableMemoryAccess,
+    last_page_record::LastPageRecord,
+    layout::{
+        StableBody, StableObjectInfo, StableObjectKind, StableSummary, StableTag, StableValue,
+        inspect,
+    },
+};
+
+// Number of leading array elements or blob bytes shown per object in the report.
+const PREVIEW_VALUES: u64 = 8;
+const PREVIEW_BYTES: usize = 32;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum InspectionError {
+    EmptyStableMemory,
+    UnsupportedVersion(u32),
+    /// The last page record refers to data outside the stable memory.
+    InvalidRecord,
+    InvalidTag {
+        address: u64,
+        tag: u64,
+    },
+    /// An object extends beyond the end of the serialized data.
+    TruncatedObject {
+        address: u64,
+    },
+    /// The length recorded in an object overflows the object size.
+    InvalidObjectSize {
+        address: u64,
+    },
+    /// Malformed Candid type table or type offset table.
+    InvalidTypeTable {
+        offset: u64,
+    },
+}
+
+impl Display for InspectionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EmptyStableMemory => write!(f, "empty stable memory"),
+            Self::UnsupportedVersion(version) => write!(
+                f,
+                "version {version} is not a graph-copy stabilization snapshot"
+            ),
+            Self::InvalidRecord => write!(f, "last page record out of bounds"),
+            Self::InvalidTag { address, tag } => {
+                write!(f, "invalid tag {tag:#x} at object {address:#x}")
+            }
+            Self::TruncatedObject { address } => {
+                write!(f, "object {address:#x} exceeds the serialized data")
+            }
+            Self::InvalidObjectSize { address } => {
+                write!(f, "invalid length in object {address:#x}")
+            }
+            Self::InvalidTypeTable { offset } => {
+                write!(f, "malformed type table at offset {offset:#x}")
+            }
+        }
+    }
+}
+
+/// Graph-copy stabilization snapshot in stable memory.
+pub struct Snapshot {
+    record: LastPageRecord,
+    stable_memory_size: u64,
+}
+
+impl Snapshot {
+    /// Reads and validates the last page record.
+    pub fn open() -> Result<Snapshot, InspectionError> {
+        let stable_memory_size = unsafe { ic0_stable64_size() } * PAGE_SIZE;
+        let record_size = size_of::<LastPageRecord>() as u64;
+        if stable_memory_size < record_size {
+            return Err(InspectionError::EmptyStableMemory);
+        }
+        let mut record = LastPageRecord::default();
+        unsafe {
+            ic0_stable64_read(
+                &mut record as *mut LastPageRecord as u64,
+                stable_memory_size - record_size,
+                record_size,
+            );
+        }
+        match record.version() as usize {
+            VERSION_GRAPH_COPY_NO_REGIONS | VERSION_GRAPH_COPY_REGIONS => {
+                // V0 records do not carry the extension.
+                record.dedup_table_address = StableValue::from_raw(0);
+                record.migrations_list_address = StableValue::from_raw(0);
+            }
+            VERSION_GRAPH_COPY_V1_NO_REGIONS | VERSION_GRAPH_COPY_V1_REGIONS => {}
+            _ => return Err(InspectionError::UnsupportedVersion(record.version())),
+        }
+        let data_end = record
+            .serialized_data_address()
+            .checked_add(record.serialized_data_length());
+        if data_end.is_none_or(|end| end > stable_memory_size)
+            || record.type_descriptor_address() >= stable_memory_size
+        {
+            return Err(InspectionError::InvalidRecord);
+        }
+        Ok(Snapshot {
+            record,
+            stable_memory_size,
+        })
+    }
+
+    pub fn record(&self) -> &LastPageRecord {
+        &self.record
+    }
+
+    pub fn stable_memory_size(&self) -> u64 {
+        self.stable_memory_size
+    }
+
+    pub fn uses_regions(&self) -> bool {
+        matches!(
+            self.record.version() as usize,
+            VERSION_GRAPH_COPY_REGIONS | VERSION_GRAPH_COPY_V1_REGIONS
+        )
+    }
+
+    fn stable_memory(&self) -> StableMemoryAccess {
+        let start = self.record.serialized_data_address();
+        StableMemoryAccess::open(start, self.stable_memory_size - start)
+    }
+
+    /// Linear walk over the serialized objects, starting with the stable root at offset 0.
+    pub fn objects(&self) -> SnapshotObjects {
+        SnapshotObjects {
+            stable_memory: self.stable_memory(),
+            // The very first word of stable memory is zeroed and backed up in the last page.
+            first_word_backup: if self.record.serialized_data_address() == 0 {
+                Some(self.record.first_word_backup())
+            } else {
+                None
+            },
+            address: 0,
+            length: self.record.serialized_data_length(),
+            failed: false,
+        }
+    }
+
+    /// Reads an element of an array or a field of an object.
+    pub fn body_value(&self, object: &StableObjectInfo, index: u64) -> StableValue {
+        debug_assert!(matches!(object.body, StableBody::Values(count) if index < count));
+        let offset = index * size_of::<StableValue>() as u64;
+        self.stable_memory()
+            .read::<StableValue>(object.body_address() + offset)
+    }
+
+    /// Copies bytes of a blob or big integer payload into `buffer`, returning the number of copied bytes.
+    pub fn body_bytes(&self, object: &StableObjectInfo, offset: u64, buffer: &mut [u8]) -> usize {
+        let StableBody::Bytes(length) = object.body else {
+            return 0;
+        };
+        let count = (length.saturating_sub(offset) as usize).min(buffer.len());
+        self.stable_memory().raw_read(
+            object.body_address() + offset,
+            buffer.as_mut_ptr() as usize,
+            count,
+        );
+        count
+    }
+
+    pub fn type_table(&self) -> Result<TypeTable, InspectionError> {
+        let mut reader = StableReader::new(
+            self.record.type_descriptor_address(),
+            self.stable_memory_size,
+        );
+        let candid_length = reader.read_u64()?;
+        let candid_address = reader.offset;
+        reader.skip(candid_length)?;
+        let offsets_length = reader.read_u64()?;
+        let offsets_address = reader.offset;
+        reader.skip(offsets_length)?;
+        if offsets_length % size_of::<u64>() as u64 != 0 {
+            return Err(InspectionError::InvalidTypeTable {
+                offset: offsets_address,
+            });
+        }
+        Ok(TypeTable {
+            candid_address,
+            candid_length,
+            offsets_address,
+            type_count: offsets_length / size_of::<u64>() as u64,
+        })
+    }
+}
+
+pub struct SnapshotObjects {
+    stable_memory: StableMemoryAccess,
+    first_word_backup: Option<u32>,
+    address: u64,
+    length: u64,
+    failed: bool,
+}
+
+impl SnapshotObjects {
+    fn read_tag(&self) -> StableTag {
+        let tag = self.stable_memory.read::<u64>(self.address);
+        let tag = match self.first_word_backup {
+            Some(backup) if self.address == 0 => (tag & !(u32::MAX as u64)) | backup as u64,
+            _ => tag,
+        };
+        StableTag::from_raw(tag)
+    }
+
+    /// Decodes the object at the current address, returning it with its size.
+    fn next_object(&self) -> Result<(StableObjectInfo, u64), InspectionError> {
+        if self.length - self.address < size_of::<StableTag>() as u64 {
+            return Err(InspectionError::TruncatedObject {
+                address: self.address,
+            });
+        }
+        let tag = self.read_tag();
+        let object_kind = tag.try_decode().ok_or(InspectionError::InvalidTag {
+            address: self.address,
+            tag: tag.get_raw(),
+        })?;
+        let stable_object = StableValue::from_stable_address(self.address);
+        let object = inspect(&self.stable_memory, stable_object, object_kind);
+        let size = object.size().ok_or(InspectionError::InvalidObjectSize {
+            address: self.address,
+        })?;
+        if size > self.length - self.address {
+            return Err(InspectionError::TruncatedObject {
+                address: self.address,
+            });
+        }
+        Ok((object, size))
+    }
+}
+
+impl Iterator for SnapshotObjects {
+    type Item = Result<StableObjectInfo, InspectionError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed || self.address >= self.length {
+            return None;
+        }
+        match self.next_object() {
+            Ok((object, size)) => {
+                self.address += size;
+                Some(Ok(object))
+            }
+            Err(error) => {
+                self.failed = true;
+                Some(Err(error))
+            }
+        }
+    }
+}
+
+/// Location of the Candid type table and the type offset table in stable memory.
+pub struct TypeTable {
+    candid_address: u64,
+    candid_length: u64,
+    offsets_address: u64,
+    type_count: u64,
+}
+
+impl TypeTable {
+    pub fn type_count(&self) -> u64 {
+        self.type_count
+    }
+
+    pub fn candid_length(&self) -> u64 {
+        self.candid_length
+    }
+
+    /// Offset of the type definition in the Candid data.
+    pub fn type_offset(&self, index: u64) -> u64 {
+        debug_assert!(index < self.type_count);
+        let mut reader = StableReader::new(
+            self.offsets_address + index * size_of::<u64>() as u64,
+            u64::MAX,
+        );
+        reader.read_u64().unwrap()
+    }
+
+    /// Prints the type definition in a Candid-like syntax, referring to other
+    /// type table entries as `t<index>` and to record fields by their hashes.
+    pub fn write_type<W: Write>(&self, out: &mut W, index: u64) -> Result<(), WriteError> {
+        let offset = self.type_offset(index);
+        if offset >= self.candid_length {
+            return Err(InspectionError::InvalidTypeTable { offset }.into());
+        }
+        let mut reader = StableReader::new(
+            self.candid_address + offset,
+            self.candid_address + self.candid_length,
+        );
+        write_type_definition(out, &mut reader)
+    }
+}
+
+/// Failure of writing an inspection report.
+pub enum WriteError {
+    Format(fmt::Error),
+    Inspection(InspectionError),
+}
+
+impl From<fmt::Error> for WriteError {
+    fn from(error: fmt::Error) -> Self {
+        WriteError::Format(error)
+    }
+}
+
+impl From<InspectionError> for WriteError {
+    fn from(error: InspectionError) -> Self {
+        WriteError::Inspection(error)
+    }
+}
+
+/// Sequential reader of stable memory bytes, used for the type descriptor.
+struct StableReader {
+    offset: u64,
+    end: u64,
+}
+
+impl StableReader {
+    fn new(offset: u64, end: u64) -> Self {
+        StableReader { offset, end }
+    }
+
+    fn error(&self) -> InspectionError {
+        InspectionError::InvalidTypeTable {
+            offset: self.offset,
+        }
+    }
+
+    fn skip(&mut self, length: u64) -> Result<(), InspectionError> {
+        match self.offset.checked_add(length) {
+            Some(next) if next <= self.end => {
+                self.offset = next;
+                Ok(())
+            }
+            _ => Err(self.error()),
+        }
+    }
+
+    fn read_bytes(&mut self, buffer: &mut [u8]) -> Result<(), InspectionError> {
+        let offset = self.offset;
+        self.skip(buffer.len() as u64)?;
+        unsafe {
+            ic0_stable64_read(buffer.as_mut_ptr() as u64, offset, buffer.len() as u64);
+        }
+        Ok(())
+    }
+
+    fn read_byte(&mut self) -> Result<u8, InspectionError> {
+        let mut buffer = [0u8; 1];
+        self.read_bytes(&mut buffer)?;
+        Ok(buffer[0])
+    }
+
+    fn read_u64(&mut self) -> Result<u64, InspectionError> {
+        let mut buffer = [0u8; size_of::<u64>()];
+        self.read_bytes(&mut buffer)?;
+        Ok(u64::from_le_bytes(buffer))
+    }
+
+    fn read_leb128(&mut self) -> Result<u64, InspectionError> {
+        let mut result = 0u64;
+        let mut shift = 0;
+        loop {
+            let byte = self.read_byte()?;
+            if shift >= u64::BITS {
+                return Err(self.error());
+            }
+            result |= ((byte & 0x7f) as u64) << shift;
+            shift += 7;
+            if byte & 0x80 == 0 {
+                return Ok(result);
+            }
+        }
+    }
+
+    fn read_sleb128(&mut self) -> Result<i64, InspectionError> {
+        let mut result = 0i64;
+        let mut shift = 0;
+        loop {
+            let byte = self.read_byte()?;
+            if shift >= i64::BITS {
+                return Err(self.error());
+            }
+            result |= ((byte & 0x7f) as i64) << shift;
+            shift += 7;
+            if byte & 0x80 == 0 {
+                if shift < i64::BITS && byte & 0x40 != 0 {
+                    result |= -1 << shift;
+                }
+                return Ok(result);
+            }
+        }
+    }
+}
+
+// Candid type opcodes, including the Motoko-specific extensions, see `idl.rs`.
+fn candid_type_name(opcode: i64) -> Option<&'static str> {
+    Some(match opcode {
+        -1 => "null",
+        -2 => "bool",
+        -3 => "nat",
+        -4 => "int",
+        -5 => "nat8",
+        -6 => "nat16",
+        -7 => "nat32",
+        -8 => "nat64",
+        -9 => "int8",
+        -10 => "int16",
+        -11 => "int32",
+        -12 => "int64",
+        -13 => "float32",
+        -14 => "float64",
+        -15 => "text",
+        -16 => "reserved",
+        -17 => "empty",
+        -18 => "opt",
+        -19 => "vec",
+        -20 => "record",
+        -21 => "variant",
+        -22 => "func",
+        -23 => "service",
+        -24 => "principal",
+        -128 => "region",
+        -129 => "blob",
+        -130 => "tuple",
+        -131 => "weak",
+        _ => return None,
+    })
+}
+
+const CANDID_OPT: i64 = -18;
+const CANDID_VEC: i64 = -19;
+const CANDID_RECORD: i64 = -20;
+const CANDID_VARIANT: i64 = -21;
+const CANDID_FUNC: i64 = -22;
+const CANDID_SERVICE: i64 = -23;
+const CANDID_TUPLE: i64 = -130;
+const CANDID_WEAK: i64 = -131;
+// Mutable stable variable, see Note [mutable stable values] in `codegen/compile.ml`.
+const CANDID_ALIAS: i64 = 1;
+
+fn write_type_reference<W: Write>(
+    out: &mut W,
+    reader: &mut StableReader,
+) -> Result<(), WriteError> {
+    let reference = reader.read_sleb128()?;
+    if reference >= 0 {
+        write!(out, "t{reference}")?;
+    } else if let Some(name) = candid_type_name(reference) {
+        out.write_str(name)?;
+    } else {
+        write!(out, "<unknown type {reference}>")?;
+    }
+    Ok(())
+}
+
+fn write_type_sequence<W: Write>(out: &mut W, reader: &mut StableReader) -> Result<(), WriteError> {
+    out.write_char('(')?;
+    for index in 0..reader.read_leb128()? {
+        if index > 0 {
+            out.write_str(", ")?;
+        }
+        write_type_reference(out, reader)?;
+    }
+    out.write_char(')')?;
+    Ok(())
+}
+
+fn write_type_definition<W: Write>(
+    out: &mut W,
+    reader: &mut StableReader,
+) -> Result<(), WriteError> {
+    let opcode = reader.read_sleb128()?;
+    match opcode {
+        CANDID_ALIAS => {
+            out.write_str("var ")?;
+            write_type_reference(out, reader)?;
+        }
+        CANDID_OPT | CANDID_VEC | CANDID_WEAK => {
+            write!(out, "{} ", candid_type_name(opcode).unwrap())?;
+            write_type_reference(out, reader)?;
+        }
+        CANDID_RECORD | CANDID_VARIANT | CANDID_TUPLE => {
+            write!(out, "{} {{", candid_type_name(opcode).unwrap())?;
+            for index in 0..reader.read_leb128()? {
+                let separator = if index > 0 { "; " } else { "" };
+                let label = reader.read_leb128()?;
+                if opcode == CANDID_TUPLE {
+                    write!(out, "{separator}{label}: ")?;
+                } else {
+                    write!(out, "{separator}#{label}: ")?;
+                }
+                write_type_reference(out, reader)?;
+            }
+            out.write_char('}')?;
+        }
+        CANDID_FUNC => {
+            out.write_str("func ")?;
+            write_type_sequence(out, reader)?;
+            out.write_str(" -> ")?;
+            write_type_sequence(out, reader)?;
+            for _ in 0..reader.read_leb128()? {
+                match reader.read_byte()? {
+                    1 => out.write_str(" query")?,
+                    2 => out.write_str(" oneway")?,
+                    3 => out.write_str(" composite_query")?,
+                    annotation => write!(out, " <annotation {annotation}>")?,
+                }
+            }
+        }
+        CANDID_SERVICE => {
+            out.write_str("service {")?;
+            for index in 0..reader.read_leb128()? {
+                if index > 0 {
+                    out.write_str("; ")?;
+                }
+                let length = reader.read_leb128()?;
+                let mut name = [0u8; 64];
+                let shown = (length as usize).min(name.len());
+                reader.read_bytes(&mut name[..shown])?;
+                reader.skip(length - shown as u64)?;
+                match core::str::from_utf8(&name[..shown]) {
+                    Ok(name) if shown as u64 == length => out.write_str(name)?,
+                    Ok(prefix) => write!(out, "{prefix}...")?,
+                    Err(_) => out.write_str("<invalid name>")?,
+                }
+                out.write_str(": ")?;
+                write_type_reference(out, reader)?;
+            }
+            out.write_char('}')?;
+        }
+        _ => write!(out, "<unknown constructor {opcode}>")?,
+    }
+    Ok(())
+}
+
+fn write_value<W: Write>(out: &mut W, value: StableValue) -> fmt::Result {
+    if value.is_ptr() {
+        write!(out, "@{:#x}", value.to_stable_address())
+    } else {
+        write!(out, "{:#x}", value.get_raw())
+    }
+}
+
+fn write_object<W: Write>(
+    out: &mut W,
+    snapshot: &Snapshot,
+    object: &StableObjectInfo,
+) -> fmt::Result {
+    write!(out, "  @{:#x} {:?}", object.address, object.kind)?;
+    match object.summary {
+        StableSummary::None => {}
+        StableSummary::Bits64(bits) => write!(out, " {bits:#x}")?,
+        StableSummary::BigInt {
+            is_negative,
+            number_of_bits,
+        } => {
+            let sign = if is_negative { "negative" } else { "positive" };
+            write!(out, " {sign} {number_of_bits} bits")?
+        }
+        StableSummary::Concat { number_of_bytes } => write!(out, " {number_of_bytes} bytes")?,
+        StableSummary::Region { id, page_count } => write!(out, " id {id} {page_count} pages")?,
+        StableSummary::Variant { tag } => write!(out, " #{tag}")?,
+    }
+    for value in object.static_values() {
+        out.write_char(' ')?;
+        write_value(out, *value)?;
+    }
+    match object.body {
+        StableBody::Empty => {}
+        StableBody::Values(count) => {
+            write!(out, " [{count}]")?;
+            for index in 0..count.min(PREVIEW_VALUES) {
+                out.write_char(' ')?;
+                write_value(out, snapshot.body_value(object, index))?;
+            }
+            if count > PREVIEW_VALUES {
+                out.write_str(" ...")?;
+            }
+        }
+        StableBody::Bytes(length) => {
+            write!(out, " [{length} bytes]")?;
+            let mut preview = [0u8; PREVIEW_BYTES];
+            let shown = snapshot.body_bytes(object, 0, &mut preview);
+            let preview = &preview[..shown];
+            if object.kind == StableObjectKind::BlobText {
+                let text = match core::str::from_utf8(preview) {
+                    Ok(text) => text,
+                    // Cut at the last complete character of the preview.
+                    Err(error) => core::str::from_utf8(&preview[..error.valid_up_to()]).unwrap(),
+                };
+                write!(out, " \"{}\"", text.escape_debug())?;
+            } else if object.kind != StableObjectKind::BigInt {
+                out.write_char(' ')?;
+                for byte in preview {
+                    write!(out, "{byte:02x}")?;
+                }
+            }
+            if shown as u64 != length && object.kind != StableObjectKind::BigInt {
+                out.write_str("...")?;
+            }
+        }
+    }
+    out.write_char('\n')
+}
+
+/// Writes a human-readable report of the snapshot: version, statistics,
+/// type table, and a walk of the serialized object graph.
+/// Errors in the snapshot are included in the report, stopping the affected section.
+pub fn write_report<W: Write>(out: &mut W, snapshot: &Snapshot) -> fmt::Result {
+    let record = snapshot.record();
+    let version = record.version();
+    let format = match version as usize {
+        VERSION_GRAPH_COPY_NO_REGIONS | VERSION_GRAPH_COPY_REGIONS => "V0",
+        _ => "V1",
+    };
+    let regions = if snapshot.uses_regions() {
+        "regions"
+    } else {
+        "no regions"
+    };
+    writeln!(out, "Graph-copy stabilization snapshot")?;
+    writeln!(out, "  version: {version} ({format}, {regions})")?;
+    writeln!(
+        out,
+        "  stable memory size: {} bytes",
+        snapshot.stable_memory_size()
+    )?;
+    writeln!(
+        out,
+        "  stabilization instructions: {}",
+        record.statistics.stabilization_instructions
+    )?;
+    writeln!(
+        out,
+        "  serialized data: address {:#x}, length {}",
+        record.serialized_data_address(),
+        record.serialized_data_length()
+    )?;
+    writeln!(
+        out,
+        "  type descriptor: address {:#x}",
+        record.type_descriptor_address()
+    )?;
+    out.write_str("  dedup table: ")?;
+    write_value(out, record.dedup_table_address)?;
+    out.write_str(", migrations list: ")?;
+    write_value(out, record.migrations_list_address)?;
+    out.write_char('\n')?;
+
+    match snapshot.type_table() {
+        Ok(table) => {
+            writeln!(
+                out,
+                "Type table: {} types, {} bytes",
+                table.type_count(),
+                table.candid_length()
+            )?;
+            for index in 0..table.type_count() {
+                write!(out, "  t{index} = ")?;
+                match table.write_type(out, index) {
+                    Ok(()) => out.write_char('\n')?,
+                    Err(WriteError::Format(error)) => return Err(error),
+                    Err(WriteError::Inspection(error)) => {
+                        writeln!(out, "error: {error}")?;
+                        break;
+                    }
+                }
+            }
+        }
+        Err(error) => writeln!(out, "Type table: error: {error}")?,
+    }
+
+    writeln!(out, "Object graph:")?;
+    let mut count = 0;
+    for object in snapshot.objects() {
+        match object {
+            Ok(object) => {
+                write_object(out, snapshot, &object)?;
+                count += 1;
+            }
+            Err(error) => writeln!(out, "  error: {error}")?,
+        }
+    }
+    writeln!(out, "  {count} objects")
+}
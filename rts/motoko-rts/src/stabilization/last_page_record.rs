@@ -0,0 +1,68 @@
+//! Metadata record at the end of the last stable memory page, describing
+//! a graph-copy stabilization snapshot.
+//! See `ic/metadata.rs` for the complete stable memory format.
+//!
+//! The record is also read outside the IC, e.g. by the offline snapshot
+//! inspection in `inspection.rs`.
+
+use super::layout::StableValue;
+
+#[repr(C)]
+#[derive(Default, Clone, Copy)]
+pub struct UpgradeStatistics {
+    pub stabilization_instructions: u64,
+}
+
+/// `#[repr(C)]` laid out to mirror the full V1 last-page record in stable
+/// memory: the 16-byte extension (extra GC roots) sits in the first two
+/// fields, followed by the legacy 40-byte core ending with `first_word_backup`
+/// and `version` at the compiler-hard-coded offsets. This ordering lets a
+/// single `ic0_stable64_read`/`write` move the whole record in one go.
+///
+/// The extension must be at the *start* of the struct because the record is
+/// anchored at the *end* of the last stable-memory page (so that `version`
+/// lands at `PAGE_SIZE-4`, as the OCaml compiler hard-codes). The extension
+/// therefore precedes the legacy core in stable memory too, and only a
+/// struct that opens with the extension yields a 1:1 byte mapping over a
+/// single `ic0_stable64_read`/`write`.
+#[repr(C)]
+#[derive(Default, Clone, Copy)]
+pub struct LastPageRecord {
+    /// Extra arguments to handle extra GC roots:
+    ///     * dedup_table_address
+    ///     * migrations_list_address
+    /// The two fields are tracking the addresses of
+    /// the saved extra GC-roots used specifically for
+    /// handling the auxiliary runtime data, i.e., the blob dedup table and the migrations list.
+    /// The value is 0 if not present, or a u64 integer with the address.
+    pub dedup_table_address: StableValue,
+    pub migrations_list_address: StableValue,
+    pub statistics: UpgradeStatistics,
+    pub(crate) serialized_data_address: u64,
+    pub(crate) serialized_data_length: u64,
+    pub(crate) type_descriptor_address: u64,
+    pub(crate) first_word_backup: u32,
+    pub(crate) version: u32,
+}
+
+impl LastPageRecord {
+    pub fn serialized_data_address(&self) -> u64 {
+        self.serialized_data_address
+    }
+
+    pub fn serialized_data_length(&self) -> u64 {
+        self.serialized_data_length
+    }
+
+    pub fn type_descriptor_address(&self) -> u64 {
+        self.type_descriptor_address
+    }
+
+    pub fn first_word_backup(&self) -> u32 {
+        self.first_word_backup
+    }
+
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+}
@@ -31,8 +31,8 @@ use crate::{
     types::{
         TAG_ARRAY_I, TAG_ARRAY_M, TAG_ARRAY_S, TAG_ARRAY_SLICE_MIN, TAG_ARRAY_T, TAG_BIGINT,
         TAG_BITS64_F, TAG_BITS64_S, TAG_BITS64_U, TAG_BLOB_A, TAG_BLOB_B, TAG_BLOB_P, TAG_BLOB_T,
-        TAG_CONCAT, TAG_MUTBOX, TAG_OBJECT, TAG_REGION, TAG_SOME, TAG_VARIANT, TAG_WEAK_REF,
-        TRUE_VALUE, Tag, Value, base_array_tag, size_of,
+        TAG_CONCAT, TAG_COUNTED_CONCAT, TAG_MUTBOX, TAG_OBJECT, TAG_REGION, TAG_SOME, TAG_VARIANT,
+        TAG_WEAK_REF, TRUE_VALUE, Tag, Value, base_array_tag, size_of,
     },
 };
 
@@ -99,6 +99,15 @@ impl StableObjectKind {
 
 impl StableTag {
     pub fn decode(&self) -> StableObjectKind {
+        match self.try_decode() {
+            Some(kind) => kind,
+            None => rts_trap_with("Invalid tag"),
+        }
+    }
+
+    /// Returns `None` for an invalid tag instead of trapping.
+    /// Used for inspecting potentially corrupted stable memory.
+    pub fn try_decode(&self) -> Option<StableObjectKind> {
         const STABLE_TAG_ARRAY_IMMUTABLE: u64 = StableObjectKind::ArrayImmutable as u64;
         const STABLE_TAG_ARRAY_MUTABLE: u64 = StableObjectKind::ArrayMutable as u64;
         const STABLE_TAG_ARRAY_TUPLE: u64 = StableObjectKind::ArrayTuple as u64;
@@ -119,28 +128,36 @@ impl StableTag {
         const STABLE_TAG_SOME: u64 = StableObjectKind::Some as u64;
         const STABLE_TAG_WEAK_REF: u64 = StableObjectKind::WeakRef as u64;
         match self.0 {
-            STABLE_TAG_ARRAY_IMMUTABLE => StableObjectKind::ArrayImmutable,
-            STABLE_TAG_ARRAY_MUTABLE => StableObjectKind::ArrayMutable,
-            STABLE_TAG_ARRAY_TUPLE => StableObjectKind::ArrayTuple,
-            STABLE_TAG_ARRAY_SHARED_FUNCTION => StableObjectKind::ArraySharedFunction,
-            STABLE_TAG_MUTBOX => StableObjectKind::MutBox,
-            STABLE_TAG_OBJECT => StableObjectKind::Object,
-            STABLE_TAG_BLOB_BYTES => StableObjectKind::BlobBytes,
-            STABLE_TAG_BLOB_TEXT => StableObjectKind::BlobText,
-            STABLE_TAG_BLOB_PRINCIPAL => StableObjectKind::BlobPrincipal,
-            STABLE_TAG_BLOB_ACTOR => StableObjectKind::BlobActor,
-            STABLE_TAG_BITS64_UNSIGNED => StableObjectKind::Bits64Unsigned,
-            STABLE_TAG_BITS64_SIGNED => StableObjectKind::Bits64Signed,
-            STABLE_TAG_BITS64_FLOAT => StableObjectKind::Bits64Float,
-            STABLE_TAG_REGION => StableObjectKind::Region,
-            STABLE_TAG_VARIANT => StableObjectKind::Variant,
-            STABLE_TAG_CONCAT => StableObjectKind::Concat,
-            STABLE_TAG_BIGINT => StableObjectKind::BigInt,
-            STABLE_TAG_SOME => StableObjectKind::Some,
-            STABLE_TAG_WEAK_REF => StableObjectKind::WeakRef,
-            _ => rts_trap_with("Invalid tag"),
+            STABLE_TAG_ARRAY_IMMUTABLE => Some(StableObjectKind::ArrayImmutable),
+            STABLE_TAG_ARRAY_MUTABLE => Some(StableObjectKind::ArrayMutable),
+            STABLE_TAG_ARRAY_TUPLE => Some(StableObjectKind::ArrayTuple),
+            STABLE_TAG_ARRAY_SHARED_FUNCTION => Some(StableObjectKind::ArraySharedFunction),
+            STABLE_TAG_MUTBOX => Some(StableObjectKind::MutBox),
+            STABLE_TAG_OBJECT => Some(StableObjectKind::Object),
+            STABLE_TAG_BLOB_BYTES => Some(StableObjectKind::BlobBytes),
+            STABLE_TAG_BLOB_TEXT => Some(StableObjectKind::BlobText),
+            STABLE_TAG_BLOB_PRINCIPAL => Some(StableObjectKind::BlobPrincipal),
+            STABLE_TAG_BLOB_ACTOR => Some(StableObjectKind::BlobActor),
+            STABLE_TAG_BITS64_UNSIGNED => Some(StableObjectKind::Bits64Unsigned),
+            STABLE_TAG_BITS64_SIGNED => Some(StableObjectKind::Bits64Signed),
+            STABLE_TAG_BITS64_FLOAT => Some(StableObjectKind::Bits64Float),
+            STABLE_TAG_REGION => Some(StableObjectKind::Region),
+            STABLE_TAG_VARIANT => Some(StableObjectKind::Variant),
+            STABLE_TAG_CONCAT => Some(StableObjectKind::Concat),
+            STABLE_TAG_BIGINT => Some(StableObjectKind::BigInt),
+            STABLE_TAG_SOME => Some(StableObjectKind::Some),
+            STABLE_TAG_WEAK_REF => Some(StableObjectKind::WeakRef),
+            _ => None,
         }
     }
+
+    pub const fn from_raw(tag: u64) -> Self {
+        StableTag(tag)
+    }
+
+    pub fn get_raw(&self) -> u64 {
+        self.0
+    }
 }
 
 impl StableObjectKind {
@@ -169,7 +186,8 @@ impl StableObjectKind {
             TAG_BITS64_F => StableObjectKind::Bits64Float,
             TAG_REGION => StableObjectKind::Region,
             TAG_VARIANT => StableObjectKind::Variant,
-            TAG_CONCAT => StableObjectKind::Concat,
+            // The character count is not serialized, deserialized concat nodes are uncounted.
+            TAG_CONCAT | TAG_COUNTED_CONCAT => StableObjectKind::Concat,
             TAG_BIGINT => StableObjectKind::BigInt,
             TAG_SOME => StableObjectKind::Some,
 
@@ -183,7 +201,7 @@ impl StableObjectKind {
 pub struct StableValue(u64);
 
 impl StableValue {
-    fn is_ptr(&self) -> bool {
+    pub fn is_ptr(&self) -> bool {
         self.0 & 0b1 == 1 && self.0 != TRUE_VALUE as u64
     }
 
@@ -200,6 +218,10 @@ impl StableValue {
         StableValue(value)
     }
 
+    pub fn get_raw(&self) -> u64 {
+        self.0
+    }
+
     pub fn from_stable_address(address: u64) -> Self {
         debug_assert_eq!(address % WORD_SIZE as u64, 0);
         StableValue(Self::skew(address))
@@ -456,3 +478,166 @@ pub unsafe fn deserialize<M: Memory>(
         }
     }
 }
+
+/// Maximum number of values in the static part of a stable object (see `StableConcat`).
+const MAX_STATIC_VALUES: usize = 2;
+
+/// Dynamically sized body following the static part of a stable object.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum StableBody {
+    Empty,
+    /// Sequence of `StableValue`, e.g. array elements or object fields.
+    Values(u64),
+    /// Raw bytes, padded to 64-bit alignment in the stable format.
+    Bytes(u64),
+}
+
+/// Scalar information from the static part of a stable object.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum StableSummary {
+    None,
+    Bits64(u64),
+    BigInt {
+        is_negative: bool,
+        number_of_bits: u64,
+    },
+    Concat {
+        number_of_bytes: u64,
+    },
+    Region {
+        id: u64,
+        page_count: u64,
+    },
+    Variant {
+        tag: u64,
+    },
+}
+
+/// Read-only description of a serialized object, used for offline inspection
+/// of a stabilization snapshot.
+#[derive(Clone, Copy)]
+pub struct StableObjectInfo {
+    /// Offset of the object tag relative to the start of the serialized data.
+    pub address: u64,
+    pub kind: StableObjectKind,
+    pub summary: StableSummary,
+    pub body: StableBody,
+    static_size: u64,
+    static_values: [StableValue; MAX_STATIC_VALUES],
+    static_value_count: usize,
+}
+
+impl StableObjectInfo {
+    /// Values stored in the static part of the object, e.g. the hash blob of an object
+    /// or the two texts of a concatenation.
+    pub fn static_values(&self) -> &[StableValue] {
+        &self.static_values[..self.static_value_count]
+    }
+
+    /// Offset of the dynamic body relative to the start of the serialized data.
+    pub fn body_address(&self) -> u64 {
+        self.address + size_of::<StableTag>().to_bytes().as_usize() as u64 + self.static_size
+    }
+
+    /// Total size of the object in the stable format, including tag and padding.
+    /// Returns `None` if the length recorded in a corrupted object overflows the size.
+    pub fn size(&self) -> Option<u64> {
+        let body_size = match self.body {
+            StableBody::Empty => 0,
+            StableBody::Values(count) => {
+                count.checked_mul(size_of::<StableValue>().to_bytes().as_usize() as u64)?
+            }
+            StableBody::Bytes(length) => {
+                length.checked_next_multiple_of(size_of::<u64>().to_bytes().as_usize() as u64)?
+            }
+        };
+        (self.body_address() - self.address).checked_add(body_size)
+    }
+}
+
+trait Inspector
+where
+    Self: Sized + StaticScanner<StableValue>,
+{
+    fn dynamic_body(&self) -> StableBody {
+        StableBody::Empty
+    }
+
+    fn summary(&self) -> StableSummary {
+        StableSummary::None
+    }
+
+    fn inspect(
+        stable_memory: &StableMemoryAccess,
+        stable_object: StableValue,
+        object_kind: StableObjectKind,
+    ) -> StableObjectInfo {
+        let mut static_part = stable_memory.read::<Self>(stable_object.payload_address());
+        let mut info = StableObjectInfo {
+            address: stable_object.to_stable_address(),
+            kind: object_kind,
+            summary: static_part.summary(),
+            body: static_part.dynamic_body(),
+            static_size: size_of::<Self>().to_bytes().as_usize() as u64,
+            static_values: [StableValue::default(); MAX_STATIC_VALUES],
+            static_value_count: 0,
+        };
+        // Only record the static values, without modifying them.
+        static_part.update_pointers(&mut info, &|info, value| {
+            info.static_values[info.static_value_count] = value;
+            info.static_value_count += 1;
+            value
+        });
+        info
+    }
+}
+
+/// Describe the serialized object at `stable_object` without deserializing it.
+/// The object tag is decoded by the caller.
+pub fn inspect(
+    stable_memory: &StableMemoryAccess,
+    stable_object: StableValue,
+    object_kind: StableObjectKind,
+) -> StableObjectInfo {
+    match object_kind {
+        StableObjectKind::ArrayImmutable
+        | StableObjectKind::ArrayMutable
+        | StableObjectKind::ArrayTuple
+        | StableObjectKind::ArraySharedFunction => {
+            StableArray::inspect(stable_memory, stable_object, object_kind)
+        }
+        StableObjectKind::MutBox => {
+            StableMutBox::inspect(stable_memory, stable_object, object_kind)
+        }
+        StableObjectKind::Object => {
+            StableObject::inspect(stable_memory, stable_object, object_kind)
+        }
+        StableObjectKind::BlobBytes
+        | StableObjectKind::BlobText
+        | StableObjectKind::BlobPrincipal
+        | StableObjectKind::BlobActor => {
+            StableBlob::inspect(stable_memory, stable_object, object_kind)
+        }
+        StableObjectKind::Bits64Unsigned
+        | StableObjectKind::Bits64Signed
+        | StableObjectKind::Bits64Float => {
+            StableBits64::inspect(stable_memory, stable_object, object_kind)
+        }
+        StableObjectKind::Region => {
+            StableRegion::inspect(stable_memory, stable_object, object_kind)
+        }
+        StableObjectKind::Variant => {
+            StableVariant::inspect(stable_memory, stable_object, object_kind)
+        }
+        StableObjectKind::Concat => {
+            StableConcat::inspect(stable_memory, stable_object, object_kind)
+        }
+        StableObjectKind::BigInt => {
+            StableBigInt::inspect(stable_memory, stable_object, object_kind)
+        }
+        StableObjectKind::Some => StableSome::inspect(stable_memory, stable_object, object_kind),
+        StableObjectKind::WeakRef => {
+            StableWeakRef::inspect(stable_memory, stable_object, object_kind)
+        }
+    }
+}
@@ -11,7 +11,9 @@ use crate::{
     types::{Array, TAG_ARRAY_I, TAG_ARRAY_M, TAG_ARRAY_S, TAG_ARRAY_T, Tag, Value, size_of},
 };
 
-use super::{Serializer, StableObjectKind, StableToSpace, StableValue, StaticScanner};
+use super::{
+    Inspector, Serializer, StableBody, StableObjectKind, StableToSpace, StableValue, StaticScanner,
+};
 
 #[repr(C)]
 pub struct StableArray {
@@ -21,6 +23,12 @@ pub struct StableArray {
 
 impl StaticScanner<StableValue> for StableArray {}
 
+impl Inspector for StableArray {
+    fn dynamic_body(&self) -> StableBody {
+        StableBody::Values(self.array_length)
+    }
+}
+
 impl Serializer<Array> for StableArray {
     unsafe fn serialize_static_part(
         _stable_memory: &mut StableMemoryStream,
@@ -9,7 +9,8 @@ use crate::tommath_bindings::mp_digit;
 use crate::types::{BigInt, Bytes, TAG_BIGINT, Value, size_of};
 
 use super::{
-    Serializer, StableObjectKind, StableToSpace, StableValue, StaticScanner, round_to_u64,
+    Inspector, Serializer, StableBody, StableObjectKind, StableSummary, StableToSpace, StableValue,
+    StaticScanner, round_to_u64,
 };
 
 // Tom's math library, as configured for Motoko RTS with 64-bit enhanced orthogonal persistence,
@@ -77,6 +78,19 @@ fn ceiling_div(dividend: u64, divisor: u64) -> u64 {
 
 impl StaticScanner<StableValue> for StableBigInt {}
 
+impl Inspector for StableBigInt {
+    fn dynamic_body(&self) -> StableBody {
+        StableBody::Bytes(self.number_of_bits.to_bytes().as_usize() as u64)
+    }
+
+    fn summary(&self) -> StableSummary {
+        StableSummary::BigInt {
+            is_negative: self.is_negative,
+            number_of_bits: self.number_of_bits.0,
+        }
+    }
+}
+
 impl Serializer<BigInt> for StableBigInt {
     unsafe fn serialize_static_part(
         _stable_memory: &mut StableMemoryStream,
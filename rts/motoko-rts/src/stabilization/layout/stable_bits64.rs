@@ -3,7 +3,7 @@ use crate::{
     types::{Bits64, TAG_BITS64_F, TAG_BITS64_S, TAG_BITS64_U, Tag, Value},
 };
 
-use super::{Serializer, StableObjectKind, StableValue, StaticScanner};
+use super::{Inspector, Serializer, StableObjectKind, StableSummary, StableValue, StaticScanner};
 
 #[repr(C)]
 pub struct StableBits64 {
@@ -12,6 +12,12 @@ pub struct StableBits64 {
 
 impl StaticScanner<StableValue> for StableBits64 {}
 
+impl Inspector for StableBits64 {
+    fn summary(&self) -> StableSummary {
+        StableSummary::Bits64(self.bits)
+    }
+}
+
 impl Serializer<Bits64> for StableBits64 {
     unsafe fn serialize_static_part(
         _stable_memory: &mut StableMemoryStream,
@@ -11,8 +11,8 @@ use crate::{
 };
 
 use super::{
-    Serializer, StableObjectKind, StableToSpace, StableValue, StaticScanner, round_to_u64,
-    write_padding_u64,
+    Inspector, Serializer, StableBody, StableObjectKind, StableToSpace, StableValue, StaticScanner,
+    round_to_u64, write_padding_u64,
 };
 
 #[repr(C)]
@@ -32,6 +32,12 @@ impl StableBlob {
 
 impl StaticScanner<StableValue> for StableBlob {}
 
+impl Inspector for StableBlob {
+    fn dynamic_body(&self) -> StableBody {
+        StableBody::Bytes(self.byte_length)
+    }
+}
+
 impl Serializer<Blob> for StableBlob {
     unsafe fn serialize_static_part(
         _stable_memory: &mut StableMemoryStream,
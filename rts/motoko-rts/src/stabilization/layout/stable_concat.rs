@@ -3,7 +3,7 @@ use crate::{
     types::{Bytes, Concat, TAG_CONCAT, Value},
 };
 
-use super::{Serializer, StableObjectKind, StableValue, StaticScanner};
+use super::{Inspector, Serializer, StableObjectKind, StableSummary, StableValue, StaticScanner};
 
 #[repr(C)]
 pub struct StableConcat {
@@ -24,6 +24,14 @@ impl StaticScanner<StableValue> for StableConcat {
     }
 }
 
+impl Inspector for StableConcat {
+    fn summary(&self) -> StableSummary {
+        StableSummary::Concat {
+            number_of_bytes: self.number_of_bytes,
+        }
+    }
+}
+
 impl Serializer<Concat> for StableConcat {
     unsafe fn serialize_static_part(
         _stable_memory: &mut StableMemoryStream,
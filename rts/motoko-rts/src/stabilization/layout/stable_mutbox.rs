@@ -3,7 +3,7 @@ use crate::{
     types::{MutBox, TAG_MUTBOX, Value},
 };
 
-use super::{Serializer, StableObjectKind, StableValue, StaticScanner};
+use super::{Inspector, Serializer, StableObjectKind, StableValue, StaticScanner};
 
 #[repr(C)]
 pub struct StableMutBox {
@@ -21,6 +21,8 @@ impl StaticScanner<StableValue> for StableMutBox {
     }
 }
 
+impl Inspector for StableMutBox {}
+
 impl Serializer<MutBox> for StableMutBox {
     unsafe fn serialize_static_part(
         _stable_memory: &mut StableMemoryStream,
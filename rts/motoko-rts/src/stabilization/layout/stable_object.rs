@@ -11,7 +11,9 @@ use crate::{
     types::{FwdPtr, Object, TAG_FWD_PTR, TAG_OBJECT, Tag, Value, Words, size_of},
 };
 
-use super::{Serializer, StableTag, StableToSpace, StableValue, StaticScanner};
+use super::{
+    Inspector, Serializer, StableBody, StableTag, StableToSpace, StableValue, StaticScanner,
+};
 
 #[repr(C)]
 pub struct StableObject {
@@ -31,6 +33,12 @@ impl StaticScanner<StableValue> for StableObject {
     }
 }
 
+impl Inspector for StableObject {
+    fn dynamic_body(&self) -> StableBody {
+        StableBody::Values(self.size)
+    }
+}
+
 impl Serializer<Object> for StableObject {
     unsafe fn serialize_static_part(
         stable_memory: &mut StableMemoryStream,
@@ -3,7 +3,7 @@ use crate::{
     types::{Region, TAG_REGION, Value},
 };
 
-use super::{Serializer, StableObjectKind, StableValue, StaticScanner};
+use super::{Inspector, Serializer, StableObjectKind, StableSummary, StableValue, StaticScanner};
 
 #[repr(C)]
 pub struct StableRegion {
@@ -23,6 +23,15 @@ impl StaticScanner<StableValue> for StableRegion {
     }
 }
 
+impl Inspector for StableRegion {
+    fn summary(&self) -> StableSummary {
+        StableSummary::Region {
+            id: self.id,
+            page_count: self.page_count,
+        }
+    }
+}
+
 impl Serializer<Region> for StableRegion {
     unsafe fn serialize_static_part(
         _stable_memory: &mut StableMemoryStream,
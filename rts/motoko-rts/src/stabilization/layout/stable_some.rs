@@ -3,7 +3,7 @@ use crate::{
     types::{Some, TAG_SOME, Value},
 };
 
-use super::{Serializer, StableObjectKind, StableValue, StaticScanner};
+use super::{Inspector, Serializer, StableObjectKind, StableValue, StaticScanner};
 
 #[repr(C)]
 pub struct StableSome {
@@ -21,6 +21,8 @@ impl StaticScanner<StableValue> for StableSome {
     }
 }
 
+impl Inspector for StableSome {}
+
 impl Serializer<Some> for StableSome {
     unsafe fn serialize_static_part(
         _stable_memory: &mut StableMemoryStream,
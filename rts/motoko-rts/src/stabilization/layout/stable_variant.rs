@@ -3,7 +3,7 @@ use crate::{
     types::{TAG_VARIANT, Value, Variant},
 };
 
-use super::{Serializer, StableObjectKind, StableValue, StaticScanner};
+use super::{Inspector, Serializer, StableObjectKind, StableSummary, StableValue, StaticScanner};
 
 #[repr(C)]
 pub struct StableVariant {
@@ -22,6 +22,12 @@ impl StaticScanner<StableValue> for StableVariant {
     }
 }
 
+impl Inspector for StableVariant {
+    fn summary(&self) -> StableSummary {
+        StableSummary::Variant { tag: self.tag }
+    }
+}
+
 impl Serializer<Variant> for StableVariant {
     unsafe fn serialize_static_part(
         _stable_memory: &mut StableMemoryStream,
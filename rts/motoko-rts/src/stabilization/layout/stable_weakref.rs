@@ -3,7 +3,7 @@ use crate::{
     types::{TAG_WEAK_REF, Value},
 };
 
-use super::{Serializer, StableObjectKind, StableValue, StaticScanner};
+use super::{Inspector, Serializer, StableObjectKind, StableValue, StaticScanner};
 use crate::types::WeakRef;
 
 #[repr(C)]
@@ -22,6 +22,8 @@ impl StaticScanner<StableValue> for StableWeakRef {
     }
 }
 
+impl Inspector for StableWeakRef {}
+
 impl Serializer<WeakRef> for StableWeakRef {
     unsafe fn serialize_static_part(
         _stable_memory: &mut StableMemoryStream,
@@ -53,6 +53,7 @@ const _: () = assert!(size_of::<MutBox>() == HEADER_SIZE + 1 * WORD_SIZE);
 const _: () = assert!(size_of::<Some>() == HEADER_SIZE + 1 * WORD_SIZE);
 const _: () = assert!(size_of::<Variant>() == HEADER_SIZE + 2 * WORD_SIZE);
 const _: () = assert!(size_of::<Concat>() == HEADER_SIZE + 3 * WORD_SIZE);
+const _: () = assert!(size_of::<CountedConcat>() == HEADER_SIZE + 4 * WORD_SIZE);
 
 const _: () = assert!(
     size_of::<Bits64>()
@@ -79,6 +80,7 @@ const _: () = assert!(align_of::<MutBox>() == WORD_SIZE);
 const _: () = assert!(align_of::<Some>() == WORD_SIZE);
 const _: () = assert!(align_of::<Variant>() == WORD_SIZE);
 const _: () = assert!(align_of::<Concat>() == WORD_SIZE);
+const _: () = assert!(align_of::<CountedConcat>() == WORD_SIZE);
 const _: () = assert!(align_of::<Bits64>() == WORD_SIZE);
 const _: () = assert!(align_of::<OneWordFiller>() == WORD_SIZE);
 const _: () = assert!(align_of::<FreeSpace>() == WORD_SIZE);
@@ -12,17 +12,18 @@
 //!
 //! In a subsequent step, the actual concatenation node has been introduced.
 //!
-//! From here on, there are stretch goals like:
-//!  - restructure recursive code to not use unbounded Rust stack
-//!  - maybe rebalancing
+//! Concatenation keeps ropes built by appending or prepending in a loop balanced, see
+//! "Balancing" below, and all traversals of ropes are iterative, so that the Rust stack stays
+//! bounded for ropes of any shape.
 
 // Layout of a concat node:
 //
-// ┌────────────┬─────────┬───────┬───────┐
-// │ obj header │ n_bytes │ text1 │ text2 │
-// └────────────┴─────────┴───────┴───────┘
+// ┌────────────┬─────────┬───────┬───────┬─────────┐
+// │ obj header │ n_bytes │ text1 │ text2 │ n_chars │
+// └────────────┴─────────┴───────┴───────┴─────────┘
 //
-// The object header includes tag (`TAG_CONCAT`) and forwarding pointer.
+// The object header includes tag (`TAG_COUNTED_CONCAT`) and forwarding pointer. Concat nodes
+// with `TAG_CONCAT` lack `n_chars`, see "Character positions" below.
 // Note that `CONCAT_LEN` and `BLOB_LEN` are identical, so no need to check the tag to know the
 // size of the text.
 
@@ -30,7 +31,11 @@ use crate::barriers::allocation_barrier;
 use crate::mem_utils::memcpy_bytes;
 use crate::memory::{Memory, alloc_blob};
 use crate::rts_trap_with;
-use crate::types::{Blob, Bytes, Concat, TAG_BLOB_T, TAG_CONCAT, Value, size_of};
+use crate::types::{
+    Blob, Bytes, CountedConcat, TAG_BLOB_T, TAG_COUNTED_CONCAT, UNKNOWN_CHAR_COUNT, Value,
+    is_concat_tag, size_of,
+};
+use crate::unicode::{GraphemeBoundaries, NormalizationForm, case_fold, normalize};
 
 use alloc::string::String;
 use core::cmp::{Ordering, min};
@@ -70,6 +75,30 @@ pub unsafe fn text_of_str<M: Memory>(mem: &mut M, s: &str) -> Value {
     text_of_ptr_size(mem, s.as_ptr(), Bytes(s.len()))
 }
 
+// Balancing
+//
+// Appending to a text in a loop would produce a left-deep rope, with a depth linear in the number
+// of appends. Instead, `text_concat` treats the right parts along the left spine of a rope like
+// the digits of a binary counter: A text appended to a much larger one is first concatenated with
+// the right part of the larger one if both are of comparable size, and the result again with the
+// next right part down the spine, and so on. The right parts along the left spine therefore grow
+// geometrically, which keeps the depth logarithmic in the size of the text, at an amortized
+// constant number of allocations per concatenation. Prepending is balanced in the same way along
+// the right spine.
+//
+// Short blobs are merged when concatenated, which bounds the number of nodes of texts built from
+// short pieces.
+//
+// Texts that grow at both ends, or ropes that were built before balancing was introduced, are not
+// rebalanced, but their traversal is still iterative.
+
+/// A text is much larger than another text if it is more than this many times the size of the
+/// other text.
+const BALANCE_RATIO: usize = 3;
+
+/// Blobs are merged when their concatenation is at most this size.
+const MAX_MERGED_BLOB_SIZE: Bytes<usize> = Bytes(64);
+
 #[ic_mem_fn]
 pub unsafe fn text_concat<M: Memory>(mem: &mut M, s1: Value, s2: Value) -> Value {
     let blob1_len = text_size(s1);
@@ -83,12 +112,73 @@ pub unsafe fn text_concat<M: Memory>(mem: &mut M, s1: Value, s2: Value) -> Value
         return s1;
     }
 
+    // Check max size
+    if blob1_len + blob2_len > MAX_STR_SIZE {
+        rts_trap_with("text_concat: Text too large");
+    }
+
+    if is_much_larger(s1, s2) {
+        text_append(mem, s1, s2)
+    } else if is_much_larger(s2, s1) {
+        text_prepend(mem, s1, s2)
+    } else {
+        concat_node(mem, s1, s2)
+    }
+}
+
+/// Whether `s1` is more than `BALANCE_RATIO` times the size of `s2`.
+unsafe fn is_much_larger(s1: Value, s2: Value) -> bool {
+    // Dividing rather than multiplying cannot overflow
+    text_size(s1).as_usize() / BALANCE_RATIO > text_size(s2).as_usize()
+}
+
+/// Whether neither text is much larger than the other.
+unsafe fn is_comparable(s1: Value, s2: Value) -> bool {
+    !is_much_larger(s1, s2) && !is_much_larger(s2, s1)
+}
+
+/// Appends `s2` to a much larger `s1`, carrying the right parts of comparable size along the left
+/// spine of `s1` over to `s2`.
+unsafe fn text_append<M: Memory>(mem: &mut M, mut s1: Value, mut s2: Value) -> Value {
+    while is_concat_tag(s1.tag()) {
+        let concat = s1.as_concat();
+        let right = concat.text2();
+        if !is_comparable(right, s2) {
+            break;
+        }
+        s2 = concat_node(mem, right, s2);
+        s1 = concat.text1();
+    }
+    concat_node(mem, s1, s2)
+}
+
+/// Prepends `s1` to a much larger `s2`, carrying the left parts of comparable size along the right
+/// spine of `s2` over to `s1`.
+unsafe fn text_prepend<M: Memory>(mem: &mut M, mut s1: Value, mut s2: Value) -> Value {
+    while is_concat_tag(s2.tag()) {
+        let concat = s2.as_concat();
+        let left = concat.text1();
+        if !is_comparable(left, s1) {
+            break;
+        }
+        s1 = concat_node(mem, s1, left);
+        s2 = concat.text2();
+    }
+    concat_node(mem, s1, s2)
+}
+
+/// Concatenates two non-empty texts without rebalancing. Short blobs are copied into a single
+/// blob, otherwise a concat node is created.
+unsafe fn concat_node<M: Memory>(mem: &mut M, s1: Value, s2: Value) -> Value {
+    let blob1_len = text_size(s1);
+    let blob2_len = text_size(s2);
     let new_len = blob1_len + blob2_len;
 
-    // Short texts are copied into a single blob
-    if new_len < MIN_CONCAT_SIZE {
-        // Because lengths of texts are smaller than MIN_CONCAT_SIZE we know both are blobs so the
-        // casts are safe
+    // Short texts are copied into a single blob. Texts smaller than MIN_CONCAT_SIZE are always
+    // blobs, so the casts are safe.
+    if new_len < MIN_CONCAT_SIZE
+        || (new_len <= MAX_MERGED_BLOB_SIZE && s1.tag() == TAG_BLOB_T && s2.tag() == TAG_BLOB_T)
+    {
         let blob1 = s1.as_blob();
         let blob2 = s2.as_blob();
 
@@ -107,22 +197,43 @@ pub unsafe fn text_concat<M: Memory>(mem: &mut M, s1: Value, s2: Value) -> Value
         return allocation_barrier(r);
     }
 
-    // Check max size
-    if new_len > MAX_STR_SIZE {
-        rts_trap_with("text_concat: Text too large");
-    }
+    let n_chars = match (cheap_len(s1), cheap_len(s2)) {
+        (Some(n_chars1), Some(n_chars2)) => n_chars1 + n_chars2,
+        _ => UNKNOWN_CHAR_COUNT,
+    };
 
     // Create concat node
-    let r = mem.alloc_words(size_of::<Concat>());
-    let r_concat = r.get_ptr() as *mut Concat;
-    (*r_concat).header.tag = TAG_CONCAT;
+    let r = mem.alloc_words(size_of::<CountedConcat>());
+    let r_concat = r.get_ptr() as *mut CountedConcat;
+    (*r_concat).header.tag = TAG_COUNTED_CONCAT;
     (*r_concat).header.init_forward(r);
     (*r_concat).n_bytes = new_len;
     (*r_concat).text1 = s1.forward_if_possible();
     (*r_concat).text2 = s2.forward_if_possible();
+    (*r_concat).n_chars = n_chars;
     allocation_barrier(r)
 }
 
+/// Blobs up to this size are counted when creating a concat node.
+const MAX_COUNTED_BLOB_SIZE: Bytes<usize> = Bytes(1024);
+
+/// Number of characters of a text if it is known or can be counted in bounded time.
+unsafe fn cheap_len(s: Value) -> Option<usize> {
+    if s.tag() == TAG_BLOB_T {
+        let blob = s.as_blob();
+        if blob.len() <= MAX_COUNTED_BLOB_SIZE {
+            Some(count_chars(slice::from_raw_parts(
+                blob.payload_const(),
+                blob.len().as_usize(),
+            )))
+        } else {
+            None
+        }
+    } else {
+        s.as_concat().n_chars()
+    }
+}
+
 // Leaving breadcrumbs in the destination buffer for which concat node/blob to continue
 // serializing
 #[repr(packed)]
@@ -176,19 +287,10 @@ unsafe extern "C" fn text_to_buf(mut s: Value, mut buf: *mut u8) {
 
 #[unsafe(no_mangle)]
 #[classical_persistence]
-unsafe extern "C" fn stream_write_text(stream: *mut Stream, mut s: Value) {
-    use crate::types::TAG_BLOB_B;
-    loop {
-        let s_ptr = s.as_obj();
-        if s_ptr.tag() == TAG_BLOB_B || s_ptr.tag() == TAG_BLOB_T {
-            let blob = s_ptr.as_blob();
-            stream.cache_bytes(blob.payload_addr(), blob.len());
-            break;
-        } else {
-            let concat = s_ptr.as_concat();
-            stream_write_text(stream, concat.text1());
-            s = concat.text2()
-        }
+unsafe extern "C" fn stream_write_text(stream: *mut Stream, s: Value) {
+    let mut cursor = TextCursor::new(s);
+    while let Some(chunk) = cursor.next_chunk() {
+        stream.cache_bytes(chunk.as_ptr(), Bytes(chunk.len()));
     }
 }
 
@@ -216,110 +318,123 @@ pub unsafe extern "C" fn text_size(s: Value) -> Bytes<usize> {
     (s.forward().get_ptr() as *mut Blob).len()
 }
 
-/// Compares texts from given offset on for the given number of bytes. All assumed to be in range.
-unsafe fn text_compare_range(
-    s1: Value,
-    offset1: Bytes<usize>,
-    s2: Value,
-    offset2: Bytes<usize>,
-    n: Bytes<usize>,
-) -> Ordering {
-    // Follow the left/right strings of concat nodes until we reach to blobs or concats that cannot
-    // be split further (the range spans left and right strings)
-    let (s1, offset1) = text_get_range(s1, offset1, n);
-    let (s2, offset2) = text_get_range(s2, offset2, n);
-
-    let s1_obj = s1.as_obj();
-    let s2_obj = s2.as_obj();
-
-    // Decompose concats
-    if s1_obj.tag() == TAG_CONCAT {
-        let s1_concat = s1_obj.as_concat();
-        let n_compared = text_size(s1_concat.text1()) - offset1;
-        let cmp = text_compare_range(s1_concat.text1(), offset1, s2, offset2, n_compared);
-        match cmp {
-            Ordering::Less | Ordering::Greater => cmp,
-            Ordering::Equal => text_compare_range(
-                s1_concat.text2(),
-                Bytes(0),
-                s2,
-                offset2 + n_compared,
-                n - n_compared,
-            ),
-        }
-    } else if s2_obj.tag() == TAG_CONCAT {
-        let s2_concat = s2_obj.as_concat();
-        let n_compared = text_size(s2_concat.text1()) - offset2;
-        let cmp = text_compare_range(s1, offset1, s2_concat.text1(), offset2, n_compared);
-        match cmp {
-            Ordering::Less | Ordering::Greater => cmp,
-            Ordering::Equal => text_compare_range(
-                s1,
-                offset1 + n_compared,
-                s2_concat.text2(),
-                Bytes(0),
-                n - n_compared,
-            ),
-        }
-    } else {
-        debug_assert_eq!(s1_obj.tag(), TAG_BLOB_T);
-        debug_assert_eq!(s2_obj.tag(), TAG_BLOB_T);
+/// Capacity of the stack of a `TextCursor`, sufficient for balanced ropes of any size.
+const CURSOR_STACK_SIZE: usize = 2 * usize::BITS as usize;
+
+/// Visits the blobs of a text in order, without recursion. The cursor remembers the right parts
+/// of the concat nodes on the path to the current blob, keeping the innermost
+/// `CURSOR_STACK_SIZE` of them. If the stack runs empty before the end of a deeper rope, the next
+/// blob is located from the root again.
+struct TextCursor {
+    text: Value,
+    /// Offset of the next blob in the text
+    offset: Bytes<usize>,
+    /// Ring buffer of right parts, the last one is visited next
+    stack: [Value; CURSOR_STACK_SIZE],
+    stack_start: usize,
+    stack_len: usize,
+}
 
-        let s1_blob = s1_obj.as_blob();
-        let s2_blob = s2_obj.as_blob();
+impl TextCursor {
+    unsafe fn new(text: Value) -> TextCursor {
+        TextCursor::at(text, Bytes(0))
+    }
 
-        let cmp = memcmp(
-            s1_blob.payload_addr().add(offset1.as_usize()) as *const _,
-            s2_blob.payload_addr().add(offset2.as_usize()) as *const _,
-            n.as_usize(),
-        );
+    /// Cursor starting at the given byte offset, which must be in range.
+    unsafe fn at(text: Value, offset: Bytes<usize>) -> TextCursor {
+        TextCursor {
+            text,
+            offset,
+            stack: [Value::from_scalar(0); CURSOR_STACK_SIZE],
+            stack_start: 0,
+            stack_len: 0,
+        }
+    }
 
-        if cmp < 0 {
-            Ordering::Less
-        } else if cmp == 0 {
-            Ordering::Equal
+    fn push(&mut self, text: Value) {
+        let index = (self.stack_start + self.stack_len) % CURSOR_STACK_SIZE;
+        self.stack[index] = text;
+        if self.stack_len == CURSOR_STACK_SIZE {
+            // Drop the outermost part, it is located from the root again when needed
+            self.stack_start = (self.stack_start + 1) % CURSOR_STACK_SIZE;
         } else {
-            Ordering::Greater
+            self.stack_len += 1;
         }
     }
-}
 
-/// Follow left/right strings of concat nodes until we reach to a BLOB or a CONCAT that can't be
-/// split further (i.e. range spans left and right nodes). Returns a BLOB or CONCAT.
-unsafe fn text_get_range(
-    mut s: Value,
-    mut offset: Bytes<usize>,
-    n: Bytes<usize>,
-) -> (Value, Bytes<usize>) {
-    loop {
-        let s_obj = s.as_obj();
+    fn pop(&mut self) -> Option<Value> {
+        if self.stack_len == 0 {
+            return None;
+        }
+        self.stack_len -= 1;
+        Some(self.stack[(self.stack_start + self.stack_len) % CURSOR_STACK_SIZE])
+    }
 
-        if s_obj.tag() == TAG_CONCAT {
-            let s_concat = s_obj.as_concat();
+    /// The payload of the next blob, or its remainder when starting within a blob. `None` at the
+    /// end of the text.
+    unsafe fn next_chunk(&mut self) -> Option<&'static [u8]> {
+        if self.offset >= text_size(self.text) {
+            return None;
+        }
 
-            let left = s_concat.text1();
+        let (mut s, mut offset) = match self.pop() {
+            Some(s) => (s, Bytes(0)),
+            None => (self.text, self.offset),
+        };
+        while is_concat_tag(s.tag()) {
+            let concat = s.as_concat();
+            let left = concat.text1();
             let left_size = text_size(left);
-
-            // Follow left node?
-            if left_size >= offset + n {
+            if offset < left_size {
+                self.push(concat.text2());
                 s = left;
-                continue;
-            }
-
-            // Follow right node?
-            if offset >= left_size {
-                s = s_concat.text2();
+            } else {
                 offset -= left_size;
-                continue;
+                s = concat.text2();
             }
-        } else {
-            debug_assert_eq!(s_obj.tag(), TAG_BLOB_T);
         }
-
-        break;
+        let blob = s.as_blob();
+        let n = blob.len() - offset;
+        self.offset += n;
+        Some(slice::from_raw_parts(
+            blob.payload_const().add(offset.as_usize()),
+            n.as_usize(),
+        ))
     }
+}
+
+/// Compares the first `n` bytes of the texts, blob by blob. `n` is assumed to be in range.
+unsafe fn text_compare_prefix(s1: Value, s2: Value, n: Bytes<usize>) -> Ordering {
+    compare_cursors(&mut TextCursor::new(s1), &mut TextCursor::new(s2), n)
+}
+
+/// Compares the next `n` bytes of two cursors. `n` is assumed to be in range.
+unsafe fn compare_cursors(
+    cursor1: &mut TextCursor,
+    cursor2: &mut TextCursor,
+    n: Bytes<usize>,
+) -> Ordering {
+    let mut chunk1: &[u8] = &[];
+    let mut chunk2: &[u8] = &[];
+    let mut remaining = n.as_usize();
+    while remaining > 0 {
+        if chunk1.is_empty() {
+            chunk1 = cursor1.next_chunk().unwrap();
+        }
+        if chunk2.is_empty() {
+            chunk2 = cursor2.next_chunk().unwrap();
+        }
 
-    (s, offset)
+        let k = min(remaining, min(chunk1.len(), chunk2.len()));
+        match chunk1[..k].cmp(&chunk2[..k]) {
+            Ordering::Equal => {}
+            ordering => return ordering,
+        }
+        chunk1 = &chunk1[k..];
+        chunk2 = &chunk2[k..];
+        remaining -= k;
+    }
+    Ordering::Equal
 }
 
 #[unsafe(no_mangle)]
@@ -328,7 +443,7 @@ pub unsafe extern "C" fn text_compare(s1: Value, s2: Value) -> isize {
     let n2 = text_size(s2);
     let n = min(n1, n2);
 
-    match text_compare_range(s1, Bytes(0), s2, Bytes(0), n) {
+    match text_compare_prefix(s1, s2, n) {
         Ordering::Less => -1,
         Ordering::Greater => 1,
         Ordering::Equal => {
@@ -369,23 +484,317 @@ pub unsafe extern "C" fn blob_compare(s1: Value, s2: Value) -> isize {
 /// Length in characters
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn text_len(text: Value) -> usize {
-    if text.tag() == TAG_BLOB_T {
-        let blob = text.as_blob();
-        let payload_addr = blob.payload_const();
-        let len = blob.len();
-
-        str::from_utf8_unchecked(slice::from_raw_parts(
-            payload_addr as *const u8,
-            len.as_usize(),
-        ))
-        .chars()
-        .count()
-    } else {
-        let concat = text.as_concat();
-        text_len(concat.text1()) + text_len(concat.text2())
+    let len = count_text_chars(text);
+    if is_concat_tag(text.tag()) {
+        // Remember the count if it was unknown
+        text.as_concat().set_n_chars(len);
+    }
+    len
+}
+
+/// Number of characters of a text, using the counts recorded in concat nodes
+unsafe fn count_text_chars(mut text: Value) -> usize {
+    // The order of the blobs does not matter for counting. Continuing with the smaller part of a
+    // concat node and deferring the larger part bounds the deferred parts by the number of bits of
+    // the text size, as the smaller part is at most half of the concat node.
+    let mut deferred = [Value::from_scalar(0); usize::BITS as usize];
+    let mut n_deferred = 0;
+    let mut len = 0;
+    loop {
+        if text.tag() == TAG_BLOB_T {
+            let blob = text.as_blob();
+            len += count_chars(slice::from_raw_parts(
+                blob.payload_const(),
+                blob.len().as_usize(),
+            ));
+        } else {
+            let concat = text.as_concat();
+            if let Some(n_chars) = concat.n_chars() {
+                len += n_chars;
+            } else {
+                let (smaller, larger) = if text_size(concat.text1()) <= text_size(concat.text2()) {
+                    (concat.text1(), concat.text2())
+                } else {
+                    (concat.text2(), concat.text1())
+                };
+                deferred[n_deferred] = larger;
+                n_deferred += 1;
+                text = smaller;
+                continue;
+            }
+        }
+
+        if n_deferred == 0 {
+            return len;
+        }
+        n_deferred -= 1;
+        text = deferred[n_deferred];
+    }
+}
+
+/// Number of characters of UTF-8 encoded bytes
+unsafe fn count_chars(bytes: &[u8]) -> usize {
+    str::from_utf8_unchecked(bytes).chars().count()
+}
+
+/// Length in grapheme clusters, i.e. user-perceived characters
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn text_grapheme_count(text: Value) -> usize {
+    let mut boundaries = GraphemeBoundaries::new();
+    let mut count = 0;
+    let mut cursor = TextCursor::new(text);
+    while let Some(chunk) = cursor.next_chunk() {
+        // Characters never span blobs
+        for c in str::from_utf8_unchecked(chunk).chars() {
+            if boundaries.is_boundary(c) {
+                count += 1;
+            }
+        }
+    }
+    count
+}
+
+// Character positions
+//
+// Concat nodes record the number of characters of their text, so a character position is located
+// by descending from the root, in time logarithmic in the size of a balanced text, plus scanning
+// the blob containing it. The count is determined when creating the concat node if the counts of
+// both parts are known or their blobs are short, see `cheap_len`, and otherwise by `text_len`
+// when first needed, which remembers it in the node. Concatenation therefore remains constant
+// time, while texts built from short pieces are counted throughout.
+//
+// Concat nodes with `TAG_CONCAT` have no count: They persisted with enhanced orthogonal
+// persistence from before the count was introduced, or were deserialized from stable memory,
+// whose format does not include the count. Locating a position in them counts the characters of
+// the blobs before it, which is linear in the size of the text. A subtree is entered without
+// counting when its size in bytes suffices, as a text of `n` bytes has at least `n / 4`
+// characters.
+
+/// Byte offset of the character at `index`, or the size of the text if `index` is its length.
+/// `None` if `index` is larger than the length.
+unsafe fn text_char_offset(mut s: Value, mut index: usize) -> Option<Bytes<usize>> {
+    let mut offset = Bytes(0);
+    while is_concat_tag(s.tag()) {
+        let concat = s.as_concat();
+        let left = concat.text1();
+        let left_size = text_size(left);
+        if index < left_size.as_usize().div_ceil(4) {
+            s = left;
+            continue;
+        }
+
+        let left_len = text_len(left);
+        if index < left_len {
+            s = left;
+        } else {
+            index -= left_len;
+            offset += left_size;
+            s = concat.text2();
+        }
+    }
+
+    let blob = s.as_blob();
+    let payload = str::from_utf8_unchecked(slice::from_raw_parts(
+        blob.payload_const(),
+        blob.len().as_usize(),
+    ));
+    match payload.char_indices().nth(index) {
+        Some((blob_offset, _)) => Some(offset + Bytes(blob_offset)),
+        None if index == payload.chars().count() => Some(offset + blob.len()),
+        None => None,
+    }
+}
+
+/// Character at the given character index. Traps if the index is out of range.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn text_char_at(s: Value, index: usize) -> u32 {
+    match text_char_offset(s, index) {
+        Some(offset) if offset < text_size(s) => {
+            // Characters do not span blobs
+            let chunk = TextCursor::at(s, offset).next_chunk().unwrap();
+            let mut size = 0;
+            decode_code_point(chunk.as_ptr(), &mut size)
+        }
+        _ => rts_trap_with("text_char_at: Index out of range"),
+    }
+}
+
+/// The `len` characters starting at character index `start`. Traps if the range is out of
+/// bounds. Concat nodes and blobs within the range are shared with the original text, only the
+/// blobs at both ends of the range are copied.
+#[ic_mem_fn]
+pub unsafe fn text_slice<M: Memory>(mem: &mut M, s: Value, start: usize, len: usize) -> Value {
+    let start_offset = match text_char_offset(s, start) {
+        Some(offset) => offset,
+        None => rts_trap_with("text_slice: Range out of bounds"),
+    };
+
+    let end_offset = match start
+        .checked_add(len)
+        .and_then(|end| text_char_offset(s, end))
+    {
+        Some(offset) => offset,
+        None => rts_trap_with("text_slice: Range out of bounds"),
+    };
+
+    text_sub(mem, s, start_offset, end_offset)
+}
+
+/// The bytes from `start` to `end` of a text, both at character boundaries.
+unsafe fn text_sub<M: Memory>(
+    mem: &mut M,
+    mut s: Value,
+    mut start: Bytes<usize>,
+    mut end: Bytes<usize>,
+) -> Value {
+    // Descend to the smallest subtree containing the range
+    loop {
+        if start == Bytes(0) && end == text_size(s) {
+            return s;
+        }
+        if !is_concat_tag(s.tag()) {
+            return blob_sub(mem, s, start, end);
+        }
+
+        let concat = s.as_concat();
+        let left_size = text_size(concat.text1());
+        if end <= left_size {
+            s = concat.text1();
+        } else if start >= left_size {
+            s = concat.text2();
+            start -= left_size;
+            end -= left_size;
+        } else {
+            let suffix = text_suffix(mem, concat.text1(), start);
+            let prefix = text_prefix(mem, concat.text2(), end - left_size);
+            return text_concat(mem, suffix, prefix);
+        }
     }
 }
 
+/// The bytes of a text from `start` on, which is less than the size of the text.
+unsafe fn text_suffix<M: Memory>(mem: &mut M, mut s: Value, mut start: Bytes<usize>) -> Value {
+    // The right parts along the path to `start` are concatenated from the right
+    let mut result: Option<Value> = None;
+    let piece = loop {
+        if start == Bytes(0) {
+            break s;
+        }
+        if !is_concat_tag(s.tag()) {
+            break blob_sub(mem, s, start, text_size(s));
+        }
+
+        let concat = s.as_concat();
+        let left_size = text_size(concat.text1());
+        if start >= left_size {
+            s = concat.text2();
+            start -= left_size;
+        } else {
+            let right = concat.text2();
+            result = Some(match result {
+                Some(result) => text_concat(mem, right, result),
+                None => right,
+            });
+            s = concat.text1();
+        }
+    };
+    match result {
+        Some(result) => text_concat(mem, piece, result),
+        None => piece,
+    }
+}
+
+/// The bytes of a text up to `end`, which is larger than zero.
+unsafe fn text_prefix<M: Memory>(mem: &mut M, mut s: Value, end: Bytes<usize>) -> Value {
+    // The left parts along the path to `end` are concatenated from the left
+    let mut end = end;
+    let mut result: Option<Value> = None;
+    let piece = loop {
+        if end == text_size(s) {
+            break s;
+        }
+        if !is_concat_tag(s.tag()) {
+            break blob_sub(mem, s, Bytes(0), end);
+        }
+
+        let concat = s.as_concat();
+        let left = concat.text1();
+        let left_size = text_size(left);
+        if end <= left_size {
+            s = left;
+        } else {
+            result = Some(match result {
+                Some(result) => text_concat(mem, result, left),
+                None => left,
+            });
+            s = concat.text2();
+            end -= left_size;
+        }
+    };
+    match result {
+        Some(result) => text_concat(mem, result, piece),
+        None => piece,
+    }
+}
+
+/// Copy of the bytes from `start` to `end` of a blob.
+unsafe fn blob_sub<M: Memory>(
+    mem: &mut M,
+    blob: Value,
+    start: Bytes<usize>,
+    end: Bytes<usize>,
+) -> Value {
+    let payload = blob.as_blob().payload_const().add(start.as_usize());
+    text_of_ptr_size(mem, payload, end - start)
+}
+
+/// Character index of the first occurrence of `needle` in `haystack`, or -1 if there is none.
+/// Scans the blobs of `haystack` in order and compares the needle at the positions where its first
+/// byte occurs.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn text_find(haystack: Value, needle: Value) -> isize {
+    let haystack_size = text_size(haystack);
+    let needle_size = text_size(needle);
+    if needle_size == Bytes(0) {
+        return 0;
+    }
+    if needle_size > haystack_size {
+        return -1;
+    }
+
+    let needle_start = TextCursor::new(needle).next_chunk().unwrap();
+    let last_offset = haystack_size - needle_size;
+    let mut cursor = TextCursor::new(haystack);
+    let mut chunk_offset = Bytes(0);
+    let mut chars = 0;
+    while let Some(chunk) = cursor.next_chunk() {
+        for i in 0..chunk.len() {
+            let offset = chunk_offset + Bytes(i);
+            if offset > last_offset {
+                return -1;
+            }
+
+            // Compare within the chunk first, and across blobs only if that matches. As the first
+            // byte of a character never occurs inside another character, a match is at a
+            // character boundary.
+            let n = min(needle_start.len(), chunk.len() - i);
+            if chunk[i..i + n] == needle_start[..n]
+                && (n == needle_size.as_usize()
+                    || compare_cursors(
+                        &mut TextCursor::at(haystack, offset),
+                        &mut TextCursor::new(needle),
+                        needle_size,
+                    ) == Ordering::Equal)
+            {
+                return (chars + count_chars(&chunk[..i])) as isize;
+            }
+        }
+        chars += count_chars(chunk);
+        chunk_offset += Bytes(chunk.len());
+    }
+    -1
+}
+
 /// Decodes the character at the pointer. Returns the character, the size via the `size` parameter
 pub unsafe fn decode_code_point(s: *const u8, size: *mut usize) -> u32 {
     // 0xxxxxxx
@@ -441,6 +850,48 @@ pub unsafe fn text_uppercase<M: Memory>(mem: &mut M, text: Value) -> Value {
     text_convert(mem, text, |s| s.to_uppercase())
 }
 
+/// Full Unicode case folding of a Text value, for caseless comparison.
+#[ic_mem_fn]
+pub unsafe fn text_case_fold<M: Memory>(mem: &mut M, text: Value) -> Value {
+    text_convert(mem, text, case_fold)
+}
+
+/// Unicode normalization form C (canonical composition) of a Text value.
+#[ic_mem_fn]
+pub unsafe fn text_nfc<M: Memory>(mem: &mut M, text: Value) -> Value {
+    text_normalize(mem, text, NormalizationForm::Nfc)
+}
+
+/// Unicode normalization form D (canonical decomposition) of a Text value.
+#[ic_mem_fn]
+pub unsafe fn text_nfd<M: Memory>(mem: &mut M, text: Value) -> Value {
+    text_normalize(mem, text, NormalizationForm::Nfd)
+}
+
+/// Unicode normalization form KC (compatibility decomposition and canonical composition) of a
+/// Text value.
+#[ic_mem_fn]
+pub unsafe fn text_nfkc<M: Memory>(mem: &mut M, text: Value) -> Value {
+    text_normalize(mem, text, NormalizationForm::Nfkc)
+}
+
+/// Unicode normalization form KD (compatibility decomposition) of a Text value.
+#[ic_mem_fn]
+pub unsafe fn text_nfkd<M: Memory>(mem: &mut M, text: Value) -> Value {
+    text_normalize(mem, text, NormalizationForm::Nfkd)
+}
+
+unsafe fn text_normalize<M: Memory>(mem: &mut M, text: Value, form: NormalizationForm) -> Value {
+    // ASCII text is normalized in all forms, return it without copying
+    let mut cursor = TextCursor::new(text);
+    while let Some(chunk) = cursor.next_chunk() {
+        if !chunk.is_ascii() {
+            return text_convert(mem, text, |s| normalize(s, form));
+        }
+    }
+    text
+}
+
 /// Convert a Text value via given to_string function
 unsafe fn text_convert<M: Memory, F>(mem: &mut M, text: Value, to_string: F) -> Value
 where
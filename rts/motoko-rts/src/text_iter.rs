@@ -9,12 +9,20 @@
 //! The linked list is a tuple (array) with
 //! 1. A pointer to the text
 //! 2. 0, or a pointer to the next list entry
+//!
+//! Grapheme cluster iterators are a pair (array) of
+//!
+//! 1. A text iterator over the characters
+//! 2. The first character of the next grapheme cluster, if it was read already, or `NO_CHAR`
 
-use crate::barriers::allocation_barrier;
+use crate::barriers::{allocation_barrier, write_with_barrier};
 use crate::memory::{Memory, alloc_array};
 use crate::rts_trap_with;
-use crate::text::decode_code_point;
-use crate::types::{TAG_ARRAY_T, TAG_BLOB_T, TAG_CONCAT, Value};
+use crate::text::{decode_code_point, text_of_str};
+use crate::types::{TAG_ARRAY_T, TAG_BLOB_T, Value, is_concat_tag};
+use crate::unicode::GraphemeBoundaries;
+
+use alloc::string::String;
 
 use motoko_rts_macros::ic_mem_fn;
 
@@ -24,7 +32,7 @@ const TODO_LINK_IDX: usize = 1;
 /// Find the left-most leaf of a text, putting all the others onto a list. Used to enforce the
 /// invariant about TEXT_ITER_BLOB to be a blob.
 unsafe fn find_leaf<M: Memory>(mem: &mut M, mut text: Value, todo: *mut Value) -> Value {
-    while text.tag() == TAG_CONCAT {
+    while is_concat_tag(text.tag()) {
         let concat = text.as_concat();
 
         // Add right node to TODOs
@@ -34,7 +42,8 @@ unsafe fn find_leaf<M: Memory>(mem: &mut M, mut text: Value, todo: *mut Value) -
         new_todo_array.initialize(TODO_TEXT_IDX, (*concat).text2, mem);
         new_todo_array.initialize(TODO_LINK_IDX, *todo, mem);
         allocation_barrier(new_todo);
-        *todo = new_todo;
+        // The iterator may be older than the new TODO entry.
+        write_with_barrier(mem, todo, new_todo);
 
         // Follow left node
         text = (*concat).text1;
@@ -94,11 +103,20 @@ pub unsafe extern "C" fn text_iter_done(iter: Value) -> usize {
 pub unsafe fn text_iter_next<M: Memory>(mem: &mut M, iter: Value) -> u32 {
     let iter_array = iter.as_array();
 
-    let blob = iter_array.get(ITER_BLOB_IDX).as_blob();
-    let pos = iter_array.get(ITER_POS_IDX).get_scalar();
+    loop {
+        let blob = iter_array.get(ITER_BLOB_IDX).as_blob();
+        let pos = iter_array.get(ITER_POS_IDX).get_scalar();
+
+        if pos < blob.len().as_usize() {
+            // We are not at the end, read the next character from the blob
+            let blob_payload = blob.payload_const();
+            let mut step = 0;
+            let char = decode_code_point(blob_payload.add(pos), &mut step as *mut usize);
+            iter_array.set(ITER_POS_IDX, Value::from_scalar(pos + step), mem);
+            return char;
+        }
 
-    // If we are at the end of the current blob, find the next blob
-    if pos >= blob.len().as_usize() {
+        // We are at the end of the current blob, find the next blob
         let todo = iter_array.get(ITER_TODO_IDX);
 
         if todo == NO_OBJECT {
@@ -110,7 +128,7 @@ pub unsafe fn text_iter_next<M: Memory>(mem: &mut M, iter: Value) -> u32 {
 
         let text = todo_array.get(TODO_TEXT_IDX);
 
-        if text.tag() == TAG_CONCAT {
+        if is_concat_tag(text.tag()) {
             // If next one is a concat node re-use both the iterator and the todo objects (avoids
             // allocation)
             let concat = text.as_concat();
@@ -124,8 +142,6 @@ pub unsafe fn text_iter_next<M: Memory>(mem: &mut M, iter: Value) -> u32 {
                 find_leaf(mem, (*concat).text1, todo_addr),
                 mem,
             );
-
-            text_iter_next(mem, iter)
         } else {
             // Otherwise remove the entry from the chain
             debug_assert_eq!(text.tag(), TAG_BLOB_T);
@@ -134,15 +150,80 @@ pub unsafe fn text_iter_next<M: Memory>(mem: &mut M, iter: Value) -> u32 {
             iter_array.set(ITER_POS_IDX, Value::from_scalar(0), mem);
 
             iter_array.set(ITER_TODO_IDX, todo_array.get(TODO_LINK_IDX), mem);
-
-            text_iter_next(mem, iter)
         }
+    }
+}
+
+const GRAPHEME_ITER_CHARS_IDX: usize = 0;
+const GRAPHEME_ITER_NEXT_IDX: usize = 1;
+
+// Not a code point
+const NO_CHAR: u32 = char::MAX as u32 + 1;
+
+/// Returns a new iterator over the grapheme clusters of the text
+#[ic_mem_fn]
+pub unsafe fn text_grapheme_iter<M: Memory>(mem: &mut M, text: Value) -> Value {
+    let chars = text_iter(mem, text);
+    let iter = alloc_array(mem, TAG_ARRAY_T, 2);
+    let array = iter.as_array();
+    // No pre-update barrier for object initialization, but do perform post-update barrier.
+    array.initialize(GRAPHEME_ITER_CHARS_IDX, chars, mem);
+    array.initialize(
+        GRAPHEME_ITER_NEXT_IDX,
+        Value::from_scalar(NO_CHAR as usize),
+        mem,
+    );
+    allocation_barrier(iter)
+}
+
+/// Returns whether the grapheme cluster iterator is finished
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn text_grapheme_iter_done(iter: Value) -> usize {
+    let array = iter.as_array();
+    let next = array.get(GRAPHEME_ITER_NEXT_IDX).get_scalar() as u32;
+    if next == NO_CHAR {
+        text_iter_done(array.get(GRAPHEME_ITER_CHARS_IDX))
     } else {
-        // We are not at the end, read the next character from the blob
-        let blob_payload = blob.payload_const();
-        let mut step = 0;
-        let char = decode_code_point(blob_payload.add(pos), &mut step as *mut usize);
-        iter_array.set(ITER_POS_IDX, Value::from_scalar(pos + step), mem);
-        char
+        0
     }
 }
+
+/// Returns the next grapheme cluster as a text, advances the iterator
+#[ic_mem_fn]
+pub unsafe fn text_grapheme_iter_next<M: Memory>(mem: &mut M, iter: Value) -> Value {
+    let array = iter.as_array();
+    let chars = array.get(GRAPHEME_ITER_CHARS_IDX);
+
+    let mut first = array.get(GRAPHEME_ITER_NEXT_IDX).get_scalar() as u32;
+    if first == NO_CHAR {
+        if text_iter_done(chars) == 1 {
+            // Caller should check with text_grapheme_iter_done
+            rts_trap_with("text_grapheme_iter_next: Iter already done");
+        }
+        first = text_iter_next(mem, chars);
+    }
+
+    // A boundary resets the state of the grapheme cluster rules, so the rules can start over at
+    // the first character of each grapheme cluster
+    let mut boundaries = GraphemeBoundaries::new();
+    let first = char::from_u32_unchecked(first);
+    boundaries.is_boundary(first);
+    let mut cluster = String::new();
+    cluster.push(first);
+
+    let mut next = NO_CHAR;
+    while text_iter_done(chars) == 0 {
+        let c = char::from_u32_unchecked(text_iter_next(mem, chars));
+        if boundaries.is_boundary(c) {
+            next = c as u32;
+            break;
+        }
+        cluster.push(c);
+    }
+    array.set(
+        GRAPHEME_ITER_NEXT_IDX,
+        Value::from_scalar(next as usize),
+        mem,
+    );
+    text_of_str(mem, &cluster)
+}
@@ -420,9 +420,9 @@ impl Value {
     }
 
     /// Get the pointer as `Concat` using forwarding. In debug mode panics if the value is not a pointer or the
-    /// pointed object is not a `Concat`.
+    /// pointed object is not a `Concat` or `CountedConcat`.
     pub unsafe fn as_concat(self) -> *const Concat {
-        debug_assert_eq!(self.tag(), TAG_CONCAT);
+        debug_assert!(is_concat_tag(self.tag()));
         self.check_forwarding_pointer();
         self.forward().get_ptr() as *const Concat
     }
@@ -532,6 +532,10 @@ pub const TAG_FREE_SPACE: Tag = 43;
 #[enhanced_orthogonal_persistence]
 pub const TAG_WEAK_REF: Tag = 45;
 
+// Concat node that also records the number of characters, see `CountedConcat`.
+#[enhanced_orthogonal_persistence]
+pub const TAG_COUNTED_CONCAT: Tag = 47;
+
 // Special value to visit only a range of array fields.
 // This and all values above it are reserved and mean
 // a slice of an array object (i.e. compressed array tag + start index) for
@@ -543,7 +547,7 @@ pub const TAG_WEAK_REF: Tag = 45;
 // a lower boundary to distinguish slice information from
 // the actual tag values.
 #[enhanced_orthogonal_persistence]
-pub const TAG_ARRAY_SLICE_MIN: Tag = 46;
+pub const TAG_ARRAY_SLICE_MIN: Tag = 48;
 
 pub const TAG_SPACING: Tag = 2;
 
@@ -560,16 +564,18 @@ pub const TAG_ONE_WORD_FILLER: Tag = 49;
 #[classical_persistence]
 pub const TAG_FREE_SPACE: Tag = 51;
 #[classical_persistence]
-pub const TAG_ARRAY_SLICE_MIN: Tag = 52;
+pub const TAG_COUNTED_CONCAT: Tag = 53;
+#[classical_persistence]
+pub const TAG_ARRAY_SLICE_MIN: Tag = 54;
 
 #[enhanced_orthogonal_persistence]
 pub fn is_object_tag(tag: Tag) -> bool {
-    tag >= TAG_OBJECT && tag <= TAG_REGION || tag == TAG_WEAK_REF
+    tag >= TAG_OBJECT && tag <= TAG_REGION || tag == TAG_WEAK_REF || tag == TAG_COUNTED_CONCAT
 }
 
 #[classical_persistence]
 pub fn is_object_tag(tag: Tag) -> bool {
-    tag >= TAG_OBJECT && tag <= TAG_NULL
+    tag >= TAG_OBJECT && tag <= TAG_NULL || tag == TAG_COUNTED_CONCAT
 }
 
 #[enhanced_orthogonal_persistence]
@@ -577,6 +583,10 @@ pub fn is_weak_ref_tag(tag: Tag) -> bool {
     tag == TAG_WEAK_REF
 }
 
+pub fn is_concat_tag(tag: Tag) -> bool {
+    tag == TAG_CONCAT || tag == TAG_COUNTED_CONCAT
+}
+
 pub fn is_blob_tag(tag: Tag) -> bool {
     tag == TAG_BLOB_B || tag == TAG_BLOB_T || tag == TAG_BLOB_P || tag == TAG_BLOB_A
 }
@@ -673,7 +683,7 @@ impl Obj {
     }
 
     pub unsafe fn as_concat(self: *mut Self) -> *const Concat {
-        debug_assert_eq!(self.tag(), TAG_CONCAT);
+        debug_assert!(is_concat_tag(self.tag()));
         self as *const Concat
     }
 }
@@ -1122,6 +1132,40 @@ impl Concat {
     pub unsafe fn text2(self: *const Self) -> Value {
         (*self).text2
     }
+
+    /// Number of characters, if known (see `CountedConcat`).
+    pub unsafe fn n_chars(self: *const Self) -> Option<usize> {
+        if (self as *mut Obj).tag() != TAG_COUNTED_CONCAT {
+            return None;
+        }
+        match (*(self as *const CountedConcat)).n_chars {
+            UNKNOWN_CHAR_COUNT => None,
+            n_chars => Some(n_chars),
+        }
+    }
+
+    /// Records the number of characters. No effect on concat nodes without a count.
+    pub unsafe fn set_n_chars(self: *const Self, n_chars: usize) {
+        if (self as *mut Obj).tag() == TAG_COUNTED_CONCAT {
+            (*(self as *mut CountedConcat)).n_chars = n_chars;
+        }
+    }
+}
+
+/// Character count of a `CountedConcat` that has not been determined yet.
+pub const UNKNOWN_CHAR_COUNT: usize = usize::MAX;
+
+/// A `Concat` that also records the number of characters of the text, or
+/// `UNKNOWN_CHAR_COUNT` until it is first needed. It extends the layout of `Concat`, such that it
+/// can be accessed as a `Concat`. Concat nodes with `TAG_CONCAT`, which persisted from before the
+/// count was introduced or were deserialized from stable memory, have no count.
+#[repr(C)] // See the note at the beginning of this module
+pub struct CountedConcat {
+    pub header: Obj,
+    pub n_bytes: Bytes<usize>,
+    pub text1: Value,
+    pub text2: Value,
+    pub n_chars: usize,
 }
 
 #[repr(C)] // See the note at the beginning of this module
@@ -1264,6 +1308,8 @@ pub(crate) unsafe fn block_size(address: usize) -> Words<usize> {
 
         TAG_CONCAT => size_of::<Concat>(),
 
+        TAG_COUNTED_CONCAT => size_of::<CountedConcat>(),
+
         TAG_ONE_WORD_FILLER => size_of::<OneWordFiller>(),
 
         TAG_FREE_SPACE => {
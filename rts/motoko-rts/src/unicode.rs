@@ -0,0 +1,345 @@
+//! Unicode normalization (NFC, NFD, NFKC, NFKD), case folding and grapheme cluster boundaries.
+//!
+//! The case mappings of `core::char` are complemented by the tables in `tables.rs`, which are
+//! generated by `generate_tables.py` from the Unicode Character Database. The tables must be of
+//! the Unicode version of `core::char`, so that normalization, case folding and grapheme cluster
+//! boundaries all follow the same version. Regenerate them when the Rust toolchain changes it.
+//! Rust strings are allocated temporarily, as in `text_convert`.
+//!
+//! Normalization decomposes all characters, canonically orders the combining marks and, for NFC
+//! and NFKC, composes the characters again, as specified in Unicode Standard Annex #15.
+//!
+//! Grapheme cluster boundaries are those of extended grapheme clusters in Unicode Standard Annex
+//! #29. They are determined incrementally, one character at a time, so that ropes can be
+//! segmented without flattening them.
+
+#[rustfmt::skip]
+mod tables;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use self::tables::{
+    CASE_FOLDING_EXCEPTIONS, COMBINING_CLASSES, COMPATIBILITY_FLAG, COMPOSITIONS,
+    DECOMPOSITION_EXPANSIONS, DECOMPOSITIONS, EXPANSION_FLAG, GRAPHEME_BREAKS, GraphemeBreak,
+    SIMPLE_CASE_FOLDING, SIMPLE_LOWERCASE, SIMPLE_UPPERCASE,
+};
+
+pub use self::tables::UNICODE_VERSION;
+
+const _: () = assert!(
+    UNICODE_VERSION.0 == char::UNICODE_VERSION.0
+        && UNICODE_VERSION.1 == char::UNICODE_VERSION.1
+        && UNICODE_VERSION.2 == char::UNICODE_VERSION.2,
+    "Unicode tables and `core::char` differ in version, regenerate `tables.rs`"
+);
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum NormalizationForm {
+    Nfc,
+    Nfd,
+    Nfkc,
+    Nfkd,
+}
+
+impl NormalizationForm {
+    fn is_compatibility(self) -> bool {
+        self == NormalizationForm::Nfkc || self == NormalizationForm::Nfkd
+    }
+
+    fn is_composed(self) -> bool {
+        self == NormalizationForm::Nfc || self == NormalizationForm::Nfkc
+    }
+}
+
+pub fn normalize(s: &str, form: NormalizationForm) -> String {
+    // ASCII text is normalized in all forms
+    if s.is_ascii() {
+        return String::from(s);
+    }
+
+    let mut chars = Vec::with_capacity(s.len());
+    for c in s.chars() {
+        decompose(c, form.is_compatibility(), &mut chars);
+    }
+    order_combining_marks(&mut chars);
+    if form.is_composed() {
+        compose(&mut chars);
+    }
+    chars.into_iter().collect()
+}
+
+/// Full case folding, e.g. "Straße" and "STRASSE" both fold to "strasse". Case folding does not
+/// normalize, for caseless matching of identifiers use e.g. `normalize(&case_fold(s), Nfkc)`.
+pub fn case_fold(s: &str) -> String {
+    let mut result = String::with_capacity(s.len());
+    for c in s.chars() {
+        match lookup(&CASE_FOLDING_EXCEPTIONS, c) {
+            Some(folded) => result.extend(
+                folded
+                    .iter()
+                    .take_while(|&&f| f != 0)
+                    .map(|&f| char_unchecked(f)),
+            ),
+            None => result.extend(c.to_lowercase()),
+        }
+    }
+    result
+}
+
+/// Simple uppercase mapping, to a single character.
+pub fn simple_uppercase(c: char) -> char {
+    simple_mapping(c, c.to_uppercase(), &SIMPLE_UPPERCASE)
+}
+
+/// Simple lowercase mapping, to a single character.
+pub fn simple_lowercase(c: char) -> char {
+    simple_mapping(c, c.to_lowercase(), &SIMPLE_LOWERCASE)
+}
+
+/// Simple case folding, to a single character.
+pub fn simple_case_fold(c: char) -> char {
+    match lookup(&CASE_FOLDING_EXCEPTIONS, c) {
+        Some(&[folded, 0, 0]) => char_unchecked(folded),
+        Some(_) => lookup(&SIMPLE_CASE_FOLDING, c).map_or(c, |&f| char_unchecked(f)),
+        // Without an exception, the full case folding is the full lowercase mapping
+        None => simple_mapping(c, c.to_lowercase(), &[]),
+    }
+}
+
+/// The full mapping if that is a single character, otherwise the simple mapping of the table, if
+/// any. Characters without a simple mapping map to themselves.
+fn simple_mapping(
+    c: char,
+    mut full: impl ExactSizeIterator<Item = char>,
+    table: &[(u32, u32)],
+) -> char {
+    if full.len() == 1 {
+        full.next().unwrap()
+    } else {
+        lookup(table, c).map_or(c, |&m| char_unchecked(m))
+    }
+}
+
+fn lookup<T>(table: &[(u32, T)], c: char) -> Option<&T> {
+    table
+        .binary_search_by_key(&(c as u32), |&(key, _)| key)
+        .ok()
+        .map(|index| &table[index].1)
+}
+
+fn char_unchecked(c: u32) -> char {
+    // The tables only contain valid code points
+    unsafe { char::from_u32_unchecked(c) }
+}
+
+// Hangul syllables, see section 3.12 of the Unicode Standard
+
+const HANGUL_S_BASE: u32 = 0xAC00;
+const HANGUL_L_BASE: u32 = 0x1100;
+const HANGUL_V_BASE: u32 = 0x1161;
+const HANGUL_T_BASE: u32 = 0x11A7;
+const HANGUL_L_COUNT: u32 = 19;
+const HANGUL_V_COUNT: u32 = 21;
+const HANGUL_T_COUNT: u32 = 28;
+const HANGUL_N_COUNT: u32 = HANGUL_V_COUNT * HANGUL_T_COUNT;
+const HANGUL_S_COUNT: u32 = HANGUL_L_COUNT * HANGUL_N_COUNT;
+
+/// Appends the full decomposition of `c`, recursing over the table entries.
+fn decompose(c: char, compatibility: bool, result: &mut Vec<char>) {
+    let code = c as u32;
+    if (HANGUL_S_BASE..HANGUL_S_BASE + HANGUL_S_COUNT).contains(&code) {
+        let index = code - HANGUL_S_BASE;
+        result.push(char_unchecked(HANGUL_L_BASE + index / HANGUL_N_COUNT));
+        result.push(char_unchecked(
+            HANGUL_V_BASE + (index % HANGUL_N_COUNT) / HANGUL_T_COUNT,
+        ));
+        if !index.is_multiple_of(HANGUL_T_COUNT) {
+            result.push(char_unchecked(HANGUL_T_BASE + index % HANGUL_T_COUNT));
+        }
+        return;
+    }
+
+    let entry = DECOMPOSITIONS
+        .binary_search_by_key(&code, |&(key, _)| key & !COMPATIBILITY_FLAG)
+        .ok()
+        .map(|index| DECOMPOSITIONS[index]);
+    match entry {
+        Some((key, value)) if compatibility || key & COMPATIBILITY_FLAG == 0 => {
+            if value & EXPANSION_FLAG == 0 {
+                decompose(char_unchecked(value), compatibility, result);
+            } else {
+                let start = (value & 0xFFFF) as usize;
+                let len = ((value >> 16) & 0xFF) as usize;
+                for &d in &DECOMPOSITION_EXPANSIONS[start..start + len] {
+                    decompose(char_unchecked(d), compatibility, result);
+                }
+            }
+        }
+        _ => result.push(c),
+    }
+}
+
+fn combining_class(c: char) -> u8 {
+    let code = c as u32;
+    match COMBINING_CLASSES.binary_search_by(|&(first, last, _)| {
+        if last < code {
+            core::cmp::Ordering::Less
+        } else if first > code {
+            core::cmp::Ordering::Greater
+        } else {
+            core::cmp::Ordering::Equal
+        }
+    }) {
+        Ok(index) => COMBINING_CLASSES[index].2,
+        Err(_) => 0,
+    }
+}
+
+/// Sorts each sequence of combining marks by combining class, keeping the order of marks of the
+/// same class.
+fn order_combining_marks(chars: &mut [char]) {
+    let mut start = 0;
+    while start < chars.len() {
+        if combining_class(chars[start]) == 0 {
+            start += 1;
+            continue;
+        }
+        let mut end = start + 1;
+        while end < chars.len() && combining_class(chars[end]) != 0 {
+            end += 1;
+        }
+        // Stable sort
+        chars[start..end].sort_by_key(|&c| combining_class(c));
+        start = end;
+    }
+}
+
+/// Composes a canonically ordered decomposition in place.
+fn compose(chars: &mut Vec<char>) {
+    let mut result = 0;
+    // Index of the last starter in the result, and the highest combining class after it, `None`
+    // if no character follows the starter
+    let mut starter: Option<usize> = None;
+    let mut last_class: Option<u8> = None;
+    for index in 0..chars.len() {
+        let c = chars[index];
+        let class = combining_class(c);
+        if let Some(starter_index) = starter {
+            // A character between the starter and `c` of a class of at least the class of `c`
+            // blocks `c` from the starter
+            let blocked = last_class.is_some_and(|last_class| last_class >= class);
+            if !blocked && let Some(composite) = compose_pair(chars[starter_index], c) {
+                chars[starter_index] = composite;
+                continue;
+            }
+        }
+        if class == 0 {
+            starter = Some(result);
+            last_class = None;
+        } else {
+            last_class = Some(class);
+        }
+        chars[result] = c;
+        result += 1;
+    }
+    chars.truncate(result);
+}
+
+fn compose_pair(first: char, second: char) -> Option<char> {
+    let (first, second) = (first as u32, second as u32);
+
+    // Hangul LV and LVT syllables
+    if (HANGUL_L_BASE..HANGUL_L_BASE + HANGUL_L_COUNT).contains(&first)
+        && (HANGUL_V_BASE..HANGUL_V_BASE + HANGUL_V_COUNT).contains(&second)
+    {
+        let l_index = first - HANGUL_L_BASE;
+        let v_index = second - HANGUL_V_BASE;
+        return Some(char_unchecked(
+            HANGUL_S_BASE + l_index * HANGUL_N_COUNT + v_index * HANGUL_T_COUNT,
+        ));
+    }
+    if (HANGUL_S_BASE..HANGUL_S_BASE + HANGUL_S_COUNT).contains(&first)
+        && (first - HANGUL_S_BASE).is_multiple_of(HANGUL_T_COUNT)
+        && (HANGUL_T_BASE + 1..HANGUL_T_BASE + HANGUL_T_COUNT).contains(&second)
+    {
+        return Some(char_unchecked(first + (second - HANGUL_T_BASE)));
+    }
+
+    COMPOSITIONS
+        .binary_search_by_key(&(first, second), |&(f, s, _)| (f, s))
+        .ok()
+        .map(|index| char_unchecked(COMPOSITIONS[index].2))
+}
+
+/// Finds the grapheme cluster boundaries of a text, given its characters in order.
+#[derive(Default)]
+pub struct GraphemeBoundaries {
+    previous: Option<GraphemeBreak>,
+    /// The preceding characters end in an extended pictographic character followed by extending
+    /// characters
+    pictographic: bool,
+    /// The preceding characters end in such a sequence followed by a zero width joiner
+    pictographic_zwj: bool,
+    /// The preceding characters end in an odd number of regional indicators
+    odd_indicators: bool,
+    /// The preceding characters end in an Indic conjunct consonant followed by extending characters
+    /// and linkers
+    conjunct: bool,
+    /// The preceding characters end in such a sequence with at least one linker
+    conjunct_linker: bool,
+}
+
+impl GraphemeBoundaries {
+    pub const fn new() -> Self {
+        GraphemeBoundaries {
+            previous: None,
+            pictographic: false,
+            pictographic_zwj: false,
+            odd_indicators: false,
+            conjunct: false,
+            conjunct_linker: false,
+        }
+    }
+
+    /// Whether a grapheme cluster starts at `c`, i.e. whether there is a boundary between the
+    /// preceding characters and `c`. True for the first character.
+    pub fn is_boundary(&mut self, c: char) -> bool {
+        use GraphemeBreak::*;
+
+        let next = grapheme_break(c);
+        let extend = matches!(next, Extend | NonConjunctExtend | ConjunctLinker);
+        let boundary = match (self.previous, next) {
+            (None, _) => true,                                                    // GB1
+            (Some(Cr), Lf) => false,                                              // GB3
+            (Some(Control | Cr | Lf), _) | (_, Control | Cr | Lf) => true,        // GB4, GB5
+            (Some(L), L | V | Lv | Lvt) => false,                                 // GB6
+            (Some(Lv | V), V | T) => false,                                       // GB7
+            (Some(Lvt | T), T) => false,                                          // GB8
+            _ if extend => false,                                                 // GB9
+            (_, Zwj | SpacingMark) => false,                                      // GB9, GB9a
+            (Some(Prepend), _) => false,                                          // GB9b
+            (_, ConjunctConsonant) => !self.conjunct_linker,                      // GB9c
+            (Some(Zwj), ExtendedPictographic) => !self.pictographic_zwj,          // GB11
+            (Some(RegionalIndicator), RegionalIndicator) => !self.odd_indicators, // GB12, GB13
+            _ => true,                                                            // GB999
+        };
+
+        self.pictographic_zwj = self.pictographic && next == Zwj;
+        self.pictographic = next == ExtendedPictographic || (self.pictographic && extend);
+        self.conjunct = next == ConjunctConsonant
+            || (self.conjunct && matches!(next, Extend | Zwj | ConjunctLinker));
+        self.conjunct_linker = self.conjunct
+            && next != ConjunctConsonant
+            && (self.conjunct_linker || next == ConjunctLinker);
+        self.odd_indicators = next == RegionalIndicator && !self.odd_indicators;
+        self.previous = Some(next);
+        boundary
+    }
+}
+
+fn grapheme_break(c: char) -> GraphemeBreak {
+    // The table starts with code point 0
+    let index = GRAPHEME_BREAKS.partition_point(|&(first, _)| first <= c as u32);
+    GRAPHEME_BREAKS[index - 1].1
+}
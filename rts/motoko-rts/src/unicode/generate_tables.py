@@ -0,0 +1,327 @@
+#!/usr/bin/env python3
+"""Generates `tables.rs` from the files of the Unicode Character Database.
+
+Usage (in `rts/motoko-rts/src/unicode`):
+
+    python3 generate_tables.py <UCD directory> > tables.rs
+
+The UCD directory is an unpacked `UCD.zip` of https://www.unicode.org/Public/<version>/ucd/. Its
+version must be the Unicode version of `core::char` of the Rust toolchain, `core::char::UNICODE_VERSION`,
+as the runtime takes the full case mappings from `core::char`. `unicode.rs` checks this at compile time.
+"""
+
+import os
+import sys
+
+MAX_CODE_POINT = 0x10FFFF
+
+# Hangul syllables are decomposed and composed algorithmically
+HANGUL_SYLLABLES = range(0xAC00, 0xD7A4)
+
+
+def read_ucd(directory, name):
+    """The data lines of a UCD file as lists of fields, without comments."""
+    with open(os.path.join(directory, name), encoding="utf-8") as file:
+        for line in file:
+            line = line.split("#")[0].strip()
+            if line:
+                yield [field.strip() for field in line.split(";")]
+
+
+def read_version(directory, name):
+    """The Unicode version of a UCD file, from its first line, e.g. `# CaseFolding-17.0.0.txt`."""
+    with open(os.path.join(directory, name), encoding="utf-8") as file:
+        first_line = file.readline()
+    base = os.path.basename(name)[: -len(".txt")]
+    assert first_line.startswith("# %s-" % base), "%s has no version header" % name
+    return first_line[len("# %s-" % base) :].strip()[: -len(".txt")]
+
+
+def code_points(field):
+    """The code points of a field with a single code point or a range `first..last`."""
+    first, _, last = field.partition("..")
+    return range(int(first, 16), int(last or first, 16) + 1)
+
+
+def hex_values(field):
+    return [int(value, 16) for value in field.split()]
+
+
+class Ucd:
+    def __init__(self, directory):
+        self.version = read_version(directory, "CaseFolding.txt")
+        for name in [
+            "SpecialCasing.txt",
+            "DerivedNormalizationProps.txt",
+            "DerivedCoreProperties.txt",
+            "auxiliary/GraphemeBreakProperty.txt",
+        ]:
+            assert read_version(directory, name) == self.version, "Unicode versions of the UCD files differ"
+
+        self.combining_class = {}
+        self.decomposition = {}
+        self.simple_uppercase = {}
+        self.simple_lowercase = {}
+        for fields in read_ucd(directory, "UnicodeData.txt"):
+            cp = int(fields[0], 16)
+            if int(fields[3]) != 0:
+                self.combining_class[cp] = int(fields[3])
+            if fields[5] and cp not in HANGUL_SYLLABLES:
+                mapping = fields[5].split()
+                compatibility = mapping[0].startswith("<")
+                if compatibility:
+                    mapping = mapping[1:]
+                self.decomposition[cp] = (compatibility, [int(value, 16) for value in mapping])
+            if fields[12]:
+                self.simple_uppercase[cp] = int(fields[12], 16)
+            if fields[13]:
+                self.simple_lowercase[cp] = int(fields[13], 16)
+
+        self.composition_exclusions = set()
+        for fields in read_ucd(directory, "DerivedNormalizationProps.txt"):
+            if fields[1] == "Full_Composition_Exclusion":
+                self.composition_exclusions.update(code_points(fields[0]))
+
+        # Unconditional special casings (lower, title, upper)
+        self.special_casing = {}
+        for fields in read_ucd(directory, "SpecialCasing.txt"):
+            if len(fields) == 4 or not fields[4]:
+                self.special_casing[int(fields[0], 16)] = [hex_values(field) for field in fields[1:4]]
+
+        self.simple_case_folding = {}
+        self.full_case_folding = {}
+        for fields in read_ucd(directory, "CaseFolding.txt"):
+            cp, status, mapping = int(fields[0], 16), fields[1], hex_values(fields[2])
+            if status in "CS":
+                self.simple_case_folding[cp] = mapping[0]
+            if status in "CF":
+                self.full_case_folding[cp] = mapping
+
+        self.grapheme_break = {}
+        for fields in read_ucd(directory, "auxiliary/GraphemeBreakProperty.txt"):
+            for cp in code_points(fields[0]):
+                self.grapheme_break[cp] = fields[1]
+        self.indic_conjunct_break = {}
+        for fields in read_ucd(directory, "DerivedCoreProperties.txt"):
+            if fields[1] == "InCB":
+                for cp in code_points(fields[0]):
+                    self.indic_conjunct_break[cp] = fields[2]
+        self.extended_pictographic = set()
+        for fields in read_ucd(directory, "emoji/emoji-data.txt"):
+            if fields[1] == "Extended_Pictographic":
+                self.extended_pictographic.update(code_points(fields[0]))
+
+    def full_uppercase(self, cp):
+        if cp in self.special_casing:
+            return self.special_casing[cp][2]
+        return [self.simple_uppercase.get(cp, cp)]
+
+    def full_lowercase(self, cp):
+        if cp in self.special_casing:
+            return self.special_casing[cp][0]
+        return [self.simple_lowercase.get(cp, cp)]
+
+    def full_case_fold(self, cp):
+        return self.full_case_folding.get(cp, [cp])
+
+
+def decompositions(ucd):
+    """Single-level decomposition mappings: (code point, is compatibility mapping, mapping)."""
+    return [(cp, compatibility, mapping) for cp, (compatibility, mapping) in sorted(ucd.decomposition.items())]
+
+
+def compositions(ucd, decompositions):
+    """Primary composites: (first, second, composite), sorted by the pair."""
+    result = []
+    for cp, compatibility, mapping in decompositions:
+        # Full composition exclusions include singletons and non-starter decompositions
+        if not compatibility and cp not in ucd.composition_exclusions:
+            assert len(mapping) == 2
+            result.append((mapping[0], mapping[1], cp))
+    return sorted(result)
+
+
+def combining_classes(ucd):
+    """Ranges of code points with the same non-zero canonical combining class."""
+    result = []
+    for cp, ccc in sorted(ucd.combining_class.items()):
+        if result and result[-1][1] == cp - 1 and result[-1][2] == ccc:
+            result[-1][1] = cp
+        else:
+            result.append([cp, cp, ccc])
+    return result
+
+
+def case_folding_exceptions(ucd):
+    """Full case foldings that differ from the full lowercase mapping."""
+    result = []
+    for cp in range(MAX_CODE_POINT + 1):
+        folded = ucd.full_case_fold(cp)
+        if folded != ucd.full_lowercase(cp):
+            assert len(folded) <= 3
+            result.append((cp, folded))
+    return result
+
+
+def simple_mappings(full, simple):
+    """Simple case mappings of characters whose full mapping expands to several characters."""
+    result = []
+    for cp in range(MAX_CODE_POINT + 1):
+        if len(full(cp)) > 1 and simple.get(cp, cp) != cp:
+            result.append((cp, simple[cp]))
+    return result
+
+
+# Grapheme cluster break classes, in the order of the `GraphemeBreak` enum, by property values of
+# Grapheme_Cluster_Break and Indic_Conjunct_Break
+GRAPHEME_BREAKS = [
+    ("Other", ("Other", "None")),
+    ("Cr", ("CR", "None")),
+    ("Lf", ("LF", "None")),
+    ("Control", ("Control", "None")),
+    ("Extend", ("Extend", "Extend")),
+    ("Zwj", ("ZWJ", "Extend")),
+    ("RegionalIndicator", ("Regional_Indicator", "None")),
+    ("Prepend", ("Prepend", "None")),
+    ("SpacingMark", ("SpacingMark", "None")),
+    ("L", ("L", "None")),
+    ("V", ("V", "None")),
+    ("T", ("T", "None")),
+    ("Lv", ("LV", "None")),
+    ("Lvt", ("LVT", "None")),
+    ("ExtendedPictographic", None),
+    ("ConjunctConsonant", ("Other", "Consonant")),
+    ("ConjunctLinker", ("Extend", "Linker")),
+    ("NonConjunctExtend", ("Extend", "None")),
+]
+
+
+def grapheme_breaks(ucd):
+    """Inversion map of the grapheme cluster break classes: (first code point, class)."""
+    classes = {value: name for name, value in GRAPHEME_BREAKS if value is not None}
+    result = []
+    for cp in range(MAX_CODE_POINT + 1):
+        value = classes[(ucd.grapheme_break.get(cp, "Other"), ucd.indic_conjunct_break.get(cp, "None"))]
+        if cp in ucd.extended_pictographic:
+            # Extended pictographic characters are all of class Other
+            assert value == "Other"
+            value = "ExtendedPictographic"
+        if not result or result[-1][1] != value:
+            result.append((cp, value))
+    return result
+
+
+def hex_list(values):
+    return ", ".join("0x%04X" % value for value in values)
+
+
+def emit_pairs(name, pairs, last=False):
+    print("pub static %s: [(u32, u32); %d] = [" % (name, len(pairs)))
+    for index in range(0, len(pairs), 4):
+        print("    " + " ".join("(0x%04X, 0x%04X)," % pair for pair in pairs[index : index + 4]))
+    print("];")
+    if not last:
+        print()
+
+
+def main():
+    ucd = Ucd(sys.argv[1])
+    decomposition_list = decompositions(ucd)
+
+    print("//! Unicode tables for normalization and case folding.")
+    print("//!")
+    print("//! @generated by `generate_tables.py`, do not edit.")
+    print()
+    major, minor, patch = ucd.version.split(".")
+    print("/// Version of the Unicode Character Database of the tables.")
+    print("pub const UNICODE_VERSION: (u8, u8, u8) = (%s, %s, %s);" % (major, minor, patch))
+    print()
+
+    print("/// Flags a compatibility mapping in the key of `DECOMPOSITIONS`.")
+    print("pub const COMPATIBILITY_FLAG: u32 = 1 << 31;")
+    print()
+    print("/// Flags a mapping to several characters in the value of `DECOMPOSITIONS`.")
+    print("pub const EXPANSION_FLAG: u32 = 1 << 31;")
+    print()
+
+    expansion_chars = []
+    entries = []
+    for cp, compatibility, mapping in decomposition_list:
+        key = cp | (0x80000000 if compatibility else 0)
+        if len(mapping) == 1:
+            value = mapping[0]
+        else:
+            assert len(mapping) < 1 << 8 and len(expansion_chars) < 1 << 16
+            value = 0x80000000 | (len(mapping) << 16) | len(expansion_chars)
+            expansion_chars.extend(mapping)
+        entries.append((key, value))
+
+    print("/// Single-level decomposition mappings, sorted by code point. The key is the code point,")
+    print("/// with `COMPATIBILITY_FLAG` for compatibility mappings. The value is the character of a")
+    print("/// single-character mapping, or `EXPANSION_FLAG`, the length (bits 16 to 23) and the start")
+    print("/// index in `DECOMPOSITION_EXPANSIONS` (bits 0 to 15) of a longer mapping.")
+    emit_pairs("DECOMPOSITIONS", entries)
+
+    print("/// Characters of the decomposition mappings to several characters.")
+    print("pub static DECOMPOSITION_EXPANSIONS: [u32; %d] = [" % len(expansion_chars))
+    for index in range(0, len(expansion_chars), 8):
+        print("    " + hex_list(expansion_chars[index : index + 8]) + ",")
+    print("];")
+    print()
+
+    composition_list = compositions(ucd, decomposition_list)
+    print("/// Primary composites as (first, second, composite), sorted by the pair.")
+    print("pub static COMPOSITIONS: [(u32, u32, u32); %d] = [" % len(composition_list))
+    for index in range(0, len(composition_list), 3):
+        print(
+            "    "
+            + " ".join("(0x%04X, 0x%04X, 0x%04X)," % entry for entry in composition_list[index : index + 3])
+        )
+    print("];")
+    print()
+
+    ranges = combining_classes(ucd)
+    print("/// Ranges (first, last, class) of non-zero canonical combining classes, sorted.")
+    print("pub static COMBINING_CLASSES: [(u32, u32, u8); %d] = [" % len(ranges))
+    for index in range(0, len(ranges), 4):
+        print("    " + " ".join("(0x%04X, 0x%04X, %d)," % tuple(entry) for entry in ranges[index : index + 4]))
+    print("];")
+    print()
+
+    exceptions = case_folding_exceptions(ucd)
+    print("/// Full case foldings that differ from the full lowercase mapping, padded with zeros.")
+    print("pub static CASE_FOLDING_EXCEPTIONS: [(u32, [u32; 3]); %d] = [" % len(exceptions))
+    for cp, folded in exceptions:
+        print("    (0x%04X, [%s])," % (cp, hex_list(folded + [0] * (3 - len(folded)))))
+    print("];")
+    print()
+
+    print("/// Simple uppercase mappings of characters whose full uppercase mapping expands.")
+    emit_pairs("SIMPLE_UPPERCASE", simple_mappings(ucd.full_uppercase, ucd.simple_uppercase))
+    print("/// Simple lowercase mappings of characters whose full lowercase mapping expands.")
+    emit_pairs("SIMPLE_LOWERCASE", simple_mappings(ucd.full_lowercase, ucd.simple_lowercase))
+    print("/// Simple case foldings of characters whose full case folding expands.")
+    emit_pairs("SIMPLE_CASE_FOLDING", simple_mappings(ucd.full_case_fold, ucd.simple_case_folding))
+
+    print("/// Grapheme cluster break classes of Unicode Standard Annex #29, with the extended")
+    print("/// pictographic characters as a separate class. The class `Other` is split by the Indic")
+    print("/// conjunct break consonants and the class `Extend` by the Indic conjunct break linkers and")
+    print("/// the characters that do not extend Indic conjuncts.")
+    print("#[derive(Clone, Copy, PartialEq, Eq, Debug)]")
+    print("pub enum GraphemeBreak {")
+    for name, _ in GRAPHEME_BREAKS:
+        print("    %s," % name)
+    print("}")
+    print()
+
+    breaks = grapheme_breaks(ucd)
+    print("/// Grapheme cluster break classes by first code point, sorted. Each class extends to the next")
+    print("/// entry.")
+    print("pub static GRAPHEME_BREAKS: [(u32, GraphemeBreak); %d] = [" % len(breaks))
+    for index in range(0, len(breaks), 4):
+        print("    " + " ".join("(0x%04X, GraphemeBreak::%s)," % entry for entry in breaks[index : index + 4]))
+    print("];")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,3462 @@
+//! Unicode tables for normalization and case folding.
+//!
+//! @generated by `generate_tables.py`, do not edit.
+
+/// Version of the Unicode Character Database of the tables.
+pub const UNICODE_VERSION: (u8, u8, u8) = (17, 0, 0);
+
+/// Flags a compatibility mapping in the key of `DECOMPOSITIONS`.
+pub const COMPATIBILITY_FLAG: u32 = 1 << 31;
+
+/// Flags a mapping to several characters in the value of `DECOMPOSITIONS`.
+pub const EXPANSION_FLAG: u32 = 1 << 31;
+
+/// Single-level decomposition mappings, sorted by code point. The key is the code point,
+/// with `COMPATIBILITY_FLAG` for compatibility mappings. The value is the character of a
+/// single-character mapping, or `EXPANSION_FLAG`, the length (bits 16 to 23) and the start
+/// index in `DECOMPOSITION_EXPANSIONS` (bits 0 to 15) of a longer mapping.
+pub static DECOMPOSITIONS: [(u32, u32); 5914] = [
+    (0x800000A0, 0x0020), (0x800000A8, 0x80020000), (0x800000AA, 0x0061), (0x800000AF, 0x80020002),
+    (0x800000B2, 0x0032), (0x800000B3, 0x0033), (0x800000B4, 0x80020004), (0x800000B5, 0x03BC),
+    (0x800000B8, 0x80020006), (0x800000B9, 0x0031), (0x800000BA, 0x006F), (0x800000BC, 0x80030008),
+    (0x800000BD, 0x8003000B), (0x800000BE, 0x8003000E), (0x00C0, 0x80020011), (0x00C1, 0x80020013),
+    (0x00C2, 0x80020015), (0x00C3, 0x80020017), (0x00C4, 0x80020019), (0x00C5, 0x8002001B),
+    (0x00C7, 0x8002001D), (0x00C8, 0x8002001F), (0x00C9, 0x80020021), (0x00CA, 0x80020023),
+    (0x00CB, 0x80020025), (0x00CC, 0x80020027), (0x00CD, 0x80020029), (0x00CE, 0x8002002B),
+    (0x00CF, 0x8002002D), (0x00D1, 0x8002002F), (0x00D2, 0x80020031), (0x00D3, 0x80020033),
+    (0x00D4, 0x80020035), (0x00D5, 0x80020037), (0x00D6, 0x80020039), (0x00D9, 0x8002003B),
+    (0x00DA, 0x8002003D), (0x00DB, 0x8002003F), (0x00DC, 0x80020041), (0x00DD, 0x80020043),
+    (0x00E0, 0x80020045), (0x00E1, 0x80020047), (0x00E2, 0x80020049), (0x00E3, 0x8002004B),
+    (0x00E4, 0x8002004D), (0x00E5, 0x8002004F), (0x00E7, 0x80020051), (0x00E8, 0x80020053),
+    (0x00E9, 0x80020055), (0x00EA, 0x80020057), (0x00EB, 0x80020059), (0x00EC, 0x8002005B),
+    (0x00ED, 0x8002005D), (0x00EE, 0x8002005F), (0x00EF, 0x80020061), (0x00F1, 0x80020063),
+    (0x00F2, 0x80020065), (0x00F3, 0x80020067), (0x00F4, 0x80020069), (0x00F5, 0x8002006B),
+    (0x00F6, 0x8002006D), (0x00F9, 0x8002006F), (0x00FA, 0x80020071), (0x00FB, 0x80020073),
+    (0x00FC, 0x80020075), (0x00FD, 0x80020077), (0x00FF, 0x80020079), (0x0100, 0x8002007B),
+    (0x0101, 0x8002007D), (0x0102, 0x8002007F), (0x0103, 0x80020081), (0x0104, 0x80020083),
+    (0x0105, 0x80020085), (0x0106, 0x80020087), (0x0107, 0x80020089), (0x0108, 0x8002008B),
+    (0x0109, 0x8002008D), (0x010A, 0x8002008F), (0x010B, 0x80020091), (0x010C, 0x80020093),
+    (0x010D, 0x80020095), (0x010E, 0x80020097), (0x010F, 0x80020099), (0x0112, 0x8002009B),
+    (0x0113, 0x8002009D), (0x0114, 0x8002009F), (0x0115, 0x800200A1), (0x0116, 0x800200A3),
+    (0x0117, 0x800200A5), (0x0118, 0x800200A7), (0x0119, 0x800200A9), (0x011A, 0x800200AB),
+    (0x011B, 0x800200AD), (0x011C, 0x800200AF), (0x011D, 0x800200B1), (0x011E, 0x800200B3),
+    (0x011F, 0x800200B5), (0x0120, 0x800200B7), (0x0121, 0x800200B9), (0x0122, 0x800200BB),
+    (0x0123, 0x800200BD), (0x0124, 0x800200BF), (0x0125, 0x800200C1), (0x0128, 0x800200C3),
+    (0x0129, 0x800200C5), (0x012A, 0x800200C7), (0x012B, 0x800200C9), (0x012C, 0x800200CB),
+    (0x012D, 0x800200CD), (0x012E, 0x800200CF), (0x012F, 0x800200D1), (0x0130, 0x800200D3),
+    (0x80000132, 0x800200D5), (0x80000133, 0x800200D7), (0x0134, 0x800200D9), (0x0135, 0x800200DB),
+    (0x0136, 0x800200DD), (0x0137, 0x800200DF), (0x0139, 0x800200E1), (0x013A, 0x800200E3),
+    (0x013B, 0x800200E5), (0x013C, 0x800200E7), (0x013D, 0x800200E9), (0x013E, 0x800200EB),
+    (0x8000013F, 0x800200ED), (0x80000140, 0x800200EF), (0x0143, 0x800200F1), (0x0144, 0x800200F3),
+    (0x0145, 0x800200F5), (0x0146, 0x800200F7), (0x0147, 0x800200F9), (0x0148, 0x800200FB),
+    (0x80000149, 0x800200FD), (0x014C, 0x800200FF), (0x014D, 0x80020101), (0x014E, 0x80020103),
+    (0x014F, 0x80020105), (0x0150, 0x80020107), (0x0151, 0x80020109), (0x0154, 0x8002010B),
+    (0x0155, 0x8002010D), (0x0156, 0x8002010F), (0x0157, 0x80020111), (0x0158, 0x80020113),
+    (0x0159, 0x80020115), (0x015A, 0x80020117), (0x015B, 0x80020119), (0x015C, 0x8002011B),
+    (0x015D, 0x8002011D), (0x015E, 0x8002011F), (0x015F, 0x80020121), (0x0160, 0x80020123),
+    (0x0161, 0x80020125), (0x0162, 0x80020127), (0x0163, 0x80020129), (0x0164, 0x8002012B),
+    (0x0165, 0x8002012D), (0x0168, 0x8002012F), (0x0169, 0x80020131), (0x016A, 0x80020133),
+    (0x016B, 0x80020135), (0x016C, 0x80020137), (0x016D, 0x80020139), (0x016E, 0x8002013B),
+    (0x016F, 0x8002013D), (0x0170, 0x8002013F), (0x0171, 0x80020141), (0x0172, 0x80020143),
+    (0x0173, 0x80020145), (0x0174, 0x80020147), (0x0175, 0x80020149), (0x0176, 0x8002014B),
+    (0x0177, 0x8002014D), (0x0178, 0x8002014F), (0x0179, 0x80020151), (0x017A, 0x80020153),
+    (0x017B, 0x80020155), (0x017C, 0x80020157), (0x017D, 0x80020159), (0x017E, 0x8002015B),
+    (0x8000017F, 0x0073), (0x01A0, 0x8002015D), (0x01A1, 0x8002015F), (0x01AF, 0x80020161),
+    (0x01B0, 0x80020163), (0x800001C4, 0x80020165), (0x800001C5, 0x80020167), (0x800001C6, 0x80020169),
+    (0x800001C7, 0x8002016B), (0x800001C8, 0x8002016D), (0x800001C9, 0x8002016F), (0x800001CA, 0x80020171),
+    (0x800001CB, 0x80020173), (0x800001CC, 0x80020175), (0x01CD, 0x80020177), (0x01CE, 0x80020179),
+    (0x01CF, 0x8002017B), (0x01D0, 0x8002017D), (0x01D1, 0x8002017F), (0x01D2, 0x80020181),
+    (0x01D3, 0x80020183), (0x01D4, 0x80020185), (0x01D5, 0x80020187), (0x01D6, 0x80020189),
+    (0x01D7, 0x8002018B), (0x01D8, 0x8002018D), (0x01D9, 0x8002018F), (0x01DA, 0x80020191),
+    (0x01DB, 0x80020193), (0x01DC, 0x80020195), (0x01DE, 0x80020197), (0x01DF, 0x80020199),
+    (0x01E0, 0x8002019B), (0x01E1, 0x8002019D), (0x01E2, 0x8002019F), (0x01E3, 0x800201A1),
+    (0x01E6, 0x800201A3), (0x01E7, 0x800201A5), (0x01E8, 0x800201A7), (0x01E9, 0x800201A9),
+    (0x01EA, 0x800201AB), (0x01EB, 0x800201AD), (0x01EC, 0x800201AF), (0x01ED, 0x800201B1),
+    (0x01EE, 0x800201B3), (0x01EF, 0x800201B5), (0x01F0, 0x800201B7), (0x800001F1, 0x800201B9),
+    (0x800001F2, 0x800201BB), (0x800001F3, 0x800201BD), (0x01F4, 0x800201BF), (0x01F5, 0x800201C1),
+    (0x01F8, 0x800201C3), (0x01F9, 0x800201C5), (0x01FA, 0x800201C7), (0x01FB, 0x800201C9),
+    (0x01FC, 0x800201CB), (0x01FD, 0x800201CD), (0x01FE, 0x800201CF), (0x01FF, 0x800201D1),
+    (0x0200, 0x800201D3), (0x0201, 0x800201D5), (0x0202, 0x800201D7), (0x0203, 0x800201D9),
+    (0x0204, 0x800201DB), (0x0205, 0x800201DD), (0x0206, 0x800201DF), (0x0207, 0x800201E1),
+    (0x0208, 0x800201E3), (0x0209, 0x800201E5), (0x020A, 0x800201E7), (0x020B, 0x800201E9),
+    (0x020C, 0x800201EB), (0x020D, 0x800201ED), (0x020E, 0x800201EF), (0x020F, 0x800201F1),
+    (0x0210, 0x800201F3), (0x0211, 0x800201F5), (0x0212, 0x800201F7), (0x0213, 0x800201F9),
+    (0x0214, 0x800201FB), (0x0215, 0x800201FD), (0x0216, 0x800201FF), (0x0217, 0x80020201),
+    (0x0218, 0x80020203), (0x0219, 0x80020205), (0x021A, 0x80020207), (0x021B, 0x80020209),
+    (0x021E, 0x8002020B), (0x021F, 0x8002020D), (0x0226, 0x8002020F), (0x0227, 0x80020211),
+    (0x0228, 0x80020213), (0x0229, 0x80020215), (0x022A, 0x80020217), (0x022B, 0x80020219),
+    (0x022C, 0x8002021B), (0x022D, 0x8002021D), (0x022E, 0x8002021F), (0x022F, 0x80020221),
+    (0x0230, 0x80020223), (0x0231, 0x80020225), (0x0232, 0x80020227), (0x0233, 0x80020229),
+    (0x800002B0, 0x0068), (0x800002B1, 0x0266), (0x800002B2, 0x006A), (0x800002B3, 0x0072),
+    (0x800002B4, 0x0279), (0x800002B5, 0x027B), (0x800002B6, 0x0281), (0x800002B7, 0x0077),
+    (0x800002B8, 0x0079), (0x800002D8, 0x8002022B), (0x800002D9, 0x8002022D), (0x800002DA, 0x8002022F),
+    (0x800002DB, 0x80020231), (0x800002DC, 0x80020233), (0x800002DD, 0x80020235), (0x800002E0, 0x0263),
+    (0x800002E1, 0x006C), (0x800002E2, 0x0073), (0x800002E3, 0x0078), (0x800002E4, 0x0295),
+    (0x0340, 0x0300), (0x0341, 0x0301), (0x0343, 0x0313), (0x0344, 0x80020237),
+    (0x0374, 0x02B9), (0x8000037A, 0x80020239), (0x037E, 0x003B), (0x80000384, 0x8002023B),
+    (0x0385, 0x8002023D), (0x0386, 0x8002023F), (0x0387, 0x00B7), (0x0388, 0x80020241),
+    (0x0389, 0x80020243), (0x038A, 0x80020245), (0x038C, 0x80020247), (0x038E, 0x80020249),
+    (0x038F, 0x8002024B), (0x0390, 0x8002024D), (0x03AA, 0x8002024F), (0x03AB, 0x80020251),
+    (0x03AC, 0x80020253), (0x03AD, 0x80020255), (0x03AE, 0x80020257), (0x03AF, 0x80020259),
+    (0x03B0, 0x8002025B), (0x03CA, 0x8002025D), (0x03CB, 0x8002025F), (0x03CC, 0x80020261),
+    (0x03CD, 0x80020263), (0x03CE, 0x80020265), (0x800003D0, 0x03B2), (0x800003D1, 0x03B8),
+    (0x800003D2, 0x03A5), (0x03D3, 0x80020267), (0x03D4, 0x80020269), (0x800003D5, 0x03C6),
+    (0x800003D6, 0x03C0), (0x800003F0, 0x03BA), (0x800003F1, 0x03C1), (0x800003F2, 0x03C2),
+    (0x800003F4, 0x0398), (0x800003F5, 0x03B5), (0x800003F9, 0x03A3), (0x0400, 0x8002026B),
+    (0x0401, 0x8002026D), (0x0403, 0x8002026F), (0x0407, 0x80020271), (0x040C, 0x80020273),
+    (0x040D, 0x80020275), (0x040E, 0x80020277), (0x0419, 0x80020279), (0x0439, 0x8002027B),
+    (0x0450, 0x8002027D), (0x0451, 0x8002027F), (0x0453, 0x80020281), (0x0457, 0x80020283),
+    (0x045C, 0x80020285), (0x045D, 0x80020287), (0x045E, 0x80020289), (0x0476, 0x8002028B),
+    (0x0477, 0x8002028D), (0x04C1, 0x8002028F), (0x04C2, 0x80020291), (0x04D0, 0x80020293),
+    (0x04D1, 0x80020295), (0x04D2, 0x80020297), (0x04D3, 0x80020299), (0x04D6, 0x8002029B),
+    (0x04D7, 0x8002029D), (0x04DA, 0x8002029F), (0x04DB, 0x800202A1), (0x04DC, 0x800202A3),
+    (0x04DD, 0x800202A5), (0x04DE, 0x800202A7), (0x04DF, 0x800202A9), (0x04E2, 0x800202AB),
+    (0x04E3, 0x800202AD), (0x04E4, 0x800202AF), (0x04E5, 0x800202B1), (0x04E6, 0x800202B3),
+    (0x04E7, 0x800202B5), (0x04EA, 0x800202B7), (0x04EB, 0x800202B9), (0x04EC, 0x800202BB),
+    (0x04ED, 0x800202BD), (0x04EE, 0x800202BF), (0x04EF, 0x800202C1), (0x04F0, 0x800202C3),
+    (0x04F1, 0x800202C5), (0x04F2, 0x800202C7), (0x04F3, 0x800202C9), (0x04F4, 0x800202CB),
+    (0x04F5, 0x800202CD), (0x04F8, 0x800202CF), (0x04F9, 0x800202D1), (0x80000587, 0x800202D3),
+    (0x0622, 0x800202D5), (0x0623, 0x800202D7), (0x0624, 0x800202D9), (0x0625, 0x800202DB),
+    (0x0626, 0x800202DD), (0x80000675, 0x800202DF), (0x80000676, 0x800202E1), (0x80000677, 0x800202E3),
+    (0x80000678, 0x800202E5), (0x06C0, 0x800202E7), (0x06C2, 0x800202E9), (0x06D3, 0x800202EB),
+    (0x0929, 0x800202ED), (0x0931, 0x800202EF), (0x0934, 0x800202F1), (0x0958, 0x800202F3),
+    (0x0959, 0x800202F5), (0x095A, 0x800202F7), (0x095B, 0x800202F9), (0x095C, 0x800202FB),
+    (0x095D, 0x800202FD), (0x095E, 0x800202FF), (0x095F, 0x80020301), (0x09CB, 0x80020303),
+    (0x09CC, 0x80020305), (0x09DC, 0x80020307), (0x09DD, 0x80020309), (0x09DF, 0x8002030B),
+    (0x0A33, 0x8002030D), (0x0A36, 0x8002030F), (0x0A59, 0x80020311), (0x0A5A, 0x80020313),
+    (0x0A5B, 0x80020315), (0x0A5E, 0x80020317), (0x0B48, 0x80020319), (0x0B4B, 0x8002031B),
+    (0x0B4C, 0x8002031D), (0x0B5C, 0x8002031F), (0x0B5D, 0x80020321), (0x0B94, 0x80020323),
+    (0x0BCA, 0x80020325), (0x0BCB, 0x80020327), (0x0BCC, 0x80020329), (0x0C48, 0x8002032B),
+    (0x0CC0, 0x8002032D), (0x0CC7, 0x8002032F), (0x0CC8, 0x80020331), (0x0CCA, 0x80020333),
+    (0x0CCB, 0x80020335), (0x0D4A, 0x80020337), (0x0D4B, 0x80020339), (0x0D4C, 0x8002033B),
+    (0x0DDA, 0x8002033D), (0x0DDC, 0x8002033F), (0x0DDD, 0x80020341), (0x0DDE, 0x80020343),
+    (0x80000E33, 0x80020345), (0x80000EB3, 0x80020347), (0x80000EDC, 0x80020349), (0x80000EDD, 0x8002034B),
+    (0x80000F0C, 0x0F0B), (0x0F43, 0x8002034D), (0x0F4D, 0x8002034F), (0x0F52, 0x80020351),
+    (0x0F57, 0x80020353), (0x0F5C, 0x80020355), (0x0F69, 0x80020357), (0x0F73, 0x80020359),
+    (0x0F75, 0x8002035B), (0x0F76, 0x8002035D), (0x80000F77, 0x8002035F), (0x0F78, 0x80020361),
+    (0x80000F79, 0x80020363), (0x0F81, 0x80020365), (0x0F93, 0x80020367), (0x0F9D, 0x80020369),
+    (0x0FA2, 0x8002036B), (0x0FA7, 0x8002036D), (0x0FAC, 0x8002036F), (0x0FB9, 0x80020371),
+    (0x1026, 0x80020373), (0x800010FC, 0x10DC), (0x1B06, 0x80020375), (0x1B08, 0x80020377),
+    (0x1B0A, 0x80020379), (0x1B0C, 0x8002037B), (0x1B0E, 0x8002037D), (0x1B12, 0x8002037F),
+    (0x1B3B, 0x80020381), (0x1B3D, 0x80020383), (0x1B40, 0x80020385), (0x1B41, 0x80020387),
+    (0x1B43, 0x80020389), (0x80001D2C, 0x0041), (0x80001D2D, 0x00C6), (0x80001D2E, 0x0042),
+    (0x80001D30, 0x0044), (0x80001D31, 0x0045), (0x80001D32, 0x018E), (0x80001D33, 0x0047),
+    (0x80001D34, 0x0048), (0x80001D35, 0x0049), (0x80001D36, 0x004A), (0x80001D37, 0x004B),
+    (0x80001D38, 0x004C), (0x80001D39, 0x004D), (0x80001D3A, 0x004E), (0x80001D3C, 0x004F),
+    (0x80001D3D, 0x0222), (0x80001D3E, 0x0050), (0x80001D3F, 0x0052), (0x80001D40, 0x0054),
+    (0x80001D41, 0x0055), (0x80001D42, 0x0057), (0x80001D43, 0x0061), (0x80001D44, 0x0250),
+    (0x80001D45, 0x0251), (0x80001D46, 0x1D02), (0x80001D47, 0x0062), (0x80001D48, 0x0064),
+    (0x80001D49, 0x0065), (0x80001D4A, 0x0259), (0x80001D4B, 0x025B), (0x80001D4C, 0x025C),
+    (0x80001D4D, 0x0067), (0x80001D4F, 0x006B), (0x80001D50, 0x006D), (0x80001D51, 0x014B),
+    (0x80001D52, 0x006F), (0x80001D53, 0x0254), (0x80001D54, 0x1D16), (0x80001D55, 0x1D17),
+    (0x80001D56, 0x0070), (0x80001D57, 0x0074), (0x80001D58, 0x0075), (0x80001D59, 0x1D1D),
+    (0x80001D5A, 0x026F), (0x80001D5B, 0x0076), (0x80001D5C, 0x1D25), (0x80001D5D, 0x03B2),
+    (0x80001D5E, 0x03B3), (0x80001D5F, 0x03B4), (0x80001D60, 0x03C6), (0x80001D61, 0x03C7),
+    (0x80001D62, 0x0069), (0x80001D63, 0x0072), (0x80001D64, 0x0075), (0x80001D65, 0x0076),
+    (0x80001D66, 0x03B2), (0x80001D67, 0x03B3), (0x80001D68, 0x03C1), (0x80001D69, 0x03C6),
+    (0x80001D6A, 0x03C7), (0x80001D78, 0x043D), (0x80001D9B, 0x0252), (0x80001D9C, 0x0063),
+    (0x80001D9D, 0x0255), (0x80001D9E, 0x00F0), (0x80001D9F, 0x025C), (0x80001DA0, 0x0066),
+    (0x80001DA1, 0x025F), (0x80001DA2, 0x0261), (0x80001DA3, 0x0265), (0x80001DA4, 0x0268),
+    (0x80001DA5, 0x0269), (0x80001DA6, 0x026A), (0x80001DA7, 0x1D7B), (0x80001DA8, 0x029D),
+    (0x80001DA9, 0x026D), (0x80001DAA, 0x1D85), (0x80001DAB, 0x029F), (0x80001DAC, 0x0271),
+    (0x80001DAD, 0x0270), (0x80001DAE, 0x0272), (0x80001DAF, 0x0273), (0x80001DB0, 0x0274),
+    (0x80001DB1, 0x0275), (0x80001DB2, 0x0278), (0x80001DB3, 0x0282), (0x80001DB4, 0x0283),
+    (0x80001DB5, 0x01AB), (0x80001DB6, 0x0289), (0x80001DB7, 0x028A), (0x80001DB8, 0x1D1C),
+    (0x80001DB9, 0x028B), (0x80001DBA, 0x028C), (0x80001DBB, 0x007A), (0x80001DBC, 0x0290),
+    (0x80001DBD, 0x0291), (0x80001DBE, 0x0292), (0x80001DBF, 0x03B8), (0x1E00, 0x8002038B),
+    (0x1E01, 0x8002038D), (0x1E02, 0x8002038F), (0x1E03, 0x80020391), (0x1E04, 0x80020393),
+    (0x1E05, 0x80020395), (0x1E06, 0x80020397), (0x1E07, 0x80020399), (0x1E08, 0x8002039B),
+    (0x1E09, 0x8002039D), (0x1E0A, 0x8002039F), (0x1E0B, 0x800203A1), (0x1E0C, 0x800203A3),
+    (0x1E0D, 0x800203A5), (0x1E0E, 0x800203A7), (0x1E0F, 0x800203A9), (0x1E10, 0x800203AB),
+    (0x1E11, 0x800203AD), (0x1E12, 0x800203AF), (0x1E13, 0x800203B1), (0x1E14, 0x800203B3),
+    (0x1E15, 0x800203B5), (0x1E16, 0x800203B7), (0x1E17, 0x800203B9), (0x1E18, 0x800203BB),
+    (0x1E19, 0x800203BD), (0x1E1A, 0x800203BF), (0x1E1B, 0x800203C1), (0x1E1C, 0x800203C3),
+    (0x1E1D, 0x800203C5), (0x1E1E, 0x800203C7), (0x1E1F, 0x800203C9), (0x1E20, 0x800203CB),
+    (0x1E21, 0x800203CD), (0x1E22, 0x800203CF), (0x1E23, 0x800203D1), (0x1E24, 0x800203D3),
+    (0x1E25, 0x800203D5), (0x1E26, 0x800203D7), (0x1E27, 0x800203D9), (0x1E28, 0x800203DB),
+    (0x1E29, 0x800203DD), (0x1E2A, 0x800203DF), (0x1E2B, 0x800203E1), (0x1E2C, 0x800203E3),
+    (0x1E2D, 0x800203E5), (0x1E2E, 0x800203E7), (0x1E2F, 0x800203E9), (0x1E30, 0x800203EB),
+    (0x1E31, 0x800203ED), (0x1E32, 0x800203EF), (0x1E33, 0x800203F1), (0x1E34, 0x800203F3),
+    (0x1E35, 0x800203F5), (0x1E36, 0x800203F7), (0x1E37, 0x800203F9), (0x1E38, 0x800203FB),
+    (0x1E39, 0x800203FD), (0x1E3A, 0x800203FF), (0x1E3B, 0x80020401), (0x1E3C, 0x80020403),
+    (0x1E3D, 0x80020405), (0x1E3E, 0x80020407), (0x1E3F, 0x80020409), (0x1E40, 0x8002040B),
+    (0x1E41, 0x8002040D), (0x1E42, 0x8002040F), (0x1E43, 0x80020411), (0x1E44, 0x80020413),
+    (0x1E45, 0x80020415), (0x1E46, 0x80020417), (0x1E47, 0x80020419), (0x1E48, 0x8002041B),
+    (0x1E49, 0x8002041D), (0x1E4A, 0x8002041F), (0x1E4B, 0x80020421), (0x1E4C, 0x80020423),
+    (0x1E4D, 0x80020425), (0x1E4E, 0x80020427), (0x1E4F, 0x80020429), (0x1E50, 0x8002042B),
+    (0x1E51, 0x8002042D), (0x1E52, 0x8002042F), (0x1E53, 0x80020431), (0x1E54, 0x80020433),
+    (0x1E55, 0x80020435), (0x1E56, 0x80020437), (0x1E57, 0x80020439), (0x1E58, 0x8002043B),
+    (0x1E59, 0x8002043D), (0x1E5A, 0x8002043F), (0x1E5B, 0x80020441), (0x1E5C, 0x80020443),
+    (0x1E5D, 0x80020445), (0x1E5E, 0x80020447), (0x1E5F, 0x80020449), (0x1E60, 0x8002044B),
+    (0x1E61, 0x8002044D), (0x1E62, 0x8002044F), (0x1E63, 0x80020451), (0x1E64, 0x80020453),
+    (0x1E65, 0x80020455), (0x1E66, 0x80020457), (0x1E67, 0x80020459), (0x1E68, 0x8002045B),
+    (0x1E69, 0x8002045D), (0x1E6A, 0x8002045F), (0x1E6B, 0x80020461), (0x1E6C, 0x80020463),
+    (0x1E6D, 0x80020465), (0x1E6E, 0x80020467), (0x1E6F, 0x80020469), (0x1E70, 0x8002046B),
+    (0x1E71, 0x8002046D), (0x1E72, 0x8002046F), (0x1E73, 0x80020471), (0x1E74, 0x80020473),
+    (0x1E75, 0x80020475), (0x1E76, 0x80020477), (0x1E77, 0x80020479), (0x1E78, 0x8002047B),
+    (0x1E79, 0x8002047D), (0x1E7A, 0x8002047F), (0x1E7B, 0x80020481), (0x1E7C, 0x80020483),
+    (0x1E7D, 0x80020485), (0x1E7E, 0x80020487), (0x1E7F, 0x80020489), (0x1E80, 0x8002048B),
+    (0x1E81, 0x8002048D), (0x1E82, 0x8002048F), (0x1E83, 0x80020491), (0x1E84, 0x80020493),
+    (0x1E85, 0x80020495), (0x1E86, 0x80020497), (0x1E87, 0x80020499), (0x1E88, 0x8002049B),
+    (0x1E89, 0x8002049D), (0x1E8A, 0x8002049F), (0x1E8B, 0x800204A1), (0x1E8C, 0x800204A3),
+    (0x1E8D, 0x800204A5), (0x1E8E, 0x800204A7), (0x1E8F, 0x800204A9), (0x1E90, 0x800204AB),
+    (0x1E91, 0x800204AD), (0x1E92, 0x800204AF), (0x1E93, 0x800204B1), (0x1E94, 0x800204B3),
+    (0x1E95, 0x800204B5), (0x1E96, 0x800204B7), (0x1E97, 0x800204B9), (0x1E98, 0x800204BB),
+    (0x1E99, 0x800204BD), (0x80001E9A, 0x800204BF), (0x1E9B, 0x800204C1), (0x1EA0, 0x800204C3),
+    (0x1EA1, 0x800204C5), (0x1EA2, 0x800204C7), (0x1EA3, 0x800204C9), (0x1EA4, 0x800204CB),
+    (0x1EA5, 0x800204CD), (0x1EA6, 0x800204CF), (0x1EA7, 0x800204D1), (0x1EA8, 0x800204D3),
+    (0x1EA9, 0x800204D5), (0x1EAA, 0x800204D7), (0x1EAB, 0x800204D9), (0x1EAC, 0x800204DB),
+    (0x1EAD, 0x800204DD), (0x1EAE, 0x800204DF), (0x1EAF, 0x800204E1), (0x1EB0, 0x800204E3),
+    (0x1EB1, 0x800204E5), (0x1EB2, 0x800204E7), (0x1EB3, 0x800204E9), (0x1EB4, 0x800204EB),
+    (0x1EB5, 0x800204ED), (0x1EB6, 0x800204EF), (0x1EB7, 0x800204F1), (0x1EB8, 0x800204F3),
+    (0x1EB9, 0x800204F5), (0x1EBA, 0x800204F7), (0x1EBB, 0x800204F9), (0x1EBC, 0x800204FB),
+    (0x1EBD, 0x800204FD), (0x1EBE, 0x800204FF), (0x1EBF, 0x80020501), (0x1EC0, 0x80020503),
+    (0x1EC1, 0x80020505), (0x1EC2, 0x80020507), (0x1EC3, 0x80020509), (0x1EC4, 0x8002050B),
+    (0x1EC5, 0x8002050D), (0x1EC6, 0x8002050F), (0x1EC7, 0x80020511), (0x1EC8, 0x80020513),
+    (0x1EC9, 0x80020515), (0x1ECA, 0x80020517), (0x1ECB, 0x80020519), (0x1ECC, 0x8002051B),
+    (0x1ECD, 0x8002051D), (0x1ECE, 0x8002051F), (0x1ECF, 0x80020521), (0x1ED0, 0x80020523),
+    (0x1ED1, 0x80020525), (0x1ED2, 0x80020527), (0x1ED3, 0x80020529), (0x1ED4, 0x8002052B),
+    (0x1ED5, 0x8002052D), (0x1ED6, 0x8002052F), (0x1ED7, 0x80020531), (0x1ED8, 0x80020533),
+    (0x1ED9, 0x80020535), (0x1EDA, 0x80020537), (0x1EDB, 0x80020539), (0x1EDC, 0x8002053B),
+    (0x1EDD, 0x8002053D), (0x1EDE, 0x8002053F), (0x1EDF, 0x80020541), (0x1EE0, 0x80020543),
+    (0x1EE1, 0x80020545), (0x1EE2, 0x80020547), (0x1EE3, 0x80020549), (0x1EE4, 0x8002054B),
+    (0x1EE5, 0x8002054D), (0x1EE6, 0x8002054F), (0x1EE7, 0x80020551), (0x1EE8, 0x80020553),
+    (0x1EE9, 0x80020555), (0x1EEA, 0x80020557), (0x1EEB, 0x80020559), (0x1EEC, 0x8002055B),
+    (0x1EED, 0x8002055D), (0x1EEE, 0x8002055F), (0x1EEF, 0x80020561), (0x1EF0, 0x80020563),
+    (0x1EF1, 0x80020565), (0x1EF2, 0x80020567), (0x1EF3, 0x80020569), (0x1EF4, 0x8002056B),
+    (0x1EF5, 0x8002056D), (0x1EF6, 0x8002056F), (0x1EF7, 0x80020571), (0x1EF8, 0x80020573),
+    (0x1EF9, 0x80020575), (0x1F00, 0x80020577), (0x1F01, 0x80020579), (0x1F02, 0x8002057B),
+    (0x1F03, 0x8002057D), (0x1F04, 0x8002057F), (0x1F05, 0x80020581), (0x1F06, 0x80020583),
+    (0x1F07, 0x80020585), (0x1F08, 0x80020587), (0x1F09, 0x80020589), (0x1F0A, 0x8002058B),
+    (0x1F0B, 0x8002058D), (0x1F0C, 0x8002058F), (0x1F0D, 0x80020591), (0x1F0E, 0x80020593),
+    (0x1F0F, 0x80020595), (0x1F10, 0x80020597), (0x1F11, 0x80020599), (0x1F12, 0x8002059B),
+    (0x1F13, 0x8002059D), (0x1F14, 0x8002059F), (0x1F15, 0x800205A1), (0x1F18, 0x800205A3),
+    (0x1F19, 0x800205A5), (0x1F1A, 0x800205A7), (0x1F1B, 0x800205A9), (0x1F1C, 0x800205AB),
+    (0x1F1D, 0x800205AD), (0x1F20, 0x800205AF), (0x1F21, 0x800205B1), (0x1F22, 0x800205B3),
+    (0x1F23, 0x800205B5), (0x1F24, 0x800205B7), (0x1F25, 0x800205B9), (0x1F26, 0x800205BB),
+    (0x1F27, 0x800205BD), (0x1F28, 0x800205BF), (0x1F29, 0x800205C1), (0x1F2A, 0x800205C3),
+    (0x1F2B, 0x800205C5), (0x1F2C, 0x800205C7), (0x1F2D, 0x800205C9), (0x1F2E, 0x800205CB),
+    (0x1F2F, 0x800205CD), (0x1F30, 0x800205CF), (0x1F31, 0x800205D1), (0x1F32, 0x800205D3),
+    (0x1F33, 0x800205D5), (0x1F34, 0x800205D7), (0x1F35, 0x800205D9), (0x1F36, 0x800205DB),
+    (0x1F37, 0x800205DD), (0x1F38, 0x800205DF), (0x1F39, 0x800205E1), (0x1F3A, 0x800205E3),
+    (0x1F3B, 0x800205E5), (0x1F3C, 0x800205E7), (0x1F3D, 0x800205E9), (0x1F3E, 0x800205EB),
+    (0x1F3F, 0x800205ED), (0x1F40, 0x800205EF), (0x1F41, 0x800205F1), (0x1F42, 0x800205F3),
+    (0x1F43, 0x800205F5), (0x1F44, 0x800205F7), (0x1F45, 0x800205F9), (0x1F48, 0x800205FB),
+    (0x1F49, 0x800205FD), (0x1F4A, 0x800205FF), (0x1F4B, 0x80020601), (0x1F4C, 0x80020603),
+    (0x1F4D, 0x80020605), (0x1F50, 0x80020607), (0x1F51, 0x80020609), (0x1F52, 0x8002060B),
+    (0x1F53, 0x8002060D), (0x1F54, 0x8002060F), (0x1F55, 0x80020611), (0x1F56, 0x80020613),
+    (0x1F57, 0x80020615), (0x1F59, 0x80020617), (0x1F5B, 0x80020619), (0x1F5D, 0x8002061B),
+    (0x1F5F, 0x8002061D), (0x1F60, 0x8002061F), (0x1F61, 0x80020621), (0x1F62, 0x80020623),
+    (0x1F63, 0x80020625), (0x1F64, 0x80020627), (0x1F65, 0x80020629), (0x1F66, 0x8002062B),
+    (0x1F67, 0x8002062D), (0x1F68, 0x8002062F), (0x1F69, 0x80020631), (0x1F6A, 0x80020633),
+    (0x1F6B, 0x80020635), (0x1F6C, 0x80020637), (0x1F6D, 0x80020639), (0x1F6E, 0x8002063B),
+    (0x1F6F, 0x8002063D), (0x1F70, 0x8002063F), (0x1F71, 0x03AC), (0x1F72, 0x80020641),
+    (0x1F73, 0x03AD), (0x1F74, 0x80020643), (0x1F75, 0x03AE), (0x1F76, 0x80020645),
+    (0x1F77, 0x03AF), (0x1F78, 0x80020647), (0x1F79, 0x03CC), (0x1F7A, 0x80020649),
+    (0x1F7B, 0x03CD), (0x1F7C, 0x8002064B), (0x1F7D, 0x03CE), (0x1F80, 0x8002064D),
+    (0x1F81, 0x8002064F), (0x1F82, 0x80020651), (0x1F83, 0x80020653), (0x1F84, 0x80020655),
+    (0x1F85, 0x80020657), (0x1F86, 0x80020659), (0x1F87, 0x8002065B), (0x1F88, 0x8002065D),
+    (0x1F89, 0x8002065F), (0x1F8A, 0x80020661), (0x1F8B, 0x80020663), (0x1F8C, 0x80020665),
+    (0x1F8D, 0x80020667), (0x1F8E, 0x80020669), (0x1F8F, 0x8002066B), (0x1F90, 0x8002066D),
+    (0x1F91, 0x8002066F), (0x1F92, 0x80020671), (0x1F93, 0x80020673), (0x1F94, 0x80020675),
+    (0x1F95, 0x80020677), (0x1F96, 0x80020679), (0x1F97, 0x8002067B), (0x1F98, 0x8002067D),
+    (0x1F99, 0x8002067F), (0x1F9A, 0x80020681), (0x1F9B, 0x80020683), (0x1F9C, 0x80020685),
+    (0x1F9D, 0x80020687), (0x1F9E, 0x80020689), (0x1F9F, 0x8002068B), (0x1FA0, 0x8002068D),
+    (0x1FA1, 0x8002068F), (0x1FA2, 0x80020691), (0x1FA3, 0x80020693), (0x1FA4, 0x80020695),
+    (0x1FA5, 0x80020697), (0x1FA6, 0x80020699), (0x1FA7, 0x8002069B), (0x1FA8, 0x8002069D),
+    (0x1FA9, 0x8002069F), (0x1FAA, 0x800206A1), (0x1FAB, 0x800206A3), (0x1FAC, 0x800206A5),
+    (0x1FAD, 0x800206A7), (0x1FAE, 0x800206A9), (0x1FAF, 0x800206AB), (0x1FB0, 0x800206AD),
+    (0x1FB1, 0x800206AF), (0x1FB2, 0x800206B1), (0x1FB3, 0x800206B3), (0x1FB4, 0x800206B5),
+    (0x1FB6, 0x800206B7), (0x1FB7, 0x800206B9), (0x1FB8, 0x800206BB), (0x1FB9, 0x800206BD),
+    (0x1FBA, 0x800206BF), (0x1FBB, 0x0386), (0x1FBC, 0x800206C1), (0x80001FBD, 0x800206C3),
+    (0x1FBE, 0x03B9), (0x80001FBF, 0x800206C5), (0x80001FC0, 0x800206C7), (0x1FC1, 0x800206C9),
+    (0x1FC2, 0x800206CB), (0x1FC3, 0x800206CD), (0x1FC4, 0x800206CF), (0x1FC6, 0x800206D1),
+    (0x1FC7, 0x800206D3), (0x1FC8, 0x800206D5), (0x1FC9, 0x0388), (0x1FCA, 0x800206D7),
+    (0x1FCB, 0x0389), (0x1FCC, 0x800206D9), (0x1FCD, 0x800206DB), (0x1FCE, 0x800206DD),
+    (0x1FCF, 0x800206DF), (0x1FD0, 0x800206E1), (0x1FD1, 0x800206E3), (0x1FD2, 0x800206E5),
+    (0x1FD3, 0x0390), (0x1FD6, 0x800206E7), (0x1FD7, 0x800206E9), (0x1FD8, 0x800206EB),
+    (0x1FD9, 0x800206ED), (0x1FDA, 0x800206EF), (0x1FDB, 0x038A), (0x1FDD, 0x800206F1),
+    (0x1FDE, 0x800206F3), (0x1FDF, 0x800206F5), (0x1FE0, 0x800206F7), (0x1FE1, 0x800206F9),
+    (0x1FE2, 0x800206FB), (0x1FE3, 0x03B0), (0x1FE4, 0x800206FD), (0x1FE5, 0x800206FF),
+    (0x1FE6, 0x80020701), (0x1FE7, 0x80020703), (0x1FE8, 0x80020705), (0x1FE9, 0x80020707),
+    (0x1FEA, 0x80020709), (0x1FEB, 0x038E), (0x1FEC, 0x8002070B), (0x1FED, 0x8002070D),
+    (0x1FEE, 0x0385), (0x1FEF, 0x0060), (0x1FF2, 0x8002070F), (0x1FF3, 0x80020711),
+    (0x1FF4, 0x80020713), (0x1FF6, 0x80020715), (0x1FF7, 0x80020717), (0x1FF8, 0x80020719),
+    (0x1FF9, 0x038C), (0x1FFA, 0x8002071B), (0x1FFB, 0x038F), (0x1FFC, 0x8002071D),
+    (0x1FFD, 0x00B4), (0x80001FFE, 0x8002071F), (0x2000, 0x2002), (0x2001, 0x2003),
+    (0x80002002, 0x0020), (0x80002003, 0x0020), (0x80002004, 0x0020), (0x80002005, 0x0020),
+    (0x80002006, 0x0020), (0x80002007, 0x0020), (0x80002008, 0x0020), (0x80002009, 0x0020),
+    (0x8000200A, 0x0020), (0x80002011, 0x2010), (0x80002017, 0x80020721), (0x80002024, 0x002E),
+    (0x80002025, 0x80020723), (0x80002026, 0x80030725), (0x8000202F, 0x0020), (0x80002033, 0x80020728),
+    (0x80002034, 0x8003072A), (0x80002036, 0x8002072D), (0x80002037, 0x8003072F), (0x8000203C, 0x80020732),
+    (0x8000203E, 0x80020734), (0x80002047, 0x80020736), (0x80002048, 0x80020738), (0x80002049, 0x8002073A),
+    (0x80002057, 0x8004073C), (0x8000205F, 0x0020), (0x80002070, 0x0030), (0x80002071, 0x0069),
+    (0x80002074, 0x0034), (0x80002075, 0x0035), (0x80002076, 0x0036), (0x80002077, 0x0037),
+    (0x80002078, 0x0038), (0x80002079, 0x0039), (0x8000207A, 0x002B), (0x8000207B, 0x2212),
+    (0x8000207C, 0x003D), (0x8000207D, 0x0028), (0x8000207E, 0x0029), (0x8000207F, 0x006E),
+    (0x80002080, 0x0030), (0x80002081, 0x0031), (0x80002082, 0x0032), (0x80002083, 0x0033),
+    (0x80002084, 0x0034), (0x80002085, 0x0035), (0x80002086, 0x0036), (0x80002087, 0x0037),
+    (0x80002088, 0x0038), (0x80002089, 0x0039), (0x8000208A, 0x002B), (0x8000208B, 0x2212),
+    (0x8000208C, 0x003D), (0x8000208D, 0x0028), (0x8000208E, 0x0029), (0x80002090, 0x0061),
+    (0x80002091, 0x0065), (0x80002092, 0x006F), (0x80002093, 0x0078), (0x80002094, 0x0259),
+    (0x80002095, 0x0068), (0x80002096, 0x006B), (0x80002097, 0x006C), (0x80002098, 0x006D),
+    (0x80002099, 0x006E), (0x8000209A, 0x0070), (0x8000209B, 0x0073), (0x8000209C, 0x0074),
+    (0x800020A8, 0x80020740), (0x80002100, 0x80030742), (0x80002101, 0x80030745), (0x80002102, 0x0043),
+    (0x80002103, 0x80020748), (0x80002105, 0x8003074A), (0x80002106, 0x8003074D), (0x80002107, 0x0190),
+    (0x80002109, 0x80020750), (0x8000210A, 0x0067), (0x8000210B, 0x0048), (0x8000210C, 0x0048),
+    (0x8000210D, 0x0048), (0x8000210E, 0x0068), (0x8000210F, 0x0127), (0x80002110, 0x0049),
+    (0x80002111, 0x0049), (0x80002112, 0x004C), (0x80002113, 0x006C), (0x80002115, 0x004E),
+    (0x80002116, 0x80020752), (0x80002119, 0x0050), (0x8000211A, 0x0051), (0x8000211B, 0x0052),
+    (0x8000211C, 0x0052), (0x8000211D, 0x0052), (0x80002120, 0x80020754), (0x80002121, 0x80030756),
+    (0x80002122, 0x80020759), (0x80002124, 0x005A), (0x2126, 0x03A9), (0x80002128, 0x005A),
+    (0x212A, 0x004B), (0x212B, 0x00C5), (0x8000212C, 0x0042), (0x8000212D, 0x0043),
+    (0x8000212F, 0x0065), (0x80002130, 0x0045), (0x80002131, 0x0046), (0x80002133, 0x004D),
+    (0x80002134, 0x006F), (0x80002135, 0x05D0), (0x80002136, 0x05D1), (0x80002137, 0x05D2),
+    (0x80002138, 0x05D3), (0x80002139, 0x0069), (0x8000213B, 0x8003075B), (0x8000213C, 0x03C0),
+    (0x8000213D, 0x03B3), (0x8000213E, 0x0393), (0x8000213F, 0x03A0), (0x80002140, 0x2211),
+    (0x80002145, 0x0044), (0x80002146, 0x0064), (0x80002147, 0x0065), (0x80002148, 0x0069),
+    (0x80002149, 0x006A), (0x80002150, 0x8003075E), (0x80002151, 0x80030761), (0x80002152, 0x80040764),
+    (0x80002153, 0x80030768), (0x80002154, 0x8003076B), (0x80002155, 0x8003076E), (0x80002156, 0x80030771),
+    (0x80002157, 0x80030774), (0x80002158, 0x80030777), (0x80002159, 0x8003077A), (0x8000215A, 0x8003077D),
+    (0x8000215B, 0x80030780), (0x8000215C, 0x80030783), (0x8000215D, 0x80030786), (0x8000215E, 0x80030789),
+    (0x8000215F, 0x8002078C), (0x80002160, 0x0049), (0x80002161, 0x8002078E), (0x80002162, 0x80030790),
+    (0x80002163, 0x80020793), (0x80002164, 0x0056), (0x80002165, 0x80020795), (0x80002166, 0x80030797),
+    (0x80002167, 0x8004079A), (0x80002168, 0x8002079E), (0x80002169, 0x0058), (0x8000216A, 0x800207A0),
+    (0x8000216B, 0x800307A2), (0x8000216C, 0x004C), (0x8000216D, 0x0043), (0x8000216E, 0x0044),
+    (0x8000216F, 0x004D), (0x80002170, 0x0069), (0x80002171, 0x800207A5), (0x80002172, 0x800307A7),
+    (0x80002173, 0x800207AA), (0x80002174, 0x0076), (0x80002175, 0x800207AC), (0x80002176, 0x800307AE),
+    (0x80002177, 0x800407B1), (0x80002178, 0x800207B5), (0x80002179, 0x0078), (0x8000217A, 0x800207B7),
+    (0x8000217B, 0x800307B9), (0x8000217C, 0x006C), (0x8000217D, 0x0063), (0x8000217E, 0x0064),
+    (0x8000217F, 0x006D), (0x80002189, 0x800307BC), (0x219A, 0x800207BF), (0x219B, 0x800207C1),
+    (0x21AE, 0x800207C3), (0x21CD, 0x800207C5), (0x21CE, 0x800207C7), (0x21CF, 0x800207C9),
+    (0x2204, 0x800207CB), (0x2209, 0x800207CD), (0x220C, 0x800207CF), (0x2224, 0x800207D1),
+    (0x2226, 0x800207D3), (0x8000222C, 0x800207D5), (0x8000222D, 0x800307D7), (0x8000222F, 0x800207DA),
+    (0x80002230, 0x800307DC), (0x2241, 0x800207DF), (0x2244, 0x800207E1), (0x2247, 0x800207E3),
+    (0x2249, 0x800207E5), (0x2260, 0x800207E7), (0x2262, 0x800207E9), (0x226D, 0x800207EB),
+    (0x226E, 0x800207ED), (0x226F, 0x800207EF), (0x2270, 0x800207F1), (0x2271, 0x800207F3),
+    (0x2274, 0x800207F5), (0x2275, 0x800207F7), (0x2278, 0x800207F9), (0x2279, 0x800207FB),
+    (0x2280, 0x800207FD), (0x2281, 0x800207FF), (0x2284, 0x80020801), (0x2285, 0x80020803),
+    (0x2288, 0x80020805), (0x2289, 0x80020807), (0x22AC, 0x80020809), (0x22AD, 0x8002080B),
+    (0x22AE, 0x8002080D), (0x22AF, 0x8002080F), (0x22E0, 0x80020811), (0x22E1, 0x80020813),
+    (0x22E2, 0x80020815), (0x22E3, 0x80020817), (0x22EA, 0x80020819), (0x22EB, 0x8002081B),
+    (0x22EC, 0x8002081D), (0x22ED, 0x8002081F), (0x2329, 0x3008), (0x232A, 0x3009),
+    (0x80002460, 0x0031), (0x80002461, 0x0032), (0x80002462, 0x0033), (0x80002463, 0x0034),
+    (0x80002464, 0x0035), (0x80002465, 0x0036), (0x80002466, 0x0037), (0x80002467, 0x0038),
+    (0x80002468, 0x0039), (0x80002469, 0x80020821), (0x8000246A, 0x80020823), (0x8000246B, 0x80020825),
+    (0x8000246C, 0x80020827), (0x8000246D, 0x80020829), (0x8000246E, 0x8002082B), (0x8000246F, 0x8002082D),
+    (0x80002470, 0x8002082F), (0x80002471, 0x80020831), (0x80002472, 0x80020833), (0x80002473, 0x80020835),
+    (0x80002474, 0x80030837), (0x80002475, 0x8003083A), (0x80002476, 0x8003083D), (0x80002477, 0x80030840),
+    (0x80002478, 0x80030843), (0x80002479, 0x80030846), (0x8000247A, 0x80030849), (0x8000247B, 0x8003084C),
+    (0x8000247C, 0x8003084F), (0x8000247D, 0x80040852), (0x8000247E, 0x80040856), (0x8000247F, 0x8004085A),
+    (0x80002480, 0x8004085E), (0x80002481, 0x80040862), (0x80002482, 0x80040866), (0x80002483, 0x8004086A),
+    (0x80002484, 0x8004086E), (0x80002485, 0x80040872), (0x80002486, 0x80040876), (0x80002487, 0x8004087A),
+    (0x80002488, 0x8002087E), (0x80002489, 0x80020880), (0x8000248A, 0x80020882), (0x8000248B, 0x80020884),
+    (0x8000248C, 0x80020886), (0x8000248D, 0x80020888), (0x8000248E, 0x8002088A), (0x8000248F, 0x8002088C),
+    (0x80002490, 0x8002088E), (0x80002491, 0x80030890), (0x80002492, 0x80030893), (0x80002493, 0x80030896),
+    (0x80002494, 0x80030899), (0x80002495, 0x8003089C), (0x80002496, 0x8003089F), (0x80002497, 0x800308A2),
+    (0x80002498, 0x800308A5), (0x80002499, 0x800308A8), (0x8000249A, 0x800308AB), (0x8000249B, 0x800308AE),
+    (0x8000249C, 0x800308B1), (0x8000249D, 0x800308B4), (0x8000249E, 0x800308B7), (0x8000249F, 0x800308BA),
+    (0x800024A0, 0x800308BD), (0x800024A1, 0x800308C0), (0x800024A2, 0x800308C3), (0x800024A3, 0x800308C6),
+    (0x800024A4, 0x800308C9), (0x800024A5, 0x800308CC), (0x800024A6, 0x800308CF), (0x800024A7, 0x800308D2),
+    (0x800024A8, 0x800308D5), (0x800024A9, 0x800308D8), (0x800024AA, 0x800308DB), (0x800024AB, 0x800308DE),
+    (0x800024AC, 0x800308E1), (0x800024AD, 0x800308E4), (0x800024AE, 0x800308E7), (0x800024AF, 0x800308EA),
+    (0x800024B0, 0x800308ED), (0x800024B1, 0x800308F0), (0x800024B2, 0x800308F3), (0x800024B3, 0x800308F6),
+    (0x800024B4, 0x800308F9), (0x800024B5, 0x800308FC), (0x800024B6, 0x0041), (0x800024B7, 0x0042),
+    (0x800024B8, 0x0043), (0x800024B9, 0x0044), (0x800024BA, 0x0045), (0x800024BB, 0x0046),
+    (0x800024BC, 0x0047), (0x800024BD, 0x0048), (0x800024BE, 0x0049), (0x800024BF, 0x004A),
+    (0x800024C0, 0x004B), (0x800024C1, 0x004C), (0x800024C2, 0x004D), (0x800024C3, 0x004E),
+    (0x800024C4, 0x004F), (0x800024C5, 0x0050), (0x800024C6, 0x0051), (0x800024C7, 0x0052),
+    (0x800024C8, 0x0053), (0x800024C9, 0x0054), (0x800024CA, 0x0055), (0x800024CB, 0x0056),
+    (0x800024CC, 0x0057), (0x800024CD, 0x0058), (0x800024CE, 0x0059), (0x800024CF, 0x005A),
+    (0x800024D0, 0x0061), (0x800024D1, 0x0062), (0x800024D2, 0x0063), (0x800024D3, 0x0064),
+    (0x800024D4, 0x0065), (0x800024D5, 0x0066), (0x800024D6, 0x0067), (0x800024D7, 0x0068),
+    (0x800024D8, 0x0069), (0x800024D9, 0x006A), (0x800024DA, 0x006B), (0x800024DB, 0x006C),
+    (0x800024DC, 0x006D), (0x800024DD, 0x006E), (0x800024DE, 0x006F), (0x800024DF, 0x0070),
+    (0x800024E0, 0x0071), (0x800024E1, 0x0072), (0x800024E2, 0x0073), (0x800024E3, 0x0074),
+    (0x800024E4, 0x0075), (0x800024E5, 0x0076), (0x800024E6, 0x0077), (0x800024E7, 0x0078),
+    (0x800024E8, 0x0079), (0x800024E9, 0x007A), (0x800024EA, 0x0030), (0x80002A0C, 0x800408FF),
+    (0x80002A74, 0x80030903), (0x80002A75, 0x80020906), (0x80002A76, 0x80030908), (0x2ADC, 0x8002090B),
+    (0x80002C7C, 0x006A), (0x80002C7D, 0x0056), (0x80002D6F, 0x2D61), (0x80002E9F, 0x6BCD),
+    (0x80002EF3, 0x9F9F), (0x80002F00, 0x4E00), (0x80002F01, 0x4E28), (0x80002F02, 0x4E36),
+    (0x80002F03, 0x4E3F), (0x80002F04, 0x4E59), (0x80002F05, 0x4E85), (0x80002F06, 0x4E8C),
+    (0x80002F07, 0x4EA0), (0x80002F08, 0x4EBA), (0x80002F09, 0x513F), (0x80002F0A, 0x5165),
+    (0x80002F0B, 0x516B), (0x80002F0C, 0x5182), (0x80002F0D, 0x5196), (0x80002F0E, 0x51AB),
+    (0x80002F0F, 0x51E0), (0x80002F10, 0x51F5), (0x80002F11, 0x5200), (0x80002F12, 0x529B),
+    (0x80002F13, 0x52F9), (0x80002F14, 0x5315), (0x80002F15, 0x531A), (0x80002F16, 0x5338),
+    (0x80002F17, 0x5341), (0x80002F18, 0x535C), (0x80002F19, 0x5369), (0x80002F1A, 0x5382),
+    (0x80002F1B, 0x53B6), (0x80002F1C, 0x53C8), (0x80002F1D, 0x53E3), (0x80002F1E, 0x56D7),
+    (0x80002F1F, 0x571F), (0x80002F20, 0x58EB), (0x80002F21, 0x5902), (0x80002F22, 0x590A),
+    (0x80002F23, 0x5915), (0x80002F24, 0x5927), (0x80002F25, 0x5973), (0x80002F26, 0x5B50),
+    (0x80002F27, 0x5B80), (0x80002F28, 0x5BF8), (0x80002F29, 0x5C0F), (0x80002F2A, 0x5C22),
+    (0x80002F2B, 0x5C38), (0x80002F2C, 0x5C6E), (0x80002F2D, 0x5C71), (0x80002F2E, 0x5DDB),
+    (0x80002F2F, 0x5DE5), (0x80002F30, 0x5DF1), (0x80002F31, 0x5DFE), (0x80002F32, 0x5E72),
+    (0x80002F33, 0x5E7A), (0x80002F34, 0x5E7F), (0x80002F35, 0x5EF4), (0x80002F36, 0x5EFE),
+    (0x80002F37, 0x5F0B), (0x80002F38, 0x5F13), (0x80002F39, 0x5F50), (0x80002F3A, 0x5F61),
+    (0x80002F3B, 0x5F73), (0x80002F3C, 0x5FC3), (0x80002F3D, 0x6208), (0x80002F3E, 0x6236),
+    (0x80002F3F, 0x624B), (0x80002F40, 0x652F), (0x80002F41, 0x6534), (0x80002F42, 0x6587),
+    (0x80002F43, 0x6597), (0x80002F44, 0x65A4), (0x80002F45, 0x65B9), (0x80002F46, 0x65E0),
+    (0x80002F47, 0x65E5), (0x80002F48, 0x66F0), (0x80002F49, 0x6708), (0x80002F4A, 0x6728),
+    (0x80002F4B, 0x6B20), (0x80002F4C, 0x6B62), (0x80002F4D, 0x6B79), (0x80002F4E, 0x6BB3),
+    (0x80002F4F, 0x6BCB), (0x80002F50, 0x6BD4), (0x80002F51, 0x6BDB), (0x80002F52, 0x6C0F),
+    (0x80002F53, 0x6C14), (0x80002F54, 0x6C34), (0x80002F55, 0x706B), (0x80002F56, 0x722A),
+    (0x80002F57, 0x7236), (0x80002F58, 0x723B), (0x80002F59, 0x723F), (0x80002F5A, 0x7247),
+    (0x80002F5B, 0x7259), (0x80002F5C, 0x725B), (0x80002F5D, 0x72AC), (0x80002F5E, 0x7384),
+    (0x80002F5F, 0x7389), (0x80002F60, 0x74DC), (0x80002F61, 0x74E6), (0x80002F62, 0x7518),
+    (0x80002F63, 0x751F), (0x80002F64, 0x7528), (0x80002F65, 0x7530), (0x80002F66, 0x758B),
+    (0x80002F67, 0x7592), (0x80002F68, 0x7676), (0x80002F69, 0x767D), (0x80002F6A, 0x76AE),
+    (0x80002F6B, 0x76BF), (0x80002F6C, 0x76EE), (0x80002F6D, 0x77DB), (0x80002F6E, 0x77E2),
+    (0x80002F6F, 0x77F3), (0x80002F70, 0x793A), (0x80002F71, 0x79B8), (0x80002F72, 0x79BE),
+    (0x80002F73, 0x7A74), (0x80002F74, 0x7ACB), (0x80002F75, 0x7AF9), (0x80002F76, 0x7C73),
+    (0x80002F77, 0x7CF8), (0x80002F78, 0x7F36), (0x80002F79, 0x7F51), (0x80002F7A, 0x7F8A),
+    (0x80002F7B, 0x7FBD), (0x80002F7C, 0x8001), (0x80002F7D, 0x800C), (0x80002F7E, 0x8012),
+    (0x80002F7F, 0x8033), (0x80002F80, 0x807F), (0x80002F81, 0x8089), (0x80002F82, 0x81E3),
+    (0x80002F83, 0x81EA), (0x80002F84, 0x81F3), (0x80002F85, 0x81FC), (0x80002F86, 0x820C),
+    (0x80002F87, 0x821B), (0x80002F88, 0x821F), (0x80002F89, 0x826E), (0x80002F8A, 0x8272),
+    (0x80002F8B, 0x8278), (0x80002F8C, 0x864D), (0x80002F8D, 0x866B), (0x80002F8E, 0x8840),
+    (0x80002F8F, 0x884C), (0x80002F90, 0x8863), (0x80002F91, 0x897E), (0x80002F92, 0x898B),
+    (0x80002F93, 0x89D2), (0x80002F94, 0x8A00), (0x80002F95, 0x8C37), (0x80002F96, 0x8C46),
+    (0x80002F97, 0x8C55), (0x80002F98, 0x8C78), (0x80002F99, 0x8C9D), (0x80002F9A, 0x8D64),
+    (0x80002F9B, 0x8D70), (0x80002F9C, 0x8DB3), (0x80002F9D, 0x8EAB), (0x80002F9E, 0x8ECA),
+    (0x80002F9F, 0x8F9B), (0x80002FA0, 0x8FB0), (0x80002FA1, 0x8FB5), (0x80002FA2, 0x9091),
+    (0x80002FA3, 0x9149), (0x80002FA4, 0x91C6), (0x80002FA5, 0x91CC), (0x80002FA6, 0x91D1),
+    (0x80002FA7, 0x9577), (0x80002FA8, 0x9580), (0x80002FA9, 0x961C), (0x80002FAA, 0x96B6),
+    (0x80002FAB, 0x96B9), (0x80002FAC, 0x96E8), (0x80002FAD, 0x9751), (0x80002FAE, 0x975E),
+    (0x80002FAF, 0x9762), (0x80002FB0, 0x9769), (0x80002FB1, 0x97CB), (0x80002FB2, 0x97ED),
+    (0x80002FB3, 0x97F3), (0x80002FB4, 0x9801), (0x80002FB5, 0x98A8), (0x80002FB6, 0x98DB),
+    (0x80002FB7, 0x98DF), (0x80002FB8, 0x9996), (0x80002FB9, 0x9999), (0x80002FBA, 0x99AC),
+    (0x80002FBB, 0x9AA8), (0x80002FBC, 0x9AD8), (0x80002FBD, 0x9ADF), (0x80002FBE, 0x9B25),
+    (0x80002FBF, 0x9B2F), (0x80002FC0, 0x9B32), (0x80002FC1, 0x9B3C), (0x80002FC2, 0x9B5A),
+    (0x80002FC3, 0x9CE5), (0x80002FC4, 0x9E75), (0x80002FC5, 0x9E7F), (0x80002FC6, 0x9EA5),
+    (0x80002FC7, 0x9EBB), (0x80002FC8, 0x9EC3), (0x80002FC9, 0x9ECD), (0x80002FCA, 0x9ED1),
+    (0x80002FCB, 0x9EF9), (0x80002FCC, 0x9EFD), (0x80002FCD, 0x9F0E), (0x80002FCE, 0x9F13),
+    (0x80002FCF, 0x9F20), (0x80002FD0, 0x9F3B), (0x80002FD1, 0x9F4A), (0x80002FD2, 0x9F52),
+    (0x80002FD3, 0x9F8D), (0x80002FD4, 0x9F9C), (0x80002FD5, 0x9FA0), (0x80003000, 0x0020),
+    (0x80003036, 0x3012), (0x80003038, 0x5341), (0x80003039, 0x5344), (0x8000303A, 0x5345),
+    (0x304C, 0x8002090D), (0x304E, 0x8002090F), (0x3050, 0x80020911), (0x3052, 0x80020913),
+    (0x3054, 0x80020915), (0x3056, 0x80020917), (0x3058, 0x80020919), (0x305A, 0x8002091B),
+    (0x305C, 0x8002091D), (0x305E, 0x8002091F), (0x3060, 0x80020921), (0x3062, 0x80020923),
+    (0x3065, 0x80020925), (0x3067, 0x80020927), (0x3069, 0x80020929), (0x3070, 0x8002092B),
+    (0x3071, 0x8002092D), (0x3073, 0x8002092F), (0x3074, 0x80020931), (0x3076, 0x80020933),
+    (0x3077, 0x80020935), (0x3079, 0x80020937), (0x307A, 0x80020939), (0x307C, 0x8002093B),
+    (0x307D, 0x8002093D), (0x3094, 0x8002093F), (0x8000309B, 0x80020941), (0x8000309C, 0x80020943),
+    (0x309E, 0x80020945), (0x8000309F, 0x80020947), (0x30AC, 0x80020949), (0x30AE, 0x8002094B),
+    (0x30B0, 0x8002094D), (0x30B2, 0x8002094F), (0x30B4, 0x80020951), (0x30B6, 0x80020953),
+    (0x30B8, 0x80020955), (0x30BA, 0x80020957), (0x30BC, 0x80020959), (0x30BE, 0x8002095B),
+    (0x30C0, 0x8002095D), (0x30C2, 0x8002095F), (0x30C5, 0x80020961), (0x30C7, 0x80020963),
+    (0x30C9, 0x80020965), (0x30D0, 0x80020967), (0x30D1, 0x80020969), (0x30D3, 0x8002096B),
+    (0x30D4, 0x8002096D), (0x30D6, 0x8002096F), (0x30D7, 0x80020971), (0x30D9, 0x80020973),
+    (0x30DA, 0x80020975), (0x30DC, 0x80020977), (0x30DD, 0x80020979), (0x30F4, 0x8002097B),
+    (0x30F7, 0x8002097D), (0x30F8, 0x8002097F), (0x30F9, 0x80020981), (0x30FA, 0x80020983),
+    (0x30FE, 0x80020985), (0x800030FF, 0x80020987), (0x80003131, 0x1100), (0x80003132, 0x1101),
+    (0x80003133, 0x11AA), (0x80003134, 0x1102), (0x80003135, 0x11AC), (0x80003136, 0x11AD),
+    (0x80003137, 0x1103), (0x80003138, 0x1104), (0x80003139, 0x1105), (0x8000313A, 0x11B0),
+    (0x8000313B, 0x11B1), (0x8000313C, 0x11B2), (0x8000313D, 0x11B3), (0x8000313E, 0x11B4),
+    (0x8000313F, 0x11B5), (0x80003140, 0x111A), (0x80003141, 0x1106), (0x80003142, 0x1107),
+    (0x80003143, 0x1108), (0x80003144, 0x1121), (0x80003145, 0x1109), (0x80003146, 0x110A),
+    (0x80003147, 0x110B), (0x80003148, 0x110C), (0x80003149, 0x110D), (0x8000314A, 0x110E),
+    (0x8000314B, 0x110F), (0x8000314C, 0x1110), (0x8000314D, 0x1111), (0x8000314E, 0x1112),
+    (0x8000314F, 0x1161), (0x80003150, 0x1162), (0x80003151, 0x1163), (0x80003152, 0x1164),
+    (0x80003153, 0x1165), (0x80003154, 0x1166), (0x80003155, 0x1167), (0x80003156, 0x1168),
+    (0x80003157, 0x1169), (0x80003158, 0x116A), (0x80003159, 0x116B), (0x8000315A, 0x116C),
+    (0x8000315B, 0x116D), (0x8000315C, 0x116E), (0x8000315D, 0x116F), (0x8000315E, 0x1170),
+    (0x8000315F, 0x1171), (0x80003160, 0x1172), (0x80003161, 0x1173), (0x80003162, 0x1174),
+    (0x80003163, 0x1175), (0x80003164, 0x1160), (0x80003165, 0x1114), (0x80003166, 0x1115),
+    (0x80003167, 0x11C7), (0x80003168, 0x11C8), (0x80003169, 0x11CC), (0x8000316A, 0x11CE),
+    (0x8000316B, 0x11D3), (0x8000316C, 0x11D7), (0x8000316D, 0x11D9), (0x8000316E, 0x111C),
+    (0x8000316F, 0x11DD), (0x80003170, 0x11DF), (0x80003171, 0x111D), (0x80003172, 0x111E),
+    (0x80003173, 0x1120), (0x80003174, 0x1122), (0x80003175, 0x1123), (0x80003176, 0x1127),
+    (0x80003177, 0x1129), (0x80003178, 0x112B), (0x80003179, 0x112C), (0x8000317A, 0x112D),
+    (0x8000317B, 0x112E), (0x8000317C, 0x112F), (0x8000317D, 0x1132), (0x8000317E, 0x1136),
+    (0x8000317F, 0x1140), (0x80003180, 0x1147), (0x80003181, 0x114C), (0x80003182, 0x11F1),
+    (0x80003183, 0x11F2), (0x80003184, 0x1157), (0x80003185, 0x1158), (0x80003186, 0x1159),
+    (0x80003187, 0x1184), (0x80003188, 0x1185), (0x80003189, 0x1188), (0x8000318A, 0x1191),
+    (0x8000318B, 0x1192), (0x8000318C, 0x1194), (0x8000318D, 0x119E), (0x8000318E, 0x11A1),
+    (0x80003192, 0x4E00), (0x80003193, 0x4E8C), (0x80003194, 0x4E09), (0x80003195, 0x56DB),
+    (0x80003196, 0x4E0A), (0x80003197, 0x4E2D), (0x80003198, 0x4E0B), (0x80003199, 0x7532),
+    (0x8000319A, 0x4E59), (0x8000319B, 0x4E19), (0x8000319C, 0x4E01), (0x8000319D, 0x5929),
+    (0x8000319E, 0x5730), (0x8000319F, 0x4EBA), (0x80003200, 0x80030989), (0x80003201, 0x8003098C),
+    (0x80003202, 0x8003098F), (0x80003203, 0x80030992), (0x80003204, 0x80030995), (0x80003205, 0x80030998),
+    (0x80003206, 0x8003099B), (0x80003207, 0x8003099E), (0x80003208, 0x800309A1), (0x80003209, 0x800309A4),
+    (0x8000320A, 0x800309A7), (0x8000320B, 0x800309AA), (0x8000320C, 0x800309AD), (0x8000320D, 0x800309B0),
+    (0x8000320E, 0x800409B3), (0x8000320F, 0x800409B7), (0x80003210, 0x800409BB), (0x80003211, 0x800409BF),
+    (0x80003212, 0x800409C3), (0x80003213, 0x800409C7), (0x80003214, 0x800409CB), (0x80003215, 0x800409CF),
+    (0x80003216, 0x800409D3), (0x80003217, 0x800409D7), (0x80003218, 0x800409DB), (0x80003219, 0x800409DF),
+    (0x8000321A, 0x800409E3), (0x8000321B, 0x800409E7), (0x8000321C, 0x800409EB), (0x8000321D, 0x800709EF),
+    (0x8000321E, 0x800609F6), (0x80003220, 0x800309FC), (0x80003221, 0x800309FF), (0x80003222, 0x80030A02),
+    (0x80003223, 0x80030A05), (0x80003224, 0x80030A08), (0x80003225, 0x80030A0B), (0x80003226, 0x80030A0E),
+    (0x80003227, 0x80030A11), (0x80003228, 0x80030A14), (0x80003229, 0x80030A17), (0x8000322A, 0x80030A1A),
+    (0x8000322B, 0x80030A1D), (0x8000322C, 0x80030A20), (0x8000322D, 0x80030A23), (0x8000322E, 0x80030A26),
+    (0x8000322F, 0x80030A29), (0x80003230, 0x80030A2C), (0x80003231, 0x80030A2F), (0x80003232, 0x80030A32),
+    (0x80003233, 0x80030A35), (0x80003234, 0x80030A38), (0x80003235, 0x80030A3B), (0x80003236, 0x80030A3E),
+    (0x80003237, 0x80030A41), (0x80003238, 0x80030A44), (0x80003239, 0x80030A47), (0x8000323A, 0x80030A4A),
+    (0x8000323B, 0x80030A4D), (0x8000323C, 0x80030A50), (0x8000323D, 0x80030A53), (0x8000323E, 0x80030A56),
+    (0x8000323F, 0x80030A59), (0x80003240, 0x80030A5C), (0x80003241, 0x80030A5F), (0x80003242, 0x80030A62),
+    (0x80003243, 0x80030A65), (0x80003244, 0x554F), (0x80003245, 0x5E7C), (0x80003246, 0x6587),
+    (0x80003247, 0x7B8F), (0x80003250, 0x80030A68), (0x80003251, 0x80020A6B), (0x80003252, 0x80020A6D),
+    (0x80003253, 0x80020A6F), (0x80003254, 0x80020A71), (0x80003255, 0x80020A73), (0x80003256, 0x80020A75),
+    (0x80003257, 0x80020A77), (0x80003258, 0x80020A79), (0x80003259, 0x80020A7B), (0x8000325A, 0x80020A7D),
+    (0x8000325B, 0x80020A7F), (0x8000325C, 0x80020A81), (0x8000325D, 0x80020A83), (0x8000325E, 0x80020A85),
+    (0x8000325F, 0x80020A87), (0x80003260, 0x1100), (0x80003261, 0x1102), (0x80003262, 0x1103),
+    (0x80003263, 0x1105), (0x80003264, 0x1106), (0x80003265, 0x1107), (0x80003266, 0x1109),
+    (0x80003267, 0x110B), (0x80003268, 0x110C), (0x80003269, 0x110E), (0x8000326A, 0x110F),
+    (0x8000326B, 0x1110), (0x8000326C, 0x1111), (0x8000326D, 0x1112), (0x8000326E, 0x80020A89),
+    (0x8000326F, 0x80020A8B), (0x80003270, 0x80020A8D), (0x80003271, 0x80020A8F), (0x80003272, 0x80020A91),
+    (0x80003273, 0x80020A93), (0x80003274, 0x80020A95), (0x80003275, 0x80020A97), (0x80003276, 0x80020A99),
+    (0x80003277, 0x80020A9B), (0x80003278, 0x80020A9D), (0x80003279, 0x80020A9F), (0x8000327A, 0x80020AA1),
+    (0x8000327B, 0x80020AA3), (0x8000327C, 0x80050AA5), (0x8000327D, 0x80040AAA), (0x8000327E, 0x80020AAE),
+    (0x80003280, 0x4E00), (0x80003281, 0x4E8C), (0x80003282, 0x4E09), (0x80003283, 0x56DB),
+    (0x80003284, 0x4E94), (0x80003285, 0x516D), (0x80003286, 0x4E03), (0x80003287, 0x516B),
+    (0x80003288, 0x4E5D), (0x80003289, 0x5341), (0x8000328A, 0x6708), (0x8000328B, 0x706B),
+    (0x8000328C, 0x6C34), (0x8000328D, 0x6728), (0x8000328E, 0x91D1), (0x8000328F, 0x571F),
+    (0x80003290, 0x65E5), (0x80003291, 0x682A), (0x80003292, 0x6709), (0x80003293, 0x793E),
+    (0x80003294, 0x540D), (0x80003295, 0x7279), (0x80003296, 0x8CA1), (0x80003297, 0x795D),
+    (0x80003298, 0x52B4), (0x80003299, 0x79D8), (0x8000329A, 0x7537), (0x8000329B, 0x5973),
+    (0x8000329C, 0x9069), (0x8000329D, 0x512A), (0x8000329E, 0x5370), (0x8000329F, 0x6CE8),
+    (0x800032A0, 0x9805), (0x800032A1, 0x4F11), (0x800032A2, 0x5199), (0x800032A3, 0x6B63),
+    (0x800032A4, 0x4E0A), (0x800032A5, 0x4E2D), (0x800032A6, 0x4E0B), (0x800032A7, 0x5DE6),
+    (0x800032A8, 0x53F3), (0x800032A9, 0x533B), (0x800032AA, 0x5B97), (0x800032AB, 0x5B66),
+    (0x800032AC, 0x76E3), (0x800032AD, 0x4F01), (0x800032AE, 0x8CC7), (0x800032AF, 0x5354),
+    (0x800032B0, 0x591C), (0x800032B1, 0x80020AB0), (0x800032B2, 0x80020AB2), (0x800032B3, 0x80020AB4),
+    (0x800032B4, 0x80020AB6), (0x800032B5, 0x80020AB8), (0x800032B6, 0x80020ABA), (0x800032B7, 0x80020ABC),
+    (0x800032B8, 0x80020ABE), (0x800032B9, 0x80020AC0), (0x800032BA, 0x80020AC2), (0x800032BB, 0x80020AC4),
+    (0x800032BC, 0x80020AC6), (0x800032BD, 0x80020AC8), (0x800032BE, 0x80020ACA), (0x800032BF, 0x80020ACC),
+    (0x800032C0, 0x80020ACE), (0x800032C1, 0x80020AD0), (0x800032C2, 0x80020AD2), (0x800032C3, 0x80020AD4),
+    (0x800032C4, 0x80020AD6), (0x800032C5, 0x80020AD8), (0x800032C6, 0x80020ADA), (0x800032C7, 0x80020ADC),
+    (0x800032C8, 0x80020ADE), (0x800032C9, 0x80030AE0), (0x800032CA, 0x80030AE3), (0x800032CB, 0x80030AE6),
+    (0x800032CC, 0x80020AE9), (0x800032CD, 0x80030AEB), (0x800032CE, 0x80020AEE), (0x800032CF, 0x80030AF0),
+    (0x800032D0, 0x30A2), (0x800032D1, 0x30A4), (0x800032D2, 0x30A6), (0x800032D3, 0x30A8),
+    (0x800032D4, 0x30AA), (0x800032D5, 0x30AB), (0x800032D6, 0x30AD), (0x800032D7, 0x30AF),
+    (0x800032D8, 0x30B1), (0x800032D9, 0x30B3), (0x800032DA, 0x30B5), (0x800032DB, 0x30B7),
+    (0x800032DC, 0x30B9), (0x800032DD, 0x30BB), (0x800032DE, 0x30BD), (0x800032DF, 0x30BF),
+    (0x800032E0, 0x30C1), (0x800032E1, 0x30C4), (0x800032E2, 0x30C6), (0x800032E3, 0x30C8),
+    (0x800032E4, 0x30CA), (0x800032E5, 0x30CB), (0x800032E6, 0x30CC), (0x800032E7, 0x30CD),
+    (0x800032E8, 0x30CE), (0x800032E9, 0x30CF), (0x800032EA, 0x30D2), (0x800032EB, 0x30D5),
+    (0x800032EC, 0x30D8), (0x800032ED, 0x30DB), (0x800032EE, 0x30DE), (0x800032EF, 0x30DF),
+    (0x800032F0, 0x30E0), (0x800032F1, 0x30E1), (0x800032F2, 0x30E2), (0x800032F3, 0x30E4),
+    (0x800032F4, 0x30E6), (0x800032F5, 0x30E8), (0x800032F6, 0x30E9), (0x800032F7, 0x30EA),
+    (0x800032F8, 0x30EB), (0x800032F9, 0x30EC), (0x800032FA, 0x30ED), (0x800032FB, 0x30EF),
+    (0x800032FC, 0x30F0), (0x800032FD, 0x30F1), (0x800032FE, 0x30F2), (0x800032FF, 0x80020AF3),
+    (0x80003300, 0x80040AF5), (0x80003301, 0x80040AF9), (0x80003302, 0x80040AFD), (0x80003303, 0x80030B01),
+    (0x80003304, 0x80040B04), (0x80003305, 0x80030B08), (0x80003306, 0x80030B0B), (0x80003307, 0x80050B0E),
+    (0x80003308, 0x80040B13), (0x80003309, 0x80030B17), (0x8000330A, 0x80030B1A), (0x8000330B, 0x80030B1D),
+    (0x8000330C, 0x80040B20), (0x8000330D, 0x80040B24), (0x8000330E, 0x80030B28), (0x8000330F, 0x80030B2B),
+    (0x80003310, 0x80020B2E), (0x80003311, 0x80030B30), (0x80003312, 0x80040B33), (0x80003313, 0x80040B37),
+    (0x80003314, 0x80020B3B), (0x80003315, 0x80050B3D), (0x80003316, 0x80060B42), (0x80003317, 0x80050B48),
+    (0x80003318, 0x80030B4D), (0x80003319, 0x80050B50), (0x8000331A, 0x80050B55), (0x8000331B, 0x80040B5A),
+    (0x8000331C, 0x80030B5E), (0x8000331D, 0x80030B61), (0x8000331E, 0x80030B64), (0x8000331F, 0x80040B67),
+    (0x80003320, 0x80050B6B), (0x80003321, 0x80040B70), (0x80003322, 0x80030B74), (0x80003323, 0x80030B77),
+    (0x80003324, 0x80030B7A), (0x80003325, 0x80020B7D), (0x80003326, 0x80020B7F), (0x80003327, 0x80020B81),
+    (0x80003328, 0x80020B83), (0x80003329, 0x80030B85), (0x8000332A, 0x80030B88), (0x8000332B, 0x80050B8B),
+    (0x8000332C, 0x80030B90), (0x8000332D, 0x80040B93), (0x8000332E, 0x80050B97), (0x8000332F, 0x80030B9C),
+    (0x80003330, 0x80020B9F), (0x80003331, 0x80020BA1), (0x80003332, 0x80050BA3), (0x80003333, 0x80040BA8),
+    (0x80003334, 0x80050BAC), (0x80003335, 0x80030BB1), (0x80003336, 0x80050BB4), (0x80003337, 0x80020BB9),
+    (0x80003338, 0x80030BBB), (0x80003339, 0x80030BBE), (0x8000333A, 0x80030BC1), (0x8000333B, 0x80030BC4),
+    (0x8000333C, 0x80030BC7), (0x8000333D, 0x80040BCA), (0x8000333E, 0x80030BCE), (0x8000333F, 0x80020BD1),
+    (0x80003340, 0x80030BD3), (0x80003341, 0x80030BD6), (0x80003342, 0x80030BD9), (0x80003343, 0x80040BDC),
+    (0x80003344, 0x80030BE0), (0x80003345, 0x80030BE3), (0x80003346, 0x80030BE6), (0x80003347, 0x80050BE9),
+    (0x80003348, 0x80040BEE), (0x80003349, 0x80020BF2), (0x8000334A, 0x80050BF4), (0x8000334B, 0x80020BF9),
+    (0x8000334C, 0x80040BFB), (0x8000334D, 0x80040BFF), (0x8000334E, 0x80030C03), (0x8000334F, 0x80030C06),
+    (0x80003350, 0x80030C09), (0x80003351, 0x80040C0C), (0x80003352, 0x80020C10), (0x80003353, 0x80030C12),
+    (0x80003354, 0x80040C15), (0x80003355, 0x80020C19), (0x80003356, 0x80050C1B), (0x80003357, 0x80030C20),
+    (0x80003358, 0x80020C23), (0x80003359, 0x80020C25), (0x8000335A, 0x80020C27), (0x8000335B, 0x80020C29),
+    (0x8000335C, 0x80020C2B), (0x8000335D, 0x80020C2D), (0x8000335E, 0x80020C2F), (0x8000335F, 0x80020C31),
+    (0x80003360, 0x80020C33), (0x80003361, 0x80020C35), (0x80003362, 0x80030C37), (0x80003363, 0x80030C3A),
+    (0x80003364, 0x80030C3D), (0x80003365, 0x80030C40), (0x80003366, 0x80030C43), (0x80003367, 0x80030C46),
+    (0x80003368, 0x80030C49), (0x80003369, 0x80030C4C), (0x8000336A, 0x80030C4F), (0x8000336B, 0x80030C52),
+    (0x8000336C, 0x80030C55), (0x8000336D, 0x80030C58), (0x8000336E, 0x80030C5B), (0x8000336F, 0x80030C5E),
+    (0x80003370, 0x80030C61), (0x80003371, 0x80030C64), (0x80003372, 0x80020C67), (0x80003373, 0x80020C69),
+    (0x80003374, 0x80030C6B), (0x80003375, 0x80020C6E), (0x80003376, 0x80020C70), (0x80003377, 0x80020C72),
+    (0x80003378, 0x80030C74), (0x80003379, 0x80030C77), (0x8000337A, 0x80020C7A), (0x8000337B, 0x80020C7C),
+    (0x8000337C, 0x80020C7E), (0x8000337D, 0x80020C80), (0x8000337E, 0x80020C82), (0x8000337F, 0x80040C84),
+    (0x80003380, 0x80020C88), (0x80003381, 0x80020C8A), (0x80003382, 0x80020C8C), (0x80003383, 0x80020C8E),
+    (0x80003384, 0x80020C90), (0x80003385, 0x80020C92), (0x80003386, 0x80020C94), (0x80003387, 0x80020C96),
+    (0x80003388, 0x80030C98), (0x80003389, 0x80040C9B), (0x8000338A, 0x80020C9F), (0x8000338B, 0x80020CA1),
+    (0x8000338C, 0x80020CA3), (0x8000338D, 0x80020CA5), (0x8000338E, 0x80020CA7), (0x8000338F, 0x80020CA9),
+    (0x80003390, 0x80020CAB), (0x80003391, 0x80030CAD), (0x80003392, 0x80030CB0), (0x80003393, 0x80030CB3),
+    (0x80003394, 0x80030CB6), (0x80003395, 0x80020CB9), (0x80003396, 0x80020CBB), (0x80003397, 0x80020CBD),
+    (0x80003398, 0x80020CBF), (0x80003399, 0x80020CC1), (0x8000339A, 0x80020CC3), (0x8000339B, 0x80020CC5),
+    (0x8000339C, 0x80020CC7), (0x8000339D, 0x80020CC9), (0x8000339E, 0x80020CCB), (0x8000339F, 0x80030CCD),
+    (0x800033A0, 0x80030CD0), (0x800033A1, 0x80020CD3), (0x800033A2, 0x80030CD5), (0x800033A3, 0x80030CD8),
+    (0x800033A4, 0x80030CDB), (0x800033A5, 0x80020CDE), (0x800033A6, 0x80030CE0), (0x800033A7, 0x80030CE3),
+    (0x800033A8, 0x80040CE6), (0x800033A9, 0x80020CEA), (0x800033AA, 0x80030CEC), (0x800033AB, 0x80030CEF),
+    (0x800033AC, 0x80030CF2), (0x800033AD, 0x80030CF5), (0x800033AE, 0x80050CF8), (0x800033AF, 0x80060CFD),
+    (0x800033B0, 0x80020D03), (0x800033B1, 0x80020D05), (0x800033B2, 0x80020D07), (0x800033B3, 0x80020D09),
+    (0x800033B4, 0x80020D0B), (0x800033B5, 0x80020D0D), (0x800033B6, 0x80020D0F), (0x800033B7, 0x80020D11),
+    (0x800033B8, 0x80020D13), (0x800033B9, 0x80020D15), (0x800033BA, 0x80020D17), (0x800033BB, 0x80020D19),
+    (0x800033BC, 0x80020D1B), (0x800033BD, 0x80020D1D), (0x800033BE, 0x80020D1F), (0x800033BF, 0x80020D21),
+    (0x800033C0, 0x80020D23), (0x800033C1, 0x80020D25), (0x800033C2, 0x80040D27), (0x800033C3, 0x80020D2B),
+    (0x800033C4, 0x80020D2D), (0x800033C5, 0x80020D2F), (0x800033C6, 0x80040D31), (0x800033C7, 0x80030D35),
+    (0x800033C8, 0x80020D38), (0x800033C9, 0x80020D3A), (0x800033CA, 0x80020D3C), (0x800033CB, 0x80020D3E),
+    (0x800033CC, 0x80020D40), (0x800033CD, 0x80020D42), (0x800033CE, 0x80020D44), (0x800033CF, 0x80020D46),
+    (0x800033D0, 0x80020D48), (0x800033D1, 0x80020D4A), (0x800033D2, 0x80030D4C), (0x800033D3, 0x80020D4F),
+    (0x800033D4, 0x80020D51), (0x800033D5, 0x80030D53), (0x800033D6, 0x80030D56), (0x800033D7, 0x80020D59),
+    (0x800033D8, 0x80040D5B), (0x800033D9, 0x80030D5F), (0x800033DA, 0x80020D62), (0x800033DB, 0x80020D64),
+    (0x800033DC, 0x80020D66), (0x800033DD, 0x80020D68), (0x800033DE, 0x80030D6A), (0x800033DF, 0x80030D6D),
+    (0x800033E0, 0x80020D70), (0x800033E1, 0x80020D72), (0x800033E2, 0x80020D74), (0x800033E3, 0x80020D76),
+    (0x800033E4, 0x80020D78), (0x800033E5, 0x80020D7A), (0x800033E6, 0x80020D7C), (0x800033E7, 0x80020D7E),
+    (0x800033E8, 0x80020D80), (0x800033E9, 0x80030D82), (0x800033EA, 0x80030D85), (0x800033EB, 0x80030D88),
+    (0x800033EC, 0x80030D8B), (0x800033ED, 0x80030D8E), (0x800033EE, 0x80030D91), (0x800033EF, 0x80030D94),
+    (0x800033F0, 0x80030D97), (0x800033F1, 0x80030D9A), (0x800033F2, 0x80030D9D), (0x800033F3, 0x80030DA0),
+    (0x800033F4, 0x80030DA3), (0x800033F5, 0x80030DA6), (0x800033F6, 0x80030DA9), (0x800033F7, 0x80030DAC),
+    (0x800033F8, 0x80030DAF), (0x800033F9, 0x80030DB2), (0x800033FA, 0x80030DB5), (0x800033FB, 0x80030DB8),
+    (0x800033FC, 0x80030DBB), (0x800033FD, 0x80030DBE), (0x800033FE, 0x80030DC1), (0x800033FF, 0x80030DC4),
+    (0x8000A69C, 0x044A), (0x8000A69D, 0x044C), (0x8000A770, 0xA76F), (0x8000A7F1, 0x0053),
+    (0x8000A7F2, 0x0043), (0x8000A7F3, 0x0046), (0x8000A7F4, 0x0051), (0x8000A7F8, 0x0126),
+    (0x8000A7F9, 0x0153), (0x8000AB5C, 0xA727), (0x8000AB5D, 0xAB37), (0x8000AB5E, 0x026B),
+    (0x8000AB5F, 0xAB52), (0x8000AB69, 0x028D), (0xF900, 0x8C48), (0xF901, 0x66F4),
+    (0xF902, 0x8ECA), (0xF903, 0x8CC8), (0xF904, 0x6ED1), (0xF905, 0x4E32),
+    (0xF906, 0x53E5), (0xF907, 0x9F9C), (0xF908, 0x9F9C), (0xF909, 0x5951),
+    (0xF90A, 0x91D1), (0xF90B, 0x5587), (0xF90C, 0x5948), (0xF90D, 0x61F6),
+    (0xF90E, 0x7669), (0xF90F, 0x7F85), (0xF910, 0x863F), (0xF911, 0x87BA),
+    (0xF912, 0x88F8), (0xF913, 0x908F), (0xF914, 0x6A02), (0xF915, 0x6D1B),
+    (0xF916, 0x70D9), (0xF917, 0x73DE), (0xF918, 0x843D), (0xF919, 0x916A),
+    (0xF91A, 0x99F1), (0xF91B, 0x4E82), (0xF91C, 0x5375), (0xF91D, 0x6B04),
+    (0xF91E, 0x721B), (0xF91F, 0x862D), (0xF920, 0x9E1E), (0xF921, 0x5D50),
+    (0xF922, 0x6FEB), (0xF923, 0x85CD), (0xF924, 0x8964), (0xF925, 0x62C9),
+    (0xF926, 0x81D8), (0xF927, 0x881F), (0xF928, 0x5ECA), (0xF929, 0x6717),
+    (0xF92A, 0x6D6A), (0xF92B, 0x72FC), (0xF92C, 0x90CE), (0xF92D, 0x4F86),
+    (0xF92E, 0x51B7), (0xF92F, 0x52DE), (0xF930, 0x64C4), (0xF931, 0x6AD3),
+    (0xF932, 0x7210), (0xF933, 0x76E7), (0xF934, 0x8001), (0xF935, 0x8606),
+    (0xF936, 0x865C), (0xF937, 0x8DEF), (0xF938, 0x9732), (0xF939, 0x9B6F),
+    (0xF93A, 0x9DFA), (0xF93B, 0x788C), (0xF93C, 0x797F), (0xF93D, 0x7DA0),
+    (0xF93E, 0x83C9), (0xF93F, 0x9304), (0xF940, 0x9E7F), (0xF941, 0x8AD6),
+    (0xF942, 0x58DF), (0xF943, 0x5F04), (0xF944, 0x7C60), (0xF945, 0x807E),
+    (0xF946, 0x7262), (0xF947, 0x78CA), (0xF948, 0x8CC2), (0xF949, 0x96F7),
+    (0xF94A, 0x58D8), (0xF94B, 0x5C62), (0xF94C, 0x6A13), (0xF94D, 0x6DDA),
+    (0xF94E, 0x6F0F), (0xF94F, 0x7D2F), (0xF950, 0x7E37), (0xF951, 0x964B),
+    (0xF952, 0x52D2), (0xF953, 0x808B), (0xF954, 0x51DC), (0xF955, 0x51CC),
+    (0xF956, 0x7A1C), (0xF957, 0x7DBE), (0xF958, 0x83F1), (0xF959, 0x9675),
+    (0xF95A, 0x8B80), (0xF95B, 0x62CF), (0xF95C, 0x6A02), (0xF95D, 0x8AFE),
+    (0xF95E, 0x4E39), (0xF95F, 0x5BE7), (0xF960, 0x6012), (0xF961, 0x7387),
+    (0xF962, 0x7570), (0xF963, 0x5317), (0xF964, 0x78FB), (0xF965, 0x4FBF),
+    (0xF966, 0x5FA9), (0xF967, 0x4E0D), (0xF968, 0x6CCC), (0xF969, 0x6578),
+    (0xF96A, 0x7D22), (0xF96B, 0x53C3), (0xF96C, 0x585E), (0xF96D, 0x7701),
+    (0xF96E, 0x8449), (0xF96F, 0x8AAA), (0xF970, 0x6BBA), (0xF971, 0x8FB0),
+    (0xF972, 0x6C88), (0xF973, 0x62FE), (0xF974, 0x82E5), (0xF975, 0x63A0),
+    (0xF976, 0x7565), (0xF977, 0x4EAE), (0xF978, 0x5169), (0xF979, 0x51C9),
+    (0xF97A, 0x6881), (0xF97B, 0x7CE7), (0xF97C, 0x826F), (0xF97D, 0x8AD2),
+    (0xF97E, 0x91CF), (0xF97F, 0x52F5), (0xF980, 0x5442), (0xF981, 0x5973),
+    (0xF982, 0x5EEC), (0xF983, 0x65C5), (0xF984, 0x6FFE), (0xF985, 0x792A),
+    (0xF986, 0x95AD), (0xF987, 0x9A6A), (0xF988, 0x9E97), (0xF989, 0x9ECE),
+    (0xF98A, 0x529B), (0xF98B, 0x66C6), (0xF98C, 0x6B77), (0xF98D, 0x8F62),
+    (0xF98E, 0x5E74), (0xF98F, 0x6190), (0xF990, 0x6200), (0xF991, 0x649A),
+    (0xF992, 0x6F23), (0xF993, 0x7149), (0xF994, 0x7489), (0xF995, 0x79CA),
+    (0xF996, 0x7DF4), (0xF997, 0x806F), (0xF998, 0x8F26), (0xF999, 0x84EE),
+    (0xF99A, 0x9023), (0xF99B, 0x934A), (0xF99C, 0x5217), (0xF99D, 0x52A3),
+    (0xF99E, 0x54BD), (0xF99F, 0x70C8), (0xF9A0, 0x88C2), (0xF9A1, 0x8AAA),
+    (0xF9A2, 0x5EC9), (0xF9A3, 0x5FF5), (0xF9A4, 0x637B), (0xF9A5, 0x6BAE),
+    (0xF9A6, 0x7C3E), (0xF9A7, 0x7375), (0xF9A8, 0x4EE4), (0xF9A9, 0x56F9),
+    (0xF9AA, 0x5BE7), (0xF9AB, 0x5DBA), (0xF9AC, 0x601C), (0xF9AD, 0x73B2),
+    (0xF9AE, 0x7469), (0xF9AF, 0x7F9A), (0xF9B0, 0x8046), (0xF9B1, 0x9234),
+    (0xF9B2, 0x96F6), (0xF9B3, 0x9748), (0xF9B4, 0x9818), (0xF9B5, 0x4F8B),
+    (0xF9B6, 0x79AE), (0xF9B7, 0x91B4), (0xF9B8, 0x96B8), (0xF9B9, 0x60E1),
+    (0xF9BA, 0x4E86), (0xF9BB, 0x50DA), (0xF9BC, 0x5BEE), (0xF9BD, 0x5C3F),
+    (0xF9BE, 0x6599), (0xF9BF, 0x6A02), (0xF9C0, 0x71CE), (0xF9C1, 0x7642),
+    (0xF9C2, 0x84FC), (0xF9C3, 0x907C), (0xF9C4, 0x9F8D), (0xF9C5, 0x6688),
+    (0xF9C6, 0x962E), (0xF9C7, 0x5289), (0xF9C8, 0x677B), (0xF9C9, 0x67F3),
+    (0xF9CA, 0x6D41), (0xF9CB, 0x6E9C), (0xF9CC, 0x7409), (0xF9CD, 0x7559),
+    (0xF9CE, 0x786B), (0xF9CF, 0x7D10), (0xF9D0, 0x985E), (0xF9D1, 0x516D),
+    (0xF9D2, 0x622E), (0xF9D3, 0x9678), (0xF9D4, 0x502B), (0xF9D5, 0x5D19),
+    (0xF9D6, 0x6DEA), (0xF9D7, 0x8F2A), (0xF9D8, 0x5F8B), (0xF9D9, 0x6144),
+    (0xF9DA, 0x6817), (0xF9DB, 0x7387), (0xF9DC, 0x9686), (0xF9DD, 0x5229),
+    (0xF9DE, 0x540F), (0xF9DF, 0x5C65), (0xF9E0, 0x6613), (0xF9E1, 0x674E),
+    (0xF9E2, 0x68A8), (0xF9E3, 0x6CE5), (0xF9E4, 0x7406), (0xF9E5, 0x75E2),
+    (0xF9E6, 0x7F79), (0xF9E7, 0x88CF), (0xF9E8, 0x88E1), (0xF9E9, 0x91CC),
+    (0xF9EA, 0x96E2), (0xF9EB, 0x533F), (0xF9EC, 0x6EBA), (0xF9ED, 0x541D),
+    (0xF9EE, 0x71D0), (0xF9EF, 0x7498), (0xF9F0, 0x85FA), (0xF9F1, 0x96A3),
+    (0xF9F2, 0x9C57), (0xF9F3, 0x9E9F), (0xF9F4, 0x6797), (0xF9F5, 0x6DCB),
+    (0xF9F6, 0x81E8), (0xF9F7, 0x7ACB), (0xF9F8, 0x7B20), (0xF9F9, 0x7C92),
+    (0xF9FA, 0x72C0), (0xF9FB, 0x7099), (0xF9FC, 0x8B58), (0xF9FD, 0x4EC0),
+    (0xF9FE, 0x8336), (0xF9FF, 0x523A), (0xFA00, 0x5207), (0xFA01, 0x5EA6),
+    (0xFA02, 0x62D3), (0xFA03, 0x7CD6), (0xFA04, 0x5B85), (0xFA05, 0x6D1E),
+    (0xFA06, 0x66B4), (0xFA07, 0x8F3B), (0xFA08, 0x884C), (0xFA09, 0x964D),
+    (0xFA0A, 0x898B), (0xFA0B, 0x5ED3), (0xFA0C, 0x5140), (0xFA0D, 0x55C0),
+    (0xFA10, 0x585A), (0xFA12, 0x6674), (0xFA15, 0x51DE), (0xFA16, 0x732A),
+    (0xFA17, 0x76CA), (0xFA18, 0x793C), (0xFA19, 0x795E), (0xFA1A, 0x7965),
+    (0xFA1B, 0x798F), (0xFA1C, 0x9756), (0xFA1D, 0x7CBE), (0xFA1E, 0x7FBD),
+    (0xFA20, 0x8612), (0xFA22, 0x8AF8), (0xFA25, 0x9038), (0xFA26, 0x90FD),
+    (0xFA2A, 0x98EF), (0xFA2B, 0x98FC), (0xFA2C, 0x9928), (0xFA2D, 0x9DB4),
+    (0xFA2E, 0x90DE), (0xFA2F, 0x96B7), (0xFA30, 0x4FAE), (0xFA31, 0x50E7),
+    (0xFA32, 0x514D), (0xFA33, 0x52C9), (0xFA34, 0x52E4), (0xFA35, 0x5351),
+    (0xFA36, 0x559D), (0xFA37, 0x5606), (0xFA38, 0x5668), (0xFA39, 0x5840),
+    (0xFA3A, 0x58A8), (0xFA3B, 0x5C64), (0xFA3C, 0x5C6E), (0xFA3D, 0x6094),
+    (0xFA3E, 0x6168), (0xFA3F, 0x618E), (0xFA40, 0x61F2), (0xFA41, 0x654F),
+    (0xFA42, 0x65E2), (0xFA43, 0x6691), (0xFA44, 0x6885), (0xFA45, 0x6D77),
+    (0xFA46, 0x6E1A), (0xFA47, 0x6F22), (0xFA48, 0x716E), (0xFA49, 0x722B),
+    (0xFA4A, 0x7422), (0xFA4B, 0x7891), (0xFA4C, 0x793E), (0xFA4D, 0x7949),
+    (0xFA4E, 0x7948), (0xFA4F, 0x7950), (0xFA50, 0x7956), (0xFA51, 0x795D),
+    (0xFA52, 0x798D), (0xFA53, 0x798E), (0xFA54, 0x7A40), (0xFA55, 0x7A81),
+    (0xFA56, 0x7BC0), (0xFA57, 0x7DF4), (0xFA58, 0x7E09), (0xFA59, 0x7E41),
+    (0xFA5A, 0x7F72), (0xFA5B, 0x8005), (0xFA5C, 0x81ED), (0xFA5D, 0x8279),
+    (0xFA5E, 0x8279), (0xFA5F, 0x8457), (0xFA60, 0x8910), (0xFA61, 0x8996),
+    (0xFA62, 0x8B01), (0xFA63, 0x8B39), (0xFA64, 0x8CD3), (0xFA65, 0x8D08),
+    (0xFA66, 0x8FB6), (0xFA67, 0x9038), (0xFA68, 0x96E3), (0xFA69, 0x97FF),
+    (0xFA6A, 0x983B), (0xFA6B, 0x6075), (0xFA6C, 0x242EE), (0xFA6D, 0x8218),
+    (0xFA70, 0x4E26), (0xFA71, 0x51B5), (0xFA72, 0x5168), (0xFA73, 0x4F80),
+    (0xFA74, 0x5145), (0xFA75, 0x5180), (0xFA76, 0x52C7), (0xFA77, 0x52FA),
+    (0xFA78, 0x559D), (0xFA79, 0x5555), (0xFA7A, 0x5599), (0xFA7B, 0x55E2),
+    (0xFA7C, 0x585A), (0xFA7D, 0x58B3), (0xFA7E, 0x5944), (0xFA7F, 0x5954),
+    (0xFA80, 0x5A62), (0xFA81, 0x5B28), (0xFA82, 0x5ED2), (0xFA83, 0x5ED9),
+    (0xFA84, 0x5F69), (0xFA85, 0x5FAD), (0xFA86, 0x60D8), (0xFA87, 0x614E),
+    (0xFA88, 0x6108), (0xFA89, 0x618E), (0xFA8A, 0x6160), (0xFA8B, 0x61F2),
+    (0xFA8C, 0x6234), (0xFA8D, 0x63C4), (0xFA8E, 0x641C), (0xFA8F, 0x6452),
+    (0xFA90, 0x6556), (0xFA91, 0x6674), (0xFA92, 0x6717), (0xFA93, 0x671B),
+    (0xFA94, 0x6756), (0xFA95, 0x6B79), (0xFA96, 0x6BBA), (0xFA97, 0x6D41),
+    (0xFA98, 0x6EDB), (0xFA99, 0x6ECB), (0xFA9A, 0x6F22), (0xFA9B, 0x701E),
+    (0xFA9C, 0x716E), (0xFA9D, 0x77A7), (0xFA9E, 0x7235), (0xFA9F, 0x72AF),
+    (0xFAA0, 0x732A), (0xFAA1, 0x7471), (0xFAA2, 0x7506), (0xFAA3, 0x753B),
+    (0xFAA4, 0x761D), (0xFAA5, 0x761F), (0xFAA6, 0x76CA), (0xFAA7, 0x76DB),
+    (0xFAA8, 0x76F4), (0xFAA9, 0x774A), (0xFAAA, 0x7740), (0xFAAB, 0x78CC),
+    (0xFAAC, 0x7AB1), (0xFAAD, 0x7BC0), (0xFAAE, 0x7C7B), (0xFAAF, 0x7D5B),
+    (0xFAB0, 0x7DF4), (0xFAB1, 0x7F3E), (0xFAB2, 0x8005), (0xFAB3, 0x8352),
+    (0xFAB4, 0x83EF), (0xFAB5, 0x8779), (0xFAB6, 0x8941), (0xFAB7, 0x8986),
+    (0xFAB8, 0x8996), (0xFAB9, 0x8ABF), (0xFABA, 0x8AF8), (0xFABB, 0x8ACB),
+    (0xFABC, 0x8B01), (0xFABD, 0x8AFE), (0xFABE, 0x8AED), (0xFABF, 0x8B39),
+    (0xFAC0, 0x8B8A), (0xFAC1, 0x8D08), (0xFAC2, 0x8F38), (0xFAC3, 0x9072),
+    (0xFAC4, 0x9199), (0xFAC5, 0x9276), (0xFAC6, 0x967C), (0xFAC7, 0x96E3),
+    (0xFAC8, 0x9756), (0xFAC9, 0x97DB), (0xFACA, 0x97FF), (0xFACB, 0x980B),
+    (0xFACC, 0x983B), (0xFACD, 0x9B12), (0xFACE, 0x9F9C), (0xFACF, 0x2284A),
+    (0xFAD0, 0x22844), (0xFAD1, 0x233D5), (0xFAD2, 0x3B9D), (0xFAD3, 0x4018),
+    (0xFAD4, 0x4039), (0xFAD5, 0x25249), (0xFAD6, 0x25CD0), (0xFAD7, 0x27ED3),
+    (0xFAD8, 0x9F43), (0xFAD9, 0x9F8E), (0x8000FB00, 0x80020DC7), (0x8000FB01, 0x80020DC9),
+    (0x8000FB02, 0x80020DCB), (0x8000FB03, 0x80030DCD), (0x8000FB04, 0x80030DD0), (0x8000FB05, 0x80020DD3),
+    (0x8000FB06, 0x80020DD5), (0x8000FB13, 0x80020DD7), (0x8000FB14, 0x80020DD9), (0x8000FB15, 0x80020DDB),
+    (0x8000FB16, 0x80020DDD), (0x8000FB17, 0x80020DDF), (0xFB1D, 0x80020DE1), (0xFB1F, 0x80020DE3),
+    (0x8000FB20, 0x05E2), (0x8000FB21, 0x05D0), (0x8000FB22, 0x05D3), (0x8000FB23, 0x05D4),
+    (0x8000FB24, 0x05DB), (0x8000FB25, 0x05DC), (0x8000FB26, 0x05DD), (0x8000FB27, 0x05E8),
+    (0x8000FB28, 0x05EA), (0x8000FB29, 0x002B), (0xFB2A, 0x80020DE5), (0xFB2B, 0x80020DE7),
+    (0xFB2C, 0x80020DE9), (0xFB2D, 0x80020DEB), (0xFB2E, 0x80020DED), (0xFB2F, 0x80020DEF),
+    (0xFB30, 0x80020DF1), (0xFB31, 0x80020DF3), (0xFB32, 0x80020DF5), (0xFB33, 0x80020DF7),
+    (0xFB34, 0x80020DF9), (0xFB35, 0x80020DFB), (0xFB36, 0x80020DFD), (0xFB38, 0x80020DFF),
+    (0xFB39, 0x80020E01), (0xFB3A, 0x80020E03), (0xFB3B, 0x80020E05), (0xFB3C, 0x80020E07),
+    (0xFB3E, 0x80020E09), (0xFB40, 0x80020E0B), (0xFB41, 0x80020E0D), (0xFB43, 0x80020E0F),
+    (0xFB44, 0x80020E11), (0xFB46, 0x80020E13), (0xFB47, 0x80020E15), (0xFB48, 0x80020E17),
+    (0xFB49, 0x80020E19), (0xFB4A, 0x80020E1B), (0xFB4B, 0x80020E1D), (0xFB4C, 0x80020E1F),
+    (0xFB4D, 0x80020E21), (0xFB4E, 0x80020E23), (0x8000FB4F, 0x80020E25), (0x8000FB50, 0x0671),
+    (0x8000FB51, 0x0671), (0x8000FB52, 0x067B), (0x8000FB53, 0x067B), (0x8000FB54, 0x067B),
+    (0x8000FB55, 0x067B), (0x8000FB56, 0x067E), (0x8000FB57, 0x067E), (0x8000FB58, 0x067E),
+    (0x8000FB59, 0x067E), (0x8000FB5A, 0x0680), (0x8000FB5B, 0x0680), (0x8000FB5C, 0x0680),
+    (0x8000FB5D, 0x0680), (0x8000FB5E, 0x067A), (0x8000FB5F, 0x067A), (0x8000FB60, 0x067A),
+    (0x8000FB61, 0x067A), (0x8000FB62, 0x067F), (0x8000FB63, 0x067F), (0x8000FB64, 0x067F),
+    (0x8000FB65, 0x067F), (0x8000FB66, 0x0679), (0x8000FB67, 0x0679), (0x8000FB68, 0x0679),
+    (0x8000FB69, 0x0679), (0x8000FB6A, 0x06A4), (0x8000FB6B, 0x06A4), (0x8000FB6C, 0x06A4),
+    (0x8000FB6D, 0x06A4), (0x8000FB6E, 0x06A6), (0x8000FB6F, 0x06A6), (0x8000FB70, 0x06A6),
+    (0x8000FB71, 0x06A6), (0x8000FB72, 0x0684), (0x8000FB73, 0x0684), (0x8000FB74, 0x0684),
+    (0x8000FB75, 0x0684), (0x8000FB76, 0x0683), (0x8000FB77, 0x0683), (0x8000FB78, 0x0683),
+    (0x8000FB79, 0x0683), (0x8000FB7A, 0x0686), (0x8000FB7B, 0x0686), (0x8000FB7C, 0x0686),
+    (0x8000FB7D, 0x0686), (0x8000FB7E, 0x0687), (0x8000FB7F, 0x0687), (0x8000FB80, 0x0687),
+    (0x8000FB81, 0x0687), (0x8000FB82, 0x068D), (0x8000FB83, 0x068D), (0x8000FB84, 0x068C),
+    (0x8000FB85, 0x068C), (0x8000FB86, 0x068E), (0x8000FB87, 0x068E), (0x8000FB88, 0x0688),
+    (0x8000FB89, 0x0688), (0x8000FB8A, 0x0698), (0x8000FB8B, 0x0698), (0x8000FB8C, 0x0691),
+    (0x8000FB8D, 0x0691), (0x8000FB8E, 0x06A9), (0x8000FB8F, 0x06A9), (0x8000FB90, 0x06A9),
+    (0x8000FB91, 0x06A9), (0x8000FB92, 0x06AF), (0x8000FB93, 0x06AF), (0x8000FB94, 0x06AF),
+    (0x8000FB95, 0x06AF), (0x8000FB96, 0x06B3), (0x8000FB97, 0x06B3), (0x8000FB98, 0x06B3),
+    (0x8000FB99, 0x06B3), (0x8000FB9A, 0x06B1), (0x8000FB9B, 0x06B1), (0x8000FB9C, 0x06B1),
+    (0x8000FB9D, 0x06B1), (0x8000FB9E, 0x06BA), (0x8000FB9F, 0x06BA), (0x8000FBA0, 0x06BB),
+    (0x8000FBA1, 0x06BB), (0x8000FBA2, 0x06BB), (0x8000FBA3, 0x06BB), (0x8000FBA4, 0x06C0),
+    (0x8000FBA5, 0x06C0), (0x8000FBA6, 0x06C1), (0x8000FBA7, 0x06C1), (0x8000FBA8, 0x06C1),
+    (0x8000FBA9, 0x06C1), (0x8000FBAA, 0x06BE), (0x8000FBAB, 0x06BE), (0x8000FBAC, 0x06BE),
+    (0x8000FBAD, 0x06BE), (0x8000FBAE, 0x06D2), (0x8000FBAF, 0x06D2), (0x8000FBB0, 0x06D3),
+    (0x8000FBB1, 0x06D3), (0x8000FBD3, 0x06AD), (0x8000FBD4, 0x06AD), (0x8000FBD5, 0x06AD),
+    (0x8000FBD6, 0x06AD), (0x8000FBD7, 0x06C7), (0x8000FBD8, 0x06C7), (0x8000FBD9, 0x06C6),
+    (0x8000FBDA, 0x06C6), (0x8000FBDB, 0x06C8), (0x8000FBDC, 0x06C8), (0x8000FBDD, 0x0677),
+    (0x8000FBDE, 0x06CB), (0x8000FBDF, 0x06CB), (0x8000FBE0, 0x06C5), (0x8000FBE1, 0x06C5),
+    (0x8000FBE2, 0x06C9), (0x8000FBE3, 0x06C9), (0x8000FBE4, 0x06D0), (0x8000FBE5, 0x06D0),
+    (0x8000FBE6, 0x06D0), (0x8000FBE7, 0x06D0), (0x8000FBE8, 0x0649), (0x8000FBE9, 0x0649),
+    (0x8000FBEA, 0x80020E27), (0x8000FBEB, 0x80020E29), (0x8000FBEC, 0x80020E2B), (0x8000FBED, 0x80020E2D),
+    (0x8000FBEE, 0x80020E2F), (0x8000FBEF, 0x80020E31), (0x8000FBF0, 0x80020E33), (0x8000FBF1, 0x80020E35),
+    (0x8000FBF2, 0x80020E37), (0x8000FBF3, 0x80020E39), (0x8000FBF4, 0x80020E3B), (0x8000FBF5, 0x80020E3D),
+    (0x8000FBF6, 0x80020E3F), (0x8000FBF7, 0x80020E41), (0x8000FBF8, 0x80020E43), (0x8000FBF9, 0x80020E45),
+    (0x8000FBFA, 0x80020E47), (0x8000FBFB, 0x80020E49), (0x8000FBFC, 0x06CC), (0x8000FBFD, 0x06CC),
+    (0x8000FBFE, 0x06CC), (0x8000FBFF, 0x06CC), (0x8000FC00, 0x80020E4B), (0x8000FC01, 0x80020E4D),
+    (0x8000FC02, 0x80020E4F), (0x8000FC03, 0x80020E51), (0x8000FC04, 0x80020E53), (0x8000FC05, 0x80020E55),
+    (0x8000FC06, 0x80020E57), (0x8000FC07, 0x80020E59), (0x8000FC08, 0x80020E5B), (0x8000FC09, 0x80020E5D),
+    (0x8000FC0A, 0x80020E5F), (0x8000FC0B, 0x80020E61), (0x8000FC0C, 0x80020E63), (0x8000FC0D, 0x80020E65),
+    (0x8000FC0E, 0x80020E67), (0x8000FC0F, 0x80020E69), (0x8000FC10, 0x80020E6B), (0x8000FC11, 0x80020E6D),
+    (0x8000FC12, 0x80020E6F), (0x8000FC13, 0x80020E71), (0x8000FC14, 0x80020E73), (0x8000FC15, 0x80020E75),
+    (0x8000FC16, 0x80020E77), (0x8000FC17, 0x80020E79), (0x8000FC18, 0x80020E7B), (0x8000FC19, 0x80020E7D),
+    (0x8000FC1A, 0x80020E7F), (0x8000FC1B, 0x80020E81), (0x8000FC1C, 0x80020E83), (0x8000FC1D, 0x80020E85),
+    (0x8000FC1E, 0x80020E87), (0x8000FC1F, 0x80020E89), (0x8000FC20, 0x80020E8B), (0x8000FC21, 0x80020E8D),
+    (0x8000FC22, 0x80020E8F), (0x8000FC23, 0x80020E91), (0x8000FC24, 0x80020E93), (0x8000FC25, 0x80020E95),
+    (0x8000FC26, 0x80020E97), (0x8000FC27, 0x80020E99), (0x8000FC28, 0x80020E9B), (0x8000FC29, 0x80020E9D),
+    (0x8000FC2A, 0x80020E9F), (0x8000FC2B, 0x80020EA1), (0x8000FC2C, 0x80020EA3), (0x8000FC2D, 0x80020EA5),
+    (0x8000FC2E, 0x80020EA7), (0x8000FC2F, 0x80020EA9), (0x8000FC30, 0x80020EAB), (0x8000FC31, 0x80020EAD),
+    (0x8000FC32, 0x80020EAF), (0x8000FC33, 0x80020EB1), (0x8000FC34, 0x80020EB3), (0x8000FC35, 0x80020EB5),
+    (0x8000FC36, 0x80020EB7), (0x8000FC37, 0x80020EB9), (0x8000FC38, 0x80020EBB), (0x8000FC39, 0x80020EBD),
+    (0x8000FC3A, 0x80020EBF), (0x8000FC3B, 0x80020EC1), (0x8000FC3C, 0x80020EC3), (0x8000FC3D, 0x80020EC5),
+    (0x8000FC3E, 0x80020EC7), (0x8000FC3F, 0x80020EC9), (0x8000FC40, 0x80020ECB), (0x8000FC41, 0x80020ECD),
+    (0x8000FC42, 0x80020ECF), (0x8000FC43, 0x80020ED1), (0x8000FC44, 0x80020ED3), (0x8000FC45, 0x80020ED5),
+    (0x8000FC46, 0x80020ED7), (0x8000FC47, 0x80020ED9), (0x8000FC48, 0x80020EDB), (0x8000FC49, 0x80020EDD),
+    (0x8000FC4A, 0x80020EDF), (0x8000FC4B, 0x80020EE1), (0x8000FC4C, 0x80020EE3), (0x8000FC4D, 0x80020EE5),
+    (0x8000FC4E, 0x80020EE7), (0x8000FC4F, 0x80020EE9), (0x8000FC50, 0x80020EEB), (0x8000FC51, 0x80020EED),
+    (0x8000FC52, 0x80020EEF), (0x8000FC53, 0x80020EF1), (0x8000FC54, 0x80020EF3), (0x8000FC55, 0x80020EF5),
+    (0x8000FC56, 0x80020EF7), (0x8000FC57, 0x80020EF9), (0x8000FC58, 0x80020EFB), (0x8000FC59, 0x80020EFD),
+    (0x8000FC5A, 0x80020EFF), (0x8000FC5B, 0x80020F01), (0x8000FC5C, 0x80020F03), (0x8000FC5D, 0x80020F05),
+    (0x8000FC5E, 0x80030F07), (0x8000FC5F, 0x80030F0A), (0x8000FC60, 0x80030F0D), (0x8000FC61, 0x80030F10),
+    (0x8000FC62, 0x80030F13), (0x8000FC63, 0x80030F16), (0x8000FC64, 0x80020F19), (0x8000FC65, 0x80020F1B),
+    (0x8000FC66, 0x80020F1D), (0x8000FC67, 0x80020F1F), (0x8000FC68, 0x80020F21), (0x8000FC69, 0x80020F23),
+    (0x8000FC6A, 0x80020F25), (0x8000FC6B, 0x80020F27), (0x8000FC6C, 0x80020F29), (0x8000FC6D, 0x80020F2B),
+    (0x8000FC6E, 0x80020F2D), (0x8000FC6F, 0x80020F2F), (0x8000FC70, 0x80020F31), (0x8000FC71, 0x80020F33),
+    (0x8000FC72, 0x80020F35), (0x8000FC73, 0x80020F37), (0x8000FC74, 0x80020F39), (0x8000FC75, 0x80020F3B),
+    (0x8000FC76, 0x80020F3D), (0x8000FC77, 0x80020F3F), (0x8000FC78, 0x80020F41), (0x8000FC79, 0x80020F43),
+    (0x8000FC7A, 0x80020F45), (0x8000FC7B, 0x80020F47), (0x8000FC7C, 0x80020F49), (0x8000FC7D, 0x80020F4B),
+    (0x8000FC7E, 0x80020F4D), (0x8000FC7F, 0x80020F4F), (0x8000FC80, 0x80020F51), (0x8000FC81, 0x80020F53),
+    (0x8000FC82, 0x80020F55), (0x8000FC83, 0x80020F57), (0x8000FC84, 0x80020F59), (0x8000FC85, 0x80020F5B),
+    (0x8000FC86, 0x80020F5D), (0x8000FC87, 0x80020F5F), (0x8000FC88, 0x80020F61), (0x8000FC89, 0x80020F63),
+    (0x8000FC8A, 0x80020F65), (0x8000FC8B, 0x80020F67), (0x8000FC8C, 0x80020F69), (0x8000FC8D, 0x80020F6B),
+    (0x8000FC8E, 0x80020F6D), (0x8000FC8F, 0x80020F6F), (0x8000FC90, 0x80020F71), (0x8000FC91, 0x80020F73),
+    (0x8000FC92, 0x80020F75), (0x8000FC93, 0x80020F77), (0x8000FC94, 0x80020F79), (0x8000FC95, 0x80020F7B),
+    (0x8000FC96, 0x80020F7D), (0x8000FC97, 0x80020F7F), (0x8000FC98, 0x80020F81), (0x8000FC99, 0x80020F83),
+    (0x8000FC9A, 0x80020F85), (0x8000FC9B, 0x80020F87), (0x8000FC9C, 0x80020F89), (0x8000FC9D, 0x80020F8B),
+    (0x8000FC9E, 0x80020F8D), (0x8000FC9F, 0x80020F8F), (0x8000FCA0, 0x80020F91), (0x8000FCA1, 0x80020F93),
+    (0x8000FCA2, 0x80020F95), (0x8000FCA3, 0x80020F97), (0x8000FCA4, 0x80020F99), (0x8000FCA5, 0x80020F9B),
+    (0x8000FCA6, 0x80020F9D), (0x8000FCA7, 0x80020F9F), (0x8000FCA8, 0x80020FA1), (0x8000FCA9, 0x80020FA3),
+    (0x8000FCAA, 0x80020FA5), (0x8000FCAB, 0x80020FA7), (0x8000FCAC, 0x80020FA9), (0x8000FCAD, 0x80020FAB),
+    (0x8000FCAE, 0x80020FAD), (0x8000FCAF, 0x80020FAF), (0x8000FCB0, 0x80020FB1), (0x8000FCB1, 0x80020FB3),
+    (0x8000FCB2, 0x80020FB5), (0x8000FCB3, 0x80020FB7), (0x8000FCB4, 0x80020FB9), (0x8000FCB5, 0x80020FBB),
+    (0x8000FCB6, 0x80020FBD), (0x8000FCB7, 0x80020FBF), (0x8000FCB8, 0x80020FC1), (0x8000FCB9, 0x80020FC3),
+    (0x8000FCBA, 0x80020FC5), (0x8000FCBB, 0x80020FC7), (0x8000FCBC, 0x80020FC9), (0x8000FCBD, 0x80020FCB),
+    (0x8000FCBE, 0x80020FCD), (0x8000FCBF, 0x80020FCF), (0x8000FCC0, 0x80020FD1), (0x8000FCC1, 0x80020FD3),
+    (0x8000FCC2, 0x80020FD5), (0x8000FCC3, 0x80020FD7), (0x8000FCC4, 0x80020FD9), (0x8000FCC5, 0x80020FDB),
+    (0x8000FCC6, 0x80020FDD), (0x8000FCC7, 0x80020FDF), (0x8000FCC8, 0x80020FE1), (0x8000FCC9, 0x80020FE3),
+    (0x8000FCCA, 0x80020FE5), (0x8000FCCB, 0x80020FE7), (0x8000FCCC, 0x80020FE9), (0x8000FCCD, 0x80020FEB),
+    (0x8000FCCE, 0x80020FED), (0x8000FCCF, 0x80020FEF), (0x8000FCD0, 0x80020FF1), (0x8000FCD1, 0x80020FF3),
+    (0x8000FCD2, 0x80020FF5), (0x8000FCD3, 0x80020FF7), (0x8000FCD4, 0x80020FF9), (0x8000FCD5, 0x80020FFB),
+    (0x8000FCD6, 0x80020FFD), (0x8000FCD7, 0x80020FFF), (0x8000FCD8, 0x80021001), (0x8000FCD9, 0x80021003),
+    (0x8000FCDA, 0x80021005), (0x8000FCDB, 0x80021007), (0x8000FCDC, 0x80021009), (0x8000FCDD, 0x8002100B),
+    (0x8000FCDE, 0x8002100D), (0x8000FCDF, 0x8002100F), (0x8000FCE0, 0x80021011), (0x8000FCE1, 0x80021013),
+    (0x8000FCE2, 0x80021015), (0x8000FCE3, 0x80021017), (0x8000FCE4, 0x80021019), (0x8000FCE5, 0x8002101B),
+    (0x8000FCE6, 0x8002101D), (0x8000FCE7, 0x8002101F), (0x8000FCE8, 0x80021021), (0x8000FCE9, 0x80021023),
+    (0x8000FCEA, 0x80021025), (0x8000FCEB, 0x80021027), (0x8000FCEC, 0x80021029), (0x8000FCED, 0x8002102B),
+    (0x8000FCEE, 0x8002102D), (0x8000FCEF, 0x8002102F), (0x8000FCF0, 0x80021031), (0x8000FCF1, 0x80021033),
+    (0x8000FCF2, 0x80031035), (0x8000FCF3, 0x80031038), (0x8000FCF4, 0x8003103B), (0x8000FCF5, 0x8002103E),
+    (0x8000FCF6, 0x80021040), (0x8000FCF7, 0x80021042), (0x8000FCF8, 0x80021044), (0x8000FCF9, 0x80021046),
+    (0x8000FCFA, 0x80021048), (0x8000FCFB, 0x8002104A), (0x8000FCFC, 0x8002104C), (0x8000FCFD, 0x8002104E),
+    (0x8000FCFE, 0x80021050), (0x8000FCFF, 0x80021052), (0x8000FD00, 0x80021054), (0x8000FD01, 0x80021056),
+    (0x8000FD02, 0x80021058), (0x8000FD03, 0x8002105A), (0x8000FD04, 0x8002105C), (0x8000FD05, 0x8002105E),
+    (0x8000FD06, 0x80021060), (0x8000FD07, 0x80021062), (0x8000FD08, 0x80021064), (0x8000FD09, 0x80021066),
+    (0x8000FD0A, 0x80021068), (0x8000FD0B, 0x8002106A), (0x8000FD0C, 0x8002106C), (0x8000FD0D, 0x8002106E),
+    (0x8000FD0E, 0x80021070), (0x8000FD0F, 0x80021072), (0x8000FD10, 0x80021074), (0x8000FD11, 0x80021076),
+    (0x8000FD12, 0x80021078), (0x8000FD13, 0x8002107A), (0x8000FD14, 0x8002107C), (0x8000FD15, 0x8002107E),
+    (0x8000FD16, 0x80021080), (0x8000FD17, 0x80021082), (0x8000FD18, 0x80021084), (0x8000FD19, 0x80021086),
+    (0x8000FD1A, 0x80021088), (0x8000FD1B, 0x8002108A), (0x8000FD1C, 0x8002108C), (0x8000FD1D, 0x8002108E),
+    (0x8000FD1E, 0x80021090), (0x8000FD1F, 0x80021092), (0x8000FD20, 0x80021094), (0x8000FD21, 0x80021096),
+    (0x8000FD22, 0x80021098), (0x8000FD23, 0x8002109A), (0x8000FD24, 0x8002109C), (0x8000FD25, 0x8002109E),
+    (0x8000FD26, 0x800210A0), (0x8000FD27, 0x800210A2), (0x8000FD28, 0x800210A4), (0x8000FD29, 0x800210A6),
+    (0x8000FD2A, 0x800210A8), (0x8000FD2B, 0x800210AA), (0x8000FD2C, 0x800210AC), (0x8000FD2D, 0x800210AE),
+    (0x8000FD2E, 0x800210B0), (0x8000FD2F, 0x800210B2), (0x8000FD30, 0x800210B4), (0x8000FD31, 0x800210B6),
+    (0x8000FD32, 0x800210B8), (0x8000FD33, 0x800210BA), (0x8000FD34, 0x800210BC), (0x8000FD35, 0x800210BE),
+    (0x8000FD36, 0x800210C0), (0x8000FD37, 0x800210C2), (0x8000FD38, 0x800210C4), (0x8000FD39, 0x800210C6),
+    (0x8000FD3A, 0x800210C8), (0x8000FD3B, 0x800210CA), (0x8000FD3C, 0x800210CC), (0x8000FD3D, 0x800210CE),
+    (0x8000FD50, 0x800310D0), (0x8000FD51, 0x800310D3), (0x8000FD52, 0x800310D6), (0x8000FD53, 0x800310D9),
+    (0x8000FD54, 0x800310DC), (0x8000FD55, 0x800310DF), (0x8000FD56, 0x800310E2), (0x8000FD57, 0x800310E5),
+    (0x8000FD58, 0x800310E8), (0x8000FD59, 0x800310EB), (0x8000FD5A, 0x800310EE), (0x8000FD5B, 0x800310F1),
+    (0x8000FD5C, 0x800310F4), (0x8000FD5D, 0x800310F7), (0x8000FD5E, 0x800310FA), (0x8000FD5F, 0x800310FD),
+    (0x8000FD60, 0x80031100), (0x8000FD61, 0x80031103), (0x8000FD62, 0x80031106), (0x8000FD63, 0x80031109),
+    (0x8000FD64, 0x8003110C), (0x8000FD65, 0x8003110F), (0x8000FD66, 0x80031112), (0x8000FD67, 0x80031115),
+    (0x8000FD68, 0x80031118), (0x8000FD69, 0x8003111B), (0x8000FD6A, 0x8003111E), (0x8000FD6B, 0x80031121),
+    (0x8000FD6C, 0x80031124), (0x8000FD6D, 0x80031127), (0x8000FD6E, 0x8003112A), (0x8000FD6F, 0x8003112D),
+    (0x8000FD70, 0x80031130), (0x8000FD71, 0x80031133), (0x8000FD72, 0x80031136), (0x8000FD73, 0x80031139),
+    (0x8000FD74, 0x8003113C), (0x8000FD75, 0x8003113F), (0x8000FD76, 0x80031142), (0x8000FD77, 0x80031145),
+    (0x8000FD78, 0x80031148), (0x8000FD79, 0x8003114B), (0x8000FD7A, 0x8003114E), (0x8000FD7B, 0x80031151),
+    (0x8000FD7C, 0x80031154), (0x8000FD7D, 0x80031157), (0x8000FD7E, 0x8003115A), (0x8000FD7F, 0x8003115D),
+    (0x8000FD80, 0x80031160), (0x8000FD81, 0x80031163), (0x8000FD82, 0x80031166), (0x8000FD83, 0x80031169),
+    (0x8000FD84, 0x8003116C), (0x8000FD85, 0x8003116F), (0x8000FD86, 0x80031172), (0x8000FD87, 0x80031175),
+    (0x8000FD88, 0x80031178), (0x8000FD89, 0x8003117B), (0x8000FD8A, 0x8003117E), (0x8000FD8B, 0x80031181),
+    (0x8000FD8C, 0x80031184), (0x8000FD8D, 0x80031187), (0x8000FD8E, 0x8003118A), (0x8000FD8F, 0x8003118D),
+    (0x8000FD92, 0x80031190), (0x8000FD93, 0x80031193), (0x8000FD94, 0x80031196), (0x8000FD95, 0x80031199),
+    (0x8000FD96, 0x8003119C), (0x8000FD97, 0x8003119F), (0x8000FD98, 0x800311A2), (0x8000FD99, 0x800311A5),
+    (0x8000FD9A, 0x800311A8), (0x8000FD9B, 0x800311AB), (0x8000FD9C, 0x800311AE), (0x8000FD9D, 0x800311B1),
+    (0x8000FD9E, 0x800311B4), (0x8000FD9F, 0x800311B7), (0x8000FDA0, 0x800311BA), (0x8000FDA1, 0x800311BD),
+    (0x8000FDA2, 0x800311C0), (0x8000FDA3, 0x800311C3), (0x8000FDA4, 0x800311C6), (0x8000FDA5, 0x800311C9),
+    (0x8000FDA6, 0x800311CC), (0x8000FDA7, 0x800311CF), (0x8000FDA8, 0x800311D2), (0x8000FDA9, 0x800311D5),
+    (0x8000FDAA, 0x800311D8), (0x8000FDAB, 0x800311DB), (0x8000FDAC, 0x800311DE), (0x8000FDAD, 0x800311E1),
+    (0x8000FDAE, 0x800311E4), (0x8000FDAF, 0x800311E7), (0x8000FDB0, 0x800311EA), (0x8000FDB1, 0x800311ED),
+    (0x8000FDB2, 0x800311F0), (0x8000FDB3, 0x800311F3), (0x8000FDB4, 0x800311F6), (0x8000FDB5, 0x800311F9),
+    (0x8000FDB6, 0x800311FC), (0x8000FDB7, 0x800311FF), (0x8000FDB8, 0x80031202), (0x8000FDB9, 0x80031205),
+    (0x8000FDBA, 0x80031208), (0x8000FDBB, 0x8003120B), (0x8000FDBC, 0x8003120E), (0x8000FDBD, 0x80031211),
+    (0x8000FDBE, 0x80031214), (0x8000FDBF, 0x80031217), (0x8000FDC0, 0x8003121A), (0x8000FDC1, 0x8003121D),
+    (0x8000FDC2, 0x80031220), (0x8000FDC3, 0x80031223), (0x8000FDC4, 0x80031226), (0x8000FDC5, 0x80031229),
+    (0x8000FDC6, 0x8003122C), (0x8000FDC7, 0x8003122F), (0x8000FDF0, 0x80031232), (0x8000FDF1, 0x80031235),
+    (0x8000FDF2, 0x80041238), (0x8000FDF3, 0x8004123C), (0x8000FDF4, 0x80041240), (0x8000FDF5, 0x80041244),
+    (0x8000FDF6, 0x80041248), (0x8000FDF7, 0x8004124C), (0x8000FDF8, 0x80041250), (0x8000FDF9, 0x80031254),
+    (0x8000FDFA, 0x80121257), (0x8000FDFB, 0x80081269), (0x8000FDFC, 0x80041271), (0x8000FE10, 0x002C),
+    (0x8000FE11, 0x3001), (0x8000FE12, 0x3002), (0x8000FE13, 0x003A), (0x8000FE14, 0x003B),
+    (0x8000FE15, 0x0021), (0x8000FE16, 0x003F), (0x8000FE17, 0x3016), (0x8000FE18, 0x3017),
+    (0x8000FE19, 0x2026), (0x8000FE30, 0x2025), (0x8000FE31, 0x2014), (0x8000FE32, 0x2013),
+    (0x8000FE33, 0x005F), (0x8000FE34, 0x005F), (0x8000FE35, 0x0028), (0x8000FE36, 0x0029),
+    (0x8000FE37, 0x007B), (0x8000FE38, 0x007D), (0x8000FE39, 0x3014), (0x8000FE3A, 0x3015),
+    (0x8000FE3B, 0x3010), (0x8000FE3C, 0x3011), (0x8000FE3D, 0x300A), (0x8000FE3E, 0x300B),
+    (0x8000FE3F, 0x3008), (0x8000FE40, 0x3009), (0x8000FE41, 0x300C), (0x8000FE42, 0x300D),
+    (0x8000FE43, 0x300E), (0x8000FE44, 0x300F), (0x8000FE47, 0x005B), (0x8000FE48, 0x005D),
+    (0x8000FE49, 0x203E), (0x8000FE4A, 0x203E), (0x8000FE4B, 0x203E), (0x8000FE4C, 0x203E),
+    (0x8000FE4D, 0x005F), (0x8000FE4E, 0x005F), (0x8000FE4F, 0x005F), (0x8000FE50, 0x002C),
+    (0x8000FE51, 0x3001), (0x8000FE52, 0x002E), (0x8000FE54, 0x003B), (0x8000FE55, 0x003A),
+    (0x8000FE56, 0x003F), (0x8000FE57, 0x0021), (0x8000FE58, 0x2014), (0x8000FE59, 0x0028),
+    (0x8000FE5A, 0x0029), (0x8000FE5B, 0x007B), (0x8000FE5C, 0x007D), (0x8000FE5D, 0x3014),
+    (0x8000FE5E, 0x3015), (0x8000FE5F, 0x0023), (0x8000FE60, 0x0026), (0x8000FE61, 0x002A),
+    (0x8000FE62, 0x002B), (0x8000FE63, 0x002D), (0x8000FE64, 0x003C), (0x8000FE65, 0x003E),
+    (0x8000FE66, 0x003D), (0x8000FE68, 0x005C), (0x8000FE69, 0x0024), (0x8000FE6A, 0x0025),
+    (0x8000FE6B, 0x0040), (0x8000FE70, 0x80021275), (0x8000FE71, 0x80021277), (0x8000FE72, 0x80021279),
+    (0x8000FE74, 0x8002127B), (0x8000FE76, 0x8002127D), (0x8000FE77, 0x8002127F), (0x8000FE78, 0x80021281),
+    (0x8000FE79, 0x80021283), (0x8000FE7A, 0x80021285), (0x8000FE7B, 0x80021287), (0x8000FE7C, 0x80021289),
+    (0x8000FE7D, 0x8002128B), (0x8000FE7E, 0x8002128D), (0x8000FE7F, 0x8002128F), (0x8000FE80, 0x0621),
+    (0x8000FE81, 0x0622), (0x8000FE82, 0x0622), (0x8000FE83, 0x0623), (0x8000FE84, 0x0623),
+    (0x8000FE85, 0x0624), (0x8000FE86, 0x0624), (0x8000FE87, 0x0625), (0x8000FE88, 0x0625),
+    (0x8000FE89, 0x0626), (0x8000FE8A, 0x0626), (0x8000FE8B, 0x0626), (0x8000FE8C, 0x0626),
+    (0x8000FE8D, 0x0627), (0x8000FE8E, 0x0627), (0x8000FE8F, 0x0628), (0x8000FE90, 0x0628),
+    (0x8000FE91, 0x0628), (0x8000FE92, 0x0628), (0x8000FE93, 0x0629), (0x8000FE94, 0x0629),
+    (0x8000FE95, 0x062A), (0x8000FE96, 0x062A), (0x8000FE97, 0x062A), (0x8000FE98, 0x062A),
+    (0x8000FE99, 0x062B), (0x8000FE9A, 0x062B), (0x8000FE9B, 0x062B), (0x8000FE9C, 0x062B),
+    (0x8000FE9D, 0x062C), (0x8000FE9E, 0x062C), (0x8000FE9F, 0x062C), (0x8000FEA0, 0x062C),
+    (0x8000FEA1, 0x062D), (0x8000FEA2, 0x062D), (0x8000FEA3, 0x062D), (0x8000FEA4, 0x062D),
+    (0x8000FEA5, 0x062E), (0x8000FEA6, 0x062E), (0x8000FEA7, 0x062E), (0x8000FEA8, 0x062E),
+    (0x8000FEA9, 0x062F), (0x8000FEAA, 0x062F), (0x8000FEAB, 0x0630), (0x8000FEAC, 0x0630),
+    (0x8000FEAD, 0x0631), (0x8000FEAE, 0x0631), (0x8000FEAF, 0x0632), (0x8000FEB0, 0x0632),
+    (0x8000FEB1, 0x0633), (0x8000FEB2, 0x0633), (0x8000FEB3, 0x0633), (0x8000FEB4, 0x0633),
+    (0x8000FEB5, 0x0634), (0x8000FEB6, 0x0634), (0x8000FEB7, 0x0634), (0x8000FEB8, 0x0634),
+    (0x8000FEB9, 0x0635), (0x8000FEBA, 0x0635), (0x8000FEBB, 0x0635), (0x8000FEBC, 0x0635),
+    (0x8000FEBD, 0x0636), (0x8000FEBE, 0x0636), (0x8000FEBF, 0x0636), (0x8000FEC0, 0x0636),
+    (0x8000FEC1, 0x0637), (0x8000FEC2, 0x0637), (0x8000FEC3, 0x0637), (0x8000FEC4, 0x0637),
+    (0x8000FEC5, 0x0638), (0x8000FEC6, 0x0638), (0x8000FEC7, 0x0638), (0x8000FEC8, 0x0638),
+    (0x8000FEC9, 0x0639), (0x8000FECA, 0x0639), (0x8000FECB, 0x0639), (0x8000FECC, 0x0639),
+    (0x8000FECD, 0x063A), (0x8000FECE, 0x063A), (0x8000FECF, 0x063A), (0x8000FED0, 0x063A),
+    (0x8000FED1, 0x0641), (0x8000FED2, 0x0641), (0x8000FED3, 0x0641), (0x8000FED4, 0x0641),
+    (0x8000FED5, 0x0642), (0x8000FED6, 0x0642), (0x8000FED7, 0x0642), (0x8000FED8, 0x0642),
+    (0x8000FED9, 0x0643), (0x8000FEDA, 0x0643), (0x8000FEDB, 0x0643), (0x8000FEDC, 0x0643),
+    (0x8000FEDD, 0x0644), (0x8000FEDE, 0x0644), (0x8000FEDF, 0x0644), (0x8000FEE0, 0x0644),
+    (0x8000FEE1, 0x0645), (0x8000FEE2, 0x0645), (0x8000FEE3, 0x0645), (0x8000FEE4, 0x0645),
+    (0x8000FEE5, 0x0646), (0x8000FEE6, 0x0646), (0x8000FEE7, 0x0646), (0x8000FEE8, 0x0646),
+    (0x8000FEE9, 0x0647), (0x8000FEEA, 0x0647), (0x8000FEEB, 0x0647), (0x8000FEEC, 0x0647),
+    (0x8000FEED, 0x0648), (0x8000FEEE, 0x0648), (0x8000FEEF, 0x0649), (0x8000FEF0, 0x0649),
+    (0x8000FEF1, 0x064A), (0x8000FEF2, 0x064A), (0x8000FEF3, 0x064A), (0x8000FEF4, 0x064A),
+    (0x8000FEF5, 0x80021291), (0x8000FEF6, 0x80021293), (0x8000FEF7, 0x80021295), (0x8000FEF8, 0x80021297),
+    (0x8000FEF9, 0x80021299), (0x8000FEFA, 0x8002129B), (0x8000FEFB, 0x8002129D), (0x8000FEFC, 0x8002129F),
+    (0x8000FF01, 0x0021), (0x8000FF02, 0x0022), (0x8000FF03, 0x0023), (0x8000FF04, 0x0024),
+    (0x8000FF05, 0x0025), (0x8000FF06, 0x0026), (0x8000FF07, 0x0027), (0x8000FF08, 0x0028),
+    (0x8000FF09, 0x0029), (0x8000FF0A, 0x002A), (0x8000FF0B, 0x002B), (0x8000FF0C, 0x002C),
+    (0x8000FF0D, 0x002D), (0x8000FF0E, 0x002E), (0x8000FF0F, 0x002F), (0x8000FF10, 0x0030),
+    (0x8000FF11, 0x0031), (0x8000FF12, 0x0032), (0x8000FF13, 0x0033), (0x8000FF14, 0x0034),
+    (0x8000FF15, 0x0035), (0x8000FF16, 0x0036), (0x8000FF17, 0x0037), (0x8000FF18, 0x0038),
+    (0x8000FF19, 0x0039), (0x8000FF1A, 0x003A), (0x8000FF1B, 0x003B), (0x8000FF1C, 0x003C),
+    (0x8000FF1D, 0x003D), (0x8000FF1E, 0x003E), (0x8000FF1F, 0x003F), (0x8000FF20, 0x0040),
+    (0x8000FF21, 0x0041), (0x8000FF22, 0x0042), (0x8000FF23, 0x0043), (0x8000FF24, 0x0044),
+    (0x8000FF25, 0x0045), (0x8000FF26, 0x0046), (0x8000FF27, 0x0047), (0x8000FF28, 0x0048),
+    (0x8000FF29, 0x0049), (0x8000FF2A, 0x004A), (0x8000FF2B, 0x004B), (0x8000FF2C, 0x004C),
+    (0x8000FF2D, 0x004D), (0x8000FF2E, 0x004E), (0x8000FF2F, 0x004F), (0x8000FF30, 0x0050),
+    (0x8000FF31, 0x0051), (0x8000FF32, 0x0052), (0x8000FF33, 0x0053), (0x8000FF34, 0x0054),
+    (0x8000FF35, 0x0055), (0x8000FF36, 0x0056), (0x8000FF37, 0x0057), (0x8000FF38, 0x0058),
+    (0x8000FF39, 0x0059), (0x8000FF3A, 0x005A), (0x8000FF3B, 0x005B), (0x8000FF3C, 0x005C),
+    (0x8000FF3D, 0x005D), (0x8000FF3E, 0x005E), (0x8000FF3F, 0x005F), (0x8000FF40, 0x0060),
+    (0x8000FF41, 0x0061), (0x8000FF42, 0x0062), (0x8000FF43, 0x0063), (0x8000FF44, 0x0064),
+    (0x8000FF45, 0x0065), (0x8000FF46, 0x0066), (0x8000FF47, 0x0067), (0x8000FF48, 0x0068),
+    (0x8000FF49, 0x0069), (0x8000FF4A, 0x006A), (0x8000FF4B, 0x006B), (0x8000FF4C, 0x006C),
+    (0x8000FF4D, 0x006D), (0x8000FF4E, 0x006E), (0x8000FF4F, 0x006F), (0x8000FF50, 0x0070),
+    (0x8000FF51, 0x0071), (0x8000FF52, 0x0072), (0x8000FF53, 0x0073), (0x8000FF54, 0x0074),
+    (0x8000FF55, 0x0075), (0x8000FF56, 0x0076), (0x8000FF57, 0x0077), (0x8000FF58, 0x0078),
+    (0x8000FF59, 0x0079), (0x8000FF5A, 0x007A), (0x8000FF5B, 0x007B), (0x8000FF5C, 0x007C),
+    (0x8000FF5D, 0x007D), (0x8000FF5E, 0x007E), (0x8000FF5F, 0x2985), (0x8000FF60, 0x2986),
+    (0x8000FF61, 0x3002), (0x8000FF62, 0x300C), (0x8000FF63, 0x300D), (0x8000FF64, 0x3001),
+    (0x8000FF65, 0x30FB), (0x8000FF66, 0x30F2), (0x8000FF67, 0x30A1), (0x8000FF68, 0x30A3),
+    (0x8000FF69, 0x30A5), (0x8000FF6A, 0x30A7), (0x8000FF6B, 0x30A9), (0x8000FF6C, 0x30E3),
+    (0x8000FF6D, 0x30E5), (0x8000FF6E, 0x30E7), (0x8000FF6F, 0x30C3), (0x8000FF70, 0x30FC),
+    (0x8000FF71, 0x30A2), (0x8000FF72, 0x30A4), (0x8000FF73, 0x30A6), (0x8000FF74, 0x30A8),
+    (0x8000FF75, 0x30AA), (0x8000FF76, 0x30AB), (0x8000FF77, 0x30AD), (0x8000FF78, 0x30AF),
+    (0x8000FF79, 0x30B1), (0x8000FF7A, 0x30B3), (0x8000FF7B, 0x30B5), (0x8000FF7C, 0x30B7),
+    (0x8000FF7D, 0x30B9), (0x8000FF7E, 0x30BB), (0x8000FF7F, 0x30BD), (0x8000FF80, 0x30BF),
+    (0x8000FF81, 0x30C1), (0x8000FF82, 0x30C4), (0x8000FF83, 0x30C6), (0x8000FF84, 0x30C8),
+    (0x8000FF85, 0x30CA), (0x8000FF86, 0x30CB), (0x8000FF87, 0x30CC), (0x8000FF88, 0x30CD),
+    (0x8000FF89, 0x30CE), (0x8000FF8A, 0x30CF), (0x8000FF8B, 0x30D2), (0x8000FF8C, 0x30D5),
+    (0x8000FF8D, 0x30D8), (0x8000FF8E, 0x30DB), (0x8000FF8F, 0x30DE), (0x8000FF90, 0x30DF),
+    (0x8000FF91, 0x30E0), (0x8000FF92, 0x30E1), (0x8000FF93, 0x30E2), (0x8000FF94, 0x30E4),
+    (0x8000FF95, 0x30E6), (0x8000FF96, 0x30E8), (0x8000FF97, 0x30E9), (0x8000FF98, 0x30EA),
+    (0x8000FF99, 0x30EB), (0x8000FF9A, 0x30EC), (0x8000FF9B, 0x30ED), (0x8000FF9C, 0x30EF),
+    (0x8000FF9D, 0x30F3), (0x8000FF9E, 0x3099), (0x8000FF9F, 0x309A), (0x8000FFA0, 0x3164),
+    (0x8000FFA1, 0x3131), (0x8000FFA2, 0x3132), (0x8000FFA3, 0x3133), (0x8000FFA4, 0x3134),
+    (0x8000FFA5, 0x3135), (0x8000FFA6, 0x3136), (0x8000FFA7, 0x3137), (0x8000FFA8, 0x3138),
+    (0x8000FFA9, 0x3139), (0x8000FFAA, 0x313A), (0x8000FFAB, 0x313B), (0x8000FFAC, 0x313C),
+    (0x8000FFAD, 0x313D), (0x8000FFAE, 0x313E), (0x8000FFAF, 0x313F), (0x8000FFB0, 0x3140),
+    (0x8000FFB1, 0x3141), (0x8000FFB2, 0x3142), (0x8000FFB3, 0x3143), (0x8000FFB4, 0x3144),
+    (0x8000FFB5, 0x3145), (0x8000FFB6, 0x3146), (0x8000FFB7, 0x3147), (0x8000FFB8, 0x3148),
+    (0x8000FFB9, 0x3149), (0x8000FFBA, 0x314A), (0x8000FFBB, 0x314B), (0x8000FFBC, 0x314C),
+    (0x8000FFBD, 0x314D), (0x8000FFBE, 0x314E), (0x8000FFC2, 0x314F), (0x8000FFC3, 0x3150),
+    (0x8000FFC4, 0x3151), (0x8000FFC5, 0x3152), (0x8000FFC6, 0x3153), (0x8000FFC7, 0x3154),
+    (0x8000FFCA, 0x3155), (0x8000FFCB, 0x3156), (0x8000FFCC, 0x3157), (0x8000FFCD, 0x3158),
+    (0x8000FFCE, 0x3159), (0x8000FFCF, 0x315A), (0x8000FFD2, 0x315B), (0x8000FFD3, 0x315C),
+    (0x8000FFD4, 0x315D), (0x8000FFD5, 0x315E), (0x8000FFD6, 0x315F), (0x8000FFD7, 0x3160),
+    (0x8000FFDA, 0x3161), (0x8000FFDB, 0x3162), (0x8000FFDC, 0x3163), (0x8000FFE0, 0x00A2),
+    (0x8000FFE1, 0x00A3), (0x8000FFE2, 0x00AC), (0x8000FFE3, 0x00AF), (0x8000FFE4, 0x00A6),
+    (0x8000FFE5, 0x00A5), (0x8000FFE6, 0x20A9), (0x8000FFE8, 0x2502), (0x8000FFE9, 0x2190),
+    (0x8000FFEA, 0x2191), (0x8000FFEB, 0x2192), (0x8000FFEC, 0x2193), (0x8000FFED, 0x25A0),
+    (0x8000FFEE, 0x25CB), (0x105C9, 0x800212A1), (0x105E4, 0x800212A3), (0x80010781, 0x02D0),
+    (0x80010782, 0x02D1), (0x80010783, 0x00E6), (0x80010784, 0x0299), (0x80010785, 0x0253),
+    (0x80010787, 0x02A3), (0x80010788, 0xAB66), (0x80010789, 0x02A5), (0x8001078A, 0x02A4),
+    (0x8001078B, 0x0256), (0x8001078C, 0x0257), (0x8001078D, 0x1D91), (0x8001078E, 0x0258),
+    (0x8001078F, 0x025E), (0x80010790, 0x02A9), (0x80010791, 0x0264), (0x80010792, 0x0262),
+    (0x80010793, 0x0260), (0x80010794, 0x029B), (0x80010795, 0x0127), (0x80010796, 0x029C),
+    (0x80010797, 0x0267), (0x80010798, 0x0284), (0x80010799, 0x02AA), (0x8001079A, 0x02AB),
+    (0x8001079B, 0x026C), (0x8001079C, 0x1DF04), (0x8001079D, 0xA78E), (0x8001079E, 0x026E),
+    (0x8001079F, 0x1DF05), (0x800107A0, 0x028E), (0x800107A1, 0x1DF06), (0x800107A2, 0x00F8),
+    (0x800107A3, 0x0276), (0x800107A4, 0x0277), (0x800107A5, 0x0071), (0x800107A6, 0x027A),
+    (0x800107A7, 0x1DF08), (0x800107A8, 0x027D), (0x800107A9, 0x027E), (0x800107AA, 0x0280),
+    (0x800107AB, 0x02A8), (0x800107AC, 0x02A6), (0x800107AD, 0xAB67), (0x800107AE, 0x02A7),
+    (0x800107AF, 0x0288), (0x800107B0, 0x2C71), (0x800107B2, 0x028F), (0x800107B3, 0x02A1),
+    (0x800107B4, 0x02A2), (0x800107B5, 0x0298), (0x800107B6, 0x01C0), (0x800107B7, 0x01C1),
+    (0x800107B8, 0x01C2), (0x800107B9, 0x1DF0A), (0x800107BA, 0x1DF1E), (0x1109A, 0x800212A5),
+    (0x1109C, 0x800212A7), (0x110AB, 0x800212A9), (0x1112E, 0x800212AB), (0x1112F, 0x800212AD),
+    (0x1134B, 0x800212AF), (0x1134C, 0x800212B1), (0x11383, 0x800212B3), (0x11385, 0x800212B5),
+    (0x1138E, 0x800212B7), (0x11391, 0x800212B9), (0x113C5, 0x800212BB), (0x113C7, 0x800212BD),
+    (0x113C8, 0x800212BF), (0x114BB, 0x800212C1), (0x114BC, 0x800212C3), (0x114BE, 0x800212C5),
+    (0x115BA, 0x800212C7), (0x115BB, 0x800212C9), (0x11938, 0x800212CB), (0x16121, 0x800212CD),
+    (0x16122, 0x800212CF), (0x16123, 0x800212D1), (0x16124, 0x800212D3), (0x16125, 0x800212D5),
+    (0x16126, 0x800212D7), (0x16127, 0x800212D9), (0x16128, 0x800212DB), (0x16D68, 0x800212DD),
+    (0x16D69, 0x800212DF), (0x16D6A, 0x800212E1), (0x8001CCD6, 0x0041), (0x8001CCD7, 0x0042),
+    (0x8001CCD8, 0x0043), (0x8001CCD9, 0x0044), (0x8001CCDA, 0x0045), (0x8001CCDB, 0x0046),
+    (0x8001CCDC, 0x0047), (0x8001CCDD, 0x0048), (0x8001CCDE, 0x0049), (0x8001CCDF, 0x004A),
+    (0x8001CCE0, 0x004B), (0x8001CCE1, 0x004C), (0x8001CCE2, 0x004D), (0x8001CCE3, 0x004E),
+    (0x8001CCE4, 0x004F), (0x8001CCE5, 0x0050), (0x8001CCE6, 0x0051), (0x8001CCE7, 0x0052),
+    (0x8001CCE8, 0x0053), (0x8001CCE9, 0x0054), (0x8001CCEA, 0x0055), (0x8001CCEB, 0x0056),
+    (0x8001CCEC, 0x0057), (0x8001CCED, 0x0058), (0x8001CCEE, 0x0059), (0x8001CCEF, 0x005A),
+    (0x8001CCF0, 0x0030), (0x8001CCF1, 0x0031), (0x8001CCF2, 0x0032), (0x8001CCF3, 0x0033),
+    (0x8001CCF4, 0x0034), (0x8001CCF5, 0x0035), (0x8001CCF6, 0x0036), (0x8001CCF7, 0x0037),
+    (0x8001CCF8, 0x0038), (0x8001CCF9, 0x0039), (0x1D15E, 0x800212E3), (0x1D15F, 0x800212E5),
+    (0x1D160, 0x800212E7), (0x1D161, 0x800212E9), (0x1D162, 0x800212EB), (0x1D163, 0x800212ED),
+    (0x1D164, 0x800212EF), (0x1D1BB, 0x800212F1), (0x1D1BC, 0x800212F3), (0x1D1BD, 0x800212F5),
+    (0x1D1BE, 0x800212F7), (0x1D1BF, 0x800212F9), (0x1D1C0, 0x800212FB), (0x8001D400, 0x0041),
+    (0x8001D401, 0x0042), (0x8001D402, 0x0043), (0x8001D403, 0x0044), (0x8001D404, 0x0045),
+    (0x8001D405, 0x0046), (0x8001D406, 0x0047), (0x8001D407, 0x0048), (0x8001D408, 0x0049),
+    (0x8001D409, 0x004A), (0x8001D40A, 0x004B), (0x8001D40B, 0x004C), (0x8001D40C, 0x004D),
+    (0x8001D40D, 0x004E), (0x8001D40E, 0x004F), (0x8001D40F, 0x0050), (0x8001D410, 0x0051),
+    (0x8001D411, 0x0052), (0x8001D412, 0x0053), (0x8001D413, 0x0054), (0x8001D414, 0x0055),
+    (0x8001D415, 0x0056), (0x8001D416, 0x0057), (0x8001D417, 0x0058), (0x8001D418, 0x0059),
+    (0x8001D419, 0x005A), (0x8001D41A, 0x0061), (0x8001D41B, 0x0062), (0x8001D41C, 0x0063),
+    (0x8001D41D, 0x0064), (0x8001D41E, 0x0065), (0x8001D41F, 0x0066), (0x8001D420, 0x0067),
+    (0x8001D421, 0x0068), (0x8001D422, 0x0069), (0x8001D423, 0x006A), (0x8001D424, 0x006B),
+    (0x8001D425, 0x006C), (0x8001D426, 0x006D), (0x8001D427, 0x006E), (0x8001D428, 0x006F),
+    (0x8001D429, 0x0070), (0x8001D42A, 0x0071), (0x8001D42B, 0x0072), (0x8001D42C, 0x0073),
+    (0x8001D42D, 0x0074), (0x8001D42E, 0x0075), (0x8001D42F, 0x0076), (0x8001D430, 0x0077),
+    (0x8001D431, 0x0078), (0x8001D432, 0x0079), (0x8001D433, 0x007A), (0x8001D434, 0x0041),
+    (0x8001D435, 0x0042), (0x8001D436, 0x0043), (0x8001D437, 0x0044), (0x8001D438, 0x0045),
+    (0x8001D439, 0x0046), (0x8001D43A, 0x0047), (0x8001D43B, 0x0048), (0x8001D43C, 0x0049),
+    (0x8001D43D, 0x004A), (0x8001D43E, 0x004B), (0x8001D43F, 0x004C), (0x8001D440, 0x004D),
+    (0x8001D441, 0x004E), (0x8001D442, 0x004F), (0x8001D443, 0x0050), (0x8001D444, 0x0051),
+    (0x8001D445, 0x0052), (0x8001D446, 0x0053), (0x8001D447, 0x0054), (0x8001D448, 0x0055),
+    (0x8001D449, 0x0056), (0x8001D44A, 0x0057), (0x8001D44B, 0x0058), (0x8001D44C, 0x0059),
+    (0x8001D44D, 0x005A), (0x8001D44E, 0x0061), (0x8001D44F, 0x0062), (0x8001D450, 0x0063),
+    (0x8001D451, 0x0064), (0x8001D452, 0x0065), (0x8001D453, 0x0066), (0x8001D454, 0x0067),
+    (0x8001D456, 0x0069), (0x8001D457, 0x006A), (0x8001D458, 0x006B), (0x8001D459, 0x006C),
+    (0x8001D45A, 0x006D), (0x8001D45B, 0x006E), (0x8001D45C, 0x006F), (0x8001D45D, 0x0070),
+    (0x8001D45E, 0x0071), (0x8001D45F, 0x0072), (0x8001D460, 0x0073), (0x8001D461, 0x0074),
+    (0x8001D462, 0x0075), (0x8001D463, 0x0076), (0x8001D464, 0x0077), (0x8001D465, 0x0078),
+    (0x8001D466, 0x0079), (0x8001D467, 0x007A), (0x8001D468, 0x0041), (0x8001D469, 0x0042),
+    (0x8001D46A, 0x0043), (0x8001D46B, 0x0044), (0x8001D46C, 0x0045), (0x8001D46D, 0x0046),
+    (0x8001D46E, 0x0047), (0x8001D46F, 0x0048), (0x8001D470, 0x0049), (0x8001D471, 0x004A),
+    (0x8001D472, 0x004B), (0x8001D473, 0x004C), (0x8001D474, 0x004D), (0x8001D475, 0x004E),
+    (0x8001D476, 0x004F), (0x8001D477, 0x0050), (0x8001D478, 0x0051), (0x8001D479, 0x0052),
+    (0x8001D47A, 0x0053), (0x8001D47B, 0x0054), (0x8001D47C, 0x0055), (0x8001D47D, 0x0056),
+    (0x8001D47E, 0x0057), (0x8001D47F, 0x0058), (0x8001D480, 0x0059), (0x8001D481, 0x005A),
+    (0x8001D482, 0x0061), (0x8001D483, 0x0062), (0x8001D484, 0x0063), (0x8001D485, 0x0064),
+    (0x8001D486, 0x0065), (0x8001D487, 0x0066), (0x8001D488, 0x0067), (0x8001D489, 0x0068),
+    (0x8001D48A, 0x0069), (0x8001D48B, 0x006A), (0x8001D48C, 0x006B), (0x8001D48D, 0x006C),
+    (0x8001D48E, 0x006D), (0x8001D48F, 0x006E), (0x8001D490, 0x006F), (0x8001D491, 0x0070),
+    (0x8001D492, 0x0071), (0x8001D493, 0x0072), (0x8001D494, 0x0073), (0x8001D495, 0x0074),
+    (0x8001D496, 0x0075), (0x8001D497, 0x0076), (0x8001D498, 0x0077), (0x8001D499, 0x0078),
+    (0x8001D49A, 0x0079), (0x8001D49B, 0x007A), (0x8001D49C, 0x0041), (0x8001D49E, 0x0043),
+    (0x8001D49F, 0x0044), (0x8001D4A2, 0x0047), (0x8001D4A5, 0x004A), (0x8001D4A6, 0x004B),
+    (0x8001D4A9, 0x004E), (0x8001D4AA, 0x004F), (0x8001D4AB, 0x0050), (0x8001D4AC, 0x0051),
+    (0x8001D4AE, 0x0053), (0x8001D4AF, 0x0054), (0x8001D4B0, 0x0055), (0x8001D4B1, 0x0056),
+    (0x8001D4B2, 0x0057), (0x8001D4B3, 0x0058), (0x8001D4B4, 0x0059), (0x8001D4B5, 0x005A),
+    (0x8001D4B6, 0x0061), (0x8001D4B7, 0x0062), (0x8001D4B8, 0x0063), (0x8001D4B9, 0x0064),
+    (0x8001D4BB, 0x0066), (0x8001D4BD, 0x0068), (0x8001D4BE, 0x0069), (0x8001D4BF, 0x006A),
+    (0x8001D4C0, 0x006B), (0x8001D4C1, 0x006C), (0x8001D4C2, 0x006D), (0x8001D4C3, 0x006E),
+    (0x8001D4C5, 0x0070), (0x8001D4C6, 0x0071), (0x8001D4C7, 0x0072), (0x8001D4C8, 0x0073),
+    (0x8001D4C9, 0x0074), (0x8001D4CA, 0x0075), (0x8001D4CB, 0x0076), (0x8001D4CC, 0x0077),
+    (0x8001D4CD, 0x0078), (0x8001D4CE, 0x0079), (0x8001D4CF, 0x007A), (0x8001D4D0, 0x0041),
+    (0x8001D4D1, 0x0042), (0x8001D4D2, 0x0043), (0x8001D4D3, 0x0044), (0x8001D4D4, 0x0045),
+    (0x8001D4D5, 0x0046), (0x8001D4D6, 0x0047), (0x8001D4D7, 0x0048), (0x8001D4D8, 0x0049),
+    (0x8001D4D9, 0x004A), (0x8001D4DA, 0x004B), (0x8001D4DB, 0x004C), (0x8001D4DC, 0x004D),
+    (0x8001D4DD, 0x004E), (0x8001D4DE, 0x004F), (0x8001D4DF, 0x0050), (0x8001D4E0, 0x0051),
+    (0x8001D4E1, 0x0052), (0x8001D4E2, 0x0053), (0x8001D4E3, 0x0054), (0x8001D4E4, 0x0055),
+    (0x8001D4E5, 0x0056), (0x8001D4E6, 0x0057), (0x8001D4E7, 0x0058), (0x8001D4E8, 0x0059),
+    (0x8001D4E9, 0x005A), (0x8001D4EA, 0x0061), (0x8001D4EB, 0x0062), (0x8001D4EC, 0x0063),
+    (0x8001D4ED, 0x0064), (0x8001D4EE, 0x0065), (0x8001D4EF, 0x0066), (0x8001D4F0, 0x0067),
+    (0x8001D4F1, 0x0068), (0x8001D4F2, 0x0069), (0x8001D4F3, 0x006A), (0x8001D4F4, 0x006B),
+    (0x8001D4F5, 0x006C), (0x8001D4F6, 0x006D), (0x8001D4F7, 0x006E), (0x8001D4F8, 0x006F),
+    (0x8001D4F9, 0x0070), (0x8001D4FA, 0x0071), (0x8001D4FB, 0x0072), (0x8001D4FC, 0x0073),
+    (0x8001D4FD, 0x0074), (0x8001D4FE, 0x0075), (0x8001D4FF, 0x0076), (0x8001D500, 0x0077),
+    (0x8001D501, 0x0078), (0x8001D502, 0x0079), (0x8001D503, 0x007A), (0x8001D504, 0x0041),
+    (0x8001D505, 0x0042), (0x8001D507, 0x0044), (0x8001D508, 0x0045), (0x8001D509, 0x0046),
+    (0x8001D50A, 0x0047), (0x8001D50D, 0x004A), (0x8001D50E, 0x004B), (0x8001D50F, 0x004C),
+    (0x8001D510, 0x004D), (0x8001D511, 0x004E), (0x8001D512, 0x004F), (0x8001D513, 0x0050),
+    (0x8001D514, 0x0051), (0x8001D516, 0x0053), (0x8001D517, 0x0054), (0x8001D518, 0x0055),
+    (0x8001D519, 0x0056), (0x8001D51A, 0x0057), (0x8001D51B, 0x0058), (0x8001D51C, 0x0059),
+    (0x8001D51E, 0x0061), (0x8001D51F, 0x0062), (0x8001D520, 0x0063), (0x8001D521, 0x0064),
+    (0x8001D522, 0x0065), (0x8001D523, 0x0066), (0x8001D524, 0x0067), (0x8001D525, 0x0068),
+    (0x8001D526, 0x0069), (0x8001D527, 0x006A), (0x8001D528, 0x006B), (0x8001D529, 0x006C),
+    (0x8001D52A, 0x006D), (0x8001D52B, 0x006E), (0x8001D52C, 0x006F), (0x8001D52D, 0x0070),
+    (0x8001D52E, 0x0071), (0x8001D52F, 0x0072), (0x8001D530, 0x0073), (0x8001D531, 0x0074),
+    (0x8001D532, 0x0075), (0x8001D533, 0x0076), (0x8001D534, 0x0077), (0x8001D535, 0x0078),
+    (0x8001D536, 0x0079), (0x8001D537, 0x007A), (0x8001D538, 0x0041), (0x8001D539, 0x0042),
+    (0x8001D53B, 0x0044), (0x8001D53C, 0x0045), (0x8001D53D, 0x0046), (0x8001D53E, 0x0047),
+    (0x8001D540, 0x0049), (0x8001D541, 0x004A), (0x8001D542, 0x004B), (0x8001D543, 0x004C),
+    (0x8001D544, 0x004D), (0x8001D546, 0x004F), (0x8001D54A, 0x0053), (0x8001D54B, 0x0054),
+    (0x8001D54C, 0x0055), (0x8001D54D, 0x0056), (0x8001D54E, 0x0057), (0x8001D54F, 0x0058),
+    (0x8001D550, 0x0059), (0x8001D552, 0x0061), (0x8001D553, 0x0062), (0x8001D554, 0x0063),
+    (0x8001D555, 0x0064), (0x8001D556, 0x0065), (0x8001D557, 0x0066), (0x8001D558, 0x0067),
+    (0x8001D559, 0x0068), (0x8001D55A, 0x0069), (0x8001D55B, 0x006A), (0x8001D55C, 0x006B),
+    (0x8001D55D, 0x006C), (0x8001D55E, 0x006D), (0x8001D55F, 0x006E), (0x8001D560, 0x006F),
+    (0x8001D561, 0x0070), (0x8001D562, 0x0071), (0x8001D563, 0x0072), (0x8001D564, 0x0073),
+    (0x8001D565, 0x0074), (0x8001D566, 0x0075), (0x8001D567, 0x0076), (0x8001D568, 0x0077),
+    (0x8001D569, 0x0078), (0x8001D56A, 0x0079), (0x8001D56B, 0x007A), (0x8001D56C, 0x0041),
+    (0x8001D56D, 0x0042), (0x8001D56E, 0x0043), (0x8001D56F, 0x0044), (0x8001D570, 0x0045),
+    (0x8001D571, 0x0046), (0x8001D572, 0x0047), (0x8001D573, 0x0048), (0x8001D574, 0x0049),
+    (0x8001D575, 0x004A), (0x8001D576, 0x004B), (0x8001D577, 0x004C), (0x8001D578, 0x004D),
+    (0x8001D579, 0x004E), (0x8001D57A, 0x004F), (0x8001D57B, 0x0050), (0x8001D57C, 0x0051),
+    (0x8001D57D, 0x0052), (0x8001D57E, 0x0053), (0x8001D57F, 0x0054), (0x8001D580, 0x0055),
+    (0x8001D581, 0x0056), (0x8001D582, 0x0057), (0x8001D583, 0x0058), (0x8001D584, 0x0059),
+    (0x8001D585, 0x005A), (0x8001D586, 0x0061), (0x8001D587, 0x0062), (0x8001D588, 0x0063),
+    (0x8001D589, 0x0064), (0x8001D58A, 0x0065), (0x8001D58B, 0x0066), (0x8001D58C, 0x0067),
+    (0x8001D58D, 0x0068), (0x8001D58E, 0x0069), (0x8001D58F, 0x006A), (0x8001D590, 0x006B),
+    (0x8001D591, 0x006C), (0x8001D592, 0x006D), (0x8001D593, 0x006E), (0x8001D594, 0x006F),
+    (0x8001D595, 0x0070), (0x8001D596, 0x0071), (0x8001D597, 0x0072), (0x8001D598, 0x0073),
+    (0x8001D599, 0x0074), (0x8001D59A, 0x0075), (0x8001D59B, 0x0076), (0x8001D59C, 0x0077),
+    (0x8001D59D, 0x0078), (0x8001D59E, 0x0079), (0x8001D59F, 0x007A), (0x8001D5A0, 0x0041),
+    (0x8001D5A1, 0x0042), (0x8001D5A2, 0x0043), (0x8001D5A3, 0x0044), (0x8001D5A4, 0x0045),
+    (0x8001D5A5, 0x0046), (0x8001D5A6, 0x0047), (0x8001D5A7, 0x0048), (0x8001D5A8, 0x0049),
+    (0x8001D5A9, 0x004A), (0x8001D5AA, 0x004B), (0x8001D5AB, 0x004C), (0x8001D5AC, 0x004D),
+    (0x8001D5AD, 0x004E), (0x8001D5AE, 0x004F), (0x8001D5AF, 0x0050), (0x8001D5B0, 0x0051),
+    (0x8001D5B1, 0x0052), (0x8001D5B2, 0x0053), (0x8001D5B3, 0x0054), (0x8001D5B4, 0x0055),
+    (0x8001D5B5, 0x0056), (0x8001D5B6, 0x0057), (0x8001D5B7, 0x0058), (0x8001D5B8, 0x0059),
+    (0x8001D5B9, 0x005A), (0x8001D5BA, 0x0061), (0x8001D5BB, 0x0062), (0x8001D5BC, 0x0063),
+    (0x8001D5BD, 0x0064), (0x8001D5BE, 0x0065), (0x8001D5BF, 0x0066), (0x8001D5C0, 0x0067),
+    (0x8001D5C1, 0x0068), (0x8001D5C2, 0x0069), (0x8001D5C3, 0x006A), (0x8001D5C4, 0x006B),
+    (0x8001D5C5, 0x006C), (0x8001D5C6, 0x006D), (0x8001D5C7, 0x006E), (0x8001D5C8, 0x006F),
+    (0x8001D5C9, 0x0070), (0x8001D5CA, 0x0071), (0x8001D5CB, 0x0072), (0x8001D5CC, 0x0073),
+    (0x8001D5CD, 0x0074), (0x8001D5CE, 0x0075), (0x8001D5CF, 0x0076), (0x8001D5D0, 0x0077),
+    (0x8001D5D1, 0x0078), (0x8001D5D2, 0x0079), (0x8001D5D3, 0x007A), (0x8001D5D4, 0x0041),
+    (0x8001D5D5, 0x0042), (0x8001D5D6, 0x0043), (0x8001D5D7, 0x0044), (0x8001D5D8, 0x0045),
+    (0x8001D5D9, 0x0046), (0x8001D5DA, 0x0047), (0x8001D5DB, 0x0048), (0x8001D5DC, 0x0049),
+    (0x8001D5DD, 0x004A), (0x8001D5DE, 0x004B), (0x8001D5DF, 0x004C), (0x8001D5E0, 0x004D),
+    (0x8001D5E1, 0x004E), (0x8001D5E2, 0x004F), (0x8001D5E3, 0x0050), (0x8001D5E4, 0x0051),
+    (0x8001D5E5, 0x0052), (0x8001D5E6, 0x0053), (0x8001D5E7, 0x0054), (0x8001D5E8, 0x0055),
+    (0x8001D5E9, 0x0056), (0x8001D5EA, 0x0057), (0x8001D5EB, 0x0058), (0x8001D5EC, 0x0059),
+    (0x8001D5ED, 0x005A), (0x8001D5EE, 0x0061), (0x8001D5EF, 0x0062), (0x8001D5F0, 0x0063),
+    (0x8001D5F1, 0x0064), (0x8001D5F2, 0x0065), (0x8001D5F3, 0x0066), (0x8001D5F4, 0x0067),
+    (0x8001D5F5, 0x0068), (0x8001D5F6, 0x0069), (0x8001D5F7, 0x006A), (0x8001D5F8, 0x006B),
+    (0x8001D5F9, 0x006C), (0x8001D5FA, 0x006D), (0x8001D5FB, 0x006E), (0x8001D5FC, 0x006F),
+    (0x8001D5FD, 0x0070), (0x8001D5FE, 0x0071), (0x8001D5FF, 0x0072), (0x8001D600, 0x0073),
+    (0x8001D601, 0x0074), (0x8001D602, 0x0075), (0x8001D603, 0x0076), (0x8001D604, 0x0077),
+    (0x8001D605, 0x0078), (0x8001D606, 0x0079), (0x8001D607, 0x007A), (0x8001D608, 0x0041),
+    (0x8001D609, 0x0042), (0x8001D60A, 0x0043), (0x8001D60B, 0x0044), (0x8001D60C, 0x0045),
+    (0x8001D60D, 0x0046), (0x8001D60E, 0x0047), (0x8001D60F, 0x0048), (0x8001D610, 0x0049),
+    (0x8001D611, 0x004A), (0x8001D612, 0x004B), (0x8001D613, 0x004C), (0x8001D614, 0x004D),
+    (0x8001D615, 0x004E), (0x8001D616, 0x004F), (0x8001D617, 0x0050), (0x8001D618, 0x0051),
+    (0x8001D619, 0x0052), (0x8001D61A, 0x0053), (0x8001D61B, 0x0054), (0x8001D61C, 0x0055),
+    (0x8001D61D, 0x0056), (0x8001D61E, 0x0057), (0x8001D61F, 0x0058), (0x8001D620, 0x0059),
+    (0x8001D621, 0x005A), (0x8001D622, 0x0061), (0x8001D623, 0x0062), (0x8001D624, 0x0063),
+    (0x8001D625, 0x0064), (0x8001D626, 0x0065), (0x8001D627, 0x0066), (0x8001D628, 0x0067),
+    (0x8001D629, 0x0068), (0x8001D62A, 0x0069), (0x8001D62B, 0x006A), (0x8001D62C, 0x006B),
+    (0x8001D62D, 0x006C), (0x8001D62E, 0x006D), (0x8001D62F, 0x006E), (0x8001D630, 0x006F),
+    (0x8001D631, 0x0070), (0x8001D632, 0x0071), (0x8001D633, 0x0072), (0x8001D634, 0x0073),
+    (0x8001D635, 0x0074), (0x8001D636, 0x0075), (0x8001D637, 0x0076), (0x8001D638, 0x0077),
+    (0x8001D639, 0x0078), (0x8001D63A, 0x0079), (0x8001D63B, 0x007A), (0x8001D63C, 0x0041),
+    (0x8001D63D, 0x0042), (0x8001D63E, 0x0043), (0x8001D63F, 0x0044), (0x8001D640, 0x0045),
+    (0x8001D641, 0x0046), (0x8001D642, 0x0047), (0x8001D643, 0x0048), (0x8001D644, 0x0049),
+    (0x8001D645, 0x004A), (0x8001D646, 0x004B), (0x8001D647, 0x004C), (0x8001D648, 0x004D),
+    (0x8001D649, 0x004E), (0x8001D64A, 0x004F), (0x8001D64B, 0x0050), (0x8001D64C, 0x0051),
+    (0x8001D64D, 0x0052), (0x8001D64E, 0x0053), (0x8001D64F, 0x0054), (0x8001D650, 0x0055),
+    (0x8001D651, 0x0056), (0x8001D652, 0x0057), (0x8001D653, 0x0058), (0x8001D654, 0x0059),
+    (0x8001D655, 0x005A), (0x8001D656, 0x0061), (0x8001D657, 0x0062), (0x8001D658, 0x0063),
+    (0x8001D659, 0x0064), (0x8001D65A, 0x0065), (0x8001D65B, 0x0066), (0x8001D65C, 0x0067),
+    (0x8001D65D, 0x0068), (0x8001D65E, 0x0069), (0x8001D65F, 0x006A), (0x8001D660, 0x006B),
+    (0x8001D661, 0x006C), (0x8001D662, 0x006D), (0x8001D663, 0x006E), (0x8001D664, 0x006F),
+    (0x8001D665, 0x0070), (0x8001D666, 0x0071), (0x8001D667, 0x0072), (0x8001D668, 0x0073),
+    (0x8001D669, 0x0074), (0x8001D66A, 0x0075), (0x8001D66B, 0x0076), (0x8001D66C, 0x0077),
+    (0x8001D66D, 0x0078), (0x8001D66E, 0x0079), (0x8001D66F, 0x007A), (0x8001D670, 0x0041),
+    (0x8001D671, 0x0042), (0x8001D672, 0x0043), (0x8001D673, 0x0044), (0x8001D674, 0x0045),
+    (0x8001D675, 0x0046), (0x8001D676, 0x0047), (0x8001D677, 0x0048), (0x8001D678, 0x0049),
+    (0x8001D679, 0x004A), (0x8001D67A, 0x004B), (0x8001D67B, 0x004C), (0x8001D67C, 0x004D),
+    (0x8001D67D, 0x004E), (0x8001D67E, 0x004F), (0x8001D67F, 0x0050), (0x8001D680, 0x0051),
+    (0x8001D681, 0x0052), (0x8001D682, 0x0053), (0x8001D683, 0x0054), (0x8001D684, 0x0055),
+    (0x8001D685, 0x0056), (0x8001D686, 0x0057), (0x8001D687, 0x0058), (0x8001D688, 0x0059),
+    (0x8001D689, 0x005A), (0x8001D68A, 0x0061), (0x8001D68B, 0x0062), (0x8001D68C, 0x0063),
+    (0x8001D68D, 0x0064), (0x8001D68E, 0x0065), (0x8001D68F, 0x0066), (0x8001D690, 0x0067),
+    (0x8001D691, 0x0068), (0x8001D692, 0x0069), (0x8001D693, 0x006A), (0x8001D694, 0x006B),
+    (0x8001D695, 0x006C), (0x8001D696, 0x006D), (0x8001D697, 0x006E), (0x8001D698, 0x006F),
+    (0x8001D699, 0x0070), (0x8001D69A, 0x0071), (0x8001D69B, 0x0072), (0x8001D69C, 0x0073),
+    (0x8001D69D, 0x0074), (0x8001D69E, 0x0075), (0x8001D69F, 0x0076), (0x8001D6A0, 0x0077),
+    (0x8001D6A1, 0x0078), (0x8001D6A2, 0x0079), (0x8001D6A3, 0x007A), (0x8001D6A4, 0x0131),
+    (0x8001D6A5, 0x0237), (0x8001D6A8, 0x0391), (0x8001D6A9, 0x0392), (0x8001D6AA, 0x0393),
+    (0x8001D6AB, 0x0394), (0x8001D6AC, 0x0395), (0x8001D6AD, 0x0396), (0x8001D6AE, 0x0397),
+    (0x8001D6AF, 0x0398), (0x8001D6B0, 0x0399), (0x8001D6B1, 0x039A), (0x8001D6B2, 0x039B),
+    (0x8001D6B3, 0x039C), (0x8001D6B4, 0x039D), (0x8001D6B5, 0x039E), (0x8001D6B6, 0x039F),
+    (0x8001D6B7, 0x03A0), (0x8001D6B8, 0x03A1), (0x8001D6B9, 0x03F4), (0x8001D6BA, 0x03A3),
+    (0x8001D6BB, 0x03A4), (0x8001D6BC, 0x03A5), (0x8001D6BD, 0x03A6), (0x8001D6BE, 0x03A7),
+    (0x8001D6BF, 0x03A8), (0x8001D6C0, 0x03A9), (0x8001D6C1, 0x2207), (0x8001D6C2, 0x03B1),
+    (0x8001D6C3, 0x03B2), (0x8001D6C4, 0x03B3), (0x8001D6C5, 0x03B4), (0x8001D6C6, 0x03B5),
+    (0x8001D6C7, 0x03B6), (0x8001D6C8, 0x03B7), (0x8001D6C9, 0x03B8), (0x8001D6CA, 0x03B9),
+    (0x8001D6CB, 0x03BA), (0x8001D6CC, 0x03BB), (0x8001D6CD, 0x03BC), (0x8001D6CE, 0x03BD),
+    (0x8001D6CF, 0x03BE), (0x8001D6D0, 0x03BF), (0x8001D6D1, 0x03C0), (0x8001D6D2, 0x03C1),
+    (0x8001D6D3, 0x03C2), (0x8001D6D4, 0x03C3), (0x8001D6D5, 0x03C4), (0x8001D6D6, 0x03C5),
+    (0x8001D6D7, 0x03C6), (0x8001D6D8, 0x03C7), (0x8001D6D9, 0x03C8), (0x8001D6DA, 0x03C9),
+    (0x8001D6DB, 0x2202), (0x8001D6DC, 0x03F5), (0x8001D6DD, 0x03D1), (0x8001D6DE, 0x03F0),
+    (0x8001D6DF, 0x03D5), (0x8001D6E0, 0x03F1), (0x8001D6E1, 0x03D6), (0x8001D6E2, 0x0391),
+    (0x8001D6E3, 0x0392), (0x8001D6E4, 0x0393), (0x8001D6E5, 0x0394), (0x8001D6E6, 0x0395),
+    (0x8001D6E7, 0x0396), (0x8001D6E8, 0x0397), (0x8001D6E9, 0x0398), (0x8001D6EA, 0x0399),
+    (0x8001D6EB, 0x039A), (0x8001D6EC, 0x039B), (0x8001D6ED, 0x039C), (0x8001D6EE, 0x039D),
+    (0x8001D6EF, 0x039E), (0x8001D6F0, 0x039F), (0x8001D6F1, 0x03A0), (0x8001D6F2, 0x03A1),
+    (0x8001D6F3, 0x03F4), (0x8001D6F4, 0x03A3), (0x8001D6F5, 0x03A4), (0x8001D6F6, 0x03A5),
+    (0x8001D6F7, 0x03A6), (0x8001D6F8, 0x03A7), (0x8001D6F9, 0x03A8), (0x8001D6FA, 0x03A9),
+    (0x8001D6FB, 0x2207), (0x8001D6FC, 0x03B1), (0x8001D6FD, 0x03B2), (0x8001D6FE, 0x03B3),
+    (0x8001D6FF, 0x03B4), (0x8001D700, 0x03B5), (0x8001D701, 0x03B6), (0x8001D702, 0x03B7),
+    (0x8001D703, 0x03B8), (0x8001D704, 0x03B9), (0x8001D705, 0x03BA), (0x8001D706, 0x03BB),
+    (0x8001D707, 0x03BC), (0x8001D708, 0x03BD), (0x8001D709, 0x03BE), (0x8001D70A, 0x03BF),
+    (0x8001D70B, 0x03C0), (0x8001D70C, 0x03C1), (0x8001D70D, 0x03C2), (0x8001D70E, 0x03C3),
+    (0x8001D70F, 0x03C4), (0x8001D710, 0x03C5), (0x8001D711, 0x03C6), (0x8001D712, 0x03C7),
+    (0x8001D713, 0x03C8), (0x8001D714, 0x03C9), (0x8001D715, 0x2202), (0x8001D716, 0x03F5),
+    (0x8001D717, 0x03D1), (0x8001D718, 0x03F0), (0x8001D719, 0x03D5), (0x8001D71A, 0x03F1),
+    (0x8001D71B, 0x03D6), (0x8001D71C, 0x0391), (0x8001D71D, 0x0392), (0x8001D71E, 0x0393),
+    (0x8001D71F, 0x0394), (0x8001D720, 0x0395), (0x8001D721, 0x0396), (0x8001D722, 0x0397),
+    (0x8001D723, 0x0398), (0x8001D724, 0x0399), (0x8001D725, 0x039A), (0x8001D726, 0x039B),
+    (0x8001D727, 0x039C), (0x8001D728, 0x039D), (0x8001D729, 0x039E), (0x8001D72A, 0x039F),
+    (0x8001D72B, 0x03A0), (0x8001D72C, 0x03A1), (0x8001D72D, 0x03F4), (0x8001D72E, 0x03A3),
+    (0x8001D72F, 0x03A4), (0x8001D730, 0x03A5), (0x8001D731, 0x03A6), (0x8001D732, 0x03A7),
+    (0x8001D733, 0x03A8), (0x8001D734, 0x03A9), (0x8001D735, 0x2207), (0x8001D736, 0x03B1),
+    (0x8001D737, 0x03B2), (0x8001D738, 0x03B3), (0x8001D739, 0x03B4), (0x8001D73A, 0x03B5),
+    (0x8001D73B, 0x03B6), (0x8001D73C, 0x03B7), (0x8001D73D, 0x03B8), (0x8001D73E, 0x03B9),
+    (0x8001D73F, 0x03BA), (0x8001D740, 0x03BB), (0x8001D741, 0x03BC), (0x8001D742, 0x03BD),
+    (0x8001D743, 0x03BE), (0x8001D744, 0x03BF), (0x8001D745, 0x03C0), (0x8001D746, 0x03C1),
+    (0x8001D747, 0x03C2), (0x8001D748, 0x03C3), (0x8001D749, 0x03C4), (0x8001D74A, 0x03C5),
+    (0x8001D74B, 0x03C6), (0x8001D74C, 0x03C7), (0x8001D74D, 0x03C8), (0x8001D74E, 0x03C9),
+    (0x8001D74F, 0x2202), (0x8001D750, 0x03F5), (0x8001D751, 0x03D1), (0x8001D752, 0x03F0),
+    (0x8001D753, 0x03D5), (0x8001D754, 0x03F1), (0x8001D755, 0x03D6), (0x8001D756, 0x0391),
+    (0x8001D757, 0x0392), (0x8001D758, 0x0393), (0x8001D759, 0x0394), (0x8001D75A, 0x0395),
+    (0x8001D75B, 0x0396), (0x8001D75C, 0x0397), (0x8001D75D, 0x0398), (0x8001D75E, 0x0399),
+    (0x8001D75F, 0x039A), (0x8001D760, 0x039B), (0x8001D761, 0x039C), (0x8001D762, 0x039D),
+    (0x8001D763, 0x039E), (0x8001D764, 0x039F), (0x8001D765, 0x03A0), (0x8001D766, 0x03A1),
+    (0x8001D767, 0x03F4), (0x8001D768, 0x03A3), (0x8001D769, 0x03A4), (0x8001D76A, 0x03A5),
+    (0x8001D76B, 0x03A6), (0x8001D76C, 0x03A7), (0x8001D76D, 0x03A8), (0x8001D76E, 0x03A9),
+    (0x8001D76F, 0x2207), (0x8001D770, 0x03B1), (0x8001D771, 0x03B2), (0x8001D772, 0x03B3),
+    (0x8001D773, 0x03B4), (0x8001D774, 0x03B5), (0x8001D775, 0x03B6), (0x8001D776, 0x03B7),
+    (0x8001D777, 0x03B8), (0x8001D778, 0x03B9), (0x8001D779, 0x03BA), (0x8001D77A, 0x03BB),
+    (0x8001D77B, 0x03BC), (0x8001D77C, 0x03BD), (0x8001D77D, 0x03BE), (0x8001D77E, 0x03BF),
+    (0x8001D77F, 0x03C0), (0x8001D780, 0x03C1), (0x8001D781, 0x03C2), (0x8001D782, 0x03C3),
+    (0x8001D783, 0x03C4), (0x8001D784, 0x03C5), (0x8001D785, 0x03C6), (0x8001D786, 0x03C7),
+    (0x8001D787, 0x03C8), (0x8001D788, 0x03C9), (0x8001D789, 0x2202), (0x8001D78A, 0x03F5),
+    (0x8001D78B, 0x03D1), (0x8001D78C, 0x03F0), (0x8001D78D, 0x03D5), (0x8001D78E, 0x03F1),
+    (0x8001D78F, 0x03D6), (0x8001D790, 0x0391), (0x8001D791, 0x0392), (0x8001D792, 0x0393),
+    (0x8001D793, 0x0394), (0x8001D794, 0x0395), (0x8001D795, 0x0396), (0x8001D796, 0x0397),
+    (0x8001D797, 0x0398), (0x8001D798, 0x0399), (0x8001D799, 0x039A), (0x8001D79A, 0x039B),
+    (0x8001D79B, 0x039C), (0x8001D79C, 0x039D), (0x8001D79D, 0x039E), (0x8001D79E, 0x039F),
+    (0x8001D79F, 0x03A0), (0x8001D7A0, 0x03A1), (0x8001D7A1, 0x03F4), (0x8001D7A2, 0x03A3),
+    (0x8001D7A3, 0x03A4), (0x8001D7A4, 0x03A5), (0x8001D7A5, 0x03A6), (0x8001D7A6, 0x03A7),
+    (0x8001D7A7, 0x03A8), (0x8001D7A8, 0x03A9), (0x8001D7A9, 0x2207), (0x8001D7AA, 0x03B1),
+    (0x8001D7AB, 0x03B2), (0x8001D7AC, 0x03B3), (0x8001D7AD, 0x03B4), (0x8001D7AE, 0x03B5),
+    (0x8001D7AF, 0x03B6), (0x8001D7B0, 0x03B7), (0x8001D7B1, 0x03B8), (0x8001D7B2, 0x03B9),
+    (0x8001D7B3, 0x03BA), (0x8001D7B4, 0x03BB), (0x8001D7B5, 0x03BC), (0x8001D7B6, 0x03BD),
+    (0x8001D7B7, 0x03BE), (0x8001D7B8, 0x03BF), (0x8001D7B9, 0x03C0), (0x8001D7BA, 0x03C1),
+    (0x8001D7BB, 0x03C2), (0x8001D7BC, 0x03C3), (0x8001D7BD, 0x03C4), (0x8001D7BE, 0x03C5),
+    (0x8001D7BF, 0x03C6), (0x8001D7C0, 0x03C7), (0x8001D7C1, 0x03C8), (0x8001D7C2, 0x03C9),
+    (0x8001D7C3, 0x2202), (0x8001D7C4, 0x03F5), (0x8001D7C5, 0x03D1), (0x8001D7C6, 0x03F0),
+    (0x8001D7C7, 0x03D5), (0x8001D7C8, 0x03F1), (0x8001D7C9, 0x03D6), (0x8001D7CA, 0x03DC),
+    (0x8001D7CB, 0x03DD), (0x8001D7CE, 0x0030), (0x8001D7CF, 0x0031), (0x8001D7D0, 0x0032),
+    (0x8001D7D1, 0x0033), (0x8001D7D2, 0x0034), (0x8001D7D3, 0x0035), (0x8001D7D4, 0x0036),
+    (0x8001D7D5, 0x0037), (0x8001D7D6, 0x0038), (0x8001D7D7, 0x0039), (0x8001D7D8, 0x0030),
+    (0x8001D7D9, 0x0031), (0x8001D7DA, 0x0032), (0x8001D7DB, 0x0033), (0x8001D7DC, 0x0034),
+    (0x8001D7DD, 0x0035), (0x8001D7DE, 0x0036), (0x8001D7DF, 0x0037), (0x8001D7E0, 0x0038),
+    (0x8001D7E1, 0x0039), (0x8001D7E2, 0x0030), (0x8001D7E3, 0x0031), (0x8001D7E4, 0x0032),
+    (0x8001D7E5, 0x0033), (0x8001D7E6, 0x0034), (0x8001D7E7, 0x0035), (0x8001D7E8, 0x0036),
+    (0x8001D7E9, 0x0037), (0x8001D7EA, 0x0038), (0x8001D7EB, 0x0039), (0x8001D7EC, 0x0030),
+    (0x8001D7ED, 0x0031), (0x8001D7EE, 0x0032), (0x8001D7EF, 0x0033), (0x8001D7F0, 0x0034),
+    (0x8001D7F1, 0x0035), (0x8001D7F2, 0x0036), (0x8001D7F3, 0x0037), (0x8001D7F4, 0x0038),
+    (0x8001D7F5, 0x0039), (0x8001D7F6, 0x0030), (0x8001D7F7, 0x0031), (0x8001D7F8, 0x0032),
+    (0x8001D7F9, 0x0033), (0x8001D7FA, 0x0034), (0x8001D7FB, 0x0035), (0x8001D7FC, 0x0036),
+    (0x8001D7FD, 0x0037), (0x8001D7FE, 0x0038), (0x8001D7FF, 0x0039), (0x8001E030, 0x0430),
+    (0x8001E031, 0x0431), (0x8001E032, 0x0432), (0x8001E033, 0x0433), (0x8001E034, 0x0434),
+    (0x8001E035, 0x0435), (0x8001E036, 0x0436), (0x8001E037, 0x0437), (0x8001E038, 0x0438),
+    (0x8001E039, 0x043A), (0x8001E03A, 0x043B), (0x8001E03B, 0x043C), (0x8001E03C, 0x043E),
+    (0x8001E03D, 0x043F), (0x8001E03E, 0x0440), (0x8001E03F, 0x0441), (0x8001E040, 0x0442),
+    (0x8001E041, 0x0443), (0x8001E042, 0x0444), (0x8001E043, 0x0445), (0x8001E044, 0x0446),
+    (0x8001E045, 0x0447), (0x8001E046, 0x0448), (0x8001E047, 0x044B), (0x8001E048, 0x044D),
+    (0x8001E049, 0x044E), (0x8001E04A, 0xA689), (0x8001E04B, 0x04D9), (0x8001E04C, 0x0456),
+    (0x8001E04D, 0x0458), (0x8001E04E, 0x04E9), (0x8001E04F, 0x04AF), (0x8001E050, 0x04CF),
+    (0x8001E051, 0x0430), (0x8001E052, 0x0431), (0x8001E053, 0x0432), (0x8001E054, 0x0433),
+    (0x8001E055, 0x0434), (0x8001E056, 0x0435), (0x8001E057, 0x0436), (0x8001E058, 0x0437),
+    (0x8001E059, 0x0438), (0x8001E05A, 0x043A), (0x8001E05B, 0x043B), (0x8001E05C, 0x043E),
+    (0x8001E05D, 0x043F), (0x8001E05E, 0x0441), (0x8001E05F, 0x0443), (0x8001E060, 0x0444),
+    (0x8001E061, 0x0445), (0x8001E062, 0x0446), (0x8001E063, 0x0447), (0x8001E064, 0x0448),
+    (0x8001E065, 0x044A), (0x8001E066, 0x044B), (0x8001E067, 0x0491), (0x8001E068, 0x0456),
+    (0x8001E069, 0x0455), (0x8001E06A, 0x045F), (0x8001E06B, 0x04AB), (0x8001E06C, 0xA651),
+    (0x8001E06D, 0x04B1), (0x8001EE00, 0x0627), (0x8001EE01, 0x0628), (0x8001EE02, 0x062C),
+    (0x8001EE03, 0x062F), (0x8001EE05, 0x0648), (0x8001EE06, 0x0632), (0x8001EE07, 0x062D),
+    (0x8001EE08, 0x0637), (0x8001EE09, 0x064A), (0x8001EE0A, 0x0643), (0x8001EE0B, 0x0644),
+    (0x8001EE0C, 0x0645), (0x8001EE0D, 0x0646), (0x8001EE0E, 0x0633), (0x8001EE0F, 0x0639),
+    (0x8001EE10, 0x0641), (0x8001EE11, 0x0635), (0x8001EE12, 0x0642), (0x8001EE13, 0x0631),
+    (0x8001EE14, 0x0634), (0x8001EE15, 0x062A), (0x8001EE16, 0x062B), (0x8001EE17, 0x062E),
+    (0x8001EE18, 0x0630), (0x8001EE19, 0x0636), (0x8001EE1A, 0x0638), (0x8001EE1B, 0x063A),
+    (0x8001EE1C, 0x066E), (0x8001EE1D, 0x06BA), (0x8001EE1E, 0x06A1), (0x8001EE1F, 0x066F),
+    (0x8001EE21, 0x0628), (0x8001EE22, 0x062C), (0x8001EE24, 0x0647), (0x8001EE27, 0x062D),
+    (0x8001EE29, 0x064A), (0x8001EE2A, 0x0643), (0x8001EE2B, 0x0644), (0x8001EE2C, 0x0645),
+    (0x8001EE2D, 0x0646), (0x8001EE2E, 0x0633), (0x8001EE2F, 0x0639), (0x8001EE30, 0x0641),
+    (0x8001EE31, 0x0635), (0x8001EE32, 0x0642), (0x8001EE34, 0x0634), (0x8001EE35, 0x062A),
+    (0x8001EE36, 0x062B), (0x8001EE37, 0x062E), (0x8001EE39, 0x0636), (0x8001EE3B, 0x063A),
+    (0x8001EE42, 0x062C), (0x8001EE47, 0x062D), (0x8001EE49, 0x064A), (0x8001EE4B, 0x0644),
+    (0x8001EE4D, 0x0646), (0x8001EE4E, 0x0633), (0x8001EE4F, 0x0639), (0x8001EE51, 0x0635),
+    (0x8001EE52, 0x0642), (0x8001EE54, 0x0634), (0x8001EE57, 0x062E), (0x8001EE59, 0x0636),
+    (0x8001EE5B, 0x063A), (0x8001EE5D, 0x06BA), (0x8001EE5F, 0x066F), (0x8001EE61, 0x0628),
+    (0x8001EE62, 0x062C), (0x8001EE64, 0x0647), (0x8001EE67, 0x062D), (0x8001EE68, 0x0637),
+    (0x8001EE69, 0x064A), (0x8001EE6A, 0x0643), (0x8001EE6C, 0x0645), (0x8001EE6D, 0x0646),
+    (0x8001EE6E, 0x0633), (0x8001EE6F, 0x0639), (0x8001EE70, 0x0641), (0x8001EE71, 0x0635),
+    (0x8001EE72, 0x0642), (0x8001EE74, 0x0634), (0x8001EE75, 0x062A), (0x8001EE76, 0x062B),
+    (0x8001EE77, 0x062E), (0x8001EE79, 0x0636), (0x8001EE7A, 0x0638), (0x8001EE7B, 0x063A),
+    (0x8001EE7C, 0x066E), (0x8001EE7E, 0x06A1), (0x8001EE80, 0x0627), (0x8001EE81, 0x0628),
+    (0x8001EE82, 0x062C), (0x8001EE83, 0x062F), (0x8001EE84, 0x0647), (0x8001EE85, 0x0648),
+    (0x8001EE86, 0x0632), (0x8001EE87, 0x062D), (0x8001EE88, 0x0637), (0x8001EE89, 0x064A),
+    (0x8001EE8B, 0x0644), (0x8001EE8C, 0x0645), (0x8001EE8D, 0x0646), (0x8001EE8E, 0x0633),
+    (0x8001EE8F, 0x0639), (0x8001EE90, 0x0641), (0x8001EE91, 0x0635), (0x8001EE92, 0x0642),
+    (0x8001EE93, 0x0631), (0x8001EE94, 0x0634), (0x8001EE95, 0x062A), (0x8001EE96, 0x062B),
+    (0x8001EE97, 0x062E), (0x8001EE98, 0x0630), (0x8001EE99, 0x0636), (0x8001EE9A, 0x0638),
+    (0x8001EE9B, 0x063A), (0x8001EEA1, 0x0628), (0x8001EEA2, 0x062C), (0x8001EEA3, 0x062F),
+    (0x8001EEA5, 0x0648), (0x8001EEA6, 0x0632), (0x8001EEA7, 0x062D), (0x8001EEA8, 0x0637),
+    (0x8001EEA9, 0x064A), (0x8001EEAB, 0x0644), (0x8001EEAC, 0x0645), (0x8001EEAD, 0x0646),
+    (0x8001EEAE, 0x0633), (0x8001EEAF, 0x0639), (0x8001EEB0, 0x0641), (0x8001EEB1, 0x0635),
+    (0x8001EEB2, 0x0642), (0x8001EEB3, 0x0631), (0x8001EEB4, 0x0634), (0x8001EEB5, 0x062A),
+    (0x8001EEB6, 0x062B), (0x8001EEB7, 0x062E), (0x8001EEB8, 0x0630), (0x8001EEB9, 0x0636),
+    (0x8001EEBA, 0x0638), (0x8001EEBB, 0x063A), (0x8001F100, 0x800212FD), (0x8001F101, 0x800212FF),
+    (0x8001F102, 0x80021301), (0x8001F103, 0x80021303), (0x8001F104, 0x80021305), (0x8001F105, 0x80021307),
+    (0x8001F106, 0x80021309), (0x8001F107, 0x8002130B), (0x8001F108, 0x8002130D), (0x8001F109, 0x8002130F),
+    (0x8001F10A, 0x80021311), (0x8001F110, 0x80031313), (0x8001F111, 0x80031316), (0x8001F112, 0x80031319),
+    (0x8001F113, 0x8003131C), (0x8001F114, 0x8003131F), (0x8001F115, 0x80031322), (0x8001F116, 0x80031325),
+    (0x8001F117, 0x80031328), (0x8001F118, 0x8003132B), (0x8001F119, 0x8003132E), (0x8001F11A, 0x80031331),
+    (0x8001F11B, 0x80031334), (0x8001F11C, 0x80031337), (0x8001F11D, 0x8003133A), (0x8001F11E, 0x8003133D),
+    (0x8001F11F, 0x80031340), (0x8001F120, 0x80031343), (0x8001F121, 0x80031346), (0x8001F122, 0x80031349),
+    (0x8001F123, 0x8003134C), (0x8001F124, 0x8003134F), (0x8001F125, 0x80031352), (0x8001F126, 0x80031355),
+    (0x8001F127, 0x80031358), (0x8001F128, 0x8003135B), (0x8001F129, 0x8003135E), (0x8001F12A, 0x80031361),
+    (0x8001F12B, 0x0043), (0x8001F12C, 0x0052), (0x8001F12D, 0x80021364), (0x8001F12E, 0x80021366),
+    (0x8001F130, 0x0041), (0x8001F131, 0x0042), (0x8001F132, 0x0043), (0x8001F133, 0x0044),
+    (0x8001F134, 0x0045), (0x8001F135, 0x0046), (0x8001F136, 0x0047), (0x8001F137, 0x0048),
+    (0x8001F138, 0x0049), (0x8001F139, 0x004A), (0x8001F13A, 0x004B), (0x8001F13B, 0x004C),
+    (0x8001F13C, 0x004D), (0x8001F13D, 0x004E), (0x8001F13E, 0x004F), (0x8001F13F, 0x0050),
+    (0x8001F140, 0x0051), (0x8001F141, 0x0052), (0x8001F142, 0x0053), (0x8001F143, 0x0054),
+    (0x8001F144, 0x0055), (0x8001F145, 0x0056), (0x8001F146, 0x0057), (0x8001F147, 0x0058),
+    (0x8001F148, 0x0059), (0x8001F149, 0x005A), (0x8001F14A, 0x80021368), (0x8001F14B, 0x8002136A),
+    (0x8001F14C, 0x8002136C), (0x8001F14D, 0x8002136E), (0x8001F14E, 0x80031370), (0x8001F14F, 0x80021373),
+    (0x8001F16A, 0x80021375), (0x8001F16B, 0x80021377), (0x8001F16C, 0x80021379), (0x8001F190, 0x8002137B),
+    (0x8001F200, 0x8002137D), (0x8001F201, 0x8002137F), (0x8001F202, 0x30B5), (0x8001F210, 0x624B),
+    (0x8001F211, 0x5B57), (0x8001F212, 0x53CC), (0x8001F213, 0x30C7), (0x8001F214, 0x4E8C),
+    (0x8001F215, 0x591A), (0x8001F216, 0x89E3), (0x8001F217, 0x5929), (0x8001F218, 0x4EA4),
+    (0x8001F219, 0x6620), (0x8001F21A, 0x7121), (0x8001F21B, 0x6599), (0x8001F21C, 0x524D),
+    (0x8001F21D, 0x5F8C), (0x8001F21E, 0x518D), (0x8001F21F, 0x65B0), (0x8001F220, 0x521D),
+    (0x8001F221, 0x7D42), (0x8001F222, 0x751F), (0x8001F223, 0x8CA9), (0x8001F224, 0x58F0),
+    (0x8001F225, 0x5439), (0x8001F226, 0x6F14), (0x8001F227, 0x6295), (0x8001F228, 0x6355),
+    (0x8001F229, 0x4E00), (0x8001F22A, 0x4E09), (0x8001F22B, 0x904A), (0x8001F22C, 0x5DE6),
+    (0x8001F22D, 0x4E2D), (0x8001F22E, 0x53F3), (0x8001F22F, 0x6307), (0x8001F230, 0x8D70),
+    (0x8001F231, 0x6253), (0x8001F232, 0x7981), (0x8001F233, 0x7A7A), (0x8001F234, 0x5408),
+    (0x8001F235, 0x6E80), (0x8001F236, 0x6709), (0x8001F237, 0x6708), (0x8001F238, 0x7533),
+    (0x8001F239, 0x5272), (0x8001F23A, 0x55B6), (0x8001F23B, 0x914D), (0x8001F240, 0x80031381),
+    (0x8001F241, 0x80031384), (0x8001F242, 0x80031387), (0x8001F243, 0x8003138A), (0x8001F244, 0x8003138D),
+    (0x8001F245, 0x80031390), (0x8001F246, 0x80031393), (0x8001F247, 0x80031396), (0x8001F248, 0x80031399),
+    (0x8001F250, 0x5F97), (0x8001F251, 0x53EF), (0x8001FBF0, 0x0030), (0x8001FBF1, 0x0031),
+    (0x8001FBF2, 0x0032), (0x8001FBF3, 0x0033), (0x8001FBF4, 0x0034), (0x8001FBF5, 0x0035),
+    (0x8001FBF6, 0x0036), (0x8001FBF7, 0x0037), (0x8001FBF8, 0x0038), (0x8001FBF9, 0x0039),
+    (0x2F800, 0x4E3D), (0x2F801, 0x4E38), (0x2F802, 0x4E41), (0x2F803, 0x20122),
+    (0x2F804, 0x4F60), (0x2F805, 0x4FAE), (0x2F806, 0x4FBB), (0x2F807, 0x5002),
+    (0x2F808, 0x507A), (0x2F809, 0x5099), (0x2F80A, 0x50E7), (0x2F80B, 0x50CF),
+    (0x2F80C, 0x349E), (0x2F80D, 0x2063A), (0x2F80E, 0x514D), (0x2F80F, 0x5154),
+    (0x2F810, 0x5164), (0x2F811, 0x5177), (0x2F812, 0x2051C), (0x2F813, 0x34B9),
+    (0x2F814, 0x5167), (0x2F815, 0x518D), (0x2F816, 0x2054B), (0x2F817, 0x5197),
+    (0x2F818, 0x51A4), (0x2F819, 0x4ECC), (0x2F81A, 0x51AC), (0x2F81B, 0x51B5),
+    (0x2F81C, 0x291DF), (0x2F81D, 0x51F5), (0x2F81E, 0x5203), (0x2F81F, 0x34DF),
+    (0x2F820, 0x523B), (0x2F821, 0x5246), (0x2F822, 0x5272), (0x2F823, 0x5277),
+    (0x2F824, 0x3515), (0x2F825, 0x52C7), (0x2F826, 0x52C9), (0x2F827, 0x52E4),
+    (0x2F828, 0x52FA), (0x2F829, 0x5305), (0x2F82A, 0x5306), (0x2F82B, 0x5317),
+    (0x2F82C, 0x5349), (0x2F82D, 0x5351), (0x2F82E, 0x535A), (0x2F82F, 0x5373),
+    (0x2F830, 0x537D), (0x2F831, 0x537F), (0x2F832, 0x537F), (0x2F833, 0x537F),
+    (0x2F834, 0x20A2C), (0x2F835, 0x7070), (0x2F836, 0x53CA), (0x2F837, 0x53DF),
+    (0x2F838, 0x20B63), (0x2F839, 0x53EB), (0x2F83A, 0x53F1), (0x2F83B, 0x5406),
+    (0x2F83C, 0x549E), (0x2F83D, 0x5438), (0x2F83E, 0x5448), (0x2F83F, 0x5468),
+    (0x2F840, 0x54A2), (0x2F841, 0x54F6), (0x2F842, 0x5510), (0x2F843, 0x5553),
+    (0x2F844, 0x5563), (0x2F845, 0x5584), (0x2F846, 0x5584), (0x2F847, 0x5599),
+    (0x2F848, 0x55AB), (0x2F849, 0x55B3), (0x2F84A, 0x55C2), (0x2F84B, 0x5716),
+    (0x2F84C, 0x5606), (0x2F84D, 0x5717), (0x2F84E, 0x5651), (0x2F84F, 0x5674),
+    (0x2F850, 0x5207), (0x2F851, 0x58EE), (0x2F852, 0x57CE), (0x2F853, 0x57F4),
+    (0x2F854, 0x580D), (0x2F855, 0x578B), (0x2F856, 0x5832), (0x2F857, 0x5831),
+    (0x2F858, 0x58AC), (0x2F859, 0x214E4), (0x2F85A, 0x58F2), (0x2F85B, 0x58F7),
+    (0x2F85C, 0x5906), (0x2F85D, 0x591A), (0x2F85E, 0x5922), (0x2F85F, 0x5962),
+    (0x2F860, 0x216A8), (0x2F861, 0x216EA), (0x2F862, 0x59EC), (0x2F863, 0x5A1B),
+    (0x2F864, 0x5A27), (0x2F865, 0x59D8), (0x2F866, 0x5A66), (0x2F867, 0x36EE),
+    (0x2F868, 0x36FC), (0x2F869, 0x5B08), (0x2F86A, 0x5B3E), (0x2F86B, 0x5B3E),
+    (0x2F86C, 0x219C8), (0x2F86D, 0x5BC3), (0x2F86E, 0x5BD8), (0x2F86F, 0x5BE7),
+    (0x2F870, 0x5BF3), (0x2F871, 0x21B18), (0x2F872, 0x5BFF), (0x2F873, 0x5C06),
+    (0x2F874, 0x5F53), (0x2F875, 0x5C22), (0x2F876, 0x3781), (0x2F877, 0x5C60),
+    (0x2F878, 0x5C6E), (0x2F879, 0x5CC0), (0x2F87A, 0x5C8D), (0x2F87B, 0x21DE4),
+    (0x2F87C, 0x5D43), (0x2F87D, 0x21DE6), (0x2F87E, 0x5D6E), (0x2F87F, 0x5D6B),
+    (0x2F880, 0x5D7C), (0x2F881, 0x5DE1), (0x2F882, 0x5DE2), (0x2F883, 0x382F),
+    (0x2F884, 0x5DFD), (0x2F885, 0x5E28), (0x2F886, 0x5E3D), (0x2F887, 0x5E69),
+    (0x2F888, 0x3862), (0x2F889, 0x22183), (0x2F88A, 0x387C), (0x2F88B, 0x5EB0),
+    (0x2F88C, 0x5EB3), (0x2F88D, 0x5EB6), (0x2F88E, 0x5ECA), (0x2F88F, 0x2A392),
+    (0x2F890, 0x5EFE), (0x2F891, 0x22331), (0x2F892, 0x22331), (0x2F893, 0x8201),
+    (0x2F894, 0x5F22), (0x2F895, 0x5F22), (0x2F896, 0x38C7), (0x2F897, 0x232B8),
+    (0x2F898, 0x261DA), (0x2F899, 0x5F62), (0x2F89A, 0x5F6B), (0x2F89B, 0x38E3),
+    (0x2F89C, 0x5F9A), (0x2F89D, 0x5FCD), (0x2F89E, 0x5FD7), (0x2F89F, 0x5FF9),
+    (0x2F8A0, 0x6081), (0x2F8A1, 0x393A), (0x2F8A2, 0x391C), (0x2F8A3, 0x6094),
+    (0x2F8A4, 0x226D4), (0x2F8A5, 0x60C7), (0x2F8A6, 0x6148), (0x2F8A7, 0x614C),
+    (0x2F8A8, 0x614E), (0x2F8A9, 0x614C), (0x2F8AA, 0x617A), (0x2F8AB, 0x618E),
+    (0x2F8AC, 0x61B2), (0x2F8AD, 0x61A4), (0x2F8AE, 0x61AF), (0x2F8AF, 0x61DE),
+    (0x2F8B0, 0x61F2), (0x2F8B1, 0x61F6), (0x2F8B2, 0x6210), (0x2F8B3, 0x621B),
+    (0x2F8B4, 0x625D), (0x2F8B5, 0x62B1), (0x2F8B6, 0x62D4), (0x2F8B7, 0x6350),
+    (0x2F8B8, 0x22B0C), (0x2F8B9, 0x633D), (0x2F8BA, 0x62FC), (0x2F8BB, 0x6368),
+    (0x2F8BC, 0x6383), (0x2F8BD, 0x63E4), (0x2F8BE, 0x22BF1), (0x2F8BF, 0x6422),
+    (0x2F8C0, 0x63C5), (0x2F8C1, 0x63A9), (0x2F8C2, 0x3A2E), (0x2F8C3, 0x6469),
+    (0x2F8C4, 0x647E), (0x2F8C5, 0x649D), (0x2F8C6, 0x6477), (0x2F8C7, 0x3A6C),
+    (0x2F8C8, 0x654F), (0x2F8C9, 0x656C), (0x2F8CA, 0x2300A), (0x2F8CB, 0x65E3),
+    (0x2F8CC, 0x66F8), (0x2F8CD, 0x6649), (0x2F8CE, 0x3B19), (0x2F8CF, 0x6691),
+    (0x2F8D0, 0x3B08), (0x2F8D1, 0x3AE4), (0x2F8D2, 0x5192), (0x2F8D3, 0x5195),
+    (0x2F8D4, 0x6700), (0x2F8D5, 0x669C), (0x2F8D6, 0x80AD), (0x2F8D7, 0x43D9),
+    (0x2F8D8, 0x6717), (0x2F8D9, 0x671B), (0x2F8DA, 0x6721), (0x2F8DB, 0x675E),
+    (0x2F8DC, 0x6753), (0x2F8DD, 0x233C3), (0x2F8DE, 0x3B49), (0x2F8DF, 0x67FA),
+    (0x2F8E0, 0x6785), (0x2F8E1, 0x6852), (0x2F8E2, 0x6885), (0x2F8E3, 0x2346D),
+    (0x2F8E4, 0x688E), (0x2F8E5, 0x681F), (0x2F8E6, 0x6914), (0x2F8E7, 0x3B9D),
+    (0x2F8E8, 0x6942), (0x2F8E9, 0x69A3), (0x2F8EA, 0x69EA), (0x2F8EB, 0x6AA8),
+    (0x2F8EC, 0x236A3), (0x2F8ED, 0x6ADB), (0x2F8EE, 0x3C18), (0x2F8EF, 0x6B21),
+    (0x2F8F0, 0x238A7), (0x2F8F1, 0x6B54), (0x2F8F2, 0x3C4E), (0x2F8F3, 0x6B72),
+    (0x2F8F4, 0x6B9F), (0x2F8F5, 0x6BBA), (0x2F8F6, 0x6BBB), (0x2F8F7, 0x23A8D),
+    (0x2F8F8, 0x21D0B), (0x2F8F9, 0x23AFA), (0x2F8FA, 0x6C4E), (0x2F8FB, 0x23CBC),
+    (0x2F8FC, 0x6CBF), (0x2F8FD, 0x6CCD), (0x2F8FE, 0x6C67), (0x2F8FF, 0x6D16),
+    (0x2F900, 0x6D3E), (0x2F901, 0x6D77), (0x2F902, 0x6D41), (0x2F903, 0x6D69),
+    (0x2F904, 0x6D78), (0x2F905, 0x6D85), (0x2F906, 0x23D1E), (0x2F907, 0x6D34),
+    (0x2F908, 0x6E2F), (0x2F909, 0x6E6E), (0x2F90A, 0x3D33), (0x2F90B, 0x6ECB),
+    (0x2F90C, 0x6EC7), (0x2F90D, 0x23ED1), (0x2F90E, 0x6DF9), (0x2F90F, 0x6F6E),
+    (0x2F910, 0x23F5E), (0x2F911, 0x23F8E), (0x2F912, 0x6FC6), (0x2F913, 0x7039),
+    (0x2F914, 0x701E), (0x2F915, 0x701B), (0x2F916, 0x3D96), (0x2F917, 0x704A),
+    (0x2F918, 0x707D), (0x2F919, 0x7077), (0x2F91A, 0x70AD), (0x2F91B, 0x20525),
+    (0x2F91C, 0x7145), (0x2F91D, 0x24263), (0x2F91E, 0x719C), (0x2F91F, 0x243AB),
+    (0x2F920, 0x7228), (0x2F921, 0x7235), (0x2F922, 0x7250), (0x2F923, 0x24608),
+    (0x2F924, 0x7280), (0x2F925, 0x7295), (0x2F926, 0x24735), (0x2F927, 0x24814),
+    (0x2F928, 0x737A), (0x2F929, 0x738B), (0x2F92A, 0x3EAC), (0x2F92B, 0x73A5),
+    (0x2F92C, 0x3EB8), (0x2F92D, 0x3EB8), (0x2F92E, 0x7447), (0x2F92F, 0x745C),
+    (0x2F930, 0x7471), (0x2F931, 0x7485), (0x2F932, 0x74CA), (0x2F933, 0x3F1B),
+    (0x2F934, 0x7524), (0x2F935, 0x24C36), (0x2F936, 0x753E), (0x2F937, 0x24C92),
+    (0x2F938, 0x7570), (0x2F939, 0x2219F), (0x2F93A, 0x7610), (0x2F93B, 0x24FA1),
+    (0x2F93C, 0x24FB8), (0x2F93D, 0x25044), (0x2F93E, 0x3FFC), (0x2F93F, 0x4008),
+    (0x2F940, 0x76F4), (0x2F941, 0x250F3), (0x2F942, 0x250F2), (0x2F943, 0x25119),
+    (0x2F944, 0x25133), (0x2F945, 0x771E), (0x2F946, 0x771F), (0x2F947, 0x771F),
+    (0x2F948, 0x774A), (0x2F949, 0x4039), (0x2F94A, 0x778B), (0x2F94B, 0x4046),
+    (0x2F94C, 0x4096), (0x2F94D, 0x2541D), (0x2F94E, 0x784E), (0x2F94F, 0x788C),
+    (0x2F950, 0x78CC), (0x2F951, 0x40E3), (0x2F952, 0x25626), (0x2F953, 0x7956),
+    (0x2F954, 0x2569A), (0x2F955, 0x256C5), (0x2F956, 0x798F), (0x2F957, 0x79EB),
+    (0x2F958, 0x412F), (0x2F959, 0x7A40), (0x2F95A, 0x7A4A), (0x2F95B, 0x7A4F),
+    (0x2F95C, 0x2597C), (0x2F95D, 0x25AA7), (0x2F95E, 0x25AA7), (0x2F95F, 0x7AEE),
+    (0x2F960, 0x4202), (0x2F961, 0x25BAB), (0x2F962, 0x7BC6), (0x2F963, 0x7BC9),
+    (0x2F964, 0x4227), (0x2F965, 0x25C80), (0x2F966, 0x7CD2), (0x2F967, 0x42A0),
+    (0x2F968, 0x7CE8), (0x2F969, 0x7CE3), (0x2F96A, 0x7D00), (0x2F96B, 0x25F86),
+    (0x2F96C, 0x7D63), (0x2F96D, 0x4301), (0x2F96E, 0x7DC7), (0x2F96F, 0x7E02),
+    (0x2F970, 0x7E45), (0x2F971, 0x4334), (0x2F972, 0x26228), (0x2F973, 0x26247),
+    (0x2F974, 0x4359), (0x2F975, 0x262D9), (0x2F976, 0x7F7A), (0x2F977, 0x2633E),
+    (0x2F978, 0x7F95), (0x2F979, 0x7FFA), (0x2F97A, 0x8005), (0x2F97B, 0x264DA),
+    (0x2F97C, 0x26523), (0x2F97D, 0x8060), (0x2F97E, 0x265A8), (0x2F97F, 0x8070),
+    (0x2F980, 0x2335F), (0x2F981, 0x43D5), (0x2F982, 0x80B2), (0x2F983, 0x8103),
+    (0x2F984, 0x440B), (0x2F985, 0x813E), (0x2F986, 0x5AB5), (0x2F987, 0x267A7),
+    (0x2F988, 0x267B5), (0x2F989, 0x23393), (0x2F98A, 0x2339C), (0x2F98B, 0x8201),
+    (0x2F98C, 0x8204), (0x2F98D, 0x8F9E), (0x2F98E, 0x446B), (0x2F98F, 0x8291),
+    (0x2F990, 0x828B), (0x2F991, 0x829D), (0x2F992, 0x52B3), (0x2F993, 0x82B1),
+    (0x2F994, 0x82B3), (0x2F995, 0x82BD), (0x2F996, 0x82E6), (0x2F997, 0x26B3C),
+    (0x2F998, 0x82E5), (0x2F999, 0x831D), (0x2F99A, 0x8363), (0x2F99B, 0x83AD),
+    (0x2F99C, 0x8323), (0x2F99D, 0x83BD), (0x2F99E, 0x83E7), (0x2F99F, 0x8457),
+    (0x2F9A0, 0x8353), (0x2F9A1, 0x83CA), (0x2F9A2, 0x83CC), (0x2F9A3, 0x83DC),
+    (0x2F9A4, 0x26C36), (0x2F9A5, 0x26D6B), (0x2F9A6, 0x26CD5), (0x2F9A7, 0x452B),
+    (0x2F9A8, 0x84F1), (0x2F9A9, 0x84F3), (0x2F9AA, 0x8516), (0x2F9AB, 0x273CA),
+    (0x2F9AC, 0x8564), (0x2F9AD, 0x26F2C), (0x2F9AE, 0x455D), (0x2F9AF, 0x4561),
+    (0x2F9B0, 0x26FB1), (0x2F9B1, 0x270D2), (0x2F9B2, 0x456B), (0x2F9B3, 0x8650),
+    (0x2F9B4, 0x865C), (0x2F9B5, 0x8667), (0x2F9B6, 0x8669), (0x2F9B7, 0x86A9),
+    (0x2F9B8, 0x8688), (0x2F9B9, 0x870E), (0x2F9BA, 0x86E2), (0x2F9BB, 0x8779),
+    (0x2F9BC, 0x8728), (0x2F9BD, 0x876B), (0x2F9BE, 0x8786), (0x2F9BF, 0x45D7),
+    (0x2F9C0, 0x87E1), (0x2F9C1, 0x8801), (0x2F9C2, 0x45F9), (0x2F9C3, 0x8860),
+    (0x2F9C4, 0x8863), (0x2F9C5, 0x27667), (0x2F9C6, 0x88D7), (0x2F9C7, 0x88DE),
+    (0x2F9C8, 0x4635), (0x2F9C9, 0x88FA), (0x2F9CA, 0x34BB), (0x2F9CB, 0x278AE),
+    (0x2F9CC, 0x27966), (0x2F9CD, 0x46BE), (0x2F9CE, 0x46C7), (0x2F9CF, 0x8AA0),
+    (0x2F9D0, 0x8AED), (0x2F9D1, 0x8B8A), (0x2F9D2, 0x8C55), (0x2F9D3, 0x27CA8),
+    (0x2F9D4, 0x8CAB), (0x2F9D5, 0x8CC1), (0x2F9D6, 0x8D1B), (0x2F9D7, 0x8D77),
+    (0x2F9D8, 0x27F2F), (0x2F9D9, 0x20804), (0x2F9DA, 0x8DCB), (0x2F9DB, 0x8DBC),
+    (0x2F9DC, 0x8DF0), (0x2F9DD, 0x208DE), (0x2F9DE, 0x8ED4), (0x2F9DF, 0x8F38),
+    (0x2F9E0, 0x285D2), (0x2F9E1, 0x285ED), (0x2F9E2, 0x9094), (0x2F9E3, 0x90F1),
+    (0x2F9E4, 0x9111), (0x2F9E5, 0x2872E), (0x2F9E6, 0x911B), (0x2F9E7, 0x9238),
+    (0x2F9E8, 0x92D7), (0x2F9E9, 0x92D8), (0x2F9EA, 0x927C), (0x2F9EB, 0x93F9),
+    (0x2F9EC, 0x9415), (0x2F9ED, 0x28BFA), (0x2F9EE, 0x958B), (0x2F9EF, 0x4995),
+    (0x2F9F0, 0x95B7), (0x2F9F1, 0x28D77), (0x2F9F2, 0x49E6), (0x2F9F3, 0x96C3),
+    (0x2F9F4, 0x5DB2), (0x2F9F5, 0x9723), (0x2F9F6, 0x29145), (0x2F9F7, 0x2921A),
+    (0x2F9F8, 0x4A6E), (0x2F9F9, 0x4A76), (0x2F9FA, 0x97E0), (0x2F9FB, 0x2940A),
+    (0x2F9FC, 0x4AB2), (0x2F9FD, 0x29496), (0x2F9FE, 0x980B), (0x2F9FF, 0x980B),
+    (0x2FA00, 0x9829), (0x2FA01, 0x295B6), (0x2FA02, 0x98E2), (0x2FA03, 0x4B33),
+    (0x2FA04, 0x9929), (0x2FA05, 0x99A7), (0x2FA06, 0x99C2), (0x2FA07, 0x99FE),
+    (0x2FA08, 0x4BCE), (0x2FA09, 0x29B30), (0x2FA0A, 0x9B12), (0x2FA0B, 0x9C40),
+    (0x2FA0C, 0x9CFD), (0x2FA0D, 0x4CCE), (0x2FA0E, 0x4CED), (0x2FA0F, 0x9D67),
+    (0x2FA10, 0x2A0CE), (0x2FA11, 0x4CF8), (0x2FA12, 0x2A105), (0x2FA13, 0x2A20E),
+    (0x2FA14, 0x2A291), (0x2FA15, 0x9EBB), (0x2FA16, 0x4D56), (0x2FA17, 0x9EF9),
+    (0x2FA18, 0x9EFE), (0x2FA19, 0x9F05), (0x2FA1A, 0x9F0F), (0x2FA1B, 0x9F16),
+    (0x2FA1C, 0x9F3B), (0x2FA1D, 0x2A600),
+];
+
+/// Characters of the decomposition mappings to several characters.
+pub static DECOMPOSITION_EXPANSIONS: [u32; 5020] = [
+    0x0020, 0x0308, 0x0020, 0x0304, 0x0020, 0x0301, 0x0020, 0x0327,
+    0x0031, 0x2044, 0x0034, 0x0031, 0x2044, 0x0032, 0x0033, 0x2044,
+    0x0034, 0x0041, 0x0300, 0x0041, 0x0301, 0x0041, 0x0302, 0x0041,
+    0x0303, 0x0041, 0x0308, 0x0041, 0x030A, 0x0043, 0x0327, 0x0045,
+    0x0300, 0x0045, 0x0301, 0x0045, 0x0302, 0x0045, 0x0308, 0x0049,
+    0x0300, 0x0049, 0x0301, 0x0049, 0x0302, 0x0049, 0x0308, 0x004E,
+    0x0303, 0x004F, 0x0300, 0x004F, 0x0301, 0x004F, 0x0302, 0x004F,
+    0x0303, 0x004F, 0x0308, 0x0055, 0x0300, 0x0055, 0x0301, 0x0055,
+    0x0302, 0x0055, 0x0308, 0x0059, 0x0301, 0x0061, 0x0300, 0x0061,
+    0x0301, 0x0061, 0x0302, 0x0061, 0x0303, 0x0061, 0x0308, 0x0061,
+    0x030A, 0x0063, 0x0327, 0x0065, 0x0300, 0x0065, 0x0301, 0x0065,
+    0x0302, 0x0065, 0x0308, 0x0069, 0x0300, 0x0069, 0x0301, 0x0069,
+    0x0302, 0x0069, 0x0308, 0x006E, 0x0303, 0x006F, 0x0300, 0x006F,
+    0x0301, 0x006F, 0x0302, 0x006F, 0x0303, 0x006F, 0x0308, 0x0075,
+    0x0300, 0x0075, 0x0301, 0x0075, 0x0302, 0x0075, 0x0308, 0x0079,
+    0x0301, 0x0079, 0x0308, 0x0041, 0x0304, 0x0061, 0x0304, 0x0041,
+    0x0306, 0x0061, 0x0306, 0x0041, 0x0328, 0x0061, 0x0328, 0x0043,
+    0x0301, 0x0063, 0x0301, 0x0043, 0x0302, 0x0063, 0x0302, 0x0043,
+    0x0307, 0x0063, 0x0307, 0x0043, 0x030C, 0x0063, 0x030C, 0x0044,
+    0x030C, 0x0064, 0x030C, 0x0045, 0x0304, 0x0065, 0x0304, 0x0045,
+    0x0306, 0x0065, 0x0306, 0x0045, 0x0307, 0x0065, 0x0307, 0x0045,
+    0x0328, 0x0065, 0x0328, 0x0045, 0x030C, 0x0065, 0x030C, 0x0047,
+    0x0302, 0x0067, 0x0302, 0x0047, 0x0306, 0x0067, 0x0306, 0x0047,
+    0x0307, 0x0067, 0x0307, 0x0047, 0x0327, 0x0067, 0x0327, 0x0048,
+    0x0302, 0x0068, 0x0302, 0x0049, 0x0303, 0x0069, 0x0303, 0x0049,
+    0x0304, 0x0069, 0x0304, 0x0049, 0x0306, 0x0069, 0x0306, 0x0049,
+    0x0328, 0x0069, 0x0328, 0x0049, 0x0307, 0x0049, 0x004A, 0x0069,
+    0x006A, 0x004A, 0x0302, 0x006A, 0x0302, 0x004B, 0x0327, 0x006B,
+    0x0327, 0x004C, 0x0301, 0x006C, 0x0301, 0x004C, 0x0327, 0x006C,
+    0x0327, 0x004C, 0x030C, 0x006C, 0x030C, 0x004C, 0x00B7, 0x006C,
+    0x00B7, 0x004E, 0x0301, 0x006E, 0x0301, 0x004E, 0x0327, 0x006E,
+    0x0327, 0x004E, 0x030C, 0x006E, 0x030C, 0x02BC, 0x006E, 0x004F,
+    0x0304, 0x006F, 0x0304, 0x004F, 0x0306, 0x006F, 0x0306, 0x004F,
+    0x030B, 0x006F, 0x030B, 0x0052, 0x0301, 0x0072, 0x0301, 0x0052,
+    0x0327, 0x0072, 0x0327, 0x0052, 0x030C, 0x0072, 0x030C, 0x0053,
+    0x0301, 0x0073, 0x0301, 0x0053, 0x0302, 0x0073, 0x0302, 0x0053,
+    0x0327, 0x0073, 0x0327, 0x0053, 0x030C, 0x0073, 0x030C, 0x0054,
+    0x0327, 0x0074, 0x0327, 0x0054, 0x030C, 0x0074, 0x030C, 0x0055,
+    0x0303, 0x0075, 0x0303, 0x0055, 0x0304, 0x0075, 0x0304, 0x0055,
+    0x0306, 0x0075, 0x0306, 0x0055, 0x030A, 0x0075, 0x030A, 0x0055,
+    0x030B, 0x0075, 0x030B, 0x0055, 0x0328, 0x0075, 0x0328, 0x0057,
+    0x0302, 0x0077, 0x0302, 0x0059, 0x0302, 0x0079, 0x0302, 0x0059,
+    0x0308, 0x005A, 0x0301, 0x007A, 0x0301, 0x005A, 0x0307, 0x007A,
+    0x0307, 0x005A, 0x030C, 0x007A, 0x030C, 0x004F, 0x031B, 0x006F,
+    0x031B, 0x0055, 0x031B, 0x0075, 0x031B, 0x0044, 0x017D, 0x0044,
+    0x017E, 0x0064, 0x017E, 0x004C, 0x004A, 0x004C, 0x006A, 0x006C,
+    0x006A, 0x004E, 0x004A, 0x004E, 0x006A, 0x006E, 0x006A, 0x0041,
+    0x030C, 0x0061, 0x030C, 0x0049, 0x030C, 0x0069, 0x030C, 0x004F,
+    0x030C, 0x006F, 0x030C, 0x0055, 0x030C, 0x0075, 0x030C, 0x00DC,
+    0x0304, 0x00FC, 0x0304, 0x00DC, 0x0301, 0x00FC, 0x0301, 0x00DC,
+    0x030C, 0x00FC, 0x030C, 0x00DC, 0x0300, 0x00FC, 0x0300, 0x00C4,
+    0x0304, 0x00E4, 0x0304, 0x0226, 0x0304, 0x0227, 0x0304, 0x00C6,
+    0x0304, 0x00E6, 0x0304, 0x0047, 0x030C, 0x0067, 0x030C, 0x004B,
+    0x030C, 0x006B, 0x030C, 0x004F, 0x0328, 0x006F, 0x0328, 0x01EA,
+    0x0304, 0x01EB, 0x0304, 0x01B7, 0x030C, 0x0292, 0x030C, 0x006A,
+    0x030C, 0x0044, 0x005A, 0x0044, 0x007A, 0x0064, 0x007A, 0x0047,
+    0x0301, 0x0067, 0x0301, 0x004E, 0x0300, 0x006E, 0x0300, 0x00C5,
+    0x0301, 0x00E5, 0x0301, 0x00C6, 0x0301, 0x00E6, 0x0301, 0x00D8,
+    0x0301, 0x00F8, 0x0301, 0x0041, 0x030F, 0x0061, 0x030F, 0x0041,
+    0x0311, 0x0061, 0x0311, 0x0045, 0x030F, 0x0065, 0x030F, 0x0045,
+    0x0311, 0x0065, 0x0311, 0x0049, 0x030F, 0x0069, 0x030F, 0x0049,
+    0x0311, 0x0069, 0x0311, 0x004F, 0x030F, 0x006F, 0x030F, 0x004F,
+    0x0311, 0x006F, 0x0311, 0x0052, 0x030F, 0x0072, 0x030F, 0x0052,
+    0x0311, 0x0072, 0x0311, 0x0055, 0x030F, 0x0075, 0x030F, 0x0055,
+    0x0311, 0x0075, 0x0311, 0x0053, 0x0326, 0x0073, 0x0326, 0x0054,
+    0x0326, 0x0074, 0x0326, 0x0048, 0x030C, 0x0068, 0x030C, 0x0041,
+    0x0307, 0x0061, 0x0307, 0x0045, 0x0327, 0x0065, 0x0327, 0x00D6,
+    0x0304, 0x00F6, 0x0304, 0x00D5, 0x0304, 0x00F5, 0x0304, 0x004F,
+    0x0307, 0x006F, 0x0307, 0x022E, 0x0304, 0x022F, 0x0304, 0x0059,
+    0x0304, 0x0079, 0x0304, 0x0020, 0x0306, 0x0020, 0x0307, 0x0020,
+    0x030A, 0x0020, 0x0328, 0x0020, 0x0303, 0x0020, 0x030B, 0x0308,
+    0x0301, 0x0020, 0x0345, 0x0020, 0x0301, 0x00A8, 0x0301, 0x0391,
+    0x0301, 0x0395, 0x0301, 0x0397, 0x0301, 0x0399, 0x0301, 0x039F,
+    0x0301, 0x03A5, 0x0301, 0x03A9, 0x0301, 0x03CA, 0x0301, 0x0399,
+    0x0308, 0x03A5, 0x0308, 0x03B1, 0x0301, 0x03B5, 0x0301, 0x03B7,
+    0x0301, 0x03B9, 0x0301, 0x03CB, 0x0301, 0x03B9, 0x0308, 0x03C5,
+    0x0308, 0x03BF, 0x0301, 0x03C5, 0x0301, 0x03C9, 0x0301, 0x03D2,
+    0x0301, 0x03D2, 0x0308, 0x0415, 0x0300, 0x0415, 0x0308, 0x0413,
+    0x0301, 0x0406, 0x0308, 0x041A, 0x0301, 0x0418, 0x0300, 0x0423,
+    0x0306, 0x0418, 0x0306, 0x0438, 0x0306, 0x0435, 0x0300, 0x0435,
+    0x0308, 0x0433, 0x0301, 0x0456, 0x0308, 0x043A, 0x0301, 0x0438,
+    0x0300, 0x0443, 0x0306, 0x0474, 0x030F, 0x0475, 0x030F, 0x0416,
+    0x0306, 0x0436, 0x0306, 0x0410, 0x0306, 0x0430, 0x0306, 0x0410,
+    0x0308, 0x0430, 0x0308, 0x0415, 0x0306, 0x0435, 0x0306, 0x04D8,
+    0x0308, 0x04D9, 0x0308, 0x0416, 0x0308, 0x0436, 0x0308, 0x0417,
+    0x0308, 0x0437, 0x0308, 0x0418, 0x0304, 0x0438, 0x0304, 0x0418,
+    0x0308, 0x0438, 0x0308, 0x041E, 0x0308, 0x043E, 0x0308, 0x04E8,
+    0x0308, 0x04E9, 0x0308, 0x042D, 0x0308, 0x044D, 0x0308, 0x0423,
+    0x0304, 0x0443, 0x0304, 0x0423, 0x0308, 0x0443, 0x0308, 0x0423,
+    0x030B, 0x0443, 0x030B, 0x0427, 0x0308, 0x0447, 0x0308, 0x042B,
+    0x0308, 0x044B, 0x0308, 0x0565, 0x0582, 0x0627, 0x0653, 0x0627,
+    0x0654, 0x0648, 0x0654, 0x0627, 0x0655, 0x064A, 0x0654, 0x0627,
+    0x0674, 0x0648, 0x0674, 0x06C7, 0x0674, 0x064A, 0x0674, 0x06D5,
+    0x0654, 0x06C1, 0x0654, 0x06D2, 0x0654, 0x0928, 0x093C, 0x0930,
+    0x093C, 0x0933, 0x093C, 0x0915, 0x093C, 0x0916, 0x093C, 0x0917,
+    0x093C, 0x091C, 0x093C, 0x0921, 0x093C, 0x0922, 0x093C, 0x092B,
+    0x093C, 0x092F, 0x093C, 0x09C7, 0x09BE, 0x09C7, 0x09D7, 0x09A1,
+    0x09BC, 0x09A2, 0x09BC, 0x09AF, 0x09BC, 0x0A32, 0x0A3C, 0x0A38,
+    0x0A3C, 0x0A16, 0x0A3C, 0x0A17, 0x0A3C, 0x0A1C, 0x0A3C, 0x0A2B,
+    0x0A3C, 0x0B47, 0x0B56, 0x0B47, 0x0B3E, 0x0B47, 0x0B57, 0x0B21,
+    0x0B3C, 0x0B22, 0x0B3C, 0x0B92, 0x0BD7, 0x0BC6, 0x0BBE, 0x0BC7,
+    0x0BBE, 0x0BC6, 0x0BD7, 0x0C46, 0x0C56, 0x0CBF, 0x0CD5, 0x0CC6,
+    0x0CD5, 0x0CC6, 0x0CD6, 0x0CC6, 0x0CC2, 0x0CCA, 0x0CD5, 0x0D46,
+    0x0D3E, 0x0D47, 0x0D3E, 0x0D46, 0x0D57, 0x0DD9, 0x0DCA, 0x0DD9,
+    0x0DCF, 0x0DDC, 0x0DCA, 0x0DD9, 0x0DDF, 0x0E4D, 0x0E32, 0x0ECD,
+    0x0EB2, 0x0EAB, 0x0E99, 0x0EAB, 0x0EA1, 0x0F42, 0x0FB7, 0x0F4C,
+    0x0FB7, 0x0F51, 0x0FB7, 0x0F56, 0x0FB7, 0x0F5B, 0x0FB7, 0x0F40,
+    0x0FB5, 0x0F71, 0x0F72, 0x0F71, 0x0F74, 0x0FB2, 0x0F80, 0x0FB2,
+    0x0F81, 0x0FB3, 0x0F80, 0x0FB3, 0x0F81, 0x0F71, 0x0F80, 0x0F92,
+    0x0FB7, 0x0F9C, 0x0FB7, 0x0FA1, 0x0FB7, 0x0FA6, 0x0FB7, 0x0FAB,
+    0x0FB7, 0x0F90, 0x0FB5, 0x1025, 0x102E, 0x1B05, 0x1B35, 0x1B07,
+    0x1B35, 0x1B09, 0x1B35, 0x1B0B, 0x1B35, 0x1B0D, 0x1B35, 0x1B11,
+    0x1B35, 0x1B3A, 0x1B35, 0x1B3C, 0x1B35, 0x1B3E, 0x1B35, 0x1B3F,
+    0x1B35, 0x1B42, 0x1B35, 0x0041, 0x0325, 0x0061, 0x0325, 0x0042,
+    0x0307, 0x0062, 0x0307, 0x0042, 0x0323, 0x0062, 0x0323, 0x0042,
+    0x0331, 0x0062, 0x0331, 0x00C7, 0x0301, 0x00E7, 0x0301, 0x0044,
+    0x0307, 0x0064, 0x0307, 0x0044, 0x0323, 0x0064, 0x0323, 0x0044,
+    0x0331, 0x0064, 0x0331, 0x0044, 0x0327, 0x0064, 0x0327, 0x0044,
+    0x032D, 0x0064, 0x032D, 0x0112, 0x0300, 0x0113, 0x0300, 0x0112,
+    0x0301, 0x0113, 0x0301, 0x0045, 0x032D, 0x0065, 0x032D, 0x0045,
+    0x0330, 0x0065, 0x0330, 0x0228, 0x0306, 0x0229, 0x0306, 0x0046,
+    0x0307, 0x0066, 0x0307, 0x0047, 0x0304, 0x0067, 0x0304, 0x0048,
+    0x0307, 0x0068, 0x0307, 0x0048, 0x0323, 0x0068, 0x0323, 0x0048,
+    0x0308, 0x0068, 0x0308, 0x0048, 0x0327, 0x0068, 0x0327, 0x0048,
+    0x032E, 0x0068, 0x032E, 0x0049, 0x0330, 0x0069, 0x0330, 0x00CF,
+    0x0301, 0x00EF, 0x0301, 0x004B, 0x0301, 0x006B, 0x0301, 0x004B,
+    0x0323, 0x006B, 0x0323, 0x004B, 0x0331, 0x006B, 0x0331, 0x004C,
+    0x0323, 0x006C, 0x0323, 0x1E36, 0x0304, 0x1E37, 0x0304, 0x004C,
+    0x0331, 0x006C, 0x0331, 0x004C, 0x032D, 0x006C, 0x032D, 0x004D,
+    0x0301, 0x006D, 0x0301, 0x004D, 0x0307, 0x006D, 0x0307, 0x004D,
+    0x0323, 0x006D, 0x0323, 0x004E, 0x0307, 0x006E, 0x0307, 0x004E,
+    0x0323, 0x006E, 0x0323, 0x004E, 0x0331, 0x006E, 0x0331, 0x004E,
+    0x032D, 0x006E, 0x032D, 0x00D5, 0x0301, 0x00F5, 0x0301, 0x00D5,
+    0x0308, 0x00F5, 0x0308, 0x014C, 0x0300, 0x014D, 0x0300, 0x014C,
+    0x0301, 0x014D, 0x0301, 0x0050, 0x0301, 0x0070, 0x0301, 0x0050,
+    0x0307, 0x0070, 0x0307, 0x0052, 0x0307, 0x0072, 0x0307, 0x0052,
+    0x0323, 0x0072, 0x0323, 0x1E5A, 0x0304, 0x1E5B, 0x0304, 0x0052,
+    0x0331, 0x0072, 0x0331, 0x0053, 0x0307, 0x0073, 0x0307, 0x0053,
+    0x0323, 0x0073, 0x0323, 0x015A, 0x0307, 0x015B, 0x0307, 0x0160,
+    0x0307, 0x0161, 0x0307, 0x1E62, 0x0307, 0x1E63, 0x0307, 0x0054,
+    0x0307, 0x0074, 0x0307, 0x0054, 0x0323, 0x0074, 0x0323, 0x0054,
+    0x0331, 0x0074, 0x0331, 0x0054, 0x032D, 0x0074, 0x032D, 0x0055,
+    0x0324, 0x0075, 0x0324, 0x0055, 0x0330, 0x0075, 0x0330, 0x0055,
+    0x032D, 0x0075, 0x032D, 0x0168, 0x0301, 0x0169, 0x0301, 0x016A,
+    0x0308, 0x016B, 0x0308, 0x0056, 0x0303, 0x0076, 0x0303, 0x0056,
+    0x0323, 0x0076, 0x0323, 0x0057, 0x0300, 0x0077, 0x0300, 0x0057,
+    0x0301, 0x0077, 0x0301, 0x0057, 0x0308, 0x0077, 0x0308, 0x0057,
+    0x0307, 0x0077, 0x0307, 0x0057, 0x0323, 0x0077, 0x0323, 0x0058,
+    0x0307, 0x0078, 0x0307, 0x0058, 0x0308, 0x0078, 0x0308, 0x0059,
+    0x0307, 0x0079, 0x0307, 0x005A, 0x0302, 0x007A, 0x0302, 0x005A,
+    0x0323, 0x007A, 0x0323, 0x005A, 0x0331, 0x007A, 0x0331, 0x0068,
+    0x0331, 0x0074, 0x0308, 0x0077, 0x030A, 0x0079, 0x030A, 0x0061,
+    0x02BE, 0x017F, 0x0307, 0x0041, 0x0323, 0x0061, 0x0323, 0x0041,
+    0x0309, 0x0061, 0x0309, 0x00C2, 0x0301, 0x00E2, 0x0301, 0x00C2,
+    0x0300, 0x00E2, 0x0300, 0x00C2, 0x0309, 0x00E2, 0x0309, 0x00C2,
+    0x0303, 0x00E2, 0x0303, 0x1EA0, 0x0302, 0x1EA1, 0x0302, 0x0102,
+    0x0301, 0x0103, 0x0301, 0x0102, 0x0300, 0x0103, 0x0300, 0x0102,
+    0x0309, 0x0103, 0x0309, 0x0102, 0x0303, 0x0103, 0x0303, 0x1EA0,
+    0x0306, 0x1EA1, 0x0306, 0x0045, 0x0323, 0x0065, 0x0323, 0x0045,
+    0x0309, 0x0065, 0x0309, 0x0045, 0x0303, 0x0065, 0x0303, 0x00CA,
+    0x0301, 0x00EA, 0x0301, 0x00CA, 0x0300, 0x00EA, 0x0300, 0x00CA,
+    0x0309, 0x00EA, 0x0309, 0x00CA, 0x0303, 0x00EA, 0x0303, 0x1EB8,
+    0x0302, 0x1EB9, 0x0302, 0x0049, 0x0309, 0x0069, 0x0309, 0x0049,
+    0x0323, 0x0069, 0x0323, 0x004F, 0x0323, 0x006F, 0x0323, 0x004F,
+    0x0309, 0x006F, 0x0309, 0x00D4, 0x0301, 0x00F4, 0x0301, 0x00D4,
+    0x0300, 0x00F4, 0x0300, 0x00D4, 0x0309, 0x00F4, 0x0309, 0x00D4,
+    0x0303, 0x00F4, 0x0303, 0x1ECC, 0x0302, 0x1ECD, 0x0302, 0x01A0,
+    0x0301, 0x01A1, 0x0301, 0x01A0, 0x0300, 0x01A1, 0x0300, 0x01A0,
+    0x0309, 0x01A1, 0x0309, 0x01A0, 0x0303, 0x01A1, 0x0303, 0x01A0,
+    0x0323, 0x01A1, 0x0323, 0x0055, 0x0323, 0x0075, 0x0323, 0x0055,
+    0x0309, 0x0075, 0x0309, 0x01AF, 0x0301, 0x01B0, 0x0301, 0x01AF,
+    0x0300, 0x01B0, 0x0300, 0x01AF, 0x0309, 0x01B0, 0x0309, 0x01AF,
+    0x0303, 0x01B0, 0x0303, 0x01AF, 0x0323, 0x01B0, 0x0323, 0x0059,
+    0x0300, 0x0079, 0x0300, 0x0059, 0x0323, 0x0079, 0x0323, 0x0059,
+    0x0309, 0x0079, 0x0309, 0x0059, 0x0303, 0x0079, 0x0303, 0x03B1,
+    0x0313, 0x03B1, 0x0314, 0x1F00, 0x0300, 0x1F01, 0x0300, 0x1F00,
+    0x0301, 0x1F01, 0x0301, 0x1F00, 0x0342, 0x1F01, 0x0342, 0x0391,
+    0x0313, 0x0391, 0x0314, 0x1F08, 0x0300, 0x1F09, 0x0300, 0x1F08,
+    0x0301, 0x1F09, 0x0301, 0x1F08, 0x0342, 0x1F09, 0x0342, 0x03B5,
+    0x0313, 0x03B5, 0x0314, 0x1F10, 0x0300, 0x1F11, 0x0300, 0x1F10,
+    0x0301, 0x1F11, 0x0301, 0x0395, 0x0313, 0x0395, 0x0314, 0x1F18,
+    0x0300, 0x1F19, 0x0300, 0x1F18, 0x0301, 0x1F19, 0x0301, 0x03B7,
+    0x0313, 0x03B7, 0x0314, 0x1F20, 0x0300, 0x1F21, 0x0300, 0x1F20,
+    0x0301, 0x1F21, 0x0301, 0x1F20, 0x0342, 0x1F21, 0x0342, 0x0397,
+    0x0313, 0x0397, 0x0314, 0x1F28, 0x0300, 0x1F29, 0x0300, 0x1F28,
+    0x0301, 0x1F29, 0x0301, 0x1F28, 0x0342, 0x1F29, 0x0342, 0x03B9,
+    0x0313, 0x03B9, 0x0314, 0x1F30, 0x0300, 0x1F31, 0x0300, 0x1F30,
+    0x0301, 0x1F31, 0x0301, 0x1F30, 0x0342, 0x1F31, 0x0342, 0x0399,
+    0x0313, 0x0399, 0x0314, 0x1F38, 0x0300, 0x1F39, 0x0300, 0x1F38,
+    0x0301, 0x1F39, 0x0301, 0x1F38, 0x0342, 0x1F39, 0x0342, 0x03BF,
+    0x0313, 0x03BF, 0x0314, 0x1F40, 0x0300, 0x1F41, 0x0300, 0x1F40,
+    0x0301, 0x1F41, 0x0301, 0x039F, 0x0313, 0x039F, 0x0314, 0x1F48,
+    0x0300, 0x1F49, 0x0300, 0x1F48, 0x0301, 0x1F49, 0x0301, 0x03C5,
+    0x0313, 0x03C5, 0x0314, 0x1F50, 0x0300, 0x1F51, 0x0300, 0x1F50,
+    0x0301, 0x1F51, 0x0301, 0x1F50, 0x0342, 0x1F51, 0x0342, 0x03A5,
+    0x0314, 0x1F59, 0x0300, 0x1F59, 0x0301, 0x1F59, 0x0342, 0x03C9,
+    0x0313, 0x03C9, 0x0314, 0x1F60, 0x0300, 0x1F61, 0x0300, 0x1F60,
+    0x0301, 0x1F61, 0x0301, 0x1F60, 0x0342, 0x1F61, 0x0342, 0x03A9,
+    0x0313, 0x03A9, 0x0314, 0x1F68, 0x0300, 0x1F69, 0x0300, 0x1F68,
+    0x0301, 0x1F69, 0x0301, 0x1F68, 0x0342, 0x1F69, 0x0342, 0x03B1,
+    0x0300, 0x03B5, 0x0300, 0x03B7, 0x0300, 0x03B9, 0x0300, 0x03BF,
+    0x0300, 0x03C5, 0x0300, 0x03C9, 0x0300, 0x1F00, 0x0345, 0x1F01,
+    0x0345, 0x1F02, 0x0345, 0x1F03, 0x0345, 0x1F04, 0x0345, 0x1F05,
+    0x0345, 0x1F06, 0x0345, 0x1F07, 0x0345, 0x1F08, 0x0345, 0x1F09,
+    0x0345, 0x1F0A, 0x0345, 0x1F0B, 0x0345, 0x1F0C, 0x0345, 0x1F0D,
+    0x0345, 0x1F0E, 0x0345, 0x1F0F, 0x0345, 0x1F20, 0x0345, 0x1F21,
+    0x0345, 0x1F22, 0x0345, 0x1F23, 0x0345, 0x1F24, 0x0345, 0x1F25,
+    0x0345, 0x1F26, 0x0345, 0x1F27, 0x0345, 0x1F28, 0x0345, 0x1F29,
+    0x0345, 0x1F2A, 0x0345, 0x1F2B, 0x0345, 0x1F2C, 0x0345, 0x1F2D,
+    0x0345, 0x1F2E, 0x0345, 0x1F2F, 0x0345, 0x1F60, 0x0345, 0x1F61,
+    0x0345, 0x1F62, 0x0345, 0x1F63, 0x0345, 0x1F64, 0x0345, 0x1F65,
+    0x0345, 0x1F66, 0x0345, 0x1F67, 0x0345, 0x1F68, 0x0345, 0x1F69,
+    0x0345, 0x1F6A, 0x0345, 0x1F6B, 0x0345, 0x1F6C, 0x0345, 0x1F6D,
+    0x0345, 0x1F6E, 0x0345, 0x1F6F, 0x0345, 0x03B1, 0x0306, 0x03B1,
+    0x0304, 0x1F70, 0x0345, 0x03B1, 0x0345, 0x03AC, 0x0345, 0x03B1,
+    0x0342, 0x1FB6, 0x0345, 0x0391, 0x0306, 0x0391, 0x0304, 0x0391,
+    0x0300, 0x0391, 0x0345, 0x0020, 0x0313, 0x0020, 0x0313, 0x0020,
+    0x0342, 0x00A8, 0x0342, 0x1F74, 0x0345, 0x03B7, 0x0345, 0x03AE,
+    0x0345, 0x03B7, 0x0342, 0x1FC6, 0x0345, 0x0395, 0x0300, 0x0397,
+    0x0300, 0x0397, 0x0345, 0x1FBF, 0x0300, 0x1FBF, 0x0301, 0x1FBF,
+    0x0342, 0x03B9, 0x0306, 0x03B9, 0x0304, 0x03CA, 0x0300, 0x03B9,
+    0x0342, 0x03CA, 0x0342, 0x0399, 0x0306, 0x0399, 0x0304, 0x0399,
+    0x0300, 0x1FFE, 0x0300, 0x1FFE, 0x0301, 0x1FFE, 0x0342, 0x03C5,
+    0x0306, 0x03C5, 0x0304, 0x03CB, 0x0300, 0x03C1, 0x0313, 0x03C1,
+    0x0314, 0x03C5, 0x0342, 0x03CB, 0x0342, 0x03A5, 0x0306, 0x03A5,
+    0x0304, 0x03A5, 0x0300, 0x03A1, 0x0314, 0x00A8, 0x0300, 0x1F7C,
+    0x0345, 0x03C9, 0x0345, 0x03CE, 0x0345, 0x03C9, 0x0342, 0x1FF6,
+    0x0345, 0x039F, 0x0300, 0x03A9, 0x0300, 0x03A9, 0x0345, 0x0020,
+    0x0314, 0x0020, 0x0333, 0x002E, 0x002E, 0x002E, 0x002E, 0x002E,
+    0x2032, 0x2032, 0x2032, 0x2032, 0x2032, 0x2035, 0x2035, 0x2035,
+    0x2035, 0x2035, 0x0021, 0x0021, 0x0020, 0x0305, 0x003F, 0x003F,
+    0x003F, 0x0021, 0x0021, 0x003F, 0x2032, 0x2032, 0x2032, 0x2032,
+    0x0052, 0x0073, 0x0061, 0x002F, 0x0063, 0x0061, 0x002F, 0x0073,
+    0x00B0, 0x0043, 0x0063, 0x002F, 0x006F, 0x0063, 0x002F, 0x0075,
+    0x00B0, 0x0046, 0x004E, 0x006F, 0x0053, 0x004D, 0x0054, 0x0045,
+    0x004C, 0x0054, 0x004D, 0x0046, 0x0041, 0x0058, 0x0031, 0x2044,
+    0x0037, 0x0031, 0x2044, 0x0039, 0x0031, 0x2044, 0x0031, 0x0030,
+    0x0031, 0x2044, 0x0033, 0x0032, 0x2044, 0x0033, 0x0031, 0x2044,
+    0x0035, 0x0032, 0x2044, 0x0035, 0x0033, 0x2044, 0x0035, 0x0034,
+    0x2044, 0x0035, 0x0031, 0x2044, 0x0036, 0x0035, 0x2044, 0x0036,
+    0x0031, 0x2044, 0x0038, 0x0033, 0x2044, 0x0038, 0x0035, 0x2044,
+    0x0038, 0x0037, 0x2044, 0x0038, 0x0031, 0x2044, 0x0049, 0x0049,
+    0x0049, 0x0049, 0x0049, 0x0049, 0x0056, 0x0056, 0x0049, 0x0056,
+    0x0049, 0x0049, 0x0056, 0x0049, 0x0049, 0x0049, 0x0049, 0x0058,
+    0x0058, 0x0049, 0x0058, 0x0049, 0x0049, 0x0069, 0x0069, 0x0069,
+    0x0069, 0x0069, 0x0069, 0x0076, 0x0076, 0x0069, 0x0076, 0x0069,
+    0x0069, 0x0076, 0x0069, 0x0069, 0x0069, 0x0069, 0x0078, 0x0078,
+    0x0069, 0x0078, 0x0069, 0x0069, 0x0030, 0x2044, 0x0033, 0x2190,
+    0x0338, 0x2192, 0x0338, 0x2194, 0x0338, 0x21D0, 0x0338, 0x21D4,
+    0x0338, 0x21D2, 0x0338, 0x2203, 0x0338, 0x2208, 0x0338, 0x220B,
+    0x0338, 0x2223, 0x0338, 0x2225, 0x0338, 0x222B, 0x222B, 0x222B,
+    0x222B, 0x222B, 0x222E, 0x222E, 0x222E, 0x222E, 0x222E, 0x223C,
+    0x0338, 0x2243, 0x0338, 0x2245, 0x0338, 0x2248, 0x0338, 0x003D,
+    0x0338, 0x2261, 0x0338, 0x224D, 0x0338, 0x003C, 0x0338, 0x003E,
+    0x0338, 0x2264, 0x0338, 0x2265, 0x0338, 0x2272, 0x0338, 0x2273,
+    0x0338, 0x2276, 0x0338, 0x2277, 0x0338, 0x227A, 0x0338, 0x227B,
+    0x0338, 0x2282, 0x0338, 0x2283, 0x0338, 0x2286, 0x0338, 0x2287,
+    0x0338, 0x22A2, 0x0338, 0x22A8, 0x0338, 0x22A9, 0x0338, 0x22AB,
+    0x0338, 0x227C, 0x0338, 0x227D, 0x0338, 0x2291, 0x0338, 0x2292,
+    0x0338, 0x22B2, 0x0338, 0x22B3, 0x0338, 0x22B4, 0x0338, 0x22B5,
+    0x0338, 0x0031, 0x0030, 0x0031, 0x0031, 0x0031, 0x0032, 0x0031,
+    0x0033, 0x0031, 0x0034, 0x0031, 0x0035, 0x0031, 0x0036, 0x0031,
+    0x0037, 0x0031, 0x0038, 0x0031, 0x0039, 0x0032, 0x0030, 0x0028,
+    0x0031, 0x0029, 0x0028, 0x0032, 0x0029, 0x0028, 0x0033, 0x0029,
+    0x0028, 0x0034, 0x0029, 0x0028, 0x0035, 0x0029, 0x0028, 0x0036,
+    0x0029, 0x0028, 0x0037, 0x0029, 0x0028, 0x0038, 0x0029, 0x0028,
+    0x0039, 0x0029, 0x0028, 0x0031, 0x0030, 0x0029, 0x0028, 0x0031,
+    0x0031, 0x0029, 0x0028, 0x0031, 0x0032, 0x0029, 0x0028, 0x0031,
+    0x0033, 0x0029, 0x0028, 0x0031, 0x0034, 0x0029, 0x0028, 0x0031,
+    0x0035, 0x0029, 0x0028, 0x0031, 0x0036, 0x0029, 0x0028, 0x0031,
+    0x0037, 0x0029, 0x0028, 0x0031, 0x0038, 0x0029, 0x0028, 0x0031,
+    0x0039, 0x0029, 0x0028, 0x0032, 0x0030, 0x0029, 0x0031, 0x002E,
+    0x0032, 0x002E, 0x0033, 0x002E, 0x0034, 0x002E, 0x0035, 0x002E,
+    0x0036, 0x002E, 0x0037, 0x002E, 0x0038, 0x002E, 0x0039, 0x002E,
+    0x0031, 0x0030, 0x002E, 0x0031, 0x0031, 0x002E, 0x0031, 0x0032,
+    0x002E, 0x0031, 0x0033, 0x002E, 0x0031, 0x0034, 0x002E, 0x0031,
+    0x0035, 0x002E, 0x0031, 0x0036, 0x002E, 0x0031, 0x0037, 0x002E,
+    0x0031, 0x0038, 0x002E, 0x0031, 0x0039, 0x002E, 0x0032, 0x0030,
+    0x002E, 0x0028, 0x0061, 0x0029, 0x0028, 0x0062, 0x0029, 0x0028,
+    0x0063, 0x0029, 0x0028, 0x0064, 0x0029, 0x0028, 0x0065, 0x0029,
+    0x0028, 0x0066, 0x0029, 0x0028, 0x0067, 0x0029, 0x0028, 0x0068,
+    0x0029, 0x0028, 0x0069, 0x0029, 0x0028, 0x006A, 0x0029, 0x0028,
+    0x006B, 0x0029, 0x0028, 0x006C, 0x0029, 0x0028, 0x006D, 0x0029,
+    0x0028, 0x006E, 0x0029, 0x0028, 0x006F, 0x0029, 0x0028, 0x0070,
+    0x0029, 0x0028, 0x0071, 0x0029, 0x0028, 0x0072, 0x0029, 0x0028,
+    0x0073, 0x0029, 0x0028, 0x0074, 0x0029, 0x0028, 0x0075, 0x0029,
+    0x0028, 0x0076, 0x0029, 0x0028, 0x0077, 0x0029, 0x0028, 0x0078,
+    0x0029, 0x0028, 0x0079, 0x0029, 0x0028, 0x007A, 0x0029, 0x222B,
+    0x222B, 0x222B, 0x222B, 0x003A, 0x003A, 0x003D, 0x003D, 0x003D,
+    0x003D, 0x003D, 0x003D, 0x2ADD, 0x0338, 0x304B, 0x3099, 0x304D,
+    0x3099, 0x304F, 0x3099, 0x3051, 0x3099, 0x3053, 0x3099, 0x3055,
+    0x3099, 0x3057, 0x3099, 0x3059, 0x3099, 0x305B, 0x3099, 0x305D,
+    0x3099, 0x305F, 0x3099, 0x3061, 0x3099, 0x3064, 0x3099, 0x3066,
+    0x3099, 0x3068, 0x3099, 0x306F, 0x3099, 0x306F, 0x309A, 0x3072,
+    0x3099, 0x3072, 0x309A, 0x3075, 0x3099, 0x3075, 0x309A, 0x3078,
+    0x3099, 0x3078, 0x309A, 0x307B, 0x3099, 0x307B, 0x309A, 0x3046,
+    0x3099, 0x0020, 0x3099, 0x0020, 0x309A, 0x309D, 0x3099, 0x3088,
+    0x308A, 0x30AB, 0x3099, 0x30AD, 0x3099, 0x30AF, 0x3099, 0x30B1,
+    0x3099, 0x30B3, 0x3099, 0x30B5, 0x3099, 0x30B7, 0x3099, 0x30B9,
+    0x3099, 0x30BB, 0x3099, 0x30BD, 0x3099, 0x30BF, 0x3099, 0x30C1,
+    0x3099, 0x30C4, 0x3099, 0x30C6, 0x3099, 0x30C8, 0x3099, 0x30CF,
+    0x3099, 0x30CF, 0x309A, 0x30D2, 0x3099, 0x30D2, 0x309A, 0x30D5,
+    0x3099, 0x30D5, 0x309A, 0x30D8, 0x3099, 0x30D8, 0x309A, 0x30DB,
+    0x3099, 0x30DB, 0x309A, 0x30A6, 0x3099, 0x30EF, 0x3099, 0x30F0,
+    0x3099, 0x30F1, 0x3099, 0x30F2, 0x3099, 0x30FD, 0x3099, 0x30B3,
+    0x30C8, 0x0028, 0x1100, 0x0029, 0x0028, 0x1102, 0x0029, 0x0028,
+    0x1103, 0x0029, 0x0028, 0x1105, 0x0029, 0x0028, 0x1106, 0x0029,
+    0x0028, 0x1107, 0x0029, 0x0028, 0x1109, 0x0029, 0x0028, 0x110B,
+    0x0029, 0x0028, 0x110C, 0x0029, 0x0028, 0x110E, 0x0029, 0x0028,
+    0x110F, 0x0029, 0x0028, 0x1110, 0x0029, 0x0028, 0x1111, 0x0029,
+    0x0028, 0x1112, 0x0029, 0x0028, 0x1100, 0x1161, 0x0029, 0x0028,
+    0x1102, 0x1161, 0x0029, 0x0028, 0x1103, 0x1161, 0x0029, 0x0028,
+    0x1105, 0x1161, 0x0029, 0x0028, 0x1106, 0x1161, 0x0029, 0x0028,
+    0x1107, 0x1161, 0x0029, 0x0028, 0x1109, 0x1161, 0x0029, 0x0028,
+    0x110B, 0x1161, 0x0029, 0x0028, 0x110C, 0x1161, 0x0029, 0x0028,
+    0x110E, 0x1161, 0x0029, 0x0028, 0x110F, 0x1161, 0x0029, 0x0028,
+    0x1110, 0x1161, 0x0029, 0x0028, 0x1111, 0x1161, 0x0029, 0x0028,
+    0x1112, 0x1161, 0x0029, 0x0028, 0x110C, 0x116E, 0x0029, 0x0028,
+    0x110B, 0x1169, 0x110C, 0x1165, 0x11AB, 0x0029, 0x0028, 0x110B,
+    0x1169, 0x1112, 0x116E, 0x0029, 0x0028, 0x4E00, 0x0029, 0x0028,
+    0x4E8C, 0x0029, 0x0028, 0x4E09, 0x0029, 0x0028, 0x56DB, 0x0029,
+    0x0028, 0x4E94, 0x0029, 0x0028, 0x516D, 0x0029, 0x0028, 0x4E03,
+    0x0029, 0x0028, 0x516B, 0x0029, 0x0028, 0x4E5D, 0x0029, 0x0028,
+    0x5341, 0x0029, 0x0028, 0x6708, 0x0029, 0x0028, 0x706B, 0x0029,
+    0x0028, 0x6C34, 0x0029, 0x0028, 0x6728, 0x0029, 0x0028, 0x91D1,
+    0x0029, 0x0028, 0x571F, 0x0029, 0x0028, 0x65E5, 0x0029, 0x0028,
+    0x682A, 0x0029, 0x0028, 0x6709, 0x0029, 0x0028, 0x793E, 0x0029,
+    0x0028, 0x540D, 0x0029, 0x0028, 0x7279, 0x0029, 0x0028, 0x8CA1,
+    0x0029, 0x0028, 0x795D, 0x0029, 0x0028, 0x52B4, 0x0029, 0x0028,
+    0x4EE3, 0x0029, 0x0028, 0x547C, 0x0029, 0x0028, 0x5B66, 0x0029,
+    0x0028, 0x76E3, 0x0029, 0x0028, 0x4F01, 0x0029, 0x0028, 0x8CC7,
+    0x0029, 0x0028, 0x5354, 0x0029, 0x0028, 0x796D, 0x0029, 0x0028,
+    0x4F11, 0x0029, 0x0028, 0x81EA, 0x0029, 0x0028, 0x81F3, 0x0029,
+    0x0050, 0x0054, 0x0045, 0x0032, 0x0031, 0x0032, 0x0032, 0x0032,
+    0x0033, 0x0032, 0x0034, 0x0032, 0x0035, 0x0032, 0x0036, 0x0032,
+    0x0037, 0x0032, 0x0038, 0x0032, 0x0039, 0x0033, 0x0030, 0x0033,
+    0x0031, 0x0033, 0x0032, 0x0033, 0x0033, 0x0033, 0x0034, 0x0033,
+    0x0035, 0x1100, 0x1161, 0x1102, 0x1161, 0x1103, 0x1161, 0x1105,
+    0x1161, 0x1106, 0x1161, 0x1107, 0x1161, 0x1109, 0x1161, 0x110B,
+    0x1161, 0x110C, 0x1161, 0x110E, 0x1161, 0x110F, 0x1161, 0x1110,
+    0x1161, 0x1111, 0x1161, 0x1112, 0x1161, 0x110E, 0x1161, 0x11B7,
+    0x1100, 0x1169, 0x110C, 0x116E, 0x110B, 0x1174, 0x110B, 0x116E,
+    0x0033, 0x0036, 0x0033, 0x0037, 0x0033, 0x0038, 0x0033, 0x0039,
+    0x0034, 0x0030, 0x0034, 0x0031, 0x0034, 0x0032, 0x0034, 0x0033,
+    0x0034, 0x0034, 0x0034, 0x0035, 0x0034, 0x0036, 0x0034, 0x0037,
+    0x0034, 0x0038, 0x0034, 0x0039, 0x0035, 0x0030, 0x0031, 0x6708,
+    0x0032, 0x6708, 0x0033, 0x6708, 0x0034, 0x6708, 0x0035, 0x6708,
+    0x0036, 0x6708, 0x0037, 0x6708, 0x0038, 0x6708, 0x0039, 0x6708,
+    0x0031, 0x0030, 0x6708, 0x0031, 0x0031, 0x6708, 0x0031, 0x0032,
+    0x6708, 0x0048, 0x0067, 0x0065, 0x0072, 0x0067, 0x0065, 0x0056,
+    0x004C, 0x0054, 0x0044, 0x4EE4, 0x548C, 0x30A2, 0x30D1, 0x30FC,
+    0x30C8, 0x30A2, 0x30EB, 0x30D5, 0x30A1, 0x30A2, 0x30F3, 0x30DA,
+    0x30A2, 0x30A2, 0x30FC, 0x30EB, 0x30A4, 0x30CB, 0x30F3, 0x30B0,
+    0x30A4, 0x30F3, 0x30C1, 0x30A6, 0x30A9, 0x30F3, 0x30A8, 0x30B9,
+    0x30AF, 0x30FC, 0x30C9, 0x30A8, 0x30FC, 0x30AB, 0x30FC, 0x30AA,
+    0x30F3, 0x30B9, 0x30AA, 0x30FC, 0x30E0, 0x30AB, 0x30A4, 0x30EA,
+    0x30AB, 0x30E9, 0x30C3, 0x30C8, 0x30AB, 0x30ED, 0x30EA, 0x30FC,
+    0x30AC, 0x30ED, 0x30F3, 0x30AC, 0x30F3, 0x30DE, 0x30AE, 0x30AC,
+    0x30AE, 0x30CB, 0x30FC, 0x30AD, 0x30E5, 0x30EA, 0x30FC, 0x30AE,
+    0x30EB, 0x30C0, 0x30FC, 0x30AD, 0x30ED, 0x30AD, 0x30ED, 0x30B0,
+    0x30E9, 0x30E0, 0x30AD, 0x30ED, 0x30E1, 0x30FC, 0x30C8, 0x30EB,
+    0x30AD, 0x30ED, 0x30EF, 0x30C3, 0x30C8, 0x30B0, 0x30E9, 0x30E0,
+    0x30B0, 0x30E9, 0x30E0, 0x30C8, 0x30F3, 0x30AF, 0x30EB, 0x30BC,
+    0x30A4, 0x30ED, 0x30AF, 0x30ED, 0x30FC, 0x30CD, 0x30B1, 0x30FC,
+    0x30B9, 0x30B3, 0x30EB, 0x30CA, 0x30B3, 0x30FC, 0x30DD, 0x30B5,
+    0x30A4, 0x30AF, 0x30EB, 0x30B5, 0x30F3, 0x30C1, 0x30FC, 0x30E0,
+    0x30B7, 0x30EA, 0x30F3, 0x30B0, 0x30BB, 0x30F3, 0x30C1, 0x30BB,
+    0x30F3, 0x30C8, 0x30C0, 0x30FC, 0x30B9, 0x30C7, 0x30B7, 0x30C9,
+    0x30EB, 0x30C8, 0x30F3, 0x30CA, 0x30CE, 0x30CE, 0x30C3, 0x30C8,
+    0x30CF, 0x30A4, 0x30C4, 0x30D1, 0x30FC, 0x30BB, 0x30F3, 0x30C8,
+    0x30D1, 0x30FC, 0x30C4, 0x30D0, 0x30FC, 0x30EC, 0x30EB, 0x30D4,
+    0x30A2, 0x30B9, 0x30C8, 0x30EB, 0x30D4, 0x30AF, 0x30EB, 0x30D4,
+    0x30B3, 0x30D3, 0x30EB, 0x30D5, 0x30A1, 0x30E9, 0x30C3, 0x30C9,
+    0x30D5, 0x30A3, 0x30FC, 0x30C8, 0x30D6, 0x30C3, 0x30B7, 0x30A7,
+    0x30EB, 0x30D5, 0x30E9, 0x30F3, 0x30D8, 0x30AF, 0x30BF, 0x30FC,
+    0x30EB, 0x30DA, 0x30BD, 0x30DA, 0x30CB, 0x30D2, 0x30D8, 0x30EB,
+    0x30C4, 0x30DA, 0x30F3, 0x30B9, 0x30DA, 0x30FC, 0x30B8, 0x30D9,
+    0x30FC, 0x30BF, 0x30DD, 0x30A4, 0x30F3, 0x30C8, 0x30DC, 0x30EB,
+    0x30C8, 0x30DB, 0x30F3, 0x30DD, 0x30F3, 0x30C9, 0x30DB, 0x30FC,
+    0x30EB, 0x30DB, 0x30FC, 0x30F3, 0x30DE, 0x30A4, 0x30AF, 0x30ED,
+    0x30DE, 0x30A4, 0x30EB, 0x30DE, 0x30C3, 0x30CF, 0x30DE, 0x30EB,
+    0x30AF, 0x30DE, 0x30F3, 0x30B7, 0x30E7, 0x30F3, 0x30DF, 0x30AF,
+    0x30ED, 0x30F3, 0x30DF, 0x30EA, 0x30DF, 0x30EA, 0x30D0, 0x30FC,
+    0x30EB, 0x30E1, 0x30AC, 0x30E1, 0x30AC, 0x30C8, 0x30F3, 0x30E1,
+    0x30FC, 0x30C8, 0x30EB, 0x30E4, 0x30FC, 0x30C9, 0x30E4, 0x30FC,
+    0x30EB, 0x30E6, 0x30A2, 0x30F3, 0x30EA, 0x30C3, 0x30C8, 0x30EB,
+    0x30EA, 0x30E9, 0x30EB, 0x30D4, 0x30FC, 0x30EB, 0x30FC, 0x30D6,
+    0x30EB, 0x30EC, 0x30E0, 0x30EC, 0x30F3, 0x30C8, 0x30B2, 0x30F3,
+    0x30EF, 0x30C3, 0x30C8, 0x0030, 0x70B9, 0x0031, 0x70B9, 0x0032,
+    0x70B9, 0x0033, 0x70B9, 0x0034, 0x70B9, 0x0035, 0x70B9, 0x0036,
+    0x70B9, 0x0037, 0x70B9, 0x0038, 0x70B9, 0x0039, 0x70B9, 0x0031,
+    0x0030, 0x70B9, 0x0031, 0x0031, 0x70B9, 0x0031, 0x0032, 0x70B9,
+    0x0031, 0x0033, 0x70B9, 0x0031, 0x0034, 0x70B9, 0x0031, 0x0035,
+    0x70B9, 0x0031, 0x0036, 0x70B9, 0x0031, 0x0037, 0x70B9, 0x0031,
+    0x0038, 0x70B9, 0x0031, 0x0039, 0x70B9, 0x0032, 0x0030, 0x70B9,
+    0x0032, 0x0031, 0x70B9, 0x0032, 0x0032, 0x70B9, 0x0032, 0x0033,
+    0x70B9, 0x0032, 0x0034, 0x70B9, 0x0068, 0x0050, 0x0061, 0x0064,
+    0x0061, 0x0041, 0x0055, 0x0062, 0x0061, 0x0072, 0x006F, 0x0056,
+    0x0070, 0x0063, 0x0064, 0x006D, 0x0064, 0x006D, 0x00B2, 0x0064,
+    0x006D, 0x00B3, 0x0049, 0x0055, 0x5E73, 0x6210, 0x662D, 0x548C,
+    0x5927, 0x6B63, 0x660E, 0x6CBB, 0x682A, 0x5F0F, 0x4F1A, 0x793E,
+    0x0070, 0x0041, 0x006E, 0x0041, 0x03BC, 0x0041, 0x006D, 0x0041,
+    0x006B, 0x0041, 0x004B, 0x0042, 0x004D, 0x0042, 0x0047, 0x0042,
+    0x0063, 0x0061, 0x006C, 0x006B, 0x0063, 0x0061, 0x006C, 0x0070,
+    0x0046, 0x006E, 0x0046, 0x03BC, 0x0046, 0x03BC, 0x0067, 0x006D,
+    0x0067, 0x006B, 0x0067, 0x0048, 0x007A, 0x006B, 0x0048, 0x007A,
+    0x004D, 0x0048, 0x007A, 0x0047, 0x0048, 0x007A, 0x0054, 0x0048,
+    0x007A, 0x03BC, 0x2113, 0x006D, 0x2113, 0x0064, 0x2113, 0x006B,
+    0x2113, 0x0066, 0x006D, 0x006E, 0x006D, 0x03BC, 0x006D, 0x006D,
+    0x006D, 0x0063, 0x006D, 0x006B, 0x006D, 0x006D, 0x006D, 0x00B2,
+    0x0063, 0x006D, 0x00B2, 0x006D, 0x00B2, 0x006B, 0x006D, 0x00B2,
+    0x006D, 0x006D, 0x00B3, 0x0063, 0x006D, 0x00B3, 0x006D, 0x00B3,
+    0x006B, 0x006D, 0x00B3, 0x006D, 0x2215, 0x0073, 0x006D, 0x2215,
+    0x0073, 0x00B2, 0x0050, 0x0061, 0x006B, 0x0050, 0x0061, 0x004D,
+    0x0050, 0x0061, 0x0047, 0x0050, 0x0061, 0x0072, 0x0061, 0x0064,
+    0x0072, 0x0061, 0x0064, 0x2215, 0x0073, 0x0072, 0x0061, 0x0064,
+    0x2215, 0x0073, 0x00B2, 0x0070, 0x0073, 0x006E, 0x0073, 0x03BC,
+    0x0073, 0x006D, 0x0073, 0x0070, 0x0056, 0x006E, 0x0056, 0x03BC,
+    0x0056, 0x006D, 0x0056, 0x006B, 0x0056, 0x004D, 0x0056, 0x0070,
+    0x0057, 0x006E, 0x0057, 0x03BC, 0x0057, 0x006D, 0x0057, 0x006B,
+    0x0057, 0x004D, 0x0057, 0x006B, 0x03A9, 0x004D, 0x03A9, 0x0061,
+    0x002E, 0x006D, 0x002E, 0x0042, 0x0071, 0x0063, 0x0063, 0x0063,
+    0x0064, 0x0043, 0x2215, 0x006B, 0x0067, 0x0043, 0x006F, 0x002E,
+    0x0064, 0x0042, 0x0047, 0x0079, 0x0068, 0x0061, 0x0048, 0x0050,
+    0x0069, 0x006E, 0x004B, 0x004B, 0x004B, 0x004D, 0x006B, 0x0074,
+    0x006C, 0x006D, 0x006C, 0x006E, 0x006C, 0x006F, 0x0067, 0x006C,
+    0x0078, 0x006D, 0x0062, 0x006D, 0x0069, 0x006C, 0x006D, 0x006F,
+    0x006C, 0x0050, 0x0048, 0x0070, 0x002E, 0x006D, 0x002E, 0x0050,
+    0x0050, 0x004D, 0x0050, 0x0052, 0x0073, 0x0072, 0x0053, 0x0076,
+    0x0057, 0x0062, 0x0056, 0x2215, 0x006D, 0x0041, 0x2215, 0x006D,
+    0x0031, 0x65E5, 0x0032, 0x65E5, 0x0033, 0x65E5, 0x0034, 0x65E5,
+    0x0035, 0x65E5, 0x0036, 0x65E5, 0x0037, 0x65E5, 0x0038, 0x65E5,
+    0x0039, 0x65E5, 0x0031, 0x0030, 0x65E5, 0x0031, 0x0031, 0x65E5,
+    0x0031, 0x0032, 0x65E5, 0x0031, 0x0033, 0x65E5, 0x0031, 0x0034,
+    0x65E5, 0x0031, 0x0035, 0x65E5, 0x0031, 0x0036, 0x65E5, 0x0031,
+    0x0037, 0x65E5, 0x0031, 0x0038, 0x65E5, 0x0031, 0x0039, 0x65E5,
+    0x0032, 0x0030, 0x65E5, 0x0032, 0x0031, 0x65E5, 0x0032, 0x0032,
+    0x65E5, 0x0032, 0x0033, 0x65E5, 0x0032, 0x0034, 0x65E5, 0x0032,
+    0x0035, 0x65E5, 0x0032, 0x0036, 0x65E5, 0x0032, 0x0037, 0x65E5,
+    0x0032, 0x0038, 0x65E5, 0x0032, 0x0039, 0x65E5, 0x0033, 0x0030,
+    0x65E5, 0x0033, 0x0031, 0x65E5, 0x0067, 0x0061, 0x006C, 0x0066,
+    0x0066, 0x0066, 0x0069, 0x0066, 0x006C, 0x0066, 0x0066, 0x0069,
+    0x0066, 0x0066, 0x006C, 0x017F, 0x0074, 0x0073, 0x0074, 0x0574,
+    0x0576, 0x0574, 0x0565, 0x0574, 0x056B, 0x057E, 0x0576, 0x0574,
+    0x056D, 0x05D9, 0x05B4, 0x05F2, 0x05B7, 0x05E9, 0x05C1, 0x05E9,
+    0x05C2, 0xFB49, 0x05C1, 0xFB49, 0x05C2, 0x05D0, 0x05B7, 0x05D0,
+    0x05B8, 0x05D0, 0x05BC, 0x05D1, 0x05BC, 0x05D2, 0x05BC, 0x05D3,
+    0x05BC, 0x05D4, 0x05BC, 0x05D5, 0x05BC, 0x05D6, 0x05BC, 0x05D8,
+    0x05BC, 0x05D9, 0x05BC, 0x05DA, 0x05BC, 0x05DB, 0x05BC, 0x05DC,
+    0x05BC, 0x05DE, 0x05BC, 0x05E0, 0x05BC, 0x05E1, 0x05BC, 0x05E3,
+    0x05BC, 0x05E4, 0x05BC, 0x05E6, 0x05BC, 0x05E7, 0x05BC, 0x05E8,
+    0x05BC, 0x05E9, 0x05BC, 0x05EA, 0x05BC, 0x05D5, 0x05B9, 0x05D1,
+    0x05BF, 0x05DB, 0x05BF, 0x05E4, 0x05BF, 0x05D0, 0x05DC, 0x0626,
+    0x0627, 0x0626, 0x0627, 0x0626, 0x06D5, 0x0626, 0x06D5, 0x0626,
+    0x0648, 0x0626, 0x0648, 0x0626, 0x06C7, 0x0626, 0x06C7, 0x0626,
+    0x06C6, 0x0626, 0x06C6, 0x0626, 0x06C8, 0x0626, 0x06C8, 0x0626,
+    0x06D0, 0x0626, 0x06D0, 0x0626, 0x06D0, 0x0626, 0x0649, 0x0626,
+    0x0649, 0x0626, 0x0649, 0x0626, 0x062C, 0x0626, 0x062D, 0x0626,
+    0x0645, 0x0626, 0x0649, 0x0626, 0x064A, 0x0628, 0x062C, 0x0628,
+    0x062D, 0x0628, 0x062E, 0x0628, 0x0645, 0x0628, 0x0649, 0x0628,
+    0x064A, 0x062A, 0x062C, 0x062A, 0x062D, 0x062A, 0x062E, 0x062A,
+    0x0645, 0x062A, 0x0649, 0x062A, 0x064A, 0x062B, 0x062C, 0x062B,
+    0x0645, 0x062B, 0x0649, 0x062B, 0x064A, 0x062C, 0x062D, 0x062C,
+    0x0645, 0x062D, 0x062C, 0x062D, 0x0645, 0x062E, 0x062C, 0x062E,
+    0x062D, 0x062E, 0x0645, 0x0633, 0x062C, 0x0633, 0x062D, 0x0633,
+    0x062E, 0x0633, 0x0645, 0x0635, 0x062D, 0x0635, 0x0645, 0x0636,
+    0x062C, 0x0636, 0x062D, 0x0636, 0x062E, 0x0636, 0x0645, 0x0637,
+    0x062D, 0x0637, 0x0645, 0x0638, 0x0645, 0x0639, 0x062C, 0x0639,
+    0x0645, 0x063A, 0x062C, 0x063A, 0x0645, 0x0641, 0x062C, 0x0641,
+    0x062D, 0x0641, 0x062E, 0x0641, 0x0645, 0x0641, 0x0649, 0x0641,
+    0x064A, 0x0642, 0x062D, 0x0642, 0x0645, 0x0642, 0x0649, 0x0642,
+    0x064A, 0x0643, 0x0627, 0x0643, 0x062C, 0x0643, 0x062D, 0x0643,
+    0x062E, 0x0643, 0x0644, 0x0643, 0x0645, 0x0643, 0x0649, 0x0643,
+    0x064A, 0x0644, 0x062C, 0x0644, 0x062D, 0x0644, 0x062E, 0x0644,
+    0x0645, 0x0644, 0x0649, 0x0644, 0x064A, 0x0645, 0x062C, 0x0645,
+    0x062D, 0x0645, 0x062E, 0x0645, 0x0645, 0x0645, 0x0649, 0x0645,
+    0x064A, 0x0646, 0x062C, 0x0646, 0x062D, 0x0646, 0x062E, 0x0646,
+    0x0645, 0x0646, 0x0649, 0x0646, 0x064A, 0x0647, 0x062C, 0x0647,
+    0x0645, 0x0647, 0x0649, 0x0647, 0x064A, 0x064A, 0x062C, 0x064A,
+    0x062D, 0x064A, 0x062E, 0x064A, 0x0645, 0x064A, 0x0649, 0x064A,
+    0x064A, 0x0630, 0x0670, 0x0631, 0x0670, 0x0649, 0x0670, 0x0020,
+    0x064C, 0x0651, 0x0020, 0x064D, 0x0651, 0x0020, 0x064E, 0x0651,
+    0x0020, 0x064F, 0x0651, 0x0020, 0x0650, 0x0651, 0x0020, 0x0651,
+    0x0670, 0x0626, 0x0631, 0x0626, 0x0632, 0x0626, 0x0645, 0x0626,
+    0x0646, 0x0626, 0x0649, 0x0626, 0x064A, 0x0628, 0x0631, 0x0628,
+    0x0632, 0x0628, 0x0645, 0x0628, 0x0646, 0x0628, 0x0649, 0x0628,
+    0x064A, 0x062A, 0x0631, 0x062A, 0x0632, 0x062A, 0x0645, 0x062A,
+    0x0646, 0x062A, 0x0649, 0x062A, 0x064A, 0x062B, 0x0631, 0x062B,
+    0x0632, 0x062B, 0x0645, 0x062B, 0x0646, 0x062B, 0x0649, 0x062B,
+    0x064A, 0x0641, 0x0649, 0x0641, 0x064A, 0x0642, 0x0649, 0x0642,
+    0x064A, 0x0643, 0x0627, 0x0643, 0x0644, 0x0643, 0x0645, 0x0643,
+    0x0649, 0x0643, 0x064A, 0x0644, 0x0645, 0x0644, 0x0649, 0x0644,
+    0x064A, 0x0645, 0x0627, 0x0645, 0x0645, 0x0646, 0x0631, 0x0646,
+    0x0632, 0x0646, 0x0645, 0x0646, 0x0646, 0x0646, 0x0649, 0x0646,
+    0x064A, 0x0649, 0x0670, 0x064A, 0x0631, 0x064A, 0x0632, 0x064A,
+    0x0645, 0x064A, 0x0646, 0x064A, 0x0649, 0x064A, 0x064A, 0x0626,
+    0x062C, 0x0626, 0x062D, 0x0626, 0x062E, 0x0626, 0x0645, 0x0626,
+    0x0647, 0x0628, 0x062C, 0x0628, 0x062D, 0x0628, 0x062E, 0x0628,
+    0x0645, 0x0628, 0x0647, 0x062A, 0x062C, 0x062A, 0x062D, 0x062A,
+    0x062E, 0x062A, 0x0645, 0x062A, 0x0647, 0x062B, 0x0645, 0x062C,
+    0x062D, 0x062C, 0x0645, 0x062D, 0x062C, 0x062D, 0x0645, 0x062E,
+    0x062C, 0x062E, 0x0645, 0x0633, 0x062C, 0x0633, 0x062D, 0x0633,
+    0x062E, 0x0633, 0x0645, 0x0635, 0x062D, 0x0635, 0x062E, 0x0635,
+    0x0645, 0x0636, 0x062C, 0x0636, 0x062D, 0x0636, 0x062E, 0x0636,
+    0x0645, 0x0637, 0x062D, 0x0638, 0x0645, 0x0639, 0x062C, 0x0639,
+    0x0645, 0x063A, 0x062C, 0x063A, 0x0645, 0x0641, 0x062C, 0x0641,
+    0x062D, 0x0641, 0x062E, 0x0641, 0x0645, 0x0642, 0x062D, 0x0642,
+    0x0645, 0x0643, 0x062C, 0x0643, 0x062D, 0x0643, 0x062E, 0x0643,
+    0x0644, 0x0643, 0x0645, 0x0644, 0x062C, 0x0644, 0x062D, 0x0644,
+    0x062E, 0x0644, 0x0645, 0x0644, 0x0647, 0x0645, 0x062C, 0x0645,
+    0x062D, 0x0645, 0x062E, 0x0645, 0x0645, 0x0646, 0x062C, 0x0646,
+    0x062D, 0x0646, 0x062E, 0x0646, 0x0645, 0x0646, 0x0647, 0x0647,
+    0x062C, 0x0647, 0x0645, 0x0647, 0x0670, 0x064A, 0x062C, 0x064A,
+    0x062D, 0x064A, 0x062E, 0x064A, 0x0645, 0x064A, 0x0647, 0x0626,
+    0x0645, 0x0626, 0x0647, 0x0628, 0x0645, 0x0628, 0x0647, 0x062A,
+    0x0645, 0x062A, 0x0647, 0x062B, 0x0645, 0x062B, 0x0647, 0x0633,
+    0x0645, 0x0633, 0x0647, 0x0634, 0x0645, 0x0634, 0x0647, 0x0643,
+    0x0644, 0x0643, 0x0645, 0x0644, 0x0645, 0x0646, 0x0645, 0x0646,
+    0x0647, 0x064A, 0x0645, 0x064A, 0x0647, 0x0640, 0x064E, 0x0651,
+    0x0640, 0x064F, 0x0651, 0x0640, 0x0650, 0x0651, 0x0637, 0x0649,
+    0x0637, 0x064A, 0x0639, 0x0649, 0x0639, 0x064A, 0x063A, 0x0649,
+    0x063A, 0x064A, 0x0633, 0x0649, 0x0633, 0x064A, 0x0634, 0x0649,
+    0x0634, 0x064A, 0x062D, 0x0649, 0x062D, 0x064A, 0x062C, 0x0649,
+    0x062C, 0x064A, 0x062E, 0x0649, 0x062E, 0x064A, 0x0635, 0x0649,
+    0x0635, 0x064A, 0x0636, 0x0649, 0x0636, 0x064A, 0x0634, 0x062C,
+    0x0634, 0x062D, 0x0634, 0x062E, 0x0634, 0x0645, 0x0634, 0x0631,
+    0x0633, 0x0631, 0x0635, 0x0631, 0x0636, 0x0631, 0x0637, 0x0649,
+    0x0637, 0x064A, 0x0639, 0x0649, 0x0639, 0x064A, 0x063A, 0x0649,
+    0x063A, 0x064A, 0x0633, 0x0649, 0x0633, 0x064A, 0x0634, 0x0649,
+    0x0634, 0x064A, 0x062D, 0x0649, 0x062D, 0x064A, 0x062C, 0x0649,
+    0x062C, 0x064A, 0x062E, 0x0649, 0x062E, 0x064A, 0x0635, 0x0649,
+    0x0635, 0x064A, 0x0636, 0x0649, 0x0636, 0x064A, 0x0634, 0x062C,
+    0x0634, 0x062D, 0x0634, 0x062E, 0x0634, 0x0645, 0x0634, 0x0631,
+    0x0633, 0x0631, 0x0635, 0x0631, 0x0636, 0x0631, 0x0634, 0x062C,
+    0x0634, 0x062D, 0x0634, 0x062E, 0x0634, 0x0645, 0x0633, 0x0647,
+    0x0634, 0x0647, 0x0637, 0x0645, 0x0633, 0x062C, 0x0633, 0x062D,
+    0x0633, 0x062E, 0x0634, 0x062C, 0x0634, 0x062D, 0x0634, 0x062E,
+    0x0637, 0x0645, 0x0638, 0x0645, 0x0627, 0x064B, 0x0627, 0x064B,
+    0x062A, 0x062C, 0x0645, 0x062A, 0x062D, 0x062C, 0x062A, 0x062D,
+    0x062C, 0x062A, 0x062D, 0x0645, 0x062A, 0x062E, 0x0645, 0x062A,
+    0x0645, 0x062C, 0x062A, 0x0645, 0x062D, 0x062A, 0x0645, 0x062E,
+    0x062C, 0x0645, 0x062D, 0x062C, 0x0645, 0x062D, 0x062D, 0x0645,
+    0x064A, 0x062D, 0x0645, 0x0649, 0x0633, 0x062D, 0x062C, 0x0633,
+    0x062C, 0x062D, 0x0633, 0x062C, 0x0649, 0x0633, 0x0645, 0x062D,
+    0x0633, 0x0645, 0x062D, 0x0633, 0x0645, 0x062C, 0x0633, 0x0645,
+    0x0645, 0x0633, 0x0645, 0x0645, 0x0635, 0x062D, 0x062D, 0x0635,
+    0x062D, 0x062D, 0x0635, 0x0645, 0x0645, 0x0634, 0x062D, 0x0645,
+    0x0634, 0x062D, 0x0645, 0x0634, 0x062C, 0x064A, 0x0634, 0x0645,
+    0x062E, 0x0634, 0x0645, 0x062E, 0x0634, 0x0645, 0x0645, 0x0634,
+    0x0645, 0x0645, 0x0636, 0x062D, 0x0649, 0x0636, 0x062E, 0x0645,
+    0x0636, 0x062E, 0x0645, 0x0637, 0x0645, 0x062D, 0x0637, 0x0645,
+    0x062D, 0x0637, 0x0645, 0x0645, 0x0637, 0x0645, 0x064A, 0x0639,
+    0x062C, 0x0645, 0x0639, 0x0645, 0x0645, 0x0639, 0x0645, 0x0645,
+    0x0639, 0x0645, 0x0649, 0x063A, 0x0645, 0x0645, 0x063A, 0x0645,
+    0x064A, 0x063A, 0x0645, 0x0649, 0x0641, 0x062E, 0x0645, 0x0641,
+    0x062E, 0x0645, 0x0642, 0x0645, 0x062D, 0x0642, 0x0645, 0x0645,
+    0x0644, 0x062D, 0x0645, 0x0644, 0x062D, 0x064A, 0x0644, 0x062D,
+    0x0649, 0x0644, 0x062C, 0x062C, 0x0644, 0x062C, 0x062C, 0x0644,
+    0x062E, 0x0645, 0x0644, 0x062E, 0x0645, 0x0644, 0x0645, 0x062D,
+    0x0644, 0x0645, 0x062D, 0x0645, 0x062D, 0x062C, 0x0645, 0x062D,
+    0x0645, 0x0645, 0x062D, 0x064A, 0x0645, 0x062C, 0x062D, 0x0645,
+    0x062C, 0x0645, 0x0645, 0x062E, 0x062C, 0x0645, 0x062E, 0x0645,
+    0x0645, 0x062C, 0x062E, 0x0647, 0x0645, 0x062C, 0x0647, 0x0645,
+    0x0645, 0x0646, 0x062D, 0x0645, 0x0646, 0x062D, 0x0649, 0x0646,
+    0x062C, 0x0645, 0x0646, 0x062C, 0x0645, 0x0646, 0x062C, 0x0649,
+    0x0646, 0x0645, 0x064A, 0x0646, 0x0645, 0x0649, 0x064A, 0x0645,
+    0x0645, 0x064A, 0x0645, 0x0645, 0x0628, 0x062E, 0x064A, 0x062A,
+    0x062C, 0x064A, 0x062A, 0x062C, 0x0649, 0x062A, 0x062E, 0x064A,
+    0x062A, 0x062E, 0x0649, 0x062A, 0x0645, 0x064A, 0x062A, 0x0645,
+    0x0649, 0x062C, 0x0645, 0x064A, 0x062C, 0x062D, 0x0649, 0x062C,
+    0x0645, 0x0649, 0x0633, 0x062E, 0x0649, 0x0635, 0x062D, 0x064A,
+    0x0634, 0x062D, 0x064A, 0x0636, 0x062D, 0x064A, 0x0644, 0x062C,
+    0x064A, 0x0644, 0x0645, 0x064A, 0x064A, 0x062D, 0x064A, 0x064A,
+    0x062C, 0x064A, 0x064A, 0x0645, 0x064A, 0x0645, 0x0645, 0x064A,
+    0x0642, 0x0645, 0x064A, 0x0646, 0x062D, 0x064A, 0x0642, 0x0645,
+    0x062D, 0x0644, 0x062D, 0x0645, 0x0639, 0x0645, 0x064A, 0x0643,
+    0x0645, 0x064A, 0x0646, 0x062C, 0x062D, 0x0645, 0x062E, 0x064A,
+    0x0644, 0x062C, 0x0645, 0x0643, 0x0645, 0x0645, 0x0644, 0x062C,
+    0x0645, 0x0646, 0x062C, 0x062D, 0x062C, 0x062D, 0x064A, 0x062D,
+    0x062C, 0x064A, 0x0645, 0x062C, 0x064A, 0x0641, 0x0645, 0x064A,
+    0x0628, 0x062D, 0x064A, 0x0643, 0x0645, 0x0645, 0x0639, 0x062C,
+    0x0645, 0x0635, 0x0645, 0x0645, 0x0633, 0x062E, 0x064A, 0x0646,
+    0x062C, 0x064A, 0x0635, 0x0644, 0x06D2, 0x0642, 0x0644, 0x06D2,
+    0x0627, 0x0644, 0x0644, 0x0647, 0x0627, 0x0643, 0x0628, 0x0631,
+    0x0645, 0x062D, 0x0645, 0x062F, 0x0635, 0x0644, 0x0639, 0x0645,
+    0x0631, 0x0633, 0x0648, 0x0644, 0x0639, 0x0644, 0x064A, 0x0647,
+    0x0648, 0x0633, 0x0644, 0x0645, 0x0635, 0x0644, 0x0649, 0x0635,
+    0x0644, 0x0649, 0x0020, 0x0627, 0x0644, 0x0644, 0x0647, 0x0020,
+    0x0639, 0x0644, 0x064A, 0x0647, 0x0020, 0x0648, 0x0633, 0x0644,
+    0x0645, 0x062C, 0x0644, 0x0020, 0x062C, 0x0644, 0x0627, 0x0644,
+    0x0647, 0x0631, 0x06CC, 0x0627, 0x0644, 0x0020, 0x064B, 0x0640,
+    0x064B, 0x0020, 0x064C, 0x0020, 0x064D, 0x0020, 0x064E, 0x0640,
+    0x064E, 0x0020, 0x064F, 0x0640, 0x064F, 0x0020, 0x0650, 0x0640,
+    0x0650, 0x0020, 0x0651, 0x0640, 0x0651, 0x0020, 0x0652, 0x0640,
+    0x0652, 0x0644, 0x0622, 0x0644, 0x0622, 0x0644, 0x0623, 0x0644,
+    0x0623, 0x0644, 0x0625, 0x0644, 0x0625, 0x0644, 0x0627, 0x0644,
+    0x0627, 0x105D2, 0x0307, 0x105DA, 0x0307, 0x11099, 0x110BA, 0x1109B,
+    0x110BA, 0x110A5, 0x110BA, 0x11131, 0x11127, 0x11132, 0x11127, 0x11347,
+    0x1133E, 0x11347, 0x11357, 0x11382, 0x113C9, 0x11384, 0x113BB, 0x1138B,
+    0x113C2, 0x11390, 0x113C9, 0x113C2, 0x113C2, 0x113C2, 0x113B8, 0x113C2,
+    0x113C9, 0x114B9, 0x114BA, 0x114B9, 0x114B0, 0x114B9, 0x114BD, 0x115B8,
+    0x115AF, 0x115B9, 0x115AF, 0x11935, 0x11930, 0x1611E, 0x1611E, 0x1611E,
+    0x16129, 0x1611E, 0x1611F, 0x16129, 0x1611F, 0x1611E, 0x16120, 0x16121,
+    0x1611F, 0x16122, 0x1611F, 0x16121, 0x16120, 0x16D67, 0x16D67, 0x16D63,
+    0x16D67, 0x16D69, 0x16D67, 0x1D157, 0x1D165, 0x1D158, 0x1D165, 0x1D15F,
+    0x1D16E, 0x1D15F, 0x1D16F, 0x1D15F, 0x1D170, 0x1D15F, 0x1D171, 0x1D15F,
+    0x1D172, 0x1D1B9, 0x1D165, 0x1D1BA, 0x1D165, 0x1D1BB, 0x1D16E, 0x1D1BC,
+    0x1D16E, 0x1D1BB, 0x1D16F, 0x1D1BC, 0x1D16F, 0x0030, 0x002E, 0x0030,
+    0x002C, 0x0031, 0x002C, 0x0032, 0x002C, 0x0033, 0x002C, 0x0034,
+    0x002C, 0x0035, 0x002C, 0x0036, 0x002C, 0x0037, 0x002C, 0x0038,
+    0x002C, 0x0039, 0x002C, 0x0028, 0x0041, 0x0029, 0x0028, 0x0042,
+    0x0029, 0x0028, 0x0043, 0x0029, 0x0028, 0x0044, 0x0029, 0x0028,
+    0x0045, 0x0029, 0x0028, 0x0046, 0x0029, 0x0028, 0x0047, 0x0029,
+    0x0028, 0x0048, 0x0029, 0x0028, 0x0049, 0x0029, 0x0028, 0x004A,
+    0x0029, 0x0028, 0x004B, 0x0029, 0x0028, 0x004C, 0x0029, 0x0028,
+    0x004D, 0x0029, 0x0028, 0x004E, 0x0029, 0x0028, 0x004F, 0x0029,
+    0x0028, 0x0050, 0x0029, 0x0028, 0x0051, 0x0029, 0x0028, 0x0052,
+    0x0029, 0x0028, 0x0053, 0x0029, 0x0028, 0x0054, 0x0029, 0x0028,
+    0x0055, 0x0029, 0x0028, 0x0056, 0x0029, 0x0028, 0x0057, 0x0029,
+    0x0028, 0x0058, 0x0029, 0x0028, 0x0059, 0x0029, 0x0028, 0x005A,
+    0x0029, 0x3014, 0x0053, 0x3015, 0x0043, 0x0044, 0x0057, 0x005A,
+    0x0048, 0x0056, 0x004D, 0x0056, 0x0053, 0x0044, 0x0053, 0x0053,
+    0x0050, 0x0050, 0x0056, 0x0057, 0x0043, 0x004D, 0x0043, 0x004D,
+    0x0044, 0x004D, 0x0052, 0x0044, 0x004A, 0x307B, 0x304B, 0x30B3,
+    0x30B3, 0x3014, 0x672C, 0x3015, 0x3014, 0x4E09, 0x3015, 0x3014,
+    0x4E8C, 0x3015, 0x3014, 0x5B89, 0x3015, 0x3014, 0x70B9, 0x3015,
+    0x3014, 0x6253, 0x3015, 0x3014, 0x76D7, 0x3015, 0x3014, 0x52DD,
+    0x3015, 0x3014, 0x6557, 0x3015,
+];
+
+/// Primary composites as (first, second, composite), sorted by the pair.
+pub static COMPOSITIONS: [(u32, u32, u32); 961] = [
+    (0x003C, 0x0338, 0x226E), (0x003D, 0x0338, 0x2260), (0x003E, 0x0338, 0x226F),
+    (0x0041, 0x0300, 0x00C0), (0x0041, 0x0301, 0x00C1), (0x0041, 0x0302, 0x00C2),
+    (0x0041, 0x0303, 0x00C3), (0x0041, 0x0304, 0x0100), (0x0041, 0x0306, 0x0102),
+    (0x0041, 0x0307, 0x0226), (0x0041, 0x0308, 0x00C4), (0x0041, 0x0309, 0x1EA2),
+    (0x0041, 0x030A, 0x00C5), (0x0041, 0x030C, 0x01CD), (0x0041, 0x030F, 0x0200),
+    (0x0041, 0x0311, 0x0202), (0x0041, 0x0323, 0x1EA0), (0x0041, 0x0325, 0x1E00),
+    (0x0041, 0x0328, 0x0104), (0x0042, 0x0307, 0x1E02), (0x0042, 0x0323, 0x1E04),
+    (0x0042, 0x0331, 0x1E06), (0x0043, 0x0301, 0x0106), (0x0043, 0x0302, 0x0108),
+    (0x0043, 0x0307, 0x010A), (0x0043, 0x030C, 0x010C), (0x0043, 0x0327, 0x00C7),
+    (0x0044, 0x0307, 0x1E0A), (0x0044, 0x030C, 0x010E), (0x0044, 0x0323, 0x1E0C),
+    (0x0044, 0x0327, 0x1E10), (0x0044, 0x032D, 0x1E12), (0x0044, 0x0331, 0x1E0E),
+    (0x0045, 0x0300, 0x00C8), (0x0045, 0x0301, 0x00C9), (0x0045, 0x0302, 0x00CA),
+    (0x0045, 0x0303, 0x1EBC), (0x0045, 0x0304, 0x0112), (0x0045, 0x0306, 0x0114),
+    (0x0045, 0x0307, 0x0116), (0x0045, 0x0308, 0x00CB), (0x0045, 0x0309, 0x1EBA),
+    (0x0045, 0x030C, 0x011A), (0x0045, 0x030F, 0x0204), (0x0045, 0x0311, 0x0206),
+    (0x0045, 0x0323, 0x1EB8), (0x0045, 0x0327, 0x0228), (0x0045, 0x0328, 0x0118),
+    (0x0045, 0x032D, 0x1E18), (0x0045, 0x0330, 0x1E1A), (0x0046, 0x0307, 0x1E1E),
+    (0x0047, 0x0301, 0x01F4), (0x0047, 0x0302, 0x011C), (0x0047, 0x0304, 0x1E20),
+    (0x0047, 0x0306, 0x011E), (0x0047, 0x0307, 0x0120), (0x0047, 0x030C, 0x01E6),
+    (0x0047, 0x0327, 0x0122), (0x0048, 0x0302, 0x0124), (0x0048, 0x0307, 0x1E22),
+    (0x0048, 0x0308, 0x1E26), (0x0048, 0x030C, 0x021E), (0x0048, 0x0323, 0x1E24),
+    (0x0048, 0x0327, 0x1E28), (0x0048, 0x032E, 0x1E2A), (0x0049, 0x0300, 0x00CC),
+    (0x0049, 0x0301, 0x00CD), (0x0049, 0x0302, 0x00CE), (0x0049, 0x0303, 0x0128),
+    (0x0049, 0x0304, 0x012A), (0x0049, 0x0306, 0x012C), (0x0049, 0x0307, 0x0130),
+    (0x0049, 0x0308, 0x00CF), (0x0049, 0x0309, 0x1EC8), (0x0049, 0x030C, 0x01CF),
+    (0x0049, 0x030F, 0x0208), (0x0049, 0x0311, 0x020A), (0x0049, 0x0323, 0x1ECA),
+    (0x0049, 0x0328, 0x012E), (0x0049, 0x0330, 0x1E2C), (0x004A, 0x0302, 0x0134),
+    (0x004B, 0x0301, 0x1E30), (0x004B, 0x030C, 0x01E8), (0x004B, 0x0323, 0x1E32),
+    (0x004B, 0x0327, 0x0136), (0x004B, 0x0331, 0x1E34), (0x004C, 0x0301, 0x0139),
+    (0x004C, 0x030C, 0x013D), (0x004C, 0x0323, 0x1E36), (0x004C, 0x0327, 0x013B),
+    (0x004C, 0x032D, 0x1E3C), (0x004C, 0x0331, 0x1E3A), (0x004D, 0x0301, 0x1E3E),
+    (0x004D, 0x0307, 0x1E40), (0x004D, 0x0323, 0x1E42), (0x004E, 0x0300, 0x01F8),
+    (0x004E, 0x0301, 0x0143), (0x004E, 0x0303, 0x00D1), (0x004E, 0x0307, 0x1E44),
+    (0x004E, 0x030C, 0x0147), (0x004E, 0x0323, 0x1E46), (0x004E, 0x0327, 0x0145),
+    (0x004E, 0x032D, 0x1E4A), (0x004E, 0x0331, 0x1E48), (0x004F, 0x0300, 0x00D2),
+    (0x004F, 0x0301, 0x00D3), (0x004F, 0x0302, 0x00D4), (0x004F, 0x0303, 0x00D5),
+    (0x004F, 0x0304, 0x014C), (0x004F, 0x0306, 0x014E), (0x004F, 0x0307, 0x022E),
+    (0x004F, 0x0308, 0x00D6), (0x004F, 0x0309, 0x1ECE), (0x004F, 0x030B, 0x0150),
+    (0x004F, 0x030C, 0x01D1), (0x004F, 0x030F, 0x020C), (0x004F, 0x0311, 0x020E),
+    (0x004F, 0x031B, 0x01A0), (0x004F, 0x0323, 0x1ECC), (0x004F, 0x0328, 0x01EA),
+    (0x0050, 0x0301, 0x1E54), (0x0050, 0x0307, 0x1E56), (0x0052, 0x0301, 0x0154),
+    (0x0052, 0x0307, 0x1E58), (0x0052, 0x030C, 0x0158), (0x0052, 0x030F, 0x0210),
+    (0x0052, 0x0311, 0x0212), (0x0052, 0x0323, 0x1E5A), (0x0052, 0x0327, 0x0156),
+    (0x0052, 0x0331, 0x1E5E), (0x0053, 0x0301, 0x015A), (0x0053, 0x0302, 0x015C),
+    (0x0053, 0x0307, 0x1E60), (0x0053, 0x030C, 0x0160), (0x0053, 0x0323, 0x1E62),
+    (0x0053, 0x0326, 0x0218), (0x0053, 0x0327, 0x015E), (0x0054, 0x0307, 0x1E6A),
+    (0x0054, 0x030C, 0x0164), (0x0054, 0x0323, 0x1E6C), (0x0054, 0x0326, 0x021A),
+    (0x0054, 0x0327, 0x0162), (0x0054, 0x032D, 0x1E70), (0x0054, 0x0331, 0x1E6E),
+    (0x0055, 0x0300, 0x00D9), (0x0055, 0x0301, 0x00DA), (0x0055, 0x0302, 0x00DB),
+    (0x0055, 0x0303, 0x0168), (0x0055, 0x0304, 0x016A), (0x0055, 0x0306, 0x016C),
+    (0x0055, 0x0308, 0x00DC), (0x0055, 0x0309, 0x1EE6), (0x0055, 0x030A, 0x016E),
+    (0x0055, 0x030B, 0x0170), (0x0055, 0x030C, 0x01D3), (0x0055, 0x030F, 0x0214),
+    (0x0055, 0x0311, 0x0216), (0x0055, 0x031B, 0x01AF), (0x0055, 0x0323, 0x1EE4),
+    (0x0055, 0x0324, 0x1E72), (0x0055, 0x0328, 0x0172), (0x0055, 0x032D, 0x1E76),
+    (0x0055, 0x0330, 0x1E74), (0x0056, 0x0303, 0x1E7C), (0x0056, 0x0323, 0x1E7E),
+    (0x0057, 0x0300, 0x1E80), (0x0057, 0x0301, 0x1E82), (0x0057, 0x0302, 0x0174),
+    (0x0057, 0x0307, 0x1E86), (0x0057, 0x0308, 0x1E84), (0x0057, 0x0323, 0x1E88),
+    (0x0058, 0x0307, 0x1E8A), (0x0058, 0x0308, 0x1E8C), (0x0059, 0x0300, 0x1EF2),
+    (0x0059, 0x0301, 0x00DD), (0x0059, 0x0302, 0x0176), (0x0059, 0x0303, 0x1EF8),
+    (0x0059, 0x0304, 0x0232), (0x0059, 0x0307, 0x1E8E), (0x0059, 0x0308, 0x0178),
+    (0x0059, 0x0309, 0x1EF6), (0x0059, 0x0323, 0x1EF4), (0x005A, 0x0301, 0x0179),
+    (0x005A, 0x0302, 0x1E90), (0x005A, 0x0307, 0x017B), (0x005A, 0x030C, 0x017D),
+    (0x005A, 0x0323, 0x1E92), (0x005A, 0x0331, 0x1E94), (0x0061, 0x0300, 0x00E0),
+    (0x0061, 0x0301, 0x00E1), (0x0061, 0x0302, 0x00E2), (0x0061, 0x0303, 0x00E3),
+    (0x0061, 0x0304, 0x0101), (0x0061, 0x0306, 0x0103), (0x0061, 0x0307, 0x0227),
+    (0x0061, 0x0308, 0x00E4), (0x0061, 0x0309, 0x1EA3), (0x0061, 0x030A, 0x00E5),
+    (0x0061, 0x030C, 0x01CE), (0x0061, 0x030F, 0x0201), (0x0061, 0x0311, 0x0203),
+    (0x0061, 0x0323, 0x1EA1), (0x0061, 0x0325, 0x1E01), (0x0061, 0x0328, 0x0105),
+    (0x0062, 0x0307, 0x1E03), (0x0062, 0x0323, 0x1E05), (0x0062, 0x0331, 0x1E07),
+    (0x0063, 0x0301, 0x0107), (0x0063, 0x0302, 0x0109), (0x0063, 0x0307, 0x010B),
+    (0x0063, 0x030C, 0x010D), (0x0063, 0x0327, 0x00E7), (0x0064, 0x0307, 0x1E0B),
+    (0x0064, 0x030C, 0x010F), (0x0064, 0x0323, 0x1E0D), (0x0064, 0x0327, 0x1E11),
+    (0x0064, 0x032D, 0x1E13), (0x0064, 0x0331, 0x1E0F), (0x0065, 0x0300, 0x00E8),
+    (0x0065, 0x0301, 0x00E9), (0x0065, 0x0302, 0x00EA), (0x0065, 0x0303, 0x1EBD),
+    (0x0065, 0x0304, 0x0113), (0x0065, 0x0306, 0x0115), (0x0065, 0x0307, 0x0117),
+    (0x0065, 0x0308, 0x00EB), (0x0065, 0x0309, 0x1EBB), (0x0065, 0x030C, 0x011B),
+    (0x0065, 0x030F, 0x0205), (0x0065, 0x0311, 0x0207), (0x0065, 0x0323, 0x1EB9),
+    (0x0065, 0x0327, 0x0229), (0x0065, 0x0328, 0x0119), (0x0065, 0x032D, 0x1E19),
+    (0x0065, 0x0330, 0x1E1B), (0x0066, 0x0307, 0x1E1F), (0x0067, 0x0301, 0x01F5),
+    (0x0067, 0x0302, 0x011D), (0x0067, 0x0304, 0x1E21), (0x0067, 0x0306, 0x011F),
+    (0x0067, 0x0307, 0x0121), (0x0067, 0x030C, 0x01E7), (0x0067, 0x0327, 0x0123),
+    (0x0068, 0x0302, 0x0125), (0x0068, 0x0307, 0x1E23), (0x0068, 0x0308, 0x1E27),
+    (0x0068, 0x030C, 0x021F), (0x0068, 0x0323, 0x1E25), (0x0068, 0x0327, 0x1E29),
+    (0x0068, 0x032E, 0x1E2B), (0x0068, 0x0331, 0x1E96), (0x0069, 0x0300, 0x00EC),
+    (0x0069, 0x0301, 0x00ED), (0x0069, 0x0302, 0x00EE), (0x0069, 0x0303, 0x0129),
+    (0x0069, 0x0304, 0x012B), (0x0069, 0x0306, 0x012D), (0x0069, 0x0308, 0x00EF),
+    (0x0069, 0x0309, 0x1EC9), (0x0069, 0x030C, 0x01D0), (0x0069, 0x030F, 0x0209),
+    (0x0069, 0x0311, 0x020B), (0x0069, 0x0323, 0x1ECB), (0x0069, 0x0328, 0x012F),
+    (0x0069, 0x0330, 0x1E2D), (0x006A, 0x0302, 0x0135), (0x006A, 0x030C, 0x01F0),
+    (0x006B, 0x0301, 0x1E31), (0x006B, 0x030C, 0x01E9), (0x006B, 0x0323, 0x1E33),
+    (0x006B, 0x0327, 0x0137), (0x006B, 0x0331, 0x1E35), (0x006C, 0x0301, 0x013A),
+    (0x006C, 0x030C, 0x013E), (0x006C, 0x0323, 0x1E37), (0x006C, 0x0327, 0x013C),
+    (0x006C, 0x032D, 0x1E3D), (0x006C, 0x0331, 0x1E3B), (0x006D, 0x0301, 0x1E3F),
+    (0x006D, 0x0307, 0x1E41), (0x006D, 0x0323, 0x1E43), (0x006E, 0x0300, 0x01F9),
+    (0x006E, 0x0301, 0x0144), (0x006E, 0x0303, 0x00F1), (0x006E, 0x0307, 0x1E45),
+    (0x006E, 0x030C, 0x0148), (0x006E, 0x0323, 0x1E47), (0x006E, 0x0327, 0x0146),
+    (0x006E, 0x032D, 0x1E4B), (0x006E, 0x0331, 0x1E49), (0x006F, 0x0300, 0x00F2),
+    (0x006F, 0x0301, 0x00F3), (0x006F, 0x0302, 0x00F4), (0x006F, 0x0303, 0x00F5),
+    (0x006F, 0x0304, 0x014D), (0x006F, 0x0306, 0x014F), (0x006F, 0x0307, 0x022F),
+    (0x006F, 0x0308, 0x00F6), (0x006F, 0x0309, 0x1ECF), (0x006F, 0x030B, 0x0151),
+    (0x006F, 0x030C, 0x01D2), (0x006F, 0x030F, 0x020D), (0x006F, 0x0311, 0x020F),
+    (0x006F, 0x031B, 0x01A1), (0x006F, 0x0323, 0x1ECD), (0x006F, 0x0328, 0x01EB),
+    (0x0070, 0x0301, 0x1E55), (0x0070, 0x0307, 0x1E57), (0x0072, 0x0301, 0x0155),
+    (0x0072, 0x0307, 0x1E59), (0x0072, 0x030C, 0x0159), (0x0072, 0x030F, 0x0211),
+    (0x0072, 0x0311, 0x0213), (0x0072, 0x0323, 0x1E5B), (0x0072, 0x0327, 0x0157),
+    (0x0072, 0x0331, 0x1E5F), (0x0073, 0x0301, 0x015B), (0x0073, 0x0302, 0x015D),
+    (0x0073, 0x0307, 0x1E61), (0x0073, 0x030C, 0x0161), (0x0073, 0x0323, 0x1E63),
+    (0x0073, 0x0326, 0x0219), (0x0073, 0x0327, 0x015F), (0x0074, 0x0307, 0x1E6B),
+    (0x0074, 0x0308, 0x1E97), (0x0074, 0x030C, 0x0165), (0x0074, 0x0323, 0x1E6D),
+    (0x0074, 0x0326, 0x021B), (0x0074, 0x0327, 0x0163), (0x0074, 0x032D, 0x1E71),
+    (0x0074, 0x0331, 0x1E6F), (0x0075, 0x0300, 0x00F9), (0x0075, 0x0301, 0x00FA),
+    (0x0075, 0x0302, 0x00FB), (0x0075, 0x0303, 0x0169), (0x0075, 0x0304, 0x016B),
+    (0x0075, 0x0306, 0x016D), (0x0075, 0x0308, 0x00FC), (0x0075, 0x0309, 0x1EE7),
+    (0x0075, 0x030A, 0x016F), (0x0075, 0x030B, 0x0171), (0x0075, 0x030C, 0x01D4),
+    (0x0075, 0x030F, 0x0215), (0x0075, 0x0311, 0x0217), (0x0075, 0x031B, 0x01B0),
+    (0x0075, 0x0323, 0x1EE5), (0x0075, 0x0324, 0x1E73), (0x0075, 0x0328, 0x0173),
+    (0x0075, 0x032D, 0x1E77), (0x0075, 0x0330, 0x1E75), (0x0076, 0x0303, 0x1E7D),
+    (0x0076, 0x0323, 0x1E7F), (0x0077, 0x0300, 0x1E81), (0x0077, 0x0301, 0x1E83),
+    (0x0077, 0x0302, 0x0175), (0x0077, 0x0307, 0x1E87), (0x0077, 0x0308, 0x1E85),
+    (0x0077, 0x030A, 0x1E98), (0x0077, 0x0323, 0x1E89), (0x0078, 0x0307, 0x1E8B),
+    (0x0078, 0x0308, 0x1E8D), (0x0079, 0x0300, 0x1EF3), (0x0079, 0x0301, 0x00FD),
+    (0x0079, 0x0302, 0x0177), (0x0079, 0x0303, 0x1EF9), (0x0079, 0x0304, 0x0233),
+    (0x0079, 0x0307, 0x1E8F), (0x0079, 0x0308, 0x00FF), (0x0079, 0x0309, 0x1EF7),
+    (0x0079, 0x030A, 0x1E99), (0x0079, 0x0323, 0x1EF5), (0x007A, 0x0301, 0x017A),
+    (0x007A, 0x0302, 0x1E91), (0x007A, 0x0307, 0x017C), (0x007A, 0x030C, 0x017E),
+    (0x007A, 0x0323, 0x1E93), (0x007A, 0x0331, 0x1E95), (0x00A8, 0x0300, 0x1FED),
+    (0x00A8, 0x0301, 0x0385), (0x00A8, 0x0342, 0x1FC1), (0x00C2, 0x0300, 0x1EA6),
+    (0x00C2, 0x0301, 0x1EA4), (0x00C2, 0x0303, 0x1EAA), (0x00C2, 0x0309, 0x1EA8),
+    (0x00C4, 0x0304, 0x01DE), (0x00C5, 0x0301, 0x01FA), (0x00C6, 0x0301, 0x01FC),
+    (0x00C6, 0x0304, 0x01E2), (0x00C7, 0x0301, 0x1E08), (0x00CA, 0x0300, 0x1EC0),
+    (0x00CA, 0x0301, 0x1EBE), (0x00CA, 0x0303, 0x1EC4), (0x00CA, 0x0309, 0x1EC2),
+    (0x00CF, 0x0301, 0x1E2E), (0x00D4, 0x0300, 0x1ED2), (0x00D4, 0x0301, 0x1ED0),
+    (0x00D4, 0x0303, 0x1ED6), (0x00D4, 0x0309, 0x1ED4), (0x00D5, 0x0301, 0x1E4C),
+    (0x00D5, 0x0304, 0x022C), (0x00D5, 0x0308, 0x1E4E), (0x00D6, 0x0304, 0x022A),
+    (0x00D8, 0x0301, 0x01FE), (0x00DC, 0x0300, 0x01DB), (0x00DC, 0x0301, 0x01D7),
+    (0x00DC, 0x0304, 0x01D5), (0x00DC, 0x030C, 0x01D9), (0x00E2, 0x0300, 0x1EA7),
+    (0x00E2, 0x0301, 0x1EA5), (0x00E2, 0x0303, 0x1EAB), (0x00E2, 0x0309, 0x1EA9),
+    (0x00E4, 0x0304, 0x01DF), (0x00E5, 0x0301, 0x01FB), (0x00E6, 0x0301, 0x01FD),
+    (0x00E6, 0x0304, 0x01E3), (0x00E7, 0x0301, 0x1E09), (0x00EA, 0x0300, 0x1EC1),
+    (0x00EA, 0x0301, 0x1EBF), (0x00EA, 0x0303, 0x1EC5), (0x00EA, 0x0309, 0x1EC3),
+    (0x00EF, 0x0301, 0x1E2F), (0x00F4, 0x0300, 0x1ED3), (0x00F4, 0x0301, 0x1ED1),
+    (0x00F4, 0x0303, 0x1ED7), (0x00F4, 0x0309, 0x1ED5), (0x00F5, 0x0301, 0x1E4D),
+    (0x00F5, 0x0304, 0x022D), (0x00F5, 0x0308, 0x1E4F), (0x00F6, 0x0304, 0x022B),
+    (0x00F8, 0x0301, 0x01FF), (0x00FC, 0x0300, 0x01DC), (0x00FC, 0x0301, 0x01D8),
+    (0x00FC, 0x0304, 0x01D6), (0x00FC, 0x030C, 0x01DA), (0x0102, 0x0300, 0x1EB0),
+    (0x0102, 0x0301, 0x1EAE), (0x0102, 0x0303, 0x1EB4), (0x0102, 0x0309, 0x1EB2),
+    (0x0103, 0x0300, 0x1EB1), (0x0103, 0x0301, 0x1EAF), (0x0103, 0x0303, 0x1EB5),
+    (0x0103, 0x0309, 0x1EB3), (0x0112, 0x0300, 0x1E14), (0x0112, 0x0301, 0x1E16),
+    (0x0113, 0x0300, 0x1E15), (0x0113, 0x0301, 0x1E17), (0x014C, 0x0300, 0x1E50),
+    (0x014C, 0x0301, 0x1E52), (0x014D, 0x0300, 0x1E51), (0x014D, 0x0301, 0x1E53),
+    (0x015A, 0x0307, 0x1E64), (0x015B, 0x0307, 0x1E65), (0x0160, 0x0307, 0x1E66),
+    (0x0161, 0x0307, 0x1E67), (0x0168, 0x0301, 0x1E78), (0x0169, 0x0301, 0x1E79),
+    (0x016A, 0x0308, 0x1E7A), (0x016B, 0x0308, 0x1E7B), (0x017F, 0x0307, 0x1E9B),
+    (0x01A0, 0x0300, 0x1EDC), (0x01A0, 0x0301, 0x1EDA), (0x01A0, 0x0303, 0x1EE0),
+    (0x01A0, 0x0309, 0x1EDE), (0x01A0, 0x0323, 0x1EE2), (0x01A1, 0x0300, 0x1EDD),
+    (0x01A1, 0x0301, 0x1EDB), (0x01A1, 0x0303, 0x1EE1), (0x01A1, 0x0309, 0x1EDF),
+    (0x01A1, 0x0323, 0x1EE3), (0x01AF, 0x0300, 0x1EEA), (0x01AF, 0x0301, 0x1EE8),
+    (0x01AF, 0x0303, 0x1EEE), (0x01AF, 0x0309, 0x1EEC), (0x01AF, 0x0323, 0x1EF0),
+    (0x01B0, 0x0300, 0x1EEB), (0x01B0, 0x0301, 0x1EE9), (0x01B0, 0x0303, 0x1EEF),
+    (0x01B0, 0x0309, 0x1EED), (0x01B0, 0x0323, 0x1EF1), (0x01B7, 0x030C, 0x01EE),
+    (0x01EA, 0x0304, 0x01EC), (0x01EB, 0x0304, 0x01ED), (0x0226, 0x0304, 0x01E0),
+    (0x0227, 0x0304, 0x01E1), (0x0228, 0x0306, 0x1E1C), (0x0229, 0x0306, 0x1E1D),
+    (0x022E, 0x0304, 0x0230), (0x022F, 0x0304, 0x0231), (0x0292, 0x030C, 0x01EF),
+    (0x0391, 0x0300, 0x1FBA), (0x0391, 0x0301, 0x0386), (0x0391, 0x0304, 0x1FB9),
+    (0x0391, 0x0306, 0x1FB8), (0x0391, 0x0313, 0x1F08), (0x0391, 0x0314, 0x1F09),
+    (0x0391, 0x0345, 0x1FBC), (0x0395, 0x0300, 0x1FC8), (0x0395, 0x0301, 0x0388),
+    (0x0395, 0x0313, 0x1F18), (0x0395, 0x0314, 0x1F19), (0x0397, 0x0300, 0x1FCA),
+    (0x0397, 0x0301, 0x0389), (0x0397, 0x0313, 0x1F28), (0x0397, 0x0314, 0x1F29),
+    (0x0397, 0x0345, 0x1FCC), (0x0399, 0x0300, 0x1FDA), (0x0399, 0x0301, 0x038A),
+    (0x0399, 0x0304, 0x1FD9), (0x0399, 0x0306, 0x1FD8), (0x0399, 0x0308, 0x03AA),
+    (0x0399, 0x0313, 0x1F38), (0x0399, 0x0314, 0x1F39), (0x039F, 0x0300, 0x1FF8),
+    (0x039F, 0x0301, 0x038C), (0x039F, 0x0313, 0x1F48), (0x039F, 0x0314, 0x1F49),
+    (0x03A1, 0x0314, 0x1FEC), (0x03A5, 0x0300, 0x1FEA), (0x03A5, 0x0301, 0x038E),
+    (0x03A5, 0x0304, 0x1FE9), (0x03A5, 0x0306, 0x1FE8), (0x03A5, 0x0308, 0x03AB),
+    (0x03A5, 0x0314, 0x1F59), (0x03A9, 0x0300, 0x1FFA), (0x03A9, 0x0301, 0x038F),
+    (0x03A9, 0x0313, 0x1F68), (0x03A9, 0x0314, 0x1F69), (0x03A9, 0x0345, 0x1FFC),
+    (0x03AC, 0x0345, 0x1FB4), (0x03AE, 0x0345, 0x1FC4), (0x03B1, 0x0300, 0x1F70),
+    (0x03B1, 0x0301, 0x03AC), (0x03B1, 0x0304, 0x1FB1), (0x03B1, 0x0306, 0x1FB0),
+    (0x03B1, 0x0313, 0x1F00), (0x03B1, 0x0314, 0x1F01), (0x03B1, 0x0342, 0x1FB6),
+    (0x03B1, 0x0345, 0x1FB3), (0x03B5, 0x0300, 0x1F72), (0x03B5, 0x0301, 0x03AD),
+    (0x03B5, 0x0313, 0x1F10), (0x03B5, 0x0314, 0x1F11), (0x03B7, 0x0300, 0x1F74),
+    (0x03B7, 0x0301, 0x03AE), (0x03B7, 0x0313, 0x1F20), (0x03B7, 0x0314, 0x1F21),
+    (0x03B7, 0x0342, 0x1FC6), (0x03B7, 0x0345, 0x1FC3), (0x03B9, 0x0300, 0x1F76),
+    (0x03B9, 0x0301, 0x03AF), (0x03B9, 0x0304, 0x1FD1), (0x03B9, 0x0306, 0x1FD0),
+    (0x03B9, 0x0308, 0x03CA), (0x03B9, 0x0313, 0x1F30), (0x03B9, 0x0314, 0x1F31),
+    (0x03B9, 0x0342, 0x1FD6), (0x03BF, 0x0300, 0x1F78), (0x03BF, 0x0301, 0x03CC),
+    (0x03BF, 0x0313, 0x1F40), (0x03BF, 0x0314, 0x1F41), (0x03C1, 0x0313, 0x1FE4),
+    (0x03C1, 0x0314, 0x1FE5), (0x03C5, 0x0300, 0x1F7A), (0x03C5, 0x0301, 0x03CD),
+    (0x03C5, 0x0304, 0x1FE1), (0x03C5, 0x0306, 0x1FE0), (0x03C5, 0x0308, 0x03CB),
+    (0x03C5, 0x0313, 0x1F50), (0x03C5, 0x0314, 0x1F51), (0x03C5, 0x0342, 0x1FE6),
+    (0x03C9, 0x0300, 0x1F7C), (0x03C9, 0x0301, 0x03CE), (0x03C9, 0x0313, 0x1F60),
+    (0x03C9, 0x0314, 0x1F61), (0x03C9, 0x0342, 0x1FF6), (0x03C9, 0x0345, 0x1FF3),
+    (0x03CA, 0x0300, 0x1FD2), (0x03CA, 0x0301, 0x0390), (0x03CA, 0x0342, 0x1FD7),
+    (0x03CB, 0x0300, 0x1FE2), (0x03CB, 0x0301, 0x03B0), (0x03CB, 0x0342, 0x1FE7),
+    (0x03CE, 0x0345, 0x1FF4), (0x03D2, 0x0301, 0x03D3), (0x03D2, 0x0308, 0x03D4),
+    (0x0406, 0x0308, 0x0407), (0x0410, 0x0306, 0x04D0), (0x0410, 0x0308, 0x04D2),
+    (0x0413, 0x0301, 0x0403), (0x0415, 0x0300, 0x0400), (0x0415, 0x0306, 0x04D6),
+    (0x0415, 0x0308, 0x0401), (0x0416, 0x0306, 0x04C1), (0x0416, 0x0308, 0x04DC),
+    (0x0417, 0x0308, 0x04DE), (0x0418, 0x0300, 0x040D), (0x0418, 0x0304, 0x04E2),
+    (0x0418, 0x0306, 0x0419), (0x0418, 0x0308, 0x04E4), (0x041A, 0x0301, 0x040C),
+    (0x041E, 0x0308, 0x04E6), (0x0423, 0x0304, 0x04EE), (0x0423, 0x0306, 0x040E),
+    (0x0423, 0x0308, 0x04F0), (0x0423, 0x030B, 0x04F2), (0x0427, 0x0308, 0x04F4),
+    (0x042B, 0x0308, 0x04F8), (0x042D, 0x0308, 0x04EC), (0x0430, 0x0306, 0x04D1),
+    (0x0430, 0x0308, 0x04D3), (0x0433, 0x0301, 0x0453), (0x0435, 0x0300, 0x0450),
+    (0x0435, 0x0306, 0x04D7), (0x0435, 0x0308, 0x0451), (0x0436, 0x0306, 0x04C2),
+    (0x0436, 0x0308, 0x04DD), (0x0437, 0x0308, 0x04DF), (0x0438, 0x0300, 0x045D),
+    (0x0438, 0x0304, 0x04E3), (0x0438, 0x0306, 0x0439), (0x0438, 0x0308, 0x04E5),
+    (0x043A, 0x0301, 0x045C), (0x043E, 0x0308, 0x04E7), (0x0443, 0x0304, 0x04EF),
+    (0x0443, 0x0306, 0x045E), (0x0443, 0x0308, 0x04F1), (0x0443, 0x030B, 0x04F3),
+    (0x0447, 0x0308, 0x04F5), (0x044B, 0x0308, 0x04F9), (0x044D, 0x0308, 0x04ED),
+    (0x0456, 0x0308, 0x0457), (0x0474, 0x030F, 0x0476), (0x0475, 0x030F, 0x0477),
+    (0x04D8, 0x0308, 0x04DA), (0x04D9, 0x0308, 0x04DB), (0x04E8, 0x0308, 0x04EA),
+    (0x04E9, 0x0308, 0x04EB), (0x0627, 0x0653, 0x0622), (0x0627, 0x0654, 0x0623),
+    (0x0627, 0x0655, 0x0625), (0x0648, 0x0654, 0x0624), (0x064A, 0x0654, 0x0626),
+    (0x06C1, 0x0654, 0x06C2), (0x06D2, 0x0654, 0x06D3), (0x06D5, 0x0654, 0x06C0),
+    (0x0928, 0x093C, 0x0929), (0x0930, 0x093C, 0x0931), (0x0933, 0x093C, 0x0934),
+    (0x09C7, 0x09BE, 0x09CB), (0x09C7, 0x09D7, 0x09CC), (0x0B47, 0x0B3E, 0x0B4B),
+    (0x0B47, 0x0B56, 0x0B48), (0x0B47, 0x0B57, 0x0B4C), (0x0B92, 0x0BD7, 0x0B94),
+    (0x0BC6, 0x0BBE, 0x0BCA), (0x0BC6, 0x0BD7, 0x0BCC), (0x0BC7, 0x0BBE, 0x0BCB),
+    (0x0C46, 0x0C56, 0x0C48), (0x0CBF, 0x0CD5, 0x0CC0), (0x0CC6, 0x0CC2, 0x0CCA),
+    (0x0CC6, 0x0CD5, 0x0CC7), (0x0CC6, 0x0CD6, 0x0CC8), (0x0CCA, 0x0CD5, 0x0CCB),
+    (0x0D46, 0x0D3E, 0x0D4A), (0x0D46, 0x0D57, 0x0D4C), (0x0D47, 0x0D3E, 0x0D4B),
+    (0x0DD9, 0x0DCA, 0x0DDA), (0x0DD9, 0x0DCF, 0x0DDC), (0x0DD9, 0x0DDF, 0x0DDE),
+    (0x0DDC, 0x0DCA, 0x0DDD), (0x1025, 0x102E, 0x1026), (0x1B05, 0x1B35, 0x1B06),
+    (0x1B07, 0x1B35, 0x1B08), (0x1B09, 0x1B35, 0x1B0A), (0x1B0B, 0x1B35, 0x1B0C),
+    (0x1B0D, 0x1B35, 0x1B0E), (0x1B11, 0x1B35, 0x1B12), (0x1B3A, 0x1B35, 0x1B3B),
+    (0x1B3C, 0x1B35, 0x1B3D), (0x1B3E, 0x1B35, 0x1B40), (0x1B3F, 0x1B35, 0x1B41),
+    (0x1B42, 0x1B35, 0x1B43), (0x1E36, 0x0304, 0x1E38), (0x1E37, 0x0304, 0x1E39),
+    (0x1E5A, 0x0304, 0x1E5C), (0x1E5B, 0x0304, 0x1E5D), (0x1E62, 0x0307, 0x1E68),
+    (0x1E63, 0x0307, 0x1E69), (0x1EA0, 0x0302, 0x1EAC), (0x1EA0, 0x0306, 0x1EB6),
+    (0x1EA1, 0x0302, 0x1EAD), (0x1EA1, 0x0306, 0x1EB7), (0x1EB8, 0x0302, 0x1EC6),
+    (0x1EB9, 0x0302, 0x1EC7), (0x1ECC, 0x0302, 0x1ED8), (0x1ECD, 0x0302, 0x1ED9),
+    (0x1F00, 0x0300, 0x1F02), (0x1F00, 0x0301, 0x1F04), (0x1F00, 0x0342, 0x1F06),
+    (0x1F00, 0x0345, 0x1F80), (0x1F01, 0x0300, 0x1F03), (0x1F01, 0x0301, 0x1F05),
+    (0x1F01, 0x0342, 0x1F07), (0x1F01, 0x0345, 0x1F81), (0x1F02, 0x0345, 0x1F82),
+    (0x1F03, 0x0345, 0x1F83), (0x1F04, 0x0345, 0x1F84), (0x1F05, 0x0345, 0x1F85),
+    (0x1F06, 0x0345, 0x1F86), (0x1F07, 0x0345, 0x1F87), (0x1F08, 0x0300, 0x1F0A),
+    (0x1F08, 0x0301, 0x1F0C), (0x1F08, 0x0342, 0x1F0E), (0x1F08, 0x0345, 0x1F88),
+    (0x1F09, 0x0300, 0x1F0B), (0x1F09, 0x0301, 0x1F0D), (0x1F09, 0x0342, 0x1F0F),
+    (0x1F09, 0x0345, 0x1F89), (0x1F0A, 0x0345, 0x1F8A), (0x1F0B, 0x0345, 0x1F8B),
+    (0x1F0C, 0x0345, 0x1F8C), (0x1F0D, 0x0345, 0x1F8D), (0x1F0E, 0x0345, 0x1F8E),
+    (0x1F0F, 0x0345, 0x1F8F), (0x1F10, 0x0300, 0x1F12), (0x1F10, 0x0301, 0x1F14),
+    (0x1F11, 0x0300, 0x1F13), (0x1F11, 0x0301, 0x1F15), (0x1F18, 0x0300, 0x1F1A),
+    (0x1F18, 0x0301, 0x1F1C), (0x1F19, 0x0300, 0x1F1B), (0x1F19, 0x0301, 0x1F1D),
+    (0x1F20, 0x0300, 0x1F22), (0x1F20, 0x0301, 0x1F24), (0x1F20, 0x0342, 0x1F26),
+    (0x1F20, 0x0345, 0x1F90), (0x1F21, 0x0300, 0x1F23), (0x1F21, 0x0301, 0x1F25),
+    (0x1F21, 0x0342, 0x1F27), (0x1F21, 0x0345, 0x1F91), (0x1F22, 0x0345, 0x1F92),
+    (0x1F23, 0x0345, 0x1F93), (0x1F24, 0x0345, 0x1F94), (0x1F25, 0x0345, 0x1F95),
+    (0x1F26, 0x0345, 0x1F96), (0x1F27, 0x0345, 0x1F97), (0x1F28, 0x0300, 0x1F2A),
+    (0x1F28, 0x0301, 0x1F2C), (0x1F28, 0x0342, 0x1F2E), (0x1F28, 0x0345, 0x1F98),
+    (0x1F29, 0x0300, 0x1F2B), (0x1F29, 0x0301, 0x1F2D), (0x1F29, 0x0342, 0x1F2F),
+    (0x1F29, 0x0345, 0x1F99), (0x1F2A, 0x0345, 0x1F9A), (0x1F2B, 0x0345, 0x1F9B),
+    (0x1F2C, 0x0345, 0x1F9C), (0x1F2D, 0x0345, 0x1F9D), (0x1F2E, 0x0345, 0x1F9E),
+    (0x1F2F, 0x0345, 0x1F9F), (0x1F30, 0x0300, 0x1F32), (0x1F30, 0x0301, 0x1F34),
+    (0x1F30, 0x0342, 0x1F36), (0x1F31, 0x0300, 0x1F33), (0x1F31, 0x0301, 0x1F35),
+    (0x1F31, 0x0342, 0x1F37), (0x1F38, 0x0300, 0x1F3A), (0x1F38, 0x0301, 0x1F3C),
+    (0x1F38, 0x0342, 0x1F3E), (0x1F39, 0x0300, 0x1F3B), (0x1F39, 0x0301, 0x1F3D),
+    (0x1F39, 0x0342, 0x1F3F), (0x1F40, 0x0300, 0x1F42), (0x1F40, 0x0301, 0x1F44),
+    (0x1F41, 0x0300, 0x1F43), (0x1F41, 0x0301, 0x1F45), (0x1F48, 0x0300, 0x1F4A),
+    (0x1F48, 0x0301, 0x1F4C), (0x1F49, 0x0300, 0x1F4B), (0x1F49, 0x0301, 0x1F4D),
+    (0x1F50, 0x0300, 0x1F52), (0x1F50, 0x0301, 0x1F54), (0x1F50, 0x0342, 0x1F56),
+    (0x1F51, 0x0300, 0x1F53), (0x1F51, 0x0301, 0x1F55), (0x1F51, 0x0342, 0x1F57),
+    (0x1F59, 0x0300, 0x1F5B), (0x1F59, 0x0301, 0x1F5D), (0x1F59, 0x0342, 0x1F5F),
+    (0x1F60, 0x0300, 0x1F62), (0x1F60, 0x0301, 0x1F64), (0x1F60, 0x0342, 0x1F66),
+    (0x1F60, 0x0345, 0x1FA0), (0x1F61, 0x0300, 0x1F63), (0x1F61, 0x0301, 0x1F65),
+    (0x1F61, 0x0342, 0x1F67), (0x1F61, 0x0345, 0x1FA1), (0x1F62, 0x0345, 0x1FA2),
+    (0x1F63, 0x0345, 0x1FA3), (0x1F64, 0x0345, 0x1FA4), (0x1F65, 0x0345, 0x1FA5),
+    (0x1F66, 0x0345, 0x1FA6), (0x1F67, 0x0345, 0x1FA7), (0x1F68, 0x0300, 0x1F6A),
+    (0x1F68, 0x0301, 0x1F6C), (0x1F68, 0x0342, 0x1F6E), (0x1F68, 0x0345, 0x1FA8),
+    (0x1F69, 0x0300, 0x1F6B), (0x1F69, 0x0301, 0x1F6D), (0x1F69, 0x0342, 0x1F6F),
+    (0x1F69, 0x0345, 0x1FA9), (0x1F6A, 0x0345, 0x1FAA), (0x1F6B, 0x0345, 0x1FAB),
+    (0x1F6C, 0x0345, 0x1FAC), (0x1F6D, 0x0345, 0x1FAD), (0x1F6E, 0x0345, 0x1FAE),
+    (0x1F6F, 0x0345, 0x1FAF), (0x1F70, 0x0345, 0x1FB2), (0x1F74, 0x0345, 0x1FC2),
+    (0x1F7C, 0x0345, 0x1FF2), (0x1FB6, 0x0345, 0x1FB7), (0x1FBF, 0x0300, 0x1FCD),
+    (0x1FBF, 0x0301, 0x1FCE), (0x1FBF, 0x0342, 0x1FCF), (0x1FC6, 0x0345, 0x1FC7),
+    (0x1FF6, 0x0345, 0x1FF7), (0x1FFE, 0x0300, 0x1FDD), (0x1FFE, 0x0301, 0x1FDE),
+    (0x1FFE, 0x0342, 0x1FDF), (0x2190, 0x0338, 0x219A), (0x2192, 0x0338, 0x219B),
+    (0x2194, 0x0338, 0x21AE), (0x21D0, 0x0338, 0x21CD), (0x21D2, 0x0338, 0x21CF),
+    (0x21D4, 0x0338, 0x21CE), (0x2203, 0x0338, 0x2204), (0x2208, 0x0338, 0x2209),
+    (0x220B, 0x0338, 0x220C), (0x2223, 0x0338, 0x2224), (0x2225, 0x0338, 0x2226),
+    (0x223C, 0x0338, 0x2241), (0x2243, 0x0338, 0x2244), (0x2245, 0x0338, 0x2247),
+    (0x2248, 0x0338, 0x2249), (0x224D, 0x0338, 0x226D), (0x2261, 0x0338, 0x2262),
+    (0x2264, 0x0338, 0x2270), (0x2265, 0x0338, 0x2271), (0x2272, 0x0338, 0x2274),
+    (0x2273, 0x0338, 0x2275), (0x2276, 0x0338, 0x2278), (0x2277, 0x0338, 0x2279),
+    (0x227A, 0x0338, 0x2280), (0x227B, 0x0338, 0x2281), (0x227C, 0x0338, 0x22E0),
+    (0x227D, 0x0338, 0x22E1), (0x2282, 0x0338, 0x2284), (0x2283, 0x0338, 0x2285),
+    (0x2286, 0x0338, 0x2288), (0x2287, 0x0338, 0x2289), (0x2291, 0x0338, 0x22E2),
+    (0x2292, 0x0338, 0x22E3), (0x22A2, 0x0338, 0x22AC), (0x22A8, 0x0338, 0x22AD),
+    (0x22A9, 0x0338, 0x22AE), (0x22AB, 0x0338, 0x22AF), (0x22B2, 0x0338, 0x22EA),
+    (0x22B3, 0x0338, 0x22EB), (0x22B4, 0x0338, 0x22EC), (0x22B5, 0x0338, 0x22ED),
+    (0x3046, 0x3099, 0x3094), (0x304B, 0x3099, 0x304C), (0x304D, 0x3099, 0x304E),
+    (0x304F, 0x3099, 0x3050), (0x3051, 0x3099, 0x3052), (0x3053, 0x3099, 0x3054),
+    (0x3055, 0x3099, 0x3056), (0x3057, 0x3099, 0x3058), (0x3059, 0x3099, 0x305A),
+    (0x305B, 0x3099, 0x305C), (0x305D, 0x3099, 0x305E), (0x305F, 0x3099, 0x3060),
+    (0x3061, 0x3099, 0x3062), (0x3064, 0x3099, 0x3065), (0x3066, 0x3099, 0x3067),
+    (0x3068, 0x3099, 0x3069), (0x306F, 0x3099, 0x3070), (0x306F, 0x309A, 0x3071),
+    (0x3072, 0x3099, 0x3073), (0x3072, 0x309A, 0x3074), (0x3075, 0x3099, 0x3076),
+    (0x3075, 0x309A, 0x3077), (0x3078, 0x3099, 0x3079), (0x3078, 0x309A, 0x307A),
+    (0x307B, 0x3099, 0x307C), (0x307B, 0x309A, 0x307D), (0x309D, 0x3099, 0x309E),
+    (0x30A6, 0x3099, 0x30F4), (0x30AB, 0x3099, 0x30AC), (0x30AD, 0x3099, 0x30AE),
+    (0x30AF, 0x3099, 0x30B0), (0x30B1, 0x3099, 0x30B2), (0x30B3, 0x3099, 0x30B4),
+    (0x30B5, 0x3099, 0x30B6), (0x30B7, 0x3099, 0x30B8), (0x30B9, 0x3099, 0x30BA),
+    (0x30BB, 0x3099, 0x30BC), (0x30BD, 0x3099, 0x30BE), (0x30BF, 0x3099, 0x30C0),
+    (0x30C1, 0x3099, 0x30C2), (0x30C4, 0x3099, 0x30C5), (0x30C6, 0x3099, 0x30C7),
+    (0x30C8, 0x3099, 0x30C9), (0x30CF, 0x3099, 0x30D0), (0x30CF, 0x309A, 0x30D1),
+    (0x30D2, 0x3099, 0x30D3), (0x30D2, 0x309A, 0x30D4), (0x30D5, 0x3099, 0x30D6),
+    (0x30D5, 0x309A, 0x30D7), (0x30D8, 0x3099, 0x30D9), (0x30D8, 0x309A, 0x30DA),
+    (0x30DB, 0x3099, 0x30DC), (0x30DB, 0x309A, 0x30DD), (0x30EF, 0x3099, 0x30F7),
+    (0x30F0, 0x3099, 0x30F8), (0x30F1, 0x3099, 0x30F9), (0x30F2, 0x3099, 0x30FA),
+    (0x30FD, 0x3099, 0x30FE), (0x105D2, 0x0307, 0x105C9), (0x105DA, 0x0307, 0x105E4),
+    (0x11099, 0x110BA, 0x1109A), (0x1109B, 0x110BA, 0x1109C), (0x110A5, 0x110BA, 0x110AB),
+    (0x11131, 0x11127, 0x1112E), (0x11132, 0x11127, 0x1112F), (0x11347, 0x1133E, 0x1134B),
+    (0x11347, 0x11357, 0x1134C), (0x11382, 0x113C9, 0x11383), (0x11384, 0x113BB, 0x11385),
+    (0x1138B, 0x113C2, 0x1138E), (0x11390, 0x113C9, 0x11391), (0x113C2, 0x113B8, 0x113C7),
+    (0x113C2, 0x113C2, 0x113C5), (0x113C2, 0x113C9, 0x113C8), (0x114B9, 0x114B0, 0x114BC),
+    (0x114B9, 0x114BA, 0x114BB), (0x114B9, 0x114BD, 0x114BE), (0x115B8, 0x115AF, 0x115BA),
+    (0x115B9, 0x115AF, 0x115BB), (0x11935, 0x11930, 0x11938), (0x1611E, 0x1611E, 0x16121),
+    (0x1611E, 0x1611F, 0x16123), (0x1611E, 0x16120, 0x16125), (0x1611E, 0x16129, 0x16122),
+    (0x16121, 0x1611F, 0x16126), (0x16121, 0x16120, 0x16128), (0x16122, 0x1611F, 0x16127),
+    (0x16129, 0x1611F, 0x16124), (0x16D63, 0x16D67, 0x16D69), (0x16D67, 0x16D67, 0x16D68),
+    (0x16D69, 0x16D67, 0x16D6A),
+];
+
+/// Ranges (first, last, class) of non-zero canonical combining classes, sorted.
+pub static COMBINING_CLASSES: [(u32, u32, u8); 403] = [
+    (0x0300, 0x0314, 230), (0x0315, 0x0315, 232), (0x0316, 0x0319, 220), (0x031A, 0x031A, 232),
+    (0x031B, 0x031B, 216), (0x031C, 0x0320, 220), (0x0321, 0x0322, 202), (0x0323, 0x0326, 220),
+    (0x0327, 0x0328, 202), (0x0329, 0x0333, 220), (0x0334, 0x0338, 1), (0x0339, 0x033C, 220),
+    (0x033D, 0x0344, 230), (0x0345, 0x0345, 240), (0x0346, 0x0346, 230), (0x0347, 0x0349, 220),
+    (0x034A, 0x034C, 230), (0x034D, 0x034E, 220), (0x0350, 0x0352, 230), (0x0353, 0x0356, 220),
+    (0x0357, 0x0357, 230), (0x0358, 0x0358, 232), (0x0359, 0x035A, 220), (0x035B, 0x035B, 230),
+    (0x035C, 0x035C, 233), (0x035D, 0x035E, 234), (0x035F, 0x035F, 233), (0x0360, 0x0361, 234),
+    (0x0362, 0x0362, 233), (0x0363, 0x036F, 230), (0x0483, 0x0487, 230), (0x0591, 0x0591, 220),
+    (0x0592, 0x0595, 230), (0x0596, 0x0596, 220), (0x0597, 0x0599, 230), (0x059A, 0x059A, 222),
+    (0x059B, 0x059B, 220), (0x059C, 0x05A1, 230), (0x05A2, 0x05A7, 220), (0x05A8, 0x05A9, 230),
+    (0x05AA, 0x05AA, 220), (0x05AB, 0x05AC, 230), (0x05AD, 0x05AD, 222), (0x05AE, 0x05AE, 228),
+    (0x05AF, 0x05AF, 230), (0x05B0, 0x05B0, 10), (0x05B1, 0x05B1, 11), (0x05B2, 0x05B2, 12),
+    (0x05B3, 0x05B3, 13), (0x05B4, 0x05B4, 14), (0x05B5, 0x05B5, 15), (0x05B6, 0x05B6, 16),
+    (0x05B7, 0x05B7, 17), (0x05B8, 0x05B8, 18), (0x05B9, 0x05BA, 19), (0x05BB, 0x05BB, 20),
+    (0x05BC, 0x05BC, 21), (0x05BD, 0x05BD, 22), (0x05BF, 0x05BF, 23), (0x05C1, 0x05C1, 24),
+    (0x05C2, 0x05C2, 25), (0x05C4, 0x05C4, 230), (0x05C5, 0x05C5, 220), (0x05C7, 0x05C7, 18),
+    (0x0610, 0x0617, 230), (0x0618, 0x0618, 30), (0x0619, 0x0619, 31), (0x061A, 0x061A, 32),
+    (0x064B, 0x064B, 27), (0x064C, 0x064C, 28), (0x064D, 0x064D, 29), (0x064E, 0x064E, 30),
+    (0x064F, 0x064F, 31), (0x0650, 0x0650, 32), (0x0651, 0x0651, 33), (0x0652, 0x0652, 34),
+    (0x0653, 0x0654, 230), (0x0655, 0x0656, 220), (0x0657, 0x065B, 230), (0x065C, 0x065C, 220),
+    (0x065D, 0x065E, 230), (0x065F, 0x065F, 220), (0x0670, 0x0670, 35), (0x06D6, 0x06DC, 230),
+    (0x06DF, 0x06E2, 230), (0x06E3, 0x06E3, 220), (0x06E4, 0x06E4, 230), (0x06E7, 0x06E8, 230),
+    (0x06EA, 0x06EA, 220), (0x06EB, 0x06EC, 230), (0x06ED, 0x06ED, 220), (0x0711, 0x0711, 36),
+    (0x0730, 0x0730, 230), (0x0731, 0x0731, 220), (0x0732, 0x0733, 230), (0x0734, 0x0734, 220),
+    (0x0735, 0x0736, 230), (0x0737, 0x0739, 220), (0x073A, 0x073A, 230), (0x073B, 0x073C, 220),
+    (0x073D, 0x073D, 230), (0x073E, 0x073E, 220), (0x073F, 0x0741, 230), (0x0742, 0x0742, 220),
+    (0x0743, 0x0743, 230), (0x0744, 0x0744, 220), (0x0745, 0x0745, 230), (0x0746, 0x0746, 220),
+    (0x0747, 0x0747, 230), (0x0748, 0x0748, 220), (0x0749, 0x074A, 230), (0x07EB, 0x07F1, 230),
+    (0x07F2, 0x07F2, 220), (0x07F3, 0x07F3, 230), (0x07FD, 0x07FD, 220), (0x0816, 0x0819, 230),
+    (0x081B, 0x0823, 230), (0x0825, 0x0827, 230), (0x0829, 0x082D, 230), (0x0859, 0x085B, 220),
+    (0x0897, 0x0898, 230), (0x0899, 0x089B, 220), (0x089C, 0x089F, 230), (0x08CA, 0x08CE, 230),
+    (0x08CF, 0x08D3, 220), (0x08D4, 0x08E1, 230), (0x08E3, 0x08E3, 220), (0x08E4, 0x08E5, 230),
+    (0x08E6, 0x08E6, 220), (0x08E7, 0x08E8, 230), (0x08E9, 0x08E9, 220), (0x08EA, 0x08EC, 230),
+    (0x08ED, 0x08EF, 220), (0x08F0, 0x08F0, 27), (0x08F1, 0x08F1, 28), (0x08F2, 0x08F2, 29),
+    (0x08F3, 0x08F5, 230), (0x08F6, 0x08F6, 220), (0x08F7, 0x08F8, 230), (0x08F9, 0x08FA, 220),
+    (0x08FB, 0x08FF, 230), (0x093C, 0x093C, 7), (0x094D, 0x094D, 9), (0x0951, 0x0951, 230),
+    (0x0952, 0x0952, 220), (0x0953, 0x0954, 230), (0x09BC, 0x09BC, 7), (0x09CD, 0x09CD, 9),
+    (0x09FE, 0x09FE, 230), (0x0A3C, 0x0A3C, 7), (0x0A4D, 0x0A4D, 9), (0x0ABC, 0x0ABC, 7),
+    (0x0ACD, 0x0ACD, 9), (0x0B3C, 0x0B3C, 7), (0x0B4D, 0x0B4D, 9), (0x0BCD, 0x0BCD, 9),
+    (0x0C3C, 0x0C3C, 7), (0x0C4D, 0x0C4D, 9), (0x0C55, 0x0C55, 84), (0x0C56, 0x0C56, 91),
+    (0x0CBC, 0x0CBC, 7), (0x0CCD, 0x0CCD, 9), (0x0D3B, 0x0D3C, 9), (0x0D4D, 0x0D4D, 9),
+    (0x0DCA, 0x0DCA, 9), (0x0E38, 0x0E39, 103), (0x0E3A, 0x0E3A, 9), (0x0E48, 0x0E4B, 107),
+    (0x0EB8, 0x0EB9, 118), (0x0EBA, 0x0EBA, 9), (0x0EC8, 0x0ECB, 122), (0x0F18, 0x0F19, 220),
+    (0x0F35, 0x0F35, 220), (0x0F37, 0x0F37, 220), (0x0F39, 0x0F39, 216), (0x0F71, 0x0F71, 129),
+    (0x0F72, 0x0F72, 130), (0x0F74, 0x0F74, 132), (0x0F7A, 0x0F7D, 130), (0x0F80, 0x0F80, 130),
+    (0x0F82, 0x0F83, 230), (0x0F84, 0x0F84, 9), (0x0F86, 0x0F87, 230), (0x0FC6, 0x0FC6, 220),
+    (0x1037, 0x1037, 7), (0x1039, 0x103A, 9), (0x108D, 0x108D, 220), (0x135D, 0x135F, 230),
+    (0x1714, 0x1715, 9), (0x1734, 0x1734, 9), (0x17D2, 0x17D2, 9), (0x17DD, 0x17DD, 230),
+    (0x18A9, 0x18A9, 228), (0x1939, 0x1939, 222), (0x193A, 0x193A, 230), (0x193B, 0x193B, 220),
+    (0x1A17, 0x1A17, 230), (0x1A18, 0x1A18, 220), (0x1A60, 0x1A60, 9), (0x1A75, 0x1A7C, 230),
+    (0x1A7F, 0x1A7F, 220), (0x1AB0, 0x1AB4, 230), (0x1AB5, 0x1ABA, 220), (0x1ABB, 0x1ABC, 230),
+    (0x1ABD, 0x1ABD, 220), (0x1ABF, 0x1AC0, 220), (0x1AC1, 0x1AC2, 230), (0x1AC3, 0x1AC4, 220),
+    (0x1AC5, 0x1AC9, 230), (0x1ACA, 0x1ACA, 220), (0x1ACB, 0x1ADC, 230), (0x1ADD, 0x1ADD, 220),
+    (0x1AE0, 0x1AE5, 230), (0x1AE6, 0x1AE6, 220), (0x1AE7, 0x1AEA, 230), (0x1AEB, 0x1AEB, 234),
+    (0x1B34, 0x1B34, 7), (0x1B44, 0x1B44, 9), (0x1B6B, 0x1B6B, 230), (0x1B6C, 0x1B6C, 220),
+    (0x1B6D, 0x1B73, 230), (0x1BAA, 0x1BAB, 9), (0x1BE6, 0x1BE6, 7), (0x1BF2, 0x1BF3, 9),
+    (0x1C37, 0x1C37, 7), (0x1CD0, 0x1CD2, 230), (0x1CD4, 0x1CD4, 1), (0x1CD5, 0x1CD9, 220),
+    (0x1CDA, 0x1CDB, 230), (0x1CDC, 0x1CDF, 220), (0x1CE0, 0x1CE0, 230), (0x1CE2, 0x1CE8, 1),
+    (0x1CED, 0x1CED, 220), (0x1CF4, 0x1CF4, 230), (0x1CF8, 0x1CF9, 230), (0x1DC0, 0x1DC1, 230),
+    (0x1DC2, 0x1DC2, 220), (0x1DC3, 0x1DC9, 230), (0x1DCA, 0x1DCA, 220), (0x1DCB, 0x1DCC, 230),
+    (0x1DCD, 0x1DCD, 234), (0x1DCE, 0x1DCE, 214), (0x1DCF, 0x1DCF, 220), (0x1DD0, 0x1DD0, 202),
+    (0x1DD1, 0x1DF5, 230), (0x1DF6, 0x1DF6, 232), (0x1DF7, 0x1DF8, 228), (0x1DF9, 0x1DF9, 220),
+    (0x1DFA, 0x1DFA, 218), (0x1DFB, 0x1DFB, 230), (0x1DFC, 0x1DFC, 233), (0x1DFD, 0x1DFD, 220),
+    (0x1DFE, 0x1DFE, 230), (0x1DFF, 0x1DFF, 220), (0x20D0, 0x20D1, 230), (0x20D2, 0x20D3, 1),
+    (0x20D4, 0x20D7, 230), (0x20D8, 0x20DA, 1), (0x20DB, 0x20DC, 230), (0x20E1, 0x20E1, 230),
+    (0x20E5, 0x20E6, 1), (0x20E7, 0x20E7, 230), (0x20E8, 0x20E8, 220), (0x20E9, 0x20E9, 230),
+    (0x20EA, 0x20EB, 1), (0x20EC, 0x20EF, 220), (0x20F0, 0x20F0, 230), (0x2CEF, 0x2CF1, 230),
+    (0x2D7F, 0x2D7F, 9), (0x2DE0, 0x2DFF, 230), (0x302A, 0x302A, 218), (0x302B, 0x302B, 228),
+    (0x302C, 0x302C, 232), (0x302D, 0x302D, 222), (0x302E, 0x302F, 224), (0x3099, 0x309A, 8),
+    (0xA66F, 0xA66F, 230), (0xA674, 0xA67D, 230), (0xA69E, 0xA69F, 230), (0xA6F0, 0xA6F1, 230),
+    (0xA806, 0xA806, 9), (0xA82C, 0xA82C, 9), (0xA8C4, 0xA8C4, 9), (0xA8E0, 0xA8F1, 230),
+    (0xA92B, 0xA92D, 220), (0xA953, 0xA953, 9), (0xA9B3, 0xA9B3, 7), (0xA9C0, 0xA9C0, 9),
+    (0xAAB0, 0xAAB0, 230), (0xAAB2, 0xAAB3, 230), (0xAAB4, 0xAAB4, 220), (0xAAB7, 0xAAB8, 230),
+    (0xAABE, 0xAABF, 230), (0xAAC1, 0xAAC1, 230), (0xAAF6, 0xAAF6, 9), (0xABED, 0xABED, 9),
+    (0xFB1E, 0xFB1E, 26), (0xFE20, 0xFE26, 230), (0xFE27, 0xFE2D, 220), (0xFE2E, 0xFE2F, 230),
+    (0x101FD, 0x101FD, 220), (0x102E0, 0x102E0, 220), (0x10376, 0x1037A, 230), (0x10A0D, 0x10A0D, 220),
+    (0x10A0F, 0x10A0F, 230), (0x10A38, 0x10A38, 230), (0x10A39, 0x10A39, 1), (0x10A3A, 0x10A3A, 220),
+    (0x10A3F, 0x10A3F, 9), (0x10AE5, 0x10AE5, 230), (0x10AE6, 0x10AE6, 220), (0x10D24, 0x10D27, 230),
+    (0x10D69, 0x10D6D, 230), (0x10EAB, 0x10EAC, 230), (0x10EFA, 0x10EFB, 220), (0x10EFD, 0x10EFF, 220),
+    (0x10F46, 0x10F47, 220), (0x10F48, 0x10F4A, 230), (0x10F4B, 0x10F4B, 220), (0x10F4C, 0x10F4C, 230),
+    (0x10F4D, 0x10F50, 220), (0x10F82, 0x10F82, 230), (0x10F83, 0x10F83, 220), (0x10F84, 0x10F84, 230),
+    (0x10F85, 0x10F85, 220), (0x11046, 0x11046, 9), (0x11070, 0x11070, 9), (0x1107F, 0x1107F, 9),
+    (0x110B9, 0x110B9, 9), (0x110BA, 0x110BA, 7), (0x11100, 0x11102, 230), (0x11133, 0x11134, 9),
+    (0x11173, 0x11173, 7), (0x111C0, 0x111C0, 9), (0x111CA, 0x111CA, 7), (0x11235, 0x11235, 9),
+    (0x11236, 0x11236, 7), (0x112E9, 0x112E9, 7), (0x112EA, 0x112EA, 9), (0x1133B, 0x1133C, 7),
+    (0x1134D, 0x1134D, 9), (0x11366, 0x1136C, 230), (0x11370, 0x11374, 230), (0x113CE, 0x113D0, 9),
+    (0x11442, 0x11442, 9), (0x11446, 0x11446, 7), (0x1145E, 0x1145E, 230), (0x114C2, 0x114C2, 9),
+    (0x114C3, 0x114C3, 7), (0x115BF, 0x115BF, 9), (0x115C0, 0x115C0, 7), (0x1163F, 0x1163F, 9),
+    (0x116B6, 0x116B6, 9), (0x116B7, 0x116B7, 7), (0x1172B, 0x1172B, 9), (0x11839, 0x11839, 9),
+    (0x1183A, 0x1183A, 7), (0x1193D, 0x1193E, 9), (0x11943, 0x11943, 7), (0x119E0, 0x119E0, 9),
+    (0x11A34, 0x11A34, 9), (0x11A47, 0x11A47, 9), (0x11A99, 0x11A99, 9), (0x11C3F, 0x11C3F, 9),
+    (0x11D42, 0x11D42, 7), (0x11D44, 0x11D45, 9), (0x11D97, 0x11D97, 9), (0x11F41, 0x11F42, 9),
+    (0x1612F, 0x1612F, 9), (0x16AF0, 0x16AF4, 1), (0x16B30, 0x16B36, 230), (0x16FF0, 0x16FF1, 6),
+    (0x1BC9E, 0x1BC9E, 1), (0x1D165, 0x1D166, 216), (0x1D167, 0x1D169, 1), (0x1D16D, 0x1D16D, 226),
+    (0x1D16E, 0x1D172, 216), (0x1D17B, 0x1D182, 220), (0x1D185, 0x1D189, 230), (0x1D18A, 0x1D18B, 220),
+    (0x1D1AA, 0x1D1AD, 230), (0x1D242, 0x1D244, 230), (0x1E000, 0x1E006, 230), (0x1E008, 0x1E018, 230),
+    (0x1E01B, 0x1E021, 230), (0x1E023, 0x1E024, 230), (0x1E026, 0x1E02A, 230), (0x1E08F, 0x1E08F, 230),
+    (0x1E130, 0x1E136, 230), (0x1E2AE, 0x1E2AE, 230), (0x1E2EC, 0x1E2EF, 230), (0x1E4EC, 0x1E4ED, 232),
+    (0x1E4EE, 0x1E4EE, 220), (0x1E4EF, 0x1E4EF, 230), (0x1E5EE, 0x1E5EE, 230), (0x1E5EF, 0x1E5EF, 220),
+    (0x1E6E3, 0x1E6E3, 230), (0x1E6E6, 0x1E6E6, 230), (0x1E6EE, 0x1E6EF, 230), (0x1E6F5, 0x1E6F5, 230),
+    (0x1E8D0, 0x1E8D6, 220), (0x1E944, 0x1E949, 230), (0x1E94A, 0x1E94A, 7),
+];
+
+/// Full case foldings that differ from the full lowercase mapping, padded with zeros.
+pub static CASE_FOLDING_EXCEPTIONS: [(u32, [u32; 3]); 297] = [
+    (0x00B5, [0x03BC, 0x0000, 0x0000]),
+    (0x00DF, [0x0073, 0x0073, 0x0000]),
+    (0x0149, [0x02BC, 0x006E, 0x0000]),
+    (0x017F, [0x0073, 0x0000, 0x0000]),
+    (0x01F0, [0x006A, 0x030C, 0x0000]),
+    (0x0345, [0x03B9, 0x0000, 0x0000]),
+    (0x0390, [0x03B9, 0x0308, 0x0301]),
+    (0x03B0, [0x03C5, 0x0308, 0x0301]),
+    (0x03C2, [0x03C3, 0x0000, 0x0000]),
+    (0x03D0, [0x03B2, 0x0000, 0x0000]),
+    (0x03D1, [0x03B8, 0x0000, 0x0000]),
+    (0x03D5, [0x03C6, 0x0000, 0x0000]),
+    (0x03D6, [0x03C0, 0x0000, 0x0000]),
+    (0x03F0, [0x03BA, 0x0000, 0x0000]),
+    (0x03F1, [0x03C1, 0x0000, 0x0000]),
+    (0x03F5, [0x03B5, 0x0000, 0x0000]),
+    (0x0587, [0x0565, 0x0582, 0x0000]),
+    (0x13A0, [0x13A0, 0x0000, 0x0000]),
+    (0x13A1, [0x13A1, 0x0000, 0x0000]),
+    (0x13A2, [0x13A2, 0x0000, 0x0000]),
+    (0x13A3, [0x13A3, 0x0000, 0x0000]),
+    (0x13A4, [0x13A4, 0x0000, 0x0000]),
+    (0x13A5, [0x13A5, 0x0000, 0x0000]),
+    (0x13A6, [0x13A6, 0x0000, 0x0000]),
+    (0x13A7, [0x13A7, 0x0000, 0x0000]),
+    (0x13A8, [0x13A8, 0x0000, 0x0000]),
+    (0x13A9, [0x13A9, 0x0000, 0x0000]),
+    (0x13AA, [0x13AA, 0x0000, 0x0000]),
+    (0x13AB, [0x13AB, 0x0000, 0x0000]),
+    (0x13AC, [0x13AC, 0x0000, 0x0000]),
+    (0x13AD, [0x13AD, 0x0000, 0x0000]),
+    (0x13AE, [0x13AE, 0x0000, 0x0000]),
+    (0x13AF, [0x13AF, 0x0000, 0x0000]),
+    (0x13B0, [0x13B0, 0x0000, 0x0000]),
+    (0x13B1, [0x13B1, 0x0000, 0x0000]),
+    (0x13B2, [0x13B2, 0x0000, 0x0000]),
+    (0x13B3, [0x13B3, 0x0000, 0x0000]),
+    (0x13B4, [0x13B4, 0x0000, 0x0000]),
+    (0x13B5, [0x13B5, 0x0000, 0x0000]),
+    (0x13B6, [0x13B6, 0x0000, 0x0000]),
+    (0x13B7, [0x13B7, 0x0000, 0x0000]),
+    (0x13B8, [0x13B8, 0x0000, 0x0000]),
+    (0x13B9, [0x13B9, 0x0000, 0x0000]),
+    (0x13BA, [0x13BA, 0x0000, 0x0000]),
+    (0x13BB, [0x13BB, 0x0000, 0x0000]),
+    (0x13BC, [0x13BC, 0x0000, 0x0000]),
+    (0x13BD, [0x13BD, 0x0000, 0x0000]),
+    (0x13BE, [0x13BE, 0x0000, 0x0000]),
+    (0x13BF, [0x13BF, 0x0000, 0x0000]),
+    (0x13C0, [0x13C0, 0x0000, 0x0000]),
+    (0x13C1, [0x13C1, 0x0000, 0x0000]),
+    (0x13C2, [0x13C2, 0x0000, 0x0000]),
+    (0x13C3, [0x13C3, 0x0000, 0x0000]),
+    (0x13C4, [0x13C4, 0x0000, 0x0000]),
+    (0x13C5, [0x13C5, 0x0000, 0x0000]),
+    (0x13C6, [0x13C6, 0x0000, 0x0000]),
+    (0x13C7, [0x13C7, 0x0000, 0x0000]),
+    (0x13C8, [0x13C8, 0x0000, 0x0000]),
+    (0x13C9, [0x13C9, 0x0000, 0x0000]),
+    (0x13CA, [0x13CA, 0x0000, 0x0000]),
+    (0x13CB, [0x13CB, 0x0000, 0x0000]),
+    (0x13CC, [0x13CC, 0x0000, 0x0000]),
+    (0x13CD, [0x13CD, 0x0000, 0x0000]),
+    (0x13CE, [0x13CE, 0x0000, 0x0000]),
+    (0x13CF, [0x13CF, 0x0000, 0x0000]),
+    (0x13D0, [0x13D0, 0x0000, 0x0000]),
+    (0x13D1, [0x13D1, 0x0000, 0x0000]),
+    (0x13D2, [0x13D2, 0x0000, 0x0000]),
+    (0x13D3, [0x13D3, 0x0000, 0x0000]),
+    (0x13D4, [0x13D4, 0x0000, 0x0000]),
+    (0x13D5, [0x13D5, 0x0000, 0x0000]),
+    (0x13D6, [0x13D6, 0x0000, 0x0000]),
+    (0x13D7, [0x13D7, 0x0000, 0x0000]),
+    (0x13D8, [0x13D8, 0x0000, 0x0000]),
+    (0x13D9, [0x13D9, 0x0000, 0x0000]),
+    (0x13DA, [0x13DA, 0x0000, 0x0000]),
+    (0x13DB, [0x13DB, 0x0000, 0x0000]),
+    (0x13DC, [0x13DC, 0x0000, 0x0000]),
+    (0x13DD, [0x13DD, 0x0000, 0x0000]),
+    (0x13DE, [0x13DE, 0x0000, 0x0000]),
+    (0x13DF, [0x13DF, 0x0000, 0x0000]),
+    (0x13E0, [0x13E0, 0x0000, 0x0000]),
+    (0x13E1, [0x13E1, 0x0000, 0x0000]),
+    (0x13E2, [0x13E2, 0x0000, 0x0000]),
+    (0x13E3, [0x13E3, 0x0000, 0x0000]),
+    (0x13E4, [0x13E4, 0x0000, 0x0000]),
+    (0x13E5, [0x13E5, 0x0000, 0x0000]),
+    (0x13E6, [0x13E6, 0x0000, 0x0000]),
+    (0x13E7, [0x13E7, 0x0000, 0x0000]),
+    (0x13E8, [0x13E8, 0x0000, 0x0000]),
+    (0x13E9, [0x13E9, 0x0000, 0x0000]),
+    (0x13EA, [0x13EA, 0x0000, 0x0000]),
+    (0x13EB, [0x13EB, 0x0000, 0x0000]),
+    (0x13EC, [0x13EC, 0x0000, 0x0000]),
+    (0x13ED, [0x13ED, 0x0000, 0x0000]),
+    (0x13EE, [0x13EE, 0x0000, 0x0000]),
+    (0x13EF, [0x13EF, 0x0000, 0x0000]),
+    (0x13F0, [0x13F0, 0x0000, 0x0000]),
+    (0x13F1, [0x13F1, 0x0000, 0x0000]),
+    (0x13F2, [0x13F2, 0x0000, 0x0000]),
+    (0x13F3, [0x13F3, 0x0000, 0x0000]),
+    (0x13F4, [0x13F4, 0x0000, 0x0000]),
+    (0x13F5, [0x13F5, 0x0000, 0x0000]),
+    (0x13F8, [0x13F0, 0x0000, 0x0000]),
+    (0x13F9, [0x13F1, 0x0000, 0x0000]),
+    (0x13FA, [0x13F2, 0x0000, 0x0000]),
+    (0x13FB, [0x13F3, 0x0000, 0x0000]),
+    (0x13FC, [0x13F4, 0x0000, 0x0000]),
+    (0x13FD, [0x13F5, 0x0000, 0x0000]),
+    (0x1C80, [0x0432, 0x0000, 0x0000]),
+    (0x1C81, [0x0434, 0x0000, 0x0000]),
+    (0x1C82, [0x043E, 0x0000, 0x0000]),
+    (0x1C83, [0x0441, 0x0000, 0x0000]),
+    (0x1C84, [0x0442, 0x0000, 0x0000]),
+    (0x1C85, [0x0442, 0x0000, 0x0000]),
+    (0x1C86, [0x044A, 0x0000, 0x0000]),
+    (0x1C87, [0x0463, 0x0000, 0x0000]),
+    (0x1C88, [0xA64B, 0x0000, 0x0000]),
+    (0x1E96, [0x0068, 0x0331, 0x0000]),
+    (0x1E97, [0x0074, 0x0308, 0x0000]),
+    (0x1E98, [0x0077, 0x030A, 0x0000]),
+    (0x1E99, [0x0079, 0x030A, 0x0000]),
+    (0x1E9A, [0x0061, 0x02BE, 0x0000]),
+    (0x1E9B, [0x1E61, 0x0000, 0x0000]),
+    (0x1E9E, [0x0073, 0x0073, 0x0000]),
+    (0x1F50, [0x03C5, 0x0313, 0x0000]),
+    (0x1F52, [0x03C5, 0x0313, 0x0300]),
+    (0x1F54, [0x03C5, 0x0313, 0x0301]),
+    (0x1F56, [0x03C5, 0x0313, 0x0342]),
+    (0x1F80, [0x1F00, 0x03B9, 0x0000]),
+    (0x1F81, [0x1F01, 0x03B9, 0x0000]),
+    (0x1F82, [0x1F02, 0x03B9, 0x0000]),
+    (0x1F83, [0x1F03, 0x03B9, 0x0000]),
+    (0x1F84, [0x1F04, 0x03B9, 0x0000]),
+    (0x1F85, [0x1F05, 0x03B9, 0x0000]),
+    (0x1F86, [0x1F06, 0x03B9, 0x0000]),
+    (0x1F87, [0x1F07, 0x03B9, 0x0000]),
+    (0x1F88, [0x1F00, 0x03B9, 0x0000]),
+    (0x1F89, [0x1F01, 0x03B9, 0x0000]),
+    (0x1F8A, [0x1F02, 0x03B9, 0x0000]),
+    (0x1F8B, [0x1F03, 0x03B9, 0x0000]),
+    (0x1F8C, [0x1F04, 0x03B9, 0x0000]),
+    (0x1F8D, [0x1F05, 0x03B9, 0x0000]),
+    (0x1F8E, [0x1F06, 0x03B9, 0x0000]),
+    (0x1F8F, [0x1F07, 0x03B9, 0x0000]),
+    (0x1F90, [0x1F20, 0x03B9, 0x0000]),
+    (0x1F91, [0x1F21, 0x03B9, 0x0000]),
+    (0x1F92, [0x1F22, 0x03B9, 0x0000]),
+    (0x1F93, [0x1F23, 0x03B9, 0x0000]),
+    (0x1F94, [0x1F24, 0x03B9, 0x0000]),
+    (0x1F95, [0x1F25, 0x03B9, 0x0000]),
+    (0x1F96, [0x1F26, 0x03B9, 0x0000]),
+    (0x1F97, [0x1F27, 0x03B9, 0x0000]),
+    (0x1F98, [0x1F20, 0x03B9, 0x0000]),
+    (0x1F99, [0x1F21, 0x03B9, 0x0000]),
+    (0x1F9A, [0x1F22, 0x03B9, 0x0000]),
+    (0x1F9B, [0x1F23, 0x03B9, 0x0000]),
+    (0x1F9C, [0x1F24, 0x03B9, 0x0000]),
+    (0x1F9D, [0x1F25, 0x03B9, 0x0000]),
+    (0x1F9E, [0x1F26, 0x03B9, 0x0000]),
+    (0x1F9F, [0x1F27, 0x03B9, 0x0000]),
+    (0x1FA0, [0x1F60, 0x03B9, 0x0000]),
+    (0x1FA1, [0x1F61, 0x03B9, 0x0000]),
+    (0x1FA2, [0x1F62, 0x03B9, 0x0000]),
+    (0x1FA3, [0x1F63, 0x03B9, 0x0000]),
+    (0x1FA4, [0x1F64, 0x03B9, 0x0000]),
+    (0x1FA5, [0x1F65, 0x03B9, 0x0000]),
+    (0x1FA6, [0x1F66, 0x03B9, 0x0000]),
+    (0x1FA7, [0x1F67, 0x03B9, 0x0000]),
+    (0x1FA8, [0x1F60, 0x03B9, 0x0000]),
+    (0x1FA9, [0x1F61, 0x03B9, 0x0000]),
+    (0x1FAA, [0x1F62, 0x03B9, 0x0000]),
+    (0x1FAB, [0x1F63, 0x03B9, 0x0000]),
+    (0x1FAC, [0x1F64, 0x03B9, 0x0000]),
+    (0x1FAD, [0x1F65, 0x03B9, 0x0000]),
+    (0x1FAE, [0x1F66, 0x03B9, 0x0000]),
+    (0x1FAF, [0x1F67, 0x03B9, 0x0000]),
+    (0x1FB2, [0x1F70, 0x03B9, 0x0000]),
+    (0x1FB3, [0x03B1, 0x03B9, 0x0000]),
+    (0x1FB4, [0x03AC, 0x03B9, 0x0000]),
+    (0x1FB6, [0x03B1, 0x0342, 0x0000]),
+    (0x1FB7, [0x03B1, 0x0342, 0x03B9]),
+    (0x1FBC, [0x03B1, 0x03B9, 0x0000]),
+    (0x1FBE, [0x03B9, 0x0000, 0x0000]),
+    (0x1FC2, [0x1F74, 0x03B9, 0x0000]),
+    (0x1FC3, [0x03B7, 0x03B9, 0x0000]),
+    (0x1FC4, [0x03AE, 0x03B9, 0x0000]),
+    (0x1FC6, [0x03B7, 0x0342, 0x0000]),
+    (0x1FC7, [0x03B7, 0x0342, 0x03B9]),
+    (0x1FCC, [0x03B7, 0x03B9, 0x0000]),
+    (0x1FD2, [0x03B9, 0x0308, 0x0300]),
+    (0x1FD3, [0x03B9, 0x0308, 0x0301]),
+    (0x1FD6, [0x03B9, 0x0342, 0x0000]),
+    (0x1FD7, [0x03B9, 0x0308, 0x0342]),
+    (0x1FE2, [0x03C5, 0x0308, 0x0300]),
+    (0x1FE3, [0x03C5, 0x0308, 0x0301]),
+    (0x1FE4, [0x03C1, 0x0313, 0x0000]),
+    (0x1FE6, [0x03C5, 0x0342, 0x0000]),
+    (0x1FE7, [0x03C5, 0x0308, 0x0342]),
+    (0x1FF2, [0x1F7C, 0x03B9, 0x0000]),
+    (0x1FF3, [0x03C9, 0x03B9, 0x0000]),
+    (0x1FF4, [0x03CE, 0x03B9, 0x0000]),
+    (0x1FF6, [0x03C9, 0x0342, 0x0000]),
+    (0x1FF7, [0x03C9, 0x0342, 0x03B9]),
+    (0x1FFC, [0x03C9, 0x03B9, 0x0000]),
+    (0xAB70, [0x13A0, 0x0000, 0x0000]),
+    (0xAB71, [0x13A1, 0x0000, 0x0000]),
+    (0xAB72, [0x13A2, 0x0000, 0x0000]),
+    (0xAB73, [0x13A3, 0x0000, 0x0000]),
+    (0xAB74, [0x13A4, 0x0000, 0x0000]),
+    (0xAB75, [0x13A5, 0x0000, 0x0000]),
+    (0xAB76, [0x13A6, 0x0000, 0x0000]),
+    (0xAB77, [0x13A7, 0x0000, 0x0000]),
+    (0xAB78, [0x13A8, 0x0000, 0x0000]),
+    (0xAB79, [0x13A9, 0x0000, 0x0000]),
+    (0xAB7A, [0x13AA, 0x0000, 0x0000]),
+    (0xAB7B, [0x13AB, 0x0000, 0x0000]),
+    (0xAB7C, [0x13AC, 0x0000, 0x0000]),
+    (0xAB7D, [0x13AD, 0x0000, 0x0000]),
+    (0xAB7E, [0x13AE, 0x0000, 0x0000]),
+    (0xAB7F, [0x13AF, 0x0000, 0x0000]),
+    (0xAB80, [0x13B0, 0x0000, 0x0000]),
+    (0xAB81, [0x13B1, 0x0000, 0x0000]),
+    (0xAB82, [0x13B2, 0x0000, 0x0000]),
+    (0xAB83, [0x13B3, 0x0000, 0x0000]),
+    (0xAB84, [0x13B4, 0x0000, 0x0000]),
+    (0xAB85, [0x13B5, 0x0000, 0x0000]),
+    (0xAB86, [0x13B6, 0x0000, 0x0000]),
+    (0xAB87, [0x13B7, 0x0000, 0x0000]),
+    (0xAB88, [0x13B8, 0x0000, 0x0000]),
+    (0xAB89, [0x13B9, 0x0000, 0x0000]),
+    (0xAB8A, [0x13BA, 0x0000, 0x0000]),
+    (0xAB8B, [0x13BB, 0x0000, 0x0000]),
+    (0xAB8C, [0x13BC, 0x0000, 0x0000]),
+    (0xAB8D, [0x13BD, 0x0000, 0x0000]),
+    (0xAB8E, [0x13BE, 0x0000, 0x0000]),
+    (0xAB8F, [0x13BF, 0x0000, 0x0000]),
+    (0xAB90, [0x13C0, 0x0000, 0x0000]),
+    (0xAB91, [0x13C1, 0x0000, 0x0000]),
+    (0xAB92, [0x13C2, 0x0000, 0x0000]),
+    (0xAB93, [0x13C3, 0x0000, 0x0000]),
+    (0xAB94, [0x13C4, 0x0000, 0x0000]),
+    (0xAB95, [0x13C5, 0x0000, 0x0000]),
+    (0xAB96, [0x13C6, 0x0000, 0x0000]),
+    (0xAB97, [0x13C7, 0x0000, 0x0000]),
+    (0xAB98, [0x13C8, 0x0000, 0x0000]),
+    (0xAB99, [0x13C9, 0x0000, 0x0000]),
+    (0xAB9A, [0x13CA, 0x0000, 0x0000]),
+    (0xAB9B, [0x13CB, 0x0000, 0x0000]),
+    (0xAB9C, [0x13CC, 0x0000, 0x0000]),
+    (0xAB9D, [0x13CD, 0x0000, 0x0000]),
+    (0xAB9E, [0x13CE, 0x0000, 0x0000]),
+    (0xAB9F, [0x13CF, 0x0000, 0x0000]),
+    (0xABA0, [0x13D0, 0x0000, 0x0000]),
+    (0xABA1, [0x13D1, 0x0000, 0x0000]),
+    (0xABA2, [0x13D2, 0x0000, 0x0000]),
+    (0xABA3, [0x13D3, 0x0000, 0x0000]),
+    (0xABA4, [0x13D4, 0x0000, 0x0000]),
+    (0xABA5, [0x13D5, 0x0000, 0x0000]),
+    (0xABA6, [0x13D6, 0x0000, 0x0000]),
+    (0xABA7, [0x13D7, 0x0000, 0x0000]),
+    (0xABA8, [0x13D8, 0x0000, 0x0000]),
+    (0xABA9, [0x13D9, 0x0000, 0x0000]),
+    (0xABAA, [0x13DA, 0x0000, 0x0000]),
+    (0xABAB, [0x13DB, 0x0000, 0x0000]),
+    (0xABAC, [0x13DC, 0x0000, 0x0000]),
+    (0xABAD, [0x13DD, 0x0000, 0x0000]),
+    (0xABAE, [0x13DE, 0x0000, 0x0000]),
+    (0xABAF, [0x13DF, 0x0000, 0x0000]),
+    (0xABB0, [0x13E0, 0x0000, 0x0000]),
+    (0xABB1, [0x13E1, 0x0000, 0x0000]),
+    (0xABB2, [0x13E2, 0x0000, 0x0000]),
+    (0xABB3, [0x13E3, 0x0000, 0x0000]),
+    (0xABB4, [0x13E4, 0x0000, 0x0000]),
+    (0xABB5, [0x13E5, 0x0000, 0x0000]),
+    (0xABB6, [0x13E6, 0x0000, 0x0000]),
+    (0xABB7, [0x13E7, 0x0000, 0x0000]),
+    (0xABB8, [0x13E8, 0x0000, 0x0000]),
+    (0xABB9, [0x13E9, 0x0000, 0x0000]),
+    (0xABBA, [0x13EA, 0x0000, 0x0000]),
+    (0xABBB, [0x13EB, 0x0000, 0x0000]),
+    (0xABBC, [0x13EC, 0x0000, 0x0000]),
+    (0xABBD, [0x13ED, 0x0000, 0x0000]),
+    (0xABBE, [0x13EE, 0x0000, 0x0000]),
+    (0xABBF, [0x13EF, 0x0000, 0x0000]),
+    (0xFB00, [0x0066, 0x0066, 0x0000]),
+    (0xFB01, [0x0066, 0x0069, 0x0000]),
+    (0xFB02, [0x0066, 0x006C, 0x0000]),
+    (0xFB03, [0x0066, 0x0066, 0x0069]),
+    (0xFB04, [0x0066, 0x0066, 0x006C]),
+    (0xFB05, [0x0073, 0x0074, 0x0000]),
+    (0xFB06, [0x0073, 0x0074, 0x0000]),
+    (0xFB13, [0x0574, 0x0576, 0x0000]),
+    (0xFB14, [0x0574, 0x0565, 0x0000]),
+    (0xFB15, [0x0574, 0x056B, 0x0000]),
+    (0xFB16, [0x057E, 0x0576, 0x0000]),
+    (0xFB17, [0x0574, 0x056D, 0x0000]),
+];
+
+/// Simple uppercase mappings of characters whose full uppercase mapping expands.
+pub static SIMPLE_UPPERCASE: [(u32, u32); 27] = [
+    (0x1F80, 0x1F88), (0x1F81, 0x1F89), (0x1F82, 0x1F8A), (0x1F83, 0x1F8B),
+    (0x1F84, 0x1F8C), (0x1F85, 0x1F8D), (0x1F86, 0x1F8E), (0x1F87, 0x1F8F),
+    (0x1F90, 0x1F98), (0x1F91, 0x1F99), (0x1F92, 0x1F9A), (0x1F93, 0x1F9B),
+    (0x1F94, 0x1F9C), (0x1F95, 0x1F9D), (0x1F96, 0x1F9E), (0x1F97, 0x1F9F),
+    (0x1FA0, 0x1FA8), (0x1FA1, 0x1FA9), (0x1FA2, 0x1FAA), (0x1FA3, 0x1FAB),
+    (0x1FA4, 0x1FAC), (0x1FA5, 0x1FAD), (0x1FA6, 0x1FAE), (0x1FA7, 0x1FAF),
+    (0x1FB3, 0x1FBC), (0x1FC3, 0x1FCC), (0x1FF3, 0x1FFC),
+];
+
+/// Simple lowercase mappings of characters whose full lowercase mapping expands.
+pub static SIMPLE_LOWERCASE: [(u32, u32); 1] = [
+    (0x0130, 0x0069),
+];
+
+/// Simple case foldings of characters whose full case folding expands.
+pub static SIMPLE_CASE_FOLDING: [(u32, u32); 31] = [
+    (0x1E9E, 0x00DF), (0x1F88, 0x1F80), (0x1F89, 0x1F81), (0x1F8A, 0x1F82),
+    (0x1F8B, 0x1F83), (0x1F8C, 0x1F84), (0x1F8D, 0x1F85), (0x1F8E, 0x1F86),
+    (0x1F8F, 0x1F87), (0x1F98, 0x1F90), (0x1F99, 0x1F91), (0x1F9A, 0x1F92),
+    (0x1F9B, 0x1F93), (0x1F9C, 0x1F94), (0x1F9D, 0x1F95), (0x1F9E, 0x1F96),
+    (0x1F9F, 0x1F97), (0x1FA8, 0x1FA0), (0x1FA9, 0x1FA1), (0x1FAA, 0x1FA2),
+    (0x1FAB, 0x1FA3), (0x1FAC, 0x1FA4), (0x1FAD, 0x1FA5), (0x1FAE, 0x1FA6),
+    (0x1FAF, 0x1FA7), (0x1FBC, 0x1FB3), (0x1FCC, 0x1FC3), (0x1FD3, 0x0390),
+    (0x1FE3, 0x03B0), (0x1FFC, 0x1FF3), (0xFB05, 0xFB06),
+];
+
+/// Grapheme cluster break classes of Unicode Standard Annex #29, with the extended
+/// pictographic characters as a separate class. The class `Other` is split by the Indic
+/// conjunct break consonants and the class `Extend` by the Indic conjunct break linkers and
+/// the characters that do not extend Indic conjuncts.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum GraphemeBreak {
+    Other,
+    Cr,
+    Lf,
+    Control,
+    Extend,
+    Zwj,
+    RegionalIndicator,
+    Prepend,
+    SpacingMark,
+    L,
+    V,
+    T,
+    Lv,
+    Lvt,
+    ExtendedPictographic,
+    ConjunctConsonant,
+    ConjunctLinker,
+    NonConjunctExtend,
+}
+
+/// Grapheme cluster break classes by first code point, sorted. Each class extends to the next
+/// entry.
+pub static GRAPHEME_BREAKS: [(u32, GraphemeBreak); 2168] = [
+    (0x0000, GraphemeBreak::Control), (0x000A, GraphemeBreak::Lf), (0x000B, GraphemeBreak::Control), (0x000D, GraphemeBreak::Cr),
+    (0x000E, GraphemeBreak::Control), (0x0020, GraphemeBreak::Other), (0x007F, GraphemeBreak::Control), (0x00A0, GraphemeBreak::Other),
+    (0x00A9, GraphemeBreak::ExtendedPictographic), (0x00AA, GraphemeBreak::Other), (0x00AD, GraphemeBreak::Control), (0x00AE, GraphemeBreak::ExtendedPictographic),
+    (0x00AF, GraphemeBreak::Other), (0x0300, GraphemeBreak::Extend), (0x0370, GraphemeBreak::Other), (0x0483, GraphemeBreak::Extend),
+    (0x048A, GraphemeBreak::Other), (0x0591, GraphemeBreak::Extend), (0x05BE, GraphemeBreak::Other), (0x05BF, GraphemeBreak::Extend),
+    (0x05C0, GraphemeBreak::Other), (0x05C1, GraphemeBreak::Extend), (0x05C3, GraphemeBreak::Other), (0x05C4, GraphemeBreak::Extend),
+    (0x05C6, GraphemeBreak::Other), (0x05C7, GraphemeBreak::Extend), (0x05C8, GraphemeBreak::Other), (0x0600, GraphemeBreak::Prepend),
+    (0x0606, GraphemeBreak::Other), (0x0610, GraphemeBreak::Extend), (0x061B, GraphemeBreak::Other), (0x061C, GraphemeBreak::Control),
+    (0x061D, GraphemeBreak::Other), (0x064B, GraphemeBreak::Extend), (0x0660, GraphemeBreak::Other), (0x0670, GraphemeBreak::Extend),
+    (0x0671, GraphemeBreak::Other), (0x06D6, GraphemeBreak::Extend), (0x06DD, GraphemeBreak::Prepend), (0x06DE, GraphemeBreak::Other),
+    (0x06DF, GraphemeBreak::Extend), (0x06E5, GraphemeBreak::Other), (0x06E7, GraphemeBreak::Extend), (0x06E9, GraphemeBreak::Other),
+    (0x06EA, GraphemeBreak::Extend), (0x06EE, GraphemeBreak::Other), (0x070F, GraphemeBreak::Prepend), (0x0710, GraphemeBreak::Other),
+    (0x0711, GraphemeBreak::Extend), (0x0712, GraphemeBreak::Other), (0x0730, GraphemeBreak::Extend), (0x074B, GraphemeBreak::Other),
+    (0x07A6, GraphemeBreak::Extend), (0x07B1, GraphemeBreak::Other), (0x07EB, GraphemeBreak::Extend), (0x07F4, GraphemeBreak::Other),
+    (0x07FD, GraphemeBreak::Extend), (0x07FE, GraphemeBreak::Other), (0x0816, GraphemeBreak::Extend), (0x081A, GraphemeBreak::Other),
+    (0x081B, GraphemeBreak::Extend), (0x0824, GraphemeBreak::Other), (0x0825, GraphemeBreak::Extend), (0x0828, GraphemeBreak::Other),
+    (0x0829, GraphemeBreak::Extend), (0x082E, GraphemeBreak::Other), (0x0859, GraphemeBreak::Extend), (0x085C, GraphemeBreak::Other),
+    (0x0890, GraphemeBreak::Prepend), (0x0892, GraphemeBreak::Other), (0x0897, GraphemeBreak::Extend), (0x08A0, GraphemeBreak::Other),
+    (0x08CA, GraphemeBreak::Extend), (0x08E2, GraphemeBreak::Prepend), (0x08E3, GraphemeBreak::Extend), (0x0903, GraphemeBreak::SpacingMark),
+    (0x0904, GraphemeBreak::Other), (0x0915, GraphemeBreak::ConjunctConsonant), (0x093A, GraphemeBreak::Extend), (0x093B, GraphemeBreak::SpacingMark),
+    (0x093C, GraphemeBreak::Extend), (0x093D, GraphemeBreak::Other), (0x093E, GraphemeBreak::SpacingMark), (0x0941, GraphemeBreak::Extend),
+    (0x0949, GraphemeBreak::SpacingMark), (0x094D, GraphemeBreak::ConjunctLinker), (0x094E, GraphemeBreak::SpacingMark), (0x0950, GraphemeBreak::Other),
+    (0x0951, GraphemeBreak::Extend), (0x0958, GraphemeBreak::ConjunctConsonant), (0x0960, GraphemeBreak::Other), (0x0962, GraphemeBreak::Extend),
+    (0x0964, GraphemeBreak::Other), (0x0978, GraphemeBreak::ConjunctConsonant), (0x0980, GraphemeBreak::Other), (0x0981, GraphemeBreak::Extend),
+    (0x0982, GraphemeBreak::SpacingMark), (0x0984, GraphemeBreak::Other), (0x0995, GraphemeBreak::ConjunctConsonant), (0x09A9, GraphemeBreak::Other),
+    (0x09AA, GraphemeBreak::ConjunctConsonant), (0x09B1, GraphemeBreak::Other), (0x09B2, GraphemeBreak::ConjunctConsonant), (0x09B3, GraphemeBreak::Other),
+    (0x09B6, GraphemeBreak::ConjunctConsonant), (0x09BA, GraphemeBreak::Other), (0x09BC, GraphemeBreak::Extend), (0x09BD, GraphemeBreak::Other),
+    (0x09BE, GraphemeBreak::Extend), (0x09BF, GraphemeBreak::SpacingMark), (0x09C1, GraphemeBreak::Extend), (0x09C5, GraphemeBreak::Other),
+    (0x09C7, GraphemeBreak::SpacingMark), (0x09C9, GraphemeBreak::Other), (0x09CB, GraphemeBreak::SpacingMark), (0x09CD, GraphemeBreak::ConjunctLinker),
+    (0x09CE, GraphemeBreak::Other), (0x09D7, GraphemeBreak::Extend), (0x09D8, GraphemeBreak::Other), (0x09DC, GraphemeBreak::ConjunctConsonant),
+    (0x09DE, GraphemeBreak::Other), (0x09DF, GraphemeBreak::ConjunctConsonant), (0x09E0, GraphemeBreak::Other), (0x09E2, GraphemeBreak::Extend),
+    (0x09E4, GraphemeBreak::Other), (0x09F0, GraphemeBreak::ConjunctConsonant), (0x09F2, GraphemeBreak::Other), (0x09FE, GraphemeBreak::Extend),
+    (0x09FF, GraphemeBreak::Other), (0x0A01, GraphemeBreak::Extend), (0x0A03, GraphemeBreak::SpacingMark), (0x0A04, GraphemeBreak::Other),
+    (0x0A3C, GraphemeBreak::Extend), (0x0A3D, GraphemeBreak::Other), (0x0A3E, GraphemeBreak::SpacingMark), (0x0A41, GraphemeBreak::Extend),
+    (0x0A43, GraphemeBreak::Other), (0x0A47, GraphemeBreak::Extend), (0x0A49, GraphemeBreak::Other), (0x0A4B, GraphemeBreak::Extend),
+    (0x0A4E, GraphemeBreak::Other), (0x0A51, GraphemeBreak::Extend), (0x0A52, GraphemeBreak::Other), (0x0A70, GraphemeBreak::Extend),
+    (0x0A72, GraphemeBreak::Other), (0x0A75, GraphemeBreak::Extend), (0x0A76, GraphemeBreak::Other), (0x0A81, GraphemeBreak::Extend),
+    (0x0A83, GraphemeBreak::SpacingMark), (0x0A84, GraphemeBreak::Other), (0x0A95, GraphemeBreak::ConjunctConsonant), (0x0AA9, GraphemeBreak::Other),
+    (0x0AAA, GraphemeBreak::ConjunctConsonant), (0x0AB1, GraphemeBreak::Other), (0x0AB2, GraphemeBreak::ConjunctConsonant), (0x0AB4, GraphemeBreak::Other),
+    (0x0AB5, GraphemeBreak::ConjunctConsonant), (0x0ABA, GraphemeBreak::Other), (0x0ABC, GraphemeBreak::Extend), (0x0ABD, GraphemeBreak::Other),
+    (0x0ABE, GraphemeBreak::SpacingMark), (0x0AC1, GraphemeBreak::Extend), (0x0AC6, GraphemeBreak::Other), (0x0AC7, GraphemeBreak::Extend),
+    (0x0AC9, GraphemeBreak::SpacingMark), (0x0ACA, GraphemeBreak::Other), (0x0ACB, GraphemeBreak::SpacingMark), (0x0ACD, GraphemeBreak::ConjunctLinker),
+    (0x0ACE, GraphemeBreak::Other), (0x0AE2, GraphemeBreak::Extend), (0x0AE4, GraphemeBreak::Other), (0x0AF9, GraphemeBreak::ConjunctConsonant),
+    (0x0AFA, GraphemeBreak::Extend), (0x0B00, GraphemeBreak::Other), (0x0B01, GraphemeBreak::Extend), (0x0B02, GraphemeBreak::SpacingMark),
+    (0x0B04, GraphemeBreak::Other), (0x0B15, GraphemeBreak::ConjunctConsonant), (0x0B29, GraphemeBreak::Other), (0x0B2A, GraphemeBreak::ConjunctConsonant),
+    (0x0B31, GraphemeBreak::Other), (0x0B32, GraphemeBreak::ConjunctConsonant), (0x0B34, GraphemeBreak::Other), (0x0B35, GraphemeBreak::ConjunctConsonant),
+    (0x0B3A, GraphemeBreak::Other), (0x0B3C, GraphemeBreak::Extend), (0x0B3D, GraphemeBreak::Other), (0x0B3E, GraphemeBreak::Extend),
+    (0x0B40, GraphemeBreak::SpacingMark), (0x0B41, GraphemeBreak::Extend), (0x0B45, GraphemeBreak::Other), (0x0B47, GraphemeBreak::SpacingMark),
+    (0x0B49, GraphemeBreak::Other), (0x0B4B, GraphemeBreak::SpacingMark), (0x0B4D, GraphemeBreak::ConjunctLinker), (0x0B4E, GraphemeBreak::Other),
+    (0x0B55, GraphemeBreak::Extend), (0x0B58, GraphemeBreak::Other), (0x0B5C, GraphemeBreak::ConjunctConsonant), (0x0B5E, GraphemeBreak::Other),
+    (0x0B5F, GraphemeBreak::ConjunctConsonant), (0x0B60, GraphemeBreak::Other), (0x0B62, GraphemeBreak::Extend), (0x0B64, GraphemeBreak::Other),
+    (0x0B71, GraphemeBreak::ConjunctConsonant), (0x0B72, GraphemeBreak::Other), (0x0B82, GraphemeBreak::Extend), (0x0B83, GraphemeBreak::Other),
+    (0x0BBE, GraphemeBreak::Extend), (0x0BBF, GraphemeBreak::SpacingMark), (0x0BC0, GraphemeBreak::Extend), (0x0BC1, GraphemeBreak::SpacingMark),
+    (0x0BC3, GraphemeBreak::Other), (0x0BC6, GraphemeBreak::SpacingMark), (0x0BC9, GraphemeBreak::Other), (0x0BCA, GraphemeBreak::SpacingMark),
+    (0x0BCD, GraphemeBreak::Extend), (0x0BCE, GraphemeBreak::Other), (0x0BD7, GraphemeBreak::Extend), (0x0BD8, GraphemeBreak::Other),
+    (0x0C00, GraphemeBreak::Extend), (0x0C01, GraphemeBreak::SpacingMark), (0x0C04, GraphemeBreak::Extend), (0x0C05, GraphemeBreak::Other),
+    (0x0C15, GraphemeBreak::ConjunctConsonant), (0x0C29, GraphemeBreak::Other), (0x0C2A, GraphemeBreak::ConjunctConsonant), (0x0C3A, GraphemeBreak::Other),
+    (0x0C3C, GraphemeBreak::Extend), (0x0C3D, GraphemeBreak::Other), (0x0C3E, GraphemeBreak::Extend), (0x0C41, GraphemeBreak::SpacingMark),
+    (0x0C45, GraphemeBreak::Other), (0x0C46, GraphemeBreak::Extend), (0x0C49, GraphemeBreak::Other), (0x0C4A, GraphemeBreak::Extend),
+    (0x0C4D, GraphemeBreak::ConjunctLinker), (0x0C4E, GraphemeBreak::Other), (0x0C55, GraphemeBreak::Extend), (0x0C57, GraphemeBreak::Other),
+    (0x0C58, GraphemeBreak::ConjunctConsonant), (0x0C5B, GraphemeBreak::Other), (0x0C62, GraphemeBreak::Extend), (0x0C64, GraphemeBreak::Other),
+    (0x0C81, GraphemeBreak::Extend), (0x0C82, GraphemeBreak::SpacingMark), (0x0C84, GraphemeBreak::Other), (0x0CBC, GraphemeBreak::Extend),
+    (0x0CBD, GraphemeBreak::Other), (0x0CBE, GraphemeBreak::SpacingMark), (0x0CBF, GraphemeBreak::Extend), (0x0CC1, GraphemeBreak::SpacingMark),
+    (0x0CC2, GraphemeBreak::Extend), (0x0CC3, GraphemeBreak::SpacingMark), (0x0CC5, GraphemeBreak::Other), (0x0CC6, GraphemeBreak::Extend),
+    (0x0CC9, GraphemeBreak::Other), (0x0CCA, GraphemeBreak::Extend), (0x0CCE, GraphemeBreak::Other), (0x0CD5, GraphemeBreak::Extend),
+    (0x0CD7, GraphemeBreak::Other), (0x0CE2, GraphemeBreak::Extend), (0x0CE4, GraphemeBreak::Other), (0x0CF3, GraphemeBreak::SpacingMark),
+    (0x0CF4, GraphemeBreak::Other), (0x0D00, GraphemeBreak::Extend), (0x0D02, GraphemeBreak::SpacingMark), (0x0D04, GraphemeBreak::Other),
+    (0x0D15, GraphemeBreak::ConjunctConsonant), (0x0D3B, GraphemeBreak::Extend), (0x0D3D, GraphemeBreak::Other), (0x0D3E, GraphemeBreak::Extend),
+    (0x0D3F, GraphemeBreak::SpacingMark), (0x0D41, GraphemeBreak::Extend), (0x0D45, GraphemeBreak::Other), (0x0D46, GraphemeBreak::SpacingMark),
+    (0x0D49, GraphemeBreak::Other), (0x0D4A, GraphemeBreak::SpacingMark), (0x0D4D, GraphemeBreak::ConjunctLinker), (0x0D4E, GraphemeBreak::Prepend),
+    (0x0D4F, GraphemeBreak::Other), (0x0D57, GraphemeBreak::Extend), (0x0D58, GraphemeBreak::Other), (0x0D62, GraphemeBreak::Extend),
+    (0x0D64, GraphemeBreak::Other), (0x0D81, GraphemeBreak::Extend), (0x0D82, GraphemeBreak::SpacingMark), (0x0D84, GraphemeBreak::Other),
+    (0x0DCA, GraphemeBreak::Extend), (0x0DCB, GraphemeBreak::Other), (0x0DCF, GraphemeBreak::Extend), (0x0DD0, GraphemeBreak::SpacingMark),
+    (0x0DD2, GraphemeBreak::Extend), (0x0DD5, GraphemeBreak::Other), (0x0DD6, GraphemeBreak::Extend), (0x0DD7, GraphemeBreak::Other),
+    (0x0DD8, GraphemeBreak::SpacingMark), (0x0DDF, GraphemeBreak::Extend), (0x0DE0, GraphemeBreak::Other), (0x0DF2, GraphemeBreak::SpacingMark),
+    (0x0DF4, GraphemeBreak::Other), (0x0E31, GraphemeBreak::Extend), (0x0E32, GraphemeBreak::Other), (0x0E33, GraphemeBreak::SpacingMark),
+    (0x0E34, GraphemeBreak::Extend), (0x0E3B, GraphemeBreak::Other), (0x0E47, GraphemeBreak::Extend), (0x0E4F, GraphemeBreak::Other),
+    (0x0EB1, GraphemeBreak::Extend), (0x0EB2, GraphemeBreak::Other), (0x0EB3, GraphemeBreak::SpacingMark), (0x0EB4, GraphemeBreak::Extend),
+    (0x0EBD, GraphemeBreak::Other), (0x0EC8, GraphemeBreak::Extend), (0x0ECF, GraphemeBreak::Other), (0x0F18, GraphemeBreak::Extend),
+    (0x0F1A, GraphemeBreak::Other), (0x0F35, GraphemeBreak::Extend), (0x0F36, GraphemeBreak::Other), (0x0F37, GraphemeBreak::Extend),
+    (0x0F38, GraphemeBreak::Other), (0x0F39, GraphemeBreak::Extend), (0x0F3A, GraphemeBreak::Other), (0x0F3E, GraphemeBreak::SpacingMark),
+    (0x0F40, GraphemeBreak::Other), (0x0F71, GraphemeBreak::Extend), (0x0F7F, GraphemeBreak::SpacingMark), (0x0F80, GraphemeBreak::Extend),
+    (0x0F85, GraphemeBreak::Other), (0x0F86, GraphemeBreak::Extend), (0x0F88, GraphemeBreak::Other), (0x0F8D, GraphemeBreak::Extend),
+    (0x0F98, GraphemeBreak::Other), (0x0F99, GraphemeBreak::Extend), (0x0FBD, GraphemeBreak::Other), (0x0FC6, GraphemeBreak::Extend),
+    (0x0FC7, GraphemeBreak::Other), (0x1000, GraphemeBreak::ConjunctConsonant), (0x102B, GraphemeBreak::Other), (0x102D, GraphemeBreak::Extend),
+    (0x1031, GraphemeBreak::SpacingMark), (0x1032, GraphemeBreak::Extend), (0x1038, GraphemeBreak::Other), (0x1039, GraphemeBreak::ConjunctLinker),
+    (0x103A, GraphemeBreak::Extend), (0x103B, GraphemeBreak::SpacingMark), (0x103D, GraphemeBreak::Extend), (0x103F, GraphemeBreak::ConjunctConsonant),
+    (0x1040, GraphemeBreak::Other), (0x1050, GraphemeBreak::ConjunctConsonant), (0x1056, GraphemeBreak::SpacingMark), (0x1058, GraphemeBreak::Extend),
+    (0x105A, GraphemeBreak::ConjunctConsonant), (0x105E, GraphemeBreak::Extend), (0x1061, GraphemeBreak::ConjunctConsonant), (0x1062, GraphemeBreak::Other),
+    (0x1065, GraphemeBreak::ConjunctConsonant), (0x1067, GraphemeBreak::Other), (0x106E, GraphemeBreak::ConjunctConsonant), (0x1071, GraphemeBreak::Extend),
+    (0x1075, GraphemeBreak::ConjunctConsonant), (0x1082, GraphemeBreak::Extend), (0x1083, GraphemeBreak::Other), (0x1084, GraphemeBreak::SpacingMark),
+    (0x1085, GraphemeBreak::Extend), (0x1087, GraphemeBreak::Other), (0x108D, GraphemeBreak::Extend), (0x108E, GraphemeBreak::ConjunctConsonant),
+    (0x108F, GraphemeBreak::Other), (0x109D, GraphemeBreak::Extend), (0x109E, GraphemeBreak::Other), (0x1100, GraphemeBreak::L),
+    (0x1160, GraphemeBreak::V), (0x11A8, GraphemeBreak::T), (0x1200, GraphemeBreak::Other), (0x135D, GraphemeBreak::Extend),
+    (0x1360, GraphemeBreak::Other), (0x1712, GraphemeBreak::Extend), (0x1716, GraphemeBreak::Other), (0x1732, GraphemeBreak::Extend),
+    (0x1735, GraphemeBreak::Other), (0x1752, GraphemeBreak::Extend), (0x1754, GraphemeBreak::Other), (0x1772, GraphemeBreak::Extend),
+    (0x1774, GraphemeBreak::Other), (0x1780, GraphemeBreak::ConjunctConsonant), (0x17B4, GraphemeBreak::Extend), (0x17B6, GraphemeBreak::SpacingMark),
+    (0x17B7, GraphemeBreak::Extend), (0x17BE, GraphemeBreak::SpacingMark), (0x17C6, GraphemeBreak::Extend), (0x17C7, GraphemeBreak::SpacingMark),
+    (0x17C9, GraphemeBreak::Extend), (0x17D2, GraphemeBreak::ConjunctLinker), (0x17D3, GraphemeBreak::Extend), (0x17D4, GraphemeBreak::Other),
+    (0x17DD, GraphemeBreak::Extend), (0x17DE, GraphemeBreak::Other), (0x180B, GraphemeBreak::Extend), (0x180E, GraphemeBreak::Control),
+    (0x180F, GraphemeBreak::Extend), (0x1810, GraphemeBreak::Other), (0x1885, GraphemeBreak::Extend), (0x1887, GraphemeBreak::Other),
+    (0x18A9, GraphemeBreak::Extend), (0x18AA, GraphemeBreak::Other), (0x1920, GraphemeBreak::Extend), (0x1923, GraphemeBreak::SpacingMark),
+    (0x1927, GraphemeBreak::Extend), (0x1929, GraphemeBreak::SpacingMark), (0x192C, GraphemeBreak::Other), (0x1930, GraphemeBreak::SpacingMark),
+    (0x1932, GraphemeBreak::Extend), (0x1933, GraphemeBreak::SpacingMark), (0x1939, GraphemeBreak::Extend), (0x193C, GraphemeBreak::Other),
+    (0x1A17, GraphemeBreak::Extend), (0x1A19, GraphemeBreak::SpacingMark), (0x1A1B, GraphemeBreak::Extend), (0x1A1C, GraphemeBreak::Other),
+    (0x1A20, GraphemeBreak::ConjunctConsonant), (0x1A55, GraphemeBreak::SpacingMark), (0x1A56, GraphemeBreak::Extend), (0x1A57, GraphemeBreak::SpacingMark),
+    (0x1A58, GraphemeBreak::Extend), (0x1A5F, GraphemeBreak::Other), (0x1A60, GraphemeBreak::ConjunctLinker), (0x1A61, GraphemeBreak::Other),
+    (0x1A62, GraphemeBreak::Extend), (0x1A63, GraphemeBreak::Other), (0x1A65, GraphemeBreak::Extend), (0x1A6D, GraphemeBreak::SpacingMark),
+    (0x1A73, GraphemeBreak::Extend), (0x1A7D, GraphemeBreak::Other), (0x1A7F, GraphemeBreak::Extend), (0x1A80, GraphemeBreak::Other),
+    (0x1AB0, GraphemeBreak::Extend), (0x1ADE, GraphemeBreak::Other), (0x1AE0, GraphemeBreak::Extend), (0x1AEC, GraphemeBreak::Other),
+    (0x1B00, GraphemeBreak::Extend), (0x1B04, GraphemeBreak::SpacingMark), (0x1B05, GraphemeBreak::Other), (0x1B0B, GraphemeBreak::ConjunctConsonant),
+    (0x1B0D, GraphemeBreak::Other), (0x1B13, GraphemeBreak::ConjunctConsonant), (0x1B34, GraphemeBreak::Extend), (0x1B3E, GraphemeBreak::SpacingMark),
+    (0x1B42, GraphemeBreak::Extend), (0x1B44, GraphemeBreak::ConjunctLinker), (0x1B45, GraphemeBreak::ConjunctConsonant), (0x1B4D, GraphemeBreak::Other),
+    (0x1B6B, GraphemeBreak::Extend), (0x1B74, GraphemeBreak::Other), (0x1B80, GraphemeBreak::Extend), (0x1B82, GraphemeBreak::SpacingMark),
+    (0x1B83, GraphemeBreak::ConjunctConsonant), (0x1BA1, GraphemeBreak::SpacingMark), (0x1BA2, GraphemeBreak::Extend), (0x1BA6, GraphemeBreak::SpacingMark),
+    (0x1BA8, GraphemeBreak::Extend), (0x1BAB, GraphemeBreak::ConjunctLinker), (0x1BAC, GraphemeBreak::Extend), (0x1BAE, GraphemeBreak::ConjunctConsonant),
+    (0x1BB0, GraphemeBreak::Other), (0x1BBB, GraphemeBreak::ConjunctConsonant), (0x1BBE, GraphemeBreak::Other), (0x1BE6, GraphemeBreak::Extend),
+    (0x1BE7, GraphemeBreak::SpacingMark), (0x1BE8, GraphemeBreak::Extend), (0x1BEA, GraphemeBreak::SpacingMark), (0x1BED, GraphemeBreak::Extend),
+    (0x1BEE, GraphemeBreak::SpacingMark), (0x1BEF, GraphemeBreak::Extend), (0x1BF4, GraphemeBreak::Other), (0x1C24, GraphemeBreak::SpacingMark),
+    (0x1C2C, GraphemeBreak::Extend), (0x1C34, GraphemeBreak::SpacingMark), (0x1C36, GraphemeBreak::Extend), (0x1C38, GraphemeBreak::Other),
+    (0x1CD0, GraphemeBreak::Extend), (0x1CD3, GraphemeBreak::Other), (0x1CD4, GraphemeBreak::Extend), (0x1CE1, GraphemeBreak::SpacingMark),
+    (0x1CE2, GraphemeBreak::Extend), (0x1CE9, GraphemeBreak::Other), (0x1CED, GraphemeBreak::Extend), (0x1CEE, GraphemeBreak::Other),
+    (0x1CF4, GraphemeBreak::Extend), (0x1CF5, GraphemeBreak::Other), (0x1CF7, GraphemeBreak::SpacingMark), (0x1CF8, GraphemeBreak::Extend),
+    (0x1CFA, GraphemeBreak::Other), (0x1DC0, GraphemeBreak::Extend), (0x1E00, GraphemeBreak::Other), (0x200B, GraphemeBreak::Control),
+    (0x200C, GraphemeBreak::NonConjunctExtend), (0x200D, GraphemeBreak::Zwj), (0x200E, GraphemeBreak::Control), (0x2010, GraphemeBreak::Other),
+    (0x2028, GraphemeBreak::Control), (0x202F, GraphemeBreak::Other), (0x203C, GraphemeBreak::ExtendedPictographic), (0x203D, GraphemeBreak::Other),
+    (0x2049, GraphemeBreak::ExtendedPictographic), (0x204A, GraphemeBreak::Other), (0x2060, GraphemeBreak::Control), (0x2070, GraphemeBreak::Other),
+    (0x20D0, GraphemeBreak::Extend), (0x20F1, GraphemeBreak::Other), (0x2122, GraphemeBreak::ExtendedPictographic), (0x2123, GraphemeBreak::Other),
+    (0x2139, GraphemeBreak::ExtendedPictographic), (0x213A, GraphemeBreak::Other), (0x2194, GraphemeBreak::ExtendedPictographic), (0x219A, GraphemeBreak::Other),
+    (0x21A9, GraphemeBreak::ExtendedPictographic), (0x21AB, GraphemeBreak::Other), (0x231A, GraphemeBreak::ExtendedPictographic), (0x231C, GraphemeBreak::Other),
+    (0x2328, GraphemeBreak::ExtendedPictographic), (0x2329, GraphemeBreak::Other), (0x23CF, GraphemeBreak::ExtendedPictographic), (0x23D0, GraphemeBreak::Other),
+    (0x23E9, GraphemeBreak::ExtendedPictographic), (0x23F4, GraphemeBreak::Other), (0x23F8, GraphemeBreak::ExtendedPictographic), (0x23FB, GraphemeBreak::Other),
+    (0x24C2, GraphemeBreak::ExtendedPictographic), (0x24C3, GraphemeBreak::Other), (0x25AA, GraphemeBreak::ExtendedPictographic), (0x25AC, GraphemeBreak::Other),
+    (0x25B6, GraphemeBreak::ExtendedPictographic), (0x25B7, GraphemeBreak::Other), (0x25C0, GraphemeBreak::ExtendedPictographic), (0x25C1, GraphemeBreak::Other),
+    (0x25FB, GraphemeBreak::ExtendedPictographic), (0x25FF, GraphemeBreak::Other), (0x2600, GraphemeBreak::ExtendedPictographic), (0x2605, GraphemeBreak::Other),
+    (0x260E, GraphemeBreak::ExtendedPictographic), (0x260F, GraphemeBreak::Other), (0x2611, GraphemeBreak::ExtendedPictographic), (0x2612, GraphemeBreak::Other),
+    (0x2614, GraphemeBreak::ExtendedPictographic), (0x2616, GraphemeBreak::Other), (0x2618, GraphemeBreak::ExtendedPictographic), (0x2619, GraphemeBreak::Other),
+    (0x261D, GraphemeBreak::ExtendedPictographic), (0x261E, GraphemeBreak::Other), (0x2620, GraphemeBreak::ExtendedPictographic), (0x2621, GraphemeBreak::Other),
+    (0x2622, GraphemeBreak::ExtendedPictographic), (0x2624, GraphemeBreak::Other), (0x2626, GraphemeBreak::ExtendedPictographic), (0x2627, GraphemeBreak::Other),
+    (0x262A, GraphemeBreak::ExtendedPictographic), (0x262B, GraphemeBreak::Other), (0x262E, GraphemeBreak::ExtendedPictographic), (0x2630, GraphemeBreak::Other),
+    (0x2638, GraphemeBreak::ExtendedPictographic), (0x263B, GraphemeBreak::Other), (0x2640, GraphemeBreak::ExtendedPictographic), (0x2641, GraphemeBreak::Other),
+    (0x2642, GraphemeBreak::ExtendedPictographic), (0x2643, GraphemeBreak::Other), (0x2648, GraphemeBreak::ExtendedPictographic), (0x2654, GraphemeBreak::Other),
+    (0x265F, GraphemeBreak::ExtendedPictographic), (0x2661, GraphemeBreak::Other), (0x2663, GraphemeBreak::ExtendedPictographic), (0x2664, GraphemeBreak::Other),
+    (0x2665, GraphemeBreak::ExtendedPictographic), (0x2667, GraphemeBreak::Other), (0x2668, GraphemeBreak::ExtendedPictographic), (0x2669, GraphemeBreak::Other),
+    (0x267B, GraphemeBreak::ExtendedPictographic), (0x267C, GraphemeBreak::Other), (0x267E, GraphemeBreak::ExtendedPictographic), (0x2680, GraphemeBreak::Other),
+    (0x2692, GraphemeBreak::ExtendedPictographic), (0x2698, GraphemeBreak::Other), (0x2699, GraphemeBreak::ExtendedPictographic), (0x269A, GraphemeBreak::Other),
+    (0x269B, GraphemeBreak::ExtendedPictographic), (0x269D, GraphemeBreak::Other), (0x26A0, GraphemeBreak::ExtendedPictographic), (0x26A2, GraphemeBreak::Other),
+    (0x26A7, GraphemeBreak::ExtendedPictographic), (0x26A8, GraphemeBreak::Other), (0x26AA, GraphemeBreak::ExtendedPictographic), (0x26AC, GraphemeBreak::Other),
+    (0x26B0, GraphemeBreak::ExtendedPictographic), (0x26B2, GraphemeBreak::Other), (0x26BD, GraphemeBreak::ExtendedPictographic), (0x26BF, GraphemeBreak::Other),
+    (0x26C4, GraphemeBreak::ExtendedPictographic), (0x26C6, GraphemeBreak::Other), (0x26C8, GraphemeBreak::ExtendedPictographic), (0x26C9, GraphemeBreak::Other),
+    (0x26CE, GraphemeBreak::ExtendedPictographic), (0x26D0, GraphemeBreak::Other), (0x26D1, GraphemeBreak::ExtendedPictographic), (0x26D2, GraphemeBreak::Other),
+    (0x26D3, GraphemeBreak::ExtendedPictographic), (0x26D5, GraphemeBreak::Other), (0x26E9, GraphemeBreak::ExtendedPictographic), (0x26EB, GraphemeBreak::Other),
+    (0x26F0, GraphemeBreak::ExtendedPictographic), (0x26F6, GraphemeBreak::Other), (0x26F7, GraphemeBreak::ExtendedPictographic), (0x26FB, GraphemeBreak::Other),
+    (0x26FD, GraphemeBreak::ExtendedPictographic), (0x26FE, GraphemeBreak::Other), (0x2702, GraphemeBreak::ExtendedPictographic), (0x2703, GraphemeBreak::Other),
+    (0x2705, GraphemeBreak::ExtendedPictographic), (0x2706, GraphemeBreak::Other), (0x2708, GraphemeBreak::ExtendedPictographic), (0x270E, GraphemeBreak::Other),
+    (0x270F, GraphemeBreak::ExtendedPictographic), (0x2710, GraphemeBreak::Other), (0x2712, GraphemeBreak::ExtendedPictographic), (0x2713, GraphemeBreak::Other),
+    (0x2714, GraphemeBreak::ExtendedPictographic), (0x2715, GraphemeBreak::Other), (0x2716, GraphemeBreak::ExtendedPictographic), (0x2717, GraphemeBreak::Other),
+    (0x271D, GraphemeBreak::ExtendedPictographic), (0x271E, GraphemeBreak::Other), (0x2721, GraphemeBreak::ExtendedPictographic), (0x2722, GraphemeBreak::Other),
+    (0x2728, GraphemeBreak::ExtendedPictographic), (0x2729, GraphemeBreak::Other), (0x2733, GraphemeBreak::ExtendedPictographic), (0x2735, GraphemeBreak::Other),
+    (0x2744, GraphemeBreak::ExtendedPictographic), (0x2745, GraphemeBreak::Other), (0x2747, GraphemeBreak::ExtendedPictographic), (0x2748, GraphemeBreak::Other),
+    (0x274C, GraphemeBreak::ExtendedPictographic), (0x274D, GraphemeBreak::Other), (0x274E, GraphemeBreak::ExtendedPictographic), (0x274F, GraphemeBreak::Other),
+    (0x2753, GraphemeBreak::ExtendedPictographic), (0x2756, GraphemeBreak::Other), (0x2757, GraphemeBreak::ExtendedPictographic), (0x2758, GraphemeBreak::Other),
+    (0x2763, GraphemeBreak::ExtendedPictographic), (0x2765, GraphemeBreak::Other), (0x2795, GraphemeBreak::ExtendedPictographic), (0x2798, GraphemeBreak::Other),
+    (0x27A1, GraphemeBreak::ExtendedPictographic), (0x27A2, GraphemeBreak::Other), (0x27B0, GraphemeBreak::ExtendedPictographic), (0x27B1, GraphemeBreak::Other),
+    (0x27BF, GraphemeBreak::ExtendedPictographic), (0x27C0, GraphemeBreak::Other), (0x2934, GraphemeBreak::ExtendedPictographic), (0x2936, GraphemeBreak::Other),
+    (0x2B05, GraphemeBreak::ExtendedPictographic), (0x2B08, GraphemeBreak::Other), (0x2B1B, GraphemeBreak::ExtendedPictographic), (0x2B1D, GraphemeBreak::Other),
+    (0x2B50, GraphemeBreak::ExtendedPictographic), (0x2B51, GraphemeBreak::Other), (0x2B55, GraphemeBreak::ExtendedPictographic), (0x2B56, GraphemeBreak::Other),
+    (0x2CEF, GraphemeBreak::Extend), (0x2CF2, GraphemeBreak::Other), (0x2D7F, GraphemeBreak::Extend), (0x2D80, GraphemeBreak::Other),
+    (0x2DE0, GraphemeBreak::Extend), (0x2E00, GraphemeBreak::Other), (0x302A, GraphemeBreak::Extend), (0x3030, GraphemeBreak::ExtendedPictographic),
+    (0x3031, GraphemeBreak::Other), (0x303D, GraphemeBreak::ExtendedPictographic), (0x303E, GraphemeBreak::Other), (0x3099, GraphemeBreak::Extend),
+    (0x309B, GraphemeBreak::Other), (0x3297, GraphemeBreak::ExtendedPictographic), (0x3298, GraphemeBreak::Other), (0x3299, GraphemeBreak::ExtendedPictographic),
+    (0x329A, GraphemeBreak::Other), (0xA66F, GraphemeBreak::Extend), (0xA673, GraphemeBreak::Other), (0xA674, GraphemeBreak::Extend),
+    (0xA67E, GraphemeBreak::Other), (0xA69E, GraphemeBreak::Extend), (0xA6A0, GraphemeBreak::Other), (0xA6F0, GraphemeBreak::Extend),
+    (0xA6F2, GraphemeBreak::Other), (0xA802, GraphemeBreak::Extend), (0xA803, GraphemeBreak::Other), (0xA806, GraphemeBreak::Extend),
+    (0xA807, GraphemeBreak::Other), (0xA80B, GraphemeBreak::Extend), (0xA80C, GraphemeBreak::Other), (0xA823, GraphemeBreak::SpacingMark),
+    (0xA825, GraphemeBreak::Extend), (0xA827, GraphemeBreak::SpacingMark), (0xA828, GraphemeBreak::Other), (0xA82C, GraphemeBreak::Extend),
+    (0xA82D, GraphemeBreak::Other), (0xA880, GraphemeBreak::SpacingMark), (0xA882, GraphemeBreak::Other), (0xA8B4, GraphemeBreak::SpacingMark),
+    (0xA8C4, GraphemeBreak::Extend), (0xA8C6, GraphemeBreak::Other), (0xA8E0, GraphemeBreak::Extend), (0xA8F2, GraphemeBreak::Other),
+    (0xA8FF, GraphemeBreak::Extend), (0xA900, GraphemeBreak::Other), (0xA926, GraphemeBreak::Extend), (0xA92E, GraphemeBreak::Other),
+    (0xA947, GraphemeBreak::Extend), (0xA952, GraphemeBreak::SpacingMark), (0xA953, GraphemeBreak::Extend), (0xA954, GraphemeBreak::Other),
+    (0xA960, GraphemeBreak::L), (0xA97D, GraphemeBreak::Other), (0xA980, GraphemeBreak::Extend), (0xA983, GraphemeBreak::SpacingMark),
+    (0xA984, GraphemeBreak::Other), (0xA989, GraphemeBreak::ConjunctConsonant), (0xA98C, GraphemeBreak::Other), (0xA98F, GraphemeBreak::ConjunctConsonant),
+    (0xA9B3, GraphemeBreak::Extend), (0xA9B4, GraphemeBreak::SpacingMark), (0xA9B6, GraphemeBreak::Extend), (0xA9BA, GraphemeBreak::SpacingMark),
+    (0xA9BC, GraphemeBreak::Extend), (0xA9BE, GraphemeBreak::SpacingMark), (0xA9C0, GraphemeBreak::ConjunctLinker), (0xA9C1, GraphemeBreak::Other),
+    (0xA9E0, GraphemeBreak::ConjunctConsonant), (0xA9E5, GraphemeBreak::Extend), (0xA9E6, GraphemeBreak::Other), (0xA9E7, GraphemeBreak::ConjunctConsonant),
+    (0xA9F0, GraphemeBreak::Other), (0xA9FA, GraphemeBreak::ConjunctConsonant), (0xA9FF, GraphemeBreak::Other), (0xAA29, GraphemeBreak::Extend),
+    (0xAA2F, GraphemeBreak::SpacingMark), (0xAA31, GraphemeBreak::Extend), (0xAA33, GraphemeBreak::SpacingMark), (0xAA35, GraphemeBreak::Extend),
+    (0xAA37, GraphemeBreak::Other), (0xAA43, GraphemeBreak::Extend), (0xAA44, GraphemeBreak::Other), (0xAA4C, GraphemeBreak::Extend),
+    (0xAA4D, GraphemeBreak::SpacingMark), (0xAA4E, GraphemeBreak::Other), (0xAA60, GraphemeBreak::ConjunctConsonant), (0xAA70, GraphemeBreak::Other),
+    (0xAA71, GraphemeBreak::ConjunctConsonant), (0xAA74, GraphemeBreak::Other), (0xAA7A, GraphemeBreak::ConjunctConsonant), (0xAA7B, GraphemeBreak::Other),
+    (0xAA7C, GraphemeBreak::Extend), (0xAA7D, GraphemeBreak::Other), (0xAA7E, GraphemeBreak::ConjunctConsonant), (0xAA80, GraphemeBreak::Other),
+    (0xAAB0, GraphemeBreak::Extend), (0xAAB1, GraphemeBreak::Other), (0xAAB2, GraphemeBreak::Extend), (0xAAB5, GraphemeBreak::Other),
+    (0xAAB7, GraphemeBreak::Extend), (0xAAB9, GraphemeBreak::Other), (0xAABE, GraphemeBreak::Extend), (0xAAC0, GraphemeBreak::Other),
+    (0xAAC1, GraphemeBreak::Extend), (0xAAC2, GraphemeBreak::Other), (0xAAE0, GraphemeBreak::ConjunctConsonant), (0xAAEB, GraphemeBreak::SpacingMark),
+    (0xAAEC, GraphemeBreak::Extend), (0xAAEE, GraphemeBreak::SpacingMark), (0xAAF0, GraphemeBreak::Other), (0xAAF5, GraphemeBreak::SpacingMark),
+    (0xAAF6, GraphemeBreak::ConjunctLinker), (0xAAF7, GraphemeBreak::Other), (0xABC0, GraphemeBreak::ConjunctConsonant), (0xABDB, GraphemeBreak::Other),
+    (0xABE3, GraphemeBreak::SpacingMark), (0xABE5, GraphemeBreak::Extend), (0xABE6, GraphemeBreak::SpacingMark), (0xABE8, GraphemeBreak::Extend),
+    (0xABE9, GraphemeBreak::SpacingMark), (0xABEB, GraphemeBreak::Other), (0xABEC, GraphemeBreak::SpacingMark), (0xABED, GraphemeBreak::Extend),
+    (0xABEE, GraphemeBreak::Other), (0xAC00, GraphemeBreak::Lv), (0xAC01, GraphemeBreak::Lvt), (0xAC1C, GraphemeBreak::Lv),
+    (0xAC1D, GraphemeBreak::Lvt), (0xAC38, GraphemeBreak::Lv), (0xAC39, GraphemeBreak::Lvt), (0xAC54, GraphemeBreak::Lv),
+    (0xAC55, GraphemeBreak::Lvt), (0xAC70, GraphemeBreak::Lv), (0xAC71, GraphemeBreak::Lvt), (0xAC8C, GraphemeBreak::Lv),
+    (0xAC8D, GraphemeBreak::Lvt), (0xACA8, GraphemeBreak::Lv), (0xACA9, GraphemeBreak::Lvt), (0xACC4, GraphemeBreak::Lv),
+    (0xACC5, GraphemeBreak::Lvt), (0xACE0, GraphemeBreak::Lv), (0xACE1, GraphemeBreak::Lvt), (0xACFC, GraphemeBreak::Lv),
+    (0xACFD, GraphemeBreak::Lvt), (0xAD18, GraphemeBreak::Lv), (0xAD19, GraphemeBreak::Lvt), (0xAD34, GraphemeBreak::Lv),
+    (0xAD35, GraphemeBreak::Lvt), (0xAD50, GraphemeBreak::Lv), (0xAD51, GraphemeBreak::Lvt), (0xAD6C, GraphemeBreak::Lv),
+    (0xAD6D, GraphemeBreak::Lvt), (0xAD88, GraphemeBreak::Lv), (0xAD89, GraphemeBreak::Lvt), (0xADA4, GraphemeBreak::Lv),
+    (0xADA5, GraphemeBreak::Lvt), (0xADC0, GraphemeBreak::Lv), (0xADC1, GraphemeBreak::Lvt), (0xADDC, GraphemeBreak::Lv),
+    (0xADDD, GraphemeBreak::Lvt), (0xADF8, GraphemeBreak::Lv), (0xADF9, GraphemeBreak::Lvt), (0xAE14, GraphemeBreak::Lv),
+    (0xAE15, GraphemeBreak::Lvt), (0xAE30, GraphemeBreak::Lv), (0xAE31, GraphemeBreak::Lvt), (0xAE4C, GraphemeBreak::Lv),
+    (0xAE4D, GraphemeBreak::Lvt), (0xAE68, GraphemeBreak::Lv), (0xAE69, GraphemeBreak::Lvt), (0xAE84, GraphemeBreak::Lv),
+    (0xAE85, GraphemeBreak::Lvt), (0xAEA0, GraphemeBreak::Lv), (0xAEA1, GraphemeBreak::Lvt), (0xAEBC, GraphemeBreak::Lv),
+    (0xAEBD, GraphemeBreak::Lvt), (0xAED8, GraphemeBreak::Lv), (0xAED9, GraphemeBreak::Lvt), (0xAEF4, GraphemeBreak::Lv),
+    (0xAEF5, GraphemeBreak::Lvt), (0xAF10, GraphemeBreak::Lv), (0xAF11, GraphemeBreak::Lvt), (0xAF2C, GraphemeBreak::Lv),
+    (0xAF2D, GraphemeBreak::Lvt), (0xAF48, GraphemeBreak::Lv), (0xAF49, GraphemeBreak::Lvt), (0xAF64, GraphemeBreak::Lv),
+    (0xAF65, GraphemeBreak::Lvt), (0xAF80, GraphemeBreak::Lv), (0xAF81, GraphemeBreak::Lvt), (0xAF9C, GraphemeBreak::Lv),
+    (0xAF9D, GraphemeBreak::Lvt), (0xAFB8, GraphemeBreak::Lv), (0xAFB9, GraphemeBreak::Lvt), (0xAFD4, GraphemeBreak::Lv),
+    (0xAFD5, GraphemeBreak::Lvt), (0xAFF0, GraphemeBreak::Lv), (0xAFF1, GraphemeBreak::Lvt), (0xB00C, GraphemeBreak::Lv),
+    (0xB00D, GraphemeBreak::Lvt), (0xB028, GraphemeBreak::Lv), (0xB029, GraphemeBreak::Lvt), (0xB044, GraphemeBreak::Lv),
+    (0xB045, GraphemeBreak::Lvt), (0xB060, GraphemeBreak::Lv), (0xB061, GraphemeBreak::Lvt), (0xB07C, GraphemeBreak::Lv),
+    (0xB07D, GraphemeBreak::Lvt), (0xB098, GraphemeBreak::Lv), (0xB099, GraphemeBreak::Lvt), (0xB0B4, GraphemeBreak::Lv),
+    (0xB0B5, GraphemeBreak::Lvt), (0xB0D0, GraphemeBreak::Lv), (0xB0D1, GraphemeBreak::Lvt), (0xB0EC, GraphemeBreak::Lv),
+    (0xB0ED, GraphemeBreak::Lvt), (0xB108, GraphemeBreak::Lv), (0xB109, GraphemeBreak::Lvt), (0xB124, GraphemeBreak::Lv),
+    (0xB125, GraphemeBreak::Lvt), (0xB140, GraphemeBreak::Lv), (0xB141, GraphemeBreak::Lvt), (0xB15C, GraphemeBreak::Lv),
+    (0xB15D, GraphemeBreak::Lvt), (0xB178, GraphemeBreak::Lv), (0xB179, GraphemeBreak::Lvt), (0xB194, GraphemeBreak::Lv),
+    (0xB195, GraphemeBreak::Lvt), (0xB1B0, GraphemeBreak::Lv), (0xB1B1, GraphemeBreak::Lvt), (0xB1CC, GraphemeBreak::Lv),
+    (0xB1CD, GraphemeBreak::Lvt), (0xB1E8, GraphemeBreak::Lv), (0xB1E9, GraphemeBreak::Lvt), (0xB204, GraphemeBreak::Lv),
+    (0xB205, GraphemeBreak::Lvt), (0xB220, GraphemeBreak::Lv), (0xB221, GraphemeBreak::Lvt), (0xB23C, GraphemeBreak::Lv),
+    (0xB23D, GraphemeBreak::Lvt), (0xB258, GraphemeBreak::Lv), (0xB259, GraphemeBreak::Lvt), (0xB274, GraphemeBreak::Lv),
+    (0xB275, GraphemeBreak::Lvt), (0xB290, GraphemeBreak::Lv), (0xB291, GraphemeBreak::Lvt), (0xB2AC, GraphemeBreak::Lv),
+    (0xB2AD, GraphemeBreak::Lvt), (0xB2C8, GraphemeBreak::Lv), (0xB2C9, GraphemeBreak::Lvt), (0xB2E4, GraphemeBreak::Lv),
+    (0xB2E5, GraphemeBreak::Lvt), (0xB300, GraphemeBreak::Lv), (0xB301, GraphemeBreak::Lvt), (0xB31C, GraphemeBreak::Lv),
+    (0xB31D, GraphemeBreak::Lvt), (0xB338, GraphemeBreak::Lv), (0xB339, GraphemeBreak::Lvt), (0xB354, GraphemeBreak::Lv),
+    (0xB355, GraphemeBreak::Lvt), (0xB370, GraphemeBreak::Lv), (0xB371, GraphemeBreak::Lvt), (0xB38C, GraphemeBreak::Lv),
+    (0xB38D, GraphemeBreak::Lvt), (0xB3A8, GraphemeBreak::Lv), (0xB3A9, GraphemeBreak::Lvt), (0xB3C4, GraphemeBreak::Lv),
+    (0xB3C5, GraphemeBreak::Lvt), (0xB3E0, GraphemeBreak::Lv), (0xB3E1, GraphemeBreak::Lvt), (0xB3FC, GraphemeBreak::Lv),
+    (0xB3FD, GraphemeBreak::Lvt), (0xB418, GraphemeBreak::Lv), (0xB419, GraphemeBreak::Lvt), (0xB434, GraphemeBreak::Lv),
+    (0xB435, GraphemeBreak::Lvt), (0xB450, GraphemeBreak::Lv), (0xB451, GraphemeBreak::Lvt), (0xB46C, GraphemeBreak::Lv),
+    (0xB46D, GraphemeBreak::Lvt), (0xB488, GraphemeBreak::Lv), (0xB489, GraphemeBreak::Lvt), (0xB4A4, GraphemeBreak::Lv),
+    (0xB4A5, GraphemeBreak::Lvt), (0xB4C0, GraphemeBreak::Lv), (0xB4C1, GraphemeBreak::Lvt), (0xB4DC, GraphemeBreak::Lv),
+    (0xB4DD, GraphemeBreak::Lvt), (0xB4F8, GraphemeBreak::Lv), (0xB4F9, GraphemeBreak::Lvt), (0xB514, GraphemeBreak::Lv),
+    (0xB515, GraphemeBreak::Lvt), (0xB530, GraphemeBreak::Lv), (0xB531, GraphemeBreak::Lvt), (0xB54C, GraphemeBreak::Lv),
+    (0xB54D, GraphemeBreak::Lvt), (0xB568, GraphemeBreak::Lv), (0xB569, GraphemeBreak::Lvt), (0xB584, GraphemeBreak::Lv),
+    (0xB585, GraphemeBreak::Lvt), (0xB5A0, GraphemeBreak::Lv), (0xB5A1, GraphemeBreak::Lvt), (0xB5BC, GraphemeBreak::Lv),
+    (0xB5BD, GraphemeBreak::Lvt), (0xB5D8, GraphemeBreak::Lv), (0xB5D9, GraphemeBreak::Lvt), (0xB5F4, GraphemeBreak::Lv),
+    (0xB5F5, GraphemeBreak::Lvt), (0xB610, GraphemeBreak::Lv), (0xB611, GraphemeBreak::Lvt), (0xB62C, GraphemeBreak::Lv),
+    (0xB62D, GraphemeBreak::Lvt), (0xB648, GraphemeBreak::Lv), (0xB649, GraphemeBreak::Lvt), (0xB664, GraphemeBreak::Lv),
+    (0xB665, GraphemeBreak::Lvt), (0xB680, GraphemeBreak::Lv), (0xB681, GraphemeBreak::Lvt), (0xB69C, GraphemeBreak::Lv),
+    (0xB69D, GraphemeBreak::Lvt), (0xB6B8, GraphemeBreak::Lv), (0xB6B9, GraphemeBreak::Lvt), (0xB6D4, GraphemeBreak::Lv),
+    (0xB6D5, GraphemeBreak::Lvt), (0xB6F0, GraphemeBreak::Lv), (0xB6F1, GraphemeBreak::Lvt), (0xB70C, GraphemeBreak::Lv),
+    (0xB70D, GraphemeBreak::Lvt), (0xB728, GraphemeBreak::Lv), (0xB729, GraphemeBreak::Lvt), (0xB744, GraphemeBreak::Lv),
+    (0xB745, GraphemeBreak::Lvt), (0xB760, GraphemeBreak::Lv), (0xB761, GraphemeBreak::Lvt), (0xB77C, GraphemeBreak::Lv),
+    (0xB77D, GraphemeBreak::Lvt), (0xB798, GraphemeBreak::Lv), (0xB799, GraphemeBreak::Lvt), (0xB7B4, GraphemeBreak::Lv),
+    (0xB7B5, GraphemeBreak::Lvt), (0xB7D0, GraphemeBreak::Lv), (0xB7D1, GraphemeBreak::Lvt), (0xB7EC, GraphemeBreak::Lv),
+    (0xB7ED, GraphemeBreak::Lvt), (0xB808, GraphemeBreak::Lv), (0xB809, GraphemeBreak::Lvt), (0xB824, GraphemeBreak::Lv),
+    (0xB825, GraphemeBreak::Lvt), (0xB840, GraphemeBreak::Lv), (0xB841, GraphemeBreak::Lvt), (0xB85C, GraphemeBreak::Lv),
+    (0xB85D, GraphemeBreak::Lvt), (0xB878, GraphemeBreak::Lv), (0xB879, GraphemeBreak::Lvt), (0xB894, GraphemeBreak::Lv),
+    (0xB895, GraphemeBreak::Lvt), (0xB8B0, GraphemeBreak::Lv), (0xB8B1, GraphemeBreak::Lvt), (0xB8CC, GraphemeBreak::Lv),
+    (0xB8CD, GraphemeBreak::Lvt), (0xB8E8, GraphemeBreak::Lv), (0xB8E9, GraphemeBreak::Lvt), (0xB904, GraphemeBreak::Lv),
+    (0xB905, GraphemeBreak::Lvt), (0xB920, GraphemeBreak::Lv), (0xB921, GraphemeBreak::Lvt), (0xB93C, GraphemeBreak::Lv),
+    (0xB93D, GraphemeBreak::Lvt), (0xB958, GraphemeBreak::Lv), (0xB959, GraphemeBreak::Lvt), (0xB974, GraphemeBreak::Lv),
+    (0xB975, GraphemeBreak::Lvt), (0xB990, GraphemeBreak::Lv), (0xB991, GraphemeBreak::Lvt), (0xB9AC, GraphemeBreak::Lv),
+    (0xB9AD, GraphemeBreak::Lvt), (0xB9C8, GraphemeBreak::Lv), (0xB9C9, GraphemeBreak::Lvt), (0xB9E4, GraphemeBreak::Lv),
+    (0xB9E5, GraphemeBreak::Lvt), (0xBA00, GraphemeBreak::Lv), (0xBA01, GraphemeBreak::Lvt), (0xBA1C, GraphemeBreak::Lv),
+    (0xBA1D, GraphemeBreak::Lvt), (0xBA38, GraphemeBreak::Lv), (0xBA39, GraphemeBreak::Lvt), (0xBA54, GraphemeBreak::Lv),
+    (0xBA55, GraphemeBreak::Lvt), (0xBA70, GraphemeBreak::Lv), (0xBA71, GraphemeBreak::Lvt), (0xBA8C, GraphemeBreak::Lv),
+    (0xBA8D, GraphemeBreak::Lvt), (0xBAA8, GraphemeBreak::Lv), (0xBAA9, GraphemeBreak::Lvt), (0xBAC4, GraphemeBreak::Lv),
+    (0xBAC5, GraphemeBreak::Lvt), (0xBAE0, GraphemeBreak::Lv), (0xBAE1, GraphemeBreak::Lvt), (0xBAFC, GraphemeBreak::Lv),
+    (0xBAFD, GraphemeBreak::Lvt), (0xBB18, GraphemeBreak::Lv), (0xBB19, GraphemeBreak::Lvt), (0xBB34, GraphemeBreak::Lv),
+    (0xBB35, GraphemeBreak::Lvt), (0xBB50, GraphemeBreak::Lv), (0xBB51, GraphemeBreak::Lvt), (0xBB6C, GraphemeBreak::Lv),
+    (0xBB6D, GraphemeBreak::Lvt), (0xBB88, GraphemeBreak::Lv), (0xBB89, GraphemeBreak::Lvt), (0xBBA4, GraphemeBreak::Lv),
+    (0xBBA5, GraphemeBreak::Lvt), (0xBBC0, GraphemeBreak::Lv), (0xBBC1, GraphemeBreak::Lvt), (0xBBDC, GraphemeBreak::Lv),
+    (0xBBDD, GraphemeBreak::Lvt), (0xBBF8, GraphemeBreak::Lv), (0xBBF9, GraphemeBreak::Lvt), (0xBC14, GraphemeBreak::Lv),
+    (0xBC15, GraphemeBreak::Lvt), (0xBC30, GraphemeBreak::Lv), (0xBC31, GraphemeBreak::Lvt), (0xBC4C, GraphemeBreak::Lv),
+    (0xBC4D, GraphemeBreak::Lvt), (0xBC68, GraphemeBreak::Lv), (0xBC69, GraphemeBreak::Lvt), (0xBC84, GraphemeBreak::Lv),
+    (0xBC85, GraphemeBreak::Lvt), (0xBCA0, GraphemeBreak::Lv), (0xBCA1, GraphemeBreak::Lvt), (0xBCBC, GraphemeBreak::Lv),
+    (0xBCBD, GraphemeBreak::Lvt), (0xBCD8, GraphemeBreak::Lv), (0xBCD9, GraphemeBreak::Lvt), (0xBCF4, GraphemeBreak::Lv),
+    (0xBCF5, GraphemeBreak::Lvt), (0xBD10, GraphemeBreak::Lv), (0xBD11, GraphemeBreak::Lvt), (0xBD2C, GraphemeBreak::Lv),
+    (0xBD2D, GraphemeBreak::Lvt), (0xBD48, GraphemeBreak::Lv), (0xBD49, GraphemeBreak::Lvt), (0xBD64, GraphemeBreak::Lv),
+    (0xBD65, GraphemeBreak::Lvt), (0xBD80, GraphemeBreak::Lv), (0xBD81, GraphemeBreak::Lvt), (0xBD9C, GraphemeBreak::Lv),
+    (0xBD9D, GraphemeBreak::Lvt), (0xBDB8, GraphemeBreak::Lv), (0xBDB9, GraphemeBreak::Lvt), (0xBDD4, GraphemeBreak::Lv),
+    (0xBDD5, GraphemeBreak::Lvt), (0xBDF0, GraphemeBreak::Lv), (0xBDF1, GraphemeBreak::Lvt), (0xBE0C, GraphemeBreak::Lv),
+    (0xBE0D, GraphemeBreak::Lvt), (0xBE28, GraphemeBreak::Lv), (0xBE29, GraphemeBreak::Lvt), (0xBE44, GraphemeBreak::Lv),
+    (0xBE45, GraphemeBreak::Lvt), (0xBE60, GraphemeBreak::Lv), (0xBE61, GraphemeBreak::Lvt), (0xBE7C, GraphemeBreak::Lv),
+    (0xBE7D, GraphemeBreak::Lvt), (0xBE98, GraphemeBreak::Lv), (0xBE99, GraphemeBreak::Lvt), (0xBEB4, GraphemeBreak::Lv),
+    (0xBEB5, GraphemeBreak::Lvt), (0xBED0, GraphemeBreak::Lv), (0xBED1, GraphemeBreak::Lvt), (0xBEEC, GraphemeBreak::Lv),
+    (0xBEED, GraphemeBreak::Lvt), (0xBF08, GraphemeBreak::Lv), (0xBF09, GraphemeBreak::Lvt), (0xBF24, GraphemeBreak::Lv),
+    (0xBF25, GraphemeBreak::Lvt), (0xBF40, GraphemeBreak::Lv), (0xBF41, GraphemeBreak::Lvt), (0xBF5C, GraphemeBreak::Lv),
+    (0xBF5D, GraphemeBreak::Lvt), (0xBF78, GraphemeBreak::Lv), (0xBF79, GraphemeBreak::Lvt), (0xBF94, GraphemeBreak::Lv),
+    (0xBF95, GraphemeBreak::Lvt), (0xBFB0, GraphemeBreak::Lv), (0xBFB1, GraphemeBreak::Lvt), (0xBFCC, GraphemeBreak::Lv),
+    (0xBFCD, GraphemeBreak::Lvt), (0xBFE8, GraphemeBreak::Lv), (0xBFE9, GraphemeBreak::Lvt), (0xC004, GraphemeBreak::Lv),
+    (0xC005, GraphemeBreak::Lvt), (0xC020, GraphemeBreak::Lv), (0xC021, GraphemeBreak::Lvt), (0xC03C, GraphemeBreak::Lv),
+    (0xC03D, GraphemeBreak::Lvt), (0xC058, GraphemeBreak::Lv), (0xC059, GraphemeBreak::Lvt), (0xC074, GraphemeBreak::Lv),
+    (0xC075, GraphemeBreak::Lvt), (0xC090, GraphemeBreak::Lv), (0xC091, GraphemeBreak::Lvt), (0xC0AC, GraphemeBreak::Lv),
+    (0xC0AD, GraphemeBreak::Lvt), (0xC0C8, GraphemeBreak::Lv), (0xC0C9, GraphemeBreak::Lvt), (0xC0E4, GraphemeBreak::Lv),
+    (0xC0E5, GraphemeBreak::Lvt), (0xC100, GraphemeBreak::Lv), (0xC101, GraphemeBreak::Lvt), (0xC11C, GraphemeBreak::Lv),
+    (0xC11D, GraphemeBreak::Lvt), (0xC138, GraphemeBreak::Lv), (0xC139, GraphemeBreak::Lvt), (0xC154, GraphemeBreak::Lv),
+    (0xC155, GraphemeBreak::Lvt), (0xC170, GraphemeBreak::Lv), (0xC171, GraphemeBreak::Lvt), (0xC18C, GraphemeBreak::Lv),
+    (0xC18D, GraphemeBreak::Lvt), (0xC1A8, GraphemeBreak::Lv), (0xC1A9, GraphemeBreak::Lvt), (0xC1C4, GraphemeBreak::Lv),
+    (0xC1C5, GraphemeBreak::Lvt), (0xC1E0, GraphemeBreak::Lv), (0xC1E1, GraphemeBreak::Lvt), (0xC1FC, GraphemeBreak::Lv),
+    (0xC1FD, GraphemeBreak::Lvt), (0xC218, GraphemeBreak::Lv), (0xC219, GraphemeBreak::Lvt), (0xC234, GraphemeBreak::Lv),
+    (0xC235, GraphemeBreak::Lvt), (0xC250, GraphemeBreak::Lv), (0xC251, GraphemeBreak::Lvt), (0xC26C, GraphemeBreak::Lv),
+    (0xC26D, GraphemeBreak::Lvt), (0xC288, GraphemeBreak::Lv), (0xC289, GraphemeBreak::Lvt), (0xC2A4, GraphemeBreak::Lv),
+    (0xC2A5, GraphemeBreak::Lvt), (0xC2C0, GraphemeBreak::Lv), (0xC2C1, GraphemeBreak::Lvt), (0xC2DC, GraphemeBreak::Lv),
+    (0xC2DD, GraphemeBreak::Lvt), (0xC2F8, GraphemeBreak::Lv), (0xC2F9, GraphemeBreak::Lvt), (0xC314, GraphemeBreak::Lv),
+    (0xC315, GraphemeBreak::Lvt), (0xC330, GraphemeBreak::Lv), (0xC331, GraphemeBreak::Lvt), (0xC34C, GraphemeBreak::Lv),
+    (0xC34D, GraphemeBreak::Lvt), (0xC368, GraphemeBreak::Lv), (0xC369, GraphemeBreak::Lvt), (0xC384, GraphemeBreak::Lv),
+    (0xC385, GraphemeBreak::Lvt), (0xC3A0, GraphemeBreak::Lv), (0xC3A1, GraphemeBreak::Lvt), (0xC3BC, GraphemeBreak::Lv),
+    (0xC3BD, GraphemeBreak::Lvt), (0xC3D8, GraphemeBreak::Lv), (0xC3D9, GraphemeBreak::Lvt), (0xC3F4, GraphemeBreak::Lv),
+    (0xC3F5, GraphemeBreak::Lvt), (0xC410, GraphemeBreak::Lv), (0xC411, GraphemeBreak::Lvt), (0xC42C, GraphemeBreak::Lv),
+    (0xC42D, GraphemeBreak::Lvt), (0xC448, GraphemeBreak::Lv), (0xC449, GraphemeBreak::Lvt), (0xC464, GraphemeBreak::Lv),
+    (0xC465, GraphemeBreak::Lvt), (0xC480, GraphemeBreak::Lv), (0xC481, GraphemeBreak::Lvt), (0xC49C, GraphemeBreak::Lv),
+    (0xC49D, GraphemeBreak::Lvt), (0xC4B8, GraphemeBreak::Lv), (0xC4B9, GraphemeBreak::Lvt), (0xC4D4, GraphemeBreak::Lv),
+    (0xC4D5, GraphemeBreak::Lvt), (0xC4F0, GraphemeBreak::Lv), (0xC4F1, GraphemeBreak::Lvt), (0xC50C, GraphemeBreak::Lv),
+    (0xC50D, GraphemeBreak::Lvt), (0xC528, GraphemeBreak::Lv), (0xC529, GraphemeBreak::Lvt), (0xC544, GraphemeBreak::Lv),
+    (0xC545, GraphemeBreak::Lvt), (0xC560, GraphemeBreak::Lv), (0xC561, GraphemeBreak::Lvt), (0xC57C, GraphemeBreak::Lv),
+    (0xC57D, GraphemeBreak::Lvt), (0xC598, GraphemeBreak::Lv), (0xC599, GraphemeBreak::Lvt), (0xC5B4, GraphemeBreak::Lv),
+    (0xC5B5, GraphemeBreak::Lvt), (0xC5D0, GraphemeBreak::Lv), (0xC5D1, GraphemeBreak::Lvt), (0xC5EC, GraphemeBreak::Lv),
+    (0xC5ED, GraphemeBreak::Lvt), (0xC608, GraphemeBreak::Lv), (0xC609, GraphemeBreak::Lvt), (0xC624, GraphemeBreak::Lv),
+    (0xC625, GraphemeBreak::Lvt), (0xC640, GraphemeBreak::Lv), (0xC641, GraphemeBreak::Lvt), (0xC65C, GraphemeBreak::Lv),
+    (0xC65D, GraphemeBreak::Lvt), (0xC678, GraphemeBreak::Lv), (0xC679, GraphemeBreak::Lvt), (0xC694, GraphemeBreak::Lv),
+    (0xC695, GraphemeBreak::Lvt), (0xC6B0, GraphemeBreak::Lv), (0xC6B1, GraphemeBreak::Lvt), (0xC6CC, GraphemeBreak::Lv),
+    (0xC6CD, GraphemeBreak::Lvt), (0xC6E8, GraphemeBreak::Lv), (0xC6E9, GraphemeBreak::Lvt), (0xC704, GraphemeBreak::Lv),
+    (0xC705, GraphemeBreak::Lvt), (0xC720, GraphemeBreak::Lv), (0xC721, GraphemeBreak::Lvt), (0xC73C, GraphemeBreak::Lv),
+    (0xC73D, GraphemeBreak::Lvt), (0xC758, GraphemeBreak::Lv), (0xC759, GraphemeBreak::Lvt), (0xC774, GraphemeBreak::Lv),
+    (0xC775, GraphemeBreak::Lvt), (0xC790, GraphemeBreak::Lv), (0xC791, GraphemeBreak::Lvt), (0xC7AC, GraphemeBreak::Lv),
+    (0xC7AD, GraphemeBreak::Lvt), (0xC7C8, GraphemeBreak::Lv), (0xC7C9, GraphemeBreak::Lvt), (0xC7E4, GraphemeBreak::Lv),
+    (0xC7E5, GraphemeBreak::Lvt), (0xC800, GraphemeBreak::Lv), (0xC801, GraphemeBreak::Lvt), (0xC81C, GraphemeBreak::Lv),
+    (0xC81D, GraphemeBreak::Lvt), (0xC838, GraphemeBreak::Lv), (0xC839, GraphemeBreak::Lvt), (0xC854, GraphemeBreak::Lv),
+    (0xC855, GraphemeBreak::Lvt), (0xC870, GraphemeBreak::Lv), (0xC871, GraphemeBreak::Lvt), (0xC88C, GraphemeBreak::Lv),
+    (0xC88D, GraphemeBreak::Lvt), (0xC8A8, GraphemeBreak::Lv), (0xC8A9, GraphemeBreak::Lvt), (0xC8C4, GraphemeBreak::Lv),
+    (0xC8C5, GraphemeBreak::Lvt), (0xC8E0, GraphemeBreak::Lv), (0xC8E1, GraphemeBreak::Lvt), (0xC8FC, GraphemeBreak::Lv),
+    (0xC8FD, GraphemeBreak::Lvt), (0xC918, GraphemeBreak::Lv), (0xC919, GraphemeBreak::Lvt), (0xC934, GraphemeBreak::Lv),
+    (0xC935, GraphemeBreak::Lvt), (0xC950, GraphemeBreak::Lv), (0xC951, GraphemeBreak::Lvt), (0xC96C, GraphemeBreak::Lv),
+    (0xC96D, GraphemeBreak::Lvt), (0xC988, GraphemeBreak::Lv), (0xC989, GraphemeBreak::Lvt), (0xC9A4, GraphemeBreak::Lv),
+    (0xC9A5, GraphemeBreak::Lvt), (0xC9C0, GraphemeBreak::Lv), (0xC9C1, GraphemeBreak::Lvt), (0xC9DC, GraphemeBreak::Lv),
+    (0xC9DD, GraphemeBreak::Lvt), (0xC9F8, GraphemeBreak::Lv), (0xC9F9, GraphemeBreak::Lvt), (0xCA14, GraphemeBreak::Lv),
+    (0xCA15, GraphemeBreak::Lvt), (0xCA30, GraphemeBreak::Lv), (0xCA31, GraphemeBreak::Lvt), (0xCA4C, GraphemeBreak::Lv),
+    (0xCA4D, GraphemeBreak::Lvt), (0xCA68, GraphemeBreak::Lv), (0xCA69, GraphemeBreak::Lvt), (0xCA84, GraphemeBreak::Lv),
+    (0xCA85, GraphemeBreak::Lvt), (0xCAA0, GraphemeBreak::Lv), (0xCAA1, GraphemeBreak::Lvt), (0xCABC, GraphemeBreak::Lv),
+    (0xCABD, GraphemeBreak::Lvt), (0xCAD8, GraphemeBreak::Lv), (0xCAD9, GraphemeBreak::Lvt), (0xCAF4, GraphemeBreak::Lv),
+    (0xCAF5, GraphemeBreak::Lvt), (0xCB10, GraphemeBreak::Lv), (0xCB11, GraphemeBreak::Lvt), (0xCB2C, GraphemeBreak::Lv),
+    (0xCB2D, GraphemeBreak::Lvt), (0xCB48, GraphemeBreak::Lv), (0xCB49, GraphemeBreak::Lvt), (0xCB64, GraphemeBreak::Lv),
+    (0xCB65, GraphemeBreak::Lvt), (0xCB80, GraphemeBreak::Lv), (0xCB81, GraphemeBreak::Lvt), (0xCB9C, GraphemeBreak::Lv),
+    (0xCB9D, GraphemeBreak::Lvt), (0xCBB8, GraphemeBreak::Lv), (0xCBB9, GraphemeBreak::Lvt), (0xCBD4, GraphemeBreak::Lv),
+    (0xCBD5, GraphemeBreak::Lvt), (0xCBF0, GraphemeBreak::Lv), (0xCBF1, GraphemeBreak::Lvt), (0xCC0C, GraphemeBreak::Lv),
+    (0xCC0D, GraphemeBreak::Lvt), (0xCC28, GraphemeBreak::Lv), (0xCC29, GraphemeBreak::Lvt), (0xCC44, GraphemeBreak::Lv),
+    (0xCC45, GraphemeBreak::Lvt), (0xCC60, GraphemeBreak::Lv), (0xCC61, GraphemeBreak::Lvt), (0xCC7C, GraphemeBreak::Lv),
+    (0xCC7D, GraphemeBreak::Lvt), (0xCC98, GraphemeBreak::Lv), (0xCC99, GraphemeBreak::Lvt), (0xCCB4, GraphemeBreak::Lv),
+    (0xCCB5, GraphemeBreak::Lvt), (0xCCD0, GraphemeBreak::Lv), (0xCCD1, GraphemeBreak::Lvt), (0xCCEC, GraphemeBreak::Lv),
+    (0xCCED, GraphemeBreak::Lvt), (0xCD08, GraphemeBreak::Lv), (0xCD09, GraphemeBreak::Lvt), (0xCD24, GraphemeBreak::Lv),
+    (0xCD25, GraphemeBreak::Lvt), (0xCD40, GraphemeBreak::Lv), (0xCD41, GraphemeBreak::Lvt), (0xCD5C, GraphemeBreak::Lv),
+    (0xCD5D, GraphemeBreak::Lvt), (0xCD78, GraphemeBreak::Lv), (0xCD79, GraphemeBreak::Lvt), (0xCD94, GraphemeBreak::Lv),
+    (0xCD95, GraphemeBreak::Lvt), (0xCDB0, GraphemeBreak::Lv), (0xCDB1, GraphemeBreak::Lvt), (0xCDCC, GraphemeBreak::Lv),
+    (0xCDCD, GraphemeBreak::Lvt), (0xCDE8, GraphemeBreak::Lv), (0xCDE9, GraphemeBreak::Lvt), (0xCE04, GraphemeBreak::Lv),
+    (0xCE05, GraphemeBreak::Lvt), (0xCE20, GraphemeBreak::Lv), (0xCE21, GraphemeBreak::Lvt), (0xCE3C, GraphemeBreak::Lv),
+    (0xCE3D, GraphemeBreak::Lvt), (0xCE58, GraphemeBreak::Lv), (0xCE59, GraphemeBreak::Lvt), (0xCE74, GraphemeBreak::Lv),
+    (0xCE75, GraphemeBreak::Lvt), (0xCE90, GraphemeBreak::Lv), (0xCE91, GraphemeBreak::Lvt), (0xCEAC, GraphemeBreak::Lv),
+    (0xCEAD, GraphemeBreak::Lvt), (0xCEC8, GraphemeBreak::Lv), (0xCEC9, GraphemeBreak::Lvt), (0xCEE4, GraphemeBreak::Lv),
+    (0xCEE5, GraphemeBreak::Lvt), (0xCF00, GraphemeBreak::Lv), (0xCF01, GraphemeBreak::Lvt), (0xCF1C, GraphemeBreak::Lv),
+    (0xCF1D, GraphemeBreak::Lvt), (0xCF38, GraphemeBreak::Lv), (0xCF39, GraphemeBreak::Lvt), (0xCF54, GraphemeBreak::Lv),
+    (0xCF55, GraphemeBreak::Lvt), (0xCF70, GraphemeBreak::Lv), (0xCF71, GraphemeBreak::Lvt), (0xCF8C, GraphemeBreak::Lv),
+    (0xCF8D, GraphemeBreak::Lvt), (0xCFA8, GraphemeBreak::Lv), (0xCFA9, GraphemeBreak::Lvt), (0xCFC4, GraphemeBreak::Lv),
+    (0xCFC5, GraphemeBreak::Lvt), (0xCFE0, GraphemeBreak::Lv), (0xCFE1, GraphemeBreak::Lvt), (0xCFFC, GraphemeBreak::Lv),
+    (0xCFFD, GraphemeBreak::Lvt), (0xD018, GraphemeBreak::Lv), (0xD019, GraphemeBreak::Lvt), (0xD034, GraphemeBreak::Lv),
+    (0xD035, GraphemeBreak::Lvt), (0xD050, GraphemeBreak::Lv), (0xD051, GraphemeBreak::Lvt), (0xD06C, GraphemeBreak::Lv),
+    (0xD06D, GraphemeBreak::Lvt), (0xD088, GraphemeBreak::Lv), (0xD089, GraphemeBreak::Lvt), (0xD0A4, GraphemeBreak::Lv),
+    (0xD0A5, GraphemeBreak::Lvt), (0xD0C0, GraphemeBreak::Lv), (0xD0C1, GraphemeBreak::Lvt), (0xD0DC, GraphemeBreak::Lv),
+    (0xD0DD, GraphemeBreak::Lvt), (0xD0F8, GraphemeBreak::Lv), (0xD0F9, GraphemeBreak::Lvt), (0xD114, GraphemeBreak::Lv),
+    (0xD115, GraphemeBreak::Lvt), (0xD130, GraphemeBreak::Lv), (0xD131, GraphemeBreak::Lvt), (0xD14C, GraphemeBreak::Lv),
+    (0xD14D, GraphemeBreak::Lvt), (0xD168, GraphemeBreak::Lv), (0xD169, GraphemeBreak::Lvt), (0xD184, GraphemeBreak::Lv),
+    (0xD185, GraphemeBreak::Lvt), (0xD1A0, GraphemeBreak::Lv), (0xD1A1, GraphemeBreak::Lvt), (0xD1BC, GraphemeBreak::Lv),
+    (0xD1BD, GraphemeBreak::Lvt), (0xD1D8, GraphemeBreak::Lv), (0xD1D9, GraphemeBreak::Lvt), (0xD1F4, GraphemeBreak::Lv),
+    (0xD1F5, GraphemeBreak::Lvt), (0xD210, GraphemeBreak::Lv), (0xD211, GraphemeBreak::Lvt), (0xD22C, GraphemeBreak::Lv),
+    (0xD22D, GraphemeBreak::Lvt), (0xD248, GraphemeBreak::Lv), (0xD249, GraphemeBreak::Lvt), (0xD264, GraphemeBreak::Lv),
+    (0xD265, GraphemeBreak::Lvt), (0xD280, GraphemeBreak::Lv), (0xD281, GraphemeBreak::Lvt), (0xD29C, GraphemeBreak::Lv),
+    (0xD29D, GraphemeBreak::Lvt), (0xD2B8, GraphemeBreak::Lv), (0xD2B9, GraphemeBreak::Lvt), (0xD2D4, GraphemeBreak::Lv),
+    (0xD2D5, GraphemeBreak::Lvt), (0xD2F0, GraphemeBreak::Lv), (0xD2F1, GraphemeBreak::Lvt), (0xD30C, GraphemeBreak::Lv),
+    (0xD30D, GraphemeBreak::Lvt), (0xD328, GraphemeBreak::Lv), (0xD329, GraphemeBreak::Lvt), (0xD344, GraphemeBreak::Lv),
+    (0xD345, GraphemeBreak::Lvt), (0xD360, GraphemeBreak::Lv), (0xD361, GraphemeBreak::Lvt), (0xD37C, GraphemeBreak::Lv),
+    (0xD37D, GraphemeBreak::Lvt), (0xD398, GraphemeBreak::Lv), (0xD399, GraphemeBreak::Lvt), (0xD3B4, GraphemeBreak::Lv),
+    (0xD3B5, GraphemeBreak::Lvt), (0xD3D0, GraphemeBreak::Lv), (0xD3D1, GraphemeBreak::Lvt), (0xD3EC, GraphemeBreak::Lv),
+    (0xD3ED, GraphemeBreak::Lvt), (0xD408, GraphemeBreak::Lv), (0xD409, GraphemeBreak::Lvt), (0xD424, GraphemeBreak::Lv),
+    (0xD425, GraphemeBreak::Lvt), (0xD440, GraphemeBreak::Lv), (0xD441, GraphemeBreak::Lvt), (0xD45C, GraphemeBreak::Lv),
+    (0xD45D, GraphemeBreak::Lvt), (0xD478, GraphemeBreak::Lv), (0xD479, GraphemeBreak::Lvt), (0xD494, GraphemeBreak::Lv),
+    (0xD495, GraphemeBreak::Lvt), (0xD4B0, GraphemeBreak::Lv), (0xD4B1, GraphemeBreak::Lvt), (0xD4CC, GraphemeBreak::Lv),
+    (0xD4CD, GraphemeBreak::Lvt), (0xD4E8, GraphemeBreak::Lv), (0xD4E9, GraphemeBreak::Lvt), (0xD504, GraphemeBreak::Lv),
+    (0xD505, GraphemeBreak::Lvt), (0xD520, GraphemeBreak::Lv), (0xD521, GraphemeBreak::Lvt), (0xD53C, GraphemeBreak::Lv),
+    (0xD53D, GraphemeBreak::Lvt), (0xD558, GraphemeBreak::Lv), (0xD559, GraphemeBreak::Lvt), (0xD574, GraphemeBreak::Lv),
+    (0xD575, GraphemeBreak::Lvt), (0xD590, GraphemeBreak::Lv), (0xD591, GraphemeBreak::Lvt), (0xD5AC, GraphemeBreak::Lv),
+    (0xD5AD, GraphemeBreak::Lvt), (0xD5C8, GraphemeBreak::Lv), (0xD5C9, GraphemeBreak::Lvt), (0xD5E4, GraphemeBreak::Lv),
+    (0xD5E5, GraphemeBreak::Lvt), (0xD600, GraphemeBreak::Lv), (0xD601, GraphemeBreak::Lvt), (0xD61C, GraphemeBreak::Lv),
+    (0xD61D, GraphemeBreak::Lvt), (0xD638, GraphemeBreak::Lv), (0xD639, GraphemeBreak::Lvt), (0xD654, GraphemeBreak::Lv),
+    (0xD655, GraphemeBreak::Lvt), (0xD670, GraphemeBreak::Lv), (0xD671, GraphemeBreak::Lvt), (0xD68C, GraphemeBreak::Lv),
+    (0xD68D, GraphemeBreak::Lvt), (0xD6A8, GraphemeBreak::Lv), (0xD6A9, GraphemeBreak::Lvt), (0xD6C4, GraphemeBreak::Lv),
+    (0xD6C5, GraphemeBreak::Lvt), (0xD6E0, GraphemeBreak::Lv), (0xD6E1, GraphemeBreak::Lvt), (0xD6FC, GraphemeBreak::Lv),
+    (0xD6FD, GraphemeBreak::Lvt), (0xD718, GraphemeBreak::Lv), (0xD719, GraphemeBreak::Lvt), (0xD734, GraphemeBreak::Lv),
+    (0xD735, GraphemeBreak::Lvt), (0xD750, GraphemeBreak::Lv), (0xD751, GraphemeBreak::Lvt), (0xD76C, GraphemeBreak::Lv),
+    (0xD76D, GraphemeBreak::Lvt), (0xD788, GraphemeBreak::Lv), (0xD789, GraphemeBreak::Lvt), (0xD7A4, GraphemeBreak::Other),
+    (0xD7B0, GraphemeBreak::V), (0xD7C7, GraphemeBreak::Other), (0xD7CB, GraphemeBreak::T), (0xD7FC, GraphemeBreak::Other),
+    (0xFB1E, GraphemeBreak::Extend), (0xFB1F, GraphemeBreak::Other), (0xFE00, GraphemeBreak::Extend), (0xFE10, GraphemeBreak::Other),
+    (0xFE20, GraphemeBreak::Extend), (0xFE30, GraphemeBreak::Other), (0xFEFF, GraphemeBreak::Control), (0xFF00, GraphemeBreak::Other),
+    (0xFF9E, GraphemeBreak::Extend), (0xFFA0, GraphemeBreak::Other), (0xFFF0, GraphemeBreak::Control), (0xFFFC, GraphemeBreak::Other),
+    (0x101FD, GraphemeBreak::Extend), (0x101FE, GraphemeBreak::Other), (0x102E0, GraphemeBreak::Extend), (0x102E1, GraphemeBreak::Other),
+    (0x10376, GraphemeBreak::Extend), (0x1037B, GraphemeBreak::Other), (0x10A00, GraphemeBreak::ConjunctConsonant), (0x10A01, GraphemeBreak::Extend),
+    (0x10A04, GraphemeBreak::Other), (0x10A05, GraphemeBreak::Extend), (0x10A07, GraphemeBreak::Other), (0x10A0C, GraphemeBreak::Extend),
+    (0x10A10, GraphemeBreak::ConjunctConsonant), (0x10A14, GraphemeBreak::Other), (0x10A15, GraphemeBreak::ConjunctConsonant), (0x10A18, GraphemeBreak::Other),
+    (0x10A19, GraphemeBreak::ConjunctConsonant), (0x10A36, GraphemeBreak::Other), (0x10A38, GraphemeBreak::Extend), (0x10A3B, GraphemeBreak::Other),
+    (0x10A3F, GraphemeBreak::ConjunctLinker), (0x10A40, GraphemeBreak::Other), (0x10AE5, GraphemeBreak::Extend), (0x10AE7, GraphemeBreak::Other),
+    (0x10D24, GraphemeBreak::Extend), (0x10D28, GraphemeBreak::Other), (0x10D69, GraphemeBreak::Extend), (0x10D6E, GraphemeBreak::Other),
+    (0x10EAB, GraphemeBreak::Extend), (0x10EAD, GraphemeBreak::Other), (0x10EFA, GraphemeBreak::Extend), (0x10F00, GraphemeBreak::Other),
+    (0x10F46, GraphemeBreak::Extend), (0x10F51, GraphemeBreak::Other), (0x10F82, GraphemeBreak::Extend), (0x10F86, GraphemeBreak::Other),
+    (0x11000, GraphemeBreak::SpacingMark), (0x11001, GraphemeBreak::Extend), (0x11002, GraphemeBreak::SpacingMark), (0x11003, GraphemeBreak::Other),
+    (0x11038, GraphemeBreak::Extend), (0x11047, GraphemeBreak::Other), (0x11070, GraphemeBreak::Extend), (0x11071, GraphemeBreak::Other),
+    (0x11073, GraphemeBreak::Extend), (0x11075, GraphemeBreak::Other), (0x1107F, GraphemeBreak::Extend), (0x11082, GraphemeBreak::SpacingMark),
+    (0x11083, GraphemeBreak::Other), (0x110B0, GraphemeBreak::SpacingMark), (0x110B3, GraphemeBreak::Extend), (0x110B7, GraphemeBreak::SpacingMark),
+    (0x110B9, GraphemeBreak::Extend), (0x110BB, GraphemeBreak::Other), (0x110BD, GraphemeBreak::Prepend), (0x110BE, GraphemeBreak::Other),
+    (0x110C2, GraphemeBreak::Extend), (0x110C3, GraphemeBreak::Other), (0x110CD, GraphemeBreak::Prepend), (0x110CE, GraphemeBreak::Other),
+    (0x11100, GraphemeBreak::Extend), (0x11103, GraphemeBreak::ConjunctConsonant), (0x11127, GraphemeBreak::Extend), (0x1112C, GraphemeBreak::SpacingMark),
+    (0x1112D, GraphemeBreak::Extend), (0x11133, GraphemeBreak::ConjunctLinker), (0x11134, GraphemeBreak::Extend), (0x11135, GraphemeBreak::Other),
+    (0x11144, GraphemeBreak::ConjunctConsonant), (0x11145, GraphemeBreak::SpacingMark), (0x11147, GraphemeBreak::ConjunctConsonant), (0x11148, GraphemeBreak::Other),
+    (0x11173, GraphemeBreak::Extend), (0x11174, GraphemeBreak::Other), (0x11180, GraphemeBreak::Extend), (0x11182, GraphemeBreak::SpacingMark),
+    (0x11183, GraphemeBreak::Other), (0x111B3, GraphemeBreak::SpacingMark), (0x111B6, GraphemeBreak::Extend), (0x111BF, GraphemeBreak::SpacingMark),
+    (0x111C0, GraphemeBreak::Extend), (0x111C1, GraphemeBreak::Other), (0x111C2, GraphemeBreak::Prepend), (0x111C4, GraphemeBreak::Other),
+    (0x111C9, GraphemeBreak::Extend), (0x111CD, GraphemeBreak::Other), (0x111CE, GraphemeBreak::SpacingMark), (0x111CF, GraphemeBreak::Extend),
+    (0x111D0, GraphemeBreak::Other), (0x1122C, GraphemeBreak::SpacingMark), (0x1122F, GraphemeBreak::Extend), (0x11232, GraphemeBreak::SpacingMark),
+    (0x11234, GraphemeBreak::Extend), (0x11238, GraphemeBreak::Other), (0x1123E, GraphemeBreak::Extend), (0x1123F, GraphemeBreak::Other),
+    (0x11241, GraphemeBreak::Extend), (0x11242, GraphemeBreak::Other), (0x112DF, GraphemeBreak::Extend), (0x112E0, GraphemeBreak::SpacingMark),
+    (0x112E3, GraphemeBreak::Extend), (0x112EB, GraphemeBreak::Other), (0x11300, GraphemeBreak::Extend), (0x11302, GraphemeBreak::SpacingMark),
+    (0x11304, GraphemeBreak::Other), (0x1133B, GraphemeBreak::Extend), (0x1133D, GraphemeBreak::Other), (0x1133E, GraphemeBreak::Extend),
+    (0x1133F, GraphemeBreak::SpacingMark), (0x11340, GraphemeBreak::Extend), (0x11341, GraphemeBreak::SpacingMark), (0x11345, GraphemeBreak::Other),
+    (0x11347, GraphemeBreak::SpacingMark), (0x11349, GraphemeBreak::Other), (0x1134B, GraphemeBreak::SpacingMark), (0x1134D, GraphemeBreak::Extend),
+    (0x1134E, GraphemeBreak::Other), (0x11357, GraphemeBreak::Extend), (0x11358, GraphemeBreak::Other), (0x11362, GraphemeBreak::SpacingMark),
+    (0x11364, GraphemeBreak::Other), (0x11366, GraphemeBreak::Extend), (0x1136D, GraphemeBreak::Other), (0x11370, GraphemeBreak::Extend),
+    (0x11375, GraphemeBreak::Other), (0x11380, GraphemeBreak::ConjunctConsonant), (0x1138A, GraphemeBreak::Other), (0x1138B, GraphemeBreak::ConjunctConsonant),
+    (0x1138C, GraphemeBreak::Other), (0x1138E, GraphemeBreak::ConjunctConsonant), (0x1138F, GraphemeBreak::Other), (0x11390, GraphemeBreak::ConjunctConsonant),
+    (0x113B6, GraphemeBreak::Other), (0x113B8, GraphemeBreak::Extend), (0x113B9, GraphemeBreak::SpacingMark), (0x113BB, GraphemeBreak::Extend),
+    (0x113C1, GraphemeBreak::Other), (0x113C2, GraphemeBreak::Extend), (0x113C3, GraphemeBreak::Other), (0x113C5, GraphemeBreak::Extend),
+    (0x113C6, GraphemeBreak::Other), (0x113C7, GraphemeBreak::Extend), (0x113CA, GraphemeBreak::SpacingMark), (0x113CB, GraphemeBreak::Other),
+    (0x113CC, GraphemeBreak::SpacingMark), (0x113CE, GraphemeBreak::Extend), (0x113D0, GraphemeBreak::ConjunctLinker), (0x113D1, GraphemeBreak::Prepend),
+    (0x113D2, GraphemeBreak::Extend), (0x113D3, GraphemeBreak::Other), (0x113E1, GraphemeBreak::Extend), (0x113E3, GraphemeBreak::Other),
+    (0x11435, GraphemeBreak::SpacingMark), (0x11438, GraphemeBreak::Extend), (0x11440, GraphemeBreak::SpacingMark), (0x11442, GraphemeBreak::Extend),
+    (0x11445, GraphemeBreak::SpacingMark), (0x11446, GraphemeBreak::Extend), (0x11447, GraphemeBreak::Other), (0x1145E, GraphemeBreak::Extend),
+    (0x1145F, GraphemeBreak::Other), (0x114B0, GraphemeBreak::Extend), (0x114B1, GraphemeBreak::SpacingMark), (0x114B3, GraphemeBreak::Extend),
+    (0x114B9, GraphemeBreak::SpacingMark), (0x114BA, GraphemeBreak::Extend), (0x114BB, GraphemeBreak::SpacingMark), (0x114BD, GraphemeBreak::Extend),
+    (0x114BE, GraphemeBreak::SpacingMark), (0x114BF, GraphemeBreak::Extend), (0x114C1, GraphemeBreak::SpacingMark), (0x114C2, GraphemeBreak::Extend),
+    (0x114C4, GraphemeBreak::Other), (0x115AF, GraphemeBreak::Extend), (0x115B0, GraphemeBreak::SpacingMark), (0x115B2, GraphemeBreak::Extend),
+    (0x115B6, GraphemeBreak::Other), (0x115B8, GraphemeBreak::SpacingMark), (0x115BC, GraphemeBreak::Extend), (0x115BE, GraphemeBreak::SpacingMark),
+    (0x115BF, GraphemeBreak::Extend), (0x115C1, GraphemeBreak::Other), (0x115DC, GraphemeBreak::Extend), (0x115DE, GraphemeBreak::Other),
+    (0x11630, GraphemeBreak::SpacingMark), (0x11633, GraphemeBreak::Extend), (0x1163B, GraphemeBreak::SpacingMark), (0x1163D, GraphemeBreak::Extend),
+    (0x1163E, GraphemeBreak::SpacingMark), (0x1163F, GraphemeBreak::Extend), (0x11641, GraphemeBreak::Other), (0x116AB, GraphemeBreak::Extend),
+    (0x116AC, GraphemeBreak::SpacingMark), (0x116AD, GraphemeBreak::Extend), (0x116AE, GraphemeBreak::SpacingMark), (0x116B0, GraphemeBreak::Extend),
+    (0x116B8, GraphemeBreak::Other), (0x1171D, GraphemeBreak::Extend), (0x1171E, GraphemeBreak::SpacingMark), (0x1171F, GraphemeBreak::Extend),
+    (0x11720, GraphemeBreak::Other), (0x11722, GraphemeBreak::Extend), (0x11726, GraphemeBreak::SpacingMark), (0x11727, GraphemeBreak::Extend),
+    (0x1172C, GraphemeBreak::Other), (0x1182C, GraphemeBreak::SpacingMark), (0x1182F, GraphemeBreak::Extend), (0x11838, GraphemeBreak::SpacingMark),
+    (0x11839, GraphemeBreak::Extend), (0x1183B, GraphemeBreak::Other), (0x11900, GraphemeBreak::ConjunctConsonant), (0x11907, GraphemeBreak::Other),
+    (0x11909, GraphemeBreak::ConjunctConsonant), (0x1190A, GraphemeBreak::Other), (0x1190C, GraphemeBreak::ConjunctConsonant), (0x11914, GraphemeBreak::Other),
+    (0x11915, GraphemeBreak::ConjunctConsonant), (0x11917, GraphemeBreak::Other), (0x11918, GraphemeBreak::ConjunctConsonant), (0x11930, GraphemeBreak::Extend),
+    (0x11931, GraphemeBreak::SpacingMark), (0x11936, GraphemeBreak::Other), (0x11937, GraphemeBreak::SpacingMark), (0x11939, GraphemeBreak::Other),
+    (0x1193B, GraphemeBreak::Extend), (0x1193E, GraphemeBreak::ConjunctLinker), (0x1193F, GraphemeBreak::Prepend), (0x11940, GraphemeBreak::SpacingMark),
+    (0x11941, GraphemeBreak::Prepend), (0x11942, GraphemeBreak::SpacingMark), (0x11943, GraphemeBreak::Extend), (0x11944, GraphemeBreak::Other),
+    (0x119D1, GraphemeBreak::SpacingMark), (0x119D4, GraphemeBreak::Extend), (0x119D8, GraphemeBreak::Other), (0x119DA, GraphemeBreak::Extend),
+    (0x119DC, GraphemeBreak::SpacingMark), (0x119E0, GraphemeBreak::Extend), (0x119E1, GraphemeBreak::Other), (0x119E4, GraphemeBreak::SpacingMark),
+    (0x119E5, GraphemeBreak::Other), (0x11A00, GraphemeBreak::ConjunctConsonant), (0x11A01, GraphemeBreak::Extend), (0x11A0B, GraphemeBreak::ConjunctConsonant),
+    (0x11A33, GraphemeBreak::Extend), (0x11A39, GraphemeBreak::SpacingMark), (0x11A3A, GraphemeBreak::Other), (0x11A3B, GraphemeBreak::Extend),
+    (0x11A3F, GraphemeBreak::Other), (0x11A47, GraphemeBreak::ConjunctLinker), (0x11A48, GraphemeBreak::Other), (0x11A50, GraphemeBreak::ConjunctConsonant),
+    (0x11A51, GraphemeBreak::Extend), (0x11A57, GraphemeBreak::SpacingMark), (0x11A59, GraphemeBreak::Extend), (0x11A5C, GraphemeBreak::ConjunctConsonant),
+    (0x11A84, GraphemeBreak::Prepend), (0x11A8A, GraphemeBreak::Extend), (0x11A97, GraphemeBreak::SpacingMark), (0x11A98, GraphemeBreak::Extend),
+    (0x11A99, GraphemeBreak::ConjunctLinker), (0x11A9A, GraphemeBreak::Other), (0x11B60, GraphemeBreak::Extend), (0x11B61, GraphemeBreak::SpacingMark),
+    (0x11B62, GraphemeBreak::Extend), (0x11B65, GraphemeBreak::SpacingMark), (0x11B66, GraphemeBreak::Extend), (0x11B67, GraphemeBreak::SpacingMark),
+    (0x11B68, GraphemeBreak::Other), (0x11C2F, GraphemeBreak::SpacingMark), (0x11C30, GraphemeBreak::Extend), (0x11C37, GraphemeBreak::Other),
+    (0x11C38, GraphemeBreak::Extend), (0x11C3E, GraphemeBreak::SpacingMark), (0x11C3F, GraphemeBreak::Extend), (0x11C40, GraphemeBreak::Other),
+    (0x11C92, GraphemeBreak::Extend), (0x11CA8, GraphemeBreak::Other), (0x11CA9, GraphemeBreak::SpacingMark), (0x11CAA, GraphemeBreak::Extend),
+    (0x11CB1, GraphemeBreak::SpacingMark), (0x11CB2, GraphemeBreak::Extend), (0x11CB4, GraphemeBreak::SpacingMark), (0x11CB5, GraphemeBreak::Extend),
+    (0x11CB7, GraphemeBreak::Other), (0x11D31, GraphemeBreak::Extend), (0x11D37, GraphemeBreak::Other), (0x11D3A, GraphemeBreak::Extend),
+    (0x11D3B, GraphemeBreak::Other), (0x11D3C, GraphemeBreak::Extend), (0x11D3E, GraphemeBreak::Other), (0x11D3F, GraphemeBreak::Extend),
+    (0x11D46, GraphemeBreak::Prepend), (0x11D47, GraphemeBreak::Extend), (0x11D48, GraphemeBreak::Other), (0x11D8A, GraphemeBreak::SpacingMark),
+    (0x11D8F, GraphemeBreak::Other), (0x11D90, GraphemeBreak::Extend), (0x11D92, GraphemeBreak::Other), (0x11D93, GraphemeBreak::SpacingMark),
+    (0x11D95, GraphemeBreak::Extend), (0x11D96, GraphemeBreak::SpacingMark), (0x11D97, GraphemeBreak::Extend), (0x11D98, GraphemeBreak::Other),
+    (0x11EF3, GraphemeBreak::Extend), (0x11EF5, GraphemeBreak::SpacingMark), (0x11EF7, GraphemeBreak::Other), (0x11F00, GraphemeBreak::Extend),
+    (0x11F02, GraphemeBreak::Prepend), (0x11F03, GraphemeBreak::SpacingMark), (0x11F04, GraphemeBreak::ConjunctConsonant), (0x11F11, GraphemeBreak::Other),
+    (0x11F12, GraphemeBreak::ConjunctConsonant), (0x11F34, GraphemeBreak::SpacingMark), (0x11F36, GraphemeBreak::Extend), (0x11F3B, GraphemeBreak::Other),
+    (0x11F3E, GraphemeBreak::SpacingMark), (0x11F40, GraphemeBreak::Extend), (0x11F42, GraphemeBreak::ConjunctLinker), (0x11F43, GraphemeBreak::Other),
+    (0x11F5A, GraphemeBreak::Extend), (0x11F5B, GraphemeBreak::Other), (0x13430, GraphemeBreak::Control), (0x13440, GraphemeBreak::Extend),
+    (0x13441, GraphemeBreak::Other), (0x13447, GraphemeBreak::Extend), (0x13456, GraphemeBreak::Other), (0x1611E, GraphemeBreak::Extend),
+    (0x1612A, GraphemeBreak::SpacingMark), (0x1612D, GraphemeBreak::Extend), (0x16130, GraphemeBreak::Other), (0x16AF0, GraphemeBreak::Extend),
+    (0x16AF5, GraphemeBreak::Other), (0x16B30, GraphemeBreak::Extend), (0x16B37, GraphemeBreak::Other), (0x16D63, GraphemeBreak::V),
+    (0x16D64, GraphemeBreak::Other), (0x16D67, GraphemeBreak::V), (0x16D6B, GraphemeBreak::Other), (0x16F4F, GraphemeBreak::Extend),
+    (0x16F50, GraphemeBreak::Other), (0x16F51, GraphemeBreak::SpacingMark), (0x16F88, GraphemeBreak::Other), (0x16F8F, GraphemeBreak::Extend),
+    (0x16F93, GraphemeBreak::Other), (0x16FE4, GraphemeBreak::Extend), (0x16FE5, GraphemeBreak::Other), (0x16FF0, GraphemeBreak::Extend),
+    (0x16FF2, GraphemeBreak::Other), (0x1BC9D, GraphemeBreak::Extend), (0x1BC9F, GraphemeBreak::Other), (0x1BCA0, GraphemeBreak::Control),
+    (0x1BCA4, GraphemeBreak::Other), (0x1CF00, GraphemeBreak::Extend), (0x1CF2E, GraphemeBreak::Other), (0x1CF30, GraphemeBreak::Extend),
+    (0x1CF47, GraphemeBreak::Other), (0x1D165, GraphemeBreak::Extend), (0x1D16A, GraphemeBreak::Other), (0x1D16D, GraphemeBreak::Extend),
+    (0x1D173, GraphemeBreak::Control), (0x1D17B, GraphemeBreak::Extend), (0x1D183, GraphemeBreak::Other), (0x1D185, GraphemeBreak::Extend),
+    (0x1D18C, GraphemeBreak::Other), (0x1D1AA, GraphemeBreak::Extend), (0x1D1AE, GraphemeBreak::Other), (0x1D242, GraphemeBreak::Extend),
+    (0x1D245, GraphemeBreak::Other), (0x1DA00, GraphemeBreak::Extend), (0x1DA37, GraphemeBreak::Other), (0x1DA3B, GraphemeBreak::Extend),
+    (0x1DA6D, GraphemeBreak::Other), (0x1DA75, GraphemeBreak::Extend), (0x1DA76, GraphemeBreak::Other), (0x1DA84, GraphemeBreak::Extend),
+    (0x1DA85, GraphemeBreak::Other), (0x1DA9B, GraphemeBreak::Extend), (0x1DAA0, GraphemeBreak::Other), (0x1DAA1, GraphemeBreak::Extend),
+    (0x1DAB0, GraphemeBreak::Other), (0x1E000, GraphemeBreak::Extend), (0x1E007, GraphemeBreak::Other), (0x1E008, GraphemeBreak::Extend),
+    (0x1E019, GraphemeBreak::Other), (0x1E01B, GraphemeBreak::Extend), (0x1E022, GraphemeBreak::Other), (0x1E023, GraphemeBreak::Extend),
+    (0x1E025, GraphemeBreak::Other), (0x1E026, GraphemeBreak::Extend), (0x1E02B, GraphemeBreak::Other), (0x1E08F, GraphemeBreak::Extend),
+    (0x1E090, GraphemeBreak::Other), (0x1E130, GraphemeBreak::Extend), (0x1E137, GraphemeBreak::Other), (0x1E2AE, GraphemeBreak::Extend),
+    (0x1E2AF, GraphemeBreak::Other), (0x1E2EC, GraphemeBreak::Extend), (0x1E2F0, GraphemeBreak::Other), (0x1E4EC, GraphemeBreak::Extend),
+    (0x1E4F0, GraphemeBreak::Other), (0x1E5EE, GraphemeBreak::Extend), (0x1E5F0, GraphemeBreak::Other), (0x1E6E3, GraphemeBreak::Extend),
+    (0x1E6E4, GraphemeBreak::Other), (0x1E6E6, GraphemeBreak::Extend), (0x1E6E7, GraphemeBreak::Other), (0x1E6EE, GraphemeBreak::Extend),
+    (0x1E6F0, GraphemeBreak::Other), (0x1E6F5, GraphemeBreak::Extend), (0x1E6F6, GraphemeBreak::Other), (0x1E8D0, GraphemeBreak::Extend),
+    (0x1E8D7, GraphemeBreak::Other), (0x1E944, GraphemeBreak::Extend), (0x1E94B, GraphemeBreak::Other), (0x1F004, GraphemeBreak::ExtendedPictographic),
+    (0x1F005, GraphemeBreak::Other), (0x1F02C, GraphemeBreak::ExtendedPictographic), (0x1F030, GraphemeBreak::Other), (0x1F094, GraphemeBreak::ExtendedPictographic),
+    (0x1F0A0, GraphemeBreak::Other), (0x1F0AF, GraphemeBreak::ExtendedPictographic), (0x1F0B1, GraphemeBreak::Other), (0x1F0C0, GraphemeBreak::ExtendedPictographic),
+    (0x1F0C1, GraphemeBreak::Other), (0x1F0CF, GraphemeBreak::ExtendedPictographic), (0x1F0D1, GraphemeBreak::Other), (0x1F0F6, GraphemeBreak::ExtendedPictographic),
+    (0x1F100, GraphemeBreak::Other), (0x1F170, GraphemeBreak::ExtendedPictographic), (0x1F172, GraphemeBreak::Other), (0x1F17E, GraphemeBreak::ExtendedPictographic),
+    (0x1F180, GraphemeBreak::Other), (0x1F18E, GraphemeBreak::ExtendedPictographic), (0x1F18F, GraphemeBreak::Other), (0x1F191, GraphemeBreak::ExtendedPictographic),
+    (0x1F19B, GraphemeBreak::Other), (0x1F1AE, GraphemeBreak::ExtendedPictographic), (0x1F1E6, GraphemeBreak::RegionalIndicator), (0x1F200, GraphemeBreak::Other),
+    (0x1F201, GraphemeBreak::ExtendedPictographic), (0x1F210, GraphemeBreak::Other), (0x1F21A, GraphemeBreak::ExtendedPictographic), (0x1F21B, GraphemeBreak::Other),
+    (0x1F22F, GraphemeBreak::ExtendedPictographic), (0x1F230, GraphemeBreak::Other), (0x1F232, GraphemeBreak::ExtendedPictographic), (0x1F23B, GraphemeBreak::Other),
+    (0x1F23C, GraphemeBreak::ExtendedPictographic), (0x1F240, GraphemeBreak::Other), (0x1F249, GraphemeBreak::ExtendedPictographic), (0x1F260, GraphemeBreak::Other),
+    (0x1F266, GraphemeBreak::ExtendedPictographic), (0x1F322, GraphemeBreak::Other), (0x1F324, GraphemeBreak::ExtendedPictographic), (0x1F394, GraphemeBreak::Other),
+    (0x1F396, GraphemeBreak::ExtendedPictographic), (0x1F398, GraphemeBreak::Other), (0x1F399, GraphemeBreak::ExtendedPictographic), (0x1F39C, GraphemeBreak::Other),
+    (0x1F39E, GraphemeBreak::ExtendedPictographic), (0x1F3F1, GraphemeBreak::Other), (0x1F3F3, GraphemeBreak::ExtendedPictographic), (0x1F3F6, GraphemeBreak::Other),
+    (0x1F3F7, GraphemeBreak::ExtendedPictographic), (0x1F3FB, GraphemeBreak::Extend), (0x1F400, GraphemeBreak::ExtendedPictographic), (0x1F4FE, GraphemeBreak::Other),
+    (0x1F4FF, GraphemeBreak::ExtendedPictographic), (0x1F53E, GraphemeBreak::Other), (0x1F549, GraphemeBreak::ExtendedPictographic), (0x1F54F, GraphemeBreak::Other),
+    (0x1F550, GraphemeBreak::ExtendedPictographic), (0x1F568, GraphemeBreak::Other), (0x1F56F, GraphemeBreak::ExtendedPictographic), (0x1F571, GraphemeBreak::Other),
+    (0x1F573, GraphemeBreak::ExtendedPictographic), (0x1F57B, GraphemeBreak::Other), (0x1F587, GraphemeBreak::ExtendedPictographic), (0x1F588, GraphemeBreak::Other),
+    (0x1F58A, GraphemeBreak::ExtendedPictographic), (0x1F58E, GraphemeBreak::Other), (0x1F590, GraphemeBreak::ExtendedPictographic), (0x1F591, GraphemeBreak::Other),
+    (0x1F595, GraphemeBreak::ExtendedPictographic), (0x1F597, GraphemeBreak::Other), (0x1F5A4, GraphemeBreak::ExtendedPictographic), (0x1F5A6, GraphemeBreak::Other),
+    (0x1F5A8, GraphemeBreak::ExtendedPictographic), (0x1F5A9, GraphemeBreak::Other), (0x1F5B1, GraphemeBreak::ExtendedPictographic), (0x1F5B3, GraphemeBreak::Other),
+    (0x1F5BC, GraphemeBreak::ExtendedPictographic), (0x1F5BD, GraphemeBreak::Other), (0x1F5C2, GraphemeBreak::ExtendedPictographic), (0x1F5C5, GraphemeBreak::Other),
+    (0x1F5D1, GraphemeBreak::ExtendedPictographic), (0x1F5D4, GraphemeBreak::Other), (0x1F5DC, GraphemeBreak::ExtendedPictographic), (0x1F5DF, GraphemeBreak::Other),
+    (0x1F5E1, GraphemeBreak::ExtendedPictographic), (0x1F5E2, GraphemeBreak::Other), (0x1F5E3, GraphemeBreak::ExtendedPictographic), (0x1F5E4, GraphemeBreak::Other),
+    (0x1F5E8, GraphemeBreak::ExtendedPictographic), (0x1F5E9, GraphemeBreak::Other), (0x1F5EF, GraphemeBreak::ExtendedPictographic), (0x1F5F0, GraphemeBreak::Other),
+    (0x1F5F3, GraphemeBreak::ExtendedPictographic), (0x1F5F4, GraphemeBreak::Other), (0x1F5FA, GraphemeBreak::ExtendedPictographic), (0x1F650, GraphemeBreak::Other),
+    (0x1F680, GraphemeBreak::ExtendedPictographic), (0x1F6C6, GraphemeBreak::Other), (0x1F6CB, GraphemeBreak::ExtendedPictographic), (0x1F6D3, GraphemeBreak::Other),
+    (0x1F6D5, GraphemeBreak::ExtendedPictographic), (0x1F6E6, GraphemeBreak::Other), (0x1F6E9, GraphemeBreak::ExtendedPictographic), (0x1F6EA, GraphemeBreak::Other),
+    (0x1F6EB, GraphemeBreak::ExtendedPictographic), (0x1F6F1, GraphemeBreak::Other), (0x1F6F3, GraphemeBreak::ExtendedPictographic), (0x1F700, GraphemeBreak::Other),
+    (0x1F7DA, GraphemeBreak::ExtendedPictographic), (0x1F800, GraphemeBreak::Other), (0x1F80C, GraphemeBreak::ExtendedPictographic), (0x1F810, GraphemeBreak::Other),
+    (0x1F848, GraphemeBreak::ExtendedPictographic), (0x1F850, GraphemeBreak::Other), (0x1F85A, GraphemeBreak::ExtendedPictographic), (0x1F860, GraphemeBreak::Other),
+    (0x1F888, GraphemeBreak::ExtendedPictographic), (0x1F890, GraphemeBreak::Other), (0x1F8AE, GraphemeBreak::ExtendedPictographic), (0x1F8B0, GraphemeBreak::Other),
+    (0x1F8BC, GraphemeBreak::ExtendedPictographic), (0x1F8C0, GraphemeBreak::Other), (0x1F8C2, GraphemeBreak::ExtendedPictographic), (0x1F8D0, GraphemeBreak::Other),
+    (0x1F8D9, GraphemeBreak::ExtendedPictographic), (0x1F900, GraphemeBreak::Other), (0x1F90C, GraphemeBreak::ExtendedPictographic), (0x1F93B, GraphemeBreak::Other),
+    (0x1F93C, GraphemeBreak::ExtendedPictographic), (0x1F946, GraphemeBreak::Other), (0x1F947, GraphemeBreak::ExtendedPictographic), (0x1FA00, GraphemeBreak::Other),
+    (0x1FA58, GraphemeBreak::ExtendedPictographic), (0x1FA60, GraphemeBreak::Other), (0x1FA6E, GraphemeBreak::ExtendedPictographic), (0x1FB00, GraphemeBreak::Other),
+    (0x1FC00, GraphemeBreak::ExtendedPictographic), (0x1FFFE, GraphemeBreak::Other), (0xE0000, GraphemeBreak::Control), (0xE0020, GraphemeBreak::Extend),
+    (0xE0080, GraphemeBreak::Control), (0xE0100, GraphemeBreak::Extend), (0xE01F0, GraphemeBreak::Control), (0xE1000, GraphemeBreak::Other),
+];
@@ -2,7 +2,7 @@ use crate::libc_declarations::c_char;
 
 /// Panics if the string is not valid UTF-8
 #[unsafe(no_mangle)]
-pub(crate) unsafe extern "C" fn utf8_validate(str: *const c_char, len: usize) {
+pub(crate) unsafe extern "C-unwind" fn utf8_validate(str: *const c_char, len: usize) {
     if !utf8_valid(str, len) {
         crate::rts_trap_with("utf8_validate: string is not UTF-8");
     }
@@ -100,7 +100,7 @@ pub unsafe fn visit_pointer_fields<C, F, G>(
             }
         }
 
-        TAG_CONCAT => {
+        TAG_CONCAT | TAG_COUNTED_CONCAT => {
             let concat = obj as *mut Concat;
             let field1_addr = &mut (*concat).text1;
             if pointer_to_dynamic_heap(field1_addr, heap_base) {
@@ -114,7 +114,7 @@ pub unsafe fn visit_pointer_fields<C, F, G>(
             }
         }
 
-        TAG_CONCAT => {
+        TAG_CONCAT | TAG_COUNTED_CONCAT => {
             let concat = obj as *mut Concat;
             let field1_addr = &mut (*concat).text1;
             if is_non_null_pointer_field(field1_addr) {
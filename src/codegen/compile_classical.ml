@@ -1027,6 +1027,21 @@ module RTS = struct
     add_rts_import "allocation_barrier" [I32Type] [I32Type];
     add_rts_import "stop_gc_on_upgrade" [] [];
     add_rts_import "running_gc" [] [I32Type];
+    add_rts_import "force_incremental_gc" [I64Type] [I64Type];
+    add_rts_import "set_gc_young_generation" [I32Type] [];
+    add_rts_import "get_gc_young_generation" [] [I32Type];
+    add_rts_import "set_gc_scheduling_policy" [I32Type; I32Type; I32Type; I32Type; I32Type; I32Type; I32Type] [];
+    add_rts_import "reset_gc_scheduling_policy" [] [];
+    add_rts_import "get_gc_scheduling_policy_field" [I32Type] [I32Type];
+    add_rts_import "request_heap_snapshot" [] [];
+    add_rts_import "take_heap_snapshot" [] [I32Type];
+    add_rts_import "get_census_object_count" [I32Type] [I32Type];
+    add_rts_import "get_census_object_size" [I32Type] [I32Type];
+    add_rts_import "get_partition_count" [] [I32Type];
+    add_rts_import "get_partition_flags" [I32Type] [I32Type];
+    add_rts_import "get_partition_occupied_size" [I32Type] [I32Type];
+    add_rts_import "get_partition_marked_size" [I32Type] [I32Type];
+    add_rts_import "get_partition_garbage_size" [I32Type] [I32Type];
     ()
 
   let non_incremental_gc_imports env =
@@ -1102,6 +1117,12 @@ module RTS = struct
     add_rts_import "skip_any" [I32Type; I32Type; I32Type; I32Type] [];
     add_rts_import "find_field" [I32Type; I32Type; I32Type; I32Type; I32Type] [I32Type];
     add_rts_import "skip_fields" [I32Type; I32Type; I32Type; I32Type] [];
+    add_rts_import "idl_value_limit_check" [I32Type; I64Type] [];
+    add_rts_import "idl_set_value_limit" [I64Type] [];
+    add_rts_import "idl_start_message" [] [];
+    add_rts_import "idl_decoded_values" [] [I64Type];
+    add_rts_import "idl_skipped_bytes" [] [I64Type];
+    add_rts_import "idl_subtype_cache_size" [] [I64Type];
     add_rts_import "remember_continuation" [I32Type] [I32Type];
     add_rts_import "recall_continuation" [I32Type] [I32Type];
     add_rts_import "peek_future_continuation" [I32Type] [I32Type];
@@ -1113,13 +1134,25 @@ module RTS = struct
     add_rts_import "text_iter_done" [I32Type] [I32Type];
     add_rts_import "text_iter" [I32Type] [I32Type];
     add_rts_import "text_iter_next" [I32Type] [I32Type];
+    add_rts_import "text_grapheme_iter_done" [I32Type] [I32Type];
+    add_rts_import "text_grapheme_iter" [I32Type] [I32Type];
+    add_rts_import "text_grapheme_iter_next" [I32Type] [I32Type];
     add_rts_import "text_len" [I32Type] [I32Type];
+    add_rts_import "text_grapheme_count" [I32Type] [I32Type];
     add_rts_import "text_of_ptr_size" [I32Type; I32Type] [I32Type];
     add_rts_import "text_singleton" [I32Type] [I32Type];
     add_rts_import "text_size" [I32Type] [I32Type];
     add_rts_import "text_to_buf" [I32Type; I32Type] [];
     add_rts_import "text_lowercase" [I32Type] [I32Type];
+    add_rts_import "text_char_at" [I32Type; I32Type] [I32Type];
+    add_rts_import "text_slice" [I32Type; I32Type; I32Type] [I32Type];
+    add_rts_import "text_find" [I32Type; I32Type] [I32Type];
     add_rts_import "text_uppercase" [I32Type] [I32Type];
+    add_rts_import "text_case_fold" [I32Type] [I32Type];
+    add_rts_import "text_nfc" [I32Type] [I32Type];
+    add_rts_import "text_nfd" [I32Type] [I32Type];
+    add_rts_import "text_nfkc" [I32Type] [I32Type];
+    add_rts_import "text_nfkd" [I32Type] [I32Type];
     add_rts_import "region_init" [I32Type] [];
     add_rts_import "alloc_region" [I64Type; I32Type; I32Type] [I32Type];
     add_rts_import "init_region" [I32Type; I64Type; I32Type; I32Type] [];
@@ -1129,6 +1162,12 @@ module RTS = struct
     add_rts_import "region_vec_pages" [I32Type] [I32Type];
     add_rts_import "region_size" [I32Type] [I64Type];
     add_rts_import "region_grow" [I32Type; I64Type] [I64Type];
+    add_rts_import "region_free" [I32Type] [I64Type];
+    add_rts_import "region_shrink" [I32Type; I64Type] [I64Type];
+    add_rts_import "region_copy" [I32Type; I64Type; I32Type; I64Type; I64Type] [];
+    add_rts_import "region_fill" [I32Type; I64Type; I64Type; I32Type] [];
+    add_rts_import "region_checksum" [I32Type; I64Type; I64Type] [I32Type];
+    add_rts_import "region_meta_data_checksum" [] [I32Type];
     add_rts_import "region_load_blob" [I32Type; I64Type; I32Type] [I32Type];
     add_rts_import "region_store_blob" [I32Type; I64Type; I32Type] [];
     add_rts_import "region_load_word8" [I32Type; I64Type] [I32Type];
@@ -1162,8 +1201,10 @@ module RTS = struct
     add_rts_import "fmod" [F64Type; F64Type] [F64Type];
     add_rts_import "fmodf" [F32Type; F32Type] [F32Type];
     add_rts_import "float_fmt" [F64Type; I32Type; I32Type] [I32Type];
+    add_rts_import "float_of_text" [I32Type; I32Type] [I32Type];
     add_rts_import "char_to_upper" [I32Type] [I32Type];
     add_rts_import "char_to_lower" [I32Type] [I32Type];
+    add_rts_import "char_case_fold" [I32Type] [I32Type];
     add_rts_import "char_is_whitespace" [I32Type] [I32Type];
     add_rts_import "char_is_lowercase" [I32Type] [I32Type];
     add_rts_import "char_is_uppercase" [I32Type] [I32Type];
@@ -1984,6 +2025,7 @@ module Tagged = struct
     | OneWordFiller (* Only used by the RTS *)
     | FreeSpace (* Only used by the RTS *)
     | Region
+    | CountedConcat (* String concatenation with character count, used by rts/text.c *)
     | ArraySliceMinimum (* Used by the GC for incremental array marking *)
     | StableSeen (* Marker that we have seen this thing before *)
     | CoercionFailure (* Used in the Candid decoder. Static singleton! *)
@@ -2024,7 +2066,8 @@ module Tagged = struct
     (* RTS-internal *)
     | OneWordFiller -> 49l
     | FreeSpace -> 51l
-    | ArraySliceMinimum -> 52l
+    | CountedConcat -> 53l
+    | ArraySliceMinimum -> 54l
     (* Next two tags won't be seen by the GC, so no need to set the lowest bit
        for `CoercionFailure` and `StableSeen` *)
     | CoercionFailure -> 0xfffffffel
@@ -2996,6 +3039,20 @@ module Float = struct
     Tagged.(sanity_check_tag __LINE__ env (Bits64 F)) ^^
     Tagged.load_field_float64 env (payload_field env)
 
+  let of_text env =
+    Func.share_code1 Func.Never env "Float.of_text" ("text", I32Type) [I32Type] (fun env get_text ->
+      Stack.with_words env "result" 2l (fun get_result ->
+        get_text ^^
+        get_result ^^
+        E.call_rts env "float_of_text" ^^
+        E.if1 I32Type
+          (Opt.inject_simple env (
+            get_result ^^ G.i (Load {ty = F64Type; align = 0; offset = 0L; sz = None}) ^^
+            box env))
+          (Opt.null_lit env)
+      )
+    )
+
 end (* Float *)
 
 
@@ -4566,6 +4623,30 @@ module Region = struct
     E.require_stable_memory env;
     E.call_rts env "region_grow"
 
+  let free env =
+    E.require_stable_memory env;
+    E.call_rts env "region_free"
+
+  let shrink env =
+    E.require_stable_memory env;
+    E.call_rts env "region_shrink"
+
+  let copy env =
+    E.require_stable_memory env;
+    E.call_rts env "region_copy"
+
+  let fill env =
+    E.require_stable_memory env;
+    E.call_rts env "region_fill"
+
+  let checksum env =
+    E.require_stable_memory env;
+    E.call_rts env "region_checksum"
+
+  let meta_data_checksum env =
+    E.require_stable_memory env;
+    E.call_rts env "region_meta_data_checksum"
+
   let load_blob env =
     E.require_stable_memory env;
     E.call_rts env "region_load_blob"
@@ -4617,11 +4698,12 @@ module Text = struct
 
   (* The layout of a concatenation node is
 
-     ┌──────┬─────┬─────────┬───────┬───────┐
-     │ obj header │ n_bytes │ text1 │ text2 │
-     └──────┴─────┴─────────┴───────┴───────┘
+     ┌──────┬─────┬─────────┬───────┬───────┬─────────┐
+     │ obj header │ n_bytes │ text1 │ text2 │ n_chars │
+     └──────┴─────┴─────────┴───────┴───────┴─────────┘
 
-    The object header includes the object tag (TAG_CONCAT defined in rts/types.rs) and the forwarding pointer
+    The object header includes the object tag (TAG_COUNTED_CONCAT defined in rts/types.rs) and the forwarding pointer.
+    Nodes tagged with TAG_CONCAT lack the character count `n_chars`.
     The forwarding pointer is only reserved if compiled for the incremental GC.
 
     This is internal to rts/text.c, with the exception of GC-related code.
@@ -4641,13 +4723,54 @@ module Text = struct
       E.call_rts env "text_len" ^^
       BigNum.from_word32 env
     )
+  let grapheme_count_nat env =
+    Func.share_code1 Func.Never env "text_grapheme_count" ("text", I32Type) [I32Type] (fun env get ->
+      get ^^
+      E.call_rts env "text_grapheme_count" ^^
+      BigNum.from_word32 env
+    )
   let prim_showChar env =
     TaggedSmallWord.lsb_adjust_codepoint env ^^
     E.call_rts env "text_singleton"
   let to_blob env = E.call_rts env "blob_of_text"
 
+  let char_at env =
+    Func.share_code2 Func.Never env "Text.char_at" (("text", I32Type), ("idx", I32Type)) [I32Type] (fun env get_text get_idx ->
+      get_text ^^
+      get_idx ^^ Blob.lit env Tagged.T "Text index out of bounds" ^^ BigNum.to_word32_with env ^^
+      E.call_rts env "text_char_at" ^^
+      TaggedSmallWord.msb_adjust_codepoint
+    )
+
+  let slice env =
+    Func.share_code3 Func.Never env "Text.slice" (("text", I32Type), ("start", I32Type), ("len", I32Type)) [I32Type] (fun env get_text get_start get_len ->
+      get_text ^^
+      get_start ^^ Blob.lit env Tagged.T "Text index out of bounds" ^^ BigNum.to_word32_with env ^^
+      get_len ^^ Blob.lit env Tagged.T "Text slice length out of bounds" ^^ BigNum.to_word32_with env ^^
+      E.call_rts env "text_slice"
+    )
+
+  let find env =
+    Func.share_code2 Func.Never env "Text.find" (("text", I32Type), ("pattern", I32Type)) [I32Type] (fun env get_text get_pattern ->
+      let (set_idx, get_idx) = new_local env "idx" in
+      get_text ^^
+      get_pattern ^^
+      E.call_rts env "text_find" ^^
+      set_idx ^^
+      get_idx ^^ compile_unboxed_const (-1l) ^^
+      compile_comparison I32Op.Eq ^^
+      E.if1 I32Type
+        (Opt.null_lit env)
+        (Opt.inject_simple env (get_idx ^^ BigNum.from_word32 env))
+    )
+
   let lowercase env = E.call_rts env "text_lowercase"
   let uppercase env = E.call_rts env "text_uppercase"
+  let case_fold env = E.call_rts env "text_case_fold"
+  let nfc env = E.call_rts env "text_nfc"
+  let nfd env = E.call_rts env "text_nfd"
+  let nfkc env = E.call_rts env "text_nfkc"
+  let nfkd env = E.call_rts env "text_nfkd"
 
   let of_blob env =
     let (set_blob, get_blob) = new_local env "blob" in
@@ -4668,6 +4791,12 @@ module Text = struct
   let iter_next env =
     E.call_rts env "text_iter_next" ^^
     TaggedSmallWord.msb_adjust_codepoint
+  let grapheme_iter env =
+    E.call_rts env "text_grapheme_iter"
+  let grapheme_iter_done env =
+    E.call_rts env "text_grapheme_iter_done"
+  let grapheme_iter_next env =
+    E.call_rts env "text_grapheme_iter_next"
 
   let compare env op =
     let open Operator in
@@ -5359,6 +5488,7 @@ module IC = struct
     assert (E.mode env = Flags.ICMode || E.mode env = Flags.RefMode);
     let fi = E.add_fun env "canister_inspect_message"
       (Func.of_body env [] [] (fun env ->
+        E.call_rts env "idl_start_message" ^^
         G.i (Call (nr (E.built_in env "inspect_exp"))) ^^
         system_call env "accept_message" (* assumes inspect_exp traps to reject *)
         (* no need to GC !*)))
@@ -5674,6 +5804,7 @@ let env_var_names env =
 
   let async_method_name = Type.(motoko_async_helper_fld.lab)
   let gc_trigger_method_name = Type.(motoko_gc_trigger_fld.lab)
+  let full_gc_method_name = Type.(motoko_full_gc_fld.lab)
 
   let is_self_call env =
     let (set_len_self, get_len_self) = new_local env "len_self" in
@@ -6535,6 +6666,23 @@ module RTS_Exports = struct
       edesc = nr (FuncExport (nr keep_memory_reserve_fi))
     });
 
+    let ic0_performance_counter_fi =
+      match E.mode env with
+      | Flags.ICMode | Flags.RefMode ->
+        E.reuse_import env "ic0" "performance_counter"
+      | Flags.WASIMode | Flags.WasmMode ->
+        E.add_fun env "ic0_performance_counter" (
+            Func.of_body env ["number", I32Type] [I64Type]
+              (fun env ->
+                E.trap_with env "ic0_performance_counter is not supposed to be called without the IC system API"
+              )
+          )
+    in
+    E.add_export env (nr {
+      name = Lib.Utf8.decode "ic0_performance_counter";
+      edesc = nr (FuncExport (nr ic0_performance_counter_fi))
+    });
+
     if !Flags.gc_strategy <> Flags.Incremental then
     begin
       let set_hp_fi =
@@ -7000,8 +7148,10 @@ module MakeSerialization (Strm : Stream) = struct
           G.nop
         end)
 
+    (* Checks the quota of `idl_limit_check` through the RTS, which also accounts the
+       decoded values and checks the per-call limit, see `idl_value_limit_check` *)
     let idl_limit_check env =
-      G.i (Call (nr (E.built_in env "idl_limit_check")))
+      E.call_rts env "idl_value_limit_check"
 
   end
 
@@ -9707,7 +9857,10 @@ module FuncDec = struct
       closure_codeW (mk_body env ae2)
     ))
 
-  let message_start env sort = match sort with
+  let message_start env sort =
+    (* Candid decoding statistics and value limits only apply within a message *)
+    E.call_rts env "idl_start_message" ^^
+    match sort with
       | Type.(Shared Write) ->
         Lifecycle.(trans env InUpdate)
       | Type.(Shared Query) ->
@@ -10172,6 +10325,37 @@ module FuncDec = struct
     | _ -> ()
     end
 
+  let export_full_gc_method env =
+    let name = IC.full_gc_method_name in
+    begin match E.mode env with
+    | (Flags.ICMode | Flags.RefMode) when !Flags.gc_strategy = Flags.Incremental ->
+      Func.define_built_in env name [] [] (fun env ->
+        let set_reclaimed, get_reclaimed = new_local64 env "reclaimed" in
+        message_start env (Type.Shared Type.Write) ^^
+        IC.assert_caller_self_or_controller env ^^
+        (* Like the GC trigger, ignore the argument. The full GC runs on an
+           empty call stack, capped at the instruction limit of update calls.
+           If the cap is reached, the scheduled GC increments continue the run. *)
+        GC.record_mutator_instructions env ^^
+        compile_const_64 Flags.(!stabilization_instruction_limit.update_call) ^^
+        E.call_rts env "force_incremental_gc" ^^
+        set_reclaimed ^^
+        GC.record_collector_instructions env ^^
+        (* Reply with the reclaimed bytes after the collection *)
+        get_reclaimed ^^ BigNum.from_word64 env ^^
+        Serialization.serialize env Type.[nat] ^^
+        IC.reply_with_data env ^^
+        Lifecycle.trans env Lifecycle.Idle
+      );
+
+      let fi = E.built_in env name in
+      E.add_export env (nr {
+        name = Lib.Utf8.decode ("canister_update " ^ name);
+        edesc = nr (FuncExport (nr fi))
+      })
+    | _ -> ()
+    end
+
 end (* FuncDec *)
 
 
@@ -11780,6 +11964,14 @@ and compile_prim_invocation (env : E.t) ae p es at =
     SR.bool, compile_exp_vanilla env ae e ^^ Text.iter_done env
   | OtherPrim "text_iter_next", [e] ->
     SR.UnboxedWord32 Type.Char, compile_exp_vanilla env ae e ^^ Text.iter_next env
+  | OtherPrim "text_grapheme_count", [e] ->
+    SR.Vanilla, compile_exp_vanilla env ae e ^^ Text.grapheme_count_nat env
+  | OtherPrim "text_grapheme_iter", [e] ->
+    SR.Vanilla, compile_exp_vanilla env ae e ^^ Text.grapheme_iter env
+  | OtherPrim "text_grapheme_iter_done", [e] ->
+    SR.bool, compile_exp_vanilla env ae e ^^ Text.grapheme_iter_done env
+  | OtherPrim "text_grapheme_iter_next", [e] ->
+    SR.Vanilla, compile_exp_vanilla env ae e ^^ Text.grapheme_iter_next env
   | OtherPrim "text_compare", [e1; e2] ->
     SR.UnboxedWord32 Type.Int8,
     compile_exp_vanilla env ae e1 ^^
@@ -11941,6 +12133,11 @@ and compile_prim_invocation (env : E.t) ae p es at =
     compile_exp_vanilla env ae mode ^^
     E.call_rts env "float_fmt"
 
+  | OtherPrim "float_of_text", [e] ->
+    SR.Vanilla,
+    compile_exp_vanilla env ae e ^^
+    Float.of_text env
+
   | OtherPrim "fsin", [e] ->
     SR.UnboxedFloat64,
     compile_exp_as env ae SR.UnboxedFloat64 e ^^
@@ -12061,6 +12258,94 @@ and compile_prim_invocation (env : E.t) ae p es at =
     SR.Vanilla,
     StableMem.get_mem_size env ^^ BigNum.from_word64 env
 
+  | OtherPrim "set_gc_young_generation", [e] ->
+    SR.unit,
+    compile_exp_vanilla env ae e ^^
+    if !Flags.gc_strategy = Flags.Incremental then
+      Blob.lit env Tagged.T "Invalid young generation size" ^^ BigNum.to_word32_with env ^^
+      E.call_rts env "set_gc_young_generation"
+    else
+      E.trap_with env "The young generation requires the incremental GC"
+
+  | OtherPrim "get_gc_young_generation", [] ->
+    SR.Vanilla,
+    if !Flags.gc_strategy = Flags.Incremental then
+      E.call_rts env "get_gc_young_generation" ^^ Prim.prim_word32toNat env
+    else
+      E.trap_with env "The young generation requires the incremental GC"
+
+  | OtherPrim "set_gc_scheduling_policy", ([_; _; _; _; _; _; _] as es) ->
+    SR.unit,
+    if !Flags.gc_strategy = Flags.Incremental then
+      G.concat_map (fun e ->
+        compile_exp_vanilla env ae e ^^
+        Blob.lit env Tagged.T "Invalid GC scheduling policy" ^^ BigNum.to_word32_with env
+      ) es ^^
+      E.call_rts env "set_gc_scheduling_policy"
+    else
+      E.trap_with env "The GC scheduling policy requires the incremental GC"
+
+  | OtherPrim "reset_gc_scheduling_policy", [] ->
+    SR.unit,
+    if !Flags.gc_strategy = Flags.Incremental then
+      E.call_rts env "reset_gc_scheduling_policy"
+    else
+      E.trap_with env "The GC scheduling policy requires the incremental GC"
+
+  | OtherPrim "get_gc_scheduling_policy", [] ->
+    SR.UnboxedTuple 7,
+    if !Flags.gc_strategy = Flags.Incremental then
+      G.concat (Lib.List.table 7 (fun index ->
+        compile_unboxed_const (Int32.of_int index) ^^
+        E.call_rts env "get_gc_scheduling_policy_field" ^^
+        Prim.prim_word32toNat env
+      ))
+    else
+      E.trap_with env "The GC scheduling policy requires the incremental GC"
+
+  | OtherPrim "request_heap_snapshot", [] ->
+    SR.unit,
+    if !Flags.gc_strategy = Flags.Incremental then
+      E.call_rts env "request_heap_snapshot"
+    else
+      E.trap_with env "Heap snapshots require the incremental GC"
+
+  | OtherPrim "take_heap_snapshot", [] ->
+    SR.Vanilla,
+    if !Flags.gc_strategy = Flags.Incremental then
+      E.call_rts env "take_heap_snapshot"
+    else
+      E.trap_with env "Heap snapshots require the incremental GC"
+
+  | OtherPrim ("rts_census_object_count" | "rts_census_object_size" as p), [e] ->
+    (* RTS function names: "get_census_object_*" *)
+    SR.Vanilla,
+    compile_exp_vanilla env ae e ^^
+    if !Flags.gc_strategy = Flags.Incremental then
+      Blob.lit env Tagged.T "Invalid census tag" ^^ BigNum.to_word32_with env ^^
+      E.call_rts env ("get_" ^ String.sub p 4 (String.length p - 4)) ^^
+      Prim.prim_word32toNat env
+    else
+      E.trap_with env "The heap census requires the incremental GC"
+
+  | OtherPrim "rts_partition_count", [] ->
+    SR.Vanilla,
+    if !Flags.gc_strategy = Flags.Incremental then
+      E.call_rts env "get_partition_count" ^^ Prim.prim_word32toNat env
+    else
+      E.trap_with env "Heap partitions require the incremental GC"
+
+  | OtherPrim ("rts_partition_flags" | "rts_partition_occupied_size" | "rts_partition_marked_size" | "rts_partition_garbage_size" as p), [e] ->
+    (* RTS function names: "get_partition_*" *)
+    SR.Vanilla,
+    compile_exp_vanilla env ae e ^^
+    if !Flags.gc_strategy = Flags.Incremental then
+      Blob.lit env Tagged.T "Invalid partition index" ^^ BigNum.to_word32_with env ^^
+      E.call_rts env ("get_" ^ String.sub p 4 (String.length p - 4)) ^^
+      Prim.prim_word32toNat env
+    else
+      E.trap_with env "Heap partitions require the incremental GC"
+
   | OtherPrim "rts_in_upgrade", [] -> (* classical specific *)
     assert (not !Flags.enhanced_orthogonal_persistence);
     SR.Vanilla,
@@ -12126,6 +12411,46 @@ and compile_prim_invocation (env : E.t) ae p es at =
     compile_exp_as env ae (SR.UnboxedWord64 Type.Nat64) e1 ^^
     Region.grow env
 
+  | OtherPrim "regionFree", [e0] ->
+    SR.UnboxedWord64 Type.Nat64,
+    compile_exp_as env ae SR.Vanilla e0 ^^
+    Region.free env
+
+  | OtherPrim "regionShrink", [e0; e1] ->
+    SR.UnboxedWord64 Type.Nat64,
+    compile_exp_as env ae SR.Vanilla e0 ^^
+    compile_exp_as env ae (SR.UnboxedWord64 Type.Nat64) e1 ^^
+    Region.shrink env
+
+  | OtherPrim "regionCopy", [e0; e1; e2; e3; e4] ->
+    SR.unit,
+    compile_exp_as env ae SR.Vanilla e0 ^^
+    compile_exp_as env ae (SR.UnboxedWord64 Type.Nat64) e1 ^^
+    compile_exp_as env ae SR.Vanilla e2 ^^
+    compile_exp_as env ae (SR.UnboxedWord64 Type.Nat64) e3 ^^
+    compile_exp_as env ae (SR.UnboxedWord64 Type.Nat64) e4 ^^
+    Region.copy env
+
+  | OtherPrim "regionFill", [e0; e1; e2; e3] ->
+    SR.unit,
+    compile_exp_as env ae SR.Vanilla e0 ^^
+    compile_exp_as env ae (SR.UnboxedWord64 Type.Nat64) e1 ^^
+    compile_exp_as env ae (SR.UnboxedWord64 Type.Nat64) e2 ^^
+    compile_exp_as env ae (SR.UnboxedWord32 Type.Nat8) e3 ^^
+    TaggedSmallWord.lsb_adjust Type.Nat8 ^^
+    Region.fill env
+
+  | OtherPrim "regionChecksum", [e0; e1; e2] ->
+    SR.UnboxedWord32 Type.Nat32,
+    compile_exp_as env ae SR.Vanilla e0 ^^
+    compile_exp_as env ae (SR.UnboxedWord64 Type.Nat64) e1 ^^
+    compile_exp_as env ae (SR.UnboxedWord64 Type.Nat64) e2 ^^
+    Region.checksum env
+
+  | OtherPrim "regionMetaDataChecksum", [] ->
+    SR.UnboxedWord32 Type.Nat32,
+    Region.meta_data_checksum env
+
   | OtherPrim "regionSize", [e0] ->
     SR.UnboxedWord64 Type.Nat64,
     compile_exp_as env ae SR.Vanilla e0 ^^
@@ -12362,6 +12687,9 @@ and compile_prim_invocation (env : E.t) ae p es at =
   | OtherPrim "char_to_lower", [e] ->
     compile_char_to_char_rts env ae e "char_to_lower"
 
+  | OtherPrim "char_case_fold", [e] ->
+    compile_char_to_char_rts env ae e "char_case_fold"
+
   | OtherPrim "char_is_whitespace", [e] ->
     compile_char_to_bool_rts env ae e "char_is_whitespace"
 
@@ -12389,6 +12717,50 @@ and compile_prim_invocation (env : E.t) ae p es at =
     compile_exp_vanilla env ae e ^^
     Text.uppercase env
 
+  | OtherPrim "text_case_fold", [e] ->
+    SR.Vanilla,
+    compile_exp_vanilla env ae e ^^
+    Text.case_fold env
+
+  | OtherPrim "text_nfc", [e] ->
+    SR.Vanilla,
+    compile_exp_vanilla env ae e ^^
+    Text.nfc env
+
+  | OtherPrim "text_nfd", [e] ->
+    SR.Vanilla,
+    compile_exp_vanilla env ae e ^^
+    Text.nfd env
+
+  | OtherPrim "text_nfkc", [e] ->
+    SR.Vanilla,
+    compile_exp_vanilla env ae e ^^
+    Text.nfkc env
+
+  | OtherPrim "text_nfkd", [e] ->
+    SR.Vanilla,
+    compile_exp_vanilla env ae e ^^
+    Text.nfkd env
+
+  | OtherPrim "text_char_at", [e1; e2] ->
+    SR.UnboxedWord32 Type.Char,
+    compile_exp_vanilla env ae e1 ^^
+    compile_exp_vanilla env ae e2 ^^
+    Text.char_at env
+
+  | OtherPrim "text_slice", [e1; e2; e3] ->
+    SR.Vanilla,
+    compile_exp_vanilla env ae e1 ^^
+    compile_exp_vanilla env ae e2 ^^
+    compile_exp_vanilla env ae e3 ^^
+    Text.slice env
+
+  | OtherPrim "text_find", [e1; e2] ->
+    SR.Vanilla,
+    compile_exp_vanilla env ae e1 ^^
+    compile_exp_vanilla env ae e2 ^^
+    Text.find env
+
   | OtherPrim "performanceCounter", [e] ->
     (SR.UnboxedWord64 Type.Nat64),
     compile_exp_as env ae (SR.UnboxedWord32 Type.Nat32) e ^^
@@ -12581,6 +12953,20 @@ and compile_prim_invocation (env : E.t) ae p es at =
     Serialization.Registers.get_type_bias env ^^
     BoxedSmallWord.box env Type.Nat32
 
+  | OtherPrim "setCandidValueLimit", [e] ->
+    SR.unit,
+    compile_exp_as env ae (SR.UnboxedWord64 Type.Nat64) e ^^
+    E.call_rts env "idl_set_value_limit"
+
+  | OtherPrim "getCandidDecodingStats", [] ->
+    SR.UnboxedTuple 3,
+    E.call_rts env "idl_decoded_values" ^^
+    BoxedWord64.box env Type.Nat64 ^^
+    E.call_rts env "idl_skipped_bytes" ^^
+    BoxedWord64.box env Type.Nat64 ^^
+    E.call_rts env "idl_subtype_cache_size" ^^
+    BoxedWord64.box env Type.Nat64
+
   (* dummy get_migrations function to handle case when a compile classical program
   tries to upgrade but in desugar.ml we need to generate code
   that checks get_migrations. *)
@@ -13642,6 +14028,7 @@ and conclude_module env set_serialization_globals start_fi_o =
 
   FuncDec.export_async_method env;
   FuncDec.export_gc_trigger_method env;
+  FuncDec.export_full_gc_method env;
 
   (* See Note [Candid subtype checks] *)
   Serialization.set_delayed_globals env set_serialization_globals;
@@ -1132,6 +1132,21 @@ module RTS = struct
     add_rts_import "write_with_barrier" [I64Type; I64Type] [];
     add_rts_import "allocation_barrier" [I64Type] [I64Type];
     add_rts_import "running_gc" [] [I32Type];
+    add_rts_import "force_incremental_gc" [I64Type] [I64Type];
+    add_rts_import "set_gc_young_generation" [I64Type] [];
+    add_rts_import "get_gc_young_generation" [] [I64Type];
+    add_rts_import "set_gc_scheduling_policy" [I64Type; I64Type; I64Type; I64Type; I64Type; I64Type; I64Type] [];
+    add_rts_import "reset_gc_scheduling_policy" [] [];
+    add_rts_import "get_gc_scheduling_policy_field" [I64Type] [I64Type];
+    add_rts_import "request_heap_snapshot" [] [];
+    add_rts_import "take_heap_snapshot" [] [I64Type];
+    add_rts_import "get_census_object_count" [I64Type] [I64Type];
+    add_rts_import "get_census_object_size" [I64Type] [I64Type];
+    add_rts_import "get_partition_count" [] [I64Type];
+    add_rts_import "get_partition_flags" [I64Type] [I64Type];
+    add_rts_import "get_partition_occupied_size" [I64Type] [I64Type];
+    add_rts_import "get_partition_marked_size" [I64Type] [I64Type];
+    add_rts_import "get_partition_garbage_size" [I64Type] [I64Type];
     add_rts_import "register_stable_type" [I64Type; I64Type] [];
     add_rts_import "assign_stable_type" [I64Type; I64Type] [];
     add_rts_import "has_stable_actor" [] [I32Type];
@@ -1196,6 +1211,12 @@ module RTS = struct
     add_rts_import "skip_any" [I64Type; I64Type; I32Type; I32Type] [];
     add_rts_import "find_field" [I64Type; I64Type; I64Type; I32Type; I64Type] [I32Type];
     add_rts_import "skip_fields" [I64Type; I64Type; I64Type; I64Type] [];
+    add_rts_import "idl_value_limit_check" [I32Type; I64Type] [];
+    add_rts_import "idl_set_value_limit" [I64Type] [];
+    add_rts_import "idl_start_message" [] [];
+    add_rts_import "idl_decoded_values" [] [I64Type];
+    add_rts_import "idl_skipped_bytes" [] [I64Type];
+    add_rts_import "idl_subtype_cache_size" [] [I64Type];
     add_rts_import "remember_continuation" [I64Type] [I64Type];
     add_rts_import "recall_continuation" [I64Type] [I64Type];
     add_rts_import "peek_future_continuation" [I64Type] [I64Type];
@@ -1207,13 +1228,25 @@ module RTS = struct
     add_rts_import "text_iter_done" [I64Type] [I64Type];
     add_rts_import "text_iter" [I64Type] [I64Type];
     add_rts_import "text_iter_next" [I64Type] [I32Type];
+    add_rts_import "text_grapheme_iter_done" [I64Type] [I64Type];
+    add_rts_import "text_grapheme_iter" [I64Type] [I64Type];
+    add_rts_import "text_grapheme_iter_next" [I64Type] [I64Type];
     add_rts_import "text_len" [I64Type] [I64Type];
+    add_rts_import "text_grapheme_count" [I64Type] [I64Type];
     add_rts_import "text_of_ptr_size" [I64Type; I64Type] [I64Type];
     add_rts_import "text_singleton" [I32Type] [I64Type];
     add_rts_import "text_size" [I64Type] [I64Type];
     add_rts_import "text_to_buf" [I64Type; I64Type] [];
     add_rts_import "text_lowercase" [I64Type] [I64Type];
+    add_rts_import "text_char_at" [I64Type; I64Type] [I32Type];
+    add_rts_import "text_slice" [I64Type; I64Type; I64Type] [I64Type];
+    add_rts_import "text_find" [I64Type; I64Type] [I64Type];
     add_rts_import "text_uppercase" [I64Type] [I64Type];
+    add_rts_import "text_case_fold" [I64Type] [I64Type];
+    add_rts_import "text_nfc" [I64Type] [I64Type];
+    add_rts_import "text_nfd" [I64Type] [I64Type];
+    add_rts_import "text_nfkc" [I64Type] [I64Type];
+    add_rts_import "text_nfkd" [I64Type] [I64Type];
     add_rts_import "region_init" [I64Type] [];
     add_rts_import "alloc_region" [I64Type; I64Type; I64Type] [I64Type];
     add_rts_import "init_region" [I64Type; I64Type; I64Type; I64Type] [];
@@ -1223,6 +1256,12 @@ module RTS = struct
     add_rts_import "region_vec_pages" [I64Type] [I64Type];
     add_rts_import "region_size" [I64Type] [I64Type];
     add_rts_import "region_grow" [I64Type; I64Type] [I64Type];
+    add_rts_import "region_free" [I64Type] [I64Type];
+    add_rts_import "region_shrink" [I64Type; I64Type] [I64Type];
+    add_rts_import "region_copy" [I64Type; I64Type; I64Type; I64Type; I64Type] [];
+    add_rts_import "region_fill" [I64Type; I64Type; I64Type; I32Type] [];
+    add_rts_import "region_checksum" [I64Type; I64Type; I64Type] [I32Type];
+    add_rts_import "region_meta_data_checksum" [] [I32Type];
     add_rts_import "region_load_blob" [I64Type; I64Type; I64Type] [I64Type];
     add_rts_import "region_store_blob" [I64Type; I64Type; I64Type] [];
     add_rts_import "region_load_word8" [I64Type; I64Type] [I32Type];
@@ -1256,8 +1295,10 @@ module RTS = struct
     add_rts_import "fmod" [F64Type; F64Type] [F64Type];
     add_rts_import "fmodf" [F32Type; F32Type] [F32Type];
     add_rts_import "float_fmt" [F64Type; I64Type; I64Type] [I64Type];
+    add_rts_import "float_of_text" [I64Type; I64Type] [I64Type];
     add_rts_import "char_to_upper" [I32Type] [I32Type];
     add_rts_import "char_to_lower" [I32Type] [I32Type];
+    add_rts_import "char_case_fold" [I32Type] [I32Type];
     add_rts_import "char_is_whitespace" [I32Type] [I32Type];
     add_rts_import "char_is_lowercase" [I32Type] [I32Type];
     add_rts_import "char_is_uppercase" [I32Type] [I32Type];
@@ -1951,6 +1992,7 @@ module Tagged = struct
     | FreeSpace (* Only used by the RTS *)
     | Region
     | WeakRef
+    | CountedConcat (* String concatenation with character count, used by rts/text.c *)
     | ArraySliceMinimum (* Used by the GC for incremental array marking *)
     | StableSeen (* Marker that we have seen this thing before *)
     | CoercionFailure (* Used in the Candid decoder. Static singleton! *)
@@ -1984,7 +2026,8 @@ module Tagged = struct
     | OneWordFiller -> 41L
     | FreeSpace -> 43L
     | WeakRef -> 45L
-    | ArraySliceMinimum -> 46L
+    | CountedConcat -> 47L
+    | ArraySliceMinimum -> 48L
     (* Next two tags won't be seen by the GC, so no need to set the lowest bit
        for `CoercionFailure` and `StableSeen` *)
     | CoercionFailure -> 0xffff_ffff_ffff_fffeL
@@ -2871,6 +2914,20 @@ module Float = struct
     compile_unboxed_const f ^^
     box env)
 
+  let of_text env =
+    Func.share_code1 Func.Never env "Float.of_text" ("text", I64Type) [I64Type] (fun env get_text ->
+      Stack.with_words env "result" 1L (fun get_result ->
+        get_text ^^
+        get_result ^^
+        E.call_rts env "float_of_text" ^^
+        E.if1 I64Type
+          (Opt.inject_simple env (
+            get_result ^^ G.i (Load {ty = F64Type; align = 0; offset = 0L; sz = None}) ^^
+            box env))
+          (Opt.null_lit env)
+      )
+    )
+
 end (* Float *)
 
 
@@ -4324,6 +4381,30 @@ module Region = struct
     E.require_stable_memory env;
     E.call_rts env "region_grow"
 
+  let free env =
+    E.require_stable_memory env;
+    E.call_rts env "region_free"
+
+  let shrink env =
+    E.require_stable_memory env;
+    E.call_rts env "region_shrink"
+
+  let copy env =
+    E.require_stable_memory env;
+    E.call_rts env "region_copy"
+
+  let fill env =
+    E.require_stable_memory env;
+    E.call_rts env "region_fill"
+
+  let checksum env =
+    E.require_stable_memory env;
+    E.call_rts env "region_checksum"
+
+  let meta_data_checksum env =
+    E.require_stable_memory env;
+    E.call_rts env "region_meta_data_checksum"
+
   let load_blob env =
     E.require_stable_memory env;
     E.call_rts env "region_load_blob"
@@ -4375,11 +4456,12 @@ module Text = struct
 
   (* The layout of a concatenation node is
 
-     ┌──────┬─────┬─────────┬───────┬───────┐
-     │ obj header │ n_bytes │ text1 │ text2 │
-     └──────┴─────┴─────────┴───────┴───────┘
+     ┌──────┬─────┬─────────┬───────┬───────┬─────────┐
+     │ obj header │ n_bytes │ text1 │ text2 │ n_chars │
+     └──────┴─────┴─────────┴───────┴───────┴─────────┘
 
-    The object header includes the object tag (TAG_CONCAT defined in rts/types.rs) and the forwarding pointer
+    The object header includes the object tag (TAG_COUNTED_CONCAT defined in rts/types.rs) and the forwarding pointer.
+    Nodes tagged with TAG_CONCAT lack the character count `n_chars`.
 
     This is internal to rts/text.c, with the exception of GC-related code.
   *)
@@ -4398,14 +4480,55 @@ module Text = struct
       E.call_rts env "text_len" ^^
       BigNum.from_word64 env
     )
+  let grapheme_count_nat env =
+    Func.share_code1 Func.Never env "text_grapheme_count" ("text", I64Type) [I64Type] (fun env get ->
+      get ^^
+      E.call_rts env "text_grapheme_count" ^^
+      BigNum.from_word64 env
+    )
   let prim_showChar env =
     TaggedSmallWord.lsb_adjust_codepoint env ^^
     G.i (Convert (Wasm_exts.Values.I32 I32Op.WrapI64)) ^^
     E.call_rts env "text_singleton"
   let to_blob env = E.call_rts env "blob_of_text"
 
+  let char_at env =
+    Func.share_code2 Func.Never env "Text.char_at" (("text", I64Type), ("idx", I64Type)) [I64Type] (fun env get_text get_idx ->
+      get_text ^^
+      get_idx ^^ BigNum.to_word64_with env (Blob.lit env Tagged.T "Text index out of bounds") ^^
+      E.call_rts env "text_char_at" ^^ Bool.from_rts_int32 ^^
+      TaggedSmallWord.msb_adjust_codepoint
+    )
+
+  let slice env =
+    Func.share_code3 Func.Never env "Text.slice" (("text", I64Type), ("start", I64Type), ("len", I64Type)) [I64Type] (fun env get_text get_start get_len ->
+      get_text ^^
+      get_start ^^ BigNum.to_word64_with env (Blob.lit env Tagged.T "Text index out of bounds") ^^
+      get_len ^^ BigNum.to_word64_with env (Blob.lit env Tagged.T "Text slice length out of bounds") ^^
+      E.call_rts env "text_slice"
+    )
+
+  let find env =
+    Func.share_code2 Func.Never env "Text.find" (("text", I64Type), ("pattern", I64Type)) [I64Type] (fun env get_text get_pattern ->
+      let (set_idx, get_idx) = new_local env "idx" in
+      get_text ^^
+      get_pattern ^^
+      E.call_rts env "text_find" ^^
+      set_idx ^^
+      get_idx ^^ compile_unboxed_const (-1L) ^^
+      compile_comparison I64Op.Eq ^^
+      E.if1 I64Type
+        (Opt.null_lit env)
+        (Opt.inject_simple env (get_idx ^^ BigNum.from_word64 env))
+    )
+
   let lowercase env = E.call_rts env "text_lowercase"
   let uppercase env = E.call_rts env "text_uppercase"
+  let case_fold env = E.call_rts env "text_case_fold"
+  let nfc env = E.call_rts env "text_nfc"
+  let nfd env = E.call_rts env "text_nfd"
+  let nfkc env = E.call_rts env "text_nfkc"
+  let nfkd env = E.call_rts env "text_nfkd"
 
   let of_blob env =
     let (set_blob, get_blob) = new_local env "blob" in
@@ -4426,6 +4549,12 @@ module Text = struct
   let iter_next env =
     E.call_rts env "text_iter_next" ^^ Bool.from_rts_int32 ^^
     TaggedSmallWord.msb_adjust_codepoint
+  let grapheme_iter env =
+    E.call_rts env "text_grapheme_iter"
+  let grapheme_iter_done env =
+    E.call_rts env "text_grapheme_iter_done"
+  let grapheme_iter_next env =
+    E.call_rts env "text_grapheme_iter_next"
 
   let compare env op =
     let open Operator in
@@ -5177,6 +5306,7 @@ module IC = struct
     assert (E.mode env = Flags.ICMode || E.mode env = Flags.RefMode);
     let fi = E.add_fun env "canister_inspect_message"
       (Func.of_body env [] [] (fun env ->
+        E.call_rts env "idl_start_message" ^^
         G.i (Call (nr (E.built_in env "inspect_exp"))) ^^
         system_call env "accept_message" (* assumes inspect_exp traps to reject *)
         (* no need to GC !*)))
@@ -5250,6 +5380,8 @@ module IC = struct
 
     let post_upgrade_fi = E.add_fun env "post_upgrade" (Func.of_body env [] [] (fun env ->
       compile_unboxed_one ^^ set_run_post_upgrade env ^^
+      (* The RTS state persists, including the Candid decoding statistics *)
+      E.call_rts env "idl_start_message" ^^
       Lifecycle.trans env Lifecycle.InInit ^^
       G.i (Call (nr (E.built_in env "init")))
       (* The post upgrade hook is called later after the completed destabilization,
@@ -5529,6 +5661,7 @@ module IC = struct
 
   let async_method_name = Type.(motoko_async_helper_fld.lab)
   let gc_trigger_method_name = Type.(motoko_gc_trigger_fld.lab)
+  let full_gc_method_name = Type.(motoko_full_gc_fld.lab)
 
   let is_self_call env =
     let (set_len_self, get_len_self) = new_local env "len_self" in
@@ -7392,8 +7525,10 @@ module Serialization = struct
           G.nop
         end)
 
+    (* Checks the quota of `idl_limit_check` through the RTS, which also accounts the
+       decoded values and checks the per-call limit, see `idl_value_limit_check` *)
     let idl_limit_check env =
-      G.i (Call (nr (E.built_in env "idl_limit_check")))
+      E.call_rts env "idl_value_limit_check"
 
   end
 
@@ -9744,7 +9879,10 @@ module FuncDec = struct
       closure_codeW (mk_body env ae2)
     ))
 
-  let message_start env sort = match sort with
+  let message_start env sort =
+    (* Candid decoding statistics and value limits only apply within a message *)
+    E.call_rts env "idl_start_message" ^^
+    match sort with
       | Type.(Shared Write) ->
         Lifecycle.(trans env InUpdate)
       | Type.(Shared Query) ->
@@ -10227,6 +10365,37 @@ module FuncDec = struct
     | _ -> ()
     end
 
+  let export_full_gc_method env =
+    let name = IC.full_gc_method_name in
+    begin match E.mode env with
+    | Flags.ICMode | Flags.RefMode ->
+      Func.define_built_in env name [] [] (fun env ->
+        let set_reclaimed, get_reclaimed = new_local env "reclaimed" in
+        message_start env (Type.Shared Type.Write) ^^
+        IC.assert_caller_self_or_controller env ^^
+        (* Like the GC trigger, ignore the argument. The full GC runs on an
+           empty call stack, capped at the instruction limit of update calls.
+           If the cap is reached, the scheduled GC increments continue the run. *)
+        GC.record_mutator_instructions env ^^
+        compile_unboxed_const Flags.(!stabilization_instruction_limit.update_call) ^^
+        E.call_rts env "force_incremental_gc" ^^
+        set_reclaimed ^^
+        GC.record_collector_instructions env ^^
+        (* Reply with the reclaimed bytes after the collection *)
+        get_reclaimed ^^ BigNum.from_word64 env ^^
+        Serialization.serialize env Type.[nat] ^^
+        IC.reply_with_data env ^^
+        Lifecycle.trans env Lifecycle.Idle
+      );
+
+      let fi = E.built_in env name in
+      E.add_export env (nr {
+        name = Lib.Utf8.decode ("canister_update " ^ name);
+        edesc = nr (FuncExport (nr fi))
+      })
+    | _ -> ()
+    end
+
   let export_stabilization_limits env =
     let moc_stabilization_instruction_limit_fi =
       E.add_fun env "moc_stabilization_instruction_limit" (
@@ -12122,6 +12291,14 @@ and compile_prim_invocation (env : E.t) ae p es at =
     SR.bool, compile_exp_vanilla env ae e ^^ Text.iter_done env
   | OtherPrim "text_iter_next", [e] ->
     SR.UnboxedWord64 Type.Char, compile_exp_vanilla env ae e ^^ Text.iter_next env
+  | OtherPrim "text_grapheme_count", [e] ->
+    SR.Vanilla, compile_exp_vanilla env ae e ^^ Text.grapheme_count_nat env
+  | OtherPrim "text_grapheme_iter", [e] ->
+    SR.Vanilla, compile_exp_vanilla env ae e ^^ Text.grapheme_iter env
+  | OtherPrim "text_grapheme_iter_done", [e] ->
+    SR.bool, compile_exp_vanilla env ae e ^^ Text.grapheme_iter_done env
+  | OtherPrim "text_grapheme_iter_next", [e] ->
+    SR.Vanilla, compile_exp_vanilla env ae e ^^ Text.grapheme_iter_next env
   | OtherPrim "text_compare", [e1; e2] ->
     SR.UnboxedWord64 Type.Int8,
     compile_exp_vanilla env ae e1 ^^
@@ -12281,6 +12458,11 @@ and compile_prim_invocation (env : E.t) ae p es at =
     compile_exp_vanilla env ae mode ^^
     E.call_rts env "float_fmt"
 
+  | OtherPrim "float_of_text", [e] ->
+    SR.Vanilla,
+    compile_exp_vanilla env ae e ^^
+    Float.of_text env
+
   | OtherPrim "fsin", [e] ->
     SR.UnboxedFloat64,
     compile_exp_as env ae SR.UnboxedFloat64 e ^^
@@ -12402,6 +12584,64 @@ and compile_prim_invocation (env : E.t) ae p es at =
     SR.Vanilla,
     StableMem.get_mem_size env ^^ BigNum.from_word64 env
 
+  | OtherPrim "set_gc_young_generation", [e] ->
+    SR.unit,
+    compile_exp_vanilla env ae e ^^
+    BigNum.to_word64_with env (Blob.lit env Tagged.T "Invalid young generation size") ^^
+    E.call_rts env "set_gc_young_generation"
+
+  | OtherPrim "get_gc_young_generation", [] ->
+    SR.Vanilla,
+    E.call_rts env "get_gc_young_generation" ^^ Prim.prim_word64toNat env
+
+  | OtherPrim "set_gc_scheduling_policy", ([_; _; _; _; _; _; _] as es) ->
+    SR.unit,
+    G.concat_map (fun e ->
+      compile_exp_vanilla env ae e ^^
+      BigNum.to_word64_with env (Blob.lit env Tagged.T "Invalid GC scheduling policy")
+    ) es ^^
+    E.call_rts env "set_gc_scheduling_policy"
+
+  | OtherPrim "reset_gc_scheduling_policy", [] ->
+    SR.unit,
+    E.call_rts env "reset_gc_scheduling_policy"
+
+  | OtherPrim "get_gc_scheduling_policy", [] ->
+    SR.UnboxedTuple 7,
+    G.concat (Lib.List.table 7 (fun index ->
+      compile_unboxed_const (Int64.of_int index) ^^
+      E.call_rts env "get_gc_scheduling_policy_field" ^^
+      Prim.prim_word64toNat env
+    ))
+
+  | OtherPrim "request_heap_snapshot", [] ->
+    SR.unit,
+    E.call_rts env "request_heap_snapshot"
+
+  | OtherPrim "take_heap_snapshot", [] ->
+    SR.Vanilla,
+    E.call_rts env "take_heap_snapshot"
+
+  | OtherPrim ("rts_census_object_count" | "rts_census_object_size" as p), [e] ->
+    (* RTS function names: "get_census_object_*" *)
+    SR.Vanilla,
+    compile_exp_vanilla env ae e ^^
+    BigNum.to_word64_with env (Blob.lit env Tagged.T "Invalid census tag") ^^
+    E.call_rts env ("get_" ^ String.sub p 4 (String.length p - 4)) ^^
+    Prim.prim_word64toNat env
+
+  | OtherPrim "rts_partition_count", [] ->
+    SR.Vanilla,
+    E.call_rts env "get_partition_count" ^^ Prim.prim_word64toNat env
+
+  | OtherPrim ("rts_partition_flags" | "rts_partition_occupied_size" | "rts_partition_marked_size" | "rts_partition_garbage_size" as p), [e] ->
+    (* RTS function names: "get_partition_*" *)
+    SR.Vanilla,
+    compile_exp_vanilla env ae e ^^
+    BigNum.to_word64_with env (Blob.lit env Tagged.T "Invalid partition index") ^^
+    E.call_rts env ("get_" ^ String.sub p 4 (String.length p - 4)) ^^
+    Prim.prim_word64toNat env
+
   | OtherPrim "rts_in_upgrade", [] -> (* EOP specific *)
     assert (!Flags.enhanced_orthogonal_persistence);
     SR.Vanilla,
@@ -12481,6 +12721,51 @@ and compile_prim_invocation (env : E.t) ae p es at =
     compile_exp_as env ae (SR.UnboxedWord64 Type.Nat64) e1 ^^
     Region.grow env
 
+  | OtherPrim "regionFree", [e0] ->
+    SR.UnboxedWord64 Type.Nat64,
+    compile_exp_as env ae SR.Vanilla e0 ^^
+    Region.free env
+
+  | OtherPrim "regionShrink", [e0; e1] ->
+    SR.UnboxedWord64 Type.Nat64,
+    compile_exp_as env ae SR.Vanilla e0 ^^
+    compile_exp_as env ae (SR.UnboxedWord64 Type.Nat64) e1 ^^
+    Region.shrink env
+
+  | OtherPrim "regionCopy", [e0; e1; e2; e3; e4] ->
+    SR.unit,
+    compile_exp_as env ae SR.Vanilla e0 ^^
+    compile_exp_as env ae (SR.UnboxedWord64 Type.Nat64) e1 ^^
+    compile_exp_as env ae SR.Vanilla e2 ^^
+    compile_exp_as env ae (SR.UnboxedWord64 Type.Nat64) e3 ^^
+    compile_exp_as env ae (SR.UnboxedWord64 Type.Nat64) e4 ^^
+    Region.copy env
+
+  | OtherPrim "regionFill", [e0; e1; e2; e3] ->
+    SR.unit,
+    compile_exp_as env ae SR.Vanilla e0 ^^
+    compile_exp_as env ae (SR.UnboxedWord64 Type.Nat64) e1 ^^
+    compile_exp_as env ae (SR.UnboxedWord64 Type.Nat64) e2 ^^
+    compile_exp_as env ae (SR.UnboxedWord64 Type.Nat8) e3 ^^
+    TaggedSmallWord.lsb_adjust Type.Nat8 ^^
+    G.i (Convert (Wasm_exts.Values.I32 I32Op.WrapI64)) ^^
+    Region.fill env
+
+  | OtherPrim "regionChecksum", [e0; e1; e2] ->
+    SR.UnboxedWord64 Type.Nat32,
+    compile_exp_as env ae SR.Vanilla e0 ^^
+    compile_exp_as env ae (SR.UnboxedWord64 Type.Nat64) e1 ^^
+    compile_exp_as env ae (SR.UnboxedWord64 Type.Nat64) e2 ^^
+    Region.checksum env ^^
+    G.i (Convert (Wasm_exts.Values.I64 I64Op.ExtendUI32)) ^^
+    TaggedSmallWord.msb_adjust Type.Nat32
+
+  | OtherPrim "regionMetaDataChecksum", [] ->
+    SR.UnboxedWord64 Type.Nat32,
+    Region.meta_data_checksum env ^^
+    G.i (Convert (Wasm_exts.Values.I64 I64Op.ExtendUI32)) ^^
+    TaggedSmallWord.msb_adjust Type.Nat32
+
   | OtherPrim "regionSize", [e0] ->
     SR.UnboxedWord64 Type.Nat64,
     compile_exp_as env ae SR.Vanilla e0 ^^
@@ -12737,6 +13022,9 @@ and compile_prim_invocation (env : E.t) ae p es at =
   | OtherPrim "char_to_lower", [e] ->
     compile_char_to_char_rts env ae e "char_to_lower"
 
+  | OtherPrim "char_case_fold", [e] ->
+    compile_char_to_char_rts env ae e "char_case_fold"
+
   | OtherPrim "char_is_whitespace", [e] ->
     compile_char_to_bool_rts env ae e "char_is_whitespace"
 
@@ -12764,6 +13052,50 @@ and compile_prim_invocation (env : E.t) ae p es at =
     compile_exp_vanilla env ae e ^^
     Text.uppercase env
 
+  | OtherPrim "text_case_fold", [e] ->
+    SR.Vanilla,
+    compile_exp_vanilla env ae e ^^
+    Text.case_fold env
+
+  | OtherPrim "text_nfc", [e] ->
+    SR.Vanilla,
+    compile_exp_vanilla env ae e ^^
+    Text.nfc env
+
+  | OtherPrim "text_nfd", [e] ->
+    SR.Vanilla,
+    compile_exp_vanilla env ae e ^^
+    Text.nfd env
+
+  | OtherPrim "text_nfkc", [e] ->
+    SR.Vanilla,
+    compile_exp_vanilla env ae e ^^
+    Text.nfkc env
+
+  | OtherPrim "text_nfkd", [e] ->
+    SR.Vanilla,
+    compile_exp_vanilla env ae e ^^
+    Text.nfkd env
+
+  | OtherPrim "text_char_at", [e1; e2] ->
+    SR.UnboxedWord64 Type.Char,
+    compile_exp_vanilla env ae e1 ^^
+    compile_exp_vanilla env ae e2 ^^
+    Text.char_at env
+
+  | OtherPrim "text_slice", [e1; e2; e3] ->
+    SR.Vanilla,
+    compile_exp_vanilla env ae e1 ^^
+    compile_exp_vanilla env ae e2 ^^
+    compile_exp_vanilla env ae e3 ^^
+    Text.slice env
+
+  | OtherPrim "text_find", [e1; e2] ->
+    SR.Vanilla,
+    compile_exp_vanilla env ae e1 ^^
+    compile_exp_vanilla env ae e2 ^^
+    Text.find env
+
   | OtherPrim "performanceCounter", [e] ->
     (SR.UnboxedWord64 Type.Nat64),
     compile_exp_as env ae (SR.UnboxedWord64 Type.Nat32) e ^^
@@ -12973,6 +13305,20 @@ and compile_prim_invocation (env : E.t) ae p es at =
     TaggedSmallWord.msb_adjust Type.Nat32 ^^
     TaggedSmallWord.tag env Type.Nat32
 
+  | OtherPrim "setCandidValueLimit", [e] ->
+    SR.unit,
+    compile_exp_as env ae (SR.UnboxedWord64 Type.Nat64) e ^^
+    E.call_rts env "idl_set_value_limit"
+
+  | OtherPrim "getCandidDecodingStats", [] ->
+    SR.UnboxedTuple 3,
+    E.call_rts env "idl_decoded_values" ^^
+    BoxedWord64.box env Type.Nat64 ^^
+    E.call_rts env "idl_skipped_bytes" ^^
+    BoxedWord64.box env Type.Nat64 ^^
+    E.call_rts env "idl_subtype_cache_size" ^^
+    BoxedWord64.box env Type.Nat64
+
   (* Coercions for abstract types *)
   | CastPrim (_,_), [e] ->
     compile_exp env ae e
@@ -14051,6 +14397,7 @@ and conclude_module env set_serialization_globals start_fi_o =
 
   FuncDec.export_async_method env;
   FuncDec.export_gc_trigger_method env;
+  FuncDec.export_full_gc_method env;
   FuncDec.export_stabilization_limits env;
 
   (* See Note [Candid subtype checks] *)
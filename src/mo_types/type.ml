@@ -1806,6 +1806,12 @@ let motoko_gc_trigger_fld =
     src = empty_src;
   }
 
+let motoko_full_gc_fld =
+  { lab = "__motoko_full_gc";
+    typ = Func(Shared Write, Promises, [scope_bind], [], [nat]);
+    src = empty_src;
+  }
+
 let motoko_runtime_information_type =
   obj Object [
     ("callbackTableCount", nat);
@@ -1836,6 +1842,7 @@ let well_known_actor_fields = [
     motoko_async_helper_fld;
     motoko_stable_var_info_fld;
     motoko_gc_trigger_fld;
+    motoko_full_gc_fld;
   ]
 
 let decode_msg_typ tfs =
@@ -365,6 +365,7 @@ val timeout_lab : lab
 val motoko_async_helper_fld : field
 val motoko_stable_var_info_fld : field
 val motoko_gc_trigger_fld : field
+val motoko_full_gc_fld : field
 val motoko_runtime_information_fld : field
 
 val cycles_fld : field
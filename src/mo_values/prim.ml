@@ -100,6 +100,87 @@ let num_conv_wrap_prim trap t1 t2 =
   fun v -> try of_big_int_wrap t2 (as_big_int t1 v)
            with Invalid_argument msg -> trap.trap msg
 
+(* Extended grapheme clusters of Unicode Standard Annex #29, as in the RTS *)
+let graphemes cs =
+  let gcb c = Uucp.Break.grapheme_cluster (Uchar.of_int c) in
+  let pictographic c = Uucp.Emoji.is_extended_pictographic (Uchar.of_int c) in
+  let incb c = Uucp.Break.indic_conjunct_break (Uchar.of_int c) in
+  (* [pict]: the preceding characters end in an extended pictographic character
+     followed by extending characters, [pict_zwj]: in such a sequence followed by
+     a zero width joiner, [odd_ri]: in an odd number of regional indicators,
+     [conj]: in an Indic conjunct consonant followed by extending characters and
+     linkers, [conj_linker]: in such a sequence with at least one linker *)
+  let is_boundary p c pict_zwj odd_ri conj_linker =
+    match gcb p, gcb c with
+    | `CR, `LF -> false
+    | (`CN | `CR | `LF), _ | _, (`CN | `CR | `LF) -> true
+    | `L, (`L | `V | `LV | `LVT) -> false
+    | (`LV | `V), (`V | `T) -> false
+    | (`LVT | `T), `T -> false
+    | _, (`EX | `ZWJ | `SM) -> false
+    | `PP, _ -> false
+    | _ when incb c = `Consonant -> not conj_linker
+    | `ZWJ, _ when pictographic c -> not pict_zwj
+    | `RI, `RI -> not odd_ri
+    | _ -> true in
+  let rec go clusters cluster p pict pict_zwj odd_ri conj conj_linker = function
+    | [] -> List.rev (List.rev cluster :: clusters)
+    | c :: cs ->
+      let clusters, cluster =
+        if is_boundary p c pict_zwj odd_ri conj_linker
+        then List.rev cluster :: clusters, [c]
+        else clusters, c :: cluster in
+      let conj = incb c = `Consonant || (conj && (incb c = `Extend || incb c = `Linker)) in
+      go clusters cluster c
+        (pictographic c || (pict && gcb c = `EX))
+        (pict && gcb c = `ZWJ)
+        (gcb c = `RI && not odd_ri)
+        conj
+        (conj && incb c <> `Consonant && (conj_linker || incb c = `Linker))
+        cs in
+  match cs with
+  | [] -> []
+  | c :: cs -> go [] [c] c (pictographic c) false (gcb c = `RI) (incb c = `Consonant) false cs
+
+(* The shortest number of significant digits for which the exponent format
+   parses back to the same finite float, with that format *)
+let shortest_digits f =
+  let rec digits p =
+    let s = Printf.sprintf "%.*e" (p - 1) f in
+    if p >= 17 || float_of_string s = f then p, s else digits (p + 1) in
+  digits 1
+
+let decimal_exponent s =
+  let e = String.index s 'e' in
+  e, int_of_string (String.sub s (e + 1) (String.length s - e - 1))
+
+(* Shortest decimal representation that parses back to the same float,
+   in fixed notation unless the decimal exponent is below -4 or above 16 *)
+let shortest_float f =
+  match classify_float f with
+  | FP_nan -> "nan"
+  | FP_infinite -> if f > 0.0 then "inf" else "-inf"
+  | _ ->
+    let p, s = shortest_digits f in
+    let _, x = decimal_exponent s in
+    if x < -4 || x >= 17 then s else Printf.sprintf "%.*f" (max 0 (p - 1 - x)) f
+
+(* Shortest decimal representation that parses back to the same float,
+   always in fixed notation, as Rust's formatting of floats in the RTS *)
+let exact_float f =
+  match classify_float f with
+  | FP_nan -> "NaN"
+  | FP_infinite -> if f > 0.0 then "inf" else "-inf"
+  | _ ->
+    let p, s = shortest_digits f in
+    let e, x = decimal_exponent s in
+    let sign = if s.[0] = '-' then "-" else "" in
+    let mantissa = String.sub s (String.length sign) (e - String.length sign) in
+    let digits = String.concat "" (String.split_on_char '.' mantissa) in
+    if x < 0 then sign ^ "0." ^ String.make (- x - 1) '0' ^ digits
+    else if x + 1 >= p then sign ^ digits ^ String.make (x + 1 - p) '0'
+    else sign ^ String.sub digits 0 (x + 1) ^ "." ^ String.sub digits (x + 1) (p - x - 1)
+
 let prim trap =
   let via_float f v = Float.(Float (of_float (f (to_float (as_float v))))) in
   let via_float2 f v w = Float.(Float (of_float (f (to_float (as_float v)) (to_float (as_float w))))) in
@@ -111,6 +192,8 @@ let prim trap =
     | 1 -> sprintf "%.*e" prec
     | 2 -> sprintf "%.*g" prec
     | 3 -> sprintf "%.*h" prec
+    | 4 -> exact_float
+    | 5 -> shortest_float
     | _ -> fun _ -> trap.trap "float_formatter: unrecognised mode" in
   function
   | "abs" -> fun _ v k -> k (Int (Nat.abs (as_int v)))
@@ -139,6 +222,15 @@ let prim trap =
      | [f; prec; mode] ->
        k (Text (float_formatter (unpack_nat8 prec) (unpack_nat8 mode) Float.(to_float (as_float f))))
      | _ -> assert false)
+  | "float_of_text" -> fun _ v k ->
+    let s = as_text v in
+    let valid c = match c with
+      | '0'..'9' | 'a'..'z' | 'A'..'Z' | '.' | '+' | '-' -> true
+      | _ -> false in
+    (* OCaml also accepts underscores and leading whitespace *)
+    (match String.for_all valid s, float_of_string_opt s with
+     | true, Some f -> k (Opt (Float (Float.of_float f)))
+     | _ -> k Null)
   | "fsin" -> fun _ v k -> k (via_float Stdlib.sin v)
   | "fcos" -> fun _ v k -> k (via_float Stdlib.cos v)
   | "ftan" -> fun _ v k -> k (via_float Stdlib.tan v)
@@ -277,10 +369,10 @@ let prim trap =
     let s = String.to_seq (Value.as_blob v) in
     let valuation b = Nat8 (Nat8.of_int (Char.code b)) in
     k (Iter (ref (Seq.map valuation s)))
-  | "blob_iter_done" | "text_iter_done" -> fun _ v k ->
+  | "blob_iter_done" | "text_iter_done" | "text_grapheme_iter_done" -> fun _ v k ->
     let i = Value.as_iter v in
     k (Bool (!i () = Seq.Nil))
-  | "blob_iter_next" | "text_iter_next" -> fun _ v k ->
+  | "blob_iter_next" | "text_iter_next" | "text_grapheme_iter_next" -> fun _ v k ->
     let i = Value.as_iter v in
     begin match !i () with
     | Seq.Nil -> assert false
@@ -293,6 +385,46 @@ let prim trap =
      k (Text (String.lowercase_ascii (Value.as_text v))) (* TODO -- use Unicode here. *)
   | "text_uppercase" -> fun _ v k ->
      k (Text (String.uppercase_ascii (Value.as_text v))) (* TODO -- use Unicode here. *)
+  | "text_case_fold" -> fun _ v k ->
+    let fold c = match Uucp.Case.Fold.fold (Uchar.of_int c) with
+      | `Uchars cs -> List.map Uchar.to_int cs
+      | `Self -> [c] in
+    k (Text (Lib.Utf8.encode (List.concat_map fold (Lib.Utf8.decode (Value.as_text v)))))
+  | "text_nfc" | "text_nfd" | "text_nfkc" | "text_nfkd" -> fun _ v k ->
+     k v (* TODO -- normalize here. *)
+  | "text_char_at" -> fun _ v k ->
+    (match Value.as_tup v with
+     | [t; i] ->
+       let cs = Lib.Utf8.decode (Value.as_text t) in
+       let i = Int.to_int (as_int i) in
+       if i < List.length cs then k (Char (List.nth cs i))
+       else trap.trap "text_char_at: Index out of range"
+     | _ -> assert false)
+  | "text_slice" -> fun _ v k ->
+    (match Value.as_tup v with
+     | [t; start; len] ->
+       let cs = Lib.Utf8.decode (Value.as_text t) in
+       let start, len = Int.to_int (as_int start), Int.to_int (as_int len) in
+       if start + len <= List.length cs
+       then k (Text (Lib.Utf8.encode (List.filteri (fun i _ -> start <= i && i < start + len) cs)))
+       else trap.trap "text_slice: Range out of bounds"
+     | _ -> assert false)
+  | "text_find" -> fun _ v k ->
+    (match Value.as_tup v with
+     | [t; p] ->
+       let cs = Lib.Utf8.decode (Value.as_text t) in
+       let ps = Lib.Utf8.decode (Value.as_text p) in
+       let rec is_prefix ps cs = match ps, cs with
+         | [], _ -> true
+         | p :: ps', c :: cs' -> p = c && is_prefix ps' cs'
+         | _ -> false in
+       let rec find i cs =
+         if is_prefix ps cs then k (Opt (Int (Nat.of_int i)))
+         else match cs with
+           | [] -> k Null
+           | _ :: cs' -> find (i + 1) cs' in
+       find 0 cs
+     | _ -> assert false)
   | "text_compare" -> fun _ v k ->
     (match Value.as_tup v with
      | [a; b] -> k (Int8 (Int_8.of_int
@@ -309,6 +441,12 @@ let prim trap =
     let s = Lib.Utf8.decode (Value.as_text v) in
     let i = Seq.map (fun c -> Char c) (List.to_seq s) in
     k (Iter (ref i))
+  | "text_grapheme_count" -> fun _ v k ->
+    k (Int (Nat.of_int (List.length (graphemes (Lib.Utf8.decode (Value.as_text v))))))
+  | "text_grapheme_iter" -> fun _ v k ->
+    let gs = graphemes (Lib.Utf8.decode (Value.as_text v)) in
+    let i = Seq.map (fun g -> Text (Lib.Utf8.encode g)) (List.to_seq gs) in
+    k (Iter (ref i))
   | "Array.init" -> fun _ v k ->
     (match Value.as_tup v with
     | [len; x] ->
@@ -396,10 +534,13 @@ let prim trap =
         begin match Uucp.Case.Map.to_upper (Uchar.of_int (as_char v)) with
         | `Uchars [c] -> k (Char (Uchar.to_int c))
         | `Uchars _ ->
-            (* RTS implementation of to_upper returns the input for characters
-               that map to multiple characters in uppercase versions, so to be
-               in sync with that we do the same here *)
-            k v
+            (* Characters that map to multiple characters in uppercase use
+               their simple uppercase mapping, as in the RTS. That is the
+               titlecase mapping, if it is a single character *)
+            begin match Uucp.Case.Map.to_title (Uchar.of_int (as_char v)) with
+            | `Uchars [c] -> k (Char (Uchar.to_int c))
+            | _ -> k v
+            end
         | `Self -> k v
         end
 
@@ -407,7 +548,22 @@ let prim trap =
       fun _ v k ->
         begin match Uucp.Case.Map.to_lower (Uchar.of_int (as_char v)) with
         | `Uchars [c] -> k (Char (Uchar.to_int c))
-        | `Uchars _ -> k v (* same as above, in char_to_upper *)
+        | `Uchars (c :: _) -> k (Char (Uchar.to_int c)) (* only U+0130, to its base letter *)
+        | `Uchars [] | `Self -> k v
+        end
+
+  | "char_case_fold" ->
+      fun _ v k ->
+        let c = Uchar.of_int (as_char v) in
+        begin match Uucp.Case.Fold.fold c with
+        | `Uchars [c] -> k (Char (Uchar.to_int c))
+        | `Uchars _ ->
+            (* The simple case folding is the lowercase mapping, if it is a
+               single character *)
+            begin match Uucp.Case.Map.to_lower c with
+            | `Uchars [c] -> k (Char (Uchar.to_int c))
+            | _ -> k v
+            end
         | `Self -> k v
         end
 
@@ -462,6 +618,12 @@ let prim trap =
       fun _ v k -> k (Tup Numerics.Nat32.[
         Nat32 zero; Nat32 zero])
 
+  | "setCandidValueLimit" ->
+      fun _ v k -> k unit
+  | "getCandidDecodingStats" ->
+      fun _ v k -> k (Tup Numerics.Nat64.[
+        Nat64 zero; Nat64 zero; Nat64 zero])
+
   | "alloc_weak_ref" ->
      fun _ v k ->
       (* TODO: model trapping on non-scalars *)